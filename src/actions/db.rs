@@ -4,6 +4,7 @@
 /// Follows EventsDatabase pattern with split read/write pools.
 use super::types::{Action, ActionId, ActionState, ActionStep, ActionType, StepStatus};
 use crate::logger::{self, LogTag};
+use crate::migrations::{self, Migration, MigrationSet};
 use crate::utils::get_wallet_address;
 use chrono::{DateTime, Utc};
 use r2d2::{Pool, PooledConnection};
@@ -26,6 +27,111 @@ const READ_POOL_MAX_SIZE: u32 = 10;
 const POOL_MIN_IDLE: u32 = 1;
 const CONNECTION_TIMEOUT_MS: u64 = 30_000;
 
+// =============================================================================
+// SCHEMA MIGRATIONS
+// =============================================================================
+
+/// Ordered schema migrations of actions.db
+pub const ACTIONS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "actions",
+    migrations: &[Migration::apply(1, "Baseline schema", baseline_schema)],
+};
+
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    // Create main actions table
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS actions (
+            id TEXT PRIMARY KEY,
+            action_type TEXT NOT NULL,
+            entity_id TEXT NOT NULL,
+            wallet_address TEXT NOT NULL,
+            state TEXT NOT NULL,
+            state_data TEXT,
+            started_at TEXT NOT NULL,
+            completed_at TEXT,
+            duration_ms INTEGER,
+            metadata TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+        [],
+    )
+    .map_err(|e| format!("Failed to create actions table: {}", e))?;
+
+    // Create action steps table
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS action_steps (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            action_id TEXT NOT NULL,
+            step_index INTEGER NOT NULL,
+            step_id TEXT NOT NULL,
+            name TEXT NOT NULL,
+            status TEXT NOT NULL,
+            started_at TEXT,
+            completed_at TEXT,
+            duration_ms INTEGER,
+            error TEXT,
+            metadata TEXT,
+            FOREIGN KEY (action_id) REFERENCES actions(id),
+            UNIQUE(action_id, step_index)
+        )
+        "#,
+        [],
+    )
+    .map_err(|e| format!("Failed to create action_steps table: {}", e))?;
+
+    // Create indexes for performance
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_actions_action_type ON actions(action_type)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create action_type index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_actions_entity_id ON actions(entity_id)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create entity_id index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_actions_state ON actions(state)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create state index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_actions_started_at ON actions(started_at DESC)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create started_at index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_actions_wallet_address ON actions(wallet_address)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create wallet_address index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_actions_completed_at ON actions(completed_at DESC) WHERE completed_at IS NOT NULL",
+        [],
+    )
+    .map_err(|e| format!("Failed to create completed_at index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_action_steps_action_id ON action_steps(action_id)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create action_steps index: {}", e))?;
+
+    logger::info(LogTag::System, "Actions database schema initialized");
+
+    Ok(())
+}
+
 // =============================================================================
 // DATABASE STRUCTURE
 // =============================================================================
@@ -96,95 +202,7 @@ impl ActionsDatabase {
         conn.busy_timeout(std::time::Duration::from_millis(30_000))
             .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
 
-        // Create main actions table
-        conn.execute(
-            r#"
-            CREATE TABLE IF NOT EXISTS actions (
-                id TEXT PRIMARY KEY,
-                action_type TEXT NOT NULL,
-                entity_id TEXT NOT NULL,
-                wallet_address TEXT NOT NULL,
-                state TEXT NOT NULL,
-                state_data TEXT,
-                started_at TEXT NOT NULL,
-                completed_at TEXT,
-                duration_ms INTEGER,
-                metadata TEXT NOT NULL,
-                created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL
-            )
-            "#,
-            [],
-        )
-        .map_err(|e| format!("Failed to create actions table: {}", e))?;
-
-        // Create action steps table
-        conn.execute(
-            r#"
-            CREATE TABLE IF NOT EXISTS action_steps (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                action_id TEXT NOT NULL,
-                step_index INTEGER NOT NULL,
-                step_id TEXT NOT NULL,
-                name TEXT NOT NULL,
-                status TEXT NOT NULL,
-                started_at TEXT,
-                completed_at TEXT,
-                duration_ms INTEGER,
-                error TEXT,
-                metadata TEXT,
-                FOREIGN KEY (action_id) REFERENCES actions(id),
-                UNIQUE(action_id, step_index)
-            )
-            "#,
-            [],
-        )
-        .map_err(|e| format!("Failed to create action_steps table: {}", e))?;
-
-        // Create indexes for performance
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_actions_action_type ON actions(action_type)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create action_type index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_actions_entity_id ON actions(entity_id)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create entity_id index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_actions_state ON actions(state)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create state index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_actions_started_at ON actions(started_at DESC)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create started_at index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_actions_wallet_address ON actions(wallet_address)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create wallet_address index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_actions_completed_at ON actions(completed_at DESC) WHERE completed_at IS NOT NULL",
-            [],
-        )
-        .map_err(|e| format!("Failed to create completed_at index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_action_steps_action_id ON action_steps(action_id)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create action_steps index: {}", e))?;
-
-        logger::info(LogTag::System, "Actions database schema initialized");
+        migrations::run_migrations(&conn, &ACTIONS_MIGRATIONS)?;
 
         Ok(())
     }
@@ -6,6 +6,7 @@
 //! - Tool execution tracking with inputs/outputs
 
 use crate::logger::{self, LogTag};
+use crate::migrations::{self, add_column_if_missing, Migration, MigrationSet};
 use once_cell::sync::OnceCell;
 use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
@@ -19,6 +20,16 @@ use std::sync::Arc;
 
 static GLOBAL_CHAT_POOL: OnceCell<Arc<Pool<SqliteConnectionManager>>> = OnceCell::new();
 
+/// Ordered schema migrations of ai_chat.db
+pub const AI_CHAT_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "ai_chat",
+    migrations: &[Migration::apply(
+        1,
+        "Baseline schema (chat sessions and scheduled tasks)",
+        baseline_schema,
+    )],
+};
+
 // =============================================================================
 // DATA STRUCTURES
 // =============================================================================
@@ -118,6 +129,12 @@ pub fn get_chat_pool() -> Option<Arc<Pool<SqliteConnectionManager>>> {
 
 /// Initialize database schema
 fn initialize_schema(conn: &rusqlite::Connection) -> Result<(), String> {
+    migrations::run_migrations(conn, &AI_CHAT_MIGRATIONS)?;
+    Ok(())
+}
+
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &rusqlite::Connection) -> Result<(), String> {
     // Chat sessions table
     conn.execute(
         "CREATE TABLE IF NOT EXISTS chat_sessions (
@@ -181,11 +198,13 @@ fn initialize_schema(conn: &rusqlite::Connection) -> Result<(), String> {
     )
     .map_err(|e| format!("Failed to create sessions index: {}", e))?;
 
-    // Add is_hidden column if not exists (for scheduled task sessions)
-    let _ = conn.execute(
-        "ALTER TABLE chat_sessions ADD COLUMN is_hidden INTEGER NOT NULL DEFAULT 0",
-        [],
-    );
+    // Hidden sessions (scheduled task runs)
+    add_column_if_missing(
+        conn,
+        "chat_sessions",
+        "is_hidden",
+        "INTEGER NOT NULL DEFAULT 0",
+    )?;
 
     // Initialize scheduled tasks tables
     crate::ai::scheduled_db::initialize_scheduled_tables(conn)?;
@@ -6,6 +6,7 @@
 //! - Built-in instruction templates
 
 use crate::logger::{self, LogTag};
+use crate::migrations::{self, Migration, MigrationSet};
 use once_cell::sync::OnceCell;
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
@@ -106,8 +107,20 @@ pub fn get_ai_database() -> Option<Arc<Mutex<Connection>>> {
     GLOBAL_AI_DB.get().cloned()
 }
 
+/// Ordered schema migrations of ai.db
+pub const AI_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "ai",
+    migrations: &[Migration::apply(1, "Baseline schema", baseline_schema)],
+};
+
 /// Initialize database schema
 fn initialize_schema(conn: &Connection) -> Result<(), String> {
+    migrations::run_migrations(conn, &AI_MIGRATIONS)?;
+    Ok(())
+}
+
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
     // User instructions table
     conn.execute(
         "CREATE TABLE IF NOT EXISTS ai_instructions (
@@ -7,6 +7,9 @@
 /// **Execution Modes** (mutually exclusive - choose one):
 /// - `--reset`: Reset database state
 /// - `--clean-wallet-data`: Clean all wallet-specific databases
+/// - `--backup`: Back up all databases and config, then exit
+/// - `--restore <archive>`: Restore a backup archive, then exit
+/// - `--migrate-dry-run`: Report pending schema migrations, then exit
 /// - `--help`: Show help information
 ///
 /// **Display Modes**:
@@ -87,6 +90,35 @@ pub fn is_reset_enabled() -> bool {
     has_arg("--reset")
 }
 
+/// Backup mode - archive all databases and config.toml, then exit
+pub fn is_backup_enabled() -> bool {
+    has_arg("--backup")
+}
+
+/// Restore mode - archive to restore from (bot must be stopped)
+pub fn get_restore_archive() -> Option<String> {
+    get_arg_value("--restore")
+}
+
+/// Migration dry run - report pending schema migrations without applying them
+pub fn is_migrate_dry_run_enabled() -> bool {
+    has_arg("--migrate-dry-run")
+}
+
+/// Components to restore with --restore (comma-separated); empty means all
+/// Use with: --restore <archive> --restore-only positions,strategies
+pub fn get_restore_components() -> Vec<String> {
+    get_arg_value("--restore-only")
+        .map(|value| {
+            value
+                .split(',')
+                .map(|c| c.trim().to_string())
+                .filter(|c| !c.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Dashboard demo mode - show hardcoded showcase data for screenshots/marketing
 /// Use with: --gui --dashboard-demo
 pub fn is_dashboard_demo_enabled() -> bool {
@@ -249,6 +281,12 @@ pub fn print_help() {
     );
     println!("    --reset-default-configs     Reset all config to defaults (preserves wallet + RPC URLs)");
     println!("    --clean-wallet-data         Clean all wallet-specific databases (use when switching wallets)");
+    println!("    --backup                    Back up all databases and config.toml (wallet keys excluded)");
+    println!("    --restore <ARCHIVE>         Restore a backup archive (stop the bot first)");
+    println!(
+        "    --restore-only <LIST>       Comma-separated components to restore (with --restore)"
+    );
+    println!("    --migrate-dry-run           Show pending database schema migrations without applying them");
     println!("    --help, -h                  Show this help message");
     println!();
     println!("DISPLAY OPTIONS:");
@@ -307,6 +345,9 @@ pub fn print_help() {
     println!(
         "    screenerbot --clean-wallet-data              # Clean databases when switching wallets"
     );
+    println!("    screenerbot --backup                         # Create a backup archive");
+    println!("    screenerbot --restore <archive> --restore-only positions,strategies");
+    println!("    screenerbot --migrate-dry-run                # Check databases before upgrading");
     println!();
     println!("BUILDING:");
     println!("    cargo build                                  # Build complete binary (GUI always included)");
@@ -0,0 +1,284 @@
+//! Archive layout
+//!
+//! A backup archive is a single gzip stream:
+//!
+//! ```text
+//! "SBBACKUP" | manifest length (u32 LE) | manifest JSON | entry data ...
+//! ```
+//!
+//! Entry data follows in manifest order. Sizes and SHA-256 checksums come from
+//! the manifest, so every entry is verified while streaming and the manifest
+//! can be listed without decompressing the rest of the archive.
+
+use super::types::{BackupEntry, BackupManifest};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+const MAGIC: &[u8; 8] = b"SBBACKUP";
+
+/// Upper bound for the manifest size (guards against reading garbage)
+const MAX_MANIFEST_BYTES: u32 = 1024 * 1024;
+
+/// SHA-256 (hex) and size of a file
+pub fn hash_file(path: &Path) -> Result<(String, u64), String> {
+    let mut file = File::open(path)
+        .map_err(|e| format!("Failed to open {} for checksum: {}", path.display(), e))?;
+    let mut writer = HashingWriter::new(Some(io::sink()));
+    let size = io::copy(&mut file, &mut writer)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Ok((writer.finish(), size))
+}
+
+/// Write an archive; `sources[i]` holds the data of `manifest.entries[i]`
+pub fn write_archive<W: Write>(
+    out: W,
+    manifest: &BackupManifest,
+    sources: &[PathBuf],
+) -> Result<W, String> {
+    if sources.len() != manifest.entries.len() {
+        return Err(format!(
+            "Manifest lists {} entries but {} source files were given",
+            manifest.entries.len(),
+            sources.len()
+        ));
+    }
+
+    let json = serde_json::to_vec(manifest)
+        .map_err(|e| format!("Failed to serialize backup manifest: {}", e))?;
+    let mut encoder = GzEncoder::new(out, Compression::default());
+    let write_err = |e: io::Error| format!("Failed to write backup archive: {}", e);
+
+    encoder.write_all(MAGIC).map_err(write_err)?;
+    encoder
+        .write_all(&(json.len() as u32).to_le_bytes())
+        .map_err(write_err)?;
+    encoder.write_all(&json).map_err(write_err)?;
+
+    for (entry, source) in manifest.entries.iter().zip(sources) {
+        let mut file = File::open(source)
+            .map_err(|e| format!("Failed to open {}: {}", source.display(), e))?;
+        let copied = io::copy(&mut file, &mut encoder).map_err(write_err)?;
+        if copied != entry.size_bytes {
+            return Err(format!(
+                "{} changed while archiving ({} bytes expected, {} written)",
+                entry.file_name, entry.size_bytes, copied
+            ));
+        }
+    }
+
+    encoder.finish().map_err(write_err)
+}
+
+/// Read the manifest at the start of an archive
+///
+/// Returns the decoder positioned at the first entry.
+pub fn read_manifest<R: Read>(input: R) -> Result<(BackupManifest, GzDecoder<R>), String> {
+    let mut decoder = GzDecoder::new(input);
+    let read_err = |e: io::Error| format!("Failed to read backup archive: {}", e);
+
+    let mut magic = [0u8; 8];
+    decoder.read_exact(&mut magic).map_err(read_err)?;
+    if &magic != MAGIC {
+        return Err("Not a ScreenerBot backup archive".to_string());
+    }
+
+    let mut len = [0u8; 4];
+    decoder.read_exact(&mut len).map_err(read_err)?;
+    let len = u32::from_le_bytes(len);
+    if len > MAX_MANIFEST_BYTES {
+        return Err(format!("Backup manifest too large ({} bytes)", len));
+    }
+
+    let mut json = vec![0u8; len as usize];
+    decoder.read_exact(&mut json).map_err(read_err)?;
+    let manifest: BackupManifest =
+        serde_json::from_slice(&json).map_err(|e| format!("Invalid backup manifest: {}", e))?;
+    manifest.verify()?;
+
+    Ok((manifest, decoder))
+}
+
+/// Verify every entry of an archive against its manifest checksum
+pub fn verify_entries<R: Read>(input: R) -> Result<BackupManifest, String> {
+    stream_entries(input, |_| Ok(None))
+}
+
+/// Extract the entries of `components` into `dest_dir`, verifying the whole archive
+///
+/// Files are written as `<file_name>.restore`; callers move them into place.
+pub fn extract_entries<R: Read>(
+    input: R,
+    components: &[String],
+    dest_dir: &Path,
+) -> Result<(BackupManifest, Vec<(BackupEntry, PathBuf)>), String> {
+    let mut extracted = Vec::new();
+    let manifest = stream_entries(input, |entry| {
+        if !components.contains(&entry.component) {
+            return Ok(None);
+        }
+        let path = dest_dir.join(format!("{}.restore", entry.file_name));
+        let file = File::create(&path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        extracted.push((entry.clone(), path));
+        Ok(Some(file))
+    });
+
+    match manifest {
+        Ok(manifest) => Ok((manifest, extracted)),
+        Err(e) => {
+            for (_, path) in &extracted {
+                let _ = std::fs::remove_file(path);
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Stream through all entries, handing each one to the writer returned by `open`
+fn stream_entries<R: Read>(
+    input: R,
+    mut open: impl FnMut(&BackupEntry) -> Result<Option<File>, String>,
+) -> Result<BackupManifest, String> {
+    let (manifest, mut decoder) = read_manifest(input)?;
+
+    for entry in &manifest.entries {
+        let mut writer = HashingWriter::new(open(entry)?);
+        let copied = io::copy(&mut (&mut decoder).take(entry.size_bytes), &mut writer)
+            .map_err(|e| format!("Failed to read {} from archive: {}", entry.file_name, e))?;
+        writer
+            .flush()
+            .map_err(|e| format!("Failed to write {}: {}", entry.file_name, e))?;
+
+        if copied != entry.size_bytes {
+            return Err(format!(
+                "Archive truncated: {} has {} of {} bytes",
+                entry.file_name, copied, entry.size_bytes
+            ));
+        }
+        let checksum = writer.finish();
+        if checksum != entry.sha256 {
+            return Err(format!(
+                "Checksum mismatch for {} (expected {}, got {})",
+                entry.file_name, entry.sha256, checksum
+            ));
+        }
+    }
+
+    Ok(manifest)
+}
+
+/// Writer that hashes everything passing through it
+struct HashingWriter<W> {
+    inner: Option<W>,
+    hasher: Sha256,
+}
+
+impl<W: Write> HashingWriter<W> {
+    fn new(inner: Option<W>) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    fn finish(self) -> String {
+        format!("{:x}", self.hasher.finalize())
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = match self.inner.as_mut() {
+            Some(inner) => inner.write(buf)?,
+            None => buf.len(),
+        };
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.inner.as_mut() {
+            Some(inner) => inner.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::{BackupTrigger, EntryKind, BACKUP_FORMAT, BACKUP_FORMAT_VERSION};
+    use super::*;
+
+    fn archive_with(dir: &Path, files: &[(&str, &[u8])]) -> (BackupManifest, Vec<u8>) {
+        let mut entries = Vec::new();
+        let mut sources = Vec::new();
+        for (name, data) in files {
+            let path = dir.join(name);
+            std::fs::write(&path, data).unwrap();
+            let (sha256, size_bytes) = hash_file(&path).unwrap();
+            entries.push(BackupEntry {
+                component: name.trim_end_matches(".db").to_string(),
+                file_name: name.to_string(),
+                kind: EntryKind::Database,
+                size_bytes,
+                sha256,
+            });
+            sources.push(path);
+        }
+        let manifest = BackupManifest {
+            format: BACKUP_FORMAT.to_string(),
+            format_version: BACKUP_FORMAT_VERSION,
+            created_at: chrono::Utc::now(),
+            app_version: "test".to_string(),
+            trigger: BackupTrigger::Manual,
+            entries,
+            excluded: vec![],
+        };
+        let bytes = write_archive(Vec::new(), &manifest, &sources).unwrap();
+        (manifest, bytes)
+    }
+
+    #[test]
+    fn roundtrip_extracts_selected_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let (manifest, bytes) = archive_with(
+            dir.path(),
+            &[
+                ("positions.db", b"positions data"),
+                ("tokens.db", b"tokens"),
+            ],
+        );
+
+        assert_eq!(read_manifest(bytes.as_slice()).unwrap().0, manifest);
+        assert!(verify_entries(bytes.as_slice()).is_ok());
+
+        let out = tempfile::tempdir().unwrap();
+        let (_, extracted) =
+            extract_entries(bytes.as_slice(), &["positions".to_string()], out.path()).unwrap();
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(std::fs::read(&extracted[0].1).unwrap(), b"positions data");
+        assert!(!out.path().join("tokens.db.restore").exists());
+    }
+
+    #[test]
+    fn checksum_mismatch_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let (mut manifest, _) = archive_with(dir.path(), &[("events.db", b"events")]);
+        manifest.entries[0].sha256 = "0".repeat(64);
+        let bytes = write_archive(Vec::new(), &manifest, &[dir.path().join("events.db")]).unwrap();
+
+        let err = verify_entries(bytes.as_slice()).unwrap_err();
+        assert!(err.contains("Checksum mismatch"));
+    }
+
+    #[test]
+    fn rejects_foreign_data() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"NOTABACKUP").unwrap();
+        let bytes = encoder.finish().unwrap();
+        assert!(read_manifest(bytes.as_slice()).is_err());
+    }
+}
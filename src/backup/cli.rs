@@ -0,0 +1,57 @@
+//! `--backup` and `--restore` command line modes
+
+use super::create::{backup_path, create_backup};
+use super::restore::restore_backup;
+use super::types::BackupTrigger;
+use crate::paths;
+use crate::process_lock::ProcessLock;
+use std::path::PathBuf;
+
+/// Back up everything into the backups directory
+///
+/// Safe while the bot is running: databases are copied with the online backup API.
+pub fn run_backup_cli() -> Result<(), String> {
+    paths::ensure_all_directories()?;
+    let info = create_backup(BackupTrigger::Cli, &[], &[])?;
+
+    println!("Backup created:");
+    println!(
+        "  File:       {}",
+        paths::get_backups_directory()
+            .join(&info.file_name)
+            .display()
+    );
+    println!("  Components: {}", info.components.join(", "));
+    println!(
+        "  Size:       {} bytes ({} bytes uncompressed)",
+        info.size_bytes, info.uncompressed_bytes
+    );
+    println!("  Wallet keys are not included in backups.");
+    Ok(())
+}
+
+/// Restore an archive given as a path or as a name in the backups directory
+///
+/// Holds the process lock for the duration, so it refuses to run next to a live bot.
+pub fn run_restore_cli(archive: &str, components: &[String]) -> Result<(), String> {
+    paths::ensure_all_directories()?;
+    let _lock = ProcessLock::acquire()
+        .map_err(|e| format!("Stop the bot before restoring a backup: {}", e))?;
+
+    let given = PathBuf::from(archive);
+    let path = if given.exists() {
+        given
+    } else {
+        backup_path(archive)?
+    };
+
+    let report = restore_backup(&path, components)?;
+
+    println!("Restore complete:");
+    println!("  Archive:  {}", path.display());
+    println!("  Restored: {}", report.restored.join(", "));
+    if let Some(safety) = &report.safety_backup {
+        println!("  Previous files saved to backup {}", safety);
+    }
+    Ok(())
+}
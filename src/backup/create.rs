@@ -0,0 +1,419 @@
+//! Backup creation, listing and retention
+
+use super::archive::{hash_file, read_manifest, write_archive};
+use super::types::{
+    BackupEntry, BackupInfo, BackupManifest, BackupTrigger, EntryKind, BACKUP_EXTENSION,
+    BACKUP_FORMAT, BACKUP_FORMAT_VERSION,
+};
+use crate::logger::{self, LogTag};
+use crate::paths;
+use chrono::Utc;
+use rusqlite::{Connection, OpenFlags, MAIN_DB};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+/// Config keys holding wallet key material, never written to an archive
+pub const WALLET_KEY_FIELDS: [&str; 2] = ["wallet_encrypted", "wallet_nonce"];
+
+/// Left out of every archive: encrypted wallet private keys
+const EXCLUDED_DATA: [&str; 2] = [
+    "wallets.db (encrypted wallet private keys)",
+    "config.toml wallet_encrypted / wallet_nonce",
+];
+
+/// A file that can be backed up and restored
+#[derive(Debug, Clone, Copy)]
+pub struct BackupComponent {
+    pub name: &'static str,
+    pub kind: EntryKind,
+    path: fn() -> PathBuf,
+}
+
+impl BackupComponent {
+    pub fn path(&self) -> PathBuf {
+        (self.path)()
+    }
+
+    pub fn file_name(&self) -> String {
+        self.path()
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// All components in restore order
+pub const BACKUP_COMPONENTS: [BackupComponent; 15] = [
+    db("tokens", paths::get_tokens_db_path),
+    db("transactions", paths::get_transactions_db_path),
+    db("positions", paths::get_positions_db_path),
+    db("paper_positions", paths::get_paper_positions_db_path),
+    db("wallet", paths::get_wallet_db_path),
+    db("events", paths::get_events_db_path),
+    db("pools", paths::get_pools_db_path),
+    db("strategies", paths::get_strategies_db_path),
+    db("ohlcvs", paths::get_ohlcvs_db_path),
+    db("actions", paths::get_actions_db_path),
+    db("orders", paths::get_orders_db_path),
+    db("tools", paths::get_tools_db_path),
+    db("ai", paths::get_ai_db_path),
+    db("ai_chat", paths::get_ai_chat_db_path),
+    BackupComponent {
+        name: "config",
+        kind: EntryKind::Config,
+        path: paths::get_config_path,
+    },
+];
+
+const fn db(name: &'static str, path: fn() -> PathBuf) -> BackupComponent {
+    BackupComponent {
+        name,
+        kind: EntryKind::Database,
+        path,
+    }
+}
+
+/// Look up a component by name
+pub fn find_component(name: &str) -> Option<&'static BackupComponent> {
+    BACKUP_COMPONENTS.iter().find(|c| c.name == name)
+}
+
+/// Validate component names; an empty selection means all components
+pub fn resolve_components(names: &[String]) -> Result<Vec<&'static BackupComponent>, String> {
+    if names.is_empty() {
+        return Ok(BACKUP_COMPONENTS.iter().collect());
+    }
+    let mut selected = Vec::new();
+    for name in names {
+        let component = find_component(name.trim()).ok_or_else(|| {
+            format!(
+                "Unknown backup component '{}' (available: {})",
+                name,
+                BACKUP_COMPONENTS
+                    .iter()
+                    .map(|c| c.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+        if !selected
+            .iter()
+            .any(|c: &&BackupComponent| c.name == component.name)
+        {
+            selected.push(component);
+        }
+    }
+    // Keep the canonical order regardless of how the selection was written
+    selected.sort_by_key(|c| BACKUP_COMPONENTS.iter().position(|o| o.name == c.name));
+    Ok(selected)
+}
+
+/// Create a backup archive of the selected components (all when empty)
+///
+/// Databases are copied with the SQLite online backup API, so the bot keeps
+/// running. Components whose file does not exist yet are skipped.
+pub fn create_backup(
+    trigger: BackupTrigger,
+    components: &[String],
+    excluded_components: &[String],
+) -> Result<BackupInfo, String> {
+    let selected: Vec<&BackupComponent> = resolve_components(components)?
+        .into_iter()
+        .filter(|c| !excluded_components.iter().any(|e| e == c.name))
+        .collect();
+
+    let backups_dir = paths::get_backups_directory();
+    fs::create_dir_all(&backups_dir)
+        .map_err(|e| format!("Failed to create {}: {}", backups_dir.display(), e))?;
+
+    let created_at = Utc::now();
+    let file_name = format!(
+        "screenerbot-{}-{}.{}",
+        created_at.format("%Y%m%d-%H%M%S"),
+        trigger.as_str(),
+        BACKUP_EXTENSION
+    );
+    let staging = backups_dir.join(format!(".staging-{}", uuid::Uuid::new_v4().simple()));
+    fs::create_dir_all(&staging)
+        .map_err(|e| format!("Failed to create {}: {}", staging.display(), e))?;
+
+    let result = build_archive(&backups_dir, &staging, &file_name, trigger, &selected);
+    let _ = fs::remove_dir_all(&staging);
+    let info = result?;
+
+    logger::info(
+        LogTag::System,
+        &format!(
+            "Backup created: {} ({} components, {} bytes)",
+            info.file_name,
+            info.components.len(),
+            info.size_bytes
+        ),
+    );
+    Ok(info)
+}
+
+fn build_archive(
+    backups_dir: &Path,
+    staging: &Path,
+    file_name: &str,
+    trigger: BackupTrigger,
+    selected: &[&BackupComponent],
+) -> Result<BackupInfo, String> {
+    let mut entries = Vec::new();
+    let mut sources = Vec::new();
+
+    for component in selected {
+        let source = component.path();
+        if !source.exists() {
+            logger::debug(
+                LogTag::System,
+                &format!("Backup: skipping {} (no file yet)", component.name),
+            );
+            continue;
+        }
+
+        let snapshot = staging.join(component.file_name());
+        match component.kind {
+            EntryKind::Database => snapshot_database(&source, &snapshot)?,
+            EntryKind::Config => write_sanitized_config(&source, &snapshot)?,
+        }
+
+        let (sha256, size_bytes) = hash_file(&snapshot)?;
+        entries.push(BackupEntry {
+            component: component.name.to_string(),
+            file_name: component.file_name(),
+            kind: component.kind,
+            size_bytes,
+            sha256,
+        });
+        sources.push(snapshot);
+    }
+
+    if entries.is_empty() {
+        return Err("Nothing to back up: none of the selected files exist".to_string());
+    }
+
+    let manifest = BackupManifest {
+        format: BACKUP_FORMAT.to_string(),
+        format_version: BACKUP_FORMAT_VERSION,
+        created_at: Utc::now(),
+        app_version: crate::version::VERSION.to_string(),
+        trigger,
+        entries,
+        excluded: EXCLUDED_DATA.iter().map(|s| s.to_string()).collect(),
+    };
+
+    // Write next to the final name and rename, so listings never see partial archives
+    let partial = backups_dir.join(format!(".{}.partial", file_name));
+    let dest = backups_dir.join(file_name);
+    let file = File::create(&partial)
+        .map_err(|e| format!("Failed to create {}: {}", partial.display(), e))?;
+    let written = write_archive(BufWriter::new(file), &manifest, &sources).and_then(|writer| {
+        writer
+            .into_inner()
+            .map_err(|e| format!("Failed to flush backup archive: {}", e))?
+            .sync_all()
+            .map_err(|e| format!("Failed to sync backup archive: {}", e))
+    });
+    if let Err(e) = written {
+        let _ = fs::remove_file(&partial);
+        return Err(e);
+    }
+    fs::rename(&partial, &dest)
+        .map_err(|e| format!("Failed to finalize {}: {}", dest.display(), e))?;
+
+    let size_bytes = fs::metadata(&dest).map(|m| m.len()).unwrap_or(0);
+    Ok(BackupInfo::from_manifest(
+        file_name.to_string(),
+        size_bytes,
+        &manifest,
+    ))
+}
+
+/// Consistent copy of a live database via the SQLite online backup API
+pub(super) fn snapshot_database(source: &Path, dest: &Path) -> Result<(), String> {
+    let conn = Connection::open_with_flags(
+        source,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|e| format!("Failed to open {}: {}", source.display(), e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(10))
+        .map_err(|e| format!("Failed to configure {}: {}", source.display(), e))?;
+
+    conn.backup(MAIN_DB, dest, None)
+        .map_err(|e| format!("Failed to back up {}: {}", source.display(), e))
+}
+
+/// Copy config.toml without wallet key material
+fn write_sanitized_config(source: &Path, dest: &Path) -> Result<(), String> {
+    let content = fs::read_to_string(source)
+        .map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
+    let mut table: toml::Table = content
+        .parse()
+        .map_err(|e| format!("Failed to parse {}: {}", source.display(), e))?;
+    for field in WALLET_KEY_FIELDS {
+        table.remove(field);
+    }
+    let sanitized =
+        toml::to_string_pretty(&table).map_err(|e| format!("Failed to serialize config: {}", e))?;
+    fs::write(dest, sanitized).map_err(|e| format!("Failed to write {}: {}", dest.display(), e))
+}
+
+/// Resolve an archive name to its path, rejecting anything outside the backups directory
+pub fn backup_path(file_name: &str) -> Result<PathBuf, String> {
+    let valid = !file_name.is_empty()
+        && !file_name.starts_with('.')
+        && !file_name.contains(['/', '\\'])
+        && file_name.ends_with(&format!(".{}", BACKUP_EXTENSION));
+    if !valid {
+        return Err(format!("Invalid backup name '{}'", file_name));
+    }
+    Ok(paths::get_backups_directory().join(file_name))
+}
+
+/// Read the manifest of an archive in the backups directory
+pub fn read_backup_manifest(file_name: &str) -> Result<BackupManifest, String> {
+    let path = backup_path(file_name)?;
+    let file = File::open(&path).map_err(|e| format!("Failed to open {}: {}", file_name, e))?;
+    read_manifest(BufReader::new(file)).map(|(manifest, _)| manifest)
+}
+
+/// All archives in the backups directory, newest first
+///
+/// Unreadable archives are logged and left out.
+pub fn list_backups() -> Result<Vec<BackupInfo>, String> {
+    let dir = paths::get_backups_directory();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    let read_dir =
+        fs::read_dir(&dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    for entry in read_dir.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if backup_path(&file_name).is_err() {
+            continue;
+        }
+        let size_bytes = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        match read_backup_manifest(&file_name) {
+            Ok(manifest) => {
+                backups.push(BackupInfo::from_manifest(file_name, size_bytes, &manifest))
+            }
+            Err(e) => logger::warning(
+                LogTag::System,
+                &format!("Skipping unreadable backup {}: {}", file_name, e),
+            ),
+        }
+    }
+
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(backups)
+}
+
+/// Delete an archive from the backups directory
+pub fn delete_backup(file_name: &str) -> Result<(), String> {
+    let path = backup_path(file_name)?;
+    fs::remove_file(&path).map_err(|e| format!("Failed to delete {}: {}", file_name, e))
+}
+
+/// Apply retention: keep the newest `keep_last` archives and drop those older than `max_age_days`
+///
+/// Pre-restore safety copies are never pruned automatically. `max_age_days == 0`
+/// disables the age limit. Returns the deleted file names.
+pub fn prune_backups(keep_last: usize, max_age_days: u64) -> Result<Vec<String>, String> {
+    let candidates: Vec<BackupInfo> = list_backups()?
+        .into_iter()
+        .filter(|b| b.trigger != BackupTrigger::PreRestore)
+        .collect();
+
+    let now = Utc::now();
+    let mut deleted = Vec::new();
+    for name in select_expired(&candidates, keep_last, max_age_days, now) {
+        match delete_backup(&name) {
+            Ok(()) => deleted.push(name),
+            Err(e) => logger::warning(LogTag::System, &e),
+        }
+    }
+
+    if !deleted.is_empty() {
+        logger::info(
+            LogTag::System,
+            &format!("Backup retention removed {} archive(s)", deleted.len()),
+        );
+    }
+    Ok(deleted)
+}
+
+/// Archives (sorted newest first) falling outside the retention policy
+fn select_expired(
+    backups: &[BackupInfo],
+    keep_last: usize,
+    max_age_days: u64,
+    now: chrono::DateTime<Utc>,
+) -> Vec<String> {
+    backups
+        .iter()
+        .enumerate()
+        .filter(|(index, backup)| {
+            let too_many = *index >= keep_last.max(1);
+            let too_old = max_age_days > 0
+                && now.signed_duration_since(backup.created_at).num_days() >= max_age_days as i64;
+            too_many || too_old
+        })
+        .map(|(_, backup)| backup.file_name.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn info(name: &str, age_days: i64, now: chrono::DateTime<Utc>) -> BackupInfo {
+        BackupInfo {
+            file_name: name.to_string(),
+            size_bytes: 0,
+            created_at: now - Duration::days(age_days),
+            trigger: BackupTrigger::Scheduled,
+            components: vec![],
+            uncompressed_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn retention_keeps_newest_within_age() {
+        let now = Utc::now();
+        let backups = vec![info("a", 0, now), info("b", 2, now), info("c", 5, now)];
+
+        assert_eq!(select_expired(&backups, 2, 0, now), vec!["c"]);
+        assert_eq!(select_expired(&backups, 10, 3, now), vec!["c"]);
+        assert_eq!(select_expired(&backups, 10, 1, now), vec!["b", "c"]);
+        // The newest archive always survives the count limit
+        assert_eq!(select_expired(&backups, 0, 0, now), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn component_selection_is_validated_and_ordered() {
+        let selected =
+            resolve_components(&["strategies".to_string(), "positions".to_string()]).unwrap();
+        let names: Vec<_> = selected.iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["positions", "strategies"]);
+
+        assert_eq!(
+            resolve_components(&[]).unwrap().len(),
+            BACKUP_COMPONENTS.len()
+        );
+        assert!(resolve_components(&["wallets".to_string()]).is_err());
+    }
+
+    #[test]
+    fn backup_names_cannot_escape_directory() {
+        assert!(backup_path("screenerbot-20260101-000000-manual.sbbackup").is_ok());
+        assert!(backup_path("../config.toml").is_err());
+        assert!(backup_path("sub/x.sbbackup").is_err());
+        assert!(backup_path(".x.sbbackup.partial").is_err());
+    }
+}
@@ -0,0 +1,62 @@
+//! Unified backup and restore of bot state
+//!
+//! Bundles every SQLite database from `paths.rs` plus `config.toml` into one
+//! compressed archive:
+//! - Online database snapshots via the SQLite backup API (no stop required)
+//! - Manifest with per-entry SHA-256 checksums, verified before any restore
+//! - Scheduled backups with count and age based retention
+//! - Selective restore of individual components (e.g. positions + strategies)
+//!
+//! Wallet key material is never archived: `wallets.db` is left out and the
+//! encrypted key fields are stripped from `config.toml`. A restored config
+//! keeps the key of the machine it is restored on.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use screenerbot::backup::{run_backup, stage_restore, BackupTrigger};
+//!
+//! // Back up everything while the bot keeps running
+//! let info = run_backup(BackupTrigger::Manual, vec![]).await?;
+//!
+//! // Restore only positions and strategies on the next restart
+//! stage_restore(&info.file_name, &["positions".into(), "strategies".into()])?;
+//! ```
+//!
+//! From the command line (bot stopped for restores):
+//!
+//! ```text
+//! screenerbot --backup
+//! screenerbot --restore <archive> --restore-only positions,strategies
+//! ```
+
+mod archive;
+mod cli;
+mod create;
+mod restore;
+mod schedule;
+mod types;
+
+// Re-export types
+pub use types::{
+    BackupEntry, BackupInfo, BackupManifest, BackupTrigger, EntryKind, PendingRestore,
+    RestoreReport, BACKUP_EXTENSION, BACKUP_FORMAT, BACKUP_FORMAT_VERSION,
+};
+
+// Re-export backup creation and management
+pub use create::{
+    backup_path, create_backup, delete_backup, find_component, list_backups, prune_backups,
+    read_backup_manifest, resolve_components, BackupComponent, BACKUP_COMPONENTS,
+};
+
+// Re-export restore
+pub use restore::{
+    apply_pending_restore, cancel_pending_restore, get_pending_restore, restore_backup,
+    stage_restore,
+};
+
+// Re-export command line modes
+pub use cli::{run_backup_cli, run_restore_cli};
+
+// Re-export runtime backups and scheduling
+pub use schedule::{get_backup_status, run_backup, start_backup_scheduler, BackupStatus};
@@ -0,0 +1,267 @@
+//! Selective restore
+//!
+//! Databases cannot be replaced under open connection pools, so restores only
+//! run while the bot is stopped: directly from the `--restore` CLI flag, or
+//! staged through the API and applied on the next startup before any service
+//! opens a database.
+
+use super::archive::{extract_entries, read_manifest, verify_entries};
+use super::create::{backup_path, create_backup, find_component, WALLET_KEY_FIELDS};
+use super::types::{BackupManifest, BackupTrigger, EntryKind, PendingRestore, RestoreReport};
+use crate::logger::{self, LogTag};
+use crate::paths;
+use chrono::Utc;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::Path;
+
+/// Pick the components to restore; an empty request means everything in the archive
+fn select_components(
+    manifest: &BackupManifest,
+    requested: &[String],
+) -> Result<Vec<String>, String> {
+    let available = manifest.components();
+    let selected: Vec<String> = if requested.is_empty() {
+        available.clone()
+    } else {
+        requested.iter().map(|c| c.trim().to_string()).collect()
+    };
+
+    for component in &selected {
+        if !available.contains(component) {
+            return Err(format!(
+                "Backup does not contain '{}' (available: {})",
+                component,
+                available.join(", ")
+            ));
+        }
+        if find_component(component).is_none() {
+            return Err(format!("Unknown backup component '{}'", component));
+        }
+    }
+    Ok(selected)
+}
+
+/// Restore components from an archive; the bot must not be running
+///
+/// The whole archive is verified before anything is replaced, and the current
+/// files of the selected components are saved to a pre-restore backup first.
+pub fn restore_backup(archive: &Path, components: &[String]) -> Result<RestoreReport, String> {
+    let open = || {
+        File::open(archive)
+            .map(BufReader::new)
+            .map_err(|e| format!("Failed to open {}: {}", archive.display(), e))
+    };
+    let (manifest, _) = read_manifest(open()?)?;
+    let selected = select_components(&manifest, components)?;
+
+    let has_current_files = selected
+        .iter()
+        .filter_map(|c| find_component(c))
+        .any(|c| c.path().exists());
+    let safety_backup = if has_current_files {
+        Some(create_backup(BackupTrigger::PreRestore, &selected, &[])?.file_name)
+    } else {
+        None
+    };
+
+    let data_dir = paths::get_data_directory();
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create {}: {}", data_dir.display(), e))?;
+    let (_, extracted) = extract_entries(open()?, &selected, &data_dir)?;
+
+    let mut restored = Vec::new();
+    for (entry, staged) in extracted {
+        let Some(component) = find_component(&entry.component) else {
+            let _ = fs::remove_file(&staged);
+            continue;
+        };
+        let dest = component.path();
+
+        let result = match entry.kind {
+            EntryKind::Database => replace_database(&staged, &dest),
+            EntryKind::Config => {
+                keep_wallet_keys(&staged, &dest).and_then(|_| replace_file(&staged, &dest))
+            }
+        };
+        if let Err(e) = result {
+            let _ = fs::remove_file(&staged);
+            return Err(format!(
+                "Restore stopped at '{}' after restoring [{}]: {}",
+                entry.component,
+                restored.join(", "),
+                e
+            ));
+        }
+        restored.push(entry.component);
+    }
+
+    let file_name = archive
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    logger::info(
+        LogTag::System,
+        &format!(
+            "Restored [{}] from {}{}",
+            restored.join(", "),
+            file_name,
+            safety_backup
+                .as_ref()
+                .map(|name| format!(" (previous files saved to {})", name))
+                .unwrap_or_default()
+        ),
+    );
+
+    Ok(RestoreReport {
+        file_name,
+        restored,
+        safety_backup,
+    })
+}
+
+/// Swap a database file, dropping the old WAL and SHM files
+fn replace_database(staged: &Path, dest: &Path) -> Result<(), String> {
+    for file in paths::get_db_with_wal_files(dest.to_path_buf()) {
+        if file.exists() {
+            fs::remove_file(&file)
+                .map_err(|e| format!("Failed to remove {}: {}", file.display(), e))?;
+        }
+    }
+    replace_file(staged, dest)
+}
+
+fn replace_file(staged: &Path, dest: &Path) -> Result<(), String> {
+    fs::rename(staged, dest)
+        .map_err(|e| format!("Failed to move {} into place: {}", dest.display(), e))
+}
+
+/// Carry the wallet key of the current config.toml over into the restored one
+///
+/// Archives never contain key material; without this a restore would log the
+/// bot out of its wallet.
+fn keep_wallet_keys(staged: &Path, current: &Path) -> Result<(), String> {
+    if !current.exists() {
+        return Ok(());
+    }
+    let read_table = |path: &Path| -> Result<toml::Table, String> {
+        fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+            .parse()
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    };
+
+    let current_table = read_table(current)?;
+    let mut restored = read_table(staged)?;
+    for field in WALLET_KEY_FIELDS {
+        if let Some(value) = current_table.get(field) {
+            restored.insert(field.to_string(), value.clone());
+        }
+    }
+
+    let content = toml::to_string_pretty(&restored)
+        .map_err(|e| format!("Failed to serialize restored config: {}", e))?;
+    fs::write(staged, content).map_err(|e| format!("Failed to write {}: {}", staged.display(), e))
+}
+
+// =============================================================================
+// STAGED RESTORE
+// =============================================================================
+
+/// Verify an archive and stage its restore for the next startup
+pub fn stage_restore(file_name: &str, components: &[String]) -> Result<PendingRestore, String> {
+    let path = backup_path(file_name)?;
+    let file = File::open(&path).map_err(|e| format!("Failed to open {}: {}", file_name, e))?;
+    let manifest = verify_entries(BufReader::new(file))?;
+
+    let pending = PendingRestore {
+        file_name: file_name.to_string(),
+        components: select_components(&manifest, components)?,
+        requested_at: Utc::now(),
+    };
+
+    let json = serde_json::to_string_pretty(&pending)
+        .map_err(|e| format!("Failed to serialize pending restore: {}", e))?;
+    let pending_path = paths::get_pending_restore_path();
+    fs::write(&pending_path, json)
+        .map_err(|e| format!("Failed to write {}: {}", pending_path.display(), e))?;
+
+    logger::info(
+        LogTag::System,
+        &format!(
+            "Restore of [{}] from {} staged - applied on next restart",
+            pending.components.join(", "),
+            file_name
+        ),
+    );
+    Ok(pending)
+}
+
+/// Restore staged for the next startup, if any
+pub fn get_pending_restore() -> Option<PendingRestore> {
+    let content = fs::read_to_string(paths::get_pending_restore_path()).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Cancel a staged restore; returns whether one was pending
+pub fn cancel_pending_restore() -> Result<bool, String> {
+    let path = paths::get_pending_restore_path();
+    if !path.exists() {
+        return Ok(false);
+    }
+    fs::remove_file(&path).map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+    Ok(true)
+}
+
+/// Apply a staged restore; called at startup before any database is opened
+///
+/// The staged request is consumed even when the restore fails, so a broken
+/// archive cannot block every following startup.
+pub fn apply_pending_restore() -> Result<Option<RestoreReport>, String> {
+    let Some(pending) = get_pending_restore() else {
+        return Ok(None);
+    };
+    cancel_pending_restore()?;
+
+    let path = backup_path(&pending.file_name)?;
+    restore_backup(&path, &pending.components).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::{BackupEntry, BACKUP_FORMAT, BACKUP_FORMAT_VERSION};
+    use super::*;
+
+    fn manifest(components: &[&str]) -> BackupManifest {
+        BackupManifest {
+            format: BACKUP_FORMAT.to_string(),
+            format_version: BACKUP_FORMAT_VERSION,
+            created_at: Utc::now(),
+            app_version: "test".to_string(),
+            trigger: BackupTrigger::Manual,
+            entries: components
+                .iter()
+                .map(|c| BackupEntry {
+                    component: c.to_string(),
+                    file_name: format!("{}.db", c),
+                    kind: EntryKind::Database,
+                    size_bytes: 0,
+                    sha256: String::new(),
+                })
+                .collect(),
+            excluded: vec![],
+        }
+    }
+
+    #[test]
+    fn selects_requested_components_from_archive() {
+        let manifest = manifest(&["positions", "strategies", "tokens"]);
+
+        assert_eq!(select_components(&manifest, &[]).unwrap().len(), 3);
+        assert_eq!(
+            select_components(&manifest, &["strategies".to_string()]).unwrap(),
+            vec!["strategies"]
+        );
+        assert!(select_components(&manifest, &["orders".to_string()]).is_err());
+    }
+}
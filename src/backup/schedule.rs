@@ -0,0 +1,177 @@
+//! Backup execution and scheduling
+//!
+//! All runtime backups (API and schedule) go through `run_backup`, which
+//! serializes them and moves the blocking SQLite and compression work off the
+//! async runtime.
+
+use super::create::{create_backup, list_backups, prune_backups};
+use super::types::{BackupInfo, BackupTrigger};
+use crate::config::with_config;
+use crate::events::{record_system_event, Severity};
+use crate::logger::{self, LogTag};
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, Notify, RwLock};
+
+/// How often the scheduler checks whether a backup is due
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Delay before the first scheduled check (let startup settle)
+const SCHEDULE_INITIAL_DELAY: Duration = Duration::from_secs(120);
+
+/// Serializes backup runs
+static BACKUP_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+static BACKUP_STATUS: Lazy<RwLock<BackupStatus>> =
+    Lazy::new(|| RwLock::new(BackupStatus::default()));
+
+/// Runtime state of backups
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackupStatus {
+    pub in_progress: bool,
+    pub last_backup_at: Option<DateTime<Utc>>,
+    pub last_backup_file: Option<String>,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<DateTime<Utc>>,
+}
+
+pub async fn get_backup_status() -> BackupStatus {
+    BACKUP_STATUS.read().await.clone()
+}
+
+/// Create a backup, one at a time, without blocking the async runtime
+///
+/// Scheduled backups honour `backup.exclude_components`; manual ones back up
+/// exactly the requested components (all when empty).
+pub async fn run_backup(
+    trigger: BackupTrigger,
+    components: Vec<String>,
+) -> Result<BackupInfo, String> {
+    let _guard = BACKUP_LOCK.lock().await;
+    BACKUP_STATUS.write().await.in_progress = true;
+
+    let excluded = match trigger {
+        BackupTrigger::Scheduled => with_config(|cfg| cfg.backup.exclude_components.clone()),
+        _ => Vec::new(),
+    };
+    let result =
+        tokio::task::spawn_blocking(move || create_backup(trigger, &components, &excluded))
+            .await
+            .map_err(|e| format!("Backup task failed: {}", e))
+            .and_then(|result| result);
+
+    {
+        let mut status = BACKUP_STATUS.write().await;
+        status.in_progress = false;
+        match &result {
+            Ok(info) => {
+                status.last_backup_at = Some(info.created_at);
+                status.last_backup_file = Some(info.file_name.clone());
+            }
+            Err(e) => {
+                status.last_error = Some(e.clone());
+                status.last_error_at = Some(Utc::now());
+            }
+        }
+    }
+
+    match &result {
+        Ok(info) => {
+            record_system_event(
+                "backup",
+                "created",
+                Severity::Info,
+                Some(json!({
+                    "file_name": info.file_name,
+                    "trigger": trigger.as_str(),
+                    "components": info.components,
+                    "size_bytes": info.size_bytes,
+                })),
+            )
+            .await;
+        }
+        Err(e) => {
+            logger::error(LogTag::System, &format!("Backup failed: {}", e));
+            record_system_event(
+                "backup",
+                "failed",
+                Severity::Error,
+                Some(json!({ "trigger": trigger.as_str(), "error": e })),
+            )
+            .await;
+        }
+    }
+
+    result
+}
+
+/// Scheduled backup loop with retention
+///
+/// The schedule is measured from the newest scheduled archive on disk, so
+/// restarts do not cause extra backups.
+pub async fn start_backup_scheduler(shutdown: Arc<Notify>) {
+    tokio::select! {
+        _ = shutdown.notified() => return,
+        _ = tokio::time::sleep(SCHEDULE_INITIAL_DELAY) => {}
+    }
+
+    let mut last_scheduled = tokio::task::spawn_blocking(list_backups)
+        .await
+        .ok()
+        .and_then(|result| result.ok())
+        .and_then(|backups| {
+            backups
+                .into_iter()
+                .find(|b| b.trigger == BackupTrigger::Scheduled)
+                .map(|b| b.created_at)
+        });
+
+    loop {
+        let (enabled, interval_hours, keep_last, max_age_days) = with_config(|cfg| {
+            (
+                cfg.backup.enabled,
+                cfg.backup.interval_hours,
+                cfg.backup.keep_last,
+                cfg.backup.max_age_days,
+            )
+        });
+
+        let due = last_scheduled.map_or(true, |at| {
+            Utc::now().signed_duration_since(at) >= chrono::Duration::hours(interval_hours as i64)
+        });
+
+        if enabled && due {
+            // A failed run also waits a full interval instead of retrying every minute
+            last_scheduled = Some(Utc::now());
+            if run_backup(BackupTrigger::Scheduled, Vec::new())
+                .await
+                .is_ok()
+            {
+                match tokio::task::spawn_blocking(move || prune_backups(keep_last, max_age_days))
+                    .await
+                {
+                    Ok(Err(e)) => {
+                        logger::warning(LogTag::System, &format!("Backup retention failed: {}", e))
+                    }
+                    Err(e) => logger::warning(
+                        LogTag::System,
+                        &format!("Backup retention task failed: {}", e),
+                    ),
+                    Ok(Ok(_)) => {}
+                }
+            }
+        }
+
+        tokio::select! {
+            _ = shutdown.notified() => {
+                logger::debug(LogTag::System, "Backup scheduler stopped");
+                break;
+            }
+            _ = tokio::time::sleep(SCHEDULE_CHECK_INTERVAL) => {}
+        }
+    }
+}
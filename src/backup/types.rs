@@ -0,0 +1,142 @@
+//! Backup archive types
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Identifies an archive manifest as a ScreenerBot backup
+pub const BACKUP_FORMAT: &str = "screenerbot-backup";
+
+/// Current archive layout version (bump when the layout changes)
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// File extension of backup archives
+pub const BACKUP_EXTENSION: &str = "sbbackup";
+
+/// What an archive entry contains
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    /// SQLite database snapshot
+    Database,
+    /// config.toml with wallet key material removed
+    Config,
+}
+
+/// Why a backup was taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupTrigger {
+    Manual,
+    Scheduled,
+    Cli,
+    /// Safety copy of the components replaced by a restore
+    PreRestore,
+}
+
+impl BackupTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackupTrigger::Manual => "manual",
+            BackupTrigger::Scheduled => "scheduled",
+            BackupTrigger::Cli => "cli",
+            BackupTrigger::PreRestore => "pre_restore",
+        }
+    }
+}
+
+/// One file stored in an archive
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    /// Component name (e.g. "positions", "config")
+    pub component: String,
+    /// File name inside the data directory
+    pub file_name: String,
+    pub kind: EntryKind,
+    /// Uncompressed size
+    pub size_bytes: u64,
+    /// Hex SHA-256 of the uncompressed contents
+    pub sha256: String,
+}
+
+/// Archive manifest, stored ahead of the entry data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format: String,
+    pub format_version: u32,
+    pub created_at: DateTime<Utc>,
+    pub app_version: String,
+    pub trigger: BackupTrigger,
+    /// Entries in the order their data follows the manifest
+    pub entries: Vec<BackupEntry>,
+    /// Data deliberately left out (e.g. wallet key material)
+    #[serde(default)]
+    pub excluded: Vec<String>,
+}
+
+impl BackupManifest {
+    /// Check format and layout version
+    pub fn verify(&self) -> Result<(), String> {
+        if self.format != BACKUP_FORMAT {
+            return Err(format!("Not a backup archive (format '{}')", self.format));
+        }
+        if self.format_version > BACKUP_FORMAT_VERSION {
+            return Err(format!(
+                "Backup format version {} is newer than supported version {}",
+                self.format_version, BACKUP_FORMAT_VERSION
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn components(&self) -> Vec<String> {
+        self.entries.iter().map(|e| e.component.clone()).collect()
+    }
+
+    pub fn entry(&self, component: &str) -> Option<&BackupEntry> {
+        self.entries.iter().find(|e| e.component == component)
+    }
+}
+
+/// Backup archive as listed in the backups directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupInfo {
+    pub file_name: String,
+    /// Compressed archive size
+    pub size_bytes: u64,
+    pub created_at: DateTime<Utc>,
+    pub trigger: BackupTrigger,
+    pub components: Vec<String>,
+    /// Total uncompressed size of all entries
+    pub uncompressed_bytes: u64,
+}
+
+impl BackupInfo {
+    pub fn from_manifest(file_name: String, size_bytes: u64, manifest: &BackupManifest) -> Self {
+        Self {
+            file_name,
+            size_bytes,
+            created_at: manifest.created_at,
+            trigger: manifest.trigger,
+            components: manifest.components(),
+            uncompressed_bytes: manifest.entries.iter().map(|e| e.size_bytes).sum(),
+        }
+    }
+}
+
+/// Restore staged through the API, applied on the next startup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingRestore {
+    /// Archive file name inside the backups directory
+    pub file_name: String,
+    pub components: Vec<String>,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// Result of an applied restore
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreReport {
+    pub file_name: String,
+    pub restored: Vec<String>,
+    /// Safety backup of the replaced components, if one was taken
+    pub safety_backup: Option<String>,
+}
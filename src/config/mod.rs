@@ -88,10 +88,10 @@ pub use metadata::{
 };
 
 pub use schemas::{
-    AiConfig, Config, DashboardConfig, EventsConfig, FilteringConfig, GuiConfig, InterfaceConfig,
-    LockscreenConfig, MonitoringConfig, OhlcvConfig, PositionsConfig, RpcConfig, ServicesConfig,
-    SolPriceConfig, StartupConfig, SwapsConfig, TelegramConfig, TimeUnit, TokensConfig,
-    TraderConfig, WebserverConfig,
+    AiConfig, BackupConfig, Config, CopyTradeConfig, DashboardConfig, EventsConfig,
+    FilteringConfig, GuiConfig, InterfaceConfig, LockscreenConfig, MonitoringConfig, OhlcvConfig,
+    PositionsConfig, RpcConfig, ServicesConfig, SolPriceConfig, StartupConfig, SwapsConfig,
+    TakeProfitTierConfig, TelegramConfig, TimeUnit, TokensConfig, TraderConfig, WebserverConfig,
 };
 
 pub use utils::{
@@ -0,0 +1,64 @@
+//! Scheduled backup configuration
+
+use crate::config_struct;
+use crate::field_metadata;
+
+// ============================================================================
+// BACKUP CONFIGURATION
+// ============================================================================
+
+config_struct! {
+    /// Configuration for scheduled backups of databases and config
+    pub struct BackupConfig {
+        /// Enable scheduled backups
+        #[metadata(field_metadata! {
+            label: "Scheduled Backups",
+            hint: "Periodically back up all databases and config.toml (wallet keys are never included)",
+            category: "General",
+        })]
+        enabled: bool = false,
+
+        /// Hours between scheduled backups
+        #[metadata(field_metadata! {
+            label: "Backup Interval",
+            hint: "Time between scheduled backups",
+            category: "General",
+            min: 1.0,
+            max: 720.0,
+            step: 1.0,
+            unit: "hours",
+        })]
+        interval_hours: u64 = 24,
+
+        /// Number of most recent archives to keep
+        #[metadata(field_metadata! {
+            label: "Keep Last",
+            hint: "Older archives beyond this count are deleted (pre-restore copies are kept)",
+            category: "Retention",
+            min: 1.0,
+            max: 365.0,
+            step: 1.0,
+        })]
+        keep_last: usize = 7,
+
+        /// Maximum archive age
+        #[metadata(field_metadata! {
+            label: "Max Age",
+            hint: "Delete archives older than this (0 = no age limit)",
+            category: "Retention",
+            min: 0.0,
+            max: 3650.0,
+            step: 1.0,
+            unit: "days",
+        })]
+        max_age_days: u64 = 30,
+
+        /// Components left out of scheduled backups
+        #[metadata(field_metadata! {
+            label: "Excluded Components",
+            hint: "Component names to skip in scheduled backups (e.g. ohlcvs for large candle history)",
+            category: "Contents",
+        })]
+        exclude_components: Vec<String> = Vec::new(),
+    }
+}
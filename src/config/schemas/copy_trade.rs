@@ -0,0 +1,149 @@
+//! Copy Trader tool configuration for mirroring followed wallets
+
+use crate::config_struct;
+use crate::field_metadata;
+
+// ============================================================================
+// COPY TRADE CONFIGURATION
+// ============================================================================
+
+config_struct! {
+    /// Configuration for the Copy Trader tool
+    pub struct CopyTradeConfig {
+        /// Enable copy trading of followed wallets
+        #[metadata(field_metadata! {
+            label: "Enable Copy Trading",
+            hint: "Subscribe to followed wallets and mirror their swaps",
+            category: "General",
+        })]
+        enabled: bool = false,
+
+        /// How the size of a mirrored buy is chosen
+        #[metadata(field_metadata! {
+            label: "Sizing Mode",
+            hint: "fixed = always buy Fixed Size, proportional = a percentage of the leader's SOL amount",
+            category: "Sizing",
+            placeholder: "fixed",
+        })]
+        sizing_mode: String = "fixed".to_string(),
+
+        /// SOL per mirrored buy in fixed mode
+        #[metadata(field_metadata! {
+            label: "Fixed Size",
+            hint: "SOL spent per mirrored buy in fixed mode",
+            category: "Sizing",
+            min: 0.001,
+            max: 100.0,
+            step: 0.001,
+            unit: "SOL",
+        })]
+        fixed_size_sol: f64 = 0.01,
+
+        /// Share of the leader's SOL amount in proportional mode
+        #[metadata(field_metadata! {
+            label: "Proportional Size",
+            hint: "Percentage of the leader's SOL amount to spend (e.g., 10.0 = 10%)",
+            category: "Sizing",
+            min: 0.1,
+            max: 1000.0,
+            step: 0.1,
+            unit: "%",
+        })]
+        proportional_percent: f64 = 10.0,
+
+        /// Lower bound for a mirrored buy
+        #[metadata(field_metadata! {
+            label: "Min Size",
+            hint: "Proportional buys are raised to at least this amount",
+            category: "Sizing",
+            min: 0.001,
+            max: 100.0,
+            step: 0.001,
+            unit: "SOL",
+        })]
+        min_size_sol: f64 = 0.005,
+
+        /// Upper bound for a mirrored buy
+        #[metadata(field_metadata! {
+            label: "Max Size",
+            hint: "Mirrored buys are capped at this amount",
+            category: "Sizing",
+            min: 0.001,
+            max: 100.0,
+            step: 0.001,
+            unit: "SOL",
+        })]
+        max_size_sol: f64 = 0.1,
+
+        /// Ignore leader trades smaller than this
+        #[metadata(field_metadata! {
+            label: "Min Leader Trade",
+            hint: "Skip leader swaps below this SOL amount (dust, tests)",
+            category: "Filters",
+            min: 0.0,
+            max: 100.0,
+            step: 0.01,
+            unit: "SOL",
+        })]
+        min_leader_trade_sol: f64 = 0.05,
+
+        /// Maximum price move since the leader's fill before a buy is skipped
+        #[metadata(field_metadata! {
+            label: "Max Slippage",
+            hint: "Skip a buy when our pool price is this much above the leader's fill price",
+            category: "Execution",
+            min: 0.1,
+            max: 100.0,
+            step: 0.5,
+            unit: "%",
+        })]
+        max_slippage_pct: f64 = 10.0,
+
+        /// Delay before mirroring a leader trade
+        #[metadata(field_metadata! {
+            label: "Copy Delay",
+            hint: "Wait this long after the leader's swap before mirroring it",
+            category: "Execution",
+            min: 0.0,
+            max: 300.0,
+            step: 1.0,
+            unit: "seconds",
+        })]
+        delay_secs: i32 = 0,
+
+        /// Mirror leader sells into our positions
+        #[metadata(field_metadata! {
+            label: "Mirror Sells",
+            hint: "Sell the same share of a copied position when the leader sells",
+            category: "Execution",
+        })]
+        mirror_sells: bool = true,
+
+        /// Only mirror buys of tokens that passed filtering
+        #[metadata(field_metadata! {
+            label: "Require Filter Pass",
+            hint: "Only copy buys of tokens that currently pass the token filters",
+            category: "Filters",
+        })]
+        require_filter_pass: bool = true,
+
+        /// Maximum number of active leaders
+        #[metadata(field_metadata! {
+            label: "Max Leaders",
+            hint: "Maximum number of wallets that can be followed at once",
+            category: "Limits",
+            min: 1.0,
+            max: 50.0,
+            step: 1.0,
+        })]
+        max_leaders: i32 = 10,
+
+        /// Send Telegram notifications for copied trades
+        #[metadata(field_metadata! {
+            label: "Notify Copied Trades",
+            hint: "Send a Telegram notification when a leader trade is copied or fails",
+            category: "Notifications",
+        })]
+        notify: bool = true,
+    }
+}
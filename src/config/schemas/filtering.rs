@@ -859,6 +859,160 @@ config_struct! {
     }
 }
 
+// ============================================================================
+// ON-CHAIN SECURITY FILTERING CONFIGURATION
+// ============================================================================
+
+config_struct! {
+    /// Native on-chain security filtering (mint account, Token-2022 extensions, LP, holders)
+    pub struct OnchainSecurityFilters {
+        // Enable/disable entire source
+        #[metadata(field_metadata! {
+            label: "Enable On-Chain Filters",
+            hint: "Master switch for native on-chain security filtering (also enables the on-chain analysis loop)",
+            impact: "critical",
+            category: "Source Control",
+        })]
+        enabled: bool = false,
+        #[metadata(field_metadata! {
+            label: "Rugcheck Fallback",
+            hint: "Accept tokens without Rugcheck data when on-chain analysis is available (keeps filtering working while Rugcheck is down)",
+            impact: "high",
+            category: "Source Control",
+        })]
+        rugcheck_fallback: bool = true,
+
+        // Authority checks
+        #[metadata(field_metadata! {
+            label: "Enable Authority Checks",
+            hint: "Check mint/freeze authorities read from the mint account",
+            impact: "critical",
+            category: "Authorities",
+        })]
+        authority_checks_enabled: bool = true,
+        #[metadata(field_metadata! {
+            label: "Allow Mint Authority",
+            hint: "Allow tokens with mint authority (false = reject if present)",
+            impact: "high",
+            category: "Authorities",
+        })]
+        allow_mint_authority: bool = false,
+        #[metadata(field_metadata! {
+            label: "Allow Freeze Authority",
+            hint: "Allow tokens with freeze authority (false = reject if present)",
+            impact: "high",
+            category: "Authorities",
+        })]
+        allow_freeze_authority: bool = false,
+
+        // Token-2022 extension checks
+        #[metadata(field_metadata! {
+            label: "Enable Token-2022 Checks",
+            hint: "Check dangerous Token-2022 extensions",
+            impact: "critical",
+            category: "Token-2022 Extensions",
+        })]
+        token2022_checks_enabled: bool = true,
+        #[metadata(field_metadata! {
+            label: "Block Transfer Hook",
+            hint: "Reject tokens whose transfers call an external program",
+            impact: "high",
+            category: "Token-2022 Extensions",
+        })]
+        block_transfer_hook: bool = true,
+        #[metadata(field_metadata! {
+            label: "Block Permanent Delegate",
+            hint: "Reject tokens where a delegate can move or burn any holder's balance",
+            impact: "critical",
+            category: "Token-2022 Extensions",
+        })]
+        block_permanent_delegate: bool = true,
+        #[metadata(field_metadata! {
+            label: "Block Non-Transferable",
+            hint: "Reject soulbound tokens that cannot be sold",
+            impact: "critical",
+            category: "Token-2022 Extensions",
+        })]
+        block_non_transferable: bool = true,
+        #[metadata(field_metadata! {
+            label: "Block Default Frozen",
+            hint: "Reject tokens whose new accounts start frozen",
+            impact: "high",
+            category: "Token-2022 Extensions",
+        })]
+        block_default_frozen: bool = true,
+        #[metadata(field_metadata! {
+            label: "Max Transfer Fee %",
+            hint: "Maximum acceptable transfer fee (current or scheduled)",
+            min: 0,
+            max: 100,
+            step: 0.5,
+            unit: "%",
+            impact: "critical",
+            category: "Token-2022 Extensions",
+        })]
+        max_transfer_fee_pct: f64 = 5.0,
+
+        // LP checks
+        #[metadata(field_metadata! {
+            label: "Enable LP Checks",
+            hint: "Check how much of the canonical pool's LP is burned or locked",
+            impact: "high",
+            category: "LP Burn/Lock",
+        })]
+        lp_checks_enabled: bool = true,
+        #[metadata(field_metadata! {
+            label: "Min LP Burned/Locked %",
+            hint: "Minimum share of LP that is burned or held by a locker program",
+            min: 0,
+            max: 100,
+            step: 5,
+            unit: "%",
+            impact: "high",
+            category: "LP Burn/Lock",
+        })]
+        min_lp_secured_pct: f64 = 50.0,
+        #[metadata(field_metadata! {
+            label: "Require LP Data",
+            hint: "Reject when LP status is unknown (pool types without LP tokens such as CLMM/DLMM are always unknown)",
+            impact: "medium",
+            category: "LP Burn/Lock",
+        })]
+        require_lp_data: bool = false,
+
+        // Holder concentration
+        #[metadata(field_metadata! {
+            label: "Enable Holder Checks",
+            hint: "Check holder concentration (pool vaults and other program accounts excluded)",
+            impact: "high",
+            category: "Holder Distribution",
+        })]
+        holder_checks_enabled: bool = true,
+        #[metadata(field_metadata! {
+            label: "Max Top Holder %",
+            hint: "Maximum share of supply held by the largest wallet",
+            min: 0,
+            max: 100,
+            step: 1,
+            unit: "%",
+            impact: "critical",
+            category: "Holder Distribution",
+        })]
+        max_top_holder_pct: f64 = 40.0,
+        #[metadata(field_metadata! {
+            label: "Max Top 10 Holders %",
+            hint: "Maximum combined share of supply held by the 10 largest wallets",
+            min: 0,
+            max: 100,
+            step: 1,
+            unit: "%",
+            impact: "high",
+            category: "Holder Distribution",
+        })]
+        max_top_10_holders_pct: f64 = 80.0,
+    }
+}
+
 // ============================================================================
 // MAIN FILTERING CONFIGURATION (Orchestrates All Sources)
 // ============================================================================
@@ -926,5 +1080,13 @@ config_struct! {
             category: "Data Sources",
         })]
         rugcheck: RugCheckFilters = RugCheckFilters::default(),
+
+        #[metadata(field_metadata! {
+            label: "On-Chain Security Filters",
+            hint: "Native security filtering from on-chain data (works without Rugcheck)",
+            impact: "high",
+            category: "Data Sources",
+        })]
+        onchain: OnchainSecurityFilters = OnchainSecurityFilters::default(),
     }
 }
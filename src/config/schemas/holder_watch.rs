@@ -69,6 +69,18 @@ config_struct! {
         })]
         holder_drop_percent: f64 = 10.0,
 
+        /// Share of supply a single top holder must gain between checks to count as accumulation
+        #[metadata(field_metadata! {
+            label: "Whale Accumulation Threshold",
+            hint: "Alert when a top holder gains this share of supply between checks (e.g., 1.0 = 1%)",
+            category: "Thresholds",
+            min: 0.1,
+            max: 50.0,
+            step: 0.1,
+            unit: "%",
+        })]
+        whale_accumulation_percent: f64 = 1.0,
+
         /// Maximum tokens to watch simultaneously
         #[metadata(field_metadata! {
             label: "Max Watched Tokens",
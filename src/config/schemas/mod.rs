@@ -3,7 +3,9 @@
 use crate::config_struct;
 
 mod ai;
+mod backup;
 mod connectivity;
+mod copy_trade;
 mod events;
 mod filtering;
 mod gui;
@@ -24,7 +26,9 @@ mod wallet;
 mod webserver;
 
 pub use ai::*;
+pub use backup::*;
 pub use connectivity::*;
+pub use copy_trade::*;
 pub use events::*;
 pub use filtering::*;
 pub use gui::*;
@@ -114,6 +118,12 @@ config_struct! {
         /// Holder watch tool configuration
         holder_watch: HolderWatchConfig = HolderWatchConfig::default(),
 
+        /// Copy trader tool configuration
+        copy_trade: CopyTradeConfig = CopyTradeConfig::default(),
+
+        /// Scheduled backup configuration
+        backup: BackupConfig = BackupConfig::default(),
+
         /// AI integration configuration for filtering and trading
         ai: AiConfig = AiConfig::default(),
     }
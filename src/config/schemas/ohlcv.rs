@@ -103,5 +103,26 @@ config_struct! {
             category: "Fallback",
         })]
         max_pool_failures: u32 = 5,
+
+        /// Build 1m candles locally from pool service price ticks
+        #[metadata(field_metadata! {
+            label: "Local Candles",
+            hint: "Build 1m candles from pool price ticks to fill gaps and cover tokens the API does not index",
+            impact: "medium",
+            category: "Local Candles",
+        })]
+        local_candles_enabled: bool = true,
+        /// Interval between local candle builds
+        #[metadata(field_metadata! {
+            label: "Build Interval",
+            hint: "Seconds between local candle builds (only closed minutes are written)",
+            min: 15,
+            max: 300,
+            step: 15,
+            unit: "seconds",
+            impact: "low",
+            category: "Local Candles",
+        })]
+        local_candles_interval_secs: u64 = 60,
     }
 }
@@ -99,5 +99,92 @@ config_struct! {
             category: "Fetcher",
         })]
         failure_window_secs: u64 = 300,
+        #[metadata(field_metadata! {
+            label: "Account Streaming",
+            hint: "Stream pool accounts of open positions over WebSocket (polling stays as fallback)",
+            impact: "high",
+            category: "Streaming",
+        })]
+        enable_account_streaming: bool = true,
+        #[metadata(field_metadata! {
+            label: "Stream Top Tokens",
+            hint: "Also stream pools of the N most liquid watched tokens (0 = open positions only)",
+            min: 0,
+            max: 100,
+            step: 1,
+            unit: "tokens",
+            impact: "medium",
+            category: "Streaming",
+        })]
+        stream_top_watched_tokens: usize = 0,
+        #[metadata(field_metadata! {
+            label: "Max Streamed Accounts",
+            hint: "Upper bound on concurrent accountSubscribe streams",
+            min: 10,
+            max: 1000,
+            step: 10,
+            unit: "accounts",
+            impact: "medium",
+            category: "Streaming",
+        })]
+        max_streamed_accounts: usize = 200,
+        #[metadata(field_metadata! {
+            label: "Multi-Pool Pricing",
+            hint: "Decode several pools per token and use a liquidity-weighted price instead of the canonical pool alone",
+            impact: "high",
+            category: "Multi-Pool Pricing",
+        })]
+        multi_pool_pricing_enabled: bool = false,
+        #[metadata(field_metadata! {
+            label: "Max Pools Per Token",
+            hint: "Pools decoded per token, canonical pool included",
+            min: 2,
+            max: 10,
+            step: 1,
+            unit: "pools",
+            impact: "medium",
+            category: "Multi-Pool Pricing",
+        })]
+        multi_pool_max_pools: usize = 3,
+        #[metadata(field_metadata! {
+            label: "Min Pool Liquidity",
+            hint: "Extra pools below this liquidity are ignored",
+            min: 0,
+            max: 1000000,
+            step: 1000,
+            unit: "USD",
+            impact: "medium",
+            category: "Multi-Pool Pricing",
+        })]
+        multi_pool_min_liquidity_usd: f64 = 5000.0,
+        #[metadata(field_metadata! {
+            label: "Outlier Threshold",
+            hint: "Pools further than this from the weighted median are excluded from the price",
+            min: 1,
+            max: 50,
+            step: 1,
+            unit: "%",
+            impact: "high",
+            category: "Multi-Pool Pricing",
+        })]
+        multi_pool_outlier_pct: f64 = 10.0,
+        #[metadata(field_metadata! {
+            label: "Divergence Alert",
+            hint: "Raise a pool_price_divergence event when pools differ by more than this",
+            min: 1,
+            max: 50,
+            step: 0.5,
+            unit: "%",
+            impact: "low",
+            category: "Multi-Pool Pricing",
+        })]
+        multi_pool_divergence_alert_pct: f64 = 5.0,
+        #[metadata(field_metadata! {
+            label: "Divergence Telegram Alert",
+            hint: "Also send divergence alerts to Telegram",
+            impact: "low",
+            category: "Multi-Pool Pricing",
+        })]
+        multi_pool_divergence_telegram: bool = false,
     }
 }
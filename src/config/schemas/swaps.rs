@@ -119,6 +119,158 @@ config_struct! {
     }
 }
 
+config_struct! {
+    /// Direct on-chain router configuration
+    pub struct DirectConfig {
+        #[metadata(field_metadata! {
+            label: "Enabled",
+            hint: "Quote and swap directly against known pools (no aggregator API)",
+            impact: "high",
+            category: "Router",
+        })]
+        enabled: bool = false,
+        #[metadata(field_metadata! {
+            label: "Max Pools",
+            hint: "How many of the token's pools to quote (canonical pool first)",
+            min: 1,
+            max: 10,
+            step: 1,
+            unit: "pools",
+            impact: "low",
+            category: "Routing",
+        })]
+        max_pools: usize = 3,
+        #[metadata(field_metadata! {
+            label: "Max Price Impact",
+            hint: "Reject direct quotes that move the pool price more than this",
+            min: 0.1,
+            max: 50,
+            step: 0.5,
+            unit: "%",
+            impact: "high",
+            category: "Risk",
+        })]
+        max_price_impact_pct: f64 = 10.0,
+    }
+}
+
+config_struct! {
+    /// Dynamic priority fee configuration
+    pub struct PriorityFeeConfig {
+        #[metadata(field_metadata! {
+            label: "Dynamic Priority Fees",
+            hint: "Estimate priority fees from recent fees paid on the traded pool (static fee when off)",
+            impact: "high",
+            category: "Fees",
+        })]
+        enabled: bool = true,
+        #[metadata(field_metadata! {
+            label: "Emergency Percentile",
+            hint: "Fee percentile for stop losses and forced exits",
+            min: 1,
+            max: 100,
+            step: 5,
+            unit: "%",
+            impact: "medium",
+            category: "Urgency",
+        })]
+        emergency_percentile: f64 = 90.0,
+        #[metadata(field_metadata! {
+            label: "High Percentile",
+            hint: "Fee percentile for trailing stops and manual trades",
+            min: 1,
+            max: 100,
+            step: 5,
+            unit: "%",
+            impact: "medium",
+            category: "Urgency",
+        })]
+        high_percentile: f64 = 75.0,
+        #[metadata(field_metadata! {
+            label: "Normal Percentile",
+            hint: "Fee percentile for entries and regular exits",
+            min: 1,
+            max: 100,
+            step: 5,
+            unit: "%",
+            impact: "medium",
+            category: "Urgency",
+        })]
+        normal_percentile: f64 = 50.0,
+        #[metadata(field_metadata! {
+            label: "Low Percentile",
+            hint: "Fee percentile for trades that can wait",
+            min: 1,
+            max: 100,
+            step: 5,
+            unit: "%",
+            impact: "low",
+            category: "Urgency",
+        })]
+        low_percentile: f64 = 25.0,
+        #[metadata(field_metadata! {
+            label: "Max Fee Per Trade",
+            hint: "Upper bound on the priority fee of a single swap",
+            min: 0.00001,
+            max: 0.1,
+            step: 0.0001,
+            unit: "SOL",
+            impact: "high",
+            category: "Fees",
+        })]
+        max_fee_sol: f64 = 0.002,
+        #[metadata(field_metadata! {
+            label: "Compute Unit Limit",
+            hint: "Compute units assumed per swap when converting fee rates to lamports",
+            min: 50000,
+            max: 1400000,
+            step: 10000,
+            unit: "CU",
+            impact: "low",
+            category: "Fees",
+        })]
+        compute_unit_limit: u32 = 200_000,
+        #[metadata(field_metadata! {
+            label: "Cache TTL",
+            hint: "How long fee samples for the same accounts are reused",
+            min: 1,
+            max: 120,
+            step: 1,
+            unit: "seconds",
+            impact: "low",
+            category: "Performance",
+        })]
+        cache_ttl_secs: u64 = 10,
+    }
+}
+
+config_struct! {
+    /// Pre-flight transaction simulation configuration
+    pub struct SimulationConfig {
+        #[metadata(field_metadata! {
+            label: "Simulate Before Send",
+            hint: "Simulate every swap transaction and abort it when the simulation fails",
+            impact: "high",
+            category: "Protection",
+        })]
+        enabled: bool = true,
+        #[metadata(field_metadata! {
+            label: "Check Output",
+            hint: "Reject swaps whose simulated output is below the quote minus slippage",
+            impact: "high",
+            category: "Protection",
+        })]
+        check_output: bool = true,
+        #[metadata(field_metadata! {
+            label: "Send On RPC Failure",
+            hint: "Send the swap anyway when the simulation request itself fails",
+            impact: "medium",
+            category: "Protection",
+        })]
+        allow_on_rpc_error: bool = true,
+    }
+}
+
 config_struct! {
     /// Slippage configuration
     pub struct SlippageConfig {
@@ -200,6 +352,33 @@ config_struct! {
         })]
         raydium: RaydiumConfig = RaydiumConfig::default(),
 
+        /// Direct on-chain router configuration
+        #[metadata(field_metadata! {
+            label: "Direct",
+            hint: "Direct pool swaps built from decoded on-chain state",
+            impact: "high",
+            category: "Routers",
+        })]
+        direct: DirectConfig = DirectConfig::default(),
+
+        /// Dynamic priority fee configuration
+        #[metadata(field_metadata! {
+            label: "Priority Fees",
+            hint: "Priority fee estimation from recent prioritization fees",
+            impact: "high",
+            category: "Fees",
+        })]
+        priority_fees: PriorityFeeConfig = PriorityFeeConfig::default(),
+
+        /// Pre-flight simulation configuration
+        #[metadata(field_metadata! {
+            label: "Simulation",
+            hint: "Simulate swap transactions before sending them",
+            impact: "high",
+            category: "Risk",
+        })]
+        simulation: SimulationConfig = SimulationConfig::default(),
+
         /// Slippage configuration
         #[metadata(field_metadata! {
             label: "Slippage",
@@ -136,6 +136,48 @@ config_struct! {
         })]
         notify_daily_summary: bool = false,
 
+        /// Weekly rollup of the daily summary
+        #[metadata(field_metadata! {
+            label: "Weekly Summary",
+            hint: "Send a weekly rollup of trading activity and P&L",
+            category: "Notifications",
+        })]
+        notify_weekly_summary: bool = false,
+
+        /// Local hour when summaries are sent
+        #[metadata(field_metadata! {
+            label: "Summary Hour",
+            hint: "Local hour (0-23) when daily and weekly summaries are sent",
+            min: 0.0,
+            max: 23.0,
+            step: 1.0,
+            category: "Notifications",
+        })]
+        summary_hour: u32 = 20,
+
+        /// Time zone used for summary scheduling, as an offset from UTC
+        #[metadata(field_metadata! {
+            label: "Summary Time Zone",
+            hint: "Offset from UTC in minutes for summary scheduling (e.g., 60 = UTC+1, -300 = UTC-5)",
+            unit: "min",
+            min: -720.0,
+            max: 840.0,
+            step: 15.0,
+            category: "Notifications",
+        })]
+        summary_utc_offset_minutes: i32 = 0,
+
+        /// Day the weekly summary is sent
+        #[metadata(field_metadata! {
+            label: "Weekly Summary Day",
+            hint: "Day the weekly summary is sent (0 = Monday ... 6 = Sunday)",
+            min: 0.0,
+            max: 6.0,
+            step: 1.0,
+            category: "Notifications",
+        })]
+        weekly_summary_weekday: u32 = 0,
+
         /// Notify on partial exits
         #[metadata(field_metadata! {
             label: "Partial Exits",
@@ -152,6 +194,14 @@ config_struct! {
         })]
         notify_dca_executed: bool = true,
 
+        /// Notify when pending orders fill, fail or expire
+        #[metadata(field_metadata! {
+            label: "Order Updates",
+            hint: "Notify when a limit, stop or OCO order fills, fails or expires",
+            category: "Notifications",
+        })]
+        notify_order_updates: bool = true,
+
         /// Notify on startup
         #[metadata(field_metadata! {
             label: "Bot Startup",
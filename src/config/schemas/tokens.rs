@@ -100,6 +100,26 @@ config_struct! {
             step: 1.0,
         })]
         security_seconds: u64 = 60,
+
+        #[metadata(field_metadata! {
+            label: "On-Chain Security Interval (s)",
+            hint: "How often to run native on-chain security analysis (only when the On-Chain filter source is enabled)",
+            impact: "low",
+            category: "Updates",
+            min: 0.0,
+            step: 1.0,
+        })]
+        onchain_security_seconds: u64 = 30,
+
+        #[metadata(field_metadata! {
+            label: "On-Chain Security Max Age (s)",
+            hint: "Re-analyze a token once its on-chain security data is older than this",
+            impact: "low",
+            category: "Updates",
+            min: 300.0,
+            step: 300.0,
+        })]
+        onchain_security_max_age_seconds: u64 = 3600,
     }
 }
 
@@ -62,6 +62,24 @@ config_struct! {
             category: "Core Trading",
         })]
         enabled: bool = true,
+        #[metadata(field_metadata! {
+            label: "Paper Trading",
+            hint: "Simulate fills against live quotes instead of sending swaps (requires restart)",
+            impact: "critical",
+            category: "Core Trading",
+        })]
+        paper_trading_enabled: bool = false,
+        #[metadata(field_metadata! {
+            label: "Paper Starting Balance",
+            hint: "Simulated SOL balance paper trading starts from (used by wallet-based sizing)",
+            min: 0.1,
+            max: 10000,
+            step: 0.1,
+            unit: "SOL",
+            impact: "medium",
+            category: "Core Trading",
+        })]
+        paper_starting_balance_sol: f64 = 10.0,
 
         // Core trading parameters
         #[metadata(field_metadata! {
@@ -93,6 +111,98 @@ config_struct! {
         })]
         entry_sizes: Vec<f64> = vec![0.005, 0.01, 0.02, 0.05],
 
+        // ==================== POSITION SIZING ====================
+        #[metadata(field_metadata! {
+            label: "Sizing Mode",
+            hint: "How automated entries are sized: fixed, wallet_percent, liquidity_capped, volatility, kelly",
+            impact: "critical",
+            category: "Position Sizing",
+        })]
+        sizing_mode: String = "fixed".to_string(),
+        #[metadata(field_metadata! {
+            label: "Wallet %",
+            hint: "wallet_percent mode: % of SOL balance per entry",
+            min: 0.1,
+            max: 50,
+            step: 0.1,
+            unit: "%",
+            impact: "critical",
+            category: "Position Sizing",
+        })]
+        sizing_wallet_percent: f64 = 2.0,
+        #[metadata(field_metadata! {
+            label: "Max Pool %",
+            hint: "liquidity_capped mode: max % of the pool SOL reserve per entry (1% ≈ 1% price impact)",
+            min: 0.1,
+            max: 20,
+            step: 0.1,
+            unit: "%",
+            impact: "high",
+            category: "Position Sizing",
+        })]
+        sizing_max_pool_percent: f64 = 1.0,
+        #[metadata(field_metadata! {
+            label: "ATR Timeframe",
+            hint: "volatility mode: candle timeframe for ATR (1m, 5m, 15m, 1h)",
+            impact: "medium",
+            category: "Position Sizing",
+        })]
+        sizing_atr_timeframe: String = "5m".to_string(),
+        #[metadata(field_metadata! {
+            label: "ATR Period",
+            hint: "volatility mode: candles averaged for ATR",
+            min: 2,
+            max: 100,
+            step: 1,
+            unit: "candles",
+            impact: "medium",
+            category: "Position Sizing",
+        })]
+        sizing_atr_period: usize = 14,
+        #[metadata(field_metadata! {
+            label: "Target ATR %",
+            hint: "volatility mode: ATR % at which the full trade size is used (higher ATR = smaller size)",
+            min: 0.1,
+            max: 100,
+            step: 0.1,
+            unit: "%",
+            impact: "high",
+            category: "Position Sizing",
+        })]
+        sizing_target_atr_percent: f64 = 5.0,
+        #[metadata(field_metadata! {
+            label: "Kelly Fraction",
+            hint: "kelly mode: fraction of the full Kelly bet to use (0.25 = quarter Kelly)",
+            min: 0.05,
+            max: 1,
+            step: 0.05,
+            impact: "critical",
+            category: "Position Sizing",
+        })]
+        sizing_kelly_fraction: f64 = 0.25,
+        #[metadata(field_metadata! {
+            label: "Kelly Min Trades",
+            hint: "kelly mode: closed trades needed before Kelly sizing applies (falls back to trade size)",
+            min: 5,
+            max: 500,
+            step: 1,
+            unit: "trades",
+            impact: "medium",
+            category: "Position Sizing",
+        })]
+        sizing_kelly_min_trades: usize = 20,
+        #[metadata(field_metadata! {
+            label: "Min Entry Size",
+            hint: "Smallest entry any sizing mode may produce",
+            min: 0.001,
+            max: 10,
+            step: 0.001,
+            unit: "SOL",
+            impact: "high",
+            category: "Position Sizing",
+        })]
+        sizing_min_size_sol: f64 = 0.001,
+
         // ==================== ROI EXIT CONFIGURATION ====================
         #[metadata(field_metadata! {
             label: "Enable ROI Exit",
@@ -113,6 +223,22 @@ config_struct! {
         })]
         roi_target_percent: f64 = 20.0,
 
+        // ==================== TAKE PROFIT LADDER ====================
+        #[metadata(field_metadata! {
+            label: "Enable Take Profit Ladder",
+            hint: "Sell in tiers as profit grows (requires partial exits; disable ROI Exit so the rest rides the trailing stop)",
+            impact: "high",
+            category: "Take Profit Ladder",
+        })]
+        take_profit_ladder_enabled: bool = false,
+        #[metadata(field_metadata! {
+            label: "Ladder Tiers",
+            hint: "Each tier sells a % of the original position once profit reaches its trigger; the rest rides the trailing stop",
+            impact: "high",
+            category: "Take Profit Ladder",
+        })]
+        take_profit_ladder: Vec<TakeProfitTierConfig> = default_take_profit_ladder(),
+
         // ==================== TIME OVERRIDE CONFIGURATION ====================
         #[metadata(field_metadata! {
             label: "Enable Time Override",
@@ -358,3 +484,27 @@ config_struct! {
         loss_limit_auto_resume: bool = true,
     }
 }
+
+config_struct! {
+    /// Single take-profit ladder tier
+    pub struct TakeProfitTierConfig {
+        /// Profit % from average entry that triggers this tier (25 = +25%)
+        trigger_pct: f64 = 0.0,
+        /// % of the original position to sell when the tier fires
+        sell_pct: f64 = 0.0,
+    }
+}
+
+/// Returns the default take-profit ladder (30% at +25%, 30% at +60%, rest on trailing stop)
+pub fn default_take_profit_ladder() -> Vec<TakeProfitTierConfig> {
+    vec![
+        TakeProfitTierConfig {
+            trigger_pct: 25.0,
+            sell_pct: 30.0,
+        },
+        TakeProfitTierConfig {
+            trigger_pct: 60.0,
+            sell_pct: 30.0,
+        },
+    ]
+}
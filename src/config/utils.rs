@@ -123,6 +123,48 @@ pub fn validate_config(config: &Config) -> Result<(), String> {
         return Err("trader.entry_check_concurrency must be at least 1".to_string());
     }
 
+    if !config.trader.paper_starting_balance_sol.is_finite()
+        || config.trader.paper_starting_balance_sol <= 0.0
+    {
+        return Err("trader.paper_starting_balance_sol must be greater than 0".to_string());
+    }
+
+    // Position sizing validation
+    if !matches!(
+        config.trader.sizing_mode.as_str(),
+        "fixed" | "wallet_percent" | "liquidity_capped" | "volatility" | "kelly"
+    ) {
+        return Err(format!(
+            "trader.sizing_mode must be one of fixed, wallet_percent, liquidity_capped, volatility, kelly (got '{}')",
+            config.trader.sizing_mode
+        ));
+    }
+    if config.trader.sizing_wallet_percent <= 0.0 || config.trader.sizing_wallet_percent > 100.0 {
+        return Err("trader.sizing_wallet_percent must be between 0 and 100".to_string());
+    }
+    if config.trader.sizing_max_pool_percent <= 0.0 || config.trader.sizing_max_pool_percent > 100.0
+    {
+        return Err("trader.sizing_max_pool_percent must be between 0 and 100".to_string());
+    }
+    if crate::ohlcvs::Timeframe::from_str(&config.trader.sizing_atr_timeframe).is_none() {
+        return Err(format!(
+            "trader.sizing_atr_timeframe '{}' is not a valid timeframe",
+            config.trader.sizing_atr_timeframe
+        ));
+    }
+    if config.trader.sizing_atr_period < 2 {
+        return Err("trader.sizing_atr_period must be at least 2".to_string());
+    }
+    if config.trader.sizing_target_atr_percent <= 0.0 {
+        return Err("trader.sizing_target_atr_percent must be greater than 0".to_string());
+    }
+    if config.trader.sizing_kelly_fraction <= 0.0 || config.trader.sizing_kelly_fraction > 1.0 {
+        return Err("trader.sizing_kelly_fraction must be between 0 and 1".to_string());
+    }
+    if !config.trader.sizing_min_size_sol.is_finite() || config.trader.sizing_min_size_sol <= 0.0 {
+        return Err("trader.sizing_min_size_sol must be greater than 0".to_string());
+    }
+
     // DCA validation
     if config.trader.dca_enabled {
         if config.trader.dca_threshold_pct >= 0.0 {
@@ -287,6 +329,110 @@ pub fn validate_config(config: &Config) -> Result<(), String> {
         return Err("swaps.slippage.exit_retry_steps_pct cannot be empty - at least one slippage step is required".to_string());
     }
 
+    if config.swaps.direct.max_pools == 0 {
+        return Err("swaps.direct.max_pools must be at least 1".to_string());
+    }
+    if config.swaps.direct.max_price_impact_pct <= 0.0
+        || config.swaps.direct.max_price_impact_pct > 100.0
+    {
+        return Err("swaps.direct.max_price_impact_pct must be between 0 and 100".to_string());
+    }
+
+    let fees = &config.swaps.priority_fees;
+    for (name, value) in [
+        ("emergency_percentile", fees.emergency_percentile),
+        ("high_percentile", fees.high_percentile),
+        ("normal_percentile", fees.normal_percentile),
+        ("low_percentile", fees.low_percentile),
+    ] {
+        if !(0.0..=100.0).contains(&value) {
+            return Err(format!(
+                "swaps.priority_fees.{} must be between 0 and 100",
+                name
+            ));
+        }
+    }
+    if fees.max_fee_sol <= 0.0 {
+        return Err("swaps.priority_fees.max_fee_sol must be positive".to_string());
+    }
+    if fees.compute_unit_limit == 0 || fees.compute_unit_limit > 1_400_000 {
+        return Err(
+            "swaps.priority_fees.compute_unit_limit must be between 1 and 1400000".to_string(),
+        );
+    }
+
+    // Telegram summary schedule validation
+    if config.telegram.summary_hour > 23 {
+        return Err("telegram.summary_hour must be between 0 and 23".to_string());
+    }
+    if !(-720..=840).contains(&config.telegram.summary_utc_offset_minutes) {
+        return Err("telegram.summary_utc_offset_minutes must be between -720 and 840".to_string());
+    }
+    if config.telegram.weekly_summary_weekday > 6 {
+        return Err("telegram.weekly_summary_weekday must be between 0 and 6".to_string());
+    }
+
+    // Holder watch validation
+    if config.holder_watch.check_interval_secs < 10 {
+        return Err("holder_watch.check_interval_secs must be at least 10".to_string());
+    }
+    if config.holder_watch.max_watched_tokens < 1 {
+        return Err("holder_watch.max_watched_tokens must be at least 1".to_string());
+    }
+    if config.holder_watch.holder_drop_percent <= 0.0
+        || config.holder_watch.holder_drop_percent > 100.0
+    {
+        return Err("holder_watch.holder_drop_percent must be between 0 and 100".to_string());
+    }
+    if config.holder_watch.whale_accumulation_percent <= 0.0
+        || config.holder_watch.whale_accumulation_percent > 100.0
+    {
+        return Err(
+            "holder_watch.whale_accumulation_percent must be between 0 and 100".to_string(),
+        );
+    }
+
+    // Copy trade validation
+    if !matches!(
+        config.copy_trade.sizing_mode.as_str(),
+        "fixed" | "proportional"
+    ) {
+        return Err("copy_trade.sizing_mode must be 'fixed' or 'proportional'".to_string());
+    }
+    if config.copy_trade.fixed_size_sol <= 0.0 || config.copy_trade.proportional_percent <= 0.0 {
+        return Err(
+            "copy_trade.fixed_size_sol and copy_trade.proportional_percent must be positive"
+                .to_string(),
+        );
+    }
+    if config.copy_trade.min_size_sol <= 0.0
+        || config.copy_trade.max_size_sol < config.copy_trade.min_size_sol
+    {
+        return Err(
+            "copy_trade.min_size_sol must be positive and not above copy_trade.max_size_sol"
+                .to_string(),
+        );
+    }
+    if config.copy_trade.max_slippage_pct <= 0.0 {
+        return Err("copy_trade.max_slippage_pct must be positive".to_string());
+    }
+    if config.copy_trade.delay_secs < 0 {
+        return Err("copy_trade.delay_secs cannot be negative".to_string());
+    }
+    if config.copy_trade.max_leaders < 1 {
+        return Err("copy_trade.max_leaders must be at least 1".to_string());
+    }
+
+    // Backup validation
+    if config.backup.interval_hours < 1 {
+        return Err("backup.interval_hours must be at least 1".to_string());
+    }
+    if config.backup.keep_last < 1 {
+        return Err("backup.keep_last must be at least 1".to_string());
+    }
+    crate::backup::resolve_components(&config.backup.exclude_components)
+        .map_err(|e| format!("backup.exclude_components: {}", e))?;
+
     // Router availability check - Jupiter is the primary user-configurable router
     if !config.swaps.jupiter.enabled {
         return Err("Jupiter router must be enabled (primary swap router)".to_string());
@@ -0,0 +1,167 @@
+// Debug tool for strategy backtesting - replays stored 1m OHLCV candles through a strategy
+//
+// Usage:
+// cargo run --bin debug_backtest -- run --strategy <id> --mint <mint> [--hours 24]
+// cargo run --bin debug_backtest -- history --strategy <id>
+
+use clap::{Parser, Subcommand};
+use screenerbot::strategies::backtest::{self, BacktestConfig, BacktestReport};
+
+#[derive(Parser)]
+#[command(name = "debug_backtest")]
+#[command(about = "Backtest strategies over stored OHLCV history", long_about = None)]
+struct Cli {
+  #[command(subcommand)]
+  command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+  /// Run a backtest and store the report
+  Run {
+    /// Strategy ID to backtest
+    #[arg(short, long)]
+    strategy: String,
+
+    /// Token mint(s) to replay (repeatable)
+    #[arg(short, long, required = true)]
+    mint: Vec<String>,
+
+    /// Replay window in hours (ending now)
+    #[arg(long, default_value = "24")]
+    hours: i64,
+
+    /// Entry strategy (required when backtesting an EXIT strategy)
+    #[arg(long)]
+    entry_strategy: Option<String>,
+
+    /// Exit strategy evaluated alongside the built-in exit rules
+    #[arg(long)]
+    exit_strategy: Option<String>,
+
+    /// Override trade size in SOL
+    #[arg(long)]
+    size: Option<f64>,
+
+    /// Override slippage percent per fill
+    #[arg(long)]
+    slippage: Option<f64>,
+
+    /// Print every simulated trade
+    #[arg(short, long)]
+    trades: bool,
+  },
+
+  /// Show stored backtest reports for a strategy
+  History {
+    /// Strategy ID
+    #[arg(short, long)]
+    strategy: String,
+
+    /// Number of reports to show
+    #[arg(short, long, default_value = "5")]
+    limit: usize,
+  },
+}
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+  let cli = Cli::parse();
+
+  screenerbot::config::load_config()?;
+  screenerbot::strategies::db::init_strategies_db()?;
+
+  match cli.command {
+    Commands::Run {
+      strategy,
+      mint,
+      hours,
+      entry_strategy,
+      exit_strategy,
+      size,
+      slippage,
+      trades,
+    } => {
+      let end = chrono::Utc::now().timestamp();
+      let mut config = BacktestConfig::from_config(mint, end - hours * 3600, end);
+      config.entry_strategy_id = entry_strategy;
+      config.exit_strategy_id = exit_strategy;
+      if let Some(size) = size {
+        config.trade_size_sol = size;
+      }
+      if let Some(slippage) = slippage {
+        config.fill_model.slippage_pct = slippage;
+      }
+
+      let report = backtest::run_backtest(&strategy, config).await?;
+      print_report(&report, trades);
+    }
+    Commands::History { strategy, limit } => {
+      let reports = screenerbot::strategies::db::get_backtests(&strategy, limit)?;
+      if reports.is_empty() {
+        println!("No backtests stored for strategy {}", strategy);
+      }
+      for report in &reports {
+        print_report(report, false);
+      }
+    }
+  }
+
+  Ok(())
+}
+
+fn print_report(report: &BacktestReport, show_trades: bool) {
+  println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+  println!("Backtest {} ({})", report.id, report.created_at.to_rfc3339());
+  println!("Strategy: {} ({})", report.strategy_name, report.strategy_id);
+  println!(
+    "Window: {} -> {}",
+    report.start_time.to_rfc3339(),
+    report.end_time.to_rfc3339()
+  );
+  println!();
+  println!(
+    "Trades: {} (wins {}, losses {}, win rate {:.1}%)",
+    report.total_trades, report.win_trades, report.loss_trades, report.win_rate_pct
+  );
+  println!("Total PnL: {:.6} SOL", report.total_profit_sol);
+  println!("Avg PnL: {:.2}%", report.avg_pnl_pct);
+  if let (Some(best), Some(worst)) = (report.best_trade_pct, report.worst_trade_pct) {
+    println!("Best/Worst: {:.2}% / {:.2}%", best, worst);
+  }
+  println!("Avg hold: {:.0}s", report.avg_hold_seconds);
+  println!(
+    "Max drawdown: {:.6} SOL ({:.2}%)",
+    report.max_drawdown_sol, report.max_drawdown_pct
+  );
+  println!("Evaluation errors: {}", report.evaluation_errors);
+  println!();
+
+  for mint in &report.mints {
+    match &mint.skipped_reason {
+      Some(reason) => println!("  {} skipped: {}", mint.mint, reason),
+      None => println!(
+        "  {} candles={} trades={} pnl={:.6} SOL",
+        mint.mint, mint.candles, mint.trades, mint.pnl_sol
+      ),
+    }
+  }
+
+  if show_trades && !report.trades.is_empty() {
+    println!();
+    for trade in &report.trades {
+      println!(
+        "  {} {} -> {} entry={:.10} exit={:.10} pnl={:.6} SOL ({:.2}%) [{:?}]",
+        trade.mint,
+        trade.entry_time.format("%m-%d %H:%M"),
+        trade.exit_time.format("%m-%d %H:%M"),
+        trade.entry_price,
+        trade.exit_price,
+        trade.pnl_sol,
+        trade.pnl_pct,
+        trade.exit_reason
+      );
+    }
+  }
+  println!();
+}
@@ -18,6 +18,7 @@ use screenerbot::{
   logger::{self, LogTag},
   swaps::{execute_swap_with_fallback, get_best_quote, QuoteRequest, SwapMode},
   tokens::{decimals, types::DataSource, priorities::Priority, Token},
+  trader::TradePriority,
   utils::{get_sol_balance, get_token_balance, get_wallet_address, lamports_to_sol, sol_to_lamports},
 };
 
@@ -159,6 +160,7 @@ async fn run_test(args: Args) -> Result<(), String> {
     wallet_address: wallet_address.clone(),
     slippage_pct,
     swap_mode: SwapMode::ExactIn,
+    priority: TradePriority::Normal,
   };
 
   println!("\n Fetching quote from routers...");
@@ -226,6 +228,7 @@ async fn run_test(args: Args) -> Result<(), String> {
         wallet_address: wallet_address.clone(),
         slippage_pct,
         swap_mode: SwapMode::ExactIn,
+        priority: TradePriority::Normal,
       };
 
       println!("\n Fetching quote from routers...");
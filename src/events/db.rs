@@ -1,10 +1,11 @@
 /// Events Database Module
 ///
 /// High-performance SQLite database for persistent event storage.
-/// Fresh schema (no migrations), split read/write pools, batched writes,
+/// Versioned schema migrations, split read/write pools, batched writes,
 /// and keyset-optimized queries.
 use crate::events::types::{Event, EventCategory, Severity};
 use crate::logger::{self, LogTag};
+use crate::migrations::{self, Migration, MigrationSet};
 use chrono::{DateTime, Utc};
 use r2d2::{Pool, PooledConnection};
 use r2d2_sqlite::SqliteConnectionManager;
@@ -26,6 +27,97 @@ const READ_POOL_MAX_SIZE: u32 = 10;
 const POOL_MIN_IDLE: u32 = 1;
 const CONNECTION_TIMEOUT_MS: u64 = 30_000;
 
+// =============================================================================
+// SCHEMA MIGRATIONS
+// =============================================================================
+
+/// Ordered schema migrations of events.db
+pub const EVENTS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "events",
+    migrations: &[Migration::apply(1, "Baseline schema", baseline_schema)],
+};
+
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    // Main events table
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS events (
+            id              INTEGER PRIMARY KEY AUTOINCREMENT,
+            event_time      TEXT    NOT NULL,
+            category        TEXT    NOT NULL,
+            subtype         TEXT,
+            severity        TEXT    NOT NULL,
+            mint            TEXT,
+            reference_id    TEXT,
+            message_short   TEXT,
+            json_payload    TEXT    NOT NULL,
+            created_at      TEXT    NOT NULL DEFAULT (datetime('now'))
+        )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create events table: {}", e))?;
+
+    // Create optimized indexes
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_events_category_time 
+         ON events(category, event_time DESC)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create category-time index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_events_reference_id 
+         ON events(reference_id)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create reference_id index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_events_mint 
+         ON events(mint)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create mint index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_events_severity_time 
+         ON events(severity, event_time DESC)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create severity-time index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_events_created_at 
+         ON events(created_at)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create created_at index: {}", e))?;
+
+    // Keyset and composite indexes for pagination and filters
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_events_id_desc 
+         ON events(id DESC)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create id desc index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_events_category_severity_id 
+         ON events(category, severity, id DESC)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create category-severity-id index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_events_mint_id 
+         ON events(mint, id DESC)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create mint-id index: {}", e))?;
+
+    Ok(())
+}
+
 // =============================================================================
 // DATABASE STRUCTURE
 // =============================================================================
@@ -97,81 +189,7 @@ impl EventsDatabase {
         conn.busy_timeout(std::time::Duration::from_millis(30_000))
             .map_err(|e| format!("Failed to set busy timeout: {}", e))?;
 
-        // Create main events table (fresh schema)
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS events (
-                id              INTEGER PRIMARY KEY AUTOINCREMENT,
-                event_time      TEXT    NOT NULL,
-                category        TEXT    NOT NULL,
-                subtype         TEXT,
-                severity        TEXT    NOT NULL,
-                mint            TEXT,
-                reference_id    TEXT,
-                message_short   TEXT,
-                json_payload    TEXT    NOT NULL,
-                created_at      TEXT    NOT NULL DEFAULT (datetime('now'))
-            )",
-            [],
-        )
-        .map_err(|e| format!("Failed to create events table: {}", e))?;
-
-        // Create optimized indexes
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_events_category_time 
-             ON events(category, event_time DESC)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create category-time index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_events_reference_id 
-             ON events(reference_id)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create reference_id index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_events_mint 
-             ON events(mint)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create mint index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_events_severity_time 
-             ON events(severity, event_time DESC)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create severity-time index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_events_created_at 
-             ON events(created_at)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create created_at index: {}", e))?;
-
-        // Keyset and composite indexes for pagination and filters
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_events_id_desc 
-             ON events(id DESC)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create id desc index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_events_category_severity_id 
-             ON events(category, severity, id DESC)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create category-severity-id index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_events_mint_id 
-             ON events(mint, id DESC)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create mint-id index: {}", e))?;
+        migrations::run_migrations(&conn, &EVENTS_MIGRATIONS)?;
 
         Ok(())
     }
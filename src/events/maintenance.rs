@@ -216,6 +216,50 @@ pub async fn record_swap_event(
     crate::events::record_safe(event).await;
 }
 
+/// Record the outcome of a pre-flight swap simulation
+pub async fn record_swap_simulation_event(
+    input_mint: &str,
+    output_mint: &str,
+    passed: bool,
+    details: Value,
+) {
+    if !is_category_enabled(&EventCategory::Swap) {
+        return;
+    }
+
+    let mut payload = json!({
+        "input_mint": input_mint,
+        "output_mint": output_mint,
+        "passed": passed,
+        "event_time": Utc::now().to_rfc3339()
+    });
+    if let (Some(obj), Value::Object(extra)) = (payload.as_object_mut(), details) {
+        obj.extend(extra);
+    }
+
+    let severity = if passed {
+        Severity::Info
+    } else {
+        Severity::Warn
+    };
+    let mint = if input_mint != SOL_MINT {
+        Some(input_mint.to_string())
+    } else {
+        Some(output_mint.to_string())
+    };
+
+    let event = Event::new(
+        EventCategory::Swap,
+        Some("simulation".to_string()),
+        severity,
+        mint,
+        None,
+        payload,
+    );
+
+    crate::events::record_safe(event).await;
+}
+
 // =============================================================================
 // POOL EVENTS
 // =============================================================================
@@ -55,6 +55,8 @@ pub struct ToolFeatures {
     pub trade_watcher: FeatureStatus,
     /// Holder watch tool - monitor token holders
     pub holder_watch: FeatureStatus,
+    /// Copy trader tool - mirror swaps of followed wallets
+    pub copy_trader: FeatureStatus,
     /// Volume aggregator tool - aggregate trading volume
     pub volume_aggregator: FeatureStatus,
     /// Multi-buy tool - buy from multiple wallets
@@ -77,7 +79,8 @@ impl Default for ToolFeatures {
             token_analyzer: FeatureStatus::ComingSoon,
             create_token: FeatureStatus::ComingSoon,
             trade_watcher: FeatureStatus::ComingSoon,
-            holder_watch: FeatureStatus::ComingSoon,
+            holder_watch: FeatureStatus::Beta,
+            copy_trader: FeatureStatus::Beta,
             volume_aggregator: FeatureStatus::ComingSoon,
             multi_buy: FeatureStatus::ComingSoon,
             multi_sell: FeatureStatus::ComingSoon,
@@ -98,6 +101,7 @@ impl ToolFeatures {
             "create-token" => self.create_token,
             "trade-watcher" => self.trade_watcher,
             "token-watch" | "holder-watch" => self.holder_watch,
+            "copy-trader" => self.copy_trader,
             "volume-aggregator" => self.volume_aggregator,
             "buy-multi-wallets" => self.multi_buy,
             "sell-multi-wallets" => self.multi_sell,
@@ -226,7 +230,7 @@ pub fn get_features() -> Features {
 ///
 /// Tool IDs:
 /// - "wallet-cleanup", "burn-tokens", "token-analyzer", "create-token"
-/// - "trade-watcher", "token-watch", "holder-watch", "volume-aggregator"
+/// - "trade-watcher", "token-watch", "holder-watch", "copy-trader", "volume-aggregator"
 /// - "buy-multi-wallets", "sell-multi-wallets", "wallet-consolidation"
 /// - "airdrop-checker", "wallet-generator"
 pub fn is_tool_available(tool_id: &str) -> bool {
@@ -9,11 +9,12 @@ use crate::config::FilteringConfig;
 use crate::events::{record_filtering_event, Severity};
 use crate::logger::{self, LogTag};
 use crate::positions;
-use crate::tokens::types::{DataSource, Token};
+use crate::tokens::types::{DataSource, OnChainSecurityData, Token};
 use crate::tokens::{
     batch_clear_rejection_status_async, batch_update_priority_async,
     batch_update_rejection_status_async, batch_upsert_rejection_stats_async,
-    get_all_tokens_for_filtering_async, list_blacklisted_tokens_async,
+    get_all_onchain_security_async, get_all_tokens_for_filtering_async,
+    list_blacklisted_tokens_async,
 };
 
 use super::sources::{self, FilterRejectionReason};
@@ -243,6 +244,22 @@ pub async fn compute_snapshot(
             }
         };
 
+    // On-chain security analyses are loaded once per pass (only when the source is enabled)
+    let onchain_map: HashMap<String, OnChainSecurityData> = if config.onchain.enabled {
+        match get_all_onchain_security_async().await {
+            Ok(map) => map,
+            Err(err) => {
+                logger::warning(
+                    LogTag::Filtering,
+                    &format!("failed_to_load_onchain_security err={}", err),
+                );
+                HashMap::new()
+            }
+        }
+    } else {
+        HashMap::new()
+    };
+
     let mut filtered_mints: Vec<String> = Vec::new();
     let mut rejected_mints: Vec<String> = Vec::new();
     let mut passed_tokens: Vec<PassedToken> = Vec::new();
@@ -302,7 +319,7 @@ pub async fn compute_snapshot(
             },
         );
 
-        match apply_all_filters(token, &config).await {
+        match apply_all_filters(token, onchain_map.get(mint), &config).await {
             Ok(()) => {
                 filtered_mints.push(token.mint.clone());
                 stats.passed += 1;
@@ -552,6 +569,7 @@ pub async fn compute_snapshot(
 
 async fn apply_all_filters(
     token: &Token,
+    onchain: Option<&OnChainSecurityData>,
     config: &FilteringConfig,
 ) -> Result<(), FilterRejectionReason> {
     sources::meta::evaluate(token, config).await?;
@@ -624,11 +642,23 @@ async fn apply_all_filters(
             || token.transfer_fee_max_amount.is_some()
             || token.transfer_fee_authority.is_some();
 
-        if !has_rug_data {
+        // With the on-chain fallback enabled, a token lacking Rugcheck data can still be
+        // judged by the on-chain checks below instead of being rejected outright
+        let onchain_fallback =
+            config.onchain.enabled && config.onchain.rugcheck_fallback && onchain.is_some();
+
+        if has_rug_data {
+            sources::rugcheck::evaluate(token, &config.rugcheck)?;
+        } else if !onchain_fallback {
             return Err(FilterRejectionReason::RugcheckDataMissing);
         }
+    }
 
-        sources::rugcheck::evaluate(token, &config.rugcheck)?;
+    if config.onchain.enabled {
+        match onchain {
+            Some(data) => sources::onchain::evaluate(data, &config.onchain)?,
+            None => return Err(FilterRejectionReason::OnchainDataMissing),
+        }
     }
 
     // AI filtering runs LAST after all standard filters pass
@@ -4,6 +4,7 @@ pub mod ai;
 pub mod dexscreener;
 pub mod geckoterminal;
 pub mod meta;
+pub mod onchain;
 pub mod rugcheck;
 
 /// High level origin for a filtering rejection.
@@ -13,6 +14,7 @@ pub enum FilterSource {
     DexScreener,
     GeckoTerminal,
     Rugcheck,
+    Onchain,
     Ai,
 }
 
@@ -23,6 +25,7 @@ impl FilterSource {
             FilterSource::DexScreener => "dexscreener",
             FilterSource::GeckoTerminal => "geckoterminal",
             FilterSource::Rugcheck => "rugcheck",
+            FilterSource::Onchain => "onchain",
             FilterSource::Ai => "ai",
         }
     }
@@ -38,6 +41,7 @@ pub enum FilterRejectionReason {
     DexScreenerDataMissing,
     GeckoTerminalDataMissing,
     RugcheckDataMissing,
+    OnchainDataMissing,
 
     // AI filtering
     AiRejected {
@@ -130,6 +134,19 @@ pub enum FilterRejectionReason {
     RugcheckLpProvidersMissing,
     RugcheckLpLockTooLow,
     RugcheckLpLockMissing,
+
+    // Native on-chain analysis
+    OnchainMintAuthority,
+    OnchainFreezeAuthority,
+    OnchainTransferFeeTooHigh,
+    OnchainTransferHook,
+    OnchainPermanentDelegate,
+    OnchainNonTransferable,
+    OnchainDefaultFrozen,
+    OnchainLpNotSecured,
+    OnchainLpDataMissing,
+    OnchainTopHolderTooHigh,
+    OnchainTop10HoldersTooHigh,
 }
 
 impl FilterRejectionReason {
@@ -142,6 +159,7 @@ impl FilterRejectionReason {
             FilterRejectionReason::DexScreenerDataMissing => "dex_data_missing".to_string(),
             FilterRejectionReason::GeckoTerminalDataMissing => "gecko_data_missing".to_string(),
             FilterRejectionReason::RugcheckDataMissing => "rug_data_missing".to_string(),
+            FilterRejectionReason::OnchainDataMissing => "chain_data_missing".to_string(),
             FilterRejectionReason::AiRejected { .. } => "ai_rejected".to_string(),
             FilterRejectionReason::DexScreenerEmptyName => "dex_empty_name".to_string(),
             FilterRejectionReason::DexScreenerEmptySymbol => "dex_empty_symbol".to_string(),
@@ -294,6 +312,21 @@ impl FilterRejectionReason {
             }
             FilterRejectionReason::RugcheckLpLockTooLow => "rug_lp_lock_low".to_string(),
             FilterRejectionReason::RugcheckLpLockMissing => "rug_lp_lock_missing".to_string(),
+            FilterRejectionReason::OnchainMintAuthority => "chain_mint_authority".to_string(),
+            FilterRejectionReason::OnchainFreezeAuthority => "chain_freeze_authority".to_string(),
+            FilterRejectionReason::OnchainTransferFeeTooHigh => {
+                "chain_transfer_fee_high".to_string()
+            }
+            FilterRejectionReason::OnchainTransferHook => "chain_transfer_hook".to_string(),
+            FilterRejectionReason::OnchainPermanentDelegate => {
+                "chain_permanent_delegate".to_string()
+            }
+            FilterRejectionReason::OnchainNonTransferable => "chain_non_transferable".to_string(),
+            FilterRejectionReason::OnchainDefaultFrozen => "chain_default_frozen".to_string(),
+            FilterRejectionReason::OnchainLpNotSecured => "chain_lp_secured_low".to_string(),
+            FilterRejectionReason::OnchainLpDataMissing => "chain_lp_missing".to_string(),
+            FilterRejectionReason::OnchainTopHolderTooHigh => "chain_top_holder".to_string(),
+            FilterRejectionReason::OnchainTop10HoldersTooHigh => "chain_top10_holders".to_string(),
         }
     }
 
@@ -318,6 +351,7 @@ impl FilterRejectionReason {
                 "GeckoTerminal data missing".to_string()
             }
             FilterRejectionReason::RugcheckDataMissing => "Rugcheck data missing".to_string(),
+            FilterRejectionReason::OnchainDataMissing => "On-chain data missing".to_string(),
             FilterRejectionReason::DexScreenerEmptyName => "Empty name".to_string(),
             FilterRejectionReason::DexScreenerEmptySymbol => "Empty symbol".to_string(),
             FilterRejectionReason::DexScreenerEmptyLogoUrl => "Empty logo URL".to_string(),
@@ -473,6 +507,21 @@ impl FilterRejectionReason {
             FilterRejectionReason::RugcheckLpProvidersMissing => "LP providers missing".to_string(),
             FilterRejectionReason::RugcheckLpLockTooLow => "LP lock too low".to_string(),
             FilterRejectionReason::RugcheckLpLockMissing => "LP lock missing".to_string(),
+            FilterRejectionReason::OnchainMintAuthority => "Mint authority present".to_string(),
+            FilterRejectionReason::OnchainFreezeAuthority => "Freeze authority present".to_string(),
+            FilterRejectionReason::OnchainTransferFeeTooHigh => "Transfer fee too high".to_string(),
+            FilterRejectionReason::OnchainTransferHook => "Transfer hook present".to_string(),
+            FilterRejectionReason::OnchainPermanentDelegate => {
+                "Permanent delegate present".to_string()
+            }
+            FilterRejectionReason::OnchainNonTransferable => "Non-transferable token".to_string(),
+            FilterRejectionReason::OnchainDefaultFrozen => "Accounts frozen by default".to_string(),
+            FilterRejectionReason::OnchainLpNotSecured => "LP burned/locked too low".to_string(),
+            FilterRejectionReason::OnchainLpDataMissing => "LP status unknown".to_string(),
+            FilterRejectionReason::OnchainTopHolderTooHigh => "Top holder % too high".to_string(),
+            FilterRejectionReason::OnchainTop10HoldersTooHigh => {
+                "Top 10 holders % too high".to_string()
+            }
         }
     }
 
@@ -485,7 +534,8 @@ impl FilterRejectionReason {
             | FilterRejectionReason::CooldownFiltered
             | FilterRejectionReason::DexScreenerDataMissing
             | FilterRejectionReason::GeckoTerminalDataMissing
-            | FilterRejectionReason::RugcheckDataMissing => FilterSource::Core,
+            | FilterRejectionReason::RugcheckDataMissing
+            | FilterRejectionReason::OnchainDataMissing => FilterSource::Core,
             FilterRejectionReason::DexScreenerEmptyName
             | FilterRejectionReason::DexScreenerEmptySymbol
             | FilterRejectionReason::DexScreenerEmptyLogoUrl
@@ -565,6 +615,17 @@ impl FilterRejectionReason {
             | FilterRejectionReason::RugcheckLpProvidersMissing
             | FilterRejectionReason::RugcheckLpLockTooLow
             | FilterRejectionReason::RugcheckLpLockMissing => FilterSource::Rugcheck,
+            FilterRejectionReason::OnchainMintAuthority
+            | FilterRejectionReason::OnchainFreezeAuthority
+            | FilterRejectionReason::OnchainTransferFeeTooHigh
+            | FilterRejectionReason::OnchainTransferHook
+            | FilterRejectionReason::OnchainPermanentDelegate
+            | FilterRejectionReason::OnchainNonTransferable
+            | FilterRejectionReason::OnchainDefaultFrozen
+            | FilterRejectionReason::OnchainLpNotSecured
+            | FilterRejectionReason::OnchainLpDataMissing
+            | FilterRejectionReason::OnchainTopHolderTooHigh
+            | FilterRejectionReason::OnchainTop10HoldersTooHigh => FilterSource::Onchain,
         }
     }
 }
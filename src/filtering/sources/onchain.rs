@@ -0,0 +1,74 @@
+use crate::config::schemas::OnchainSecurityFilters;
+use crate::filtering::sources::FilterRejectionReason;
+use crate::tokens::types::OnChainSecurityData;
+
+pub fn evaluate(
+    data: &OnChainSecurityData,
+    config: &OnchainSecurityFilters,
+) -> Result<(), FilterRejectionReason> {
+    if !config.enabled {
+        return Ok(());
+    }
+
+    if config.authority_checks_enabled {
+        if !config.allow_mint_authority && data.mint_authority.is_some() {
+            return Err(FilterRejectionReason::OnchainMintAuthority);
+        }
+
+        if !config.allow_freeze_authority && data.freeze_authority.is_some() {
+            return Err(FilterRejectionReason::OnchainFreezeAuthority);
+        }
+    }
+
+    if config.token2022_checks_enabled {
+        if config.block_non_transferable && data.non_transferable {
+            return Err(FilterRejectionReason::OnchainNonTransferable);
+        }
+
+        if config.block_permanent_delegate && data.permanent_delegate.is_some() {
+            return Err(FilterRejectionReason::OnchainPermanentDelegate);
+        }
+
+        if config.block_transfer_hook && data.transfer_hook_program.is_some() {
+            return Err(FilterRejectionReason::OnchainTransferHook);
+        }
+
+        if config.block_default_frozen && data.default_account_frozen {
+            return Err(FilterRejectionReason::OnchainDefaultFrozen);
+        }
+
+        if let Some(fee_pct) = data.transfer_fee_pct() {
+            if fee_pct > config.max_transfer_fee_pct {
+                return Err(FilterRejectionReason::OnchainTransferFeeTooHigh);
+            }
+        }
+    }
+
+    if config.lp_checks_enabled {
+        match data.lp_secured_pct() {
+            Some(secured) if secured < config.min_lp_secured_pct => {
+                return Err(FilterRejectionReason::OnchainLpNotSecured);
+            }
+            None if config.require_lp_data => {
+                return Err(FilterRejectionReason::OnchainLpDataMissing);
+            }
+            _ => {}
+        }
+    }
+
+    if config.holder_checks_enabled {
+        if let Some(top) = data.top_holder_pct {
+            if top > config.max_top_holder_pct {
+                return Err(FilterRejectionReason::OnchainTopHolderTooHigh);
+            }
+        }
+
+        if let Some(top_10) = data.top_10_holders_pct {
+            if top_10 > config.max_top_10_holders_pct {
+                return Err(FilterRejectionReason::OnchainTop10HoldersTooHigh);
+            }
+        }
+    }
+
+    Ok(())
+}
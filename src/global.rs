@@ -161,6 +161,25 @@ pub fn validate_security_token(token: &str) -> bool {
     }
 }
 
+// ================================================================================================
+// PAPER TRADING MODE - SIMULATED FILLS AGAINST LIVE MARKET DATA
+// ================================================================================================
+
+/// Whether paper trading is active for this process
+/// Latched once when the positions database is opened (switching requires a restart
+/// because paper positions live in a separate database)
+pub static PAPER_TRADING_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Set paper trading mode (called once before the positions database is opened)
+pub fn set_paper_trading(enabled: bool) {
+    PAPER_TRADING_ACTIVE.store(enabled, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Check if paper trading is active (no swaps are submitted on-chain)
+pub fn is_paper_trading() -> bool {
+    PAPER_TRADING_ACTIVE.load(std::sync::atomic::Ordering::SeqCst)
+}
+
 // ================================================================================================
 // TOOLS EXECUTION STATE - PAUSES BACKGROUND SERVICES WHEN TOOLS ARE RUNNING
 // ================================================================================================
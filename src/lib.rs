@@ -5,6 +5,7 @@ pub mod ai;
 pub mod apis;
 pub mod arguments;
 pub mod ata_cleanup;
+pub mod backup;
 pub mod config;
 pub mod connectivity;
 pub mod constants;
@@ -14,6 +15,7 @@ pub mod features;
 pub mod filtering;
 pub mod global;
 pub mod logger;
+pub mod migrations;
 pub mod nfts;
 pub mod ohlcvs;
 pub mod paths;
@@ -180,6 +180,30 @@ async fn main() {
         "Logger initialized, attempting to load config...",
     );
 
+    // Backup, restore and migration check modes (execute and exit, no config required)
+    if screenerbot::arguments::is_backup_enabled() {
+        if let Err(e) = screenerbot::backup::run_backup_cli() {
+            error(LogTag::System, &format!("Backup failed: {e}"));
+            std::process::exit(1);
+        }
+        return;
+    }
+    if let Some(archive) = screenerbot::arguments::get_restore_archive() {
+        let components = screenerbot::arguments::get_restore_components();
+        if let Err(e) = screenerbot::backup::run_restore_cli(&archive, &components) {
+            error(LogTag::System, &format!("Restore failed: {e}"));
+            std::process::exit(1);
+        }
+        return;
+    }
+    if screenerbot::arguments::is_migrate_dry_run_enabled() {
+        if let Err(e) = screenerbot::migrations::run_migrate_dry_run() {
+            error(LogTag::System, &format!("Migration check failed: {e}"));
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Load configuration
     if let Err(e) = load_config() {
         error(
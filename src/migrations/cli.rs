@@ -0,0 +1,71 @@
+//! `--migrate-dry-run` command line mode
+
+use super::registry::plan_all_migrations;
+use super::runner::PlanStatus;
+
+/// Print what the next startup would migrate, without touching any database
+///
+/// Fails when a database is newer than this binary, since startup would be refused.
+pub fn run_migrate_dry_run() -> Result<(), String> {
+    let plans = plan_all_migrations();
+    let mut pending_total = 0;
+    let mut blocked = Vec::new();
+
+    println!("Schema migration dry run (no changes are made)");
+    println!();
+    println!(
+        "  {:<16} {:>8} {:>8}  {}",
+        "DATABASE", "CURRENT", "LATEST", "STATUS"
+    );
+
+    for (name, plan) in &plans {
+        let plan = match plan {
+            Ok(plan) => plan,
+            Err(e) => {
+                println!("  {:<16} {:>8} {:>8}  error: {}", name, "-", "-", e);
+                blocked.push(name.to_string());
+                continue;
+            }
+        };
+
+        let current = plan
+            .current_version
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let status = match plan.status {
+            PlanStatus::Missing => "not created yet".to_string(),
+            PlanStatus::UpToDate => "up to date".to_string(),
+            PlanStatus::Pending => format!("{} pending", plan.pending.len()),
+            PlanStatus::TooNew => "NEWER THAN THIS BUILD - startup refused".to_string(),
+        };
+        println!(
+            "  {:<16} {:>8} {:>8}  {}",
+            name, current, plan.latest_version, status
+        );
+        for migration in &plan.pending {
+            println!(
+                "  {:<16}   -> v{} {}",
+                "", migration.version, migration.description
+            );
+        }
+
+        pending_total += plan.pending.len();
+        if plan.status == PlanStatus::TooNew {
+            blocked.push(name.to_string());
+        }
+    }
+
+    println!();
+    if !blocked.is_empty() {
+        return Err(format!("Startup would fail for: {}", blocked.join(", ")));
+    }
+    if pending_total == 0 {
+        println!("All databases are up to date.");
+    } else {
+        println!(
+            "{} migration(s) will be applied on the next start. Consider `--backup` first.",
+            pending_total
+        );
+    }
+    Ok(())
+}
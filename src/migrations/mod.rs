@@ -0,0 +1,45 @@
+//! Versioned schema migrations for all SQLite stores
+//!
+//! Each store declares an ordered `MigrationSet`; its database records the
+//! applied version in SQLite's `user_version` header field:
+//! - Migrations run in order, each in its own `BEGIN IMMEDIATE` transaction
+//! - Databases newer than the binary are refused instead of half-used
+//! - `--migrate-dry-run` reports pending migrations without touching files
+//!
+//! Migration 1 of every store is its baseline: the schema as it stood when
+//! versioning was introduced, written to also accept databases created by
+//! older unversioned builds. Later schema changes must be appended as new
+//! migrations; released migrations are never edited.
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use screenerbot::migrations::{run_migrations, Migration, MigrationSet};
+//!
+//! pub const ORDERS_MIGRATIONS: MigrationSet = MigrationSet {
+//!     store: "orders",
+//!     migrations: &[
+//!         Migration::sql(1, "Baseline schema", SCHEMA_ORDERS),
+//!         Migration::sql(2, "Add slippage", "ALTER TABLE orders ADD COLUMN slippage_bps INTEGER;"),
+//!     ],
+//! };
+//!
+//! run_migrations(&conn, &ORDERS_MIGRATIONS)?;
+//! ```
+
+mod cli;
+mod registry;
+mod runner;
+
+// Re-export migration definitions and the runner
+pub use runner::{
+    add_column_if_missing, column_exists, plan_migrations, read_user_version, run_migrations,
+    Migration, MigrationOutcome, MigrationPlan, MigrationSet, MigrationStep, PendingMigration,
+    PlanStatus,
+};
+
+// Re-export the store registry
+pub use registry::{check_store_versions, plan_all_migrations, MigratedStore, MIGRATED_STORES};
+
+// Re-export command line mode
+pub use cli::run_migrate_dry_run;
@@ -0,0 +1,162 @@
+//! Every SQLite store of the bot with its migration set
+
+use super::runner::{plan_migrations, MigrationPlan, MigrationSet, PlanStatus};
+use crate::paths;
+use std::path::PathBuf;
+
+/// A database file and the migrations that maintain it
+pub struct MigratedStore {
+    pub name: &'static str,
+    pub path: fn() -> PathBuf,
+    pub migrations: &'static MigrationSet,
+}
+
+const fn store(
+    name: &'static str,
+    path: fn() -> PathBuf,
+    migrations: &'static MigrationSet,
+) -> MigratedStore {
+    MigratedStore {
+        name,
+        path,
+        migrations,
+    }
+}
+
+/// All stores, in the order they are reported
+pub const MIGRATED_STORES: &[MigratedStore] = &[
+    store(
+        "tokens",
+        paths::get_tokens_db_path,
+        &crate::tokens::schema::TOKENS_MIGRATIONS,
+    ),
+    store(
+        "transactions",
+        paths::get_transactions_db_path,
+        &crate::transactions::database::TRANSACTIONS_MIGRATIONS,
+    ),
+    store(
+        "positions",
+        paths::get_positions_db_path,
+        &crate::positions::db::POSITIONS_MIGRATIONS,
+    ),
+    store(
+        "paper_positions",
+        paths::get_paper_positions_db_path,
+        &crate::positions::db::POSITIONS_MIGRATIONS,
+    ),
+    store(
+        "wallet",
+        paths::get_wallet_db_path,
+        &crate::wallet::WALLET_MIGRATIONS,
+    ),
+    store(
+        "wallets",
+        paths::get_wallets_db_path,
+        &crate::wallets::WALLETS_MIGRATIONS,
+    ),
+    store(
+        "events",
+        paths::get_events_db_path,
+        &crate::events::db::EVENTS_MIGRATIONS,
+    ),
+    store(
+        "pools",
+        paths::get_pools_db_path,
+        &crate::pools::db::POOLS_MIGRATIONS,
+    ),
+    store(
+        "strategies",
+        paths::get_strategies_db_path,
+        &crate::strategies::db::STRATEGIES_MIGRATIONS,
+    ),
+    store(
+        "ohlcvs",
+        paths::get_ohlcvs_db_path,
+        &crate::ohlcvs::OHLCV_MIGRATIONS,
+    ),
+    store(
+        "actions",
+        paths::get_actions_db_path,
+        &crate::actions::db::ACTIONS_MIGRATIONS,
+    ),
+    store(
+        "orders",
+        paths::get_orders_db_path,
+        &crate::trader::orders::ORDERS_MIGRATIONS,
+    ),
+    store(
+        "tools",
+        paths::get_tools_db_path,
+        &crate::tools::database::TOOLS_MIGRATIONS,
+    ),
+    store("ai", paths::get_ai_db_path, &crate::ai::db::AI_MIGRATIONS),
+    store(
+        "ai_chat",
+        paths::get_ai_chat_db_path,
+        &crate::ai::chat_db::AI_CHAT_MIGRATIONS,
+    ),
+    store(
+        "rpc_stats",
+        crate::rpc::stats::database::get_rpc_stats_db_path,
+        &crate::rpc::stats::database::RPC_STATS_MIGRATIONS,
+    ),
+];
+
+/// Inspect every store without modifying anything
+pub fn plan_all_migrations() -> Vec<(&'static str, Result<MigrationPlan, String>)> {
+    MIGRATED_STORES
+        .iter()
+        .map(|store| {
+            (
+                store.name,
+                plan_migrations(&(store.path)(), store.migrations),
+            )
+        })
+        .collect()
+}
+
+/// Refuse to start when any database was written by a newer binary
+///
+/// Runs before services open their databases, so a downgrade fails up front
+/// instead of halfway through startup.
+pub fn check_store_versions() -> Result<(), String> {
+    let too_new: Vec<String> = plan_all_migrations()
+        .into_iter()
+        .filter_map(|(name, plan)| match plan {
+            Ok(plan) if plan.status == PlanStatus::TooNew => Some(format!(
+                "{} (v{}, supported up to v{})",
+                name,
+                plan.current_version.unwrap_or_default(),
+                plan.latest_version
+            )),
+            _ => None,
+        })
+        .collect();
+
+    if too_new.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "Databases were written by a newer ScreenerBot version: {}. \
+         Upgrade the bot or restore a backup made with this version.",
+        too_new.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_migration_sets_are_well_formed() {
+        for store in MIGRATED_STORES {
+            assert!(
+                store.migrations.validate().is_ok(),
+                "{} migrations are invalid",
+                store.name
+            );
+            assert!(store.migrations.latest_version() >= 1, "{}", store.name);
+        }
+    }
+}
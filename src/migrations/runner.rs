@@ -0,0 +1,379 @@
+//! Migration definitions and the runner that applies them
+
+use crate::logger::{self, LogTag};
+use rusqlite::{Connection, OpenFlags, Transaction, TransactionBehavior};
+use serde::Serialize;
+use std::path::Path;
+
+/// What a migration does
+#[derive(Clone, Copy)]
+pub enum MigrationStep {
+    /// SQL batch executed as-is
+    Sql(&'static str),
+    /// Code for changes plain SQL cannot express (conditional columns, data rewrites)
+    Apply(fn(&Connection) -> Result<(), String>),
+}
+
+/// One numbered schema change
+///
+/// Versions start at 1 and increase by one. A released migration must never be
+/// edited; schema changes always go into a new migration.
+#[derive(Clone, Copy)]
+pub struct Migration {
+    pub version: u32,
+    pub description: &'static str,
+    pub step: MigrationStep,
+}
+
+impl Migration {
+    pub const fn sql(version: u32, description: &'static str, sql: &'static str) -> Self {
+        Self {
+            version,
+            description,
+            step: MigrationStep::Sql(sql),
+        }
+    }
+
+    pub const fn apply(
+        version: u32,
+        description: &'static str,
+        apply: fn(&Connection) -> Result<(), String>,
+    ) -> Self {
+        Self {
+            version,
+            description,
+            step: MigrationStep::Apply(apply),
+        }
+    }
+
+    fn run(&self, conn: &Connection) -> Result<(), String> {
+        match self.step {
+            MigrationStep::Sql(sql) => conn.execute_batch(sql).map_err(|e| e.to_string()),
+            MigrationStep::Apply(apply) => apply(conn),
+        }
+    }
+}
+
+/// Ordered migrations of one database
+pub struct MigrationSet {
+    /// Store name used in logs and reports (e.g. "positions")
+    pub store: &'static str,
+    pub migrations: &'static [Migration],
+}
+
+impl MigrationSet {
+    /// Schema version this binary produces
+    pub fn latest_version(&self) -> u32 {
+        self.migrations.last().map(|m| m.version).unwrap_or(0)
+    }
+
+    /// Migrations that still need to run on a database at `current`
+    pub fn pending(&self, current: u32) -> impl Iterator<Item = &Migration> {
+        self.migrations.iter().filter(move |m| m.version > current)
+    }
+
+    /// Check that versions run 1, 2, 3, ... without gaps or duplicates
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, migration) in self.migrations.iter().enumerate() {
+            let expected = index as u32 + 1;
+            if migration.version != expected {
+                return Err(format!(
+                    "{} migrations are out of order: expected version {}, found {}",
+                    self.store, expected, migration.version
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Refuse databases written by a newer binary
+    pub fn check_version(&self, current: u32) -> Result<(), String> {
+        let latest = self.latest_version();
+        if current > latest {
+            return Err(format!(
+                "{} database is at schema version {} but this build only supports up to {}. \
+                 It was written by a newer ScreenerBot version; upgrade the bot or restore a \
+                 backup made with this version.",
+                self.store, current, latest
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Result of bringing a database up to date
+#[derive(Debug, Clone, Default)]
+pub struct MigrationOutcome {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub applied: Vec<u32>,
+}
+
+pub fn read_user_version(conn: &Connection) -> Result<u32, String> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))
+        .map(|v| v as u32)
+        .map_err(|e| format!("Failed to read schema version: {}", e))
+}
+
+/// Apply all pending migrations, each in its own transaction
+///
+/// Every migration takes the write lock (`BEGIN IMMEDIATE`) and re-reads the
+/// version first, so connections racing on the same file apply each migration
+/// once. `user_version` is bumped inside the same transaction, so a failed
+/// migration leaves both schema and version untouched.
+pub fn run_migrations(conn: &Connection, set: &MigrationSet) -> Result<MigrationOutcome, String> {
+    set.validate()?;
+    let from_version = read_user_version(conn)?;
+    set.check_version(from_version)?;
+
+    let mut outcome = MigrationOutcome {
+        from_version,
+        to_version: from_version,
+        applied: Vec::new(),
+    };
+
+    for migration in set.pending(from_version) {
+        let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)
+            .map_err(|e| format!("Failed to begin {} migration: {}", set.store, e))?;
+
+        let current = read_user_version(&tx)?;
+        set.check_version(current)?;
+        if current >= migration.version {
+            continue;
+        }
+
+        migration.run(&tx).map_err(|e| {
+            format!(
+                "{} migration {} ({}) failed: {}",
+                set.store, migration.version, migration.description, e
+            )
+        })?;
+        tx.pragma_update(None, "user_version", migration.version)
+            .map_err(|e| format!("Failed to record {} schema version: {}", set.store, e))?;
+        tx.commit().map_err(|e| {
+            format!(
+                "Failed to commit {} migration {}: {}",
+                set.store, migration.version, e
+            )
+        })?;
+
+        outcome.applied.push(migration.version);
+        outcome.to_version = migration.version;
+    }
+
+    if !outcome.applied.is_empty() {
+        logger::info(
+            LogTag::System,
+            &format!(
+                "Migrated {} database schema v{} -> v{}",
+                set.store, outcome.from_version, outcome.to_version
+            ),
+        );
+    }
+    Ok(outcome)
+}
+
+// =============================================================================
+// DRY RUN
+// =============================================================================
+
+/// State of a database relative to this binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStatus {
+    /// No database file yet; created at the latest version on first start
+    Missing,
+    UpToDate,
+    Pending,
+    /// Written by a newer binary; startup is refused
+    TooNew,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingMigration {
+    pub version: u32,
+    pub description: &'static str,
+}
+
+/// What `run_migrations` would do to one database
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationPlan {
+    pub store: &'static str,
+    pub path: String,
+    pub status: PlanStatus,
+    pub current_version: Option<u32>,
+    pub latest_version: u32,
+    pub pending: Vec<PendingMigration>,
+}
+
+/// Inspect a database without modifying (or creating) it
+pub fn plan_migrations(path: &Path, set: &MigrationSet) -> Result<MigrationPlan, String> {
+    set.validate()?;
+    let mut plan = MigrationPlan {
+        store: set.store,
+        path: path.display().to_string(),
+        status: PlanStatus::Missing,
+        current_version: None,
+        latest_version: set.latest_version(),
+        pending: Vec::new(),
+    };
+    if !path.exists() {
+        return Ok(plan);
+    }
+
+    let conn = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let current = read_user_version(&conn)?;
+
+    plan.current_version = Some(current);
+    plan.pending = set
+        .pending(current)
+        .map(|m| PendingMigration {
+            version: m.version,
+            description: m.description,
+        })
+        .collect();
+    plan.status = if current > plan.latest_version {
+        PlanStatus::TooNew
+    } else if plan.pending.is_empty() {
+        PlanStatus::UpToDate
+    } else {
+        PlanStatus::Pending
+    };
+    Ok(plan)
+}
+
+// =============================================================================
+// HELPERS FOR MIGRATIONS
+// =============================================================================
+
+pub fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool, String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .map_err(|e| format!("Failed to inspect {}: {}", table, e))?;
+    let mut rows = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| format!("Failed to inspect {}: {}", table, e))?;
+    Ok(rows.any(|name| name.map_or(false, |name| name == column)))
+}
+
+/// Add a column unless it exists already
+///
+/// For baseline migrations that must also accept databases which received the
+/// column from an older, unversioned startup.
+pub fn add_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    if column_exists(conn, table, column)? {
+        return Ok(());
+    }
+    conn.execute_batch(&format!(
+        "ALTER TABLE {} ADD COLUMN {} {}",
+        table, column, definition
+    ))
+    .map_err(|e| format!("Failed to add {}.{}: {}", table, column, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add_label(conn: &Connection) -> Result<(), String> {
+        add_column_if_missing(conn, "items", "label", "TEXT")
+    }
+
+    const MIGRATIONS: &[Migration] = &[
+        Migration::sql(
+            1,
+            "Create items",
+            "CREATE TABLE IF NOT EXISTS items (id INTEGER PRIMARY KEY)",
+        ),
+        Migration::apply(2, "Add label", add_label),
+    ];
+
+    const SET: MigrationSet = MigrationSet {
+        store: "test",
+        migrations: MIGRATIONS,
+    };
+
+    #[test]
+    fn applies_pending_migrations_once() {
+        let conn = Connection::open_in_memory().unwrap();
+
+        let first = run_migrations(&conn, &SET).unwrap();
+        assert_eq!(first.applied, vec![1, 2]);
+        assert_eq!(read_user_version(&conn).unwrap(), 2);
+        assert!(column_exists(&conn, "items", "label").unwrap());
+
+        let second = run_migrations(&conn, &SET).unwrap();
+        assert!(second.applied.is_empty());
+        assert_eq!(second.from_version, 2);
+    }
+
+    #[test]
+    fn refuses_newer_database() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", 3).unwrap();
+
+        assert!(run_migrations(&conn, &SET).is_err());
+    }
+
+    #[test]
+    fn failed_migration_rolls_back() {
+        const BROKEN: &[Migration] = &[
+            Migration::sql(1, "Create items", "CREATE TABLE items (id INTEGER)"),
+            Migration::sql(
+                2,
+                "Broken",
+                "CREATE TABLE other (id INTEGER); INSERT INTO missing VALUES (1);",
+            ),
+        ];
+        let set = MigrationSet {
+            store: "test",
+            migrations: BROKEN,
+        };
+        let conn = Connection::open_in_memory().unwrap();
+
+        assert!(run_migrations(&conn, &set).is_err());
+        assert_eq!(read_user_version(&conn).unwrap(), 1);
+        assert!(conn.prepare("SELECT * FROM other").is_err());
+    }
+
+    #[test]
+    fn rejects_gaps_in_versions() {
+        const GAP: &[Migration] = &[
+            Migration::sql(1, "One", "SELECT 1"),
+            Migration::sql(3, "Three", "SELECT 1"),
+        ];
+        let set = MigrationSet {
+            store: "test",
+            migrations: GAP,
+        };
+        assert!(set.validate().is_err());
+    }
+
+    #[test]
+    fn plans_without_touching_the_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.db");
+
+        let plan = plan_migrations(&path, &SET).unwrap();
+        assert_eq!(plan.status, PlanStatus::Missing);
+        assert!(!path.exists());
+
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE items (id INTEGER PRIMARY KEY)")
+            .unwrap();
+        let plan = plan_migrations(&path, &SET).unwrap();
+        assert_eq!(plan.status, PlanStatus::Pending);
+        assert_eq!(plan.current_version, Some(0));
+        assert_eq!(plan.pending.len(), 2);
+    }
+}
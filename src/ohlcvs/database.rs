@@ -1,9 +1,10 @@
 // Database layer for OHLCV module
 
 use crate::events::{record_ohlcv_event, Severity};
+use crate::migrations::{Migration, MigrationSet};
 use crate::ohlcvs::types::{
     Candle, MintGapAggregate, OhlcvError, OhlcvResult, PoolConfig, Priority, Timeframe,
-    TokenOhlcvConfig,
+    TokenOhlcvConfig, LOCAL_CANDLE_SOURCE,
 };
 use chrono::{DateTime, Duration, Utc};
 use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Result as SqliteResult};
@@ -12,6 +13,96 @@ use std::collections::HashSet;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
+/// Ordered schema migrations of ohlcvs.db
+pub const OHLCV_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "ohlcvs",
+    migrations: &[Migration::sql(1, "Baseline schema", SCHEMA_BASELINE)],
+};
+
+const SCHEMA_BASELINE: &str = r#"
+-- Pool configurations
+CREATE TABLE IF NOT EXISTS ohlcv_pools (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    mint TEXT NOT NULL,
+    pool_address TEXT NOT NULL,
+    dex TEXT NOT NULL,
+    liquidity REAL NOT NULL DEFAULT 0.0,
+    is_default INTEGER NOT NULL DEFAULT 0,
+    last_success TEXT,
+    failure_count INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(mint, pool_address)
+);
+CREATE INDEX IF NOT EXISTS idx_pools_mint ON ohlcv_pools(mint);
+CREATE INDEX IF NOT EXISTS idx_pools_default ON ohlcv_pools(mint, is_default);
+
+-- UNIFIED CANDLES TABLE (stores ALL native timeframes from API)
+-- Replaces ohlcv_1m and ohlcv_aggregated with single storage
+CREATE TABLE IF NOT EXISTS ohlcv_candles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    mint TEXT NOT NULL,
+    pool_address TEXT NOT NULL,
+    timeframe TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    open REAL NOT NULL,
+    high REAL NOT NULL,
+    low REAL NOT NULL,
+    close REAL NOT NULL,
+    volume REAL NOT NULL,
+    source TEXT NOT NULL DEFAULT 'api',
+    fetched_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(mint, pool_address, timeframe, timestamp)
+);
+CREATE INDEX IF NOT EXISTS idx_candles_lookup ON ohlcv_candles(mint, timeframe, timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_candles_pool_lookup ON ohlcv_candles(pool_address, timeframe, timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_candles_cleanup ON ohlcv_candles(fetched_at);
+
+-- Gap tracking (per timeframe)
+CREATE TABLE IF NOT EXISTS ohlcv_gaps (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    mint TEXT NOT NULL,
+    pool_address TEXT NOT NULL,
+    timeframe TEXT NOT NULL,
+    start_timestamp INTEGER NOT NULL,
+    end_timestamp INTEGER NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_attempt TEXT,
+    filled INTEGER NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(mint, pool_address, timeframe, start_timestamp, end_timestamp)
+);
+CREATE INDEX IF NOT EXISTS idx_gaps_unfilled ON ohlcv_gaps(filled, mint, timeframe);
+CREATE INDEX IF NOT EXISTS idx_gaps_retry ON ohlcv_gaps(filled, attempts, last_attempt);
+
+-- Token monitoring configuration (with backfill tracking)
+CREATE TABLE IF NOT EXISTS ohlcv_monitor_config (
+    mint TEXT PRIMARY KEY,
+    priority TEXT NOT NULL,
+    fetch_interval_seconds INTEGER NOT NULL DEFAULT 60,
+    source TEXT NOT NULL DEFAULT 'manual',
+    is_active INTEGER NOT NULL DEFAULT 1,
+    backfill_1m_complete INTEGER NOT NULL DEFAULT 0,
+    backfill_5m_complete INTEGER NOT NULL DEFAULT 0,
+    backfill_15m_complete INTEGER NOT NULL DEFAULT 0,
+    backfill_1h_complete INTEGER NOT NULL DEFAULT 0,
+    backfill_4h_complete INTEGER NOT NULL DEFAULT 0,
+    backfill_12h_complete INTEGER NOT NULL DEFAULT 0,
+    backfill_1d_complete INTEGER NOT NULL DEFAULT 0,
+    backfill_started_at TEXT,
+    backfill_completed_at TEXT,
+    last_fetch TEXT,
+    last_activity TEXT NOT NULL,
+    consecutive_empty_fetches INTEGER NOT NULL DEFAULT 0,
+    last_pool_discovery_attempt INTEGER,
+    consecutive_pool_failures INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS idx_monitor_active ON ohlcv_monitor_config(is_active, priority);
+CREATE INDEX IF NOT EXISTS idx_monitor_backfill ON ohlcv_monitor_config(is_active, backfill_1d_complete);
+"#;
+
 pub struct OhlcvDatabase {
     conn: Arc<Mutex<Connection>>,
 }
@@ -41,93 +132,8 @@ impl OhlcvDatabase {
             .lock()
             .map_err(|e| OhlcvError::DatabaseError(format!("Lock error: {}", e)))?;
 
-        conn
-            .execute_batch(
-                r#"
-            -- Pool configurations
-            CREATE TABLE IF NOT EXISTS ohlcv_pools (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                mint TEXT NOT NULL,
-                pool_address TEXT NOT NULL,
-                dex TEXT NOT NULL,
-                liquidity REAL NOT NULL DEFAULT 0.0,
-                is_default INTEGER NOT NULL DEFAULT 0,
-                last_success TEXT,
-                failure_count INTEGER NOT NULL DEFAULT 0,
-                created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(mint, pool_address)
-            );
-            CREATE INDEX IF NOT EXISTS idx_pools_mint ON ohlcv_pools(mint);
-            CREATE INDEX IF NOT EXISTS idx_pools_default ON ohlcv_pools(mint, is_default);
-
-            -- UNIFIED CANDLES TABLE (stores ALL native timeframes from API)
-            -- Replaces ohlcv_1m and ohlcv_aggregated with single storage
-            CREATE TABLE IF NOT EXISTS ohlcv_candles (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                mint TEXT NOT NULL,
-                pool_address TEXT NOT NULL,
-                timeframe TEXT NOT NULL,
-                timestamp INTEGER NOT NULL,
-                open REAL NOT NULL,
-                high REAL NOT NULL,
-                low REAL NOT NULL,
-                close REAL NOT NULL,
-                volume REAL NOT NULL,
-                source TEXT NOT NULL DEFAULT 'api',
-                fetched_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(mint, pool_address, timeframe, timestamp)
-            );
-            CREATE INDEX IF NOT EXISTS idx_candles_lookup ON ohlcv_candles(mint, timeframe, timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_candles_pool_lookup ON ohlcv_candles(pool_address, timeframe, timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_candles_cleanup ON ohlcv_candles(fetched_at);
-
-            -- Gap tracking (per timeframe)
-            CREATE TABLE IF NOT EXISTS ohlcv_gaps (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                mint TEXT NOT NULL,
-                pool_address TEXT NOT NULL,
-                timeframe TEXT NOT NULL,
-                start_timestamp INTEGER NOT NULL,
-                end_timestamp INTEGER NOT NULL,
-                attempts INTEGER NOT NULL DEFAULT 0,
-                last_attempt TEXT,
-                filled INTEGER NOT NULL DEFAULT 0,
-                error_message TEXT,
-                created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(mint, pool_address, timeframe, start_timestamp, end_timestamp)
-            );
-            CREATE INDEX IF NOT EXISTS idx_gaps_unfilled ON ohlcv_gaps(filled, mint, timeframe);
-            CREATE INDEX IF NOT EXISTS idx_gaps_retry ON ohlcv_gaps(filled, attempts, last_attempt);
-
-            -- Token monitoring configuration (with backfill tracking)
-            CREATE TABLE IF NOT EXISTS ohlcv_monitor_config (
-                mint TEXT PRIMARY KEY,
-                priority TEXT NOT NULL,
-                fetch_interval_seconds INTEGER NOT NULL DEFAULT 60,
-                source TEXT NOT NULL DEFAULT 'manual',
-                is_active INTEGER NOT NULL DEFAULT 1,
-                backfill_1m_complete INTEGER NOT NULL DEFAULT 0,
-                backfill_5m_complete INTEGER NOT NULL DEFAULT 0,
-                backfill_15m_complete INTEGER NOT NULL DEFAULT 0,
-                backfill_1h_complete INTEGER NOT NULL DEFAULT 0,
-                backfill_4h_complete INTEGER NOT NULL DEFAULT 0,
-                backfill_12h_complete INTEGER NOT NULL DEFAULT 0,
-                backfill_1d_complete INTEGER NOT NULL DEFAULT 0,
-                backfill_started_at TEXT,
-                backfill_completed_at TEXT,
-                last_fetch TEXT,
-                last_activity TEXT NOT NULL,
-                consecutive_empty_fetches INTEGER NOT NULL DEFAULT 0,
-                last_pool_discovery_attempt INTEGER,
-                consecutive_pool_failures INTEGER NOT NULL DEFAULT 0,
-                created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
-                updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
-            );
-            CREATE INDEX IF NOT EXISTS idx_monitor_active ON ohlcv_monitor_config(is_active, priority);
-            CREATE INDEX IF NOT EXISTS idx_monitor_backfill ON ohlcv_monitor_config(is_active, backfill_1d_complete);
-            "#
-            )
-            .map_err(|e| OhlcvError::DatabaseError(format!("Failed to create tables: {}", e)))?;
+        crate::migrations::run_migrations(&conn, &OHLCV_MIGRATIONS)
+            .map_err(OhlcvError::DatabaseError)?;
 
         Ok(())
     }
@@ -582,7 +588,8 @@ impl OhlcvDatabase {
 
     // ==================== Unified Candles Storage ====================
 
-    /// Insert batch of candles for specific timeframe
+    /// Insert batch of candles for specific timeframe.
+    /// Existing rows are kept, except locally built candles which are replaced by API data.
     pub fn insert_candles_batch(
         &self,
         mint: &str,
@@ -612,7 +619,14 @@ impl OhlcvDatabase {
                 "INSERT INTO ohlcv_candles 
                  (mint, pool_address, timeframe, timestamp, open, high, low, close, volume, source)
                  VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
-                 ON CONFLICT(mint, pool_address, timeframe, timestamp) DO NOTHING",
+                 ON CONFLICT(mint, pool_address, timeframe, timestamp) DO UPDATE SET
+                    open = excluded.open,
+                    high = excluded.high,
+                    low = excluded.low,
+                    close = excluded.close,
+                    volume = excluded.volume,
+                    source = excluded.source
+                 WHERE ohlcv_candles.source = ?11 AND excluded.source != ?11",
                 params![
                     mint,
                     pool_address,
@@ -624,6 +638,7 @@ impl OhlcvDatabase {
                     candle.close,
                     candle.volume,
                     source,
+                    LOCAL_CANDLE_SOURCE,
                 ],
             );
 
@@ -0,0 +1,350 @@
+// Local candle builder - turns pool service price ticks into 1m candles
+//
+// The pool service keeps a short in-memory price history per token. Every cycle
+// the closed minutes of that history are folded into 1m candles and stored with
+// source LOCAL_CANDLE_SOURCE. API candles for the same bucket take precedence
+// (see OhlcvDatabase::insert_candles_batch), so local candles only ever fill
+// buckets the API has not provided - gaps, the live edge, and tokens the API
+// does not index at all. Higher timeframes come from the usual 1m aggregation.
+//
+// Volume is an estimate: the absolute change in the pool's SOL reserves between
+// consecutive ticks, valued in USD at the tick's SOL price. It misses round-trips
+// that net out between two ticks and includes liquidity adds/removes.
+
+use crate::events::{record_ohlcv_event, Severity};
+use crate::logger::{self, LogTag};
+use crate::ohlcvs::cache::OhlcvCache;
+use crate::ohlcvs::database::OhlcvDatabase;
+use crate::ohlcvs::manager::PoolManager;
+use crate::ohlcvs::types::{Candle, OhlcvError, OhlcvResult, Timeframe, LOCAL_CANDLE_SOURCE};
+use crate::pools::PriceResult;
+use chrono::Utc;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::RwLock;
+
+const MINUTE_SECONDS: i64 = 60;
+
+/// Dex label used when registering a pool only known from price ticks
+const LOCAL_POOL_DEX: &str = "pool_service";
+
+/// A single price observation reduced to what candle building needs
+#[derive(Debug, Clone, Copy)]
+pub struct PriceTick {
+    /// Unix timestamp (seconds)
+    pub timestamp: i64,
+    /// Price in SOL (same quote currency as API candles)
+    pub price: f64,
+    /// SOL reserves in the pool at this tick
+    pub sol_reserves: f64,
+    /// SOL price in USD at this tick, used to value volume
+    pub sol_price_usd: f64,
+}
+
+impl PriceTick {
+    /// Convert a pool service price result, anchoring its monotonic timestamp to wall clock
+    pub fn from_price_result(
+        result: &PriceResult,
+        now_instant: Instant,
+        now_unix: i64,
+    ) -> Option<Self> {
+        if !result.price_sol.is_finite() || result.price_sol <= 0.0 {
+            return None;
+        }
+
+        let age = now_instant
+            .checked_duration_since(result.timestamp)
+            .unwrap_or_default()
+            .as_secs() as i64;
+
+        let sol_price_usd = if result.price_usd.is_finite() && result.price_usd > 0.0 {
+            result.price_usd / result.price_sol
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            timestamp: now_unix - age,
+            price: result.price_sol,
+            sol_reserves: result.sol_reserves,
+            sol_price_usd,
+        })
+    }
+}
+
+/// Fold ticks (oldest first) into 1m candles.
+///
+/// Only buckets strictly after `after` (if given) and strictly before `before`
+/// are returned, so the caller controls which minutes count as closed and which
+/// were already written. Minutes without ticks between the first and the last
+/// covered minute are emitted as flat zero-volume candles at the previous close.
+pub fn build_minute_candles(ticks: &[PriceTick], after: Option<i64>, before: i64) -> Vec<Candle> {
+    let mut buckets: BTreeMap<i64, Candle> = BTreeMap::new();
+    let mut previous: Option<&PriceTick> = None;
+
+    for tick in ticks {
+        let bucket = tick.timestamp - tick.timestamp.rem_euclid(MINUTE_SECONDS);
+
+        let volume = match previous {
+            Some(prev) if tick.sol_reserves.is_finite() && prev.sol_reserves.is_finite() => {
+                (tick.sol_reserves - prev.sol_reserves).abs() * tick.sol_price_usd
+            }
+            _ => 0.0,
+        };
+        previous = Some(tick);
+
+        buckets
+            .entry(bucket)
+            .and_modify(|candle| {
+                candle.high = candle.high.max(tick.price);
+                candle.low = candle.low.min(tick.price);
+                candle.close = tick.price;
+                candle.volume += volume;
+            })
+            .or_insert_with(|| {
+                Candle::new(
+                    bucket, tick.price, tick.price, tick.price, tick.price, volume,
+                )
+            });
+    }
+
+    let mut candles = Vec::with_capacity(buckets.len());
+    let mut last: Option<Candle> = None;
+
+    for (bucket, candle) in buckets {
+        if bucket >= before {
+            break;
+        }
+
+        // Carry the previous close through minutes without ticks
+        if let Some(prev) = &last {
+            let mut missing = prev.timestamp + MINUTE_SECONDS;
+            while missing < bucket {
+                if after.map_or(true, |a| missing > a) {
+                    candles.push(Candle::new(
+                        missing, prev.close, prev.close, prev.close, prev.close, 0.0,
+                    ));
+                }
+                missing += MINUTE_SECONDS;
+            }
+        }
+
+        if after.map_or(true, |a| bucket > a) {
+            candles.push(candle.clone());
+        }
+        last = Some(candle);
+    }
+
+    candles
+}
+
+pub struct LocalCandleBuilder {
+    db: Arc<OhlcvDatabase>,
+    cache: Arc<OhlcvCache>,
+    pool_manager: Arc<PoolManager>,
+    // Last minute bucket written per mint
+    last_written: RwLock<HashMap<String, i64>>,
+}
+
+impl LocalCandleBuilder {
+    pub fn new(
+        db: Arc<OhlcvDatabase>,
+        cache: Arc<OhlcvCache>,
+        pool_manager: Arc<PoolManager>,
+    ) -> Self {
+        Self {
+            db,
+            cache,
+            pool_manager,
+            last_written: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Build and store closed 1m candles for every token the pool service is pricing.
+    /// Returns the number of candle rows written.
+    pub async fn run_cycle(&self) -> usize {
+        let mints = crate::pools::get_available_tokens();
+        let mut written = 0;
+
+        for mint in &mints {
+            match self.build_for_mint(mint).await {
+                Ok(count) => written += count,
+                Err(e) => {
+                    logger::warning(
+                        LogTag::Ohlcv,
+                        &format!("Local candle build failed for {}: {}", mint, e),
+                    );
+                }
+            }
+        }
+
+        // Forget mints the pool service no longer tracks
+        self.last_written
+            .write()
+            .await
+            .retain(|mint, _| mints.contains(mint));
+
+        written
+    }
+
+    async fn build_for_mint(&self, mint: &str) -> OhlcvResult<usize> {
+        let history = crate::pools::get_price_history(mint);
+        let Some(latest) = history.last() else {
+            return Ok(0);
+        };
+
+        // Reserves from different pools are not comparable; stick to the current one
+        let pool_address = latest.pool_address.clone();
+        if pool_address.is_empty() {
+            return Ok(0);
+        }
+
+        let now_instant = Instant::now();
+        let now_unix = Utc::now().timestamp();
+        let ticks: Vec<PriceTick> = history
+            .iter()
+            .filter(|result| result.pool_address == pool_address)
+            .filter_map(|result| PriceTick::from_price_result(result, now_instant, now_unix))
+            .collect();
+
+        let current_minute = now_unix - now_unix.rem_euclid(MINUTE_SECONDS);
+        let after = self.last_written.read().await.get(mint).copied();
+        let candles = build_minute_candles(&ticks, after, current_minute);
+
+        let Some(last_bucket) = candles.last().map(|c| c.timestamp) else {
+            return Ok(0);
+        };
+
+        self.ensure_pool_registered(mint, &pool_address, latest)
+            .await?;
+
+        let db = Arc::clone(&self.db);
+        let mint_owned = mint.to_string();
+        let pool_owned = pool_address.clone();
+        let written = tokio::task::spawn_blocking(move || {
+            db.insert_candles_batch(
+                &mint_owned,
+                &pool_owned,
+                Timeframe::Minute1,
+                &candles,
+                LOCAL_CANDLE_SOURCE,
+            )
+        })
+        .await
+        .map_err(|e| OhlcvError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        self.last_written
+            .write()
+            .await
+            .insert(mint.to_string(), last_bucket);
+
+        if written > 0 {
+            self.cache.invalidate(mint, Some(&pool_address), None).ok();
+            // Keep the pool healthy for reads while local data keeps flowing
+            self.pool_manager.mark_success(mint, &pool_address).await?;
+        }
+
+        Ok(written)
+    }
+
+    /// Register the tick pool when the token has no OHLCV pools yet, so reads and
+    /// bundles work for tokens the API does not index
+    async fn ensure_pool_registered(
+        &self,
+        mint: &str,
+        pool_address: &str,
+        latest: &PriceResult,
+    ) -> OhlcvResult<()> {
+        let pools = self.pool_manager.get_pools(mint).await?;
+        if !pools.is_empty() {
+            return Ok(());
+        }
+
+        let liquidity_usd = if latest.price_sol > 0.0 {
+            latest.sol_reserves * 2.0 * (latest.price_usd / latest.price_sol)
+        } else {
+            0.0
+        };
+        let dex = latest.source_pool.as_deref().unwrap_or(LOCAL_POOL_DEX);
+
+        self.pool_manager
+            .register_pool(mint, pool_address, dex, liquidity_usd)
+            .await?;
+
+        record_ohlcv_event(
+            "local_pool_registered",
+            Severity::Info,
+            Some(mint),
+            Some(pool_address),
+            json!({
+                "mint": mint,
+                "pool_address": pool_address,
+                "dex": dex,
+                "source": LOCAL_CANDLE_SOURCE,
+            }),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tick(timestamp: i64, price: f64, sol_reserves: f64) -> PriceTick {
+        PriceTick {
+            timestamp,
+            price,
+            sol_reserves,
+            sol_price_usd: 100.0,
+        }
+    }
+
+    #[test]
+    fn folds_ticks_into_closed_minutes() {
+        let ticks = vec![
+            tick(60, 1.0, 10.0),
+            tick(80, 1.5, 11.0),
+            tick(110, 0.8, 10.5),
+            tick(200, 1.2, 10.0),
+            tick(250, 1.3, 10.0),
+        ];
+
+        // Minute 240 is still open
+        let candles = build_minute_candles(&ticks, None, 240);
+        assert_eq!(candles.len(), 3);
+
+        assert_eq!(candles[0].timestamp, 60);
+        assert_eq!(candles[0].open, 1.0);
+        assert_eq!(candles[0].high, 1.5);
+        assert_eq!(candles[0].low, 0.8);
+        assert_eq!(candles[0].close, 0.8);
+        assert!((candles[0].volume - 150.0).abs() < 1e-9);
+
+        // Minute without ticks carries the previous close
+        assert_eq!(candles[1].timestamp, 120);
+        assert_eq!(candles[1].open, 0.8);
+        assert_eq!(candles[1].volume, 0.0);
+
+        assert_eq!(candles[2].timestamp, 180);
+        assert_eq!(candles[2].open, 1.2);
+        assert!((candles[2].volume - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn skips_already_written_minutes() {
+        let ticks = vec![
+            tick(60, 1.0, 10.0),
+            tick(130, 1.1, 10.0),
+            tick(190, 1.2, 10.0),
+        ];
+
+        let candles = build_minute_candles(&ticks, Some(120), 240);
+        assert_eq!(candles.len(), 1);
+        assert_eq!(candles[0].timestamp, 180);
+    }
+}
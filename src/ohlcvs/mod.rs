@@ -7,6 +7,7 @@ mod cache;
 mod database;
 mod fetcher;
 mod gaps;
+mod local;
 mod manager;
 mod monitor;
 mod priorities;
@@ -15,10 +16,10 @@ mod types;
 
 pub use types::{
     Candle, OhlcvError, OhlcvMetrics, OhlcvResult, PoolConfig, PoolMetadata, Priority, Timeframe,
-    TimeframeBundle, TokenOhlcvConfig, BUNDLE_CANDLE_COUNT,
+    TimeframeBundle, TokenOhlcvConfig, BUNDLE_CANDLE_COUNT, LOCAL_CANDLE_SOURCE,
 };
 
-pub use database::{DatabaseStats, DeleteResult, OhlcvTokenStatus};
+pub use database::{DatabaseStats, DeleteResult, OhlcvTokenStatus, OHLCV_MIGRATIONS};
 pub use monitor::{MonitorStats, MonitorTelemetrySnapshot};
 pub use priorities::ActivityType;
 pub use service::OhlcvService;
@@ -50,6 +51,16 @@ pub async fn get_ohlcv_data(
     .await
 }
 
+// Full stored history for a time range (used by backtesting and exports)
+pub async fn get_candles_in_range(
+    mint: &str,
+    timeframe: Timeframe,
+    from_timestamp: Option<i64>,
+    to_timestamp: Option<i64>,
+) -> OhlcvResult<Vec<Candle>> {
+    service::get_candles_in_range(mint, timeframe, from_timestamp, to_timestamp).await
+}
+
 pub async fn get_available_pools(mint: &str) -> OhlcvResult<Vec<PoolMetadata>> {
     service::get_available_pools(mint).await
 }
@@ -8,6 +8,7 @@ use crate::ohlcvs::cache::OhlcvCache;
 use crate::ohlcvs::database::OhlcvDatabase;
 use crate::ohlcvs::fetcher::OhlcvFetcher;
 use crate::ohlcvs::gaps::GapManager;
+use crate::ohlcvs::local::LocalCandleBuilder;
 use crate::ohlcvs::manager::PoolManager;
 use crate::ohlcvs::priorities::{ActivityType, PriorityManager};
 use crate::ohlcvs::types::{
@@ -152,6 +153,7 @@ impl OhlcvMonitor {
         tokio::spawn(self.clone().cleanup_loop());
         tokio::spawn(self.clone().cache_maintenance_loop());
         tokio::spawn(self.clone().sync_pool_service_tokens());
+        tokio::spawn(self.clone().local_candle_loop());
 
         Ok(())
     }
@@ -1507,6 +1509,38 @@ impl OhlcvMonitor {
         Ok(inserted)
     }
 
+    async fn local_candle_loop(self: Arc<Self>) {
+        let builder = LocalCandleBuilder::new(
+            Arc::clone(&self.db),
+            Arc::clone(&self.cache),
+            Arc::clone(&self.pool_manager),
+        );
+
+        loop {
+            let interval_secs = with_config(|cfg| cfg.ohlcv.local_candles_interval_secs).max(15);
+            sleep(Duration::from_secs(interval_secs)).await;
+
+            if *self.shutdown_signal.read().await {
+                break;
+            }
+
+            if !with_config(|cfg| cfg.ohlcv.local_candles_enabled) {
+                continue;
+            }
+
+            let written = builder.run_cycle().await;
+            if written > 0 {
+                logger::debug(
+                    LogTag::Ohlcv,
+                    &format!(
+                        "Stored {} locally built 1m candles from pool ticks",
+                        written
+                    ),
+                );
+            }
+        }
+    }
+
     async fn cleanup_loop(self: Arc<Self>) {
         let mut tick = interval(Duration::from_secs(3600)); // Every hour
 
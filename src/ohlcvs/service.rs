@@ -208,6 +208,56 @@ impl OhlcvServiceImpl {
         Ok(candles.into_iter().skip(start_idx).collect())
     }
 
+    /// Load the full stored candle history for a time range (no limit, cache bypassed)
+    ///
+    /// Prefers the default/best pool; falls back to any pool for the mint and
+    /// de-duplicates timestamps so callers always get a single ASC series.
+    async fn get_candles_in_range(
+        &self,
+        mint: &str,
+        timeframe: Timeframe,
+        from_timestamp: Option<i64>,
+        to_timestamp: Option<i64>,
+    ) -> OhlcvResult<Vec<Candle>> {
+        let mut pool = self.pool_manager.get_default_pool(mint).await?;
+        if pool.is_none() {
+            pool = self.pool_manager.get_best_pool(mint).await?;
+        }
+        let pool_address = pool.map(|p| p.address);
+
+        let db = Arc::clone(&self.db);
+        let mint_owned = mint.to_string();
+        let mut candles = tokio::task::spawn_blocking(move || {
+            let candles = db.get_candles(
+                &mint_owned,
+                pool_address.as_deref(),
+                timeframe,
+                from_timestamp,
+                to_timestamp,
+                None,
+            )?;
+
+            if candles.is_empty() && pool_address.is_some() {
+                return db.get_candles(
+                    &mint_owned,
+                    None,
+                    timeframe,
+                    from_timestamp,
+                    to_timestamp,
+                    None,
+                );
+            }
+
+            Ok(candles)
+        })
+        .await
+        .map_err(|e| OhlcvError::DatabaseError(format!("Task join error: {}", e)))??;
+
+        candles.sort_by_key(|c| c.timestamp);
+        candles.dedup_by_key(|c| c.timestamp);
+        Ok(candles)
+    }
+
     fn has_data(&self, mint: &str) -> OhlcvResult<bool> {
         self.db.has_data_for_mint(mint)
     }
@@ -568,6 +618,19 @@ pub async fn get_ohlcv_data(
         .await
 }
 
+pub async fn get_candles_in_range(
+    mint: &str,
+    timeframe: Timeframe,
+    from_timestamp: Option<i64>,
+    to_timestamp: Option<i64>,
+) -> OhlcvResult<Vec<Candle>> {
+    let service = get_or_init_service().await?;
+
+    service
+        .get_candles_in_range(mint, timeframe, from_timestamp, to_timestamp)
+        .await
+}
+
 pub async fn get_available_pools(mint: &str) -> OhlcvResult<Vec<PoolMetadata>> {
     let service = get_or_init_service().await?;
 
@@ -178,6 +178,10 @@ impl Candle {
 /// Number of candles to fetch per timeframe for bundle creation
 pub const BUNDLE_CANDLE_COUNT: usize = 100;
 
+/// Source tag for 1m candles built locally from pool service price ticks.
+/// API candles for the same bucket always replace rows with this source.
+pub const LOCAL_CANDLE_SOURCE: &str = "pool_ticks";
+
 /// Multi-timeframe bundle containing all timeframes for a single token
 /// This is the primary data structure for strategy evaluation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +23,8 @@
 //! │ └── cache_pool/
 //! ├── logs/
 //! │ └── screenerbot_*.log
+//! ├── backups/
+//! │ └── *.sbbackup
 //! └── analysis-exports/
 //! └── *.csv
 //! ```
@@ -110,6 +112,13 @@ pub fn get_analysis_exports_directory() -> PathBuf {
     BASE_DIRECTORY.join("analysis-exports")
 }
 
+/// Returns the backups directory path
+///
+/// Contains backup archives of all databases and the config file.
+pub fn get_backups_directory() -> PathBuf {
+    BASE_DIRECTORY.join("backups")
+}
+
 // =============================================================================
 // CONFIGURATION FILE PATHS
 // =============================================================================
@@ -138,6 +147,11 @@ pub fn get_positions_db_path() -> PathBuf {
     get_data_directory().join("positions.db")
 }
 
+/// Returns the paper trading positions database path (kept separate from real positions)
+pub fn get_paper_positions_db_path() -> PathBuf {
+    get_data_directory().join("paper_positions.db")
+}
+
 /// Returns the wallet database path
 pub fn get_wallet_db_path() -> PathBuf {
     get_data_directory().join("wallet.db")
@@ -168,6 +182,11 @@ pub fn get_actions_db_path() -> PathBuf {
     get_data_directory().join("actions.db")
 }
 
+/// Returns the pending orders database path
+pub fn get_orders_db_path() -> PathBuf {
+    get_data_directory().join("orders.db")
+}
+
 /// Returns the tools database path
 pub fn get_tools_db_path() -> PathBuf {
     get_data_directory().join("tools.db")
@@ -178,6 +197,11 @@ pub fn get_ai_db_path() -> PathBuf {
     get_data_directory().join("ai.db")
 }
 
+/// Returns the wallets database path (encrypted key material)
+pub fn get_wallets_db_path() -> PathBuf {
+    get_data_directory().join("wallets.db")
+}
+
 /// Returns the AI chat database path
 pub fn get_ai_chat_db_path() -> PathBuf {
     get_data_directory().join("ai_chat.db")
@@ -212,6 +236,11 @@ pub fn get_ui_state_path() -> PathBuf {
     get_data_directory().join("ui_state.json")
 }
 
+/// Returns the pending restore file path (restore staged for the next startup)
+pub fn get_pending_restore_path() -> PathBuf {
+    get_data_directory().join("pending_restore.json")
+}
+
 /// Returns the process lock file path
 pub fn get_process_lock_path() -> PathBuf {
     get_data_directory().join(".screenerbot.lock")
@@ -262,6 +291,7 @@ pub fn get_db_with_wal_files(db_path: PathBuf) -> Vec<PathBuf> {
 /// - logs/
 /// - data/cache_pool/
 /// - analysis-exports/
+/// - backups/
 ///
 /// ## Returns
 ///
@@ -279,6 +309,7 @@ pub fn ensure_all_directories() -> Result<(), String> {
         ("logs", get_logs_directory()),
         ("cache_pool", get_cache_pool_directory()),
         ("analysis-exports", get_analysis_exports_directory()),
+        ("backups", get_backups_directory()),
     ];
 
     for (name, dir) in dirs_to_create {
@@ -446,6 +477,7 @@ mod tests {
         assert!(get_tokens_db_path().starts_with(&data));
         assert!(get_transactions_db_path().starts_with(&data));
         assert!(get_positions_db_path().starts_with(&data));
+        assert!(get_paper_positions_db_path().starts_with(&data));
         assert!(get_wallet_db_path().starts_with(&data));
         assert!(get_events_db_path().starts_with(&data));
         assert!(get_pools_db_path().starts_with(&data));
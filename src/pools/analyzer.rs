@@ -94,7 +94,11 @@ impl PoolAnalyzer {
     }
 
     /// Start analyzer background task
-    pub async fn start_analyzer_task(&self, shutdown: Arc<Notify>) {
+    pub async fn start_analyzer_task(
+        &self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> Result<tokio::task::JoinHandle<()>, String> {
         logger::info(LogTag::PoolAnalyzer, "Starting pool analyzer task");
 
         let pool_directory = self.pool_directory.clone();
@@ -107,10 +111,10 @@ impl PoolAnalyzer {
         // Take the receiver from the Arc<RwLock>
         let mut analyzer_rx = {
             let mut rx_lock = self.analyzer_rx.write().unwrap();
-            rx_lock.take().expect("Analyzer receiver already taken")
+            rx_lock.take().ok_or("Analyzer receiver already taken")?
         };
 
-        tokio::spawn(async move {
+        let handle = tokio::spawn(monitor.instrument(async move {
             logger::info(LogTag::PoolAnalyzer, "Pool analyzer task started");
 
             // Get RPC client inside the task
@@ -228,7 +232,9 @@ impl PoolAnalyzer {
             }
 
             logger::info(LogTag::PoolAnalyzer, "Pool analyzer task completed");
-        });
+        }));
+
+        Ok(handle)
     }
 
     /// Analyze a pool and extract metadata (static version for task)
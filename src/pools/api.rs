@@ -5,6 +5,7 @@
 /// implementation details are hidden.
 use super::cache;
 use super::db;
+use super::multi_pool::{self, MultiPoolPrice};
 use super::service;
 use super::types::{PoolDescriptor, PoolError, PriceResult};
 use solana_sdk::pubkey::Pubkey;
@@ -29,6 +30,18 @@ pub fn get_pool_price(mint: &str) -> Option<PriceResult> {
     cache::get_price(mint)
 }
 
+/// Get the per-pool price breakdown behind the current price
+///
+/// Only available when multi-pool pricing is enabled and at least two pools
+/// of the token have fresh prices.
+pub fn get_pool_price_breakdown(mint: &str) -> Option<MultiPoolPrice> {
+    if !service::is_pool_service_running() || !multi_pool::is_multi_pool_pricing_enabled() {
+        return None;
+    }
+
+    multi_pool::get_price_breakdown(mint)
+}
+
 /// Get pools associated with a token from the analyzer's in-memory directory.
 /// Returns a single canonical pool first (if present) followed by other pools.
 pub fn get_token_pools(mint: &str) -> Vec<PoolDescriptor> {
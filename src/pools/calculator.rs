@@ -8,6 +8,7 @@
 use super::cache;
 use super::decoders;
 use super::fetcher::{AccountData, PoolAccountBundle};
+use super::multi_pool;
 use super::types::{PoolDescriptor, PriceResult, ProgramKind};
 
 use crate::constants::{SOL_DECIMALS, SOL_MINT};
@@ -93,7 +94,11 @@ impl PriceCalculator {
     }
 
     /// Start calculator background task
-    pub async fn start_calculator_task(&self, shutdown: Arc<Notify>) {
+    pub async fn start_calculator_task(
+        &self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> Result<tokio::task::JoinHandle<()>, String> {
         logger::info(LogTag::PoolCalculator, "Starting price calculator task");
 
         let pool_directory = self.pool_directory.clone();
@@ -107,10 +112,10 @@ impl PriceCalculator {
         // Take the receiver from the Arc<RwLock>
         let mut calculator_rx = {
             let mut rx_lock = self.calculator_rx.write().unwrap();
-            rx_lock.take().expect("Calculator receiver already taken")
+            rx_lock.take().ok_or("Calculator receiver already taken")?
         };
 
-        tokio::spawn(async move {
+        let handle = tokio::spawn(monitor.instrument(async move {
             logger::info(LogTag::PoolCalculator, "Price calculator task started");
 
             loop {
@@ -161,8 +166,17 @@ impl PriceCalculator {
                                     operations.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
                                     prices_calculated.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
-                                    // Update cache with calculated price
-                                    cache::update_price(price_result.clone());
+                                    // Update cache with calculated price (liquidity-weighted across pools in multi-pool mode)
+                                    if multi_pool::is_multi_pool_pricing_enabled() {
+                                        let (aggregated, divergence) =
+                                            multi_pool::record_pool_price(price_result.clone());
+                                        cache::update_price(aggregated);
+                                        if let Some(breakdown) = divergence {
+                                            multi_pool::report_divergence(&breakdown).await;
+                                        }
+                                    } else {
+                                        cache::update_price(price_result.clone());
+                                    }
 
                                     if let Some(db) = get_global_database() {
                                         if let Err(e) = db.mark_pool_price_calculated(
@@ -261,7 +275,9 @@ impl PriceCalculator {
             }
 
             logger::info(LogTag::PoolCalculator, "Price calculator task completed");
-        });
+        }));
+
+        Ok(handle)
     }
 
     /// Calculate price for a pool (static version for task)
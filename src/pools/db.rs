@@ -6,6 +6,7 @@
 use super::types::{PriceResult, PRICE_HISTORY_MAX_ENTRIES};
 
 use crate::logger::{self, LogTag};
+use crate::migrations::{Migration, MigrationSet};
 
 use chrono::{DateTime, Utc};
 use rusqlite::{params, Connection, Row};
@@ -30,6 +31,119 @@ const DB_WRITE_INTERVAL_SECONDS: u64 = 10;
 /// Maximum allowable gap between price updates (1 minute in seconds)
 const MAX_PRICE_GAP_SECONDS: i64 = 60;
 
+// =============================================================================
+// SCHEMA MIGRATIONS
+// =============================================================================
+
+/// Ordered schema migrations of pools.db
+pub const POOLS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "pools",
+    migrations: &[Migration::apply(1, "Baseline schema", baseline_schema)],
+};
+
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    // Create price history table
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS price_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    mint TEXT NOT NULL,
+    pool_address TEXT NOT NULL,
+    price_usd REAL NOT NULL,
+    price_sol REAL NOT NULL,
+    confidence REAL NOT NULL,
+    slot INTEGER NOT NULL,
+    timestamp_unix INTEGER NOT NULL,
+    sol_reserves REAL NOT NULL,
+    token_reserves REAL NOT NULL,
+    source_pool TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE(mint, pool_address, timestamp_unix)
+  )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create price_history table: {}", e))?;
+
+    // Create indices for faster queries
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_price_history_mint_timestamp 
+   ON price_history(mint, timestamp_unix DESC)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create mint timestamp index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_price_history_pool_timestamp 
+   ON price_history(pool_address, timestamp_unix DESC)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create pool timestamp index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_price_history_created_at 
+   ON price_history(created_at)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create created_at index: {}", e))?;
+
+    // Create blacklist_accounts table
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS blacklist_accounts (
+    account_pubkey TEXT PRIMARY KEY,
+    reason TEXT NOT NULL,
+    source TEXT,
+    pool_id TEXT,
+    token_mint TEXT,
+    error_count INTEGER DEFAULT 1,
+    first_failed_at INTEGER NOT NULL,
+    last_failed_at INTEGER NOT NULL,
+    added_at INTEGER NOT NULL
+  )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create blacklist_accounts table: {}", e))?;
+
+    // Create blacklist_pools table
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS blacklist_pools (
+    pool_id TEXT PRIMARY KEY,
+    reason TEXT NOT NULL,
+    token_mint TEXT,
+    program_id TEXT,
+    error_count INTEGER DEFAULT 1,
+    first_failed_at INTEGER NOT NULL,
+    last_failed_at INTEGER NOT NULL,
+    added_at INTEGER NOT NULL
+  )",
+        [],
+    )
+    .map_err(|e| format!("Failed to create blacklist_pools table: {}", e))?;
+
+    // Create indices for blacklist tables
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_blacklist_accounts_pool 
+   ON blacklist_accounts(pool_id)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create blacklist_accounts pool index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_blacklist_accounts_token 
+   ON blacklist_accounts(token_mint)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create blacklist_accounts token index: {}", e))?;
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_blacklist_pools_token 
+   ON blacklist_pools(token_mint)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create blacklist_pools token index: {}", e))?;
+
+    Ok(())
+}
+
 // =============================================================================
 // DATABASE STRUCTURES
 // =============================================================================
@@ -237,103 +351,7 @@ impl PoolsDatabase {
         let conn = Connection::open(&self.db_path)
             .map_err(|e| format!("Failed to open pools database: {}", e))?;
 
-        // Create price history table
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS price_history (
-        id INTEGER PRIMARY KEY AUTOINCREMENT,
-        mint TEXT NOT NULL,
-        pool_address TEXT NOT NULL,
-        price_usd REAL NOT NULL,
-        price_sol REAL NOT NULL,
-        confidence REAL NOT NULL,
-        slot INTEGER NOT NULL,
-        timestamp_unix INTEGER NOT NULL,
-        sol_reserves REAL NOT NULL,
-        token_reserves REAL NOT NULL,
-        source_pool TEXT,
-        created_at TEXT NOT NULL DEFAULT (datetime('now')),
-        UNIQUE(mint, pool_address, timestamp_unix)
-      )",
-            [],
-        )
-        .map_err(|e| format!("Failed to create price_history table: {}", e))?;
-
-        // Create indices for faster queries
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_price_history_mint_timestamp 
-       ON price_history(mint, timestamp_unix DESC)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create mint timestamp index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_price_history_pool_timestamp 
-       ON price_history(pool_address, timestamp_unix DESC)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create pool timestamp index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_price_history_created_at 
-       ON price_history(created_at)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create created_at index: {}", e))?;
-
-        // Create blacklist_accounts table
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS blacklist_accounts (
-        account_pubkey TEXT PRIMARY KEY,
-        reason TEXT NOT NULL,
-        source TEXT,
-        pool_id TEXT,
-        token_mint TEXT,
-        error_count INTEGER DEFAULT 1,
-        first_failed_at INTEGER NOT NULL,
-        last_failed_at INTEGER NOT NULL,
-        added_at INTEGER NOT NULL
-      )",
-            [],
-        )
-        .map_err(|e| format!("Failed to create blacklist_accounts table: {}", e))?;
-
-        // Create blacklist_pools table
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS blacklist_pools (
-        pool_id TEXT PRIMARY KEY,
-        reason TEXT NOT NULL,
-        token_mint TEXT,
-        program_id TEXT,
-        error_count INTEGER DEFAULT 1,
-        first_failed_at INTEGER NOT NULL,
-        last_failed_at INTEGER NOT NULL,
-        added_at INTEGER NOT NULL
-      )",
-            [],
-        )
-        .map_err(|e| format!("Failed to create blacklist_pools table: {}", e))?;
-
-        // Create indices for blacklist tables
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_blacklist_accounts_pool 
-       ON blacklist_accounts(pool_id)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create blacklist_accounts pool index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_blacklist_accounts_token 
-       ON blacklist_accounts(token_mint)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create blacklist_accounts token index: {}", e))?;
-
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_blacklist_pools_token 
-       ON blacklist_pools(token_mint)",
-            [],
-        )
-        .map_err(|e| format!("Failed to create blacklist_pools token index: {}", e))?;
+        crate::migrations::run_migrations(&conn, &POOLS_MIGRATIONS)?;
 
         // Store connection
         {
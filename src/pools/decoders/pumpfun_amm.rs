@@ -10,6 +10,11 @@ use std::time::Instant;
 // Import centralized utilities
 use super::super::utils::{read_pubkey_at_offset, validate_sol_pool, PoolMintVaultInfo};
 
+/// Offset of `lp_supply` in a PumpSwap pool account: discriminator(8) + pool_bump(1) +
+/// index(2) + creator(32) + base_mint(32) + quote_mint(32) + lp_mint(32) +
+/// pool_base_token_account(32) + pool_quote_token_account(32)
+pub const LP_SUPPLY_OFFSET: usize = 8 + 1 + 2 + 32 * 6; // 203
+
 /// PumpFun AMM pool decoder and calculator
 pub struct PumpFunAmmDecoder;
 
@@ -361,13 +366,8 @@ impl PumpFunAmmDecoder {
 
 /// Extract LP supply from pool data (helper function)
 fn extract_lp_supply(data: &[u8]) -> Option<u64> {
-    // LP supply is typically at a fixed offset after all the pubkeys
-    // For PumpFun: discriminator(8) + pool_bump(1) + index(2) + creator(32) + creator(32) +
-    // base_mint(32) + quote_mint(32) + lp_mint(32) + vault1(32) + vault2(32) = 235 bytes
-    let lp_supply_offset = 8 + 1 + 2 + 32 + 32 + 32 + 32 + 32 + 32 + 32; // 235
-
-    (if data.len() >= lp_supply_offset + 8 {
-        let lp_supply_bytes = &data[lp_supply_offset..lp_supply_offset + 8];
+    (if data.len() >= LP_SUPPLY_OFFSET + 8 {
+        let lp_supply_bytes = &data[LP_SUPPLY_OFFSET..LP_SUPPLY_OFFSET + 8];
         u64::from_le_bytes(lp_supply_bytes.try_into().ok()?)
     } else {
         0
@@ -3,7 +3,8 @@
 /// This module orchestrates pool discovery for watched tokens by:
 /// 1. Building token list (filtered + position tokens)
 /// 2. Fetching pool snapshots from tokens module (which handles all caching, deduplication, selection)
-/// 3. Converting canonical pools to PoolDescriptor format
+/// 3. Converting canonical pools to PoolDescriptor format (plus the next most liquid
+///    pools when multi-pool pricing is enabled)
 /// 4. Sending to analyzer for classification
 ///
 /// All pool data fetching, caching, deduplication, and canonical selection is handled by tokens/pools module.
@@ -153,7 +154,11 @@ impl PoolDiscovery {
     }
 
     /// Start discovery background task
-    pub async fn start_discovery_task(&self, shutdown: Arc<Notify>) {
+    pub async fn start_discovery_task(
+        &self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> tokio::task::JoinHandle<()> {
         logger::info(LogTag::PoolDiscovery, "Starting pool discovery task");
 
         Self::log_source_config();
@@ -164,7 +169,7 @@ impl PoolDiscovery {
         let errors = Arc::clone(&self.errors);
         let pools_discovered = Arc::clone(&self.pools_discovered);
 
-        tokio::spawn(async move {
+        tokio::spawn(monitor.instrument(async move {
             let mut current_interval = interval_seed;
             let mut interval =
                 tokio::time::interval(tokio::time::Duration::from_secs(current_interval));
@@ -194,7 +199,7 @@ impl PoolDiscovery {
                   }
                 }
             }
-        });
+        }))
     }
 
     /// Execute one batched discovery tick: fetch canonical pools from tokens module and stream to analyzer
@@ -243,7 +248,16 @@ impl PoolDiscovery {
         };
 
         // Always include tokens with open positions for price monitoring
-        let open_position_mints: Vec<String> = crate::positions::get_open_mints().await;
+        let mut open_position_mints: Vec<String> = crate::positions::get_open_mints().await;
+
+        // Pending buy orders need prices even though no position exists yet
+        if let Ok(order_mints) = crate::trader::orders::get_pending_order_mints(true) {
+            for mint in order_mints {
+                if !open_position_mints.contains(&mint) {
+                    open_position_mints.push(mint);
+                }
+            }
+        }
         let initial_count = tokens.len();
 
         let mut token_set: std::collections::HashSet<String> = tokens.iter().cloned().collect();
@@ -312,6 +326,15 @@ impl PoolDiscovery {
         let mut tokens_with_pools = 0;
         let mut blacklist_filtered = 0;
 
+        let (multi_pool_enabled, multi_pool_max_pools, multi_pool_min_liquidity) =
+            with_config(|cfg| {
+                (
+                    cfg.pools.multi_pool_pricing_enabled,
+                    cfg.pools.multi_pool_max_pools,
+                    cfg.pools.multi_pool_min_liquidity_usd,
+                )
+            });
+
         if let Some(analyzer) = get_pool_analyzer() {
             let sender = analyzer.get_sender();
 
@@ -455,6 +478,58 @@ impl PoolDiscovery {
                     volume_h24_usd: canonical_pool.volume_h24.unwrap_or(0.0),
                 });
                 sent_count += 1;
+
+                // Multi-pool pricing: also decode the next most liquid SOL pools
+                if multi_pool_enabled {
+                    let mut extra_pools: Vec<_> = snapshot
+                        .pools
+                        .iter()
+                        .filter(|p| {
+                            p.is_sol_pair
+                                && p.pool_address != canonical_address
+                                && p.liquidity_usd.unwrap_or(0.0) >= multi_pool_min_liquidity
+                        })
+                        .collect();
+                    extra_pools.sort_by(|a, b| {
+                        b.liquidity_usd
+                            .unwrap_or(0.0)
+                            .partial_cmp(&a.liquidity_usd.unwrap_or(0.0))
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    });
+
+                    for pool in extra_pools
+                        .into_iter()
+                        .take(multi_pool_max_pools.saturating_sub(1))
+                    {
+                        let (Ok(extra_id), Ok(extra_base), Ok(extra_quote)) = (
+                            Pubkey::from_str(&pool.pool_address),
+                            Pubkey::from_str(&pool.base_mint),
+                            Pubkey::from_str(&pool.quote_mint),
+                        ) else {
+                            continue;
+                        };
+
+                        // Already analyzed pools are refreshed by the fetcher
+                        if analyzer.get_pool(&extra_id).is_some() {
+                            continue;
+                        }
+
+                        if let Ok(true) = super::db::is_pool_blacklisted(&pool.pool_address).await {
+                            blacklist_filtered += 1;
+                            continue;
+                        }
+
+                        let _ = sender.send(crate::pools::analyzer::AnalyzerMessage::AnalyzePool {
+                            pool_id: extra_id,
+                            program_id: Pubkey::default(),
+                            base_mint: extra_base,
+                            quote_mint: extra_quote,
+                            liquidity_usd: pool.liquidity_usd.unwrap_or(0.0),
+                            volume_h24_usd: pool.volume_h24.unwrap_or(0.0),
+                        });
+                        sent_count += 1;
+                    }
+                }
             }
 
             record_safe(Event::info(
@@ -197,7 +197,11 @@ impl AccountFetcher {
     }
 
     /// Start fetcher background task
-    pub async fn start_fetcher_task(&self, shutdown: Arc<Notify>) {
+    pub async fn start_fetcher_task(
+        &self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> Result<tokio::task::JoinHandle<()>, String> {
         logger::info(LogTag::PoolFetcher, "Starting account fetcher task");
 
         let pool_directory = self.pool_directory.clone();
@@ -213,10 +217,10 @@ impl AccountFetcher {
         // Take the receiver from the Arc<RwLock>
         let mut fetcher_rx = {
             let mut rx_lock = self.fetcher_rx.write().unwrap();
-            rx_lock.take().expect("Fetcher receiver already taken")
+            rx_lock.take().ok_or("Fetcher receiver already taken")?
         };
 
-        tokio::spawn(async move {
+        let handle = tokio::spawn(monitor.instrument(async move {
             let mut interval =
                 tokio::time::interval(tokio::time::Duration::from_millis(FETCH_INTERVAL_MS));
             let mut pending_accounts: HashSet<Pubkey> = HashSet::new();
@@ -302,7 +306,9 @@ impl AccountFetcher {
             }
 
             logger::info(LogTag::PoolFetcher, "Account fetcher task completed");
-        });
+        }));
+
+        Ok(handle)
     }
 
     /// Add stale accounts from pools to pending fetch list
@@ -606,6 +612,7 @@ impl AccountFetcher {
                         &account_data_list,
                         pool_directory,
                         account_bundles,
+                        false,
                     )
                     .await;
 
@@ -933,10 +940,14 @@ impl AccountFetcher {
     /// 1. Build updates in local HashMap (no locks held)
     /// 2. Apply updates to shared state (brief write lock)
     /// 3. Trigger calculations (after releasing lock)
+    ///
+    /// With `force_calculation`, every complete bundle touched by the update is recalculated
+    /// (used for streamed updates, where each notification is a new on-chain state).
     async fn organize_accounts_into_bundles(
         account_data_list: &[AccountData],
         pool_directory: &Arc<RwLock<HashMap<Pubkey, PoolDescriptor>>>,
         account_bundles: &Arc<RwLock<HashMap<Pubkey, PoolAccountBundle>>>,
+        force_calculation: bool,
     ) {
         // Phase 1: Snapshot pools (brief read lock)
         let pools = {
@@ -996,10 +1007,14 @@ impl AccountFetcher {
                     );
 
                     // Check if bundle is now complete
-                    if entry
-                        .0
-                        .is_complete_and_needs_calculation(&pool_descriptor.reserve_accounts)
-                    {
+                    let needs_calculation = if force_calculation {
+                        entry.0.is_complete(&pool_descriptor.reserve_accounts)
+                    } else {
+                        entry
+                            .0
+                            .is_complete_and_needs_calculation(&pool_descriptor.reserve_accounts)
+                    };
+                    if needs_calculation {
                         entry.0.mark_calculation_requested();
                         entry.2 = true; // Mark needs calculation
                     }
@@ -1066,6 +1081,41 @@ impl AccountFetcher {
         Ok(())
     }
 
+    /// Public interface: Apply account updates pushed by the account streamer
+    ///
+    /// Streamed accounts count as freshly fetched, so the poller skips them while the
+    /// stream is healthy and picks them up again once updates stop arriving.
+    pub async fn apply_streamed_accounts(&self, accounts: Vec<AccountData>) {
+        if accounts.is_empty() {
+            return;
+        }
+
+        {
+            let mut last_fetch = self.account_last_fetch.write().unwrap();
+            let now = Instant::now();
+            for account in &accounts {
+                last_fetch.insert(account.pubkey, now);
+            }
+        }
+
+        self.accounts_fetched
+            .fetch_add(accounts.len() as u64, std::sync::atomic::Ordering::Relaxed);
+
+        Self::organize_accounts_into_bundles(
+            &accounts,
+            &self.pool_directory,
+            &self.account_bundles,
+            true,
+        )
+        .await;
+    }
+
+    /// Snapshot of all pools currently in the directory
+    pub fn get_pool_directory_snapshot(&self) -> Vec<PoolDescriptor> {
+        let directory = self.pool_directory.read().unwrap();
+        directory.values().cloned().collect()
+    }
+
     /// Get account bundle for a specific pool
     pub fn get_pool_bundle(&self, pool_id: &Pubkey) -> Option<PoolAccountBundle> {
         let bundles = self.account_bundles.read().unwrap();
@@ -8,6 +8,7 @@
 /// - get_pool_price(mint) -> Get current price for a token
 /// - get_available_tokens() -> Get list of tokens with available prices
 /// - get_price_history(mint) -> Get price history for a token
+/// - get_pool_price_breakdown(mint) -> Get per-pool prices when multi-pool pricing is enabled
 use std::sync::Arc;
 use tokio::sync::Notify;
 
@@ -16,6 +17,8 @@ mod api;
 mod cache;
 mod calculator;
 mod discovery;
+mod multi_pool;
+mod streamer;
 
 // Re-export db types for blacklist API
 pub mod db;
@@ -30,16 +33,18 @@ pub mod types;
 pub mod utils;
 
 pub use api::{
-    get_available_tokens, get_cache_stats, get_pool_price, get_price_history, get_token_pools,
+    get_available_tokens, get_cache_stats, get_pool_price, get_pool_price_breakdown,
+    get_price_history, get_token_pools,
 };
 pub use discovery::{
     is_dexscreener_discovery_enabled, is_geckoterminal_discovery_enabled,
     is_raydium_discovery_enabled, PoolDiscovery,
 };
 pub use fetcher::AccountData;
+pub use multi_pool::{MultiPoolPrice, PoolPricePoint};
 pub use service::{
-    get_account_fetcher, get_debug_token_override, get_pool_analyzer, get_pool_discovery,
-    get_price_calculator, initialize_pool_components, is_pool_service_running,
+    get_account_fetcher, get_account_streamer, get_debug_token_override, get_pool_analyzer,
+    get_pool_discovery, get_price_calculator, initialize_pool_components, is_pool_service_running,
     is_single_pool_mode_enabled, set_debug_token_override, start_helper_tasks, stop_pool_service,
 };
 pub use types::{PoolError, PriceResult};
@@ -0,0 +1,361 @@
+/// Multi-pool pricing
+///
+/// When multi-pool pricing is enabled the calculator feeds every decoded pool price
+/// through this module instead of writing it to the cache directly:
+/// - Keeps the latest price per (mint, pool)
+/// - Computes a liquidity-weighted price (weight = SOL reserves) over fresh pools
+/// - Rejects pools too far from the weighted median so a thin or manipulated
+///   pool cannot drag the price
+/// - Detects divergence between pools and rate-limits alerts per mint
+use super::types::{price_cache_ttl_seconds, PriceResult};
+
+use crate::config::with_config;
+use crate::events::{record_safe, Event, EventCategory};
+use crate::telegram::{queue_notification, Notification};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Minimum time between divergence alerts for the same mint
+const DIVERGENCE_ALERT_COOLDOWN: Duration = Duration::from_secs(300);
+
+/// Latest decoded price per mint, keyed by pool address
+static POOL_PRICES: Lazy<DashMap<String, HashMap<String, PriceResult>>> = Lazy::new(DashMap::new);
+
+/// Last computed breakdown per mint
+static BREAKDOWNS: Lazy<DashMap<String, MultiPoolPrice>> = Lazy::new(DashMap::new);
+
+/// Last divergence alert per mint
+static LAST_DIVERGENCE_ALERT: Lazy<DashMap<String, Instant>> = Lazy::new(DashMap::new);
+
+/// Price contribution of a single pool
+#[derive(Debug, Clone, Serialize)]
+pub struct PoolPricePoint {
+    pub pool_address: String,
+    pub source: Option<String>,
+    pub price_sol: f64,
+    pub price_usd: f64,
+    pub sol_reserves: f64,
+    /// Share of the weighted price (0 for outliers)
+    pub weight_pct: f64,
+    /// Deviation from the aggregated price
+    pub deviation_pct: f64,
+    pub is_outlier: bool,
+    pub age_secs: u64,
+}
+
+/// Aggregated price and per-pool breakdown for a mint
+#[derive(Debug, Clone, Serialize)]
+pub struct MultiPoolPrice {
+    pub mint: String,
+    pub price_sol: f64,
+    pub price_usd: f64,
+    pub pools_used: usize,
+    pub pools_rejected: usize,
+    /// Largest deviation of any fresh pool from the aggregated price
+    pub divergence_pct: f64,
+    pub pools: Vec<PoolPricePoint>,
+}
+
+/// Whether multi-pool pricing is enabled
+pub fn is_multi_pool_pricing_enabled() -> bool {
+    with_config(|cfg| cfg.pools.multi_pool_pricing_enabled)
+}
+
+/// Latest multi-pool breakdown for a mint (None when fewer than two pools are fresh)
+pub fn get_price_breakdown(mint: &str) -> Option<MultiPoolPrice> {
+    BREAKDOWNS.get(mint).map(|entry| entry.clone())
+}
+
+/// Weighted median of (price, weight) pairs
+fn weighted_median(points: &[(f64, f64)]) -> Option<f64> {
+    let mut sorted: Vec<(f64, f64)> = points
+        .iter()
+        .copied()
+        .filter(|(price, weight)| price.is_finite() && *price > 0.0 && weight.is_finite())
+        .collect();
+    if sorted.is_empty() {
+        return None;
+    }
+    sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let total: f64 = sorted.iter().map(|(_, w)| w.max(0.0)).sum();
+    if total <= 0.0 {
+        return Some(sorted[sorted.len() / 2].0);
+    }
+
+    let mut cumulative = 0.0;
+    for (price, weight) in &sorted {
+        cumulative += weight.max(0.0);
+        if cumulative >= total / 2.0 {
+            return Some(*price);
+        }
+    }
+    sorted.last().map(|(price, _)| *price)
+}
+
+/// Liquidity-weighted price with outlier rejection.
+///
+/// Returns the weighted mean of pools within `outlier_pct` of the weighted median,
+/// together with an outlier flag per input point.
+pub fn weighted_price(points: &[(f64, f64)], outlier_pct: f64) -> Option<(f64, Vec<bool>)> {
+    let median = weighted_median(points)?;
+
+    let outliers: Vec<bool> = points
+        .iter()
+        .map(|(price, _)| {
+            !price.is_finite() || *price <= 0.0 || deviation_pct(*price, median) > outlier_pct
+        })
+        .collect();
+
+    let (sum, weight_sum) = points
+        .iter()
+        .zip(outliers.iter())
+        .filter(|(_, outlier)| !**outlier)
+        .fold((0.0, 0.0), |(sum, weight_sum), ((price, weight), _)| {
+            let weight = weight.max(0.0);
+            (sum + price * weight, weight_sum + weight)
+        });
+
+    let price = if weight_sum > 0.0 {
+        sum / weight_sum
+    } else {
+        median
+    };
+
+    Some((price, outliers))
+}
+
+fn deviation_pct(price: f64, reference: f64) -> f64 {
+    if reference <= 0.0 {
+        return 0.0;
+    }
+    ((price - reference) / reference).abs() * 100.0
+}
+
+fn liquidity_usd(price: &PriceResult) -> f64 {
+    if price.price_sol > 0.0 && price.price_usd > 0.0 {
+        price.sol_reserves * 2.0 * (price.price_usd / price.price_sol)
+    } else {
+        0.0
+    }
+}
+
+/// Record a decoded pool price and return the price to publish for its mint.
+///
+/// The second value is set when the pools diverge beyond the configured threshold
+/// and the per-mint alert cooldown has elapsed.
+pub fn record_pool_price(price: PriceResult) -> (PriceResult, Option<MultiPoolPrice>) {
+    let (outlier_pct, min_liquidity_usd, alert_pct) = with_config(|cfg| {
+        (
+            cfg.pools.multi_pool_outlier_pct,
+            cfg.pools.multi_pool_min_liquidity_usd,
+            cfg.pools.multi_pool_divergence_alert_pct,
+        )
+    });
+    let ttl = price_cache_ttl_seconds();
+    let mint = price.mint.clone();
+
+    let fresh: Vec<PriceResult> = {
+        let mut entry = POOL_PRICES.entry(mint.clone()).or_default();
+        entry.insert(price.pool_address.clone(), price.clone());
+        entry.retain(|_, p| p.timestamp.elapsed().as_secs() < ttl * 2);
+        entry
+            .values()
+            .filter(|p| p.timestamp.elapsed().as_secs() < ttl)
+            .cloned()
+            .collect()
+    };
+
+    // Thin pools only count when nothing else is available
+    let mut pools: Vec<PriceResult> = fresh
+        .iter()
+        .filter(|p| liquidity_usd(p) >= min_liquidity_usd)
+        .cloned()
+        .collect();
+    if pools.is_empty() {
+        pools = fresh;
+    }
+
+    if pools.len() < 2 {
+        BREAKDOWNS.remove(&mint);
+        return (price, None);
+    }
+
+    let points: Vec<(f64, f64)> = pools
+        .iter()
+        .map(|p| (p.price_sol, p.sol_reserves))
+        .collect();
+    let Some((price_sol, outliers)) = weighted_price(&points, outlier_pct) else {
+        return (price, None);
+    };
+
+    let inlier_weight: f64 = pools
+        .iter()
+        .zip(outliers.iter())
+        .filter(|(_, outlier)| !**outlier)
+        .map(|(p, _)| p.sol_reserves.max(0.0))
+        .sum();
+
+    // Reserves and pool address come from the deepest pool that was kept
+    let anchor = pools
+        .iter()
+        .zip(outliers.iter())
+        .filter(|(_, outlier)| !**outlier)
+        .map(|(p, _)| p)
+        .max_by(|a, b| {
+            a.sol_reserves
+                .partial_cmp(&b.sol_reserves)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap_or(&price);
+
+    let sol_price_usd = if anchor.price_sol > 0.0 {
+        anchor.price_usd / anchor.price_sol
+    } else {
+        0.0
+    };
+
+    let mut aggregated = anchor.clone();
+    aggregated.price_sol = price_sol;
+    aggregated.price_usd = price_sol * sol_price_usd;
+    aggregated.timestamp = price.timestamp;
+    aggregated.slot = price.slot.max(anchor.slot);
+
+    let breakdown_pools: Vec<PoolPricePoint> = pools
+        .iter()
+        .zip(outliers.iter())
+        .map(|(p, outlier)| PoolPricePoint {
+            pool_address: p.pool_address.clone(),
+            source: p.source_pool.clone(),
+            price_sol: p.price_sol,
+            price_usd: p.price_usd,
+            sol_reserves: p.sol_reserves,
+            weight_pct: if *outlier || inlier_weight <= 0.0 {
+                0.0
+            } else {
+                p.sol_reserves.max(0.0) / inlier_weight * 100.0
+            },
+            deviation_pct: deviation_pct(p.price_sol, price_sol),
+            is_outlier: *outlier,
+            age_secs: p.timestamp.elapsed().as_secs(),
+        })
+        .collect();
+
+    let divergence_pct = breakdown_pools
+        .iter()
+        .map(|p| p.deviation_pct)
+        .fold(0.0, f64::max);
+    let pools_rejected = outliers.iter().filter(|o| **o).count();
+
+    let breakdown = MultiPoolPrice {
+        mint: mint.clone(),
+        price_sol,
+        price_usd: aggregated.price_usd,
+        pools_used: pools.len() - pools_rejected,
+        pools_rejected,
+        divergence_pct,
+        pools: breakdown_pools,
+    };
+    BREAKDOWNS.insert(mint.clone(), breakdown.clone());
+
+    let alert = if divergence_pct > alert_pct {
+        let now = Instant::now();
+        let due = LAST_DIVERGENCE_ALERT
+            .get(&mint)
+            .map(|last| now.duration_since(*last) >= DIVERGENCE_ALERT_COOLDOWN)
+            .unwrap_or(true);
+        if due {
+            LAST_DIVERGENCE_ALERT.insert(mint, now);
+            Some(breakdown)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    (aggregated, alert)
+}
+
+/// Record a divergence event and optionally alert via Telegram
+pub async fn report_divergence(breakdown: &MultiPoolPrice) {
+    record_safe(Event::warn(
+        EventCategory::Pool,
+        Some("pool_price_divergence".to_string()),
+        Some(breakdown.mint.clone()),
+        None,
+        serde_json::json!({
+            "mint": breakdown.mint,
+            "price_sol": breakdown.price_sol,
+            "divergence_pct": breakdown.divergence_pct,
+            "pools_used": breakdown.pools_used,
+            "pools_rejected": breakdown.pools_rejected,
+            "pools": breakdown.pools,
+        }),
+    ))
+    .await;
+
+    if !with_config(|cfg| cfg.pools.multi_pool_divergence_telegram) {
+        return;
+    }
+
+    let symbol = crate::tokens::get_token_async(&breakdown.mint)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|token| token.symbol)
+        .unwrap_or_else(|| breakdown.mint.chars().take(8).collect());
+
+    let details = breakdown
+        .pools
+        .iter()
+        .map(|p| {
+            format!(
+                "{} {:.10} SOL ({:+.2}%){}",
+                p.source.as_deref().unwrap_or("pool"),
+                p.price_sol,
+                (p.price_sol - breakdown.price_sol) / breakdown.price_sol * 100.0,
+                if p.is_outlier { " outlier" } else { "" }
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    queue_notification(Notification::price_divergence(
+        symbol,
+        breakdown.mint.clone(),
+        breakdown.divergence_pct,
+        details,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weighted_price_rejects_thin_outlier() {
+        // Two deep pools agree, a thin pool is 50% off
+        let points = vec![(1.00, 100.0), (1.02, 80.0), (1.50, 2.0)];
+        let (price, outliers) = weighted_price(&points, 10.0).unwrap();
+
+        assert_eq!(outliers, vec![false, false, true]);
+        let expected = (1.00 * 100.0 + 1.02 * 80.0) / 180.0;
+        assert!((price - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn weighted_median_follows_liquidity() {
+        // The deep pool dominates even when outnumbered
+        let points = vec![(2.0, 1.0), (2.1, 1.0), (1.0, 50.0)];
+        assert_eq!(weighted_median(&points), Some(1.0));
+
+        let (price, outliers) = weighted_price(&points, 10.0).unwrap();
+        assert_eq!(outliers, vec![true, true, false]);
+        assert_eq!(price, 1.0);
+    }
+}
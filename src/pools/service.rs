@@ -6,6 +6,7 @@ use super::analyzer::PoolAnalyzer;
 use super::calculator::PriceCalculator;
 use super::discovery::{is_dexscreener_discovery_enabled, PoolDiscovery};
 use super::fetcher::AccountFetcher;
+use super::streamer::AccountStreamer;
 use super::types::max_watched_tokens;
 use super::{cache, db, PoolError};
 
@@ -45,6 +46,8 @@ static POOL_ANALYZER: Lazy<RwLock<Option<Arc<PoolAnalyzer>>>> = Lazy::new(|| RwL
 static ACCOUNT_FETCHER: Lazy<RwLock<Option<Arc<AccountFetcher>>>> = Lazy::new(|| RwLock::new(None));
 static PRICE_CALCULATOR: Lazy<RwLock<Option<Arc<PriceCalculator>>>> =
     Lazy::new(|| RwLock::new(None));
+static ACCOUNT_STREAMER: Lazy<RwLock<Option<Arc<AccountStreamer>>>> =
+    Lazy::new(|| RwLock::new(None));
 
 // Public accessors for service manager (used by individual service implementations)
 pub fn get_pool_discovery() -> Option<Arc<PoolDiscovery>> {
@@ -63,6 +66,10 @@ pub fn get_pool_analyzer() -> Option<Arc<PoolAnalyzer>> {
     POOL_ANALYZER.read().ok()?.clone()
 }
 
+pub fn get_account_streamer() -> Option<Arc<AccountStreamer>> {
+    ACCOUNT_STREAMER.read().ok()?.clone()
+}
+
 /// Initialize pool components only (no background tasks)
 ///
 /// This function initializes the pool service components (database, cache, RPC client, components)
@@ -301,6 +308,9 @@ pub async fn stop_pool_service(timeout_seconds: u64) -> Result<(), PoolError> {
             if let Ok(mut calculator) = PRICE_CALCULATOR.write() {
                 *calculator = None;
             }
+            if let Ok(mut streamer) = ACCOUNT_STREAMER.write() {
+                *streamer = None;
+            }
 
             logger::info(LogTag::PoolService, "Pool service stopped successfully");
 
@@ -445,6 +455,7 @@ async fn initialize_service_components() -> Result<(), String> {
     let pool_analyzer = Arc::new(PoolAnalyzer::new(pool_directory.clone()));
     let account_fetcher = Arc::new(AccountFetcher::new(pool_directory.clone()));
     let price_calculator = Arc::new(PriceCalculator::new(pool_directory.clone()));
+    let account_streamer = Arc::new(AccountStreamer::new(account_fetcher.clone()));
 
     // Store components globally using thread-safe RwLock pattern
     if let Ok(mut discovery) = POOL_DISCOVERY.write() {
@@ -459,6 +470,9 @@ async fn initialize_service_components() -> Result<(), String> {
     if let Ok(mut calculator) = PRICE_CALCULATOR.write() {
         *calculator = Some(price_calculator);
     }
+    if let Ok(mut streamer) = ACCOUNT_STREAMER.write() {
+        *streamer = Some(account_streamer);
+    }
 
     logger::debug(LogTag::PoolService, "Service components initialized");
 
@@ -468,7 +482,7 @@ async fn initialize_service_components() -> Result<(), String> {
         None,
         None,
         serde_json::json!({
-          "components": ["pool_discovery", "pool_analyzer", "account_fetcher", "price_calculator"],
+          "components": ["pool_discovery", "pool_analyzer", "account_fetcher", "price_calculator", "account_streamer"],
           "rpc_urls_count": rpc_urls_count,
           "status": "ready"
         }),
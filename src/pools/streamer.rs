@@ -0,0 +1,666 @@
+/// Account streamer module
+///
+/// Keeps live `accountSubscribe` streams for the reserve accounts of pools backing open
+/// positions (and optionally the most liquid watched tokens). Every notification goes
+/// straight into the fetcher's account bundles and on to the price calculator, so exits
+/// react within a slot instead of a poll cycle.
+///
+/// Polling stays as the fallback: streamed accounts are marked fresh on every update, so
+/// the fetcher skips them while the stream is healthy and picks them up again as soon as
+/// updates stop (connection drop, reconnect backoff, rejected subscription).
+use super::fetcher::{AccountData, AccountFetcher};
+use super::types::PoolDescriptor;
+use super::utils::is_sol_mint;
+
+use crate::config::with_config;
+use crate::events::{record_safe, Event, EventCategory};
+use crate::logger::{self, LogTag};
+use crate::rpc::{create_account_subscribe_payload_base64, create_account_unsubscribe_payload};
+
+use base64::Engine;
+use futures_util::{SinkExt, StreamExt};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+/// How often the set of streamed accounts is re-evaluated
+const TARGET_REFRESH_SECS: u64 = 5;
+/// Ping interval to keep the connection alive
+const HEARTBEAT_SECS: u64 = 15;
+/// A connection with no inbound traffic (not even pongs) for this long is considered dead
+const STREAM_SILENCE_TIMEOUT_SECS: u64 = 60;
+/// Reconnect backoff bounds
+const RECONNECT_BASE_DELAY_MS: u64 = 1_000;
+const RECONNECT_MAX_DELAY_MS: u64 = 30_000;
+
+/// Why a streaming connection ended
+enum ConnectionEnd {
+    Shutdown,
+    Dropped,
+}
+
+/// In-flight JSON-RPC request on the stream
+enum PendingRequest {
+    Subscribe(Pubkey),
+    Unsubscribe,
+}
+
+/// Subscription bookkeeping for a single WebSocket connection
+#[derive(Default)]
+struct StreamSession {
+    next_request_id: u64,
+    pending: HashMap<u64, PendingRequest>,
+    /// Subscription ID -> account
+    subscriptions: HashMap<u64, Pubkey>,
+    /// Account -> subscription ID
+    by_account: HashMap<Pubkey, u64>,
+    /// Accounts that should be streamed (subscribed or subscription in flight)
+    requested: HashSet<Pubkey>,
+}
+
+impl StreamSession {
+    fn next_id(&mut self) -> u64 {
+        self.next_request_id += 1;
+        self.next_request_id
+    }
+
+    /// Bring subscriptions in line with the target set, returning payloads to send
+    fn sync_targets(&mut self, targets: &HashSet<Pubkey>) -> Vec<String> {
+        let mut payloads = Vec::new();
+
+        for account in targets {
+            if self.requested.insert(*account) {
+                let id = self.next_id();
+                self.pending.insert(id, PendingRequest::Subscribe(*account));
+                payloads.push(create_account_subscribe_payload_base64(
+                    &account.to_string(),
+                    id,
+                ));
+            }
+        }
+
+        let removed: Vec<Pubkey> = self
+            .requested
+            .iter()
+            .filter(|account| !targets.contains(account))
+            .copied()
+            .collect();
+
+        for account in removed {
+            self.requested.remove(&account);
+            // Subscriptions still in flight are dropped when their confirmation arrives
+            if let Some(subscription_id) = self.by_account.remove(&account) {
+                self.subscriptions.remove(&subscription_id);
+                payloads.push(self.unsubscribe_payload(subscription_id));
+            }
+        }
+
+        payloads
+    }
+
+    fn unsubscribe_payload(&mut self, subscription_id: u64) -> String {
+        let id = self.next_id();
+        self.pending.insert(id, PendingRequest::Unsubscribe);
+        create_account_unsubscribe_payload(subscription_id, id)
+    }
+
+    /// Handle an inbound text frame, returning account updates and payloads to send
+    fn handle_message(&mut self, text: &str) -> (Option<AccountData>, Vec<String>) {
+        let value: serde_json::Value = match serde_json::from_str(text) {
+            Ok(value) => value,
+            Err(_) => return (None, Vec::new()),
+        };
+
+        // Responses to our subscribe/unsubscribe requests
+        if let Some(id) = value.get("id").and_then(|v| v.as_u64()) {
+            let mut payloads = Vec::new();
+            match self.pending.remove(&id) {
+                Some(PendingRequest::Subscribe(account)) => {
+                    match value.get("result").and_then(|v| v.as_u64()) {
+                        Some(subscription_id) if self.requested.contains(&account) => {
+                            self.subscriptions.insert(subscription_id, account);
+                            self.by_account.insert(account, subscription_id);
+                        }
+                        Some(subscription_id) => {
+                            // Target removed while the subscription was in flight
+                            payloads.push(self.unsubscribe_payload(subscription_id));
+                        }
+                        None => {
+                            logger::warning(
+                                LogTag::PoolFetcher,
+                                &format!(
+                                    "accountSubscribe rejected for {}: {}",
+                                    account,
+                                    value.get("error").cloned().unwrap_or_default()
+                                ),
+                            );
+                            // Retried on the next target refresh; polling covers it meanwhile
+                            self.requested.remove(&account);
+                        }
+                    }
+                }
+                Some(PendingRequest::Unsubscribe) | None => {}
+            }
+            return (None, payloads);
+        }
+
+        if value.get("method").and_then(|v| v.as_str()) != Some("accountNotification") {
+            return (None, Vec::new());
+        }
+
+        let params = match value.get("params") {
+            Some(params) => params,
+            None => return (None, Vec::new()),
+        };
+        let account = match params
+            .get("subscription")
+            .and_then(|v| v.as_u64())
+            .and_then(|id| self.subscriptions.get(&id))
+        {
+            Some(account) => *account,
+            None => return (None, Vec::new()),
+        };
+
+        (Self::parse_notification(account, params), Vec::new())
+    }
+
+    /// Decode an `accountNotification` result into account data
+    fn parse_notification(account: Pubkey, params: &serde_json::Value) -> Option<AccountData> {
+        let result = params.get("result")?;
+        let slot = result
+            .get("context")
+            .and_then(|c| c.get("slot"))
+            .and_then(|s| s.as_u64())
+            .unwrap_or(0);
+
+        // A null value means the account was closed; the poller handles missing accounts
+        let value = result.get("value").filter(|v| !v.is_null())?;
+        let encoded = value.get("data")?.get(0)?.as_str()?;
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .ok()?;
+        let owner = Pubkey::from_str(value.get("owner")?.as_str()?).ok()?;
+        let lamports = value.get("lamports")?.as_u64()?;
+
+        Some(AccountData {
+            pubkey: account,
+            data,
+            slot,
+            fetched_at: Instant::now(),
+            lamports,
+            owner,
+        })
+    }
+}
+
+/// WebSocket subscription manager for pool accounts
+pub struct AccountStreamer {
+    fetcher: Arc<AccountFetcher>,
+    connected: Arc<AtomicBool>,
+    /// No accounts to stream (no open positions and no top-token streaming)
+    idle: Arc<AtomicBool>,
+    /// Metrics
+    notifications: Arc<AtomicU64>,
+    reconnects: Arc<AtomicU64>,
+    active_subscriptions: Arc<AtomicU64>,
+}
+
+impl AccountStreamer {
+    /// Create new account streamer feeding the given fetcher
+    pub fn new(fetcher: Arc<AccountFetcher>) -> Self {
+        Self {
+            fetcher,
+            connected: Arc::new(AtomicBool::new(false)),
+            idle: Arc::new(AtomicBool::new(true)),
+            notifications: Arc::new(AtomicU64::new(0)),
+            reconnects: Arc::new(AtomicU64::new(0)),
+            active_subscriptions: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Get metrics for this streamer instance (notifications, reconnects, active subscriptions)
+    pub fn get_metrics(&self) -> (u64, u64, u64) {
+        (
+            self.notifications.load(Ordering::Relaxed),
+            self.reconnects.load(Ordering::Relaxed),
+            self.active_subscriptions.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Whether the WebSocket connection is currently open
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Whether there is currently nothing to stream
+    pub fn is_idle(&self) -> bool {
+        self.idle.load(Ordering::Relaxed)
+    }
+
+    /// Start streamer background task
+    pub async fn start_streamer_task(
+        &self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> tokio::task::JoinHandle<()> {
+        logger::info(LogTag::PoolFetcher, "Starting account streamer task");
+
+        let fetcher = self.fetcher.clone();
+        let connected = self.connected.clone();
+        let idle = self.idle.clone();
+        let notifications = self.notifications.clone();
+        let reconnects = self.reconnects.clone();
+        let active_subscriptions = self.active_subscriptions.clone();
+
+        tokio::spawn(monitor.instrument(async move {
+            let mut delay_ms = RECONNECT_BASE_DELAY_MS;
+
+            loop {
+                // Nothing to stream yet - check again later without opening a connection
+                let has_targets = !Self::collect_target_accounts(&fetcher).await.is_empty();
+                idle.store(!has_targets, Ordering::Relaxed);
+                if !has_targets {
+                    tokio::select! {
+                        _ = shutdown.notified() => break,
+                        _ = tokio::time::sleep(Duration::from_secs(TARGET_REFRESH_SECS)) => continue,
+                    }
+                }
+
+                let ws_url = match crate::rpc::get_websocket_url() {
+                    Ok(url) => url,
+                    Err(e) => {
+                        logger::warning(
+                            LogTag::PoolFetcher,
+                            &format!("Account streaming unavailable: {}", e),
+                        );
+                        tokio::select! {
+                            _ = shutdown.notified() => break,
+                            _ = tokio::time::sleep(Duration::from_millis(RECONNECT_MAX_DELAY_MS)) => continue,
+                        }
+                    }
+                };
+
+                let connection_start = Instant::now();
+                let outcome = Self::run_connection(
+                    &ws_url,
+                    &fetcher,
+                    shutdown.clone(),
+                    &connected,
+                    &notifications,
+                    &active_subscriptions,
+                )
+                .await;
+
+                connected.store(false, Ordering::Relaxed);
+                active_subscriptions.store(0, Ordering::Relaxed);
+
+                let reason = match outcome {
+                    Ok(ConnectionEnd::Shutdown) => break,
+                    Ok(ConnectionEnd::Dropped) => "connection closed".to_string(),
+                    Err(e) => e,
+                };
+
+                reconnects.fetch_add(1, Ordering::Relaxed);
+
+                // A connection that stayed up for a while resets the backoff
+                if connection_start.elapsed() > Duration::from_millis(RECONNECT_MAX_DELAY_MS) {
+                    delay_ms = RECONNECT_BASE_DELAY_MS;
+                }
+
+                logger::warning(
+                    LogTag::PoolFetcher,
+                    &format!(
+                        "Account stream dropped ({}), polling fallback active - reconnecting in {}ms",
+                        reason, delay_ms
+                    ),
+                );
+
+                record_safe(Event::warn(
+                    EventCategory::Pool,
+                    Some("account_stream_dropped".to_string()),
+                    None,
+                    None,
+                    serde_json::json!({
+                        "reason": reason,
+                        "reconnect_delay_ms": delay_ms,
+                        "uptime_secs": connection_start.elapsed().as_secs(),
+                    }),
+                ))
+                .await;
+
+                tokio::select! {
+                    _ = shutdown.notified() => break,
+                    _ = tokio::time::sleep(Duration::from_millis(delay_ms)) => {}
+                }
+
+                delay_ms = (delay_ms * 2).min(RECONNECT_MAX_DELAY_MS);
+            }
+
+            logger::info(LogTag::PoolFetcher, "Account streamer task completed");
+        }))
+    }
+
+    /// Run a single WebSocket connection until it drops or shutdown is requested
+    async fn run_connection(
+        ws_url: &str,
+        fetcher: &Arc<AccountFetcher>,
+        shutdown: Arc<Notify>,
+        connected: &Arc<AtomicBool>,
+        notifications: &Arc<AtomicU64>,
+        active_subscriptions: &Arc<AtomicU64>,
+    ) -> Result<ConnectionEnd, String> {
+        let (ws_stream, _) = connect_async(ws_url)
+            .await
+            .map_err(|e| format!("Failed to connect to WebSocket: {}", e))?;
+        let (mut ws_sender, mut ws_receiver) = ws_stream.split();
+
+        connected.store(true, Ordering::Relaxed);
+        logger::info(LogTag::PoolFetcher, "Account stream connected");
+
+        let mut session = StreamSession::default();
+        let mut last_inbound = Instant::now();
+
+        let mut refresh_interval = tokio::time::interval(Duration::from_secs(TARGET_REFRESH_SECS));
+        refresh_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut heartbeat_interval = tokio::time::interval(Duration::from_secs(HEARTBEAT_SECS));
+        heartbeat_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.notified() => {
+                    let _ = ws_sender.send(Message::Close(None)).await;
+                    return Ok(ConnectionEnd::Shutdown);
+                }
+
+                _ = refresh_interval.tick() => {
+                    let targets = Self::collect_target_accounts(fetcher).await;
+                    for payload in session.sync_targets(&targets) {
+                        ws_sender
+                            .send(Message::Text(payload))
+                            .await
+                            .map_err(|e| format!("Failed to send subscription: {}", e))?;
+                    }
+                    active_subscriptions.store(session.subscriptions.len() as u64, Ordering::Relaxed);
+                }
+
+                _ = heartbeat_interval.tick() => {
+                    if last_inbound.elapsed() > Duration::from_secs(STREAM_SILENCE_TIMEOUT_SECS) {
+                        return Err("no traffic from server".to_string());
+                    }
+                    ws_sender
+                        .send(Message::Ping(vec![]))
+                        .await
+                        .map_err(|e| format!("Failed to send heartbeat ping: {}", e))?;
+                }
+
+                message = ws_receiver.next() => {
+                    last_inbound = Instant::now();
+                    match message {
+                        Some(Ok(Message::Text(text))) => {
+                            let (update, replies) = session.handle_message(&text);
+                            for payload in replies {
+                                ws_sender
+                                    .send(Message::Text(payload))
+                                    .await
+                                    .map_err(|e| format!("Failed to send unsubscribe: {}", e))?;
+                            }
+                            if let Some(account_data) = update {
+                                notifications.fetch_add(1, Ordering::Relaxed);
+                                logger::verbose(
+                                    LogTag::PoolFetcher,
+                                    &format!(
+                                        "Streamed update for {} at slot {}",
+                                        account_data.pubkey, account_data.slot
+                                    ),
+                                );
+                                fetcher.apply_streamed_accounts(vec![account_data]).await;
+                            }
+                        }
+                        Some(Ok(Message::Ping(payload))) => {
+                            ws_sender
+                                .send(Message::Pong(payload))
+                                .await
+                                .map_err(|e| format!("Failed to respond to ping: {}", e))?;
+                        }
+                        Some(Ok(Message::Close(_))) | None => return Ok(ConnectionEnd::Dropped),
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => return Err(format!("WebSocket error: {}", e)),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Accounts that should be streamed: open-position pools first, then top watched tokens
+    async fn collect_target_accounts(fetcher: &AccountFetcher) -> HashSet<Pubkey> {
+        let (top_tokens, max_accounts) = with_config(|cfg| {
+            (
+                cfg.pools.stream_top_watched_tokens,
+                cfg.pools.max_streamed_accounts,
+            )
+        });
+
+        let open_mints: HashSet<String> = crate::positions::state::get_open_mints()
+            .await
+            .into_iter()
+            .collect();
+
+        let mut pools = fetcher.get_pool_directory_snapshot();
+        pools.sort_by(|a, b| {
+            b.liquidity_usd
+                .partial_cmp(&a.liquidity_usd)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        // Whole pools in priority order, so a pool is never streamed with half its accounts
+        let mut ordered: Vec<&PoolDescriptor> = pools
+            .iter()
+            .filter(|pool| open_mints.contains(&Self::target_mint(pool)))
+            .collect();
+
+        if top_tokens > 0 {
+            let mut selected: HashSet<String> = HashSet::new();
+            for pool in &pools {
+                let mint = Self::target_mint(pool);
+                if open_mints.contains(&mint) {
+                    continue;
+                }
+                if !selected.contains(&mint) {
+                    if selected.len() >= top_tokens {
+                        continue;
+                    }
+                    selected.insert(mint);
+                }
+                ordered.push(pool);
+            }
+        }
+
+        let mut targets = HashSet::new();
+        for pool in ordered {
+            let new_accounts = pool
+                .reserve_accounts
+                .iter()
+                .filter(|account| !targets.contains(*account))
+                .count();
+            if targets.len() + new_accounts > max_accounts {
+                continue;
+            }
+            targets.extend(pool.reserve_accounts.iter().copied());
+        }
+        targets
+    }
+
+    /// The tracked (non-SOL) token mint of a pool
+    fn target_mint(pool: &PoolDescriptor) -> String {
+        if is_sol_mint(&pool.base_mint.to_string()) {
+            pool.quote_mint.to_string()
+        } else {
+            pool.base_mint.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(payload: &str) -> serde_json::Value {
+        serde_json::from_str(payload).unwrap()
+    }
+
+    fn targets(accounts: &[Pubkey]) -> HashSet<Pubkey> {
+        accounts.iter().copied().collect()
+    }
+
+    /// Subscribe to `accounts` and confirm each request with the given subscription IDs
+    fn subscribed_session(accounts: &[(Pubkey, u64)]) -> StreamSession {
+        let mut session = StreamSession::default();
+        let wanted: Vec<Pubkey> = accounts.iter().map(|(account, _)| *account).collect();
+        for payload in session.sync_targets(&targets(&wanted)) {
+            let request = parse(&payload);
+            let account = Pubkey::from_str(request["params"][0].as_str().unwrap()).unwrap();
+            let subscription_id = accounts.iter().find(|(a, _)| *a == account).unwrap().1;
+            let reply = serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": subscription_id,
+            });
+            let (update, replies) = session.handle_message(&reply.to_string());
+            assert!(update.is_none() && replies.is_empty());
+        }
+        session
+    }
+
+    #[test]
+    fn sync_targets_diffs_subscriptions() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut session = subscribed_session(&[(a, 100), (b, 200)]);
+        assert_eq!(session.subscriptions.len(), 2);
+
+        // Unchanged targets send nothing
+        assert!(session.sync_targets(&targets(&[a, b])).is_empty());
+
+        // Dropping `a` and adding `c` sends one unsubscribe and one subscribe
+        let payloads: Vec<serde_json::Value> = session
+            .sync_targets(&targets(&[b, c]))
+            .iter()
+            .map(|p| parse(p))
+            .collect();
+        assert_eq!(payloads.len(), 2);
+        assert_eq!(payloads[0]["method"], "accountSubscribe");
+        assert_eq!(payloads[0]["params"][0], c.to_string());
+        assert_eq!(payloads[1]["method"], "accountUnsubscribe");
+        assert_eq!(payloads[1]["params"][0], 100);
+
+        assert!(!session.by_account.contains_key(&a));
+        assert!(!session.subscriptions.contains_key(&100));
+        assert_eq!(session.requested, targets(&[b, c]));
+    }
+
+    #[test]
+    fn target_removed_while_subscribe_in_flight() {
+        let a = Pubkey::new_unique();
+        let mut session = StreamSession::default();
+        let subscribe = parse(&session.sync_targets(&targets(&[a]))[0]);
+
+        // Nothing to unsubscribe yet; the confirmation is still outstanding
+        assert!(session.sync_targets(&HashSet::new()).is_empty());
+
+        let reply = serde_json::json!({ "jsonrpc": "2.0", "id": subscribe["id"], "result": 7 });
+        let (_, replies) = session.handle_message(&reply.to_string());
+        assert_eq!(replies.len(), 1);
+        let unsubscribe = parse(&replies[0]);
+        assert_eq!(unsubscribe["method"], "accountUnsubscribe");
+        assert_eq!(unsubscribe["params"][0], 7);
+        assert!(session.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn rejected_subscription_is_retried_on_next_sync() {
+        let a = Pubkey::new_unique();
+        let mut session = StreamSession::default();
+        let subscribe = parse(&session.sync_targets(&targets(&[a]))[0]);
+
+        let reply = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": subscribe["id"],
+            "error": { "code": -32602, "message": "Invalid params" },
+        });
+        session.handle_message(&reply.to_string());
+        assert!(!session.requested.contains(&a));
+
+        let retry = session.sync_targets(&targets(&[a]));
+        assert_eq!(retry.len(), 1);
+        assert_eq!(parse(&retry[0])["params"][0], a.to_string());
+        assert_ne!(parse(&retry[0])["id"], subscribe["id"]);
+    }
+
+    #[test]
+    fn notifications_route_by_subscription_id() {
+        let a = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut session = subscribed_session(&[(a, 42)]);
+
+        let notification = |subscription: u64| {
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "accountNotification",
+                "params": {
+                    "subscription": subscription,
+                    "result": {
+                        "context": { "slot": 1234 },
+                        "value": {
+                            "data": ["AQID", "base64"],
+                            "owner": owner.to_string(),
+                            "lamports": 5000,
+                        },
+                    },
+                },
+            })
+            .to_string()
+        };
+
+        let (update, _) = session.handle_message(&notification(42));
+        let update = update.unwrap();
+        assert_eq!(update.pubkey, a);
+        assert_eq!(update.data, vec![1, 2, 3]);
+        assert_eq!(update.slot, 1234);
+        assert_eq!(update.lamports, 5000);
+        assert_eq!(update.owner, owner);
+
+        // Unknown subscriptions are ignored
+        assert!(session.handle_message(&notification(43)).0.is_none());
+    }
+
+    #[test]
+    fn reconnect_resubscribes_every_target() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let old = subscribed_session(&[(a, 1), (b, 2)]);
+
+        // Each connection starts a fresh session, so all current targets are requested again
+        let mut session = StreamSession::default();
+        let payloads = session.sync_targets(&old.requested);
+        let mut resubscribed: Vec<String> = payloads
+            .iter()
+            .map(|p| parse(p))
+            .inspect(|p| assert_eq!(p["method"], "accountSubscribe"))
+            .map(|p| p["params"][0].as_str().unwrap().to_string())
+            .collect();
+        resubscribed.sort();
+
+        let mut expected = vec![a.to_string(), b.to_string()];
+        expected.sort();
+        assert_eq!(resubscribed, expected);
+        assert!(session.subscriptions.is_empty());
+        assert_eq!(session.pending.len(), 2);
+    }
+}
@@ -3,13 +3,16 @@
 /// This module provides a builder pattern interface for creating swap operations.
 /// It handles validation, parameter calculation, and delegates to appropriate
 /// program-specific implementations.
+use super::programs::meteora_damm::MeteoraDammSwap;
+use super::programs::pumpfun_amm::PumpFunAmmSwap;
+use super::programs::pumpfun_legacy::PumpFunLegacySwap;
 use super::programs::raydium_clmm::RaydiumClmmSwap;
 use super::programs::raydium_cpmm::RaydiumCpmmSwap;
+use super::programs::raydium_legacy_amm::RaydiumLegacyAmmSwap;
 use super::programs::ProgramSwap;
-use super::types::{SwapDirection, SwapError, SwapRequest, SwapResult};
+use super::types::{SwapDirection, SwapError, SwapParams, SwapRequest, SwapResult};
 
 use crate::config::with_config;
-use crate::constants::{RAYDIUM_CLMM_PROGRAM_ID, RAYDIUM_CPMM_PROGRAM_ID};
 use crate::logger::{self, LogTag};
 use crate::pools::decoders::PoolDecoder;
 use crate::pools::types::ProgramKind;
@@ -17,6 +20,7 @@ use crate::pools::AccountData;
 use crate::rpc::{get_rpc_client, RpcClientMethods};
 
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::Transaction;
 use std::collections::HashMap;
 use std::str::FromStr;
 
@@ -54,6 +58,67 @@ impl SwapBuilder {
         match program_kind {
             ProgramKind::RaydiumCpmm => RaydiumCpmmSwap::execute_swap(request, pool_data).await,
             ProgramKind::RaydiumClmm => RaydiumClmmSwap::execute_swap(request, pool_data).await,
+            ProgramKind::RaydiumLegacyAmm => {
+                RaydiumLegacyAmmSwap::execute_swap(request, pool_data).await
+            }
+            ProgramKind::PumpFunAmm => PumpFunAmmSwap::execute_swap(request, pool_data).await,
+            ProgramKind::PumpFunLegacy => PumpFunLegacySwap::execute_swap(request, pool_data).await,
+            ProgramKind::MeteoraDamm => MeteoraDammSwap::execute_swap(request, pool_data).await,
+            _ => Err(SwapError::InvalidPool(format!(
+                "Unsupported program: {:?}",
+                program_kind
+            ))),
+        }
+    }
+
+    /// Quote a swap request against current pool state without building a transaction
+    pub async fn quote(request: &SwapRequest) -> Result<SwapParams, SwapError> {
+        Self::validate_request(request)?;
+        let (pool_data, program_kind) = Self::fetch_pool_data(&request.pool_address).await?;
+
+        match program_kind {
+            ProgramKind::RaydiumCpmm => RaydiumCpmmSwap::quote_swap(request, &pool_data).await,
+            ProgramKind::RaydiumClmm => RaydiumClmmSwap::quote_swap(request, &pool_data).await,
+            ProgramKind::RaydiumLegacyAmm => {
+                RaydiumLegacyAmmSwap::quote_swap(request, &pool_data).await
+            }
+            ProgramKind::PumpFunAmm => PumpFunAmmSwap::quote_swap(request, &pool_data).await,
+            ProgramKind::PumpFunLegacy => PumpFunLegacySwap::quote_swap(request, &pool_data).await,
+            ProgramKind::MeteoraDamm => MeteoraDammSwap::quote_swap(request, &pool_data).await,
+            _ => Err(SwapError::InvalidPool(format!(
+                "Unsupported program: {:?}",
+                program_kind
+            ))),
+        }
+    }
+
+    /// Build the (unsigned) transaction for a swap request
+    ///
+    /// The transaction carries a fresh blockhash and the main wallet as payer; callers
+    /// hand it to the signing service themselves.
+    pub async fn build_transaction(
+        request: &SwapRequest,
+    ) -> Result<(Transaction, SwapParams), SwapError> {
+        Self::validate_request(request)?;
+        let (pool_data, program_kind) = Self::fetch_pool_data(&request.pool_address).await?;
+
+        match program_kind {
+            ProgramKind::RaydiumCpmm => {
+                RaydiumCpmmSwap::build_transaction(request, &pool_data).await
+            }
+            ProgramKind::RaydiumClmm => {
+                RaydiumClmmSwap::build_transaction(request, &pool_data).await
+            }
+            ProgramKind::RaydiumLegacyAmm => {
+                RaydiumLegacyAmmSwap::build_transaction(request, &pool_data).await
+            }
+            ProgramKind::PumpFunAmm => PumpFunAmmSwap::build_transaction(request, &pool_data).await,
+            ProgramKind::PumpFunLegacy => {
+                PumpFunLegacySwap::build_transaction(request, &pool_data).await
+            }
+            ProgramKind::MeteoraDamm => {
+                MeteoraDammSwap::build_transaction(request, &pool_data).await
+            }
             _ => Err(SwapError::InvalidPool(format!(
                 "Unsupported program: {:?}",
                 program_kind
@@ -61,6 +126,19 @@ impl SwapBuilder {
         }
     }
 
+    /// Whether direct swaps are implemented for a pool program
+    pub fn supports_program(program_kind: ProgramKind) -> bool {
+        matches!(
+            program_kind,
+            ProgramKind::RaydiumCpmm
+                | ProgramKind::RaydiumClmm
+                | ProgramKind::RaydiumLegacyAmm
+                | ProgramKind::PumpFunAmm
+                | ProgramKind::PumpFunLegacy
+                | ProgramKind::MeteoraDamm
+        )
+    }
+
     /// Validate swap request parameters
     fn validate_request(request: &SwapRequest) -> Result<(), SwapError> {
         if request.amount <= 0.0 {
@@ -114,17 +192,13 @@ impl SwapBuilder {
         let account_data = AccountData::from_account(*pool_address, pool_account, 0);
 
         // Determine program type from owner
-        let owner_str = account_data.owner.to_string();
-        let program_kind = match owner_str.as_str() {
-            RAYDIUM_CPMM_PROGRAM_ID => ProgramKind::RaydiumCpmm,
-            RAYDIUM_CLMM_PROGRAM_ID => ProgramKind::RaydiumClmm,
-            _ => {
-                return Err(SwapError::InvalidPool(format!(
-                    "Unsupported pool program: {}",
-                    account_data.owner
-                )));
-            }
-        };
+        let program_kind = ProgramKind::from_program_id(&account_data.owner.to_string());
+        if !Self::supports_program(program_kind) {
+            return Err(SwapError::InvalidPool(format!(
+                "Unsupported pool program: {}",
+                account_data.owner
+            )));
+        }
 
         logger::info(
             LogTag::System,
@@ -142,6 +216,10 @@ pub struct SwapRequestBuilder {
     amount: Option<f64>,
     direction: Option<SwapDirection>,
     slippage_bps: u16,
+    amount_raw: Option<u64>,
+    min_output_raw: Option<u64>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
 }
 
 impl SwapRequestBuilder {
@@ -152,6 +230,10 @@ impl SwapRequestBuilder {
             amount: None,
             direction: None,
             slippage_bps: with_config(|cfg| cfg.swaps.raydium.default_slippage_bps),
+            amount_raw: None,
+            min_output_raw: None,
+            compute_unit_limit: None,
+            compute_unit_price: None,
         }
     }
 
@@ -184,6 +266,25 @@ impl SwapRequestBuilder {
         self.amount(amount)
     }
 
+    /// Exact input amount in raw units (avoids float rounding on full sells)
+    pub fn amount_raw(mut self, amount_raw: u64) -> Self {
+        self.amount_raw = Some(amount_raw);
+        self
+    }
+
+    /// Never accept less than this raw output, regardless of slippage
+    pub fn min_output_raw(mut self, min_output_raw: u64) -> Self {
+        self.min_output_raw = Some(min_output_raw);
+        self
+    }
+
+    /// Prepend compute budget instructions with this limit and price (micro-lamports per CU)
+    pub fn compute_budget(mut self, unit_limit: u32, unit_price: u64) -> Self {
+        self.compute_unit_limit = Some(unit_limit);
+        self.compute_unit_price = Some(unit_price);
+        self
+    }
+
     pub fn direction(mut self, dir: SwapDirection) -> Self {
         self.direction = Some(dir);
         self
@@ -221,6 +322,10 @@ impl SwapRequestBuilder {
                 .direction
                 .ok_or_else(|| SwapError::InvalidInput("Direction is required".to_string()))?,
             slippage_bps: self.slippage_bps,
+            amount_raw: self.amount_raw,
+            min_output_raw: self.min_output_raw,
+            compute_unit_limit: self.compute_unit_limit,
+            compute_unit_price: self.compute_unit_price,
         })
     }
 
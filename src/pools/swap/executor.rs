@@ -2,9 +2,12 @@
 ///
 /// This module handles the actual execution of swap transactions,
 /// including transaction signing and broadcasting.
-use super::types::{SwapError, SwapParams, SwapResult};
+use super::types::{SwapDirection, SwapError, SwapParams, SwapRequest, SwapResult};
+use crate::constants::SOL_MINT;
+use crate::errors::{BlockchainError, ScreenerBotError};
 use crate::logger::{self, LogTag};
 use crate::rpc::{get_rpc_client, RpcClientMethods};
+use crate::swaps::simulation::{simulate_swap, SimulationRequest};
 
 use base64::Engine;
 use solana_sdk::transaction::Transaction;
@@ -17,6 +20,7 @@ impl SwapExecutor {
     pub async fn execute_transaction(
         transaction: Transaction,
         swap_params: SwapParams,
+        request: &SwapRequest,
     ) -> Result<SwapResult, SwapError> {
         // Serialize transaction to base64 for signing service
         let serialized_tx = bincode::serialize(&transaction).map_err(|e| {
@@ -24,6 +28,9 @@ impl SwapExecutor {
         })?;
         let transaction_base64 = base64::engine::general_purpose::STANDARD.encode(&serialized_tx);
 
+        // Simulate before signing so failing swaps never pay fees
+        Self::simulate(&transaction, &transaction_base64, &swap_params, request).await?;
+
         // Send transaction using centralized signing service with main wallet
         let rpc_client = get_rpc_client();
 
@@ -46,6 +53,44 @@ impl SwapExecutor {
         })
     }
 
+    /// Pre-flight simulation of a built swap transaction
+    async fn simulate(
+        transaction: &Transaction,
+        transaction_base64: &str,
+        swap_params: &SwapParams,
+        request: &SwapRequest,
+    ) -> Result<(), SwapError> {
+        let wallet = transaction.message.account_keys.first().ok_or_else(|| {
+            SwapError::TransactionError("Transaction has no fee payer".to_string())
+        })?;
+
+        let token_mint = request.token_mint.to_string();
+        let (input_mint, output_mint) = match request.direction {
+            SwapDirection::Buy => (SOL_MINT.to_string(), token_mint),
+            SwapDirection::Sell => (token_mint, SOL_MINT.to_string()),
+        };
+
+        simulate_swap(&SimulationRequest {
+            source: "Pool swap".to_string(),
+            transaction_base64: transaction_base64.to_string(),
+            wallet_address: wallet.to_string(),
+            input_mint,
+            output_mint,
+            input_amount: swap_params.input_amount_raw,
+            expected_output: swap_params.expected_output_raw,
+            min_output: swap_params.minimum_output_raw,
+        })
+        .await
+        .map(|_| ())
+        .map_err(|e| match e {
+            ScreenerBotError::Blockchain(BlockchainError::InsufficientBalance { .. }) => {
+                SwapError::InsufficientBalance(e.to_string())
+            }
+            ScreenerBotError::Network(_) => SwapError::RpcError(e.to_string()),
+            _ => SwapError::ExecutionError(e.to_string()),
+        })
+    }
+
     /// Estimate transaction fees
     pub async fn estimate_fees(_transaction: &Transaction) -> Result<u64, SwapError> {
         // For now, return a reasonable estimate since our RPC client doesn't support this method
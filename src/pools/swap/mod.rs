@@ -17,6 +17,10 @@ pub mod types;
 
 pub use builder::SwapBuilder;
 pub use executor::SwapExecutor;
+pub use programs::meteora_damm::MeteoraDammSwap;
+pub use programs::pumpfun_amm::PumpFunAmmSwap;
+pub use programs::pumpfun_legacy::PumpFunLegacySwap;
 pub use programs::raydium_clmm::RaydiumClmmSwap;
 pub use programs::raydium_cpmm::RaydiumCpmmSwap;
-pub use types::{SwapDirection, SwapError, SwapRequest, SwapResult};
+pub use programs::raydium_legacy_amm::RaydiumLegacyAmmSwap;
+pub use types::{SwapDirection, SwapError, SwapParams, SwapRequest, SwapResult};
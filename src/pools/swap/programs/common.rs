@@ -0,0 +1,362 @@
+/// Shared helpers for program swap implementations
+///
+/// RPC lookups, token account setup, WSOL wrapping and slippage handling that every
+/// program module needs. Program modules only add their own pool decoding, quote math
+/// and swap instruction layout on top of these.
+use crate::constants::{COMPUTE_BUDGET_PROGRAM_ID, SOL_DECIMALS, SOL_MINT, TOKEN_2022_PROGRAM_ID};
+use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest};
+use crate::rpc::{get_rpc_client, RpcClientMethods};
+
+use solana_sdk::{
+    account::Account, instruction::Instruction, pubkey::Pubkey, signature::Keypair,
+    system_instruction, transaction::Transaction,
+};
+use std::str::FromStr;
+
+/// Wrapped SOL mint as a pubkey
+pub fn wsol_mint() -> Pubkey {
+    Pubkey::from_str(SOL_MINT).unwrap()
+}
+
+/// Parse a base58 address into a pubkey
+pub fn parse_pubkey(address: &str, what: &str) -> Result<Pubkey, SwapError> {
+    Pubkey::from_str(address)
+        .map_err(|e| SwapError::DecoderError(format!("Invalid {} {}: {}", what, address, e)))
+}
+
+/// Load wallet from configuration
+pub fn load_wallet() -> Result<Keypair, SwapError> {
+    crate::config::get_wallet_keypair()
+        .map_err(|e| SwapError::ExecutionError(format!("Failed to load wallet: {}", e)))
+}
+
+/// Fetch an account that must exist
+pub async fn fetch_account(pubkey: &Pubkey) -> Result<Account, SwapError> {
+    get_rpc_client()
+        .get_account(pubkey)
+        .await
+        .map_err(|e| SwapError::RpcError(format!("Failed to fetch account {}: {}", pubkey, e)))?
+        .ok_or_else(|| SwapError::RpcError(format!("Account not found: {}", pubkey)))
+}
+
+/// Read the amount of an SPL token account (offset 64)
+pub async fn get_token_account_balance(pubkey: &Pubkey) -> Result<u64, SwapError> {
+    let account = fetch_account(pubkey).await?;
+    read_u64(&account.data, 64)
+        .ok_or_else(|| SwapError::DecoderError(format!("Invalid token account data: {}", pubkey)))
+}
+
+/// Owner program of a mint (SPL Token or Token-2022)
+pub async fn get_token_program_for_mint(mint: &Pubkey) -> Result<Pubkey, SwapError> {
+    let account = fetch_account(mint).await?;
+    if account.owner == Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap() {
+        Ok(account.owner)
+    } else {
+        Ok(spl_token::id())
+    }
+}
+
+/// Read a little-endian u64 at a fixed offset
+pub fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    data.get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+}
+
+/// Read a little-endian u128 at a fixed offset
+pub fn read_u128(data: &[u8], offset: usize) -> Option<u128> {
+    data.get(offset..offset + 16)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u128::from_le_bytes)
+}
+
+/// Read a pubkey at a fixed offset
+pub fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    data.get(offset..offset + 32)
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .map(Pubkey::new_from_array)
+}
+
+/// Constant product output for an exact input, with the fee taken from the input
+pub fn constant_product_output(
+    reserve_in: u64,
+    reserve_out: u64,
+    amount_in: u64,
+    fee_numerator: u64,
+    fee_denominator: u64,
+) -> Result<u64, SwapError> {
+    if reserve_in == 0 || reserve_out == 0 {
+        return Err(SwapError::InvalidPool(
+            "Pool has empty reserves".to_string(),
+        ));
+    }
+    if fee_numerator >= fee_denominator {
+        return Err(SwapError::CalculationError(format!(
+            "Invalid fee {}/{}",
+            fee_numerator, fee_denominator
+        )));
+    }
+
+    let amount_in_after_fee = (amount_in as u128) * ((fee_denominator - fee_numerator) as u128)
+        / (fee_denominator as u128);
+    let output =
+        (reserve_out as u128) * amount_in_after_fee / ((reserve_in as u128) + amount_in_after_fee);
+
+    Ok(output as u64)
+}
+
+/// Build swap parameters from raw amounts, applying slippage and the request's output floor
+pub fn build_swap_params(
+    request: &SwapRequest,
+    input_amount_raw: u64,
+    expected_output_raw: u64,
+    token_decimals: u8,
+) -> Result<SwapParams, SwapError> {
+    if expected_output_raw == 0 {
+        return Err(SwapError::CalculationError(
+            "Swap output rounds to zero".to_string(),
+        ));
+    }
+
+    let (input_decimals, output_decimals) = match request.direction {
+        SwapDirection::Buy => (SOL_DECIMALS, token_decimals),
+        SwapDirection::Sell => (token_decimals, SOL_DECIMALS),
+    };
+
+    let mut minimum_output_raw = ((expected_output_raw as u128)
+        * (10_000 - request.slippage_bps.min(10_000) as u128)
+        / 10_000) as u64;
+
+    if let Some(floor) = request.min_output_raw {
+        if expected_output_raw < floor {
+            return Err(SwapError::CalculationError(format!(
+                "Pool output {} is below the quoted minimum {}",
+                expected_output_raw, floor
+            )));
+        }
+        minimum_output_raw = minimum_output_raw.max(floor);
+    }
+
+    let to_ui = |raw: u64, decimals: u8| (raw as f64) / 10_f64.powi(decimals as i32);
+
+    Ok(SwapParams {
+        input_amount: to_ui(input_amount_raw, input_decimals),
+        expected_output: to_ui(expected_output_raw, output_decimals),
+        minimum_output: to_ui(minimum_output_raw, output_decimals),
+        input_amount_raw,
+        expected_output_raw,
+        minimum_output_raw,
+    })
+}
+
+/// Wallet ATAs for WSOL and the token, plus idempotent create instructions for both
+pub fn prepare_token_accounts(
+    wallet: &Pubkey,
+    token_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> (Pubkey, Pubkey, Vec<Instruction>) {
+    let wsol_mint = wsol_mint();
+    let wsol_ata = spl_associated_token_account::get_associated_token_address(wallet, &wsol_mint);
+    let token_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+        wallet,
+        token_mint,
+        token_program,
+    );
+
+    let instructions = vec![
+        spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            wallet,
+            wallet,
+            &wsol_mint,
+            &spl_token::id(),
+        ),
+        spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            wallet,
+            wallet,
+            token_mint,
+            token_program,
+        ),
+    ];
+
+    (wsol_ata, token_ata, instructions)
+}
+
+/// Move lamports into the WSOL ATA and sync its balance
+pub fn wrap_sol_instructions(
+    wallet: &Pubkey,
+    wsol_ata: &Pubkey,
+    lamports: u64,
+) -> Result<Vec<Instruction>, SwapError> {
+    Ok(vec![
+        system_instruction::transfer(wallet, wsol_ata, lamports),
+        spl_token::instruction::sync_native(&spl_token::id(), wsol_ata)?,
+    ])
+}
+
+/// Close the WSOL ATA, returning all wrapped SOL to the wallet
+pub fn close_wsol_instruction(
+    wallet: &Pubkey,
+    wsol_ata: &Pubkey,
+) -> Result<Instruction, SwapError> {
+    Ok(spl_token::instruction::close_account(
+        &spl_token::id(),
+        wsol_ata,
+        wallet,
+        wallet,
+        &[],
+    )?)
+}
+
+/// Create the transaction with the latest blockhash
+pub async fn finalize_transaction(
+    mut instructions: Vec<Instruction>,
+    payer: &Pubkey,
+    request: &SwapRequest,
+) -> Result<Transaction, SwapError> {
+    instructions.splice(0..0, compute_budget_instructions(request)?);
+
+    let recent_blockhash = get_rpc_client()
+        .get_latest_blockhash()
+        .await
+        .map_err(|e| SwapError::RpcError(format!("Failed to get recent blockhash: {}", e)))?;
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(payer));
+    transaction.message.recent_blockhash = recent_blockhash;
+
+    Ok(transaction)
+}
+
+/// ComputeBudget instructions requested by the swap (limit first, then price)
+pub fn compute_budget_instructions(request: &SwapRequest) -> Result<Vec<Instruction>, SwapError> {
+    let program_id = parse_pubkey(COMPUTE_BUDGET_PROGRAM_ID, "compute budget program")?;
+    let mut instructions = Vec::new();
+
+    if let Some(limit) = request.compute_unit_limit {
+        // SetComputeUnitLimit = 2, u32 little endian
+        let mut data = vec![2u8];
+        data.extend_from_slice(&limit.to_le_bytes());
+        instructions.push(Instruction::new_with_bytes(program_id, &data, vec![]));
+    }
+    if let Some(price) = request.compute_unit_price {
+        // SetComputeUnitPrice = 3, u64 little endian (micro-lamports per CU)
+        let mut data = vec![3u8];
+        data.extend_from_slice(&price.to_le_bytes());
+        instructions.push(Instruction::new_with_bytes(program_id, &data, vec![]));
+    }
+
+    Ok(instructions)
+}
+
+/// Anchor event authority PDA for a program
+pub fn event_authority(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], program_id).0
+}
+
+/// Token decimals (cache first, chain lookup on miss)
+pub async fn token_decimals(mint: &Pubkey) -> Result<u8, SwapError> {
+    crate::tokens::get_decimals(&mint.to_string())
+        .await
+        .ok_or_else(|| SwapError::DecoderError(format!("Token decimals not available: {}", mint)))
+}
+
+/// Exact-input request used by the program quote and instruction tests
+#[cfg(test)]
+pub(crate) fn test_request(direction: SwapDirection, amount_raw: u64) -> SwapRequest {
+    SwapRequest {
+        pool_address: Pubkey::new_unique(),
+        token_mint: Pubkey::new_unique(),
+        amount: 0.0,
+        direction,
+        slippage_bps: 100,
+        amount_raw: Some(amount_raw),
+        min_output_raw: None,
+        compute_unit_limit: None,
+        compute_unit_price: None,
+    }
+}
+
+/// Swap parameters carrying only the raw amounts an instruction encodes
+#[cfg(test)]
+pub(crate) fn test_params(input_amount_raw: u64, minimum_output_raw: u64) -> SwapParams {
+    SwapParams {
+        input_amount: 0.0,
+        expected_output: 0.0,
+        minimum_output: 0.0,
+        input_amount_raw,
+        expected_output_raw: minimum_output_raw,
+        minimum_output_raw,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_product_output_takes_fee_from_input() {
+        // 10_000 in at 0.25% → 9_975 reaches the pool
+        assert_eq!(
+            constant_product_output(1_000_000, 2_000_000, 10_000, 25, 10_000).unwrap(),
+            19_752
+        );
+        // No fee: 2_000_000 * 10_000 / 1_010_000
+        assert_eq!(
+            constant_product_output(1_000_000, 2_000_000, 10_000, 0, 1).unwrap(),
+            19_801
+        );
+    }
+
+    #[test]
+    fn constant_product_output_rejects_bad_pools() {
+        assert!(constant_product_output(0, 1_000, 10, 0, 1).is_err());
+        assert!(constant_product_output(1_000, 0, 10, 0, 1).is_err());
+        assert!(constant_product_output(1_000, 1_000, 10, 100, 100).is_err());
+    }
+
+    #[test]
+    fn build_swap_params_applies_slippage_and_decimals() {
+        let request = test_request(SwapDirection::Buy, 500_000_000);
+        let params = build_swap_params(&request, 500_000_000, 2_000_000, 6).unwrap();
+
+        assert_eq!(params.minimum_output_raw, 1_980_000);
+        assert_eq!(params.input_amount, 0.5);
+        assert_eq!(params.expected_output, 2.0);
+        assert_eq!(params.minimum_output, 1.98);
+    }
+
+    #[test]
+    fn build_swap_params_honours_quoted_floor() {
+        let mut request = test_request(SwapDirection::Sell, 1_000);
+        request.min_output_raw = Some(9_950);
+
+        let params = build_swap_params(&request, 1_000, 10_000, 6).unwrap();
+        assert_eq!(params.minimum_output_raw, 9_950);
+
+        // Pool moved below the earlier quote
+        assert!(build_swap_params(&request, 1_000, 9_000, 6).is_err());
+    }
+
+    #[test]
+    fn build_swap_params_rejects_zero_output() {
+        let request = test_request(SwapDirection::Buy, 1);
+        assert!(build_swap_params(&request, 1, 0, 6).is_err());
+    }
+
+    #[test]
+    fn compute_budget_instructions_put_limit_before_price() {
+        let mut request = test_request(SwapDirection::Buy, 1);
+        assert!(compute_budget_instructions(&request).unwrap().is_empty());
+
+        request.compute_unit_limit = Some(200_000);
+        request.compute_unit_price = Some(5_000);
+        let instructions = compute_budget_instructions(&request).unwrap();
+
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(
+            instructions[0].program_id.to_string(),
+            COMPUTE_BUDGET_PROGRAM_ID
+        );
+        assert_eq!(instructions[0].data, [2, 0x40, 0x0d, 0x03, 0x00]);
+        assert_eq!(instructions[1].data, [3, 0x88, 0x13, 0, 0, 0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,431 @@
+/// Meteora DAMM v2 swap implementation
+///
+/// This module implements direct swaps for Meteora DAMM v2 (cp-amm) pools. Pools hold
+/// a single concentrated liquidity range, so quotes are computed from the pool's
+/// liquidity and sqrt price rather than vault balances. The base fee is read from the
+/// pool; dynamic fee surcharges are left to slippage.
+use super::common;
+use super::ProgramSwap;
+use crate::constants::{METEORA_DAMM_PROGRAM_ID, SOL_DECIMALS};
+use crate::logger::{self, LogTag};
+use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest};
+use crate::pools::AccountData;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::Transaction,
+};
+use std::str::FromStr;
+
+/// Anchor discriminator: first 8 bytes of SHA256("global:swap")
+const SWAP_DISCRIMINATOR: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+/// Fee numerator denominator used by cp-amm
+const FEE_DENOMINATOR: u64 = 1_000_000_000;
+
+// Pool account offsets
+const CLIFF_FEE_NUMERATOR_OFFSET: usize = 8;
+const TOKEN_A_MINT_OFFSET: usize = 168;
+const TOKEN_B_MINT_OFFSET: usize = 200;
+const TOKEN_A_VAULT_OFFSET: usize = 232;
+const TOKEN_B_VAULT_OFFSET: usize = 264;
+const LIQUIDITY_OFFSET: usize = 360;
+const SQRT_MIN_PRICE_OFFSET: usize = 424;
+const SQRT_MAX_PRICE_OFFSET: usize = 440;
+const SQRT_PRICE_OFFSET: usize = 456;
+const TOKEN_A_FLAG_OFFSET: usize = 482;
+const TOKEN_B_FLAG_OFFSET: usize = 483;
+
+/// Decoded DAMM v2 pool fields needed for swapping
+#[derive(Debug, Clone)]
+struct DammSwapInfo {
+    pool: Pubkey,
+    token_a_mint: Pubkey,
+    token_b_mint: Pubkey,
+    token_a_vault: Pubkey,
+    token_b_vault: Pubkey,
+    token_a_program: Pubkey,
+    token_b_program: Pubkey,
+    cliff_fee_numerator: u64,
+    liquidity: u128,
+    sqrt_min_price: u128,
+    sqrt_max_price: u128,
+    sqrt_price: u128,
+}
+
+impl DammSwapInfo {
+    fn is_a_sol(&self) -> Result<bool, SwapError> {
+        let sol = common::wsol_mint();
+        if self.token_a_mint == sol {
+            Ok(true)
+        } else if self.token_b_mint == sol {
+            Ok(false)
+        } else {
+            Err(SwapError::InvalidPool(
+                "Pool does not contain SOL".to_string(),
+            ))
+        }
+    }
+}
+
+/// Meteora DAMM v2 swap implementation
+pub struct MeteoraDammSwap;
+
+impl ProgramSwap for MeteoraDammSwap {
+    async fn quote_swap(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<SwapParams, SwapError> {
+        let pool_info = Self::decode_pool_state(pool_data)?;
+        Self::calculate_swap_params(request, &pool_info).await
+    }
+
+    async fn build_transaction(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<(Transaction, SwapParams), SwapError> {
+        logger::info(
+            LogTag::System,
+            &format!("Executing Meteora DAMM {:?} swap", request.direction),
+        );
+
+        let pool_info = Self::decode_pool_state(pool_data)?;
+        let wallet = common::load_wallet()?;
+        let swap_params = Self::calculate_swap_params(request, &pool_info).await?;
+
+        logger::info(
+            LogTag::System,
+            &format!(
+                "Meteora DAMM swap: {:.6} → {:.6} (min: {:.6})",
+                swap_params.input_amount, swap_params.expected_output, swap_params.minimum_output
+            ),
+        );
+
+        let wallet_pubkey = wallet.pubkey();
+        let (token_mint, token_program) = if pool_info.is_a_sol()? {
+            (pool_info.token_b_mint, pool_info.token_b_program)
+        } else {
+            (pool_info.token_a_mint, pool_info.token_a_program)
+        };
+
+        let (wsol_ata, token_ata, mut instructions) =
+            common::prepare_token_accounts(&wallet_pubkey, &token_mint, &token_program);
+
+        if request.direction == SwapDirection::Buy {
+            instructions.extend(common::wrap_sol_instructions(
+                &wallet_pubkey,
+                &wsol_ata,
+                swap_params.input_amount_raw,
+            )?);
+        }
+
+        let (input_account, output_account) = match request.direction {
+            SwapDirection::Buy => (wsol_ata, token_ata),
+            SwapDirection::Sell => (token_ata, wsol_ata),
+        };
+
+        instructions.push(Self::build_swap_instruction(
+            &wallet_pubkey,
+            &pool_info,
+            &input_account,
+            &output_account,
+            &swap_params,
+        ));
+
+        instructions.push(common::close_wsol_instruction(&wallet_pubkey, &wsol_ata)?);
+
+        let transaction =
+            common::finalize_transaction(instructions, &wallet_pubkey, request).await?;
+        Ok((transaction, swap_params))
+    }
+}
+
+impl MeteoraDammSwap {
+    fn program_id() -> Pubkey {
+        Pubkey::from_str(METEORA_DAMM_PROGRAM_ID).unwrap()
+    }
+
+    /// Token program from a cp-amm token flag (0 = SPL Token, 1 = Token-2022)
+    fn token_program_from_flag(flag: u8) -> Pubkey {
+        if flag == 1 {
+            spl_token_2022::id()
+        } else {
+            spl_token::id()
+        }
+    }
+
+    /// Decode the pool account
+    fn decode_pool_state(pool_data: &AccountData) -> Result<DammSwapInfo, SwapError> {
+        let data = &pool_data.data;
+        let invalid = || SwapError::DecoderError("Invalid Meteora DAMM pool".to_string());
+        let pubkey_at = |offset: usize| common::read_pubkey(data, offset).ok_or_else(invalid);
+        let u128_at = |offset: usize| common::read_u128(data, offset).ok_or_else(invalid);
+        let flag_at = |offset: usize| data.get(offset).copied().ok_or_else(invalid);
+
+        Ok(DammSwapInfo {
+            pool: pool_data.pubkey,
+            token_a_mint: pubkey_at(TOKEN_A_MINT_OFFSET)?,
+            token_b_mint: pubkey_at(TOKEN_B_MINT_OFFSET)?,
+            token_a_vault: pubkey_at(TOKEN_A_VAULT_OFFSET)?,
+            token_b_vault: pubkey_at(TOKEN_B_VAULT_OFFSET)?,
+            token_a_program: Self::token_program_from_flag(flag_at(TOKEN_A_FLAG_OFFSET)?),
+            token_b_program: Self::token_program_from_flag(flag_at(TOKEN_B_FLAG_OFFSET)?),
+            cliff_fee_numerator: common::read_u64(data, CLIFF_FEE_NUMERATOR_OFFSET)
+                .ok_or_else(invalid)?,
+            liquidity: u128_at(LIQUIDITY_OFFSET)?,
+            sqrt_min_price: u128_at(SQRT_MIN_PRICE_OFFSET)?,
+            sqrt_max_price: u128_at(SQRT_MAX_PRICE_OFFSET)?,
+            sqrt_price: u128_at(SQRT_PRICE_OFFSET)?,
+        })
+    }
+
+    /// Calculate swap parameters with single-range concentrated liquidity math
+    ///
+    /// With `L` and `s = sqrt(P)` (both Q64.64), selling `a` moves the price to
+    /// `s' = L·s / (L + a·s)` and pays out `L·(s − s')` of token B; selling `b` moves it to
+    /// `s' = s + b / L` and pays out `L·(s' − s) / (s·s')` of token A.
+    async fn calculate_swap_params(
+        request: &SwapRequest,
+        pool_info: &DammSwapInfo,
+    ) -> Result<SwapParams, SwapError> {
+        let is_a_sol = pool_info.is_a_sol()?;
+        let token_mint = if is_a_sol {
+            pool_info.token_b_mint
+        } else {
+            pool_info.token_a_mint
+        };
+        let token_decimals = common::token_decimals(&token_mint).await?;
+
+        let input_amount_raw = match request.direction {
+            SwapDirection::Buy => request.input_amount_raw(SOL_DECIMALS),
+            SwapDirection::Sell => request.input_amount_raw(token_decimals),
+        };
+
+        // Input side is token A when buying with SOL in a SOL/token pool, and vice versa
+        let a_to_b = match request.direction {
+            SwapDirection::Buy => is_a_sol,
+            SwapDirection::Sell => !is_a_sol,
+        };
+
+        let (next_sqrt_price, expected_output_raw) =
+            Self::quote_output(pool_info, input_amount_raw, a_to_b)?;
+
+        logger::debug(
+            LogTag::System,
+            &format!(
+                "Meteora DAMM quote: in={} out={} (sqrt {:.12} → {:.12}, fee {}/{})",
+                input_amount_raw,
+                expected_output_raw,
+                pool_info.sqrt_price as f64 / 2_f64.powi(64),
+                next_sqrt_price,
+                pool_info.cliff_fee_numerator.min(FEE_DENOMINATOR),
+                FEE_DENOMINATOR
+            ),
+        );
+
+        common::build_swap_params(
+            request,
+            input_amount_raw,
+            expected_output_raw,
+            token_decimals,
+        )
+    }
+
+    /// Next sqrt price and fee-adjusted output for an exact input
+    ///
+    /// Fails when the pool has no liquidity or the swap would push the price outside the
+    /// pool's range.
+    fn quote_output(
+        pool_info: &DammSwapInfo,
+        amount_in: u64,
+        a_to_b: bool,
+    ) -> Result<(f64, u64), SwapError> {
+        if pool_info.liquidity == 0 || pool_info.sqrt_price == 0 {
+            return Err(SwapError::InvalidPool(
+                "Meteora DAMM pool has no liquidity".to_string(),
+            ));
+        }
+
+        let q64 = 2_f64.powi(64);
+        let liquidity = pool_info.liquidity as f64 / q64;
+        let sqrt_price = pool_info.sqrt_price as f64 / q64;
+        let sqrt_min = pool_info.sqrt_min_price as f64 / q64;
+        let sqrt_max = pool_info.sqrt_max_price as f64 / q64;
+        let amount_in = amount_in as f64;
+
+        let (next_sqrt_price, output) = if a_to_b {
+            let next = liquidity * sqrt_price / (liquidity + amount_in * sqrt_price);
+            (next, liquidity * (sqrt_price - next))
+        } else {
+            let next = sqrt_price + amount_in / liquidity;
+            (next, liquidity * (next - sqrt_price) / (sqrt_price * next))
+        };
+
+        if next_sqrt_price < sqrt_min || next_sqrt_price > sqrt_max {
+            return Err(SwapError::CalculationError(
+                "Swap exceeds Meteora DAMM pool price range".to_string(),
+            ));
+        }
+
+        let fee_numerator = pool_info.cliff_fee_numerator.min(FEE_DENOMINATOR);
+        let output_after_fee =
+            output * (FEE_DENOMINATOR - fee_numerator) as f64 / FEE_DENOMINATOR as f64;
+
+        Ok((
+            next_sqrt_price,
+            output_after_fee.max(0.0).min(u64::MAX as f64) as u64,
+        ))
+    }
+
+    /// Build the cp-amm swap instruction
+    fn build_swap_instruction(
+        user: &Pubkey,
+        pool_info: &DammSwapInfo,
+        input_account: &Pubkey,
+        output_account: &Pubkey,
+        swap_params: &SwapParams,
+    ) -> Instruction {
+        let program_id = Self::program_id();
+        let pool_authority = Pubkey::find_program_address(&[b"pool_authority"], &program_id).0;
+
+        let mut data = SWAP_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&swap_params.input_amount_raw.to_le_bytes());
+        data.extend_from_slice(&swap_params.minimum_output_raw.to_le_bytes());
+
+        let accounts = vec![
+            AccountMeta::new_readonly(pool_authority, false), // pool_authority
+            AccountMeta::new(pool_info.pool, false),          // pool
+            AccountMeta::new(*input_account, false),          // input_token_account
+            AccountMeta::new(*output_account, false),         // output_token_account
+            AccountMeta::new(pool_info.token_a_vault, false), // token_a_vault
+            AccountMeta::new(pool_info.token_b_vault, false), // token_b_vault
+            AccountMeta::new_readonly(pool_info.token_a_mint, false), // token_a_mint
+            AccountMeta::new_readonly(pool_info.token_b_mint, false), // token_b_mint
+            AccountMeta::new_readonly(*user, true),           // payer
+            AccountMeta::new_readonly(pool_info.token_a_program, false), // token_a_program
+            AccountMeta::new_readonly(pool_info.token_b_program, false), // token_b_program
+            AccountMeta::new_readonly(program_id, false),     // referral_token_account (none)
+            AccountMeta::new_readonly(common::event_authority(&program_id), false), // event_authority
+            AccountMeta::new_readonly(program_id, false),                           // program
+        ];
+
+        Instruction {
+            program_id,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pools::swap::programs::common::test_params;
+
+    const Q64: u128 = 1 << 64;
+
+    /// Pool at price 1.0 with L = 1_000_000 and a [0.25, 4] price range
+    fn sample_pool(cliff_fee_numerator: u64) -> DammSwapInfo {
+        DammSwapInfo {
+            pool: Pubkey::new_unique(),
+            token_a_mint: Pubkey::new_unique(),
+            token_b_mint: common::wsol_mint(),
+            token_a_vault: Pubkey::new_unique(),
+            token_b_vault: Pubkey::new_unique(),
+            token_a_program: spl_token_2022::id(),
+            token_b_program: spl_token::id(),
+            cliff_fee_numerator,
+            liquidity: 1_000_000 * Q64,
+            sqrt_min_price: Q64 / 2,
+            sqrt_max_price: Q64 * 2,
+            sqrt_price: Q64,
+        }
+    }
+
+    #[test]
+    fn quote_output_both_directions() {
+        // Either way 1_000 in moves out L·1000/1_001_000 = 999.0009...
+        let pool = sample_pool(0);
+        let (next, out) = MeteoraDammSwap::quote_output(&pool, 1_000, true).unwrap();
+        assert_eq!(out, 999);
+        assert!((next - 1_000_000.0 / 1_001_000.0).abs() < 1e-12);
+
+        let (next, out) = MeteoraDammSwap::quote_output(&pool, 1_000, false).unwrap();
+        assert_eq!(out, 999);
+        assert!((next - 1.001).abs() < 1e-12);
+    }
+
+    #[test]
+    fn quote_output_applies_cliff_fee() {
+        // 0.25% of 999.0009... leaves 996.5034...
+        let pool = sample_pool(2_500_000);
+        let (_, out) = MeteoraDammSwap::quote_output(&pool, 1_000, true).unwrap();
+        assert_eq!(out, 996);
+    }
+
+    #[test]
+    fn quote_output_rejects_out_of_range_and_empty_pools() {
+        let pool = sample_pool(0);
+        // 2_000_000 of A drops sqrt price to 1/3, below the 0.5 minimum
+        assert!(MeteoraDammSwap::quote_output(&pool, 2_000_000, true).is_err());
+        // 2_000_000 of B lifts it to 3, above the 2.0 maximum
+        assert!(MeteoraDammSwap::quote_output(&pool, 2_000_000, false).is_err());
+
+        let empty = DammSwapInfo {
+            liquidity: 0,
+            ..sample_pool(0)
+        };
+        assert!(MeteoraDammSwap::quote_output(&empty, 1_000, true).is_err());
+    }
+
+    #[test]
+    fn swap_instruction_layout() {
+        let pool = sample_pool(0);
+        let user = Pubkey::new_unique();
+        let input = Pubkey::new_unique();
+        let output = Pubkey::new_unique();
+
+        let ix = MeteoraDammSwap::build_swap_instruction(
+            &user,
+            &pool,
+            &input,
+            &output,
+            &test_params(42, 40),
+        );
+
+        let mut data = SWAP_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&40u64.to_le_bytes());
+        assert_eq!(ix.data, data);
+
+        let program_id = MeteoraDammSwap::program_id();
+        let expected = vec![
+            (
+                Pubkey::find_program_address(&[b"pool_authority"], &program_id).0,
+                false,
+                false,
+            ),
+            (pool.pool, false, true),
+            (input, false, true),
+            (output, false, true),
+            (pool.token_a_vault, false, true),
+            (pool.token_b_vault, false, true),
+            (pool.token_a_mint, false, false),
+            (pool.token_b_mint, false, false),
+            (user, true, false),
+            (spl_token_2022::id(), false, false),
+            (spl_token::id(), false, false),
+            (program_id, false, false),
+            (common::event_authority(&program_id), false, false),
+            (program_id, false, false),
+        ];
+        let actual: Vec<_> = ix
+            .accounts
+            .iter()
+            .map(|a| (a.pubkey, a.is_signer, a.is_writable))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+}
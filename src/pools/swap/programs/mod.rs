@@ -2,18 +2,43 @@
 ///
 /// This module contains the actual swap logic for different DEX programs.
 /// Each program has its own module with a standardized interface.
-use crate::pools::swap::types::{SwapError, SwapRequest, SwapResult};
+use crate::pools::swap::executor::SwapExecutor;
+use crate::pools::swap::types::{SwapError, SwapParams, SwapRequest, SwapResult};
 use crate::pools::AccountData;
 
+use solana_sdk::transaction::Transaction;
+
+// Shared helpers
+pub mod common;
+
 // Program implementations
+pub mod meteora_damm;
+pub mod pumpfun_amm;
+pub mod pumpfun_legacy;
 pub mod raydium_clmm;
 pub mod raydium_cpmm;
+pub mod raydium_legacy_amm;
 
 /// Common trait for all program swap implementations
 pub trait ProgramSwap {
+    /// Calculate swap parameters from current pool state without building a transaction
+    async fn quote_swap(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<SwapParams, SwapError>;
+
+    /// Build the unsigned swap transaction for this specific program
+    async fn build_transaction(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<(Transaction, SwapParams), SwapError>;
+
     /// Execute a swap for this specific program
     async fn execute_swap(
         request: SwapRequest,
         pool_data: AccountData,
-    ) -> Result<SwapResult, SwapError>;
+    ) -> Result<SwapResult, SwapError> {
+        let (transaction, swap_params) = Self::build_transaction(&request, &pool_data).await?;
+        SwapExecutor::execute_transaction(transaction, swap_params, &request).await
+    }
 }
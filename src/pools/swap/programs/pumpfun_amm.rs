@@ -0,0 +1,498 @@
+/// PumpFun AMM (PumpSwap) swap implementation
+///
+/// This module implements direct swaps for PumpSwap pools, where migrated PumpFun
+/// tokens trade against WSOL. Buys use `buy_exact_quote_in` so the SOL spent is exact;
+/// sells use `sell`. LP, protocol and coin creator fees are read from the global config.
+use super::common;
+use super::ProgramSwap;
+use crate::constants::{PUMP_FUN_AMM_PROGRAM_ID, SOL_DECIMALS};
+use crate::logger::{self, LogTag};
+use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest};
+use crate::pools::AccountData;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program,
+    transaction::Transaction,
+};
+use std::str::FromStr;
+
+/// PumpFun fee program (owns the fee config accounts)
+const PUMP_FEE_PROGRAM_ID: &str = "pfeeUxB6jkeY1Hxd7CsFCAjcbHA9rWtchMGdZ6VojVZ";
+
+/// Anchor discriminators (first 8 bytes of SHA256("global:<name>"))
+const BUY_EXACT_QUOTE_IN_DISCRIMINATOR: [u8; 8] = [198, 46, 21, 82, 180, 217, 232, 112];
+const SELL_DISCRIMINATOR: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+
+/// Basis point denominator for PumpSwap fees
+const BPS_DENOMINATOR: u64 = 10_000;
+
+// Pool account offsets
+const POOL_BASE_MINT_OFFSET: usize = 43;
+const POOL_QUOTE_MINT_OFFSET: usize = 75;
+const POOL_BASE_VAULT_OFFSET: usize = 139;
+const POOL_QUOTE_VAULT_OFFSET: usize = 171;
+const POOL_COIN_CREATOR_OFFSET: usize = 211;
+
+// GlobalConfig account offsets
+const CONFIG_LP_FEE_OFFSET: usize = 40;
+const CONFIG_PROTOCOL_FEE_OFFSET: usize = 48;
+const CONFIG_FEE_RECIPIENTS_OFFSET: usize = 57;
+const CONFIG_COIN_CREATOR_FEE_OFFSET: usize = 313;
+
+/// Decoded PumpSwap pool fields needed for swapping
+#[derive(Debug, Clone)]
+struct PumpAmmSwapInfo {
+    pool: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    pool_base_vault: Pubkey,
+    pool_quote_vault: Pubkey,
+    coin_creator: Pubkey,
+}
+
+/// Fee settings from the PumpSwap global config
+#[derive(Debug, Clone)]
+struct PumpAmmFees {
+    total_fee_bps: u64,
+    protocol_fee_recipient: Pubkey,
+}
+
+/// PumpFun AMM swap implementation
+pub struct PumpFunAmmSwap;
+
+impl ProgramSwap for PumpFunAmmSwap {
+    async fn quote_swap(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<SwapParams, SwapError> {
+        let pool_info = Self::decode_pool_state(pool_data)?;
+        let fees = Self::fetch_fees().await?;
+        Self::calculate_swap_params(request, &pool_info, &fees).await
+    }
+
+    async fn build_transaction(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<(Transaction, SwapParams), SwapError> {
+        logger::info(
+            LogTag::System,
+            &format!("Executing PumpFun AMM {:?} swap", request.direction),
+        );
+
+        let pool_info = Self::decode_pool_state(pool_data)?;
+        let fees = Self::fetch_fees().await?;
+        let wallet = common::load_wallet()?;
+        let swap_params = Self::calculate_swap_params(request, &pool_info, &fees).await?;
+
+        logger::info(
+            LogTag::System,
+            &format!(
+                "PumpFun AMM swap: {:.6} → {:.6} (min: {:.6})",
+                swap_params.input_amount, swap_params.expected_output, swap_params.minimum_output
+            ),
+        );
+
+        let wallet_pubkey = wallet.pubkey();
+        let base_token_program = common::get_token_program_for_mint(&pool_info.base_mint).await?;
+
+        let (wsol_ata, token_ata, mut instructions) = common::prepare_token_accounts(
+            &wallet_pubkey,
+            &pool_info.base_mint,
+            &base_token_program,
+        );
+
+        if request.direction == SwapDirection::Buy {
+            instructions.extend(common::wrap_sol_instructions(
+                &wallet_pubkey,
+                &wsol_ata,
+                swap_params.input_amount_raw,
+            )?);
+        }
+
+        instructions.push(Self::build_swap_instruction(
+            &wallet_pubkey,
+            &pool_info,
+            &fees,
+            &token_ata,
+            &wsol_ata,
+            &base_token_program,
+            request.direction,
+            &swap_params,
+        ));
+
+        instructions.push(common::close_wsol_instruction(&wallet_pubkey, &wsol_ata)?);
+
+        let transaction =
+            common::finalize_transaction(instructions, &wallet_pubkey, request).await?;
+        Ok((transaction, swap_params))
+    }
+}
+
+impl PumpFunAmmSwap {
+    fn program_id() -> Pubkey {
+        Pubkey::from_str(PUMP_FUN_AMM_PROGRAM_ID).unwrap()
+    }
+
+    fn global_config() -> Pubkey {
+        Pubkey::find_program_address(&[b"global_config"], &Self::program_id()).0
+    }
+
+    /// Decode the pool account (token must be base, WSOL must be quote)
+    fn decode_pool_state(pool_data: &AccountData) -> Result<PumpAmmSwapInfo, SwapError> {
+        let data = &pool_data.data;
+        let pubkey_at = |offset: usize| {
+            common::read_pubkey(data, offset)
+                .ok_or_else(|| SwapError::DecoderError("Invalid PumpFun AMM pool".to_string()))
+        };
+
+        let info = PumpAmmSwapInfo {
+            pool: pool_data.pubkey,
+            base_mint: pubkey_at(POOL_BASE_MINT_OFFSET)?,
+            quote_mint: pubkey_at(POOL_QUOTE_MINT_OFFSET)?,
+            pool_base_vault: pubkey_at(POOL_BASE_VAULT_OFFSET)?,
+            pool_quote_vault: pubkey_at(POOL_QUOTE_VAULT_OFFSET)?,
+            coin_creator: pubkey_at(POOL_COIN_CREATOR_OFFSET)?,
+        };
+
+        if info.quote_mint != common::wsol_mint() {
+            return Err(SwapError::InvalidPool(format!(
+                "PumpFun AMM pool quote is not SOL: {}",
+                info.quote_mint
+            )));
+        }
+
+        Ok(info)
+    }
+
+    /// Read fee rates and a protocol fee recipient from the global config
+    async fn fetch_fees() -> Result<PumpAmmFees, SwapError> {
+        let account = common::fetch_account(&Self::global_config()).await?;
+        let data = &account.data;
+        let invalid = || SwapError::DecoderError("Invalid PumpFun AMM global config".to_string());
+
+        let lp_fee = common::read_u64(data, CONFIG_LP_FEE_OFFSET).ok_or_else(invalid)?;
+        let protocol_fee =
+            common::read_u64(data, CONFIG_PROTOCOL_FEE_OFFSET).ok_or_else(invalid)?;
+        let creator_fee = common::read_u64(data, CONFIG_COIN_CREATOR_FEE_OFFSET).unwrap_or(0);
+
+        let protocol_fee_recipient = (0..8)
+            .filter_map(|i| common::read_pubkey(data, CONFIG_FEE_RECIPIENTS_OFFSET + i * 32))
+            .find(|recipient| *recipient != Pubkey::default())
+            .ok_or_else(invalid)?;
+
+        Ok(PumpAmmFees {
+            total_fee_bps: lp_fee + protocol_fee + creator_fee,
+            protocol_fee_recipient,
+        })
+    }
+
+    /// Calculate swap parameters using constant product formula
+    ///
+    /// On buys the fee is charged on top of the SOL that reaches the pool; on sells it
+    /// is deducted from the SOL output.
+    async fn calculate_swap_params(
+        request: &SwapRequest,
+        pool_info: &PumpAmmSwapInfo,
+        fees: &PumpAmmFees,
+    ) -> Result<SwapParams, SwapError> {
+        let token_decimals = common::token_decimals(&pool_info.base_mint).await?;
+        let base_reserve = common::get_token_account_balance(&pool_info.pool_base_vault).await?;
+        let quote_reserve = common::get_token_account_balance(&pool_info.pool_quote_vault).await?;
+
+        logger::debug(
+            LogTag::System,
+            &format!(
+                "PumpFun AMM reserves: token={} sol={} fee={}bps",
+                base_reserve, quote_reserve, fees.total_fee_bps
+            ),
+        );
+
+        let (input_amount_raw, expected_output_raw) = Self::quote_amounts(
+            request,
+            base_reserve,
+            quote_reserve,
+            fees.total_fee_bps,
+            token_decimals,
+        )?;
+
+        common::build_swap_params(
+            request,
+            input_amount_raw,
+            expected_output_raw,
+            token_decimals,
+        )
+    }
+
+    /// Raw input and expected output for the request against the pool reserves
+    fn quote_amounts(
+        request: &SwapRequest,
+        base_reserve: u64,
+        quote_reserve: u64,
+        total_fee_bps: u64,
+        token_decimals: u8,
+    ) -> Result<(u64, u64), SwapError> {
+        let amounts = match request.direction {
+            SwapDirection::Buy => {
+                let sol_in = request.input_amount_raw(SOL_DECIMALS);
+                let sol_to_pool = ((sol_in as u128) * (BPS_DENOMINATOR as u128)
+                    / ((BPS_DENOMINATOR + total_fee_bps) as u128))
+                    as u64;
+                let tokens_out = common::constant_product_output(
+                    quote_reserve,
+                    base_reserve,
+                    sol_to_pool,
+                    0,
+                    1,
+                )?;
+                (sol_in, tokens_out)
+            }
+            SwapDirection::Sell => {
+                let tokens_in = request.input_amount_raw(token_decimals);
+                let sol_out =
+                    common::constant_product_output(base_reserve, quote_reserve, tokens_in, 0, 1)?;
+                let sol_after_fee = ((sol_out as u128)
+                    * (BPS_DENOMINATOR.saturating_sub(total_fee_bps) as u128)
+                    / (BPS_DENOMINATOR as u128)) as u64;
+                (tokens_in, sol_after_fee)
+            }
+        };
+
+        Ok(amounts)
+    }
+
+    /// Build the buy_exact_quote_in / sell instruction
+    fn build_swap_instruction(
+        user: &Pubkey,
+        pool_info: &PumpAmmSwapInfo,
+        fees: &PumpAmmFees,
+        user_base_account: &Pubkey,
+        user_quote_account: &Pubkey,
+        base_token_program: &Pubkey,
+        direction: SwapDirection,
+        swap_params: &SwapParams,
+    ) -> Instruction {
+        let program_id = Self::program_id();
+        let fee_program = Pubkey::from_str(PUMP_FEE_PROGRAM_ID).unwrap();
+        let quote_token_program = spl_token::id();
+
+        let protocol_fee_recipient_ata =
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                &fees.protocol_fee_recipient,
+                &pool_info.quote_mint,
+                &quote_token_program,
+            );
+        let coin_creator_vault_authority = Pubkey::find_program_address(
+            &[b"creator_vault", pool_info.coin_creator.as_ref()],
+            &program_id,
+        )
+        .0;
+        let coin_creator_vault_ata =
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                &coin_creator_vault_authority,
+                &pool_info.quote_mint,
+                &quote_token_program,
+            );
+        let fee_config =
+            Pubkey::find_program_address(&[b"fee_config", program_id.as_ref()], &fee_program).0;
+
+        let mut accounts = vec![
+            AccountMeta::new(pool_info.pool, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(Self::global_config(), false),
+            AccountMeta::new_readonly(pool_info.base_mint, false),
+            AccountMeta::new_readonly(pool_info.quote_mint, false),
+            AccountMeta::new(*user_base_account, false),
+            AccountMeta::new(*user_quote_account, false),
+            AccountMeta::new(pool_info.pool_base_vault, false),
+            AccountMeta::new(pool_info.pool_quote_vault, false),
+            AccountMeta::new_readonly(fees.protocol_fee_recipient, false),
+            AccountMeta::new(protocol_fee_recipient_ata, false),
+            AccountMeta::new_readonly(*base_token_program, false),
+            AccountMeta::new_readonly(quote_token_program, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(common::event_authority(&program_id), false),
+            AccountMeta::new_readonly(program_id, false),
+            AccountMeta::new(coin_creator_vault_ata, false),
+            AccountMeta::new_readonly(coin_creator_vault_authority, false),
+        ];
+
+        let mut data = Vec::with_capacity(25);
+        match direction {
+            SwapDirection::Buy => {
+                let global_volume_accumulator =
+                    Pubkey::find_program_address(&[b"global_volume_accumulator"], &program_id).0;
+                let user_volume_accumulator = Pubkey::find_program_address(
+                    &[b"user_volume_accumulator", user.as_ref()],
+                    &program_id,
+                )
+                .0;
+                accounts.push(AccountMeta::new_readonly(global_volume_accumulator, false));
+                accounts.push(AccountMeta::new(user_volume_accumulator, false));
+
+                // buy_exact_quote_in(spendable_quote_in, min_base_amount_out, track_volume)
+                data.extend_from_slice(&BUY_EXACT_QUOTE_IN_DISCRIMINATOR);
+                data.extend_from_slice(&swap_params.input_amount_raw.to_le_bytes());
+                data.extend_from_slice(&swap_params.minimum_output_raw.to_le_bytes());
+                data.push(0);
+            }
+            SwapDirection::Sell => {
+                // sell(base_amount_in, min_quote_amount_out)
+                data.extend_from_slice(&SELL_DISCRIMINATOR);
+                data.extend_from_slice(&swap_params.input_amount_raw.to_le_bytes());
+                data.extend_from_slice(&swap_params.minimum_output_raw.to_le_bytes());
+            }
+        }
+
+        accounts.push(AccountMeta::new_readonly(fee_config, false));
+        accounts.push(AccountMeta::new_readonly(fee_program, false));
+
+        Instruction {
+            program_id,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pools::swap::programs::common::{test_params, test_request};
+
+    fn account_flags(ix: &Instruction) -> Vec<(Pubkey, bool, bool)> {
+        ix.accounts
+            .iter()
+            .map(|a| (a.pubkey, a.is_signer, a.is_writable))
+            .collect()
+    }
+
+    #[test]
+    fn quote_amounts_charge_fee_on_sol_side() {
+        // 1B tokens (6 decimals) / 100 SOL, 1.25% total fee
+        let base_reserve = 1_000_000_000_000_000;
+        let quote_reserve = 100_000_000_000;
+
+        // Buy: fee comes on top, so 1 SOL puts 1/1.0125 SOL into the pool
+        let buy = test_request(SwapDirection::Buy, 1_000_000_000);
+        assert_eq!(
+            PumpFunAmmSwap::quote_amounts(&buy, base_reserve, quote_reserve, 125, 6).unwrap(),
+            (1_000_000_000, 9_779_951_090_560)
+        );
+
+        // Sell: 99_900_099 lamports out of the curve, minus 1.25%
+        let sell = test_request(SwapDirection::Sell, 1_000_000_000_000);
+        assert_eq!(
+            PumpFunAmmSwap::quote_amounts(&sell, base_reserve, quote_reserve, 125, 6).unwrap(),
+            (1_000_000_000_000, 98_651_347)
+        );
+    }
+
+    #[test]
+    fn swap_instruction_layout() {
+        let pool_info = PumpAmmSwapInfo {
+            pool: Pubkey::new_unique(),
+            base_mint: Pubkey::new_unique(),
+            quote_mint: common::wsol_mint(),
+            pool_base_vault: Pubkey::new_unique(),
+            pool_quote_vault: Pubkey::new_unique(),
+            coin_creator: Pubkey::new_unique(),
+        };
+        let fees = PumpAmmFees {
+            total_fee_bps: 125,
+            protocol_fee_recipient: Pubkey::new_unique(),
+        };
+        let user = Pubkey::new_unique();
+        let user_base = Pubkey::new_unique();
+        let user_quote = Pubkey::new_unique();
+        let build = |direction| {
+            PumpFunAmmSwap::build_swap_instruction(
+                &user,
+                &pool_info,
+                &fees,
+                &user_base,
+                &user_quote,
+                &spl_token::id(),
+                direction,
+                &test_params(7_000, 6_000),
+            )
+        };
+
+        let buy = build(SwapDirection::Buy);
+        let sell = build(SwapDirection::Sell);
+
+        let mut buy_data = BUY_EXACT_QUOTE_IN_DISCRIMINATOR.to_vec();
+        buy_data.extend_from_slice(&7_000u64.to_le_bytes());
+        buy_data.extend_from_slice(&6_000u64.to_le_bytes());
+        buy_data.push(0);
+        assert_eq!(buy.data, buy_data);
+
+        let mut sell_data = SELL_DISCRIMINATOR.to_vec();
+        sell_data.extend_from_slice(&7_000u64.to_le_bytes());
+        sell_data.extend_from_slice(&6_000u64.to_le_bytes());
+        assert_eq!(sell.data, sell_data);
+
+        let program_id = PumpFunAmmSwap::program_id();
+        let fee_program = Pubkey::from_str(PUMP_FEE_PROGRAM_ID).unwrap();
+        let creator_vault_authority = Pubkey::find_program_address(
+            &[b"creator_vault", pool_info.coin_creator.as_ref()],
+            &program_id,
+        )
+        .0;
+        let ata = |owner: &Pubkey| {
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                owner,
+                &pool_info.quote_mint,
+                &spl_token::id(),
+            )
+        };
+        let fee_config =
+            Pubkey::find_program_address(&[b"fee_config", program_id.as_ref()], &fee_program).0;
+
+        let shared = vec![
+            (pool_info.pool, false, true),
+            (user, true, true),
+            (PumpFunAmmSwap::global_config(), false, false),
+            (pool_info.base_mint, false, false),
+            (pool_info.quote_mint, false, false),
+            (user_base, false, true),
+            (user_quote, false, true),
+            (pool_info.pool_base_vault, false, true),
+            (pool_info.pool_quote_vault, false, true),
+            (fees.protocol_fee_recipient, false, false),
+            (ata(&fees.protocol_fee_recipient), false, true),
+            (spl_token::id(), false, false),
+            (spl_token::id(), false, false),
+            (system_program::id(), false, false),
+            (spl_associated_token_account::id(), false, false),
+            (common::event_authority(&program_id), false, false),
+            (program_id, false, false),
+            (ata(&creator_vault_authority), false, true),
+            (creator_vault_authority, false, false),
+        ];
+
+        let mut expected_sell = shared.clone();
+        expected_sell.push((fee_config, false, false));
+        expected_sell.push((fee_program, false, false));
+        assert_eq!(account_flags(&sell), expected_sell);
+
+        let mut expected_buy = shared;
+        expected_buy.push((
+            Pubkey::find_program_address(&[b"global_volume_accumulator"], &program_id).0,
+            false,
+            false,
+        ));
+        expected_buy.push((
+            Pubkey::find_program_address(&[b"user_volume_accumulator", user.as_ref()], &program_id)
+                .0,
+            false,
+            true,
+        ));
+        expected_buy.push((fee_config, false, false));
+        expected_buy.push((fee_program, false, false));
+        assert_eq!(account_flags(&buy), expected_buy);
+    }
+}
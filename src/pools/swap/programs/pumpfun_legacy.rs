@@ -0,0 +1,516 @@
+/// PumpFun bonding curve swap implementation
+///
+/// This module implements direct buys and sells against PumpFun bonding curves
+/// (tokens that have not migrated to an AMM yet). Bonding curves trade native SOL, so
+/// no WSOL account is involved. Buys are exact-out: the curve delivers the minimum
+/// token amount and never charges more than the requested SOL.
+use super::common;
+use super::ProgramSwap;
+use crate::constants::{PUMP_FUN_LEGACY_PROGRAM_ID, SOL_DECIMALS};
+use crate::logger::{self, LogTag};
+use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest};
+use crate::pools::AccountData;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program,
+    transaction::Transaction,
+};
+use std::str::FromStr;
+
+/// PumpFun fee program (owns the fee config accounts)
+const PUMP_FEE_PROGRAM_ID: &str = "pfeeUxB6jkeY1Hxd7CsFCAjcbHA9rWtchMGdZ6VojVZ";
+
+/// Anchor discriminators (first 8 bytes of SHA256("global:<name>"))
+const BUY_DISCRIMINATOR: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+const SELL_DISCRIMINATOR: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+
+/// Basis point denominator for bonding curve fees
+const BPS_DENOMINATOR: u64 = 10_000;
+
+// BondingCurve account offsets
+const CURVE_VIRTUAL_TOKEN_OFFSET: usize = 8;
+const CURVE_VIRTUAL_SOL_OFFSET: usize = 16;
+const CURVE_REAL_TOKEN_OFFSET: usize = 24;
+const CURVE_COMPLETE_OFFSET: usize = 48;
+const CURVE_CREATOR_OFFSET: usize = 49;
+
+// Global account offsets
+const GLOBAL_FEE_RECIPIENT_OFFSET: usize = 41;
+const GLOBAL_FEE_BPS_OFFSET: usize = 105;
+const GLOBAL_CREATOR_FEE_BPS_OFFSET: usize = 154;
+
+/// Decoded bonding curve fields needed for swapping
+#[derive(Debug, Clone)]
+struct BondingCurveSwapInfo {
+    bonding_curve: Pubkey,
+    virtual_token_reserves: u64,
+    virtual_sol_reserves: u64,
+    real_token_reserves: u64,
+    creator: Pubkey,
+}
+
+/// Fee settings from the PumpFun global account
+#[derive(Debug, Clone)]
+struct BondingCurveFees {
+    total_fee_bps: u64,
+    fee_recipient: Pubkey,
+}
+
+/// PumpFun bonding curve swap implementation
+pub struct PumpFunLegacySwap;
+
+impl ProgramSwap for PumpFunLegacySwap {
+    async fn quote_swap(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<SwapParams, SwapError> {
+        let curve = Self::decode_pool_state(pool_data)?;
+        let fees = Self::fetch_fees().await?;
+        Self::calculate_swap_params(request, &curve, &fees).await
+    }
+
+    async fn build_transaction(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<(Transaction, SwapParams), SwapError> {
+        logger::info(
+            LogTag::System,
+            &format!(
+                "Executing PumpFun bonding curve {:?} swap",
+                request.direction
+            ),
+        );
+
+        let curve = Self::decode_pool_state(pool_data)?;
+        let fees = Self::fetch_fees().await?;
+        let wallet = common::load_wallet()?;
+        let swap_params = Self::calculate_swap_params(request, &curve, &fees).await?;
+
+        logger::info(
+            LogTag::System,
+            &format!(
+                "Bonding curve swap: {:.6} → {:.6} (min: {:.6})",
+                swap_params.input_amount, swap_params.expected_output, swap_params.minimum_output
+            ),
+        );
+
+        let wallet_pubkey = wallet.pubkey();
+        let token_mint = request.token_mint;
+        let token_program = common::get_token_program_for_mint(&token_mint).await?;
+        let user_token_account =
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                &wallet_pubkey,
+                &token_mint,
+                &token_program,
+            );
+
+        let instructions = vec![
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &wallet_pubkey,
+                &wallet_pubkey,
+                &token_mint,
+                &token_program,
+            ),
+            Self::build_swap_instruction(
+                &wallet_pubkey,
+                &token_mint,
+                &token_program,
+                &user_token_account,
+                &curve,
+                &fees,
+                request.direction,
+                &swap_params,
+            ),
+        ];
+
+        let transaction =
+            common::finalize_transaction(instructions, &wallet_pubkey, request).await?;
+        Ok((transaction, swap_params))
+    }
+}
+
+impl PumpFunLegacySwap {
+    fn program_id() -> Pubkey {
+        Pubkey::from_str(PUMP_FUN_LEGACY_PROGRAM_ID).unwrap()
+    }
+
+    fn global() -> Pubkey {
+        Pubkey::find_program_address(&[b"global"], &Self::program_id()).0
+    }
+
+    /// Decode the bonding curve account; completed curves have migrated and cannot trade
+    fn decode_pool_state(pool_data: &AccountData) -> Result<BondingCurveSwapInfo, SwapError> {
+        let data = &pool_data.data;
+        let invalid = || SwapError::DecoderError("Invalid PumpFun bonding curve".to_string());
+
+        if data.get(CURVE_COMPLETE_OFFSET).copied().unwrap_or(0) != 0 {
+            return Err(SwapError::InvalidPool(
+                "Bonding curve is complete (token migrated)".to_string(),
+            ));
+        }
+
+        Ok(BondingCurveSwapInfo {
+            bonding_curve: pool_data.pubkey,
+            virtual_token_reserves: common::read_u64(data, CURVE_VIRTUAL_TOKEN_OFFSET)
+                .ok_or_else(invalid)?,
+            virtual_sol_reserves: common::read_u64(data, CURVE_VIRTUAL_SOL_OFFSET)
+                .ok_or_else(invalid)?,
+            real_token_reserves: common::read_u64(data, CURVE_REAL_TOKEN_OFFSET)
+                .ok_or_else(invalid)?,
+            creator: common::read_pubkey(data, CURVE_CREATOR_OFFSET).ok_or_else(invalid)?,
+        })
+    }
+
+    /// Read protocol/creator fee rates and the fee recipient from the global account
+    async fn fetch_fees() -> Result<BondingCurveFees, SwapError> {
+        let account = common::fetch_account(&Self::global()).await?;
+        let data = &account.data;
+        let invalid = || SwapError::DecoderError("Invalid PumpFun global account".to_string());
+
+        let fee_bps = common::read_u64(data, GLOBAL_FEE_BPS_OFFSET).ok_or_else(invalid)?;
+        let creator_fee_bps = common::read_u64(data, GLOBAL_CREATOR_FEE_BPS_OFFSET).unwrap_or(0);
+
+        Ok(BondingCurveFees {
+            total_fee_bps: fee_bps + creator_fee_bps,
+            fee_recipient: common::read_pubkey(data, GLOBAL_FEE_RECIPIENT_OFFSET)
+                .ok_or_else(invalid)?,
+        })
+    }
+
+    /// Calculate swap parameters from the virtual reserves
+    async fn calculate_swap_params(
+        request: &SwapRequest,
+        curve: &BondingCurveSwapInfo,
+        fees: &BondingCurveFees,
+    ) -> Result<SwapParams, SwapError> {
+        // The curve account does not store its mint; make sure it belongs to the token
+        let expected_curve = Pubkey::find_program_address(
+            &[b"bonding-curve", request.token_mint.as_ref()],
+            &Self::program_id(),
+        )
+        .0;
+        if expected_curve != curve.bonding_curve {
+            return Err(SwapError::InvalidPool(format!(
+                "Bonding curve {} does not belong to mint {}",
+                curve.bonding_curve, request.token_mint
+            )));
+        }
+
+        let token_decimals = common::token_decimals(&request.token_mint).await?;
+
+        let (input_amount_raw, expected_output_raw) =
+            Self::quote_amounts(request, curve, fees.total_fee_bps, token_decimals)?;
+
+        logger::debug(
+            LogTag::System,
+            &format!(
+                "Bonding curve quote: in={} out={} (virt_sol={} virt_token={} fee={}bps)",
+                input_amount_raw,
+                expected_output_raw,
+                curve.virtual_sol_reserves,
+                curve.virtual_token_reserves,
+                fees.total_fee_bps
+            ),
+        );
+
+        common::build_swap_params(
+            request,
+            input_amount_raw,
+            expected_output_raw,
+            token_decimals,
+        )
+    }
+
+    /// Raw input and expected output against the virtual reserves; buys are capped at
+    /// the tokens the curve actually holds
+    fn quote_amounts(
+        request: &SwapRequest,
+        curve: &BondingCurveSwapInfo,
+        total_fee_bps: u64,
+        token_decimals: u8,
+    ) -> Result<(u64, u64), SwapError> {
+        let amounts = match request.direction {
+            SwapDirection::Buy => {
+                let sol_in = request.input_amount_raw(SOL_DECIMALS);
+                let sol_to_curve = ((sol_in as u128) * (BPS_DENOMINATOR as u128)
+                    / ((BPS_DENOMINATOR + total_fee_bps) as u128))
+                    as u64;
+                let tokens_out = common::constant_product_output(
+                    curve.virtual_sol_reserves,
+                    curve.virtual_token_reserves,
+                    sol_to_curve,
+                    0,
+                    1,
+                )?
+                .min(curve.real_token_reserves);
+                (sol_in, tokens_out)
+            }
+            SwapDirection::Sell => {
+                let tokens_in = request.input_amount_raw(token_decimals);
+                let sol_out = common::constant_product_output(
+                    curve.virtual_token_reserves,
+                    curve.virtual_sol_reserves,
+                    tokens_in,
+                    0,
+                    1,
+                )?;
+                let sol_after_fee = ((sol_out as u128)
+                    * (BPS_DENOMINATOR.saturating_sub(total_fee_bps) as u128)
+                    / (BPS_DENOMINATOR as u128)) as u64;
+                (tokens_in, sol_after_fee)
+            }
+        };
+
+        Ok(amounts)
+    }
+
+    /// Build the buy / sell instruction
+    fn build_swap_instruction(
+        user: &Pubkey,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+        user_token_account: &Pubkey,
+        curve: &BondingCurveSwapInfo,
+        fees: &BondingCurveFees,
+        direction: SwapDirection,
+        swap_params: &SwapParams,
+    ) -> Instruction {
+        let program_id = Self::program_id();
+        let fee_program = Pubkey::from_str(PUMP_FEE_PROGRAM_ID).unwrap();
+
+        let associated_bonding_curve =
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                &curve.bonding_curve,
+                mint,
+                token_program,
+            );
+        let creator_vault =
+            Pubkey::find_program_address(&[b"creator-vault", curve.creator.as_ref()], &program_id)
+                .0;
+        let fee_config =
+            Pubkey::find_program_address(&[b"fee_config", program_id.as_ref()], &fee_program).0;
+        let event_authority = common::event_authority(&program_id);
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(Self::global(), false),
+            AccountMeta::new(fees.fee_recipient, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(curve.bonding_curve, false),
+            AccountMeta::new(associated_bonding_curve, false),
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(*user, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+
+        let mut data = Vec::with_capacity(25);
+        match direction {
+            SwapDirection::Buy => {
+                let global_volume_accumulator =
+                    Pubkey::find_program_address(&[b"global_volume_accumulator"], &program_id).0;
+                let user_volume_accumulator = Pubkey::find_program_address(
+                    &[b"user_volume_accumulator", user.as_ref()],
+                    &program_id,
+                )
+                .0;
+
+                accounts.extend([
+                    AccountMeta::new_readonly(*token_program, false),
+                    AccountMeta::new(creator_vault, false),
+                    AccountMeta::new_readonly(event_authority, false),
+                    AccountMeta::new_readonly(program_id, false),
+                    AccountMeta::new_readonly(global_volume_accumulator, false),
+                    AccountMeta::new(user_volume_accumulator, false),
+                ]);
+
+                // buy(amount, max_sol_cost, track_volume): exact tokens out, SOL capped at input
+                data.extend_from_slice(&BUY_DISCRIMINATOR);
+                data.extend_from_slice(&swap_params.minimum_output_raw.to_le_bytes());
+                data.extend_from_slice(&swap_params.input_amount_raw.to_le_bytes());
+                data.push(0);
+            }
+            SwapDirection::Sell => {
+                accounts.extend([
+                    AccountMeta::new(creator_vault, false),
+                    AccountMeta::new_readonly(*token_program, false),
+                    AccountMeta::new_readonly(event_authority, false),
+                    AccountMeta::new_readonly(program_id, false),
+                ]);
+
+                // sell(amount, min_sol_output)
+                data.extend_from_slice(&SELL_DISCRIMINATOR);
+                data.extend_from_slice(&swap_params.input_amount_raw.to_le_bytes());
+                data.extend_from_slice(&swap_params.minimum_output_raw.to_le_bytes());
+            }
+        }
+
+        accounts.push(AccountMeta::new_readonly(fee_config, false));
+        accounts.push(AccountMeta::new_readonly(fee_program, false));
+
+        Instruction {
+            program_id,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pools::swap::programs::common::{test_params, test_request};
+
+    fn fresh_curve() -> BondingCurveSwapInfo {
+        BondingCurveSwapInfo {
+            bonding_curve: Pubkey::new_unique(),
+            virtual_token_reserves: 1_073_000_000_000_000,
+            virtual_sol_reserves: 30_000_000_000,
+            real_token_reserves: 793_100_000_000_000,
+            creator: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn quote_amounts_on_fresh_curve() {
+        let curve = fresh_curve();
+
+        // 1 SOL at 1% fee: 990_099_009 lamports reach the curve
+        let buy = test_request(SwapDirection::Buy, 1_000_000_000);
+        assert_eq!(
+            PumpFunLegacySwap::quote_amounts(&buy, &curve, 100, 6).unwrap(),
+            (1_000_000_000, 34_281_150_129_545)
+        );
+
+        // 1M tokens: 27_932_960 lamports out of the curve, minus 1%
+        let sell = test_request(SwapDirection::Sell, 1_000_000_000_000);
+        assert_eq!(
+            PumpFunLegacySwap::quote_amounts(&sell, &curve, 100, 6).unwrap(),
+            (1_000_000_000_000, 27_653_630)
+        );
+    }
+
+    #[test]
+    fn quote_amounts_cap_buys_at_real_reserves() {
+        let curve = BondingCurveSwapInfo {
+            virtual_token_reserves: 100_000_000_000_000,
+            virtual_sol_reserves: 80_000_000_000,
+            real_token_reserves: 5_000_000_000_000,
+            ..fresh_curve()
+        };
+
+        // The virtual curve would pay out 11_013_215_859_021
+        let buy = test_request(SwapDirection::Buy, 10_000_000_000);
+        assert_eq!(
+            PumpFunLegacySwap::quote_amounts(&buy, &curve, 100, 6).unwrap(),
+            (10_000_000_000, 5_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn swap_instruction_layout() {
+        let curve = fresh_curve();
+        let fees = BondingCurveFees {
+            total_fee_bps: 100,
+            fee_recipient: Pubkey::new_unique(),
+        };
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let user_token = Pubkey::new_unique();
+        let token_program = spl_token::id();
+        let build = |direction| {
+            PumpFunLegacySwap::build_swap_instruction(
+                &user,
+                &mint,
+                &token_program,
+                &user_token,
+                &curve,
+                &fees,
+                direction,
+                &test_params(2_000_000, 1_500_000_000),
+            )
+        };
+        let flags = |ix: &Instruction| -> Vec<(Pubkey, bool, bool)> {
+            ix.accounts
+                .iter()
+                .map(|a| (a.pubkey, a.is_signer, a.is_writable))
+                .collect()
+        };
+
+        let program_id = PumpFunLegacySwap::program_id();
+        let fee_program = Pubkey::from_str(PUMP_FEE_PROGRAM_ID).unwrap();
+        let associated_bonding_curve =
+            spl_associated_token_account::get_associated_token_address_with_program_id(
+                &curve.bonding_curve,
+                &mint,
+                &token_program,
+            );
+        let creator_vault =
+            Pubkey::find_program_address(&[b"creator-vault", curve.creator.as_ref()], &program_id)
+                .0;
+        let fee_config =
+            Pubkey::find_program_address(&[b"fee_config", program_id.as_ref()], &fee_program).0;
+        let event_authority = common::event_authority(&program_id);
+        let shared = vec![
+            (PumpFunLegacySwap::global(), false, false),
+            (fees.fee_recipient, false, true),
+            (mint, false, false),
+            (curve.bonding_curve, false, true),
+            (associated_bonding_curve, false, true),
+            (user_token, false, true),
+            (user, true, true),
+            (system_program::id(), false, false),
+        ];
+
+        // Buy is exact-out: token amount first, SOL cap second
+        let buy = build(SwapDirection::Buy);
+        let mut buy_data = BUY_DISCRIMINATOR.to_vec();
+        buy_data.extend_from_slice(&1_500_000_000u64.to_le_bytes());
+        buy_data.extend_from_slice(&2_000_000u64.to_le_bytes());
+        buy_data.push(0);
+        assert_eq!(buy.data, buy_data);
+
+        let mut expected_buy = shared.clone();
+        expected_buy.extend([
+            (token_program, false, false),
+            (creator_vault, false, true),
+            (event_authority, false, false),
+            (program_id, false, false),
+            (
+                Pubkey::find_program_address(&[b"global_volume_accumulator"], &program_id).0,
+                false,
+                false,
+            ),
+            (
+                Pubkey::find_program_address(
+                    &[b"user_volume_accumulator", user.as_ref()],
+                    &program_id,
+                )
+                .0,
+                false,
+                true,
+            ),
+            (fee_config, false, false),
+            (fee_program, false, false),
+        ]);
+        assert_eq!(flags(&buy), expected_buy);
+
+        let sell = build(SwapDirection::Sell);
+        let mut sell_data = SELL_DISCRIMINATOR.to_vec();
+        sell_data.extend_from_slice(&2_000_000u64.to_le_bytes());
+        sell_data.extend_from_slice(&1_500_000_000u64.to_le_bytes());
+        assert_eq!(sell.data, sell_data);
+
+        let mut expected_sell = shared;
+        expected_sell.extend([
+            (creator_vault, false, true),
+            (token_program, false, false),
+            (event_authority, false, false),
+            (program_id, false, false),
+            (fee_config, false, false),
+            (fee_program, false, false),
+        ]);
+        assert_eq!(flags(&sell), expected_sell);
+    }
+}
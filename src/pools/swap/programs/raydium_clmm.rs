@@ -3,52 +3,67 @@
 /// This module implements direct swaps for Raydium Concentrated Liquidity pools.
 /// It integrates with the centralized Raydium CLMM decoder and provides proper
 /// account derivation and swap calculations based on the Uniswap V3 model.
+use super::common;
 use super::ProgramSwap;
-use crate::constants::{
-    MEMO_PROGRAM_ID, RAYDIUM_CLMM_PROGRAM_ID, SOL_MINT, SPL_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
-};
+use crate::constants::{MEMO_PROGRAM_ID, RAYDIUM_CLMM_PROGRAM_ID, SOL_DECIMALS, SOL_MINT};
 use crate::logger::{self, LogTag};
 use crate::pools::decoders::raydium_clmm::{ClmmPoolInfo, RaydiumClmmDecoder};
-use crate::pools::swap::executor::SwapExecutor;
-use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest, SwapResult};
+use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest};
 use crate::pools::AccountData;
 use crate::rpc::{get_rpc_client, RpcClientMethods};
-use crate::utils::sol_to_lamports;
 
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    signature::{Keypair, Signer},
-    system_instruction,
+    signature::Signer,
     transaction::Transaction,
 };
-use spl_associated_token_account;
-use spl_token;
 use spl_token_2022;
 use std::collections::HashMap;
 use std::str::FromStr;
 
+/// Trade fee denominator used by Raydium AMM configs
+const FEE_RATE_DENOMINATOR: u64 = 1_000_000;
+
+/// Offset of `trade_fee_rate` (u32) in the CLMM AmmConfig account
+/// (discriminator 8 + bump 1 + index 2 + owner 32 + protocol_fee_rate 4)
+const AMM_CONFIG_TRADE_FEE_OFFSET: usize = 47;
+
+/// Ticks per tick array account
+const TICK_ARRAY_SIZE: i32 = 60;
+
+/// Tick arrays passed to the swap (current one plus the next ones in swap direction)
+const TICK_ARRAYS_PER_SWAP: i32 = 3;
+
 /// Raydium CLMM swap implementation
 pub struct RaydiumClmmSwap;
 
 impl ProgramSwap for RaydiumClmmSwap {
-    async fn execute_swap(
-        request: SwapRequest,
-        pool_data: AccountData,
-    ) -> Result<SwapResult, SwapError> {
+    async fn quote_swap(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<SwapParams, SwapError> {
+        let pool_info = Self::decode_pool_state(pool_data)?;
+        Self::calculate_clmm_swap_params(request, &pool_info).await
+    }
+
+    async fn build_transaction(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<(Transaction, SwapParams), SwapError> {
         logger::info(
             LogTag::System,
             &format!("Starting Raydium CLMM {:?} swap", request.direction),
         );
 
         // Decode pool state using centralized decoder
-        let pool_info = Self::decode_pool_state(&pool_data)?;
+        let pool_info = Self::decode_pool_state(pool_data)?;
 
         // Load wallet
-        let wallet = Self::load_wallet().await?;
+        let wallet = common::load_wallet()?;
 
         // Calculate swap parameters using CLMM math
-        let swap_params = Self::calculate_clmm_swap_params(&request, &pool_info).await?;
+        let swap_params = Self::calculate_clmm_swap_params(request, &pool_info).await?;
 
         logger::info(
             LogTag::System,
@@ -60,16 +75,15 @@ impl ProgramSwap for RaydiumClmmSwap {
 
         // Build transaction with proper account derivation
         let transaction = Self::build_clmm_swap_transaction(
-            &wallet,
-            &request,
+            &wallet.pubkey(),
+            request,
             &pool_info,
             &swap_params,
-            &pool_data,
+            pool_data,
         )
         .await?;
 
-        // Execute transaction
-        SwapExecutor::execute_transaction(transaction, swap_params).await
+        Ok((transaction, swap_params))
     }
 }
 
@@ -86,213 +100,182 @@ impl RaydiumClmmSwap {
         })
     }
 
-    /// Load wallet from configuration
-    async fn load_wallet() -> Result<Keypair, SwapError> {
-        crate::config::get_wallet_keypair()
-            .map_err(|e| SwapError::ExecutionError(format!("Failed to load wallet: {}", e)))
+    /// Whether token_0 is the SOL side of the pool
+    fn is_token_0_sol(pool_info: &ClmmPoolInfo) -> Result<bool, SwapError> {
+        if pool_info.token_mint_0 == SOL_MINT {
+            Ok(true)
+        } else if pool_info.token_mint_1 == SOL_MINT {
+            Ok(false)
+        } else {
+            Err(SwapError::InvalidPool(
+                "Pool does not contain SOL".to_string(),
+            ))
+        }
+    }
+
+    /// Read the trade fee rate from the pool's AMM config
+    async fn get_trade_fee_rate(pool_info: &ClmmPoolInfo) -> Result<u64, SwapError> {
+        let amm_config = common::parse_pubkey(&pool_info.amm_config, "amm_config")?;
+        let account = common::fetch_account(&amm_config).await?;
+        account
+            .data
+            .get(AMM_CONFIG_TRADE_FEE_OFFSET..AMM_CONFIG_TRADE_FEE_OFFSET + 4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(|bytes| u32::from_le_bytes(bytes) as u64)
+            .ok_or_else(|| SwapError::DecoderError("Invalid CLMM AMM config".to_string()))
     }
 
     /// Calculate swap parameters using CLMM concentrated liquidity math
+    ///
+    /// Only the active tick range is priced. Swaps that would cross into a neighbouring
+    /// range are refused rather than estimated, since liquidity there can differ.
     async fn calculate_clmm_swap_params(
         request: &SwapRequest,
         pool_info: &ClmmPoolInfo,
     ) -> Result<SwapParams, SwapError> {
-        // Get vault balances
-        let vault_0_balance = Self::get_token_account_balance(&pool_info.token_vault_0).await?;
-        let vault_1_balance = Self::get_token_account_balance(&pool_info.token_vault_1).await?;
+        let is_token_0_sol = Self::is_token_0_sol(pool_info)?;
+        let token_decimals = if is_token_0_sol {
+            pool_info.mint_decimals_1
+        } else {
+            pool_info.mint_decimals_0
+        };
 
-        logger::info(
+        let (input_amount_raw, zero_for_one) = match request.direction {
+            SwapDirection::Buy => (request.input_amount_raw(SOL_DECIMALS), is_token_0_sol),
+            SwapDirection::Sell => (request.input_amount_raw(token_decimals), !is_token_0_sol),
+        };
+
+        let trade_fee_rate = Self::get_trade_fee_rate(pool_info).await?;
+        let expected_output_raw = Self::quote_in_current_range(
+            pool_info,
+            input_amount_raw,
+            trade_fee_rate,
+            zero_for_one,
+        )?;
+
+        logger::debug(
             LogTag::System,
             &format!(
-                "CLMM Vault balances - Vault0: {}, Vault1: {}, Current tick: {}, Price: {:.12}",
-                vault_0_balance,
-                vault_1_balance,
+                "CLMM quote: in={} out={} (tick {}, spacing {}, zero_for_one {})",
+                input_amount_raw,
+                expected_output_raw,
                 pool_info.tick_current,
-                Self::sqrt_price_x64_to_price(pool_info.sqrt_price_x64)
+                pool_info.tick_spacing,
+                zero_for_one
             ),
         );
 
-        // Determine which token is SOL and get current price
-        let (sol_mint, token_mint, sol_decimals, token_decimals, is_token_0_sol) =
-            if pool_info.token_mint_0 == SOL_MINT {
-                (
-                    SOL_MINT,
-                    &pool_info.token_mint_1,
-                    9,
-                    pool_info.mint_decimals_1,
-                    true,
-                )
-            } else if pool_info.token_mint_1 == SOL_MINT {
-                (
-                    SOL_MINT,
-                    &pool_info.token_mint_0,
-                    9,
-                    pool_info.mint_decimals_0,
-                    false,
-                )
-            } else {
-                return Err(SwapError::InvalidPool(
-                    "Pool does not contain SOL".to_string(),
-                ));
-            };
+        common::build_swap_params(
+            request,
+            input_amount_raw,
+            expected_output_raw,
+            token_decimals,
+        )
+    }
 
-        // Convert sqrt_price_x64 to actual price
+    /// Output for an exact input that stays inside the current tick spacing range
+    ///
+    /// Initialized ticks sit on multiples of the tick spacing, so liquidity is constant
+    /// between the spacing boundaries around the current tick. With `L` and `s = sqrt(P)`,
+    /// token_0 in moves the price to `s' = L·s / (L + a·s)` and pays `L·(s − s')` of
+    /// token_1; token_1 in moves it to `s' = s + a / L` and pays `L·(s' − s) / (s·s')` of
+    /// token_0. The trade fee is taken from the input first.
+    fn quote_in_current_range(
+        pool_info: &ClmmPoolInfo,
+        amount_in: u64,
+        trade_fee_rate: u64,
+        zero_for_one: bool,
+    ) -> Result<u64, SwapError> {
+        if pool_info.liquidity == 0 || pool_info.sqrt_price_x64 == 0 {
+            return Err(SwapError::InvalidPool(
+                "CLMM pool has no active liquidity".to_string(),
+            ));
+        }
+        if pool_info.tick_spacing == 0 {
+            return Err(SwapError::InvalidPool(
+                "CLMM pool has zero tick spacing".to_string(),
+            ));
+        }
+        if trade_fee_rate >= FEE_RATE_DENOMINATOR {
+            return Err(SwapError::CalculationError(format!(
+                "Invalid CLMM trade fee rate {}",
+                trade_fee_rate
+            )));
+        }
+
+        let tick_spacing = pool_info.tick_spacing as i32;
+        let range_lower = pool_info.tick_current.div_euclid(tick_spacing) * tick_spacing;
+        let sqrt_lower = Self::tick_to_sqrt_price(range_lower);
+        let sqrt_upper = Self::tick_to_sqrt_price(range_lower + tick_spacing);
+
+        let amount_after_fee = ((amount_in as u128)
+            * ((FEE_RATE_DENOMINATOR - trade_fee_rate) as u128)
+            / (FEE_RATE_DENOMINATOR as u128)) as f64;
+        let liquidity = pool_info.liquidity as f64;
         let sqrt_price = Self::sqrt_price_x64_to_price(pool_info.sqrt_price_x64);
-        let current_price = sqrt_price * sqrt_price;
-
-        // Calculate swap amounts based on CLMM pricing
-        let (input_amount, expected_output, input_amount_raw, minimum_output_raw) = match request
-            .direction
-        {
-            SwapDirection::Buy => {
-                // Buying tokens with SOL
-                let sol_amount = request.amount;
-                let sol_amount_raw = sol_to_lamports(sol_amount);
-
-                // In CLMM, we use the current price for estimation
-                // The actual execution will use the concentrated liquidity
-                let token_amount = if is_token_0_sol {
-                    // SOL is token_0, token is token_1
-                    // price = token_1/token_0, so tokens = SOL / price
-                    sol_amount / current_price
-                } else {
-                    // SOL is token_1, token is token_0
-                    // price = token_0/token_1, so tokens = SOL * price
-                    sol_amount * current_price
-                };
-
-                let token_amount_raw = (token_amount * (10_f64).powi(token_decimals as i32)) as u64;
-                let minimum_token_raw = ((token_amount_raw as f64)
-                    * (1.0 - (request.slippage_bps as f64) / 10000.0))
-                    as u64;
-
-                (sol_amount, token_amount, sol_amount_raw, minimum_token_raw)
-            }
-            SwapDirection::Sell => {
-                // Selling tokens for SOL
-                let token_amount = request.amount;
-                let token_amount_raw = (token_amount * (10_f64).powi(token_decimals as i32)) as u64;
-
-                // Calculate expected SOL output
-                let sol_amount = if is_token_0_sol {
-                    // SOL is token_0, token is token_1
-                    // price = token_1/token_0, so SOL = tokens * price
-                    token_amount * current_price
-                } else {
-                    // SOL is token_1, token is token_0
-                    // price = token_0/token_1, so SOL = tokens / price
-                    token_amount / current_price
-                };
-
-                let sol_amount_raw = sol_to_lamports(sol_amount);
-                let minimum_sol_raw = ((sol_amount_raw as f64)
-                    * (1.0 - (request.slippage_bps as f64) / 10000.0))
-                    as u64;
-
-                (token_amount, sol_amount, token_amount_raw, minimum_sol_raw)
-            }
+
+        let (next_sqrt_price, output) = if zero_for_one {
+            let next = liquidity * sqrt_price / (liquidity + amount_after_fee * sqrt_price);
+            (next, liquidity * (sqrt_price - next))
+        } else {
+            let next = sqrt_price + amount_after_fee / liquidity;
+            (next, liquidity * (next - sqrt_price) / (sqrt_price * next))
         };
 
-        Ok(SwapParams {
-            input_amount,
-            expected_output,
-            minimum_output: (minimum_output_raw as f64)
-                / (10_f64).powi(match request.direction {
-                    SwapDirection::Buy => token_decimals as i32,
-                    SwapDirection::Sell => sol_decimals as i32,
-                }),
-            input_amount_raw,
-            minimum_output_raw,
-        })
+        if next_sqrt_price < sqrt_lower || next_sqrt_price > sqrt_upper {
+            return Err(SwapError::CalculationError(format!(
+                "Swap would cross out of the current CLMM tick range [{}, {})",
+                range_lower,
+                range_lower + tick_spacing
+            )));
+        }
+
+        Ok(output.max(0.0).min(u64::MAX as f64) as u64)
     }
 
     /// Build the complete CLMM swap transaction
     async fn build_clmm_swap_transaction(
-        wallet: &Keypair,
+        wallet_pubkey: &Pubkey,
         request: &SwapRequest,
         pool_info: &ClmmPoolInfo,
         swap_params: &SwapParams,
         pool_data: &AccountData,
     ) -> Result<Transaction, SwapError> {
-        let mut instructions = Vec::new();
-        let wallet_pubkey = wallet.pubkey();
-
-        // Determine token mint and programs - need to properly detect Token-2022
-        let (token_mint, token_program_id, is_token_0_sol) = if pool_info.token_mint_0 == SOL_MINT {
-            let token_mint = &pool_info.token_mint_1;
-            // Check if this is a Token-2022 token by attempting to get account info
-            let token_program_id = Self::get_token_program_for_mint(token_mint).await?;
-            (token_mint, token_program_id, false)
-        } else if pool_info.token_mint_1 == SOL_MINT {
-            let token_mint = &pool_info.token_mint_0;
-            let token_program_id = Self::get_token_program_for_mint(token_mint).await?;
-            (token_mint, token_program_id, true)
-        } else {
-            return Err(SwapError::InvalidPool(
-                "Pool does not contain SOL".to_string(),
-            ));
-        };
-
-        // Get associated token accounts with correct program
-        let wsol_ata = spl_associated_token_account::get_associated_token_address(
-            &wallet_pubkey,
-            &Pubkey::from_str(SOL_MINT).unwrap(),
-        );
+        let is_token_0_sol = Self::is_token_0_sol(pool_info)?;
 
-        let token_ata = if token_program_id == Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap() {
-            // Token-2022 ATA
-            spl_associated_token_account::get_associated_token_address_with_program_id(
-                &wallet_pubkey,
-                &Pubkey::from_str(token_mint).unwrap(),
-                &token_program_id,
-            )
+        // Determine token mint and program - need to properly detect Token-2022
+        let token_mint = if is_token_0_sol {
+            &pool_info.token_mint_1
         } else {
-            // Legacy SPL token ATA
-            spl_associated_token_account::get_associated_token_address(
-                &wallet_pubkey,
-                &Pubkey::from_str(token_mint).unwrap(),
-            )
+            &pool_info.token_mint_0
         };
+        let token_mint = common::parse_pubkey(token_mint, "token mint")?;
+        let token_program_id = common::get_token_program_for_mint(&token_mint).await?;
 
-        // Create token accounts if needed
-        if !Self::account_exists(&wsol_ata).await? {
-            let create_wsol_ix =
-                spl_associated_token_account::instruction::create_associated_token_account(
-                    &wallet_pubkey,
-                    &wallet_pubkey,
-                    &Pubkey::from_str(SOL_MINT).unwrap(),
-                    &spl_token::id(),
-                );
-            instructions.push(create_wsol_ix);
-        }
-
-        if !Self::account_exists(&token_ata).await? {
-            let create_token_ix =
-                spl_associated_token_account::instruction::create_associated_token_account(
-                    &wallet_pubkey,
-                    &wallet_pubkey,
-                    &Pubkey::from_str(token_mint).unwrap(),
-                    &token_program_id,
-                );
-            instructions.push(create_token_ix);
-        }
+        // Get associated token accounts, creating them if needed
+        let (wsol_ata, token_ata, mut instructions) =
+            common::prepare_token_accounts(wallet_pubkey, &token_mint, &token_program_id);
 
         // Handle WSOL wrapping for buy operations
         if request.direction == SwapDirection::Buy {
-            let transfer_ix = system_instruction::transfer(
-                &wallet_pubkey,
+            instructions.extend(common::wrap_sol_instructions(
+                wallet_pubkey,
                 &wsol_ata,
                 swap_params.input_amount_raw,
-            );
-            instructions.push(transfer_ix);
-
-            let sync_native_ix = spl_token::instruction::sync_native(&spl_token::id(), &wsol_ata)?;
-            instructions.push(sync_native_ix);
+            )?);
         }
 
+        // Tick arrays the swap may traverse
+        let zero_for_one = match request.direction {
+            SwapDirection::Buy => is_token_0_sol,
+            SwapDirection::Sell => !is_token_0_sol,
+        };
+        let remaining_accounts =
+            Self::get_swap_remaining_accounts(&pool_data.pubkey, pool_info, zero_for_one).await?;
+
         // Build the actual CLMM swap instruction
         let swap_ix = Self::build_clmm_swap_instruction(
-            &wallet_pubkey,
+            wallet_pubkey,
             pool_info,
             &wsol_ata,
             &token_ata,
@@ -300,36 +283,87 @@ impl RaydiumClmmSwap {
             swap_params,
             is_token_0_sol,
             &pool_data.pubkey, // Pass the actual pool address
-        )
-        .await?;
+            remaining_accounts,
+        )?;
         instructions.push(swap_ix);
 
         // Handle WSOL unwrapping
-        let close_wsol_ix = spl_token::instruction::close_account(
-            &spl_token::id(),
-            &wsol_ata,
-            &wallet_pubkey,
-            &wallet_pubkey,
-            &[],
-        )?;
-        instructions.push(close_wsol_ix);
+        instructions.push(common::close_wsol_instruction(wallet_pubkey, &wsol_ata)?);
+
+        common::finalize_transaction(instructions, wallet_pubkey, request).await
+    }
+
+    /// Tick array bitmap extension (if initialized) and the tick arrays in swap direction
+    async fn get_swap_remaining_accounts(
+        pool_address: &Pubkey,
+        pool_info: &ClmmPoolInfo,
+        zero_for_one: bool,
+    ) -> Result<Vec<AccountMeta>, SwapError> {
+        let program_id = Pubkey::from_str(RAYDIUM_CLMM_PROGRAM_ID).unwrap();
+        let ticks_per_array = pool_info.tick_spacing as i32 * TICK_ARRAY_SIZE;
+        if ticks_per_array == 0 {
+            return Err(SwapError::InvalidPool(
+                "CLMM pool has zero tick spacing".to_string(),
+            ));
+        }
 
-        // Create transaction
-        let rpc_client = get_rpc_client();
-        let recent_blockhash = rpc_client
-            .get_latest_blockhash()
+        let bitmap_extension = Pubkey::find_program_address(
+            &[b"pool_tick_array_bitmap_extension", pool_address.as_ref()],
+            &program_id,
+        )
+        .0;
+
+        let current_start = pool_info.tick_current.div_euclid(ticks_per_array) * ticks_per_array;
+        let step = if zero_for_one {
+            -ticks_per_array
+        } else {
+            ticks_per_array
+        };
+        let tick_arrays: Vec<Pubkey> = (0..TICK_ARRAYS_PER_SWAP)
+            .map(|i| {
+                let start_index = current_start + step * i;
+                Pubkey::find_program_address(
+                    &[
+                        b"tick_array",
+                        pool_address.as_ref(),
+                        &start_index.to_be_bytes(),
+                    ],
+                    &program_id,
+                )
+                .0
+            })
+            .collect();
+
+        let mut keys = vec![bitmap_extension];
+        keys.extend(tick_arrays.iter().copied());
+        let accounts = get_rpc_client()
+            .get_multiple_accounts(&keys)
             .await
-            .map_err(|e| SwapError::RpcError(format!("Failed to get blockhash: {}", e)))?;
+            .map_err(|e| SwapError::RpcError(format!("Failed to fetch tick arrays: {}", e)))?;
 
-        let transaction = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
-        let mut transaction_with_blockhash = transaction;
-        transaction_with_blockhash.message.recent_blockhash = recent_blockhash;
+        if accounts.get(1).map_or(true, |a| a.is_none()) {
+            return Err(SwapError::InvalidPool(
+                "Current CLMM tick array is not initialized".to_string(),
+            ));
+        }
 
-        Ok(transaction_with_blockhash)
+        let mut remaining = Vec::new();
+        if accounts.first().map_or(false, |a| a.is_some()) {
+            remaining.push(AccountMeta::new_readonly(bitmap_extension, false));
+        }
+        // Stop at the first uninitialized array; the program walks them in order
+        for (key, account) in tick_arrays.iter().zip(accounts.iter().skip(1)) {
+            if account.is_none() {
+                break;
+            }
+            remaining.push(AccountMeta::new(*key, false));
+        }
+
+        Ok(remaining)
     }
 
     /// Build the Raydium CLMM swap instruction
-    async fn build_clmm_swap_instruction(
+    fn build_clmm_swap_instruction(
         user: &Pubkey,
         pool_info: &ClmmPoolInfo,
         wsol_ata: &Pubkey,
@@ -338,6 +372,7 @@ impl RaydiumClmmSwap {
         swap_params: &SwapParams,
         is_token_0_sol: bool,
         pool_address: &Pubkey, // Pass the actual pool address from AccountData
+        remaining_accounts: Vec<AccountMeta>,
     ) -> Result<Instruction, SwapError> {
         // Use the passed pool address
         let amm_config = Pubkey::from_str(&pool_info.amm_config)
@@ -346,7 +381,7 @@ impl RaydiumClmmSwap {
             .map_err(|e| SwapError::TransactionError(format!("Invalid observation_key: {}", e)))?;
 
         // Get mint addresses
-        let wsol_mint = Pubkey::from_str(SOL_MINT).unwrap();
+        let wsol_mint = common::wsol_mint();
         let token_mint = if is_token_0_sol {
             Pubkey::from_str(&pool_info.token_mint_1).unwrap()
         } else {
@@ -380,8 +415,8 @@ impl RaydiumClmmSwap {
                 }
             };
 
-        // Build instruction data with correct SwapV2 discriminator
-        let mut instruction_data = vec![0x96, 0x43, 0x18, 0xcd, 0xc5, 0x65, 0x95, 0x7b]; // SwapV2 discriminator
+        // SwapV2 discriminator: first 8 bytes of SHA256("global:swap_v2")
+        let mut instruction_data = vec![0x2b, 0x04, 0xed, 0x0b, 0x1a, 0xc9, 0x1e, 0x62];
         instruction_data.extend_from_slice(&swap_params.input_amount_raw.to_le_bytes());
         instruction_data.extend_from_slice(&swap_params.minimum_output_raw.to_le_bytes());
 
@@ -392,15 +427,13 @@ impl RaydiumClmmSwap {
         instruction_data.push(1u8);
 
         // Determine input/output mints based on direction
-        let (input_mint, output_mint) = match (direction, is_token_0_sol) {
-            (SwapDirection::Buy, true) => (wsol_mint, token_mint), // SOL → Token
-            (SwapDirection::Buy, false) => (wsol_mint, token_mint), // SOL → Token
-            (SwapDirection::Sell, true) => (token_mint, wsol_mint), // Token → SOL
-            (SwapDirection::Sell, false) => (token_mint, wsol_mint), // Token → SOL
+        let (input_mint, output_mint) = match direction {
+            SwapDirection::Buy => (wsol_mint, token_mint), // SOL → Token
+            SwapDirection::Sell => (token_mint, wsol_mint), // Token → SOL
         };
 
         // Build accounts in correct SwapSingleV2 order
-        let accounts = vec![
+        let mut accounts = vec![
             AccountMeta::new_readonly(*user, true),            // payer
             AccountMeta::new_readonly(amm_config, false),      // amm_config
             AccountMeta::new(*pool_address, false),            // pool_state
@@ -415,6 +448,7 @@ impl RaydiumClmmSwap {
             AccountMeta::new_readonly(input_mint, false), // input_vault_mint
             AccountMeta::new_readonly(output_mint, false), // output_vault_mint
         ];
+        accounts.extend(remaining_accounts);
 
         Ok(Instruction {
             program_id: Pubkey::from_str(RAYDIUM_CLMM_PROGRAM_ID).unwrap(),
@@ -423,66 +457,171 @@ impl RaydiumClmmSwap {
         })
     }
 
-    /// Helper functions
-    async fn account_exists(pubkey: &Pubkey) -> Result<bool, SwapError> {
-        let rpc_client = get_rpc_client();
-        match rpc_client.get_account(pubkey).await {
-            Ok(Some(_)) => Ok(true),
-            Ok(None) => Ok(false),
-            Err(_) => Ok(false),
-        }
+    /// Convert sqrt_price_x64 to sqrt of the raw price (token_1 units per token_0 unit)
+    fn sqrt_price_x64_to_price(sqrt_price_x64: u128) -> f64 {
+        (sqrt_price_x64 as f64) / (2_f64).powi(64)
     }
 
-    /// Determine the correct token program for a mint
-    async fn get_token_program_for_mint(mint_address: &str) -> Result<Pubkey, SwapError> {
-        let rpc_client = get_rpc_client();
-        let mint_pubkey = Pubkey::from_str(mint_address)
-            .map_err(|e| SwapError::RpcError(format!("Invalid mint address: {}", e)))?;
+    /// Sqrt of the raw price at a tick (price = 1.0001^tick)
+    fn tick_to_sqrt_price(tick: i32) -> f64 {
+        1.0001_f64.powf(tick as f64 / 2.0)
+    }
+}
 
-        // Get the mint account to check its owner
-        let mint_account = rpc_client
-            .get_account(&mint_pubkey)
-            .await
-            .map_err(|e| SwapError::RpcError(format!("Failed to fetch mint account: {}", e)))?
-            .ok_or_else(|| {
-                SwapError::RpcError(format!("Mint account not found: {}", mint_address))
-            })?;
-
-        // Check the owner to determine if it's Token-2022 or legacy SPL Token
-        if mint_account.owner == Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap() {
-            Ok(Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap())
-        } else {
-            Ok(spl_token::id()) // Default to legacy SPL Token
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pools::swap::programs::common::test_params;
+
+    /// Pool at tick 30 (spacing 60) with L = 1e12, token_0 = SOL
+    fn sample_pool() -> ClmmPoolInfo {
+        let key = || Pubkey::new_unique().to_string();
+        ClmmPoolInfo {
+            bump: 0,
+            amm_config: key(),
+            owner: key(),
+            token_mint_0: SOL_MINT.to_string(),
+            token_mint_1: key(),
+            token_vault_0: key(),
+            token_vault_1: key(),
+            observation_key: key(),
+            mint_decimals_0: 9,
+            mint_decimals_1: 6,
+            tick_spacing: 60,
+            liquidity: 1_000_000_000_000,
+            // sqrt(1.0001^30) in Q64.64
+            sqrt_price_x64: 18_474_433_567_297_175_552,
+            tick_current: 30,
+            padding3: 0,
+            padding4: 0,
+            fee_growth_global_0_x64: 0,
+            fee_growth_global_1_x64: 0,
+            protocol_fees_token_0: 0,
+            protocol_fees_token_1: 0,
+            swap_in_amount_token_0: 0,
+            swap_out_amount_token_1: 0,
+            swap_in_amount_token_1: 0,
+            swap_out_amount_token_0: 0,
+            status: 0,
+            padding: [0; 7],
+            reward_infos: Vec::new(),
+            tick_array_bitmap: [0; 16],
+            total_fees_token_0: 0,
+            total_fees_claimed_token_0: 0,
+            total_fees_token_1: 0,
+            total_fees_claimed_token_1: 0,
+            fund_fees_token_0: 0,
+            fund_fees_token_1: 0,
+            open_time: 0,
+            recent_epoch: 0,
+            padding1: [0; 24],
+            padding2: [0; 32],
         }
     }
 
-    async fn get_token_account_balance(account_address: &str) -> Result<u64, SwapError> {
-        let rpc_client = get_rpc_client();
-        let pubkey = Pubkey::from_str(account_address)
-            .map_err(|e| SwapError::RpcError(format!("Invalid account address: {}", e)))?;
+    #[test]
+    fn quote_within_current_range() {
+        let pool = sample_pool();
 
-        let account = rpc_client
-            .get_account(&pubkey)
-            .await
-            .map_err(|e| SwapError::RpcError(format!("Failed to fetch account: {}", e)))?
-            .ok_or_else(|| {
-                SwapError::RpcError(format!("Token account not found: {}", account_address))
-            })?;
-
-        // Parse token account data to get amount
-        if account.data.len() >= 72 {
-            let amount_bytes: [u8; 8] = account.data[64..72]
-                .try_into()
-                .map_err(|_| SwapError::RpcError("Invalid token account data".to_string()))?;
-            Ok(u64::from_le_bytes(amount_bytes))
-        } else {
-            Err(SwapError::RpcError("Account data too short".to_string()))
-        }
+        // 1e9 of token_0 at 0.25% fee: sqrt price 1.0015 → 1.0005
+        assert_eq!(
+            RaydiumClmmSwap::quote_in_current_range(&pool, 1_000_000_000, 2_500, true).unwrap(),
+            999_498_347
+        );
+        // 1e9 of token_1 at 0.25% fee: sqrt price 1.0015 → 1.0025
+        assert_eq!(
+            RaydiumClmmSwap::quote_in_current_range(&pool, 1_000_000_000, 2_500, false).unwrap(),
+            993_522_580
+        );
     }
 
-    /// Convert sqrt_price_x64 to normal price
-    fn sqrt_price_x64_to_price(sqrt_price_x64: u128) -> f64 {
-        let sqrt_price = (sqrt_price_x64 as f64) / (2_f64).powi(64);
-        sqrt_price
+    #[test]
+    fn quote_refuses_to_cross_tick_range() {
+        let pool = sample_pool();
+
+        // Range is [0, 60): sqrt price must stay within [1.0, 1.0030]
+        assert!(
+            RaydiumClmmSwap::quote_in_current_range(&pool, 5_000_000_000, 2_500, true).is_err()
+        );
+        assert!(
+            RaydiumClmmSwap::quote_in_current_range(&pool, 5_000_000_000, 2_500, false).is_err()
+        );
+    }
+
+    #[test]
+    fn quote_uses_spacing_range_below_zero() {
+        // Tick -30 sits in [-60, 0), so the upper bound is sqrt price 1.0
+        let pool = ClmmPoolInfo {
+            tick_current: -30,
+            sqrt_price_x64: (RaydiumClmmSwap::tick_to_sqrt_price(-30) * 2_f64.powi(64)) as u128,
+            ..sample_pool()
+        };
+
+        assert!(RaydiumClmmSwap::quote_in_current_range(&pool, 1_000_000_000, 0, false).is_ok());
+        assert!(RaydiumClmmSwap::quote_in_current_range(&pool, 2_000_000_000, 0, false).is_err());
+    }
+
+    #[test]
+    fn quote_rejects_empty_pool() {
+        let pool = ClmmPoolInfo {
+            liquidity: 0,
+            ..sample_pool()
+        };
+        assert!(RaydiumClmmSwap::quote_in_current_range(&pool, 1_000, 2_500, true).is_err());
+    }
+
+    #[test]
+    fn swap_v2_instruction_layout() {
+        let pool = sample_pool();
+        let pool_address = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let wsol_ata = Pubkey::new_unique();
+        let token_ata = Pubkey::new_unique();
+        let tick_array = AccountMeta::new(Pubkey::new_unique(), false);
+
+        let ix = RaydiumClmmSwap::build_clmm_swap_instruction(
+            &user,
+            &pool,
+            &wsol_ata,
+            &token_ata,
+            SwapDirection::Buy,
+            &test_params(1_000_000, 900),
+            true,
+            &pool_address,
+            vec![tick_array.clone()],
+        )
+        .unwrap();
+
+        let mut data = vec![0x2b, 0x04, 0xed, 0x0b, 0x1a, 0xc9, 0x1e, 0x62];
+        data.extend_from_slice(&1_000_000u64.to_le_bytes());
+        data.extend_from_slice(&900u64.to_le_bytes());
+        data.extend_from_slice(&0u128.to_le_bytes());
+        data.push(1);
+        assert_eq!(ix.data, data);
+        assert_eq!(ix.program_id.to_string(), RAYDIUM_CLMM_PROGRAM_ID);
+
+        let parse = |s: &str| Pubkey::from_str(s).unwrap();
+        let expected = vec![
+            (user, true, false),
+            (parse(&pool.amm_config), false, false),
+            (pool_address, false, true),
+            (wsol_ata, false, true),
+            (token_ata, false, true),
+            (parse(&pool.token_vault_0), false, true),
+            (parse(&pool.token_vault_1), false, true),
+            (parse(&pool.observation_key), false, true),
+            (spl_token::id(), false, false),
+            (spl_token_2022::id(), false, false),
+            (parse(MEMO_PROGRAM_ID), false, false),
+            (parse(SOL_MINT), false, false),
+            (parse(&pool.token_mint_1), false, false),
+            (tick_array.pubkey, false, true),
+        ];
+        let actual: Vec<_> = ix
+            .accounts
+            .iter()
+            .map(|a| (a.pubkey, a.is_signer, a.is_writable))
+            .collect();
+        assert_eq!(actual, expected);
     }
 }
@@ -2,51 +2,58 @@
 ///
 /// This module implements direct swaps for Raydium Constant Product Market Maker pools.
 /// It integrates with the centralized Raydium CPMM decoder and provides both buy and sell operations.
+use super::common;
 use super::ProgramSwap;
-use crate::constants::{
-    RAYDIUM_CPMM_PROGRAM_ID, SOL_MINT, SPL_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
-};
+use crate::constants::{RAYDIUM_CPMM_PROGRAM_ID, SOL_DECIMALS, SOL_MINT};
 use crate::logger::{self, LogTag};
 use crate::pools::decoders::raydium_cpmm::{RaydiumCpmmDecoder, RaydiumCpmmPoolInfo};
-use crate::pools::swap::executor::SwapExecutor;
-use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest, SwapResult};
+use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest};
 use crate::pools::AccountData;
-use crate::rpc::{get_rpc_client, RpcClientMethods};
-use crate::utils::sol_to_lamports;
 
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    signature::{Keypair, Signer},
-    system_instruction,
+    signature::Signer,
     transaction::Transaction,
 };
-use spl_associated_token_account;
-use spl_token;
-use std::collections::HashMap;
 use std::str::FromStr;
 
+/// Trade fee denominator used by Raydium AMM configs
+const FEE_RATE_DENOMINATOR: u64 = 1_000_000;
+
+/// Offset of `trade_fee_rate` in the AmmConfig account
+/// (discriminator 8 + bump 1 + disable_create_pool 1 + index 2)
+const AMM_CONFIG_TRADE_FEE_OFFSET: usize = 12;
+
 /// Raydium CPMM swap implementation
 pub struct RaydiumCpmmSwap;
 
 impl ProgramSwap for RaydiumCpmmSwap {
-    async fn execute_swap(
-        request: SwapRequest,
-        pool_data: AccountData,
-    ) -> Result<SwapResult, SwapError> {
+    async fn quote_swap(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<SwapParams, SwapError> {
+        let pool_info = Self::decode_pool_state(pool_data)?;
+        Self::calculate_swap_params(request, &pool_info).await
+    }
+
+    async fn build_transaction(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<(Transaction, SwapParams), SwapError> {
         logger::info(
             LogTag::System,
             &format!("Executing Raydium CPMM {:?} swap", request.direction),
         );
 
         // Decode pool state using centralized decoder
-        let pool_info = Self::decode_pool_state(&pool_data)?;
+        let pool_info = Self::decode_pool_state(pool_data)?;
 
         // Load wallet
-        let wallet = Self::load_wallet().await?;
+        let wallet = common::load_wallet()?;
 
         // Calculate swap parameters
-        let swap_params = Self::calculate_swap_params(&request, &pool_info).await?;
+        let swap_params = Self::calculate_swap_params(request, &pool_info).await?;
 
         logger::info(
             LogTag::System,
@@ -58,32 +65,41 @@ impl ProgramSwap for RaydiumCpmmSwap {
 
         // Build transaction
         let transaction =
-            Self::build_swap_transaction(&wallet, &request, &pool_info, &swap_params).await?;
+            Self::build_swap_transaction(&wallet.pubkey(), request, &pool_info, &swap_params)
+                .await?;
 
-        // Execute transaction
-        SwapExecutor::execute_transaction(transaction, swap_params).await
+        Ok((transaction, swap_params))
     }
 }
 
 impl RaydiumCpmmSwap {
     /// Decode pool state using the centralized decoder
     fn decode_pool_state(pool_data: &AccountData) -> Result<RaydiumCpmmPoolInfo, SwapError> {
-        // Create accounts map for decoder
-        let mut accounts = HashMap::new();
-        accounts.insert(pool_data.pubkey.to_string(), pool_data.clone());
-
-        // Use the centralized decoder to decode pool info
-        // We need to call the internal decode method directly since we already have the pool account
         RaydiumCpmmDecoder::decode_raydium_cpmm_pool(&pool_data.data, &pool_data.pubkey.to_string())
             .ok_or_else(|| {
                 SwapError::DecoderError("Failed to decode Raydium CPMM pool".to_string())
             })
     }
 
-    /// Load wallet from configuration
-    async fn load_wallet() -> Result<Keypair, SwapError> {
-        crate::config::get_wallet_keypair()
-            .map_err(|e| SwapError::ExecutionError(format!("Failed to load wallet: {}", e)))
+    /// Whether token_0 is the SOL side of the pool
+    fn is_token_0_sol(pool_info: &RaydiumCpmmPoolInfo) -> Result<bool, SwapError> {
+        if pool_info.token_0_mint == SOL_MINT {
+            Ok(true)
+        } else if pool_info.token_1_mint == SOL_MINT {
+            Ok(false)
+        } else {
+            Err(SwapError::InvalidPool(
+                "Pool does not contain SOL".to_string(),
+            ))
+        }
+    }
+
+    /// Read the trade fee rate from the pool's AMM config
+    async fn get_trade_fee_rate(pool_info: &RaydiumCpmmPoolInfo) -> Result<u64, SwapError> {
+        let amm_config = common::parse_pubkey(&pool_info.amm_config, "amm_config")?;
+        let account = common::fetch_account(&amm_config).await?;
+        common::read_u64(&account.data, AMM_CONFIG_TRADE_FEE_OFFSET)
+            .ok_or_else(|| SwapError::DecoderError("Invalid CPMM AMM config".to_string()))
     }
 
     /// Calculate swap parameters using constant product formula
@@ -91,192 +107,155 @@ impl RaydiumCpmmSwap {
         request: &SwapRequest,
         pool_info: &RaydiumCpmmPoolInfo,
     ) -> Result<SwapParams, SwapError> {
+        let is_token_0_sol = Self::is_token_0_sol(pool_info)?;
+
         // Get vault balances
-        let vault_0_balance = Self::get_token_account_balance(&pool_info.token_0_vault).await?;
-        let vault_1_balance = Self::get_token_account_balance(&pool_info.token_1_vault).await?;
+        let vault_0_balance = common::get_token_account_balance(&common::parse_pubkey(
+            &pool_info.token_0_vault,
+            "token_0_vault",
+        )?)
+        .await?;
+        let vault_1_balance = common::get_token_account_balance(&common::parse_pubkey(
+            &pool_info.token_1_vault,
+            "token_1_vault",
+        )?)
+        .await?;
+
+        let (sol_reserve, token_reserve) =
+            Self::tradable_reserves(pool_info, vault_0_balance, vault_1_balance, is_token_0_sol);
 
         logger::debug(
             LogTag::System,
             &format!(
-                "Vault balances: {} = {}, {} = {}",
-                pool_info.token_0_vault, vault_0_balance, pool_info.token_1_vault, vault_1_balance
+                "CPMM reserves: sol = {}, token = {}",
+                sol_reserve, token_reserve
             ),
         );
 
-        // Determine which vault is SOL and which is the token
-        let (sol_reserve, token_reserve, token_decimals) = if pool_info.token_0_mint == SOL_MINT {
-            (vault_0_balance, vault_1_balance, pool_info.token_1_decimals)
+        let token_decimals = if is_token_0_sol {
+            pool_info.token_1_decimals
         } else {
-            (vault_1_balance, vault_0_balance, pool_info.token_0_decimals)
+            pool_info.token_0_decimals
         };
 
-        // Calculate using constant product formula: x * y = k
-        let (input_amount, expected_output, input_amount_raw, minimum_output_raw) = match request
-            .direction
-        {
-            SwapDirection::Sell => {
-                // For selling: user provides token amount, get SOL output
-                let token_amount_raw =
-                    (request.amount * (10_f64).powi(token_decimals as i32)) as u64;
-
-                // Calculate expected SOL output using constant product formula
-                // Use u128 to prevent overflow with large numbers
-                let sol_output_raw = ((sol_reserve as u128) * (token_amount_raw as u128))
-                    / ((token_reserve as u128) + (token_amount_raw as u128));
-                let sol_output = (sol_output_raw as f64) / (10_f64).powi(9); // SOL always has 9 decimals
-
-                // Apply slippage to the calculated output
-                let min_sol_output_raw =
-                    (sol_output_raw * (10000 - (request.slippage_bps as u128))) / 10000;
-
-                (
-                    request.amount,
-                    sol_output,
-                    token_amount_raw,
-                    min_sol_output_raw as u64,
-                )
-            }
-            SwapDirection::Buy => {
-                // Buying tokens with SOL: user provides SOL amount, get token output
-                let sol_amount_raw = sol_to_lamports(request.amount); // Keep as lamports
-
-                // Use u128 to prevent overflow with large numbers
-                let token_output_raw = ((token_reserve as u128) * (sol_amount_raw as u128))
-                    / ((sol_reserve as u128) + (sol_amount_raw as u128));
-                let token_output = (token_output_raw as f64) / (10_f64).powi(token_decimals as i32);
-                let min_token_output_raw =
-                    (token_output_raw * (10000 - (request.slippage_bps as u128))) / 10000;
-
-                (
-                    request.amount,
-                    token_output,
-                    sol_amount_raw,
-                    min_token_output_raw as u64,
-                )
-            }
+        let trade_fee_rate = Self::get_trade_fee_rate(pool_info).await?;
+
+        let (input_amount_raw, expected_output_raw) = Self::quote_amounts(
+            request,
+            sol_reserve,
+            token_reserve,
+            token_decimals,
+            trade_fee_rate,
+        )?;
+
+        common::build_swap_params(
+            request,
+            input_amount_raw,
+            expected_output_raw,
+            token_decimals,
+        )
+    }
+
+    /// SOL and token reserves, without the accrued protocol/fund fees that sit in the
+    /// vaults but are not tradable liquidity
+    fn tradable_reserves(
+        pool_info: &RaydiumCpmmPoolInfo,
+        vault_0_balance: u64,
+        vault_1_balance: u64,
+        is_token_0_sol: bool,
+    ) -> (u64, u64) {
+        let reserve_0 = vault_0_balance
+            .saturating_sub(pool_info.protocol_fees_token_0)
+            .saturating_sub(pool_info.fund_fees_token_0);
+        let reserve_1 = vault_1_balance
+            .saturating_sub(pool_info.protocol_fees_token_1)
+            .saturating_sub(pool_info.fund_fees_token_1);
+
+        if is_token_0_sol {
+            (reserve_0, reserve_1)
+        } else {
+            (reserve_1, reserve_0)
+        }
+    }
+
+    /// Raw input and expected output for the request against the given reserves
+    fn quote_amounts(
+        request: &SwapRequest,
+        sol_reserve: u64,
+        token_reserve: u64,
+        token_decimals: u8,
+        trade_fee_rate: u64,
+    ) -> Result<(u64, u64), SwapError> {
+        let (reserve_in, reserve_out, input_amount_raw) = match request.direction {
+            SwapDirection::Buy => (
+                sol_reserve,
+                token_reserve,
+                request.input_amount_raw(SOL_DECIMALS),
+            ),
+            SwapDirection::Sell => (
+                token_reserve,
+                sol_reserve,
+                request.input_amount_raw(token_decimals),
+            ),
         };
 
-        Ok(SwapParams {
-            input_amount,
-            expected_output,
-            minimum_output: expected_output * (1.0 - (request.slippage_bps as f64) / 10000.0),
+        let output = common::constant_product_output(
+            reserve_in,
+            reserve_out,
             input_amount_raw,
-            minimum_output_raw,
-        })
+            trade_fee_rate,
+            FEE_RATE_DENOMINATOR,
+        )?;
+
+        Ok((input_amount_raw, output))
     }
 
     /// Build the complete swap transaction
     async fn build_swap_transaction(
-        wallet: &Keypair,
+        wallet_pubkey: &Pubkey,
         request: &SwapRequest,
         pool_info: &RaydiumCpmmPoolInfo,
         swap_params: &SwapParams,
     ) -> Result<Transaction, SwapError> {
-        let mut instructions = Vec::new();
-        let wallet_pubkey = wallet.pubkey();
+        let is_token_0_sol = Self::is_token_0_sol(pool_info)?;
 
         // Determine token mint (non-SOL token) and its program
-        let (token_mint, token_program) = if pool_info.token_0_mint == SOL_MINT {
+        let (token_mint, token_program) = if is_token_0_sol {
             (&pool_info.token_1_mint, &pool_info.token_1_program)
         } else {
             (&pool_info.token_0_mint, &pool_info.token_0_program)
         };
+        let token_mint = common::parse_pubkey(token_mint, "token mint")?;
+        let token_program = common::parse_pubkey(token_program, "token program")?;
 
-        // Get associated token accounts
-        let wsol_ata = spl_associated_token_account::get_associated_token_address(
-            &wallet_pubkey,
-            &Pubkey::from_str(SOL_MINT).unwrap(),
-        );
-
-        let token_ata = if token_program == TOKEN_2022_PROGRAM_ID {
-            spl_associated_token_account::get_associated_token_address_with_program_id(
-                &wallet_pubkey,
-                &Pubkey::from_str(token_mint).unwrap(),
-                &Pubkey::from_str(token_program).unwrap(),
-            )
-        } else {
-            spl_associated_token_account::get_associated_token_address(
-                &wallet_pubkey,
-                &Pubkey::from_str(token_mint).unwrap(),
-            )
-        };
-
-        // Create token accounts if needed
-        if !Self::account_exists(&wsol_ata).await? {
-            instructions.push(
-                spl_associated_token_account::instruction::create_associated_token_account(
-                    &wallet_pubkey,
-                    &wallet_pubkey,
-                    &Pubkey::from_str(SOL_MINT).unwrap(),
-                    &spl_token::id(),
-                ),
-            );
-        }
-
-        if !Self::account_exists(&token_ata).await? {
-            let token_program_id = Pubkey::from_str(token_program).unwrap();
-            instructions.push(
-                spl_associated_token_account::instruction::create_associated_token_account(
-                    &wallet_pubkey,
-                    &wallet_pubkey,
-                    &Pubkey::from_str(token_mint).unwrap(),
-                    &token_program_id,
-                ),
-            );
-        }
+        // Get associated token accounts, creating them if needed
+        let (wsol_ata, token_ata, mut instructions) =
+            common::prepare_token_accounts(wallet_pubkey, &token_mint, &token_program);
 
         // Handle WSOL wrapping for buy operations
         if request.direction == SwapDirection::Buy {
-            let wsol_amount = sol_to_lamports(request.amount);
-
-            instructions.push(system_instruction::transfer(
-                &wallet_pubkey,
+            instructions.extend(common::wrap_sol_instructions(
+                wallet_pubkey,
                 &wsol_ata,
-                wsol_amount,
-            ));
-
-            instructions
-                .push(spl_token::instruction::sync_native(&spl_token::id(), &wsol_ata).unwrap());
+                swap_params.input_amount_raw,
+            )?);
         }
 
         // Build swap instruction
-        let swap_instruction = Self::build_swap_instruction(
-            &wallet_pubkey,
+        instructions.push(Self::build_swap_instruction(
+            wallet_pubkey,
             pool_info,
             &wsol_ata,
             &token_ata,
             request.direction,
             swap_params,
-        )?;
-
-        instructions.push(swap_instruction);
-
-        // Handle WSOL unwrapping for sell operations or remaining WSOL after buy
-        if request.direction == SwapDirection::Sell || request.direction == SwapDirection::Buy {
-            instructions.push(
-                spl_token::instruction::close_account(
-                    &spl_token::id(),
-                    &wsol_ata,
-                    &wallet_pubkey,
-                    &wallet_pubkey,
-                    &[],
-                )
-                .unwrap(),
-            );
-        }
-
-        // Get recent blockhash and create transaction
-        let rpc_client = get_rpc_client();
-        let recent_blockhash = rpc_client
-            .get_latest_blockhash()
-            .await
-            .map_err(|e| SwapError::RpcError(format!("Failed to get recent blockhash: {}", e)))?;
+            is_token_0_sol,
+        )?);
 
-        let transaction = Transaction::new_with_payer(&instructions, Some(&wallet_pubkey));
-        let mut transaction_with_blockhash = transaction;
-        transaction_with_blockhash.message.recent_blockhash = recent_blockhash;
+        // Unwrap remaining / received WSOL
+        instructions.push(common::close_wsol_instruction(wallet_pubkey, &wsol_ata)?);
 
-        Ok(transaction_with_blockhash)
+        common::finalize_transaction(instructions, wallet_pubkey, request).await
     }
 
     /// Build the Raydium CPMM swap instruction
@@ -287,6 +266,7 @@ impl RaydiumCpmmSwap {
         token_ata: &Pubkey,
         direction: SwapDirection,
         swap_params: &SwapParams,
+        is_token_0_sol: bool,
     ) -> Result<Instruction, SwapError> {
         // SwapBaseInput instruction discriminator calculated from SHA256("global:swap_base_input")
         // 8fbe5adac41e33de7fd664ed224c46a877892e28fe513659f68296f7079c123d -> first 8 bytes
@@ -294,44 +274,32 @@ impl RaydiumCpmmSwap {
         instruction_data.extend_from_slice(&swap_params.input_amount_raw.to_le_bytes());
         instruction_data.extend_from_slice(&swap_params.minimum_output_raw.to_le_bytes());
 
+        // Pool sides as (vault, mint, program), SOL side first
+        let side_0 = (
+            common::parse_pubkey(&pool_info.token_0_vault, "token_0_vault")?,
+            common::parse_pubkey(&pool_info.token_0_mint, "token_0_mint")?,
+            common::parse_pubkey(&pool_info.token_0_program, "token_0_program")?,
+        );
+        let side_1 = (
+            common::parse_pubkey(&pool_info.token_1_vault, "token_1_vault")?,
+            common::parse_pubkey(&pool_info.token_1_mint, "token_1_mint")?,
+            common::parse_pubkey(&pool_info.token_1_program, "token_1_program")?,
+        );
+        let (sol_side, token_side) = if is_token_0_sol {
+            (side_0, side_1)
+        } else {
+            (side_1, side_0)
+        };
+
         // Determine input/output accounts based on direction
-        let (
-            input_token_account,
-            output_token_account,
-            input_vault,
-            output_vault,
-            input_mint,
-            output_mint,
-            input_program,
-            output_program,
-        ) = match direction {
-            SwapDirection::Buy => {
-                // Buying: SOL → Token
-                (
-                    *wsol_ata,                                             // SOL account
-                    *token_ata,                                            // Token account
-                    Pubkey::from_str(&pool_info.token_0_vault).unwrap(),   // SOL vault
-                    Pubkey::from_str(&pool_info.token_1_vault).unwrap(),   // Token vault
-                    Pubkey::from_str(&pool_info.token_0_mint).unwrap(),    // SOL mint
-                    Pubkey::from_str(&pool_info.token_1_mint).unwrap(),    // Token mint
-                    Pubkey::from_str(&pool_info.token_0_program).unwrap(), // SOL program
-                    Pubkey::from_str(&pool_info.token_1_program).unwrap(), // Token program
-                )
-            }
-            SwapDirection::Sell => {
-                // Selling: Token → SOL
-                (
-                    *token_ata,                                            // Token account
-                    *wsol_ata,                                             // SOL account
-                    Pubkey::from_str(&pool_info.token_1_vault).unwrap(),   // Token vault
-                    Pubkey::from_str(&pool_info.token_0_vault).unwrap(),   // SOL vault
-                    Pubkey::from_str(&pool_info.token_1_mint).unwrap(),    // Token mint
-                    Pubkey::from_str(&pool_info.token_0_mint).unwrap(),    // SOL mint
-                    Pubkey::from_str(&pool_info.token_1_program).unwrap(), // Token program
-                    Pubkey::from_str(&pool_info.token_0_program).unwrap(), // SOL program
-                )
-            }
+        let (input_token_account, output_token_account, input_side, output_side) = match direction {
+            // Buying: SOL → Token
+            SwapDirection::Buy => (*wsol_ata, *token_ata, sol_side, token_side),
+            // Selling: Token → SOL
+            SwapDirection::Sell => (*token_ata, *wsol_ata, token_side, sol_side),
         };
+        let (input_vault, input_mint, input_program) = input_side;
+        let (output_vault, output_mint, output_program) = output_side;
 
         // Authority PDA (derived from "vault_and_lp_mint_auth_seed"seed)
         let authority = Pubkey::find_program_address(
@@ -340,9 +308,9 @@ impl RaydiumCpmmSwap {
         )
         .0;
 
-        let pool_pubkey = Pubkey::from_str(&pool_info.pool_id).unwrap();
-        let amm_config = Pubkey::from_str(&pool_info.amm_config).unwrap();
-        let observation_key = Pubkey::from_str(&pool_info.observation_key).unwrap();
+        let pool_pubkey = common::parse_pubkey(&pool_info.pool_id, "pool_id")?;
+        let amm_config = common::parse_pubkey(&pool_info.amm_config, "amm_config")?;
+        let observation_key = common::parse_pubkey(&pool_info.observation_key, "observation_key")?;
 
         // Build accounts according to Raydium CPMM swap instruction format
         let accounts = vec![
@@ -367,43 +335,130 @@ impl RaydiumCpmmSwap {
             data: instruction_data,
         })
     }
+}
 
-    /// Helper functions
-    async fn account_exists(pubkey: &Pubkey) -> Result<bool, SwapError> {
-        let rpc_client = get_rpc_client();
-        match rpc_client.get_account(pubkey).await {
-            Ok(Some(_)) => Ok(true),
-            Ok(None) => Ok(false),
-            Err(_) => Ok(false),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pools::swap::programs::common::{test_params, test_request};
+
+    fn sample_pool() -> RaydiumCpmmPoolInfo {
+        let key = || Pubkey::new_unique().to_string();
+        RaydiumCpmmPoolInfo {
+            token_0_mint: SOL_MINT.to_string(),
+            token_1_mint: key(),
+            token_0_vault: key(),
+            token_1_vault: key(),
+            token_0_decimals: 9,
+            token_1_decimals: 6,
+            pool_id: key(),
+            amm_config: key(),
+            pool_creator: key(),
+            lp_mint: key(),
+            token_0_program: spl_token::id().to_string(),
+            token_1_program: spl_token_2022::id().to_string(),
+            observation_key: key(),
+            auth_bump: 0,
+            status: 0,
+            lp_mint_decimals: 9,
+            lp_supply: 0,
+            protocol_fees_token_0: 0,
+            protocol_fees_token_1: 0,
+            fund_fees_token_0: 0,
+            fund_fees_token_1: 0,
+            open_time: 0,
+            recent_epoch: 0,
+            creator_fee_on: 0,
+            enable_creator_fee: false,
+            creator_fees_token_0: 0,
+            creator_fees_token_1: 0,
         }
     }
 
-    async fn get_token_account_balance(account_address: &str) -> Result<u64, SwapError> {
-        let rpc_client = get_rpc_client();
-        let account_pubkey = Pubkey::from_str(account_address)
-            .map_err(|e| SwapError::InvalidInput(format!("Invalid account address: {}", e)))?;
+    #[test]
+    fn tradable_reserves_exclude_accrued_fees() {
+        let mut pool = sample_pool();
+        pool.protocol_fees_token_0 = 300;
+        pool.fund_fees_token_0 = 200;
+        pool.fund_fees_token_1 = 1_000;
 
-        let account = rpc_client
-            .get_account(&account_pubkey)
-            .await
-            .map_err(|e| SwapError::RpcError(format!("Failed to get token account: {}", e)))?
-            .ok_or_else(|| {
-                SwapError::RpcError(format!("Token account not found: {}", account_address))
-            })?;
-
-        // Decode token account amount (at offset 64)
-        if account.data.len() < 72 {
-            return Err(SwapError::DecoderError(
-                "Invalid token account data".to_string(),
-            ));
-        }
+        assert_eq!(
+            RaydiumCpmmSwap::tradable_reserves(&pool, 10_000, 50_000, true),
+            (9_500, 49_000)
+        );
+        assert_eq!(
+            RaydiumCpmmSwap::tradable_reserves(&pool, 10_000, 50_000, false),
+            (49_000, 9_500)
+        );
+    }
+
+    #[test]
+    fn quote_amounts_buy_and_sell() {
+        // 100 SOL / 1M tokens (6 decimals), 0.25% trade fee
+        let sol_reserve = 100_000_000_000;
+        let token_reserve = 1_000_000_000_000;
 
-        let amount_bytes = &account.data[64..72];
-        let amount =
-            u64::from_le_bytes(amount_bytes.try_into().map_err(|e| {
-                SwapError::DecoderError(format!("Failed to decode amount: {:?}", e))
-            })?);
+        let buy = test_request(SwapDirection::Buy, 1_000_000_000);
+        assert_eq!(
+            RaydiumCpmmSwap::quote_amounts(&buy, sol_reserve, token_reserve, 6, 2_500).unwrap(),
+            (1_000_000_000, 9_876_482_091)
+        );
+
+        let sell = test_request(SwapDirection::Sell, 10_000_000_000);
+        assert_eq!(
+            RaydiumCpmmSwap::quote_amounts(&sell, sol_reserve, token_reserve, 6, 2_500).unwrap(),
+            (10_000_000_000, 987_648_209)
+        );
+    }
 
-        Ok(amount)
+    #[test]
+    fn sell_instruction_layout() {
+        let pool = sample_pool();
+        let user = Pubkey::new_unique();
+        let wsol_ata = Pubkey::new_unique();
+        let token_ata = Pubkey::new_unique();
+        let params = test_params(1_234, 5_678);
+
+        let ix = RaydiumCpmmSwap::build_swap_instruction(
+            &user,
+            &pool,
+            &wsol_ata,
+            &token_ata,
+            SwapDirection::Sell,
+            &params,
+            true,
+        )
+        .unwrap();
+
+        let mut data = vec![0x8f, 0xbe, 0x5a, 0xda, 0xc4, 0x1e, 0x33, 0xde];
+        data.extend_from_slice(&1_234u64.to_le_bytes());
+        data.extend_from_slice(&5_678u64.to_le_bytes());
+        assert_eq!(ix.data, data);
+        assert_eq!(ix.program_id.to_string(), RAYDIUM_CPMM_PROGRAM_ID);
+
+        let parse = |s: &str| Pubkey::from_str(s).unwrap();
+        let authority =
+            Pubkey::find_program_address(&[b"vault_and_lp_mint_auth_seed"], &ix.program_id).0;
+        let expected = vec![
+            (user, true, false),
+            (authority, false, false),
+            (parse(&pool.amm_config), false, false),
+            (parse(&pool.pool_id), false, true),
+            (token_ata, false, true),
+            (wsol_ata, false, true),
+            (parse(&pool.token_1_vault), false, true),
+            (parse(&pool.token_0_vault), false, true),
+            (spl_token_2022::id(), false, false),
+            (spl_token::id(), false, false),
+            (parse(&pool.token_1_mint), false, false),
+            (parse(SOL_MINT), false, false),
+            (parse(&pool.observation_key), false, true),
+        ];
+        let actual: Vec<_> = ix
+            .accounts
+            .iter()
+            .map(|a| (a.pubkey, a.is_signer, a.is_writable))
+            .collect();
+        assert_eq!(actual, expected);
     }
 }
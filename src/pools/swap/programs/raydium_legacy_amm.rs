@@ -0,0 +1,383 @@
+/// Raydium Legacy AMM (v4) swap implementation
+///
+/// This module implements direct swaps for Raydium AMM v4 pools using the
+/// `SwapBaseInV2` instruction, which no longer requires OpenBook market accounts.
+/// Reserves are the vault balances minus the PnL the pool still owes to the protocol.
+use super::common;
+use super::ProgramSwap;
+use crate::constants::{RAYDIUM_LEGACY_AMM_PROGRAM_ID, SOL_DECIMALS};
+use crate::logger::{self, LogTag};
+use crate::pools::swap::types::{SwapDirection, SwapError, SwapParams, SwapRequest};
+use crate::pools::AccountData;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::Transaction,
+};
+use std::str::FromStr;
+
+/// `SwapBaseInV2` instruction tag
+const SWAP_BASE_IN_V2: u8 = 16;
+
+// AmmInfo field offsets
+const SWAP_FEE_NUMERATOR_OFFSET: usize = 176;
+const SWAP_FEE_DENOMINATOR_OFFSET: usize = 184;
+const NEED_TAKE_PNL_COIN_OFFSET: usize = 192;
+const NEED_TAKE_PNL_PC_OFFSET: usize = 200;
+const COIN_VAULT_OFFSET: usize = 336;
+const PC_VAULT_OFFSET: usize = 368;
+const COIN_MINT_OFFSET: usize = 400;
+const PC_MINT_OFFSET: usize = 432;
+const AMM_INFO_MIN_SIZE: usize = 464;
+
+/// Decoded Legacy AMM fields needed for swapping
+#[derive(Debug, Clone)]
+struct LegacyAmmSwapInfo {
+    pool: Pubkey,
+    coin_vault: Pubkey,
+    pc_vault: Pubkey,
+    coin_mint: Pubkey,
+    pc_mint: Pubkey,
+    need_take_pnl_coin: u64,
+    need_take_pnl_pc: u64,
+    swap_fee_numerator: u64,
+    swap_fee_denominator: u64,
+}
+
+impl LegacyAmmSwapInfo {
+    fn is_coin_sol(&self) -> Result<bool, SwapError> {
+        let sol = common::wsol_mint();
+        if self.coin_mint == sol {
+            Ok(true)
+        } else if self.pc_mint == sol {
+            Ok(false)
+        } else {
+            Err(SwapError::InvalidPool(
+                "Pool does not contain SOL".to_string(),
+            ))
+        }
+    }
+
+    fn token_mint(&self) -> Result<Pubkey, SwapError> {
+        Ok(if self.is_coin_sol()? {
+            self.pc_mint
+        } else {
+            self.coin_mint
+        })
+    }
+}
+
+/// Raydium Legacy AMM swap implementation
+pub struct RaydiumLegacyAmmSwap;
+
+impl ProgramSwap for RaydiumLegacyAmmSwap {
+    async fn quote_swap(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<SwapParams, SwapError> {
+        let pool_info = Self::decode_pool_state(pool_data)?;
+        Self::calculate_swap_params(request, &pool_info).await
+    }
+
+    async fn build_transaction(
+        request: &SwapRequest,
+        pool_data: &AccountData,
+    ) -> Result<(Transaction, SwapParams), SwapError> {
+        logger::info(
+            LogTag::System,
+            &format!("Executing Raydium Legacy AMM {:?} swap", request.direction),
+        );
+
+        let pool_info = Self::decode_pool_state(pool_data)?;
+        let wallet = common::load_wallet()?;
+        let swap_params = Self::calculate_swap_params(request, &pool_info).await?;
+
+        logger::info(
+            LogTag::System,
+            &format!(
+                "Legacy AMM swap: {:.6} → {:.6} (min: {:.6})",
+                swap_params.input_amount, swap_params.expected_output, swap_params.minimum_output
+            ),
+        );
+
+        let wallet_pubkey = wallet.pubkey();
+        let token_mint = pool_info.token_mint()?;
+
+        // AMM v4 only supports the legacy SPL token program
+        let (wsol_ata, token_ata, mut instructions) =
+            common::prepare_token_accounts(&wallet_pubkey, &token_mint, &spl_token::id());
+
+        if request.direction == SwapDirection::Buy {
+            instructions.extend(common::wrap_sol_instructions(
+                &wallet_pubkey,
+                &wsol_ata,
+                swap_params.input_amount_raw,
+            )?);
+        }
+
+        let (user_source, user_destination) = match request.direction {
+            SwapDirection::Buy => (wsol_ata, token_ata),
+            SwapDirection::Sell => (token_ata, wsol_ata),
+        };
+
+        instructions.push(Self::build_swap_instruction(
+            &wallet_pubkey,
+            &pool_info,
+            &user_source,
+            &user_destination,
+            &swap_params,
+        ));
+
+        instructions.push(common::close_wsol_instruction(&wallet_pubkey, &wsol_ata)?);
+
+        let transaction =
+            common::finalize_transaction(instructions, &wallet_pubkey, request).await?;
+        Ok((transaction, swap_params))
+    }
+}
+
+impl RaydiumLegacyAmmSwap {
+    /// Decode the AmmInfo account
+    fn decode_pool_state(pool_data: &AccountData) -> Result<LegacyAmmSwapInfo, SwapError> {
+        let data = &pool_data.data;
+        if data.len() < AMM_INFO_MIN_SIZE {
+            return Err(SwapError::DecoderError(format!(
+                "Legacy AMM pool data too small: {}",
+                data.len()
+            )));
+        }
+
+        let pubkey_at = |offset: usize| {
+            common::read_pubkey(data, offset)
+                .ok_or_else(|| SwapError::DecoderError("Invalid Legacy AMM pool".to_string()))
+        };
+        let u64_at = |offset: usize| {
+            common::read_u64(data, offset)
+                .ok_or_else(|| SwapError::DecoderError("Invalid Legacy AMM pool".to_string()))
+        };
+
+        Ok(LegacyAmmSwapInfo {
+            pool: pool_data.pubkey,
+            coin_vault: pubkey_at(COIN_VAULT_OFFSET)?,
+            pc_vault: pubkey_at(PC_VAULT_OFFSET)?,
+            coin_mint: pubkey_at(COIN_MINT_OFFSET)?,
+            pc_mint: pubkey_at(PC_MINT_OFFSET)?,
+            need_take_pnl_coin: u64_at(NEED_TAKE_PNL_COIN_OFFSET)?,
+            need_take_pnl_pc: u64_at(NEED_TAKE_PNL_PC_OFFSET)?,
+            swap_fee_numerator: u64_at(SWAP_FEE_NUMERATOR_OFFSET)?,
+            swap_fee_denominator: u64_at(SWAP_FEE_DENOMINATOR_OFFSET)?,
+        })
+    }
+
+    /// Calculate swap parameters using constant product formula
+    async fn calculate_swap_params(
+        request: &SwapRequest,
+        pool_info: &LegacyAmmSwapInfo,
+    ) -> Result<SwapParams, SwapError> {
+        let is_coin_sol = pool_info.is_coin_sol()?;
+        let token_decimals = common::token_decimals(&pool_info.token_mint()?).await?;
+
+        let coin_balance = common::get_token_account_balance(&pool_info.coin_vault).await?;
+        let pc_balance = common::get_token_account_balance(&pool_info.pc_vault).await?;
+
+        let (input_amount_raw, expected_output_raw) = Self::quote_amounts(
+            request,
+            pool_info,
+            coin_balance,
+            pc_balance,
+            is_coin_sol,
+            token_decimals,
+        )?;
+
+        common::build_swap_params(
+            request,
+            input_amount_raw,
+            expected_output_raw,
+            token_decimals,
+        )
+    }
+
+    /// Raw input and expected output from the vault balances, net of the PnL the pool
+    /// still owes to the protocol
+    fn quote_amounts(
+        request: &SwapRequest,
+        pool_info: &LegacyAmmSwapInfo,
+        coin_balance: u64,
+        pc_balance: u64,
+        is_coin_sol: bool,
+        token_decimals: u8,
+    ) -> Result<(u64, u64), SwapError> {
+        let coin_reserve = coin_balance.saturating_sub(pool_info.need_take_pnl_coin);
+        let pc_reserve = pc_balance.saturating_sub(pool_info.need_take_pnl_pc);
+
+        let (sol_reserve, token_reserve) = if is_coin_sol {
+            (coin_reserve, pc_reserve)
+        } else {
+            (pc_reserve, coin_reserve)
+        };
+
+        logger::debug(
+            LogTag::System,
+            &format!(
+                "Legacy AMM reserves: sol={} token={} fee={}/{}",
+                sol_reserve,
+                token_reserve,
+                pool_info.swap_fee_numerator,
+                pool_info.swap_fee_denominator
+            ),
+        );
+
+        let (reserve_in, reserve_out, input_amount_raw) = match request.direction {
+            SwapDirection::Buy => (
+                sol_reserve,
+                token_reserve,
+                request.input_amount_raw(SOL_DECIMALS),
+            ),
+            SwapDirection::Sell => (
+                token_reserve,
+                sol_reserve,
+                request.input_amount_raw(token_decimals),
+            ),
+        };
+
+        let expected_output_raw = common::constant_product_output(
+            reserve_in,
+            reserve_out,
+            input_amount_raw,
+            pool_info.swap_fee_numerator,
+            pool_info.swap_fee_denominator,
+        )?;
+
+        Ok((input_amount_raw, expected_output_raw))
+    }
+
+    /// Build the SwapBaseInV2 instruction
+    fn build_swap_instruction(
+        user: &Pubkey,
+        pool_info: &LegacyAmmSwapInfo,
+        user_source: &Pubkey,
+        user_destination: &Pubkey,
+        swap_params: &SwapParams,
+    ) -> Instruction {
+        let program_id = Pubkey::from_str(RAYDIUM_LEGACY_AMM_PROGRAM_ID).unwrap();
+        let authority = Pubkey::find_program_address(&[b"amm authority"], &program_id).0;
+
+        let mut data = vec![SWAP_BASE_IN_V2];
+        data.extend_from_slice(&swap_params.input_amount_raw.to_le_bytes());
+        data.extend_from_slice(&swap_params.minimum_output_raw.to_le_bytes());
+
+        let accounts = vec![
+            AccountMeta::new_readonly(spl_token::id(), false), // token_program
+            AccountMeta::new(pool_info.pool, false),           // amm
+            AccountMeta::new_readonly(authority, false),       // amm_authority
+            AccountMeta::new(pool_info.coin_vault, false),     // amm_coin_vault
+            AccountMeta::new(pool_info.pc_vault, false),       // amm_pc_vault
+            AccountMeta::new(*user_source, false),             // user_source
+            AccountMeta::new(*user_destination, false),        // user_destination
+            AccountMeta::new_readonly(*user, true),            // user_owner
+        ];
+
+        Instruction {
+            program_id,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pools::swap::programs::common::{test_params, test_request};
+
+    fn sample_pool() -> LegacyAmmSwapInfo {
+        LegacyAmmSwapInfo {
+            pool: Pubkey::new_unique(),
+            coin_vault: Pubkey::new_unique(),
+            pc_vault: Pubkey::new_unique(),
+            coin_mint: Pubkey::new_unique(),
+            pc_mint: common::wsol_mint(),
+            need_take_pnl_coin: 1_000_000,
+            need_take_pnl_pc: 5_000_000_000,
+            swap_fee_numerator: 25,
+            swap_fee_denominator: 10_000,
+        }
+    }
+
+    #[test]
+    fn quote_amounts_net_out_pending_pnl() {
+        // Token is coin, SOL is pc: reserves 195 SOL / 1_999_999 tokens after PnL
+        let pool = sample_pool();
+        assert!(!pool.is_coin_sol().unwrap());
+
+        let buy = test_request(SwapDirection::Buy, 1_000_000_000);
+        assert_eq!(
+            RaydiumLegacyAmmSwap::quote_amounts(
+                &buy,
+                &pool,
+                2_000_000_000_000,
+                200_000_000_000,
+                false,
+                6
+            )
+            .unwrap(),
+            (1_000_000_000, 10_178_696_169)
+        );
+
+        let sell = test_request(SwapDirection::Sell, 5_000_000_000);
+        assert_eq!(
+            RaydiumLegacyAmmSwap::quote_amounts(
+                &sell,
+                &pool,
+                2_000_000_000_000,
+                200_000_000_000,
+                false,
+                6
+            )
+            .unwrap(),
+            (5_000_000_000, 485_071_844)
+        );
+    }
+
+    #[test]
+    fn swap_base_in_v2_layout() {
+        let pool = sample_pool();
+        let user = Pubkey::new_unique();
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let ix = RaydiumLegacyAmmSwap::build_swap_instruction(
+            &user,
+            &pool,
+            &source,
+            &destination,
+            &test_params(1_000, 990),
+        );
+
+        let mut data = vec![16];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&990u64.to_le_bytes());
+        assert_eq!(ix.data, data);
+        assert_eq!(ix.program_id.to_string(), RAYDIUM_LEGACY_AMM_PROGRAM_ID);
+
+        let authority = Pubkey::find_program_address(&[b"amm authority"], &ix.program_id).0;
+        let expected = vec![
+            (spl_token::id(), false, false),
+            (pool.pool, false, true),
+            (authority, false, false),
+            (pool.coin_vault, false, true),
+            (pool.pc_vault, false, true),
+            (source, false, true),
+            (destination, false, true),
+            (user, true, false),
+        ];
+        let actual: Vec<_> = ix
+            .accounts
+            .iter()
+            .map(|a| (a.pubkey, a.is_signer, a.is_writable))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+}
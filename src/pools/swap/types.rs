@@ -27,6 +27,22 @@ pub struct SwapRequest {
     pub direction: SwapDirection,
     /// Slippage tolerance in basis points (100 = 1%)
     pub slippage_bps: u16,
+    /// Exact input amount in raw units; takes precedence over `amount` when set
+    pub amount_raw: Option<u64>,
+    /// Lower bound for the minimum output in raw units (e.g. from an earlier quote)
+    pub min_output_raw: Option<u64>,
+    /// Compute unit limit instruction prepended to the transaction when set
+    pub compute_unit_limit: Option<u32>,
+    /// Compute unit price (micro-lamports per CU) prepended to the transaction when set
+    pub compute_unit_price: Option<u64>,
+}
+
+impl SwapRequest {
+    /// Input amount in raw units for the given input decimals
+    pub fn input_amount_raw(&self, decimals: u8) -> u64 {
+        self.amount_raw
+            .unwrap_or_else(|| (self.amount * 10_f64.powi(decimals as i32)) as u64)
+    }
 }
 
 /// Calculated swap parameters
@@ -40,6 +56,8 @@ pub struct SwapParams {
     pub minimum_output: f64,
     /// Input amount in raw units (smallest denomination)
     pub input_amount_raw: u64,
+    /// Expected output amount in raw units (smallest denomination)
+    pub expected_output_raw: u64,
     /// Minimum output amount in raw units (smallest denomination)
     pub minimum_output_raw: u64,
 }
@@ -461,6 +461,11 @@ pub fn max_watched_tokens() -> usize {
     crate::config::with_config(|cfg| cfg.pools.max_watched_tokens.max(1))
 }
 
+/// Whether pool accounts of open positions are streamed over WebSocket
+pub fn account_streaming_enabled() -> bool {
+    crate::config::with_config(|cfg| cfg.pools.enable_account_streaming)
+}
+
 /// Maximum allowable gap between consecutive price updates (1 minute)
 /// If gap is larger, older data becomes invalid and should be removed
 pub const MAX_PRICE_GAP_SECONDS: u64 = 60;
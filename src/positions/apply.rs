@@ -1,6 +1,6 @@
 use super::db::{
-    force_database_sync, save_entry_record, save_exit_record, update_position,
-    update_position_price_fields,
+    force_database_sync, get_take_profit_tiers, release_take_profit_tier, save_entry_record,
+    save_exit_record, update_position, update_position_price_fields,
 };
 use super::{
     loss_detection::process_position_loss_detection,
@@ -640,12 +640,30 @@ pub async fn apply_transition(transition: PositionTransition) -> Result<ApplyEff
                                 let partial_pnl = sol_received
                                     - (exit_amount as f64 / 10_f64.powi(9)
                                         * position.average_entry_price);
+                                // Ladder tier claimed for this exit (signature attached on submit)
+                                let take_profit_tier =
+                                    match get_take_profit_tiers(position_id).await {
+                                        Ok(tiers) => tiers
+                                            .iter()
+                                            .find(|t| {
+                                                t.transaction_signature.as_deref()
+                                                    == Some(exit_signature.as_str())
+                                            })
+                                            .map(|t| {
+                                                let total =
+                                                    crate::trader::config::get_take_profit_ladder()
+                                                        .len();
+                                                (t.tier, total as u32)
+                                            }),
+                                        Err(_) => None,
+                                    };
                                 queue_notification(Notification::partial_exit(
                                     position.symbol.clone(),
                                     position.mint.clone(),
                                     exit_percentage,
                                     partial_pnl,
                                     remaining_pct,
+                                    take_profit_tier,
                                 ));
                             }
 
@@ -688,6 +706,14 @@ pub async fn apply_transition(transition: PositionTransition) -> Result<ApplyEff
             );
             if let Some(position) = get_position_by_id(position_id).await {
                 if let Some(exit_sig) = position.exit_transaction_signature.clone() {
+                    // Let a failed take-profit tier fire again
+                    if let Ok(tiers) = get_take_profit_tiers(position_id).await {
+                        for tier in tiers.iter().filter(|t| {
+                            t.transaction_signature.as_deref() == Some(exit_sig.as_str())
+                        }) {
+                            let _ = release_take_profit_tier(position_id, tier.trigger_pct).await;
+                        }
+                    }
                     if let Err(err) = super::state::clear_pending_partial_exit(&exit_sig).await {
                         logger::error(
               LogTag::Positions,
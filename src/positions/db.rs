@@ -18,7 +18,8 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 
 use crate::logger::{self, LogTag};
-use crate::positions::types::{EntryRecord, ExitRecord, Position};
+use crate::migrations::{self, add_column_if_missing, Migration, MigrationSet};
+use crate::positions::types::{EntryRecord, ExitRecord, Position, TakeProfitTierRecord};
 
 // Static flag to track if database has been initialized (to reduce log noise)
 static POSITIONS_DB_INITIALIZED: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(false));
@@ -42,7 +43,7 @@ const POSITION_SELECT_COLUMNS: &str = r#"
   phantom_confirmations, phantom_first_seen, synthetic_exit, closed_reason,
   pnl, pnl_percent, unrealized_pnl, unrealized_pnl_percent,
   remaining_token_amount, total_exited_amount, average_exit_price, partial_exit_count,
-  dca_count, average_entry_price, last_dca_time
+  dca_count, average_entry_price, last_dca_time, sizing_mode, sizing_reason
 "#;
 
 const SCHEMA_POSITIONS: &str = r#"
@@ -100,6 +101,9 @@ CREATE TABLE IF NOT EXISTS positions (
   dca_count INTEGER NOT NULL DEFAULT 0, -- Number of additional entries (DCA)
   average_entry_price REAL NOT NULL DEFAULT 0, -- Weighted average entry price
   last_dca_time TEXT, -- Last DCA timestamp for cooldown
+  -- Position sizing (set at entry)
+  sizing_mode TEXT, -- Sizing mode that chose entry_size_sol
+  sizing_reason TEXT, -- Sizing rationale
   -- Timestamps
   created_at TEXT NOT NULL DEFAULT (datetime('now')),
   updated_at TEXT NOT NULL DEFAULT (datetime('now'))
@@ -150,6 +154,22 @@ CREATE TABLE IF NOT EXISTS position_entries (
 );
 "#;
 
+const SCHEMA_POSITION_TP_TIERS: &str = r#"
+CREATE TABLE IF NOT EXISTS position_tp_tiers (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  position_id INTEGER NOT NULL,
+  wallet_address TEXT NOT NULL,
+  tier INTEGER NOT NULL, -- 1-based tier number in the ladder sorted by trigger_pct
+  trigger_pct REAL NOT NULL, -- Profit % that triggered the tier
+  sell_pct REAL NOT NULL, -- % of original position sold
+  trigger_price REAL NOT NULL, -- Price when the tier fired
+  transaction_signature TEXT, -- NULL while the tier exit is in flight
+  triggered_at TEXT NOT NULL,
+  UNIQUE (position_id, tier),
+  FOREIGN KEY (position_id) REFERENCES positions(id) ON DELETE CASCADE
+);
+"#;
+
 const SCHEMA_POSITION_TRACKING: &str = r#"
 CREATE TABLE IF NOT EXISTS position_tracking (
   id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -228,14 +248,26 @@ CREATE TABLE IF NOT EXISTS token_snapshots (
 );
 "#;
 
-const MIGRATION_ADD_PNL_FIELDS: &str = r#"
--- Add P&L fields to positions table (safe migration - columns are nullable)
-ALTER TABLE positions ADD COLUMN pnl REAL;
-ALTER TABLE positions ADD COLUMN pnl_percent REAL;
-ALTER TABLE positions ADD COLUMN unrealized_pnl REAL;
-ALTER TABLE positions ADD COLUMN unrealized_pnl_percent REAL;
+const SCHEMA_SOL_PRICE_HISTORY: &str = r#"
+CREATE TABLE IF NOT EXISTS sol_price_history (
+  minute INTEGER PRIMARY KEY, -- Unix timestamp truncated to the minute
+  price_usd REAL NOT NULL
+);
 "#;
 
+// Positions columns added after the first release (column, definition); older
+// databases may have any subset of them, so only the missing ones are added
+const BASELINE_POSITION_COLUMNS: &[(&str, &str)] = &[
+    // P&L fields
+    ("pnl", "REAL"),
+    ("pnl_percent", "REAL"),
+    ("unrealized_pnl", "REAL"),
+    ("unrealized_pnl_percent", "REAL"),
+    // Position sizing fields
+    ("sizing_mode", "TEXT"),
+    ("sizing_reason", "TEXT"),
+];
+
 // Performance indexes
 const POSITIONS_INDEXES: &[&str] = &[
   "CREATE INDEX IF NOT EXISTS idx_positions_wallet ON positions(wallet_address);",
@@ -260,8 +292,93 @@ const POSITIONS_INDEXES: &[&str] = &[
   "CREATE INDEX IF NOT EXISTS idx_position_entries_wallet ON position_entries(wallet_address);",
   "CREATE INDEX IF NOT EXISTS idx_position_entries_position_id ON position_entries(position_id, timestamp DESC);",
   "CREATE INDEX IF NOT EXISTS idx_position_entries_timestamp ON position_entries(timestamp DESC);",
+  "CREATE INDEX IF NOT EXISTS idx_position_tp_tiers_position_id ON position_tp_tiers(position_id, tier);",
 ];
 
+/// Ordered schema migrations of positions.db (and paper_positions.db)
+pub const POSITIONS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "positions",
+    migrations: &[
+        Migration::apply(
+            1,
+            "Baseline schema (tables, P&L and sizing columns, indexes)",
+            baseline_schema,
+        ),
+        Migration::sql(
+            2,
+            "Key take-profit tiers by trigger %",
+            KEY_TP_TIERS_BY_TRIGGER,
+        ),
+    ],
+};
+
+/// Migration 2: fired tiers are unique per trigger % instead of per ladder index,
+/// so editing the ladder while a position is open keeps fired tiers fired
+const KEY_TP_TIERS_BY_TRIGGER: &str = r#"
+CREATE TABLE position_tp_tiers_new (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  position_id INTEGER NOT NULL,
+  wallet_address TEXT NOT NULL,
+  tier INTEGER NOT NULL, -- 1-based tier number in the ladder when the tier fired
+  trigger_pct REAL NOT NULL, -- Profit % that triggered the tier (identifies the tier)
+  sell_pct REAL NOT NULL, -- % of original position sold
+  trigger_price REAL NOT NULL, -- Price when the tier fired
+  transaction_signature TEXT, -- NULL while the tier exit is in flight
+  triggered_at TEXT NOT NULL,
+  UNIQUE (position_id, trigger_pct),
+  FOREIGN KEY (position_id) REFERENCES positions(id) ON DELETE CASCADE
+);
+INSERT OR IGNORE INTO position_tp_tiers_new (position_id, wallet_address, tier, trigger_pct,
+  sell_pct, trigger_price, transaction_signature, triggered_at)
+  SELECT position_id, wallet_address, tier, trigger_pct, sell_pct, trigger_price,
+    transaction_signature, triggered_at
+  FROM position_tp_tiers ORDER BY id;
+DROP TABLE position_tp_tiers;
+ALTER TABLE position_tp_tiers_new RENAME TO position_tp_tiers;
+CREATE INDEX IF NOT EXISTS idx_position_tp_tiers_position_id ON position_tp_tiers(position_id, trigger_pct);
+"#;
+
+/// Migration 1: the schema as it stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    conn.execute(SCHEMA_POSITIONS, [])
+        .map_err(|e| format!("Failed to create positions table: {}", e))?;
+
+    conn.execute(SCHEMA_POSITION_STATES, [])
+        .map_err(|e| format!("Failed to create position_states table: {}", e))?;
+
+    conn.execute(SCHEMA_POSITION_EXITS, [])
+        .map_err(|e| format!("Failed to create position_exits table: {}", e))?;
+
+    conn.execute(SCHEMA_POSITION_ENTRIES, [])
+        .map_err(|e| format!("Failed to create position_entries table: {}", e))?;
+
+    conn.execute(SCHEMA_POSITION_TP_TIERS, [])
+        .map_err(|e| format!("Failed to create position_tp_tiers table: {}", e))?;
+
+    conn.execute(SCHEMA_POSITION_TRACKING, [])
+        .map_err(|e| format!("Failed to create position_tracking table: {}", e))?;
+
+    conn.execute(SCHEMA_POSITION_METADATA, [])
+        .map_err(|e| format!("Failed to create position_metadata table: {}", e))?;
+
+    conn.execute(SCHEMA_TOKEN_SNAPSHOTS, [])
+        .map_err(|e| format!("Failed to create token_snapshots table: {}", e))?;
+
+    conn.execute(SCHEMA_SOL_PRICE_HISTORY, [])
+        .map_err(|e| format!("Failed to create sol_price_history table: {}", e))?;
+
+    for (column, definition) in BASELINE_POSITION_COLUMNS {
+        add_column_if_missing(conn, "positions", column, definition)?;
+    }
+
+    for index_sql in POSITIONS_INDEXES {
+        conn.execute(index_sql, [])
+            .map_err(|e| format!("Failed to create positions index: {}", e))?;
+    }
+
+    Ok(())
+}
+
 // =============================================================================
 // DATA STRUCTURES
 // =============================================================================
@@ -426,7 +543,11 @@ pub struct PositionsDatabase {
 impl PositionsDatabase {
     /// Create new PositionsDatabase with connection pooling
     pub async fn new() -> Result<Self, String> {
-        let database_path = crate::paths::get_positions_db_path();
+        let database_path = if crate::global::is_paper_trading() {
+            crate::paths::get_paper_positions_db_path()
+        } else {
+            crate::paths::get_positions_db_path()
+        };
         let database_path_str = database_path.to_string_lossy().to_string();
 
         // Only log detailed initialization on first database creation
@@ -484,65 +605,7 @@ impl PositionsDatabase {
         let _ = conn.pragma_update(None, "temp_store", "memory");
         let _ = conn.busy_timeout(std::time::Duration::from_millis(30_000));
 
-        // Create all tables
-        conn.execute(SCHEMA_POSITIONS, [])
-            .map_err(|e| format!("Failed to create positions table: {}", e))?;
-
-        conn.execute(SCHEMA_POSITION_STATES, [])
-            .map_err(|e| format!("Failed to create position_states table: {}", e))?;
-
-        conn.execute(SCHEMA_POSITION_EXITS, [])
-            .map_err(|e| format!("Failed to create position_exits table: {}", e))?;
-
-        conn.execute(SCHEMA_POSITION_ENTRIES, [])
-            .map_err(|e| format!("Failed to create position_entries table: {}", e))?;
-
-        conn.execute(SCHEMA_POSITION_TRACKING, [])
-            .map_err(|e| format!("Failed to create position_tracking table: {}", e))?;
-
-        conn.execute(SCHEMA_POSITION_METADATA, [])
-            .map_err(|e| format!("Failed to create position_metadata table: {}", e))?;
-
-        conn.execute(SCHEMA_TOKEN_SNAPSHOTS, [])
-            .map_err(|e| format!("Failed to create token_snapshots table: {}", e))?;
-
-        // Migrate existing database to add PnL fields if needed
-        // Check if migration is needed by attempting to add columns
-        match conn.execute_batch(MIGRATION_ADD_PNL_FIELDS) {
-            Ok(_) => {
-                if log_initialization {
-                    crate::logger::info(
-                        crate::logger::LogTag::Positions,
-                        "PnL columns migration completed successfully",
-                    );
-                }
-            }
-            Err(e) => {
-                let err_msg = e.to_string().to_lowercase();
-                // SQLite returns "duplicate column name"if columns already exist - this is OK
-                if err_msg.contains("duplicate column") {
-                    if log_initialization {
-                        crate::logger::debug(
-                            crate::logger::LogTag::Positions,
-                            "PnL columns already exist, skipping migration",
-                        );
-                    }
-                } else {
-                    // Real error - this is critical and should be logged
-                    crate::logger::error(
-                        crate::logger::LogTag::Positions,
-                        &format!("CRITICAL: Failed to migrate PnL columns: {}", e),
-                    );
-                    return Err(format!("Database migration failed: {}", e));
-                }
-            }
-        }
-
-        // Create all indexes
-        for index_sql in POSITIONS_INDEXES {
-            conn.execute(index_sql, [])
-                .map_err(|e| format!("Failed to create positions index: {}", e))?;
-        }
+        migrations::run_migrations(&conn, &POSITIONS_MIGRATIONS)?;
 
         // Set schema version
         conn.execute(
@@ -697,11 +760,11 @@ impl PositionsDatabase {
         phantom_confirmations, phantom_first_seen, synthetic_exit, closed_reason,
         pnl, pnl_percent, unrealized_pnl, unrealized_pnl_percent,
         remaining_token_amount, total_exited_amount, average_exit_price, partial_exit_count,
-        dca_count, average_entry_price, last_dca_time
+        dca_count, average_entry_price, last_dca_time, sizing_mode, sizing_reason
       ) VALUES (
         ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
         ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32,
-        ?33, ?34, ?35, ?36, ?37, ?38, ?39, ?40, ?41, ?42, ?43
+        ?33, ?34, ?35, ?36, ?37, ?38, ?39, ?40, ?41, ?42, ?43, ?44, ?45
       ) RETURNING id
       "#,
                 params![
@@ -747,7 +810,9 @@ impl PositionsDatabase {
                     position.partial_exit_count as i64,
                     position.dca_count as i64,
                     position.average_entry_price,
-                    position.last_dca_time.map(|t| t.to_rfc3339())
+                    position.last_dca_time.map(|t| t.to_rfc3339()),
+                    position.sizing_mode,
+                    position.sizing_reason
                 ],
                 |row| row.get::<_, i64>(0),
             )
@@ -2177,6 +2242,9 @@ impl PositionsDatabase {
             dca_count: row.get::<_, i64>("dca_count")? as u32,
             average_entry_price: row.get("average_entry_price")?,
             last_dca_time,
+            // Sizing columns are absent from some SELECT lists
+            sizing_mode: row.get::<_, Option<String>>("sizing_mode").ok().flatten(),
+            sizing_reason: row.get::<_, Option<String>>("sizing_reason").ok().flatten(),
         })
     }
 }
@@ -2196,6 +2264,16 @@ pub async fn initialize_positions_database() -> Result<(), String> {
         return Ok(()); // Already initialized
     }
 
+    // Latch paper mode before opening the database so the whole process uses one store
+    let paper_trading = crate::config::with_config(|cfg| cfg.trader.paper_trading_enabled);
+    crate::global::set_paper_trading(paper_trading);
+    if paper_trading {
+        logger::warning(
+            LogTag::Positions,
+            "PAPER TRADING MODE: swaps are simulated and positions are stored in paper_positions.db",
+        );
+    }
+
     let db = PositionsDatabase::new().await?;
     *db_lock = Some(db);
 
@@ -2605,22 +2683,7 @@ pub async fn get_exit_history(position_id: i64) -> Result<Vec<ExitRecord>, Strin
         .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
     let records = stmt
-        .query_map(params![position_id, wallet_address], |row| {
-            Ok(ExitRecord {
-                id: row.get(0)?,
-                position_id: row.get(1)?,
-                timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(2)?)
-                    .unwrap()
-                    .with_timezone(&Utc),
-                amount: row.get::<_, i64>(3)? as u64,
-                price: row.get(4)?,
-                sol_received: row.get(5)?,
-                transaction_signature: row.get(6)?,
-                is_partial: row.get(7)?,
-                percentage: row.get(8)?,
-                fees_lamports: row.get::<_, Option<i64>>(9)?.map(|f| f as u64),
-            })
-        })
+        .query_map(params![position_id, wallet_address], row_to_exit_record)
         .map_err(|e| format!("Failed to query exit records: {}", e))?
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| format!("Failed to collect exit records: {}", e))?;
@@ -2702,25 +2765,448 @@ pub async fn get_entry_history(position_id: i64) -> Result<Vec<EntryRecord>, Str
         )
         .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
+    let records = stmt
+        .query_map(params![position_id, wallet_address], row_to_entry_record)
+        .map_err(|e| format!("Failed to query entry records: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect entry records: {}", e))?;
+
+    Ok(records)
+}
+
+fn row_to_exit_record(row: &rusqlite::Row) -> rusqlite::Result<ExitRecord> {
+    Ok(ExitRecord {
+        id: row.get(0)?,
+        position_id: row.get(1)?,
+        timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(2)?)
+            .unwrap()
+            .with_timezone(&Utc),
+        amount: row.get::<_, i64>(3)? as u64,
+        price: row.get(4)?,
+        sol_received: row.get(5)?,
+        transaction_signature: row.get(6)?,
+        is_partial: row.get(7)?,
+        percentage: row.get(8)?,
+        fees_lamports: row.get::<_, Option<i64>>(9)?.map(|f| f as u64),
+    })
+}
+
+fn row_to_entry_record(row: &rusqlite::Row) -> rusqlite::Result<EntryRecord> {
+    Ok(EntryRecord {
+        id: row.get(0)?,
+        position_id: row.get(1)?,
+        timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(2)?)
+            .unwrap()
+            .with_timezone(&Utc),
+        amount: row.get::<_, i64>(3)? as u64,
+        price: row.get(4)?,
+        sol_spent: row.get(5)?,
+        transaction_signature: row.get(6)?,
+        is_dca: row.get(7)?,
+        fees_lamports: row.get::<_, Option<i64>>(8)?.map(|f| f as u64),
+    })
+}
+
+// ==================== WALLET HISTORY FUNCTIONS ====================
+
+/// Get all positions recorded for a wallet, oldest first
+pub async fn get_positions_for_wallet(wallet_address: &str) -> Result<Vec<Position>, String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db.get_connection()?;
+
+    let query = format!(
+        "SELECT {} FROM positions WHERE wallet_address = ?1 ORDER BY entry_time ASC",
+        POSITION_SELECT_COLUMNS
+    );
+
+    let mut stmt = conn
+        .prepare(&query)
+        .map_err(|e| format!("Failed to prepare wallet positions query: {}", e))?;
+
+    let positions = stmt
+        .query_map(params![wallet_address], |row| db.row_to_position(row))
+        .map_err(|e| format!("Failed to execute wallet positions query: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to parse position row: {}", e))?;
+
+    Ok(positions)
+}
+
+/// Get every entry record (initial entries and DCA) for a wallet, oldest first
+pub async fn get_entry_records_for_wallet(
+    wallet_address: &str,
+) -> Result<Vec<EntryRecord>, String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db.get_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, position_id, timestamp, amount, price, sol_spent,
+       transaction_signature, is_dca, fees_lamports
+       FROM position_entries WHERE wallet_address = ?1 ORDER BY timestamp ASC",
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let records = stmt
+        .query_map(params![wallet_address], row_to_entry_record)
+        .map_err(|e| format!("Failed to query entry records: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect entry records: {}", e))?;
+
+    Ok(records)
+}
+
+/// Get every exit record for a wallet, oldest first
+pub async fn get_exit_records_for_wallet(wallet_address: &str) -> Result<Vec<ExitRecord>, String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db.get_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, position_id, timestamp, amount, price, sol_received,
+       transaction_signature, is_partial, percentage, fees_lamports
+       FROM position_exits WHERE wallet_address = ?1 ORDER BY timestamp ASC",
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let records = stmt
+        .query_map(params![wallet_address], row_to_exit_record)
+        .map_err(|e| format!("Failed to query exit records: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect exit records: {}", e))?;
+
+    Ok(records)
+}
+
+// ==================== SOL PRICE HISTORY FUNCTIONS ====================
+
+/// Store a SOL/USD sample (one row per minute, latest sample wins)
+pub async fn record_sol_price_sample(
+    timestamp: DateTime<Utc>,
+    price_usd: f64,
+) -> Result<(), String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db.get_connection()?;
+    let minute = timestamp.timestamp() - timestamp.timestamp().rem_euclid(60);
+
+    conn.execute(
+        "INSERT OR REPLACE INTO sol_price_history (minute, price_usd) VALUES (?1, ?2)",
+        params![minute, price_usd],
+    )
+    .map_err(|e| format!("Failed to record SOL price sample: {}", e))?;
+
+    Ok(())
+}
+
+/// Get SOL/USD samples between two unix timestamps (inclusive), oldest first
+pub async fn get_sol_price_samples(from: i64, to: i64) -> Result<Vec<(i64, f64)>, String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db.get_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT minute, price_usd FROM sol_price_history
+       WHERE minute >= ?1 AND minute <= ?2 ORDER BY minute ASC",
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let samples = stmt
+        .query_map(params![from, to], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| format!("Failed to query SOL price samples: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect SOL price samples: {}", e))?;
+
+    Ok(samples)
+}
+
+/// Claim a take-profit ladder tier for a position before its exit is executed
+///
+/// Tiers are identified by their trigger %, so editing the ladder while a position
+/// is open neither re-fires nor skips tiers. Returns false if the tier was already
+/// claimed (fired or in flight) or another tier of the position is still in flight,
+/// which keeps concurrent evaluations from selling twice.
+pub async fn claim_take_profit_tier(
+    position_id: i64,
+    tier: u32,
+    trigger_pct: f64,
+    sell_pct: f64,
+    trigger_price: f64,
+) -> Result<bool, String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db
+        .pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    let wallet_address = crate::utils::get_wallet_address().map_err(|e| e.to_string())?;
+
+    insert_take_profit_tier(
+        &conn,
+        position_id,
+        &wallet_address,
+        tier,
+        trigger_pct,
+        sell_pct,
+        trigger_price,
+    )
+}
+
+/// Attach the exit transaction signature to a claimed take-profit tier
+pub async fn set_take_profit_tier_signature(
+    position_id: i64,
+    trigger_pct: f64,
+    transaction_signature: &str,
+) -> Result<(), String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db
+        .pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    conn.execute(
+        "UPDATE position_tp_tiers SET transaction_signature = ?1 WHERE position_id = ?2 AND trigger_pct = ?3",
+        params![transaction_signature, position_id, trigger_pct],
+    )
+    .map_err(|e| format!("Failed to update take-profit tier signature: {}", e))?;
+
+    logger::info(
+        LogTag::Positions,
+        &format!(
+            "Take-profit tier +{}% fired for position {}: tx={}",
+            trigger_pct, position_id, transaction_signature
+        ),
+    );
+
+    Ok(())
+}
+
+/// Release a take-profit tier after its exit failed so it can fire again
+pub async fn release_take_profit_tier(position_id: i64, trigger_pct: f64) -> Result<(), String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db
+        .pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    conn.execute(
+        "DELETE FROM position_tp_tiers WHERE position_id = ?1 AND trigger_pct = ?2",
+        params![position_id, trigger_pct],
+    )
+    .map_err(|e| format!("Failed to release take-profit tier: {}", e))?;
+
+    Ok(())
+}
+
+/// Get take-profit ladder tiers fired (or in flight) for a position
+pub async fn get_take_profit_tiers(position_id: i64) -> Result<Vec<TakeProfitTierRecord>, String> {
+    let db_guard = GLOBAL_POSITIONS_DB.lock().await;
+    let db = db_guard
+        .as_ref()
+        .ok_or("Positions database not initialized")?;
+
+    let conn = db
+        .pool
+        .get()
+        .map_err(|e| format!("Failed to get connection: {}", e))?;
+
+    let wallet_address = crate::utils::get_wallet_address().map_err(|e| e.to_string())?;
+
+    query_take_profit_tiers(&conn, position_id, &wallet_address)
+}
+
+/// Claims without a signature after this long belong to an exit that never
+/// returned (e.g. the bot stopped mid-swap) and are dropped
+const TP_TIER_CLAIM_TTL_SECS: i64 = 600;
+
+fn insert_take_profit_tier(
+    conn: &Connection,
+    position_id: i64,
+    wallet_address: &str,
+    tier: u32,
+    trigger_pct: f64,
+    sell_pct: f64,
+    trigger_price: f64,
+) -> Result<bool, String> {
+    let stale_before =
+        (Utc::now() - chrono::Duration::seconds(TP_TIER_CLAIM_TTL_SECS)).to_rfc3339();
+    conn.execute(
+        "DELETE FROM position_tp_tiers
+     WHERE position_id = ?1 AND transaction_signature IS NULL AND triggered_at < ?2",
+        params![position_id, stale_before],
+    )
+    .map_err(|e| format!("Failed to drop stale take-profit claims: {}", e))?;
+
+    let inserted = conn
+        .execute(
+            "INSERT OR IGNORE INTO position_tp_tiers (position_id, wallet_address, tier, trigger_pct,
+     sell_pct, trigger_price, triggered_at)
+     SELECT ?1, ?2, ?3, ?4, ?5, ?6, ?7
+     WHERE NOT EXISTS (SELECT 1 FROM position_tp_tiers
+       WHERE position_id = ?1 AND transaction_signature IS NULL)",
+            params![
+                position_id,
+                wallet_address,
+                tier,
+                trigger_pct,
+                sell_pct,
+                trigger_price,
+                Utc::now().to_rfc3339(),
+            ],
+        )
+        .map_err(|e| format!("Failed to claim take-profit tier: {}", e))?;
+
+    Ok(inserted > 0)
+}
+
+fn query_take_profit_tiers(
+    conn: &Connection,
+    position_id: i64,
+    wallet_address: &str,
+) -> Result<Vec<TakeProfitTierRecord>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT position_id, tier, trigger_pct, sell_pct, trigger_price,
+       transaction_signature, triggered_at
+       FROM position_tp_tiers WHERE position_id = ?1 AND wallet_address = ?2 ORDER BY trigger_pct ASC",
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
     let records = stmt
         .query_map(params![position_id, wallet_address], |row| {
-            Ok(EntryRecord {
-                id: row.get(0)?,
-                position_id: row.get(1)?,
-                timestamp: DateTime::parse_from_rfc3339(&row.get::<_, String>(2)?)
-                    .unwrap()
-                    .with_timezone(&Utc),
-                amount: row.get::<_, i64>(3)? as u64,
-                price: row.get(4)?,
-                sol_spent: row.get(5)?,
-                transaction_signature: row.get(6)?,
-                is_dca: row.get(7)?,
-                fees_lamports: row.get::<_, Option<i64>>(8)?.map(|f| f as u64),
+            Ok(TakeProfitTierRecord {
+                position_id: row.get(0)?,
+                tier: row.get(1)?,
+                trigger_pct: row.get(2)?,
+                sell_pct: row.get(3)?,
+                trigger_price: row.get(4)?,
+                transaction_signature: row.get(5)?,
+                triggered_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(6)?)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
             })
         })
-        .map_err(|e| format!("Failed to query entry records: {}", e))?
+        .map_err(|e| format!("Failed to query take-profit tiers: {}", e))?
         .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| format!("Failed to collect entry records: {}", e))?;
+        .map_err(|e| format!("Failed to collect take-profit tiers: {}", e))?;
 
     Ok(records)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET: &str = "wallet";
+
+    /// Migrated in-memory database holding positions 1 and 2 for the tier foreign key
+    fn migrated_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        migrations::run_migrations(&conn, &POSITIONS_MIGRATIONS).unwrap();
+        for id in [1, 2] {
+            conn.execute(
+                "INSERT INTO positions (id, wallet_address, mint, symbol, name, entry_price, entry_time,
+                    position_type, entry_size_sol, total_size_sol, price_highest, price_lowest)
+                 VALUES (?1, ?2, 'mint', 'TEST', 'Test', 1.0, ?3, 'buy', 0.1, 0.1, 1.0, 1.0)",
+                params![id, WALLET, Utc::now().to_rfc3339()],
+            )
+            .unwrap();
+        }
+        conn
+    }
+
+    fn sign(conn: &Connection, position_id: i64, trigger_pct: f64, signature: &str) {
+        conn.execute(
+            "UPDATE position_tp_tiers SET transaction_signature = ?1 WHERE position_id = ?2 AND trigger_pct = ?3",
+            params![signature, position_id, trigger_pct],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_take_profit_tier_claim_and_release() {
+        let conn = migrated_connection();
+
+        assert!(insert_take_profit_tier(&conn, 1, WALLET, 1, 25.0, 30.0, 1.25).unwrap());
+        // Same trigger cannot be claimed twice
+        assert!(!insert_take_profit_tier(&conn, 1, WALLET, 1, 25.0, 30.0, 1.26).unwrap());
+        // Another tier waits until the in-flight claim has a signature
+        assert!(!insert_take_profit_tier(&conn, 1, WALLET, 2, 60.0, 30.0, 1.6).unwrap());
+        // Other positions are independent
+        assert!(insert_take_profit_tier(&conn, 2, WALLET, 1, 25.0, 30.0, 1.25).unwrap());
+
+        sign(&conn, 1, 25.0, "sig_1");
+        assert!(insert_take_profit_tier(&conn, 1, WALLET, 2, 60.0, 30.0, 1.6).unwrap());
+
+        // Releasing a failed tier lets it fire again
+        conn.execute(
+            "DELETE FROM position_tp_tiers WHERE position_id = ?1 AND trigger_pct = ?2",
+            params![1, 60.0],
+        )
+        .unwrap();
+        assert!(insert_take_profit_tier(&conn, 1, WALLET, 2, 60.0, 30.0, 1.61).unwrap());
+
+        let tiers = query_take_profit_tiers(&conn, 1, WALLET).unwrap();
+        assert_eq!(tiers.len(), 2);
+        assert_eq!(tiers[0].trigger_pct, 25.0);
+        assert_eq!(tiers[0].transaction_signature.as_deref(), Some("sig_1"));
+        assert_eq!(tiers[1].trigger_pct, 60.0);
+        assert_eq!(tiers[1].trigger_price, 1.61);
+        assert!(tiers[1].transaction_signature.is_none());
+
+        // An in-flight claim that never got a signature expires
+        let stale = (Utc::now() - chrono::Duration::hours(1)).to_rfc3339();
+        conn.execute(
+            "UPDATE position_tp_tiers SET triggered_at = ?1 WHERE position_id = 1 AND trigger_pct = 60.0",
+            params![stale],
+        )
+        .unwrap();
+        assert!(insert_take_profit_tier(&conn, 1, WALLET, 2, 60.0, 30.0, 1.62).unwrap());
+    }
+
+    #[test]
+    fn test_take_profit_tiers_survive_ladder_edits() {
+        let conn = migrated_connection();
+
+        // Tier 1 (+25%) fired, then a +10% tier is inserted in front of it
+        assert!(insert_take_profit_tier(&conn, 1, WALLET, 1, 25.0, 30.0, 1.25).unwrap());
+        sign(&conn, 1, 25.0, "sig_1");
+
+        // The new first tier is a different trigger and can still fire...
+        assert!(insert_take_profit_tier(&conn, 1, WALLET, 1, 10.0, 20.0, 1.1).unwrap());
+        sign(&conn, 1, 10.0, "sig_2");
+        // ...while +25% (now tier 2) stays fired
+        assert!(!insert_take_profit_tier(&conn, 1, WALLET, 2, 25.0, 30.0, 1.3).unwrap());
+    }
+}
@@ -0,0 +1,697 @@
+//! Accounting export of realized trades
+//!
+//! Rebuilds the trade ledger of a wallet from the positions database: initial
+//! entries and DCA buys from `position_entries`, partial exits from
+//! `position_exits`, and the final exit from the position row itself (its
+//! `sol_received` is overwritten with the final swap on verification).
+//!
+//! Each trade is enriched with:
+//! - fees split into network, priority and MEV tips from the transaction analyzer
+//!   when the transaction is in the local database, otherwise the recorded fee
+//! - the SOL/USD price at the time from the per-minute SOL price history
+//! - FIFO cost basis per lot for sells, matched across all positions of the mint
+//!
+//! Buy fees are added to the lot cost basis and sell fees reduce proceeds. Lots are
+//! matched over the whole history so that the date range only filters output rows.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::db::{
+    get_entry_records_for_wallet, get_exit_records_for_wallet, get_positions_for_wallet,
+    get_sol_price_samples,
+};
+use super::is_paper_signature;
+use crate::transactions::analyzer::TransactionAnalyzer;
+use crate::transactions::database::get_transaction_database;
+use crate::utils::lamports_to_sol;
+
+/// Largest gap between a trade and the nearest SOL price sample that is still used
+const SOL_PRICE_MAX_GAP_SECS: i64 = 3600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeKind {
+    Buy,
+    Dca,
+    PartialExit,
+    Exit,
+}
+
+impl TradeKind {
+    pub fn is_sell(&self) -> bool {
+        matches!(self, TradeKind::PartialExit | TradeKind::Exit)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradeKind::Buy => "buy",
+            TradeKind::Dca => "dca",
+            TradeKind::PartialExit => "partial_exit",
+            TradeKind::Exit => "exit",
+        }
+    }
+}
+
+/// Where the fee split of a trade comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeSource {
+    /// Transaction analyzer over the stored transaction
+    Transaction,
+    /// Fee recorded on the position (network fee only)
+    Recorded,
+    /// Paper trade, no on-chain fees
+    Paper,
+}
+
+/// Part of a sell matched against one buy lot
+#[derive(Debug, Clone, Serialize)]
+pub struct LotMatch {
+    /// Buy transaction of the lot (None when the sell exceeded the known lots; zero basis)
+    pub lot_signature: Option<String>,
+    pub lot_timestamp: Option<DateTime<Utc>>,
+    pub token_amount: u64,
+    pub cost_basis_sol: f64,
+    pub proceeds_sol: f64,
+    pub realized_gain_sol: f64,
+    pub cost_basis_usd: Option<f64>,
+    pub proceeds_usd: Option<f64>,
+    pub realized_gain_usd: Option<f64>,
+}
+
+/// One buy or sell in the ledger
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeRecord {
+    pub timestamp: DateTime<Utc>,
+    pub position_id: i64,
+    pub mint: String,
+    pub symbol: String,
+    pub kind: TradeKind,
+    pub signature: String,
+    /// Raw token amount (base units)
+    pub token_amount: u64,
+    /// SOL spent (buys) or received (sells), before fees
+    pub sol_amount: f64,
+    pub price_sol: f64,
+    pub sol_price_usd: Option<f64>,
+    pub usd_value: Option<f64>,
+    pub network_fee_sol: f64,
+    pub priority_fee_sol: f64,
+    pub mev_tip_sol: f64,
+    pub fee_source: FeeSource,
+    /// FIFO results, sells only
+    pub cost_basis_sol: Option<f64>,
+    pub realized_gain_sol: Option<f64>,
+    pub realized_gain_usd: Option<f64>,
+    pub lots: Vec<LotMatch>,
+}
+
+impl TradeRecord {
+    pub fn total_fees_sol(&self) -> f64 {
+        self.network_fee_sol + self.priority_fee_sol + self.mev_tip_sol
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TradeExportTotals {
+    pub buys: usize,
+    pub sells: usize,
+    pub sol_spent: f64,
+    pub sol_received: f64,
+    pub fees_sol: f64,
+    pub mev_tips_sol: f64,
+    pub realized_gain_sol: f64,
+    /// None when any sell in range lacks a USD valuation
+    pub realized_gain_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeExport {
+    pub wallet_address: String,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub generated_at: DateTime<Utc>,
+    pub totals: TradeExportTotals,
+    pub trades: Vec<TradeRecord>,
+}
+
+#[derive(Debug, Serialize)]
+struct TradeCsvRow<'a> {
+    timestamp: String,
+    position_id: i64,
+    mint: &'a str,
+    symbol: &'a str,
+    kind: &'static str,
+    signature: &'a str,
+    token_amount: u64,
+    sol_amount: f64,
+    price_sol: f64,
+    sol_price_usd: Option<f64>,
+    usd_value: Option<f64>,
+    network_fee_sol: f64,
+    priority_fee_sol: f64,
+    mev_tip_sol: f64,
+    cost_basis_sol: Option<f64>,
+    realized_gain_sol: Option<f64>,
+    realized_gain_usd: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+struct LotCsvRow<'a> {
+    sell_timestamp: String,
+    sell_signature: &'a str,
+    mint: &'a str,
+    symbol: &'a str,
+    lot_timestamp: Option<String>,
+    lot_signature: Option<&'a str>,
+    token_amount: u64,
+    cost_basis_sol: f64,
+    proceeds_sol: f64,
+    realized_gain_sol: f64,
+    cost_basis_usd: Option<f64>,
+    proceeds_usd: Option<f64>,
+    realized_gain_usd: Option<f64>,
+}
+
+/// Build the trade ledger for a wallet (current wallet when None), limited to `[from, to]`
+pub async fn build_trade_export(
+    wallet_address: Option<String>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> Result<TradeExport, String> {
+    let wallet_address = match wallet_address {
+        Some(wallet) => wallet,
+        None => crate::utils::get_wallet_address().map_err(|e| e.to_string())?,
+    };
+
+    let positions = get_positions_for_wallet(&wallet_address).await?;
+    let entries = get_entry_records_for_wallet(&wallet_address).await?;
+    let exits = get_exit_records_for_wallet(&wallet_address).await?;
+
+    let mut entries_by_position: HashMap<i64, Vec<_>> = HashMap::new();
+    for entry in entries {
+        entries_by_position
+            .entry(entry.position_id)
+            .or_default()
+            .push(entry);
+    }
+    let mut exits_by_position: HashMap<i64, Vec<_>> = HashMap::new();
+    for exit in exits {
+        exits_by_position
+            .entry(exit.position_id)
+            .or_default()
+            .push(exit);
+    }
+
+    let mut trades = Vec::new();
+    for position in &positions {
+        let Some(position_id) = position.id else {
+            continue;
+        };
+        let entries = entries_by_position.remove(&position_id).unwrap_or_default();
+        let exits = exits_by_position.remove(&position_id).unwrap_or_default();
+
+        let trade =
+            |timestamp, kind, signature: &str, token_amount, sol_amount, price_sol| TradeRecord {
+                timestamp,
+                position_id,
+                mint: position.mint.clone(),
+                symbol: position.symbol.clone(),
+                kind,
+                signature: signature.to_string(),
+                token_amount,
+                sol_amount,
+                price_sol,
+                sol_price_usd: None,
+                usd_value: None,
+                network_fee_sol: 0.0,
+                priority_fee_sol: 0.0,
+                mev_tip_sol: 0.0,
+                fee_source: FeeSource::Recorded,
+                cost_basis_sol: None,
+                realized_gain_sol: None,
+                realized_gain_usd: None,
+                lots: Vec::new(),
+            };
+
+        // Older positions may predate entry history; fall back to the position row
+        let mut bought: u64 = 0;
+        if !entries.iter().any(|e| !e.is_dca) && position.transaction_entry_verified {
+            if let Some(signature) = position.entry_transaction_signature.as_deref() {
+                let amount = position.token_amount.unwrap_or(0);
+                bought += amount;
+                let mut record = trade(
+                    position.entry_time,
+                    TradeKind::Buy,
+                    signature,
+                    amount,
+                    position.entry_size_sol,
+                    position
+                        .effective_entry_price
+                        .unwrap_or(position.entry_price),
+                );
+                record.network_fee_sol = lamports_to_sol(position.entry_fee_lamports.unwrap_or(0));
+                trades.push(record);
+            }
+        }
+        for entry in &entries {
+            bought += entry.amount;
+            let kind = if entry.is_dca {
+                TradeKind::Dca
+            } else {
+                TradeKind::Buy
+            };
+            let mut record = trade(
+                entry.timestamp,
+                kind,
+                &entry.transaction_signature,
+                entry.amount,
+                entry.sol_spent,
+                entry.price,
+            );
+            record.network_fee_sol = lamports_to_sol(entry.fees_lamports.unwrap_or(0));
+            trades.push(record);
+        }
+
+        let mut sold: u64 = 0;
+        let mut exit_signatures = HashSet::new();
+        for exit in &exits {
+            sold += exit.amount;
+            exit_signatures.insert(exit.transaction_signature.as_str());
+            let kind = if exit.is_partial {
+                TradeKind::PartialExit
+            } else {
+                TradeKind::Exit
+            };
+            let mut record = trade(
+                exit.timestamp,
+                kind,
+                &exit.transaction_signature,
+                exit.amount,
+                exit.sol_received,
+                exit.price,
+            );
+            record.network_fee_sol = lamports_to_sol(exit.fees_lamports.unwrap_or(0));
+            trades.push(record);
+        }
+
+        // Final exit lives on the position row; synthetic closures never sold anything
+        if position.transaction_exit_verified && !position.synthetic_exit {
+            if let (Some(signature), Some(exit_time)) = (
+                position.exit_transaction_signature.as_deref(),
+                position.exit_time,
+            ) {
+                if !exit_signatures.contains(signature) {
+                    let mut record = trade(
+                        exit_time,
+                        TradeKind::Exit,
+                        signature,
+                        bought.saturating_sub(sold),
+                        position.sol_received.unwrap_or(0.0),
+                        position
+                            .effective_exit_price
+                            .or(position.exit_price)
+                            .unwrap_or(0.0),
+                    );
+                    record.network_fee_sol =
+                        lamports_to_sol(position.exit_fee_lamports.unwrap_or(0));
+                    trades.push(record);
+                }
+            }
+        }
+    }
+
+    trades.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.kind.is_sell().cmp(&b.kind.is_sell()))
+    });
+
+    for trade in trades.iter_mut() {
+        apply_transaction_fees(trade).await;
+    }
+
+    if let (Some(first), Some(last)) = (trades.first(), trades.last()) {
+        let samples = get_sol_price_samples(
+            first.timestamp.timestamp() - SOL_PRICE_MAX_GAP_SECS,
+            last.timestamp.timestamp() + SOL_PRICE_MAX_GAP_SECS,
+        )
+        .await?;
+        for trade in trades.iter_mut() {
+            trade.sol_price_usd = sol_price_at(&samples, trade.timestamp.timestamp());
+            trade.usd_value = trade.sol_price_usd.map(|price| trade.sol_amount * price);
+        }
+    }
+
+    apply_fifo(&mut trades);
+
+    trades.retain(|trade| {
+        from.map_or(true, |from| trade.timestamp >= from)
+            && to.map_or(true, |to| trade.timestamp <= to)
+    });
+
+    Ok(TradeExport {
+        wallet_address,
+        from,
+        to,
+        generated_at: Utc::now(),
+        totals: compute_totals(&trades),
+        trades,
+    })
+}
+
+/// Replace the recorded fee with the analyzer's fee split when the transaction is stored
+async fn apply_transaction_fees(trade: &mut TradeRecord) {
+    if is_paper_signature(&trade.signature) {
+        trade.fee_source = FeeSource::Paper;
+        return;
+    }
+
+    let Some(db) = get_transaction_database().await else {
+        return;
+    };
+    let (Ok(Some(transaction)), Ok(Some(details))) = (
+        db.get_transaction(&trade.signature).await,
+        db.get_raw_transaction_details(&trade.signature).await,
+    ) else {
+        return;
+    };
+    let Ok(analysis) = TransactionAnalyzer::new(false)
+        .analyze_transaction(&transaction, &details)
+        .await
+    else {
+        return;
+    };
+
+    let fees = &analysis.pnl.fee_breakdown;
+    trade.network_fee_sol = fees.base_fee;
+    trade.priority_fee_sol = fees.priority_fee;
+    trade.mev_tip_sol = fees.mev_tips;
+    trade.fee_source = FeeSource::Transaction;
+}
+
+/// SOL/USD price nearest to `timestamp` from samples sorted by time
+fn sol_price_at(samples: &[(i64, f64)], timestamp: i64) -> Option<f64> {
+    let index = samples.partition_point(|(ts, _)| *ts < timestamp);
+    let before = index.checked_sub(1).and_then(|i| samples.get(i));
+    let after = samples.get(index);
+
+    let nearest = match (before, after) {
+        (Some(b), Some(a)) => {
+            if timestamp - b.0 <= a.0 - timestamp {
+                b
+            } else {
+                a
+            }
+        }
+        (Some(b), None) => b,
+        (None, Some(a)) => a,
+        (None, None) => return None,
+    };
+
+    ((nearest.0 - timestamp).abs() <= SOL_PRICE_MAX_GAP_SECS).then_some(nearest.1)
+}
+
+struct Lot {
+    signature: String,
+    timestamp: DateTime<Utc>,
+    remaining: u64,
+    cost_per_token_sol: f64,
+    sol_price_usd: Option<f64>,
+}
+
+/// Match sells against buy lots first-in first-out per mint. Trades must be sorted by time.
+fn apply_fifo(trades: &mut [TradeRecord]) {
+    let mut lots: HashMap<String, VecDeque<Lot>> = HashMap::new();
+
+    for trade in trades.iter_mut() {
+        if !trade.kind.is_sell() {
+            if trade.token_amount == 0 {
+                continue;
+            }
+            lots.entry(trade.mint.clone()).or_default().push_back(Lot {
+                signature: trade.signature.clone(),
+                timestamp: trade.timestamp,
+                remaining: trade.token_amount,
+                cost_per_token_sol: (trade.sol_amount + trade.total_fees_sol())
+                    / trade.token_amount as f64,
+                sol_price_usd: trade.sol_price_usd,
+            });
+            continue;
+        }
+
+        let proceeds_per_token_sol = if trade.token_amount > 0 {
+            (trade.sol_amount - trade.total_fees_sol()) / trade.token_amount as f64
+        } else {
+            0.0
+        };
+        let queue = lots.entry(trade.mint.clone()).or_default();
+        let mut to_match = trade.token_amount;
+        let mut matches = Vec::new();
+
+        while to_match > 0 {
+            let Some(lot) = queue.front_mut() else {
+                break;
+            };
+            let amount = to_match.min(lot.remaining);
+            matches.push(lot_match(
+                Some(lot),
+                amount,
+                proceeds_per_token_sol,
+                trade.sol_price_usd,
+            ));
+            lot.remaining -= amount;
+            to_match -= amount;
+            if lot.remaining == 0 {
+                queue.pop_front();
+            }
+        }
+        if to_match > 0 {
+            matches.push(lot_match(
+                None,
+                to_match,
+                proceeds_per_token_sol,
+                trade.sol_price_usd,
+            ));
+        }
+        if trade.token_amount == 0 {
+            // Unknown amount: report the whole sale as gain without a lot
+            matches.push(LotMatch {
+                lot_signature: None,
+                lot_timestamp: None,
+                token_amount: 0,
+                cost_basis_sol: 0.0,
+                proceeds_sol: trade.sol_amount - trade.total_fees_sol(),
+                realized_gain_sol: trade.sol_amount - trade.total_fees_sol(),
+                cost_basis_usd: Some(0.0),
+                proceeds_usd: trade
+                    .sol_price_usd
+                    .map(|price| (trade.sol_amount - trade.total_fees_sol()) * price),
+                realized_gain_usd: trade
+                    .sol_price_usd
+                    .map(|price| (trade.sol_amount - trade.total_fees_sol()) * price),
+            });
+        }
+
+        trade.cost_basis_sol = Some(matches.iter().map(|m| m.cost_basis_sol).sum());
+        trade.realized_gain_sol = Some(matches.iter().map(|m| m.realized_gain_sol).sum());
+        trade.realized_gain_usd = matches
+            .iter()
+            .map(|m| m.realized_gain_usd)
+            .sum::<Option<f64>>();
+        trade.lots = matches;
+    }
+}
+
+fn lot_match(
+    lot: Option<&Lot>,
+    amount: u64,
+    proceeds_per_token_sol: f64,
+    sell_sol_price_usd: Option<f64>,
+) -> LotMatch {
+    let cost_basis_sol = lot.map_or(0.0, |lot| lot.cost_per_token_sol * amount as f64);
+    let proceeds_sol = proceeds_per_token_sol * amount as f64;
+    let cost_basis_usd = match lot {
+        Some(lot) => lot.sol_price_usd.map(|price| cost_basis_sol * price),
+        None => Some(0.0),
+    };
+    let proceeds_usd = sell_sol_price_usd.map(|price| proceeds_sol * price);
+
+    LotMatch {
+        lot_signature: lot.map(|lot| lot.signature.clone()),
+        lot_timestamp: lot.map(|lot| lot.timestamp),
+        token_amount: amount,
+        cost_basis_sol,
+        proceeds_sol,
+        realized_gain_sol: proceeds_sol - cost_basis_sol,
+        cost_basis_usd,
+        proceeds_usd,
+        realized_gain_usd: proceeds_usd
+            .zip(cost_basis_usd)
+            .map(|(proceeds, cost)| proceeds - cost),
+    }
+}
+
+fn compute_totals(trades: &[TradeRecord]) -> TradeExportTotals {
+    let mut totals = TradeExportTotals {
+        realized_gain_usd: Some(0.0),
+        ..Default::default()
+    };
+
+    for trade in trades {
+        totals.fees_sol += trade.total_fees_sol();
+        totals.mev_tips_sol += trade.mev_tip_sol;
+        if trade.kind.is_sell() {
+            totals.sells += 1;
+            totals.sol_received += trade.sol_amount;
+            totals.realized_gain_sol += trade.realized_gain_sol.unwrap_or(0.0);
+            totals.realized_gain_usd = totals
+                .realized_gain_usd
+                .zip(trade.realized_gain_usd)
+                .map(|(total, gain)| total + gain);
+        } else {
+            totals.buys += 1;
+            totals.sol_spent += trade.sol_amount;
+        }
+    }
+
+    totals
+}
+
+/// One CSV row per trade
+pub fn trades_to_csv(export: &TradeExport) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for trade in &export.trades {
+        writer
+            .serialize(TradeCsvRow {
+                timestamp: trade.timestamp.to_rfc3339(),
+                position_id: trade.position_id,
+                mint: &trade.mint,
+                symbol: &trade.symbol,
+                kind: trade.kind.as_str(),
+                signature: &trade.signature,
+                token_amount: trade.token_amount,
+                sol_amount: trade.sol_amount,
+                price_sol: trade.price_sol,
+                sol_price_usd: trade.sol_price_usd,
+                usd_value: trade.usd_value,
+                network_fee_sol: trade.network_fee_sol,
+                priority_fee_sol: trade.priority_fee_sol,
+                mev_tip_sol: trade.mev_tip_sol,
+                cost_basis_sol: trade.cost_basis_sol,
+                realized_gain_sol: trade.realized_gain_sol,
+                realized_gain_usd: trade.realized_gain_usd,
+            })
+            .map_err(|e| format!("Failed to write trade row: {}", e))?;
+    }
+    csv_writer_into_string(writer)
+}
+
+/// One CSV row per (sell, lot) FIFO match
+pub fn lots_to_csv(export: &TradeExport) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for trade in export.trades.iter().filter(|t| t.kind.is_sell()) {
+        for lot in &trade.lots {
+            writer
+                .serialize(LotCsvRow {
+                    sell_timestamp: trade.timestamp.to_rfc3339(),
+                    sell_signature: &trade.signature,
+                    mint: &trade.mint,
+                    symbol: &trade.symbol,
+                    lot_timestamp: lot.lot_timestamp.map(|ts| ts.to_rfc3339()),
+                    lot_signature: lot.lot_signature.as_deref(),
+                    token_amount: lot.token_amount,
+                    cost_basis_sol: lot.cost_basis_sol,
+                    proceeds_sol: lot.proceeds_sol,
+                    realized_gain_sol: lot.realized_gain_sol,
+                    cost_basis_usd: lot.cost_basis_usd,
+                    proceeds_usd: lot.proceeds_usd,
+                    realized_gain_usd: lot.realized_gain_usd,
+                })
+                .map_err(|e| format!("Failed to write lot row: {}", e))?;
+        }
+    }
+    csv_writer_into_string(writer)
+}
+
+fn csv_writer_into_string(writer: csv::Writer<Vec<u8>>) -> Result<String, String> {
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("Failed to finish CSV: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("CSV is not valid UTF-8: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn trade(minute: i64, kind: TradeKind, amount: u64, sol: f64) -> TradeRecord {
+        TradeRecord {
+            timestamp: Utc.timestamp_opt(minute * 60, 0).unwrap(),
+            position_id: 1,
+            mint: "mint".to_string(),
+            symbol: "TKN".to_string(),
+            kind,
+            signature: format!("sig{}", minute),
+            token_amount: amount,
+            sol_amount: sol,
+            price_sol: sol / amount.max(1) as f64,
+            sol_price_usd: Some(100.0),
+            usd_value: Some(sol * 100.0),
+            network_fee_sol: 0.0,
+            priority_fee_sol: 0.0,
+            mev_tip_sol: 0.0,
+            fee_source: FeeSource::Recorded,
+            cost_basis_sol: None,
+            realized_gain_sol: None,
+            realized_gain_usd: None,
+            lots: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn fifo_consumes_oldest_lots_first() {
+        let mut trades = vec![
+            trade(1, TradeKind::Buy, 100, 1.0),
+            trade(2, TradeKind::Dca, 100, 2.0),
+            trade(3, TradeKind::PartialExit, 150, 3.0),
+            trade(4, TradeKind::Exit, 50, 1.5),
+        ];
+        trades[0].network_fee_sol = 0.01;
+        trades[3].sol_price_usd = None;
+
+        apply_fifo(&mut trades);
+
+        // 100 from the first lot (1.01 incl. fee) + 50 from the DCA lot (1.0)
+        let partial = &trades[2];
+        assert_eq!(partial.lots.len(), 2);
+        assert_eq!(partial.lots[0].lot_signature.as_deref(), Some("sig1"));
+        assert_eq!(partial.lots[1].token_amount, 50);
+        assert!((partial.cost_basis_sol.unwrap() - 2.01).abs() < 1e-9);
+        assert!((partial.realized_gain_sol.unwrap() - 0.99).abs() < 1e-9);
+        assert!((partial.realized_gain_usd.unwrap() - 99.0).abs() < 1e-6);
+
+        // Remaining 50 of the DCA lot; no USD price at the sell
+        let exit = &trades[3];
+        assert_eq!(exit.lots.len(), 1);
+        assert_eq!(exit.lots[0].lot_signature.as_deref(), Some("sig2"));
+        assert!((exit.realized_gain_sol.unwrap() - 0.5).abs() < 1e-9);
+        assert_eq!(exit.realized_gain_usd, None);
+    }
+
+    #[test]
+    fn sol_price_uses_nearest_sample_within_gap() {
+        let samples = vec![(0, 100.0), (600, 110.0)];
+        assert_eq!(sol_price_at(&samples, 200), Some(100.0));
+        assert_eq!(sol_price_at(&samples, 400), Some(110.0));
+        assert_eq!(
+            sol_price_at(&samples, 600 + SOL_PRICE_MAX_GAP_SECS + 1),
+            None
+        );
+        assert_eq!(sol_price_at(&[], 0), None);
+    }
+}
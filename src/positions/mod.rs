@@ -1,10 +1,12 @@
 // Position management module - clean modular design
 pub mod apply;
 pub mod db;
+pub mod export;
 pub mod lib;
 pub mod loss_detection;
 pub mod metrics;
 pub mod operations;
+pub mod paper;
 pub mod price_updater;
 pub mod queue;
 pub mod state;
@@ -26,11 +28,14 @@ pub use operations::{
 pub use state::{
     acquire_position_lock, format_position_slot_error, get_active_frozen_cooldowns,
     get_closed_positions, get_open_mints, get_open_positions, get_open_positions_count,
-    get_position_by_id, get_position_by_mint, init_global_position_semaphore, is_open_position,
-    is_token_in_cooldown, parse_position_slot_error, reconcile_global_position_semaphore,
-    MINT_TO_POSITION_INDEX, POSITIONS, POSITION_SLOT_UNAVAILABLE_ERR, SIG_TO_MINT_INDEX,
+    get_position_by_id, get_position_by_mint, has_partial_exit_pending,
+    init_global_position_semaphore, is_open_position, is_token_in_cooldown,
+    parse_position_slot_error, reconcile_global_position_semaphore, MINT_TO_POSITION_INDEX,
+    POSITIONS, POSITION_SLOT_UNAVAILABLE_ERR, SIG_TO_MINT_INDEX,
 };
 
+pub use paper::{is_paper_signature, is_paper_trading, paper_sol_balance};
+
 pub use tracking::update_position_tracking;
 
 pub use metrics::get_verification_metrics;
@@ -47,13 +52,15 @@ pub use loss_detection::{
 
 // Database and library exports
 pub use db::{
-    delete_position_by_id, force_database_sync, get_closed_positions as get_db_closed_positions,
+    claim_take_profit_tier, delete_position_by_id, force_database_sync,
+    get_closed_positions as get_db_closed_positions,
     get_closed_positions_count_since as get_db_closed_positions_count_since, get_entry_history,
     get_exit_history, get_open_positions as get_db_open_positions, get_period_trading_stats,
     get_position_by_id as get_db_position_by_id, get_position_by_mint as get_db_position_by_mint,
-    get_positions_database, get_recent_closed_positions_for_mint, get_token_snapshot,
-    get_token_snapshots, initialize_positions_database, load_all_positions, save_entry_record,
-    save_exit_record, save_position, save_token_snapshot, update_position,
+    get_positions_database, get_recent_closed_positions_for_mint, get_take_profit_tiers,
+    get_token_snapshot, get_token_snapshots, initialize_positions_database, load_all_positions,
+    release_take_profit_tier, save_entry_record, save_exit_record, save_position,
+    save_token_snapshot, set_take_profit_tier_signature, update_position,
     update_position_price_fields, with_positions_database, with_positions_database_async,
     PeriodTradingStats, PositionState, PositionStateHistory, PositionTracking, PositionsDatabase,
     PositionsDatabaseStats, TokenSnapshot,
@@ -71,4 +78,4 @@ pub use metrics::ProceedsMetricsSnapshot;
 pub use queue::{enqueue_verification, VerificationItem, VerificationKind};
 pub use state::PositionLockGuard;
 pub use transitions::PositionTransition;
-pub use types::{EntryRecord, ExitRecord, Position};
+pub use types::{EntryRecord, EntrySizing, ExitRecord, Position, TakeProfitTierRecord};
@@ -1,4 +1,5 @@
 use super::db as positions_db;
+use super::paper::{is_paper_signature, is_paper_trading, simulate_swap};
 use super::PENDING_VERIFICATION_SUFFIX;
 use super::{
     apply::apply_transition,
@@ -13,7 +14,7 @@ use super::{
 };
 use super::{
     db::{save_position, update_position_price_fields},
-    types::Position,
+    types::{EntrySizing, Position},
 };
 use crate::{
     config::with_config,
@@ -26,7 +27,11 @@ use crate::{
         execute_swap_with_fallback, get_best_quote, get_best_quote_for_opening, QuoteRequest,
         SwapMode,
     },
-    utils::{get_token_balance, get_total_token_balance, get_wallet_address, sol_to_lamports},
+    trader::TradePriority,
+    utils::{
+        get_token_balance, get_total_token_balance, get_wallet_address, lamports_to_sol,
+        sol_to_lamports,
+    },
 };
 use chrono::Utc;
 use serde_json::json;
@@ -321,7 +326,12 @@ async fn persist_position_with_retry(position: &Position) -> i64 {
 }
 
 /// Internal helper to open a new position with an explicit SOL size
-async fn open_position_impl(token_mint: &str, trade_size_sol: f64) -> Result<String, String> {
+async fn open_position_impl(
+    token_mint: &str,
+    trade_size_sol: f64,
+    priority: TradePriority,
+    sizing: Option<EntrySizing>,
+) -> Result<String, String> {
     let api_token = crate::tokens::get_full_token_async(token_mint)
         .await
         .map_err(|e| format!("Failed to get token: {}", e))?
@@ -491,17 +501,22 @@ async fn open_position_impl(token_mint: &str, trade_size_sol: f64) -> Result<Str
         wallet_address: wallet_address.clone(),
         slippage_pct: slippage_quote_default,
         swap_mode: SwapMode::ExactIn,
+        priority,
     };
 
-    let quote = get_best_quote_for_opening(quote_request, &api_token.symbol)
-        .await
-        .map_err(|e| format!("Quote failed: {}", e))?;
+    let swap_result = if is_paper_trading() {
+        simulate_swap(&api_token, quote_request).await?
+    } else {
+        let quote = get_best_quote_for_opening(quote_request, &api_token.symbol)
+            .await
+            .map_err(|e| format!("Quote failed: {}", e))?;
 
-    let swap_result = execute_swap_with_fallback(&api_token, quote)
-        .await
-        .map_err(|e| format!("Swap failed: {}", e))?;
+        execute_swap_with_fallback(&api_token, quote)
+            .await
+            .map_err(|e| format!("Swap failed: {}", e))?
+    };
 
-    let transaction_signature = swap_result.transaction_signature;
+    let transaction_signature = swap_result.transaction_signature.clone();
 
     // Create position
     let position = Position {
@@ -551,6 +566,8 @@ async fn open_position_impl(token_mint: &str, trade_size_sol: f64) -> Result<Str
         dca_count: 0,
         average_entry_price: entry_price, // Initial entry price
         last_dca_time: None,
+        sizing_mode: sizing.as_ref().map(|s| s.mode.clone()),
+        sizing_reason: sizing.map(|s| s.reason),
     };
 
     // Save to database (with retry) and get ID
@@ -595,23 +612,35 @@ async fn open_position_impl(token_mint: &str, trade_size_sol: f64) -> Result<Str
     )
     .await;
 
-    // Get block height for expiration
-    let expiry_height = get_rpc_client()
-        .get_block_height()
-        .await
-        .map(|h| h + SOLANA_BLOCKHASH_VALIDITY_SLOTS)
-        .ok();
-
-    // Enqueue for verification
-    let verification_item = VerificationItem::new(
-        transaction_signature.clone(),
-        api_token.mint.clone(),
-        Some(position_id),
-        VerificationKind::Entry,
-        expiry_height,
-    );
+    if is_paper_signature(&transaction_signature) {
+        // Paper fills are final - apply the verified entry immediately
+        apply_transition(PositionTransition::EntryVerified {
+            position_id,
+            effective_entry_price: swap_result.effective_price_sol.unwrap_or(entry_price),
+            token_amount_units: swap_result.output_amount,
+            fee_lamports: swap_result.fee_lamports,
+            sol_size: trade_size_sol,
+        })
+        .await?;
+    } else {
+        // Get block height for expiration
+        let expiry_height = get_rpc_client()
+            .get_block_height()
+            .await
+            .map(|h| h + SOLANA_BLOCKHASH_VALIDITY_SLOTS)
+            .ok();
+
+        // Enqueue for verification
+        let verification_item = VerificationItem::new(
+            transaction_signature.clone(),
+            api_token.mint.clone(),
+            Some(position_id),
+            VerificationKind::Entry,
+            expiry_height,
+        );
 
-    enqueue_verification(verification_item).await;
+        enqueue_verification(verification_item).await;
+    }
 
     // We successfully created the position; clear pending-open now
     super::state::clear_pending_open(&api_token.mint).await;
@@ -644,24 +673,29 @@ async fn open_position_impl(token_mint: &str, trade_size_sol: f64) -> Result<Str
 /// Open a new position using trade size from configuration
 pub async fn open_position_direct(token_mint: &str) -> Result<String, String> {
     let trade_size_sol = with_config(|cfg| cfg.trader.trade_size_sol);
-    open_position_impl(token_mint, trade_size_sol).await
+    open_position_impl(token_mint, trade_size_sol, TradePriority::Normal, None).await
 }
 
-/// Open a new position with an explicit SOL size (used by manual buys)
+/// Open a new position with an explicit SOL size
+///
+/// `sizing` records how the size was chosen and is stored on the position.
 pub async fn open_position_with_size(
     token_mint: &str,
     trade_size_sol: f64,
+    priority: TradePriority,
+    sizing: Option<EntrySizing>,
 ) -> Result<String, String> {
     if !trade_size_sol.is_finite() || trade_size_sol <= 0.0 {
         return Err(format!("Invalid trade size: {}", trade_size_sol));
     }
-    open_position_impl(token_mint, trade_size_sol).await
+    open_position_impl(token_mint, trade_size_sol, priority, sizing).await
 }
 
 /// Close an existing position
 pub async fn close_position_direct(
     token_mint: &str,
     exit_reason: String,
+    priority: TradePriority,
 ) -> Result<String, String> {
     let api_token = crate::tokens::get_full_token_async(token_mint)
         .await
@@ -746,39 +780,50 @@ pub async fn close_position_direct(
     let wallet_address =
         get_wallet_address().map_err(|e| format!("Failed to get wallet address: {}", e))?;
 
-    let total_token_balance = get_total_token_balance(&wallet_address, token_mint)
-        .await
-        .map_err(|e| format!("Failed to get total token balance: {}", e))?;
-
-    // Fetch primary (associated) token account balance separately. This is the balance most
-    // swap routes will actually spend from. When multiple token accounts exist, passing the
-    // aggregated total to a router that only sources a single ATA causes an "insufficient funds"
-    // simulation failure (observed in logs). We therefore cap the sell amount to the primary
-    // balance when it is lower than the aggregate, and log the discrepancy.
-    let primary_token_balance = get_token_balance(&wallet_address, token_mint)
-        .await
-        .unwrap_or(0);
-
-    let (sell_amount, multi_account_note) = if primary_token_balance == 0 && total_token_balance > 0
-    {
-        // We have tokens but not in the primary ATA (likely split or token-2022 alt). Use total but
-        // expect potential router failure; still attempt but log.
-        (
-            total_token_balance,
-            Some("primary_ata_empty_using_total".to_string()),
-        )
-    } else if total_token_balance > primary_token_balance && primary_token_balance > 0 {
-        (
-            primary_token_balance,
-            Some(format!(
-                "multi_account_total={} primary={} shortfall={}, limiting_to_primary",
-                total_token_balance,
-                primary_token_balance,
-                total_token_balance - primary_token_balance
-            )),
-        )
+    // Paper positions hold no real tokens - sell the tracked remaining amount instead
+    let (sell_amount, total_token_balance, multi_account_note) = if is_paper_trading() {
+        let tracked_amount = super::state::get_position_by_mint(token_mint)
+            .await
+            .and_then(|p| p.remaining_token_amount.or(p.token_amount))
+            .unwrap_or(0);
+        (tracked_amount, tracked_amount, None)
     } else {
-        (total_token_balance, None)
+        let total_token_balance = get_total_token_balance(&wallet_address, token_mint)
+            .await
+            .map_err(|e| format!("Failed to get total token balance: {}", e))?;
+
+        // Fetch primary (associated) token account balance separately. This is the balance most
+        // swap routes will actually spend from. When multiple token accounts exist, passing the
+        // aggregated total to a router that only sources a single ATA causes an "insufficient funds"
+        // simulation failure (observed in logs). We therefore cap the sell amount to the primary
+        // balance when it is lower than the aggregate, and log the discrepancy.
+        let primary_token_balance = get_token_balance(&wallet_address, token_mint)
+            .await
+            .unwrap_or(0);
+
+        let (sell_amount, multi_account_note) =
+            if primary_token_balance == 0 && total_token_balance > 0 {
+                // We have tokens but not in the primary ATA (likely split or token-2022 alt). Use total but
+                // expect potential router failure; still attempt but log.
+                (
+                    total_token_balance,
+                    Some("primary_ata_empty_using_total".to_string()),
+                )
+            } else if total_token_balance > primary_token_balance && primary_token_balance > 0 {
+                (
+                    primary_token_balance,
+                    Some(format!(
+                        "multi_account_total={} primary={} shortfall={}, limiting_to_primary",
+                        total_token_balance,
+                        primary_token_balance,
+                        total_token_balance - primary_token_balance
+                    )),
+                )
+            } else {
+                (total_token_balance, None)
+            };
+
+        (sell_amount, total_token_balance, multi_account_note)
     };
 
     if sell_amount == 0 {
@@ -818,8 +863,23 @@ pub async fn close_position_direct(
             wallet_address: wallet_address.clone(),
             slippage_pct: *slippage,
             swap_mode: SwapMode::ExactIn,
+            priority: priority.clone(),
         };
 
+        if is_paper_trading() {
+            match simulate_swap(&api_token, quote_request).await {
+                Ok(res) => {
+                    swap_result = Some(res);
+                    last_err = None;
+                    break;
+                }
+                Err(e) => {
+                    last_err = Some(format!("Paper fill failed at step {}: {}", i + 1, e));
+                    continue;
+                }
+            }
+        }
+
         let quote = match get_best_quote(quote_request).await {
             Ok(q) => q,
             Err(e) => {
@@ -920,23 +980,35 @@ pub async fn close_position_direct(
     )
     .await;
 
-    // Get block height for expiration
-    let expiry_height = get_rpc_client()
-        .get_block_height()
-        .await
-        .map(|h| h + SOLANA_BLOCKHASH_VALIDITY_SLOTS)
-        .ok();
-
-    // Enqueue for verification
-    let verification_item = VerificationItem::new(
-        transaction_signature.clone(),
-        token_mint.to_string(),
-        Some(position_id),
-        VerificationKind::Exit,
-        expiry_height,
-    );
+    if is_paper_signature(&transaction_signature) {
+        // Paper fills are final - apply the verified exit immediately
+        apply_transition(PositionTransition::ExitVerified {
+            position_id,
+            effective_exit_price: swap_result.effective_price_sol.unwrap_or(exit_price),
+            sol_received: lamports_to_sol(swap_result.output_amount),
+            fee_lamports: swap_result.fee_lamports,
+            exit_time: Utc::now(),
+        })
+        .await?;
+    } else {
+        // Get block height for expiration
+        let expiry_height = get_rpc_client()
+            .get_block_height()
+            .await
+            .map(|h| h + SOLANA_BLOCKHASH_VALIDITY_SLOTS)
+            .ok();
 
-    enqueue_verification(verification_item).await;
+        // Enqueue for verification
+        let verification_item = VerificationItem::new(
+            transaction_signature.clone(),
+            token_mint.to_string(),
+            Some(position_id),
+            VerificationKind::Exit,
+            expiry_height,
+        );
+
+        enqueue_verification(verification_item).await;
+    }
 
     logger::info(
         LogTag::Positions,
@@ -955,10 +1027,15 @@ pub async fn close_position_direct(
 
 /// Partially close a position by selling a percentage of remaining tokens
 /// CRITICAL: This does NOT release the semaphore permit - position stays open
+///
+/// `take_profit_trigger` names the claimed take-profit tier this exit sells; its
+/// signature is attached before the exit is verified.
 pub async fn partial_close_position(
     token_mint: &str,
     exit_percentage: f64,
     exit_reason: &str,
+    priority: TradePriority,
+    take_profit_trigger: Option<f64>,
 ) -> Result<String, String> {
     // Serialize per-mint operations to avoid overlapping partials/full exits
     let _lock = acquire_position_lock(token_mint).await;
@@ -1026,59 +1103,85 @@ pub async fn partial_close_position(
         .map_err(|e| format!("Failed to get token: {}", e))?
         .ok_or_else(|| format!("Token not found: {}", token_mint))?;
 
-    // Get quote for partial exit
     let wallet_address = get_wallet_address().map_err(|e| e.to_string())?;
     let slippage_exit_retry_steps =
         with_config(|cfg| cfg.swaps.slippage.exit_retry_steps_pct.clone());
-    // Slippage retry loop for partial exit
     let mut last_err: Option<String> = None;
-    let mut quote_opt = None;
-    for (i, slippage) in slippage_exit_retry_steps.iter().enumerate() {
-        let quote_request = QuoteRequest {
+
+    let mut swap_result = if is_paper_trading() {
+        // Paper fills quote on their own (with a pool price fallback), so no live
+        // quote is required first
+        let slippage_pct = slippage_exit_retry_steps
+            .first()
+            .copied()
+            .unwrap_or_else(|| with_config(|cfg| cfg.swaps.slippage.quote_default_pct));
+        let paper_request = QuoteRequest {
             input_mint: token_mint.to_string(),
             output_mint: SOL_MINT.to_string(),
             input_amount: exit_amount,
             wallet_address: wallet_address.clone(),
-            slippage_pct: *slippage,
+            slippage_pct,
             swap_mode: SwapMode::ExactIn,
+            priority: priority.clone(),
         };
-        match get_best_quote(quote_request).await {
-            Ok(q) => {
-                quote_opt = Some(q);
-                last_err = None;
-                break;
-            }
-            Err(e) => {
-                last_err = Some(format!(
-                    "Quote failed at step {} ({}%): {}",
-                    i + 1,
-                    slippage,
-                    e
-                ));
-                continue;
+
+        // Mark pending partial BEFORE executing swap to serialize concurrent attempts
+        super::state::mark_partial_exit_pending(token_mint).await;
+        simulate_swap(&api_token, paper_request).await
+    } else {
+        // Slippage retry loop for partial exit quote
+        let mut quote_opt = None;
+        for (i, slippage) in slippage_exit_retry_steps.iter().enumerate() {
+            let quote_request = QuoteRequest {
+                input_mint: token_mint.to_string(),
+                output_mint: SOL_MINT.to_string(),
+                input_amount: exit_amount,
+                wallet_address: wallet_address.clone(),
+                slippage_pct: *slippage,
+                swap_mode: SwapMode::ExactIn,
+                priority: priority.clone(),
+            };
+            match get_best_quote(quote_request).await {
+                Ok(q) => {
+                    quote_opt = Some(q);
+                    last_err = None;
+                    break;
+                }
+                Err(e) => {
+                    last_err = Some(format!(
+                        "Quote failed at step {} ({}%): {}",
+                        i + 1,
+                        slippage,
+                        e
+                    ));
+                    continue;
+                }
             }
         }
-    }
-    let quote = quote_opt
-        .ok_or_else(|| last_err.unwrap_or_else(|| "Failed to get exit quote".to_string()))?;
+        let quote = quote_opt.ok_or_else(|| {
+            last_err
+                .clone()
+                .unwrap_or_else(|| "Failed to get exit quote".to_string())
+        })?;
 
-    logger::info(
-        LogTag::Positions,
-        &format!(
-            "Partial exit quote: {} tokens → {} SOL",
-            exit_amount,
-            quote.output_amount as f64 / 1_000_000_000.0
-        ),
-    );
+        logger::info(
+            LogTag::Positions,
+            &format!(
+                "Partial exit quote: {} tokens → {} SOL",
+                exit_amount,
+                quote.output_amount as f64 / 1_000_000_000.0
+            ),
+        );
 
-    // Mark pending partial BEFORE executing swap to serialize concurrent attempts
-    super::state::mark_partial_exit_pending(token_mint).await;
+        // Mark pending partial BEFORE executing swap to serialize concurrent attempts
+        super::state::mark_partial_exit_pending(token_mint).await;
 
-    // Execute swap with retry on different slippage levels
-    let mut swap_result = execute_swap_with_fallback(&api_token, quote)
-        .await
-        .map_err(|e| e.to_string());
-    if swap_result.is_err() {
+        // Execute swap with retry on different slippage levels
+        execute_swap_with_fallback(&api_token, quote)
+            .await
+            .map_err(|e| e.to_string())
+    };
+    if swap_result.is_err() && !is_paper_trading() {
         for (i, slippage) in slippage_exit_retry_steps.iter().enumerate() {
             let quote_request = QuoteRequest {
                 input_mint: token_mint.to_string(),
@@ -1087,6 +1190,7 @@ pub async fn partial_close_position(
                 wallet_address: wallet_address.clone(),
                 slippage_pct: *slippage,
                 swap_mode: SwapMode::ExactIn,
+                priority: priority.clone(),
             };
             let q = match get_best_quote(quote_request).await {
                 Ok(q) => q,
@@ -1142,6 +1246,25 @@ pub async fn partial_close_position(
 
     let transaction_signature = swap_result.transaction_signature.clone();
 
+    // Tie the ladder tier to this exit before verification can report it
+    if let Some(trigger_pct) = take_profit_trigger {
+        if let Err(e) = super::db::set_take_profit_tier_signature(
+            position_id,
+            trigger_pct,
+            &transaction_signature,
+        )
+        .await
+        {
+            logger::error(
+                LogTag::Positions,
+                &format!(
+                    "Failed to attach exit {} to take-profit tier +{}%: {}",
+                    transaction_signature, trigger_pct, e
+                ),
+            );
+        }
+    }
+
     let expiry_height =
         get_rpc_client().get_block_height().await.unwrap_or(0) + SOLANA_BLOCKHASH_VALIDITY_SLOTS;
 
@@ -1209,17 +1332,34 @@ pub async fn partial_close_position(
         return Err(format!("Failed to apply partial exit transition: {}", e));
     }
 
-    // Enqueue for verification with partial exit flag
-    let verification_item = VerificationItem::new_partial_exit(
-        transaction_signature.clone(),
-        token_mint.to_string(),
-        Some(position_id),
-        exit_amount,
-        exit_percentage,
-        Some(expiry_height),
-    );
+    if is_paper_signature(&transaction_signature) {
+        // Paper fills are final - apply the verified partial exit immediately
+        apply_transition(PositionTransition::PartialExitVerified {
+            position_id,
+            exit_amount: swap_result.input_amount,
+            sol_received: lamports_to_sol(swap_result.output_amount),
+            effective_exit_price: swap_result
+                .effective_price_sol
+                .unwrap_or(position.current_price.unwrap_or(position.entry_price)),
+            fee_lamports: swap_result.fee_lamports,
+            exit_time: Utc::now(),
+            exit_signature: transaction_signature.clone(),
+            exit_percentage,
+        })
+        .await?;
+    } else {
+        // Enqueue for verification with partial exit flag
+        let verification_item = VerificationItem::new_partial_exit(
+            transaction_signature.clone(),
+            token_mint.to_string(),
+            Some(position_id),
+            exit_amount,
+            exit_percentage,
+            Some(expiry_height),
+        );
 
-    enqueue_verification(verification_item).await;
+        enqueue_verification(verification_item).await;
+    }
 
     logger::info(
         LogTag::Positions,
@@ -1236,7 +1376,11 @@ pub async fn partial_close_position(
 
 /// Add to an existing position (Dollar Cost Averaging)
 /// CRITICAL: This does NOT consume a new semaphore permit - same position
-pub async fn add_to_position(token_mint: &str, dca_amount_sol: f64) -> Result<String, String> {
+pub async fn add_to_position(
+    token_mint: &str,
+    dca_amount_sol: f64,
+    priority: TradePriority,
+) -> Result<String, String> {
     // Serialize per-mint DCA operations
     let _lock = acquire_position_lock(token_mint).await;
     // Get position
@@ -1314,24 +1458,31 @@ pub async fn add_to_position(token_mint: &str, dca_amount_sol: f64) -> Result<St
         wallet_address: wallet_address.clone(),
         slippage_pct: slippage,
         swap_mode: SwapMode::ExactIn,
+        priority,
     };
-    let quote = get_best_quote_for_opening(quote_request, &api_token.symbol)
-        .await
-        .map_err(|e| format!("Failed to get DCA quote: {}", e))?;
+    let swap_result = if is_paper_trading() {
+        simulate_swap(&api_token, quote_request)
+            .await
+            .map_err(|e| format!("DCA paper fill failed: {}", e))?
+    } else {
+        let quote = get_best_quote_for_opening(quote_request, &api_token.symbol)
+            .await
+            .map_err(|e| format!("Failed to get DCA quote: {}", e))?;
 
-    logger::info(
-        LogTag::Positions,
-        &format!(
-            "DCA quote: {} SOL → {} tokens",
-            dca_amount_sol,
-            quote.output_amount as f64 / 10_f64.powi(api_token.decimals as i32)
-        ),
-    );
+        logger::info(
+            LogTag::Positions,
+            &format!(
+                "DCA quote: {} SOL → {} tokens",
+                dca_amount_sol,
+                quote.output_amount as f64 / 10_f64.powi(api_token.decimals as i32)
+            ),
+        );
 
-    // Execute swap
-    let swap_result = execute_swap_with_fallback(&api_token, quote)
-        .await
-        .map_err(|e| format!("DCA swap failed: {}", e))?;
+        // Execute swap
+        execute_swap_with_fallback(&api_token, quote)
+            .await
+            .map_err(|e| format!("DCA swap failed: {}", e))?
+    };
 
     let transaction_signature = swap_result.transaction_signature.clone();
 
@@ -1400,15 +1551,31 @@ pub async fn add_to_position(token_mint: &str, dca_amount_sol: f64) -> Result<St
         return Err(format!("Failed to apply DCA transition: {}", e));
     }
 
-    // Enqueue for verification
-    let verification_item = VerificationItem::new_dca(
-        transaction_signature.clone(),
-        token_mint.to_string(),
-        Some(position_id),
-        expiry_height,
-    );
+    if is_paper_signature(&transaction_signature) {
+        // Paper fills are final - apply the verified DCA immediately
+        apply_transition(PositionTransition::DcaVerified {
+            position_id,
+            tokens_bought: swap_result.output_amount,
+            sol_spent: dca_amount_sol,
+            effective_price: swap_result
+                .effective_price_sol
+                .unwrap_or(price_info.price_sol),
+            fee_lamports: swap_result.fee_lamports,
+            dca_time: Utc::now(),
+            dca_signature: transaction_signature.clone(),
+        })
+        .await?;
+    } else {
+        // Enqueue for verification
+        let verification_item = VerificationItem::new_dca(
+            transaction_signature.clone(),
+            token_mint.to_string(),
+            Some(position_id),
+            expiry_height,
+        );
 
-    enqueue_verification(verification_item).await;
+        enqueue_verification(verification_item).await;
+    }
 
     logger::info(
         LogTag::Positions,
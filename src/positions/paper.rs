@@ -0,0 +1,237 @@
+//! Paper trading fills
+//!
+//! When paper mode is active no swap is ever submitted. Each swap is filled against
+//! the live router quote (falling back to the pool/API price with configured slippage)
+//! and the resulting verified transition is applied immediately, so positions, PnL,
+//! notifications and the dashboard run through the normal pipeline.
+
+use super::lib::calculate_position_total_fees;
+use super::operations::get_price_with_api_fallback;
+use crate::{
+    config::with_config,
+    constants::SOL_MINT,
+    logger::{self, LogTag},
+    swaps::{get_best_quote, QuoteRequest, SwapResult},
+    tokens::Token,
+    utils::{lamports_to_sol, sol_to_lamports},
+};
+
+/// Prefix for simulated transaction signatures
+pub const PAPER_SIGNATURE_PREFIX: &str = "paper_";
+
+/// Router ID reported on simulated swap results
+pub const PAPER_ROUTER_ID: &str = "paper";
+
+/// Base network fee charged on every simulated swap (matches a typical signed transaction)
+const PAPER_BASE_FEE_LAMPORTS: u64 = 5_000;
+
+/// Check if paper trading is active for this process
+pub fn is_paper_trading() -> bool {
+    crate::global::is_paper_trading()
+}
+
+/// Check if a signature belongs to a simulated (paper) swap
+pub fn is_paper_signature(signature: &str) -> bool {
+    signature.starts_with(PAPER_SIGNATURE_PREFIX)
+}
+
+fn paper_signature(side: &str) -> String {
+    format!(
+        "{}{}_{}",
+        PAPER_SIGNATURE_PREFIX,
+        side,
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// Simulated SOL balance of the paper account
+///
+/// Starts from `trader.paper_starting_balance_sol` and nets the SOL spent, SOL received
+/// and fees of every paper position, open or closed.
+pub async fn paper_sol_balance() -> Result<f64, String> {
+    let starting_balance = with_config(|cfg| cfg.trader.paper_starting_balance_sol);
+    let mut positions = super::db::get_open_positions().await?;
+    positions.extend(super::db::get_closed_positions().await?);
+
+    let net_flow: f64 = positions
+        .iter()
+        .map(|p| {
+            p.sol_received.unwrap_or(0.0) - p.total_size_sol - calculate_position_total_fees(p)
+        })
+        .sum();
+
+    Ok(starting_balance + net_flow)
+}
+
+/// Simulate a swap for the given quote request
+///
+/// Uses the best live quote when a router can provide one; otherwise fills at the
+/// current pool/API price minus the requested slippage. The returned result has a
+/// `paper_` signature and `effective_price_sol` set, so callers can apply the
+/// verified transition directly instead of queueing on-chain verification.
+pub async fn simulate_swap(token: &Token, request: QuoteRequest) -> Result<SwapResult, String> {
+    let is_buy = request.input_mint == SOL_MINT;
+    let side = if is_buy { "buy" } else { "sell" };
+
+    let (input_amount, output_amount, fee_lamports, source) =
+        match get_best_quote(request.clone()).await {
+            Ok(quote) if quote.output_amount > 0 => (
+                quote.input_amount,
+                quote.output_amount,
+                paper_fee_lamports(quote.fee_lamports),
+                format!("quote:{}", quote.router_id),
+            ),
+            quote_result => {
+                if let Err(e) = quote_result {
+                    logger::debug(
+                        LogTag::Positions,
+                        &format!(
+                            "Paper fill for {}: no live quote ({}), using market price",
+                            token.symbol, e
+                        ),
+                    );
+                }
+
+                let (price_info, price_source) = get_price_with_api_fallback(&token.mint)
+                    .await
+                    .ok_or_else(|| format!("No price data for paper fill: {}", token.mint))?;
+                let price = price_info.price_sol;
+                if price <= 0.0 || !price.is_finite() {
+                    return Err(format!("Invalid price for paper fill: {}", token.mint));
+                }
+
+                let output_amount = market_fill_output(
+                    is_buy,
+                    request.input_amount,
+                    price,
+                    token.decimals,
+                    request.slippage_pct,
+                );
+
+                (
+                    request.input_amount,
+                    output_amount,
+                    paper_fee_lamports(0),
+                    format!("price:{:?}", price_source),
+                )
+            }
+        };
+
+    let effective_price_sol = paper_fill_price(is_buy, input_amount, output_amount, token.decimals)
+        .ok_or_else(|| format!("Paper fill for {} produced zero output", token.symbol))?;
+
+    let signature = paper_signature(side);
+
+    logger::info(
+        LogTag::Positions,
+        &format!(
+            "PAPER {} {}: in={} out={} fee={} lamports (source: {}) | {}",
+            side.to_uppercase(),
+            token.symbol,
+            input_amount,
+            output_amount,
+            fee_lamports,
+            source,
+            signature
+        ),
+    );
+
+    Ok(SwapResult {
+        success: true,
+        router_id: PAPER_ROUTER_ID.to_string(),
+        router_name: "Paper".to_string(),
+        transaction_signature: signature,
+        input_amount,
+        output_amount,
+        price_impact_pct: 0.0,
+        fee_lamports,
+        execution_time_ms: 0,
+        effective_price_sol: Some(effective_price_sol),
+    })
+}
+
+/// Network fee charged for a simulated swap on top of the router fee
+fn paper_fee_lamports(router_fee_lamports: u64) -> u64 {
+    PAPER_BASE_FEE_LAMPORTS + router_fee_lamports
+}
+
+/// Output amount (raw units) of a fill at `price_sol` per whole token minus slippage
+fn market_fill_output(
+    is_buy: bool,
+    input_amount: u64,
+    price_sol: f64,
+    decimals: u8,
+    slippage_pct: f64,
+) -> u64 {
+    let scale = 10_f64.powi(decimals as i32);
+    let slippage = (1.0 - slippage_pct / 100.0).max(0.0);
+    if is_buy {
+        (lamports_to_sol(input_amount) / price_sol * scale * slippage) as u64
+    } else {
+        sol_to_lamports(input_amount as f64 / scale * price_sol * slippage)
+    }
+}
+
+/// Effective price in SOL per whole token, or None when the fill has no output
+fn paper_fill_price(
+    is_buy: bool,
+    input_amount: u64,
+    output_amount: u64,
+    decimals: u8,
+) -> Option<f64> {
+    let (sol_lamports, token_units) = if is_buy {
+        (input_amount, output_amount)
+    } else {
+        (output_amount, input_amount)
+    };
+    if sol_lamports == 0 || token_units == 0 {
+        return None;
+    }
+
+    let tokens = token_units as f64 / 10_f64.powi(decimals as i32);
+    Some(lamports_to_sol(sol_lamports) / tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_market_fill_output() {
+        // 1 SOL at 0.25 SOL/token with 6 decimals and 50% slippage -> 2 tokens
+        let out = market_fill_output(true, 1_000_000_000, 0.25, 6, 50.0);
+        assert_eq!(out, 2_000_000);
+
+        // Selling 4 tokens at 0.25 SOL/token with 50% slippage -> 0.5 SOL
+        let out = market_fill_output(false, 4_000_000, 0.25, 6, 50.0);
+        assert_eq!(out, 500_000_000);
+
+        // Slippage above 100% cannot produce a negative fill
+        assert_eq!(market_fill_output(true, 1_000_000_000, 0.001, 6, 150.0), 0);
+    }
+
+    #[test]
+    fn test_paper_fill_price() {
+        // Bought 500 tokens (6 decimals) for 0.5 SOL -> 0.001 SOL per token
+        let price = paper_fill_price(true, 500_000_000, 500_000_000, 6).unwrap();
+        assert!((price - 0.001).abs() < 1e-12);
+
+        // Sold 2 tokens (9 decimals) for 0.3 SOL -> 0.15 SOL per token
+        let price = paper_fill_price(false, 2_000_000_000, 300_000_000, 9).unwrap();
+        assert!((price - 0.15).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_zero_output_rejected() {
+        assert!(paper_fill_price(true, 1_000_000_000, 0, 6).is_none());
+        assert!(paper_fill_price(false, 1_000_000, 0, 6).is_none());
+        // A price so high that the buy rounds down to nothing
+        assert_eq!(market_fill_output(true, 1, 1_000.0, 0, 0.0), 0);
+    }
+
+    #[test]
+    fn test_paper_fees() {
+        assert_eq!(paper_fee_lamports(0), PAPER_BASE_FEE_LAMPORTS);
+        assert_eq!(paper_fee_lamports(20_000), PAPER_BASE_FEE_LAMPORTS + 20_000);
+    }
+}
@@ -65,6 +65,10 @@ pub struct Position {
     pub dca_count: u32,                       // Number of additional entries (DCA)
     pub average_entry_price: f64,             // Weighted average entry price (all entries)
     pub last_dca_time: Option<DateTime<Utc>>, // Last DCA timestamp for cooldown
+
+    // Position sizing (set once at entry)
+    pub sizing_mode: Option<String>, // Sizing mode that chose entry_size_sol
+    pub sizing_reason: Option<String>, // Why the sizing mode picked that size
 }
 
 // ==================== EXIT & ENTRY HISTORY ====================
@@ -97,3 +101,22 @@ pub struct EntryRecord {
     pub is_dca: bool,               // true if DCA, false if initial entry
     pub fees_lamports: Option<u64>, // Transaction fee
 }
+
+/// How the entry size of a new position was chosen
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EntrySizing {
+    pub mode: String,   // Sizing mode (fixed, wallet_percent, ...)
+    pub reason: String, // Inputs and rationale behind the chosen size
+}
+
+/// Record of a take-profit ladder tier that fired for a position
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TakeProfitTierRecord {
+    pub position_id: i64,
+    pub tier: u32,          // 1-based tier number in the ladder when the tier fired
+    pub trigger_pct: f64,   // Profit % that triggered the tier (identifies the tier)
+    pub sell_pct: f64,      // % of original position sold
+    pub trigger_price: f64, // Price when the tier fired
+    pub transaction_signature: Option<String>, // None while the tier exit is in flight
+    pub triggered_at: DateTime<Utc>,
+}
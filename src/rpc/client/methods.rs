@@ -108,6 +108,39 @@ pub struct TokenSupply {
     pub ui_amount_string: String,
 }
 
+/// Account state returned by simulateTransaction
+#[derive(Debug, Clone)]
+pub struct SimulatedAccount {
+    /// Lamports held after the simulated transaction
+    pub lamports: u64,
+    /// Owner program of the account
+    pub owner: String,
+    /// Raw account data after the simulated transaction
+    pub data: Vec<u8>,
+}
+
+/// Result of simulateTransaction
+#[derive(Debug, Clone)]
+pub struct SimulationResult {
+    /// Transaction error (same shape as `meta.err`), None when the simulation succeeded
+    pub err: Option<serde_json::Value>,
+    /// Program log messages
+    pub logs: Vec<String>,
+    /// Compute units consumed
+    pub units_consumed: Option<u64>,
+    /// Post-simulation state of the requested accounts (same order, None if missing)
+    pub accounts: Vec<Option<SimulatedAccount>>,
+}
+
+/// Prioritization fee paid in a recent slot from getRecentPrioritizationFees
+#[derive(Debug, Clone)]
+pub struct PrioritizationFee {
+    /// Slot in which the fee was observed
+    pub slot: u64,
+    /// Minimum fee paid by a transaction in the slot (micro-lamports per compute unit)
+    pub prioritization_fee: u64,
+}
+
 /// Filter type for getProgramAccounts
 #[derive(Debug, Clone)]
 pub enum RpcFilterType {
@@ -245,6 +278,26 @@ pub trait RpcClientMethods {
         transaction_base64: &str,
     ) -> impl std::future::Future<Output = Result<Signature, String>> + Send;
 
+    /// Simulate a base64-encoded transaction without sending it
+    ///
+    /// Signatures are not verified and the blockhash is replaced, so unsigned
+    /// transactions can be simulated. The post-simulation state of
+    /// `account_addresses` is returned in the same order.
+    fn simulate_transaction(
+        &self,
+        transaction_base64: &str,
+        account_addresses: &[String],
+    ) -> impl std::future::Future<Output = Result<SimulationResult, String>> + Send;
+
+    /// Get prioritization fees paid in recent slots (up to 150)
+    ///
+    /// When `writable_accounts` is not empty, only transactions that lock all of
+    /// the given accounts as writable are considered.
+    fn get_recent_prioritization_fees(
+        &self,
+        writable_accounts: &[String],
+    ) -> impl std::future::Future<Output = Result<Vec<PrioritizationFee>, String>> + Send;
+
     /// Confirm a transaction with timeout
     ///
     /// Polls for transaction confirmation status until confirmed or timeout.
@@ -929,6 +982,118 @@ impl RpcClientMethods for RpcClient {
         Signature::from_str(sig_str).map_err(|e| format!("Invalid signature: {}", e))
     }
 
+    async fn simulate_transaction(
+        &self,
+        transaction_base64: &str,
+        account_addresses: &[String],
+    ) -> Result<SimulationResult, String> {
+        let mut config = serde_json::json!({
+            "encoding": "base64",
+            "commitment": "confirmed",
+            "sigVerify": false,
+            "replaceRecentBlockhash": true
+        });
+        if !account_addresses.is_empty() {
+            config["accounts"] = serde_json::json!({
+                "encoding": "base64",
+                "addresses": account_addresses
+            });
+        }
+
+        let params = serde_json::json!([transaction_base64, config]);
+
+        let result = self
+            .manager
+            .execute_raw("simulateTransaction", params)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let value = result.get("value").ok_or("Missing value field")?;
+
+        let err = value.get("err").filter(|e| !e.is_null()).cloned();
+
+        let logs = value
+            .get("logs")
+            .and_then(|v| v.as_array())
+            .map(|logs| {
+                logs.iter()
+                    .filter_map(|l| l.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let units_consumed = value.get("unitsConsumed").and_then(|v| v.as_u64());
+
+        let accounts = value
+            .get("accounts")
+            .and_then(|v| v.as_array())
+            .map(|accounts| {
+                accounts
+                    .iter()
+                    .map(|account| {
+                        if account.is_null() {
+                            return None;
+                        }
+                        let data = account
+                            .get("data")
+                            .and_then(|d| d.as_array())
+                            .and_then(|d| d.first())
+                            .and_then(|d| d.as_str())
+                            .and_then(|d| base64::engine::general_purpose::STANDARD.decode(d).ok())
+                            .unwrap_or_default();
+                        Some(SimulatedAccount {
+                            lamports: account.get("lamports").and_then(|v| v.as_u64())?,
+                            owner: account
+                                .get("owner")
+                                .and_then(|v| v.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                            data,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(SimulationResult {
+            err,
+            logs,
+            units_consumed,
+            accounts,
+        })
+    }
+
+    async fn get_recent_prioritization_fees(
+        &self,
+        writable_accounts: &[String],
+    ) -> Result<Vec<PrioritizationFee>, String> {
+        let params = if writable_accounts.is_empty() {
+            serde_json::json!([])
+        } else {
+            serde_json::json!([writable_accounts])
+        };
+
+        let result = self
+            .manager
+            .execute_raw("getRecentPrioritizationFees", params)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let entries = result
+            .as_array()
+            .ok_or("Invalid prioritization fees response")?;
+
+        Ok(entries
+            .iter()
+            .filter_map(|entry| {
+                Some(PrioritizationFee {
+                    slot: entry.get("slot")?.as_u64()?,
+                    prioritization_fee: entry.get("prioritizationFee")?.as_u64()?,
+                })
+            })
+            .collect())
+    }
+
     async fn confirm_transaction(
         &self,
         signature: &Signature,
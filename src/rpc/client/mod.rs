@@ -6,6 +6,8 @@
 pub mod methods;
 
 pub use methods::{
+    // Priority fee types
+    PrioritizationFee,
     ProviderHealthInfo,
     RpcClientMethods,
     // Program account types
@@ -14,6 +16,9 @@ pub use methods::{
     RpcTokenAccountBalance,
     // Transaction history types
     SignatureInfo,
+    // Simulation types
+    SimulatedAccount,
+    SimulationResult,
     TokenSupply,
 };
 
@@ -61,6 +61,8 @@ pub use circuit_breaker::{
 // The RpcClient is available as `rpc::client::RpcClient` (not re-exported at top level)
 // Access via get_rpc_client() helper which returns the global RpcClient instance
 pub use client::{
+    // Priority fee types
+    PrioritizationFee,
     ProviderHealthInfo,
     RpcClientMethods,
     // Program account types
@@ -69,6 +71,9 @@ pub use client::{
     RpcTokenAccountBalance,
     // Transaction history types
     SignatureInfo,
+    // Simulation types
+    SimulatedAccount,
+    SimulationResult,
     TokenSupply,
 };
 
@@ -133,7 +138,8 @@ pub use types::{
 // ============================================================================
 
 pub use websocket::{
-    build_logs_subscribe_payload, create_account_subscribe_payload, get_websocket_url,
+    build_logs_subscribe_payload, create_account_subscribe_payload,
+    create_account_subscribe_payload_base64, create_account_unsubscribe_payload, get_websocket_url,
     get_websocket_url_from_http, logs_contains_initialize_account, logs_contains_initialize_mint,
 };
 
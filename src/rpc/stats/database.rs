@@ -7,6 +7,7 @@ use rusqlite::{params, OptionalExtension};
 use std::path::{Path, PathBuf};
 
 use super::types::*;
+use crate::migrations::{Migration, MigrationSet};
 use crate::rpc::types::{CircuitState, ProviderKind};
 
 /// Database path for RPC stats
@@ -14,6 +15,93 @@ pub fn get_rpc_stats_db_path() -> PathBuf {
     crate::paths::get_data_directory().join("rpc_stats.db")
 }
 
+/// Ordered schema migrations of rpc_stats.db
+pub const RPC_STATS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "rpc_stats",
+    migrations: &[Migration::sql(1, "Baseline schema", SCHEMA_BASELINE)],
+};
+
+const SCHEMA_BASELINE: &str = r#"
+-- Sessions table
+CREATE TABLE IF NOT EXISTS sessions (
+    id TEXT PRIMARY KEY,
+    started_at TEXT NOT NULL,
+    ended_at TEXT,
+    total_calls INTEGER DEFAULT 0,
+    total_errors INTEGER DEFAULT 0,
+    is_current INTEGER DEFAULT 0
+);
+CREATE INDEX IF NOT EXISTS idx_sessions_current ON sessions(is_current);
+CREATE INDEX IF NOT EXISTS idx_sessions_started ON sessions(started_at DESC);
+
+-- Providers table
+CREATE TABLE IF NOT EXISTS providers (
+    id TEXT PRIMARY KEY,
+    url_masked TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    priority INTEGER DEFAULT 100,
+    enabled INTEGER DEFAULT 1,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+CREATE INDEX IF NOT EXISTS idx_providers_kind ON providers(kind);
+
+-- RPC calls table (time-series)
+CREATE TABLE IF NOT EXISTS calls (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id TEXT NOT NULL,
+    provider_id TEXT NOT NULL,
+    method TEXT NOT NULL,
+    success INTEGER NOT NULL,
+    latency_ms INTEGER NOT NULL,
+    error_code INTEGER,
+    error_message TEXT,
+    was_retried INTEGER DEFAULT 0,
+    retry_count INTEGER DEFAULT 0,
+    was_rate_limited INTEGER DEFAULT 0,
+    timestamp TEXT NOT NULL,
+    FOREIGN KEY (session_id) REFERENCES sessions(id)
+);
+CREATE INDEX IF NOT EXISTS idx_calls_session_time ON calls(session_id, timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_calls_provider_time ON calls(provider_id, timestamp DESC);
+CREATE INDEX IF NOT EXISTS idx_calls_method ON calls(method);
+CREATE INDEX IF NOT EXISTS idx_calls_timestamp ON calls(timestamp DESC);
+
+-- Minute buckets table (aggregated)
+CREATE TABLE IF NOT EXISTS minute_buckets (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id TEXT NOT NULL,
+    provider_id TEXT,
+    minute_start TEXT NOT NULL,
+    call_count INTEGER DEFAULT 0,
+    success_count INTEGER DEFAULT 0,
+    error_count INTEGER DEFAULT 0,
+    rate_limit_count INTEGER DEFAULT 0,
+    latency_sum_ms INTEGER DEFAULT 0,
+    latency_min_ms INTEGER,
+    latency_max_ms INTEGER,
+    FOREIGN KEY (session_id) REFERENCES sessions(id),
+    UNIQUE (session_id, provider_id, minute_start)
+);
+CREATE INDEX IF NOT EXISTS idx_minute_buckets_time ON minute_buckets(minute_start DESC);
+
+-- Provider health table
+CREATE TABLE IF NOT EXISTS provider_health (
+    provider_id TEXT PRIMARY KEY,
+    circuit_state TEXT NOT NULL DEFAULT 'closed',
+    consecutive_failures INTEGER DEFAULT 0,
+    consecutive_successes INTEGER DEFAULT 0,
+    last_success TEXT,
+    last_failure TEXT,
+    last_error TEXT,
+    avg_latency_ms REAL DEFAULT 0,
+    current_rate_limit INTEGER,
+    base_rate_limit INTEGER,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (provider_id) REFERENCES providers(id)
+);
+"#;
+
 /// RPC statistics database
 pub struct RpcStatsDatabase {
     pool: Pool<SqliteConnectionManager>,
@@ -64,88 +152,11 @@ impl RpcStatsDatabase {
             PRAGMA cache_size = 10000;
             PRAGMA temp_store = MEMORY;
             PRAGMA busy_timeout = 30000;
-
-            -- Sessions table
-            CREATE TABLE IF NOT EXISTS sessions (
-                id TEXT PRIMARY KEY,
-                started_at TEXT NOT NULL,
-                ended_at TEXT,
-                total_calls INTEGER DEFAULT 0,
-                total_errors INTEGER DEFAULT 0,
-                is_current INTEGER DEFAULT 0
-            );
-            CREATE INDEX IF NOT EXISTS idx_sessions_current ON sessions(is_current);
-            CREATE INDEX IF NOT EXISTS idx_sessions_started ON sessions(started_at DESC);
-
-            -- Providers table
-            CREATE TABLE IF NOT EXISTS providers (
-                id TEXT PRIMARY KEY,
-                url_masked TEXT NOT NULL,
-                kind TEXT NOT NULL,
-                priority INTEGER DEFAULT 100,
-                enabled INTEGER DEFAULT 1,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
-                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
-            );
-            CREATE INDEX IF NOT EXISTS idx_providers_kind ON providers(kind);
-
-            -- RPC calls table (time-series)
-            CREATE TABLE IF NOT EXISTS calls (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                session_id TEXT NOT NULL,
-                provider_id TEXT NOT NULL,
-                method TEXT NOT NULL,
-                success INTEGER NOT NULL,
-                latency_ms INTEGER NOT NULL,
-                error_code INTEGER,
-                error_message TEXT,
-                was_retried INTEGER DEFAULT 0,
-                retry_count INTEGER DEFAULT 0,
-                was_rate_limited INTEGER DEFAULT 0,
-                timestamp TEXT NOT NULL,
-                FOREIGN KEY (session_id) REFERENCES sessions(id)
-            );
-            CREATE INDEX IF NOT EXISTS idx_calls_session_time ON calls(session_id, timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_calls_provider_time ON calls(provider_id, timestamp DESC);
-            CREATE INDEX IF NOT EXISTS idx_calls_method ON calls(method);
-            CREATE INDEX IF NOT EXISTS idx_calls_timestamp ON calls(timestamp DESC);
-
-            -- Minute buckets table (aggregated)
-            CREATE TABLE IF NOT EXISTS minute_buckets (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                session_id TEXT NOT NULL,
-                provider_id TEXT,
-                minute_start TEXT NOT NULL,
-                call_count INTEGER DEFAULT 0,
-                success_count INTEGER DEFAULT 0,
-                error_count INTEGER DEFAULT 0,
-                rate_limit_count INTEGER DEFAULT 0,
-                latency_sum_ms INTEGER DEFAULT 0,
-                latency_min_ms INTEGER,
-                latency_max_ms INTEGER,
-                FOREIGN KEY (session_id) REFERENCES sessions(id),
-                UNIQUE (session_id, provider_id, minute_start)
-            );
-            CREATE INDEX IF NOT EXISTS idx_minute_buckets_time ON minute_buckets(minute_start DESC);
-
-            -- Provider health table
-            CREATE TABLE IF NOT EXISTS provider_health (
-                provider_id TEXT PRIMARY KEY,
-                circuit_state TEXT NOT NULL DEFAULT 'closed',
-                consecutive_failures INTEGER DEFAULT 0,
-                consecutive_successes INTEGER DEFAULT 0,
-                last_success TEXT,
-                last_failure TEXT,
-                last_error TEXT,
-                avg_latency_ms REAL DEFAULT 0,
-                current_rate_limit INTEGER,
-                base_rate_limit INTEGER,
-                updated_at TEXT NOT NULL DEFAULT (datetime('now')),
-                FOREIGN KEY (provider_id) REFERENCES providers(id)
-            );
             "#,
         )
-        .map_err(|e| format!("Failed to initialize schema: {}", e))?;
+        .map_err(|e| format!("Failed to set pragmas: {}", e))?;
+
+        crate::migrations::run_migrations(&conn, &RPC_STATS_MIGRATIONS)?;
 
         Ok(())
     }
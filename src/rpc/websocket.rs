@@ -62,6 +62,45 @@ pub fn create_account_subscribe_payload(pubkey: &str, id: u64) -> String {
     .to_string()
 }
 
+/// Create WebSocket subscription payload for raw account data
+///
+/// Same as `create_account_subscribe_payload` but with base64 encoding, so the
+/// notification carries the exact account bytes needed by pool decoders.
+///
+/// # Arguments
+/// * `pubkey` - The account public key to subscribe to
+/// * `id` - The JSON-RPC request ID
+pub fn create_account_subscribe_payload_base64(pubkey: &str, id: u64) -> String {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "accountSubscribe",
+        "params": [
+            pubkey,
+            {
+                "encoding": "base64",
+                "commitment": "confirmed"
+            }
+        ]
+    })
+    .to_string()
+}
+
+/// Create WebSocket unsubscribe payload for an account subscription
+///
+/// # Arguments
+/// * `subscription_id` - Subscription ID returned by `accountSubscribe`
+/// * `id` - The JSON-RPC request ID
+pub fn create_account_unsubscribe_payload(subscription_id: u64, id: u64) -> String {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "accountUnsubscribe",
+        "params": [subscription_id]
+    })
+    .to_string()
+}
+
 /// Create WebSocket subscription payload for log monitoring
 ///
 /// Creates a JSON-RPC payload for subscribing to program logs.
@@ -111,6 +111,40 @@ async fn run_bot_internal(_process_lock: ProcessLock) -> Result<(), String> {
         );
     }
 
+    // 3b. Apply a restore staged through the API (before any database is opened)
+    match crate::backup::apply_pending_restore() {
+        Ok(Some(report)) => {
+            logger::info(
+                LogTag::System,
+                &format!(
+                    "Applied staged restore of [{}] from {}",
+                    report.restored.join(", "),
+                    report.file_name
+                ),
+            );
+            // A config loaded before the restore is stale; an unloaded one is read below
+            let config_restored = report.restored.iter().any(|c| c == "config");
+            if config_restored && crate::config::is_config_initialized() {
+                if let Err(e) = crate::config::reload_config() {
+                    logger::error(
+                        LogTag::System,
+                        &format!("Failed to reload restored config: {}", e),
+                    );
+                }
+            }
+        }
+        Ok(None) => {}
+        Err(e) => {
+            logger::error(LogTag::System, &format!("Staged restore failed: {}", e));
+        }
+    }
+
+    // 3c. Refuse databases written by a newer version (before any is opened)
+    if let Err(e) = crate::migrations::check_store_versions() {
+        logger::error(LogTag::System, &e);
+        return Err(e);
+    }
+
     // 4. Check if config.toml exists (determines initialization mode)
     let config_path = crate::paths::get_config_path();
     let config_exists = config_path.exists();
@@ -128,34 +162,8 @@ async fn run_bot_internal(_process_lock: ProcessLock) -> Result<(), String> {
         // Set initialization flag to false (services will be gated)
         global::INITIALIZATION_COMPLETE.store(false, std::sync::atomic::Ordering::SeqCst);
 
-        // Create service manager with only webserver enabled
-        let mut service_manager = ServiceManager::new().await?;
-        logger::info(LogTag::System, "Service manager initialized");
-
-        // Register all services (but only webserver will be enabled)
-        register_all_services(&mut service_manager);
-
-        // Initialize global ServiceManager for webserver access
-        crate::services::init_global_service_manager(service_manager).await;
-
-        // Get mutable reference to continue
-        let manager_ref = crate::services::get_service_manager()
-            .await
-            .ok_or("Failed to get ServiceManager reference")?;
-
-        let mut service_manager = {
-            let mut guard = manager_ref.write().await;
-            guard.take().ok_or("ServiceManager was already taken")?
-        };
-
-        // Start only enabled services (webserver only in pre-init mode)
-        service_manager.start_all().await?;
-
-        // Put it back for webserver access
-        {
-            let mut guard = manager_ref.write().await;
-            *guard = Some(service_manager);
-        }
+        // Start the service manager with only the webserver enabled
+        start_webserver_only().await?;
 
         logger::info(
             LogTag::System,
@@ -189,6 +197,17 @@ async fn run_bot_internal(_process_lock: ProcessLock) -> Result<(), String> {
 
         logger::info(LogTag::System, "Wallets module initialized");
 
+        // 5b. Master password set: serve the lockscreen until the wallet keys are unlocked
+        let wallet_unlock_required = crate::wallets::is_locked();
+        if wallet_unlock_required {
+            logger::info(
+                LogTag::System,
+                "Wallet keys are protected by a master password - unlock them in the dashboard",
+            );
+            start_webserver_only().await?;
+            wait_for_wallet_unlock_or_shutdown().await?;
+        }
+
         // 6. Validate wallet consistency
         logger::info(LogTag::System, "Validating wallet consistency...");
 
@@ -294,34 +313,39 @@ async fn run_bot_internal(_process_lock: ProcessLock) -> Result<(), String> {
             initialize_llm_providers().await?;
         }
 
-        // 9. Create service manager
-        let mut service_manager = ServiceManager::new().await?;
+        if wallet_unlock_required {
+            // 9-14. The service manager already runs the webserver; start the rest
+            start_newly_enabled_services().await?;
+        } else {
+            // 9. Create service manager
+            let mut service_manager = ServiceManager::new().await?;
 
-        logger::info(LogTag::System, "Service manager initialized");
+            logger::info(LogTag::System, "Service manager initialized");
 
-        // 10. Register all services
-        register_all_services(&mut service_manager);
+            // 10. Register all services
+            register_all_services(&mut service_manager);
 
-        // 11. Initialize global ServiceManager for webserver access
-        crate::services::init_global_service_manager(service_manager).await;
+            // 11. Initialize global ServiceManager for webserver access
+            crate::services::init_global_service_manager(service_manager).await;
 
-        // 12. Get mutable reference to continue
-        let manager_ref = crate::services::get_service_manager()
-            .await
-            .ok_or("Failed to get ServiceManager reference")?;
+            // 12. Get mutable reference to continue
+            let manager_ref = crate::services::get_service_manager()
+                .await
+                .ok_or("Failed to get ServiceManager reference")?;
 
-        let mut service_manager = {
-            let mut guard = manager_ref.write().await;
-            guard.take().ok_or("ServiceManager was already taken")?
-        };
+            let mut service_manager = {
+                let mut guard = manager_ref.write().await;
+                guard.take().ok_or("ServiceManager was already taken")?
+            };
 
-        // 13. Start all enabled services
-        service_manager.start_all().await?;
+            // 13. Start all enabled services
+            service_manager.start_all().await?;
 
-        // 14. Put it back for webserver access
-        {
-            let mut guard = manager_ref.write().await;
-            *guard = Some(service_manager);
+            // 14. Put it back for webserver access
+            {
+                let mut guard = manager_ref.write().await;
+                *guard = Some(service_manager);
+            }
         }
 
         logger::info(
@@ -365,10 +389,12 @@ fn register_all_services(manager: &mut ServiceManager) {
     manager.register(Box::new(EventsService));
     manager.register(Box::new(TransactionsService));
     manager.register(Box::new(SolPriceService));
+    manager.register(Box::new(PriorityFeeService));
 
-    // Pool services (4 sub-services + 1 helper coordinator)
+    // Pool services (5 sub-services + 1 helper coordinator)
     manager.register(Box::new(PoolDiscoveryService));
     manager.register(Box::new(PoolFetcherService));
+    manager.register(Box::new(PoolStreamerService));
     manager.register(Box::new(PoolCalculatorService));
     manager.register(Box::new(PoolAnalyzerService));
     manager.register(Box::new(PoolsService));
@@ -383,6 +409,8 @@ fn register_all_services(manager: &mut ServiceManager) {
     manager.register(Box::new(WalletService));
     manager.register(Box::new(RpcStatsService));
     manager.register(Box::new(AtaCleanupService));
+    manager.register(Box::new(HolderWatchService));
+    manager.register(Box::new(CopyTraderService));
     manager.register(Box::new(crate::trader::TraderService::new()));
     manager.register(Box::new(WebserverService));
 
@@ -394,10 +422,12 @@ fn register_all_services(manager: &mut ServiceManager) {
 
     // Background utility services
     manager.register(Box::new(UpdateCheckService));
+    manager.register(Box::new(BackupService));
 
-    let service_count = 22; // connectivity, events, transactions, sol_price, pool_discovery, pool_fetcher,
-                            // pool_calculator, pool_analyzer, pools, tokens, filtering, ohlcv,
-                            // positions, wallet, rpc_stats, ata_cleanup, trader, webserver, ai, telegram, update_check
+    let service_count = 27; // connectivity, events, transactions, sol_price, priority_fees, pool_discovery,
+                            // pool_fetcher, pool_streamer, pool_calculator, pool_analyzer, pools, tokens,
+                            // filtering, ohlcv, positions, wallet, rpc_stats, ata_cleanup, holder_watch,
+                            // copy_trader, trader, webserver, ai, telegram, update_check, backup
     logger::info(
         LogTag::System,
         &format!("All services registered ({} total)", service_count),
@@ -466,6 +496,94 @@ async fn wait_for_shutdown_signal() -> Result<(), String> {
     Ok(())
 }
 
+/// Create the global service manager and start it with only the webserver enabled
+///
+/// Used while INITIALIZATION_COMPLETE is false (first-run setup, or waiting for
+/// the wallet master password). The other services start later through
+/// `start_newly_enabled`.
+async fn start_webserver_only() -> Result<(), String> {
+    // Create service manager with only webserver enabled
+    let mut service_manager = ServiceManager::new().await?;
+    logger::info(LogTag::System, "Service manager initialized");
+
+    // Register all services (but only webserver will be enabled)
+    register_all_services(&mut service_manager);
+
+    // Initialize global ServiceManager for webserver access
+    crate::services::init_global_service_manager(service_manager).await;
+
+    // Get mutable reference to continue
+    let manager_ref = crate::services::get_service_manager()
+        .await
+        .ok_or("Failed to get ServiceManager reference")?;
+
+    let mut service_manager = {
+        let mut guard = manager_ref.write().await;
+        guard.take().ok_or("ServiceManager was already taken")?
+    };
+
+    // Start only enabled services (webserver only in pre-init mode)
+    service_manager.start_all().await?;
+
+    // Put it back for webserver access
+    {
+        let mut guard = manager_ref.write().await;
+        *guard = Some(service_manager);
+    }
+
+    Ok(())
+}
+
+/// Start services enabled since `start_webserver_only`
+async fn start_newly_enabled_services() -> Result<(), String> {
+    let manager_ref = crate::services::get_service_manager()
+        .await
+        .ok_or("Failed to get ServiceManager reference")?;
+
+    let mut guard = manager_ref.write().await;
+    let manager = guard.as_mut().ok_or("ServiceManager not initialized")?;
+
+    let report = manager.start_newly_enabled().await?;
+    for failure in &report.failures {
+        logger::error(
+            LogTag::System,
+            &format!(
+                "Service startup failure: {} -> {}",
+                failure.name, failure.error
+            ),
+        );
+    }
+
+    Ok(())
+}
+
+/// Wait until the wallet master password is entered, or for a shutdown signal
+async fn wait_for_wallet_unlock_or_shutdown() -> Result<(), String> {
+    use tokio::time::{sleep, Duration};
+
+    logger::info(LogTag::System, "Waiting for the wallet master password...");
+
+    loop {
+        if !crate::wallets::is_locked() {
+            logger::info(LogTag::System, "Wallet keys unlocked - continuing startup");
+            return Ok(());
+        }
+
+        tokio::select! {
+          _ = tokio::signal::ctrl_c() => {
+            logger::warning(
+              LogTag::System,
+              "Shutdown signal received while waiting for the master password",
+            );
+            return Err("Shutdown while waiting for the master password".to_string());
+          }
+          _ = sleep(Duration::from_millis(500)) => {
+            // Continue polling
+          }
+        }
+    }
+}
+
 /// Wait for initialization to complete or shutdown signal during pre-init mode
 async fn wait_for_initialization_or_shutdown() -> Result<(), String> {
     use tokio::time::{sleep, Duration, Instant};
@@ -1,14 +1,22 @@
 //! Secure storage module for encrypting sensitive data (private keys)
 //!
-//! Uses AES-256-GCM encryption with machine-derived keys.
-//! The encryption key is derived from the machine's unique ID + app salt,
-//! so encrypted data can only be decrypted on the same machine.
+//! Uses AES-256-GCM encryption. Wallet keys are encrypted with one of two keys:
+//! - Machine key (default): derived from the machine's unique ID + app salt,
+//!   so encrypted data can only be decrypted on the same machine
+//! - Master password key: derived with Argon2id from a user password, so data
+//!   is portable but must be unlocked after every start
+//!
+//! The active key is process-wide state set by the wallets module at startup.
 
 use aes_gcm::{
     aead::{Aead, KeyInit},
     Aes256Gcm, Nonce,
 };
+use argon2::{Algorithm, Argon2, Params, Version};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
 
 /// Salt used for key derivation - app-specific to prevent rainbow attacks
 const APP_SALT: &[u8] = b"screenerbot-wallet-encryption-v1";
@@ -66,7 +74,7 @@ fn get_machine_id() -> Result<String, String> {
 /// Derive a 256-bit encryption key from machine ID
 ///
 /// Uses BLAKE3 to hash: machine_id + app_salt → 32-byte key
-fn derive_encryption_key() -> Result<[u8; 32], String> {
+pub fn derive_machine_key() -> Result<[u8; 32], String> {
     // Get machine unique ID
     let machine_id = get_machine_id()?;
 
@@ -83,17 +91,35 @@ fn derive_encryption_key() -> Result<[u8; 32], String> {
 
 /// Encrypt a private key string using AES-256-GCM
 ///
+/// Uses the active wallet key (machine key, or the master password key once unlocked).
+///
 /// # Arguments
 /// * `plaintext` - The private key to encrypt (base58 string)
 ///
 /// # Returns
 /// * `EncryptedData` containing base64-encoded ciphertext and nonce
 pub fn encrypt_private_key(plaintext: &str) -> Result<EncryptedData, String> {
-    let key = derive_encryption_key()?;
+    let key = active_wallet_key()?;
+    encrypt_with_key(plaintext, &key)
+}
+
+/// Decrypt a private key using AES-256-GCM
+///
+/// # Arguments
+/// * `encrypted` - The encrypted data (ciphertext + nonce)
+///
+/// # Returns
+/// * The decrypted private key string
+pub fn decrypt_private_key(encrypted: &EncryptedData) -> Result<String, String> {
+    let key = active_wallet_key()?;
+    decrypt_with_key(encrypted, &key)
+}
 
+/// Encrypt a string with an explicit 256-bit key
+pub fn encrypt_with_key(plaintext: &str, key: &[u8; 32]) -> Result<EncryptedData, String> {
     // Create cipher
     let cipher =
-        Aes256Gcm::new_from_slice(&key).map_err(|e| format!("Failed to create cipher: {}", e))?;
+        Aes256Gcm::new_from_slice(key).map_err(|e| format!("Failed to create cipher: {}", e))?;
 
     // Generate random 12-byte nonce
     let nonce_bytes: [u8; 12] = rand::random();
@@ -110,16 +136,8 @@ pub fn encrypt_private_key(plaintext: &str) -> Result<EncryptedData, String> {
     })
 }
 
-/// Decrypt a private key using AES-256-GCM
-///
-/// # Arguments
-/// * `encrypted` - The encrypted data (ciphertext + nonce)
-///
-/// # Returns
-/// * The decrypted private key string
-pub fn decrypt_private_key(encrypted: &EncryptedData) -> Result<String, String> {
-    let key = derive_encryption_key()?;
-
+/// Decrypt a string with an explicit 256-bit key
+pub fn decrypt_with_key(encrypted: &EncryptedData, key: &[u8; 32]) -> Result<String, String> {
     // Decode base64
     let ciphertext = BASE64
         .decode(&encrypted.ciphertext)
@@ -140,12 +158,12 @@ pub fn decrypt_private_key(encrypted: &EncryptedData) -> Result<String, String>
 
     // Create cipher
     let cipher =
-        Aes256Gcm::new_from_slice(&key).map_err(|e| format!("Failed to create cipher: {}", e))?;
+        Aes256Gcm::new_from_slice(key).map_err(|e| format!("Failed to create cipher: {}", e))?;
 
     // Decrypt
-    let plaintext_bytes = cipher
-        .decrypt(nonce, ciphertext.as_ref())
-        .map_err(|_| "Decryption failed - wrong machine or corrupted data".to_string())?;
+    let plaintext_bytes = cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
+        "Decryption failed - wrong machine, wrong password or corrupted data".to_string()
+    })?;
 
     String::from_utf8(plaintext_bytes)
         .map_err(|e| format!("Decrypted data is not valid UTF-8: {}", e))
@@ -156,6 +174,127 @@ pub fn has_encrypted_wallet(ciphertext: &str, nonce: &str) -> bool {
     !ciphertext.is_empty() && !nonce.is_empty()
 }
 
+// =============================================================================
+// MASTER PASSWORD (ARGON2ID) WALLET KEYS
+// =============================================================================
+
+/// Plaintext encrypted with a master password key to check the password on unlock
+const KEY_CHECK_PLAINTEXT: &str = "screenerbot-master-password-check-v1";
+
+/// Argon2id cost parameters
+///
+/// Stored next to the salt so stronger defaults never break existing data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    /// Memory cost in KiB
+    pub memory_kib: u32,
+    /// Number of passes
+    pub iterations: u32,
+    /// Degree of parallelism
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        // 64 MiB, 3 passes - about half a second on a desktop CPU
+        Self {
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+/// Derive a 256-bit key from a password with Argon2id
+///
+/// # Arguments
+/// * `password` - The master password
+/// * `salt` - Base64-encoded random salt (see `generate_password_salt`)
+/// * `params` - Argon2id cost parameters
+pub fn derive_password_key(
+    password: &str,
+    salt: &str,
+    params: &KdfParams,
+) -> Result<[u8; 32], String> {
+    let salt_bytes = BASE64
+        .decode(salt)
+        .map_err(|e| format!("Invalid salt encoding: {}", e))?;
+
+    let argon_params = Params::new(
+        params.memory_kib,
+        params.iterations,
+        params.parallelism,
+        Some(32),
+    )
+    .map_err(|e| format!("Invalid Argon2 parameters: {}", e))?;
+
+    let mut key = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, argon_params)
+        .hash_password_into(password.as_bytes(), &salt_bytes, &mut key)
+        .map_err(|e| format!("Key derivation failed: {}", e))?;
+
+    Ok(key)
+}
+
+/// Encrypt a known value with `key`, stored to recognize the right password later
+pub fn create_key_check(key: &[u8; 32]) -> Result<EncryptedData, String> {
+    encrypt_with_key(KEY_CHECK_PLAINTEXT, key)
+}
+
+/// Check that `key` decrypts a value created by `create_key_check`
+pub fn verify_key_check(key: &[u8; 32], check: &EncryptedData) -> bool {
+    matches!(decrypt_with_key(check, key), Ok(value) if value == KEY_CHECK_PLAINTEXT)
+}
+
+/// Key used for wallet private keys
+enum WalletKeyState {
+    /// Machine-derived key (no master password)
+    Machine,
+    /// Master password set but not entered yet
+    Locked,
+    /// Master password key, held in memory until shutdown
+    Unlocked([u8; 32]),
+}
+
+static WALLET_KEY_STATE: Lazy<RwLock<WalletKeyState>> =
+    Lazy::new(|| RwLock::new(WalletKeyState::Machine));
+
+/// Use the machine-derived key for wallet encryption
+pub fn use_machine_wallet_key() {
+    *WALLET_KEY_STATE.write().unwrap() = WalletKeyState::Machine;
+}
+
+/// Require the master password before wallet keys can be used
+pub fn lock_wallet_key() {
+    *WALLET_KEY_STATE.write().unwrap() = WalletKeyState::Locked;
+}
+
+/// Use a master password key for wallet encryption
+pub fn unlock_wallet_key(key: [u8; 32]) {
+    *WALLET_KEY_STATE.write().unwrap() = WalletKeyState::Unlocked(key);
+}
+
+/// Whether a master password is set but has not been entered yet
+pub fn is_wallet_key_locked() -> bool {
+    matches!(*WALLET_KEY_STATE.read().unwrap(), WalletKeyState::Locked)
+}
+
+/// Whether wallet keys are protected by a master password
+pub fn is_master_password_mode() -> bool {
+    !matches!(*WALLET_KEY_STATE.read().unwrap(), WalletKeyState::Machine)
+}
+
+/// The key wallet private keys are currently encrypted with
+pub fn active_wallet_key() -> Result<[u8; 32], String> {
+    match *WALLET_KEY_STATE.read().unwrap() {
+        WalletKeyState::Machine => derive_machine_key(),
+        WalletKeyState::Locked => {
+            Err("Wallet keys are locked - enter the master password to unlock".to_string())
+        }
+        WalletKeyState::Unlocked(key) => Ok(key),
+    }
+}
+
 // =============================================================================
 // PASSWORD HASHING FOR LOCKSCREEN
 // =============================================================================
@@ -258,4 +397,37 @@ mod tests {
 
         assert_eq!(decrypted, original);
     }
+
+    fn test_kdf_params() -> KdfParams {
+        // Minimal cost keeps the test fast
+        KdfParams {
+            memory_kib: 1024,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn test_password_key_roundtrip() {
+        let salt = generate_password_salt();
+        let key = derive_password_key("correct horse", &salt, &test_kdf_params()).unwrap();
+        let again = derive_password_key("correct horse", &salt, &test_kdf_params()).unwrap();
+        assert_eq!(key, again);
+
+        let encrypted = encrypt_with_key("secret", &key).unwrap();
+        assert_eq!(decrypt_with_key(&encrypted, &key).unwrap(), "secret");
+
+        let wrong = derive_password_key("wrong horse", &salt, &test_kdf_params()).unwrap();
+        assert!(decrypt_with_key(&encrypted, &wrong).is_err());
+    }
+
+    #[test]
+    fn test_key_check() {
+        let salt = generate_password_salt();
+        let key = derive_password_key("pw", &salt, &test_kdf_params()).unwrap();
+        let check = create_key_check(&key).unwrap();
+
+        assert!(verify_key_check(&key, &check));
+        assert!(!verify_key_check(&[7u8; 32], &check));
+    }
 }
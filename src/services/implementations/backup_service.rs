@@ -0,0 +1,48 @@
+use crate::services::{Service, ServiceHealth};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+pub struct BackupService;
+
+#[async_trait]
+impl Service for BackupService {
+    fn name(&self) -> &'static str {
+        "backup"
+    }
+
+    fn priority(&self) -> i32 {
+        // Background utility - runs after all core services are started
+        10
+    }
+
+    fn is_enabled(&self) -> bool {
+        // Runs whenever the bot is initialized; the scheduler idles while backup.enabled is false
+        crate::global::is_initialization_complete()
+    }
+
+    async fn start(
+        &mut self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> Result<Vec<JoinHandle<()>>, String> {
+        let handle = tokio::spawn(monitor.instrument(async move {
+            crate::backup::start_backup_scheduler(shutdown).await;
+        }));
+
+        Ok(vec![handle])
+    }
+
+    async fn health(&self) -> ServiceHealth {
+        let status = crate::backup::get_backup_status().await;
+        match (status.last_error_at, status.last_error) {
+            (Some(error_at), Some(error))
+                if status.last_backup_at.map_or(true, |at| at < error_at) =>
+            {
+                ServiceHealth::Degraded(format!("Last backup failed: {}", error))
+            }
+            _ => ServiceHealth::Healthy,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::logger::{self, LogTag};
+use crate::services::{Service, ServiceHealth, ServiceMetrics};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+pub struct CopyTraderService;
+
+#[async_trait]
+impl Service for CopyTraderService {
+    fn name(&self) -> &'static str {
+        "copy_trader"
+    }
+
+    fn priority(&self) -> i32 {
+        151 // Executes trades like the trader; start after it
+    }
+
+    fn dependencies(&self) -> Vec<&'static str> {
+        vec!["events", "positions", "pool_helpers", "filtering"]
+    }
+
+    fn is_enabled(&self) -> bool {
+        // Runs whenever the bot is initialized; the monitor holds no subscriptions while copy_trade is disabled
+        crate::global::is_initialization_complete()
+    }
+
+    async fn initialize(&mut self) -> Result<(), String> {
+        crate::tools::init_tools_db()?;
+        logger::debug(LogTag::Tools, "[COPY_TRADER] Tools database ready");
+        Ok(())
+    }
+
+    async fn start(
+        &mut self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> Result<Vec<JoinHandle<()>>, String> {
+        let handle = tokio::spawn(monitor.instrument(async move {
+            crate::tools::copy_trader::start_copy_trader_service(shutdown).await;
+        }));
+
+        Ok(vec![handle])
+    }
+
+    async fn health(&self) -> ServiceHealth {
+        let status = crate::tools::copy_trader::get_copy_trader_status().await;
+        match status.last_error {
+            Some(error) if status.enabled => ServiceHealth::Degraded(error),
+            _ => ServiceHealth::Healthy,
+        }
+    }
+
+    async fn metrics(&self) -> ServiceMetrics {
+        let mut metrics = ServiceMetrics::default();
+        let (swaps, copied) = crate::tools::copy_trader::get_copy_trader_counters().await;
+        metrics.operations_total = swaps;
+        metrics
+            .custom_metrics
+            .insert("trades_copied".to_string(), copied as f64);
+        metrics
+    }
+}
@@ -0,0 +1,64 @@
+use crate::logger::{self, LogTag};
+use crate::services::{Service, ServiceHealth, ServiceMetrics};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+pub struct HolderWatchService;
+
+#[async_trait]
+impl Service for HolderWatchService {
+    fn name(&self) -> &'static str {
+        "holder_watch"
+    }
+
+    fn priority(&self) -> i32 {
+        111
+    }
+
+    fn dependencies(&self) -> Vec<&'static str> {
+        vec!["events"]
+    }
+
+    fn is_enabled(&self) -> bool {
+        // Runs whenever the bot is initialized; the monitor idles while holder_watch is disabled
+        crate::global::is_initialization_complete()
+    }
+
+    async fn initialize(&mut self) -> Result<(), String> {
+        crate::tools::init_tools_db()?;
+        logger::debug(LogTag::Tools, "[HOLDER_WATCH] Tools database ready");
+        Ok(())
+    }
+
+    async fn start(
+        &mut self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> Result<Vec<JoinHandle<()>>, String> {
+        let handle = tokio::spawn(monitor.instrument(async move {
+            crate::tools::holder_watch::start_holder_watch_service(shutdown).await;
+        }));
+
+        Ok(vec![handle])
+    }
+
+    async fn health(&self) -> ServiceHealth {
+        let status = crate::tools::holder_watch::get_holder_watch_status().await;
+        match status.last_error {
+            Some(error) if status.enabled => ServiceHealth::Degraded(error),
+            _ => ServiceHealth::Healthy,
+        }
+    }
+
+    async fn metrics(&self) -> ServiceMetrics {
+        let mut metrics = ServiceMetrics::default();
+        let (checks, alerts) = crate::tools::holder_watch::get_holder_watch_counters().await;
+        metrics.operations_total = checks;
+        metrics
+            .custom_metrics
+            .insert("alerts_raised".to_string(), alerts as f64);
+        metrics
+    }
+}
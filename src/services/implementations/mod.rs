@@ -1,10 +1,14 @@
 pub mod ai_service;
 pub mod ata_cleanup_service;
+pub mod backup_service;
+pub mod copy_trader_service;
 pub mod events_service;
 pub mod filtering_service;
+pub mod holder_watch_service;
 pub mod ohlcv_service;
 pub mod pools_service;
 pub mod positions_service;
+pub mod priority_fee_service;
 pub mod rpc_stats_service;
 pub mod scheduled_ai_tasks_service;
 pub mod sol_price_service;
@@ -18,17 +22,22 @@ pub mod pool_analyzer_service;
 pub mod pool_calculator_service;
 pub mod pool_discovery_service;
 pub mod pool_fetcher_service;
+pub mod pool_streamer_service;
 
 // Centralized tokens service
 pub mod tokens_service;
 
 pub use ai_service::AiService;
 pub use ata_cleanup_service::AtaCleanupService;
+pub use backup_service::BackupService;
+pub use copy_trader_service::CopyTraderService;
 pub use events_service::EventsService;
 pub use filtering_service::FilteringService;
+pub use holder_watch_service::HolderWatchService;
 pub use ohlcv_service::OhlcvService;
 pub use pools_service::PoolsService;
 pub use positions_service::PositionsService;
+pub use priority_fee_service::PriorityFeeService;
 pub use rpc_stats_service::RpcStatsService;
 pub use scheduled_ai_tasks_service::ScheduledAiTasksService;
 pub use sol_price_service::SolPriceService;
@@ -42,6 +51,7 @@ pub use pool_analyzer_service::PoolAnalyzerService;
 pub use pool_calculator_service::PoolCalculatorService;
 pub use pool_discovery_service::PoolDiscoveryService;
 pub use pool_fetcher_service::PoolFetcherService;
+pub use pool_streamer_service::PoolStreamerService;
 
 // Centralized tokens service
 pub use tokens_service::TokensService;
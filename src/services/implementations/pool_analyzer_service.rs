@@ -54,9 +54,7 @@ impl Service for PoolAnalyzerService {
             .ok_or("PoolAnalyzer component not initialized".to_string())?;
 
         // Spawn analyzer task
-        let handle = tokio::spawn(monitor.instrument(async move {
-            analyzer.start_analyzer_task(shutdown).await;
-        }));
+        let handle = analyzer.start_analyzer_task(shutdown, monitor).await?;
 
         logger::info(
             LogTag::PoolService,
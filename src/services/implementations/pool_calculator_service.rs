@@ -54,9 +54,7 @@ impl Service for PoolCalculatorService {
             .ok_or("PriceCalculator component not initialized".to_string())?;
 
         // Spawn calculator task
-        let handle = tokio::spawn(monitor.instrument(async move {
-            calculator.start_calculator_task(shutdown).await;
-        }));
+        let handle = calculator.start_calculator_task(shutdown, monitor).await?;
 
         logger::info(
             LogTag::PoolService,
@@ -51,9 +51,7 @@ impl Service for PoolDiscoveryService {
             .ok_or("PoolDiscovery component not initialized".to_string())?;
 
         // Spawn discovery task (instrumented) - component tracks its own metrics
-        let handle = tokio::spawn(monitor.instrument(async move {
-            discovery.start_discovery_task(shutdown).await;
-        }));
+        let handle = discovery.start_discovery_task(shutdown, monitor).await;
 
         logger::info(
             LogTag::PoolService,
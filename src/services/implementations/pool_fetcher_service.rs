@@ -59,9 +59,7 @@ impl Service for PoolFetcherService {
             .ok_or("AccountFetcher component not initialized".to_string())?;
 
         // Spawn fetcher task
-        let handle = tokio::spawn(monitor.instrument(async move {
-            fetcher.start_fetcher_task(shutdown).await;
-        }));
+        let handle = fetcher.start_fetcher_task(shutdown, monitor).await?;
 
         logger::info(
             LogTag::PoolService,
@@ -0,0 +1,104 @@
+use crate::logger::{self, LogTag};
+use crate::services::{Service, ServiceHealth, ServiceMetrics};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+pub struct PoolStreamerService;
+
+impl Default for PoolStreamerService {
+    fn default() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl Service for PoolStreamerService {
+    fn name(&self) -> &'static str {
+        "pool_streamer"
+    }
+
+    fn priority(&self) -> i32 {
+        102
+    }
+
+    fn dependencies(&self) -> Vec<&'static str> {
+        vec!["pool_helpers", "pool_fetcher", "pool_calculator"]
+    }
+
+    fn is_enabled(&self) -> bool {
+        crate::global::is_initialization_complete()
+            && crate::pools::types::account_streaming_enabled()
+    }
+
+    async fn initialize(&mut self) -> Result<(), String> {
+        logger::info(
+            LogTag::PoolService,
+            &"Initializing pool account streamer service...".to_string(),
+        );
+        Ok(())
+    }
+
+    async fn start(
+        &mut self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> Result<Vec<JoinHandle<()>>, String> {
+        logger::info(
+            LogTag::PoolService,
+            &"Starting pool account streamer service...".to_string(),
+        );
+
+        // Get the AccountStreamer component from global state
+        let streamer = crate::pools::get_account_streamer()
+            .ok_or("AccountStreamer component not initialized".to_string())?;
+
+        // Spawn streamer task
+        let handle = streamer.start_streamer_task(shutdown, monitor).await;
+
+        logger::info(
+            LogTag::PoolService,
+            &"Pool account streamer service started (instrumented)".to_string(),
+        );
+
+        Ok(vec![handle])
+    }
+
+    async fn stop(&mut self) -> Result<(), String> {
+        logger::info(
+            LogTag::PoolService,
+            &"Pool account streamer service stopping (via shutdown signal)".to_string(),
+        );
+        Ok(())
+    }
+
+    async fn health(&self) -> ServiceHealth {
+        match crate::pools::get_account_streamer() {
+            Some(streamer) if streamer.is_connected() || streamer.is_idle() => {
+                ServiceHealth::Healthy
+            }
+            // Polling keeps prices flowing while the stream reconnects
+            Some(_) => ServiceHealth::Degraded("Account stream disconnected".to_string()),
+            None => ServiceHealth::Unhealthy("AccountStreamer component not available".to_string()),
+        }
+    }
+
+    async fn metrics(&self) -> ServiceMetrics {
+        let mut metrics = ServiceMetrics::default();
+
+        if let Some(streamer) = crate::pools::get_account_streamer() {
+            let (notifications, reconnects, active_subscriptions) = streamer.get_metrics();
+            metrics.operations_total = notifications;
+            metrics
+                .custom_metrics
+                .insert("reconnects".to_string(), reconnects as f64);
+            metrics.custom_metrics.insert(
+                "active_subscriptions".to_string(),
+                active_subscriptions as f64,
+            );
+        }
+
+        metrics
+    }
+}
@@ -0,0 +1,64 @@
+use crate::services::{Service, ServiceHealth, ServiceMetrics};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+pub struct PriorityFeeService;
+
+#[async_trait]
+impl Service for PriorityFeeService {
+    fn name(&self) -> &'static str {
+        "priority_fees"
+    }
+
+    fn priority(&self) -> i32 {
+        121
+    }
+
+    fn dependencies(&self) -> Vec<&'static str> {
+        vec![]
+    }
+
+    fn is_enabled(&self) -> bool {
+        // Runs whenever the bot is initialized; sampling idles while dynamic fees are disabled
+        crate::global::is_initialization_complete()
+    }
+
+    async fn initialize(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    async fn start(
+        &mut self,
+        shutdown: Arc<Notify>,
+        monitor: tokio_metrics::TaskMonitor,
+    ) -> Result<Vec<JoinHandle<()>>, String> {
+        let handle = tokio::spawn(monitor.instrument(async move {
+            crate::swaps::priority_fees::start_priority_fee_service(shutdown).await;
+        }));
+
+        Ok(vec![handle])
+    }
+
+    async fn health(&self) -> ServiceHealth {
+        match crate::swaps::priority_fees::get_last_refresh_error().await {
+            Some(error) => ServiceHealth::Degraded(error),
+            None => ServiceHealth::Healthy,
+        }
+    }
+
+    async fn metrics(&self) -> ServiceMetrics {
+        let mut metrics = ServiceMetrics::default();
+        let stats = crate::swaps::priority_fees::get_priority_fee_stats().await;
+        metrics.operations_total = stats.swaps_recorded as u64;
+        metrics.custom_metrics.insert(
+            "network_sample_count".to_string(),
+            stats.network_sample_count as f64,
+        );
+        metrics
+            .custom_metrics
+            .insert("landed_rate".to_string(), stats.landed_rate);
+        metrics
+    }
+}
@@ -1,9 +1,11 @@
 mod health;
 pub mod implementations;
 mod metrics;
+mod supervisor;
 
 pub use health::ServiceHealth;
 pub use metrics::{MetricsCollector, ServiceMetrics};
+pub use supervisor::ServiceRestartInfo;
 
 use crate::logger::{self, LogTag};
 use crate::startup;
@@ -177,6 +179,9 @@ pub struct ServiceManager {
     // Cached health/metrics to avoid blocking during snapshot collection
     cached_health: Arc<RwLock<HashMap<&'static str, ServiceHealth>>>,
     cached_metrics: Arc<RwLock<HashMap<&'static str, ServiceMetrics>>>,
+    // Restart bookkeeping for services whose tasks failed
+    supervision: HashMap<&'static str, supervisor::SupervisionState>,
+    shutting_down: bool,
 }
 
 impl ServiceManager {
@@ -189,6 +194,8 @@ impl ServiceManager {
             task_monitors: HashMap::new(),
             cached_health: Arc::new(RwLock::new(HashMap::new())),
             cached_metrics: Arc::new(RwLock::new(HashMap::new())),
+            supervision: HashMap::new(),
+            shutting_down: false,
         })
     }
 
@@ -544,7 +551,8 @@ impl ServiceManager {
         let shutdown_begin = format!("running={} debug_system=on", running_services.len());
         log_service_startup_phase("shutdown_begin", Some(&shutdown_begin));
 
-        // Signal shutdown
+        // Signal shutdown (also stops the supervisor from restarting exiting tasks)
+        self.shutting_down = true;
         self.shutdown.notify_waiters();
 
         // Get services in reverse startup order
@@ -716,11 +724,12 @@ impl ServiceManager {
         Ok(())
     }
 
-    /// Get health status
+    /// Get health status (adjusted for failed and restarted services)
     pub async fn get_health(&self) -> HashMap<&'static str, ServiceHealth> {
         let mut health = HashMap::new();
         for (name, service) in &self.services {
-            health.insert(*name, service.health().await);
+            let reported = service.health().await;
+            health.insert(*name, self.supervised_health(name, reported));
         }
         health
     }
@@ -827,11 +836,15 @@ impl ServiceManager {
 pub async fn init_global_service_manager(manager: ServiceManager) {
     // Do initial cache update
     manager.update_cache().await;
+    let shutdown = manager.shutdown.clone();
 
     let mut global = GLOBAL_SERVICE_MANAGER.write().await;
     *global = Some(manager);
     logger::info(LogTag::System, "Global ServiceManager initialized");
 
+    // Watch service tasks and restart failed services
+    supervisor::spawn_supervisor(GLOBAL_SERVICE_MANAGER.clone(), shutdown);
+
     // Spawn background task to update cache every 5 seconds
     // (most services are idle, so less frequent updates reduce CPU overhead)
     // Task auto-terminates when ServiceManager is cleared (during shutdown)
@@ -0,0 +1,652 @@
+//! Service supervision
+//!
+//! Watches the task handles of running services. A service is considered failed
+//! when one of its tasks panics or is cancelled; tasks that return normally are
+//! dropped from supervision without counting as a failure.
+//! Failed services are stopped together with their running dependents and
+//! restarted with exponential backoff, limited by a restart budget per window.
+//! Once the budget is exhausted the service is left stopped and reported
+//! unhealthy until the process restarts.
+
+use super::{log_service_notice, ServiceHealth, ServiceManager};
+use crate::events::{record_system_event, Severity};
+use crate::logger::{self, LogTag};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+use tokio::task::JoinError;
+
+/// How often task handles are checked
+const SUPERVISOR_INTERVAL: Duration = Duration::from_secs(5);
+
+/// First restart delay, doubled for every consecutive failure
+const BACKOFF_BASE: Duration = Duration::from_secs(2);
+
+/// Upper bound for the restart delay
+const BACKOFF_MAX: Duration = Duration::from_secs(300);
+
+/// Maximum restarts per service within `RESTART_WINDOW`
+const RESTART_BUDGET: usize = 5;
+
+/// Window for the restart budget; a service running this long without failing
+/// also has its backoff reset
+const RESTART_WINDOW: Duration = Duration::from_secs(600);
+
+/// Restart statistics of a supervised service
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ServiceRestartInfo {
+    /// Successful restarts since process start
+    pub restart_count: u32,
+    pub last_failure_reason: Option<String>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub last_restart_at: Option<DateTime<Utc>>,
+    /// Seconds until the next restart attempt while the service is down
+    pub next_restart_in_secs: Option<u64>,
+    /// Restart budget exhausted - the service stays stopped
+    pub gave_up: bool,
+}
+
+/// Per-service supervision state
+#[derive(Debug, Default)]
+pub(super) struct SupervisionState {
+    consecutive_failures: u32,
+    restarts: VecDeque<Instant>,
+    next_attempt: Option<Instant>,
+    last_restart: Option<Instant>,
+    info: ServiceRestartInfo,
+}
+
+impl SupervisionState {
+    /// Record a failure and schedule the next restart attempt
+    ///
+    /// Dependents stopped because of another service's failure pass
+    /// `counts = false` so they follow the failed service without growing
+    /// their own backoff.
+    fn record_failure(&mut self, reason: &str, counts: bool) -> Duration {
+        self.reset_if_stable();
+        if counts {
+            self.consecutive_failures += 1;
+        }
+        let delay = backoff_delay(self.consecutive_failures.max(1));
+        self.next_attempt = Some(Instant::now() + delay);
+        self.info.last_failure_reason = Some(reason.to_string());
+        self.info.last_failure_at = Some(Utc::now());
+        delay
+    }
+
+    fn record_restart(&mut self) {
+        let now = Instant::now();
+        self.restarts.push_back(now);
+        self.last_restart = Some(now);
+        self.next_attempt = None;
+        self.info.restart_count += 1;
+        self.info.last_restart_at = Some(Utc::now());
+    }
+
+    /// Whether another restart fits into the budget
+    fn has_budget(&mut self) -> bool {
+        let now = Instant::now();
+        while self
+            .restarts
+            .front()
+            .map_or(false, |at| now.duration_since(*at) > RESTART_WINDOW)
+        {
+            self.restarts.pop_front();
+        }
+        within_budget(self.restarts.len())
+    }
+
+    fn is_due(&self, now: Instant) -> bool {
+        !self.info.gave_up && self.next_attempt.map_or(false, |at| at <= now)
+    }
+
+    /// Forget consecutive failures once the service has been stable for a full window
+    fn reset_if_stable(&mut self) {
+        if self.consecutive_failures > 0
+            && self.next_attempt.is_none()
+            && self
+                .last_restart
+                .map_or(false, |at| at.elapsed() >= RESTART_WINDOW)
+        {
+            self.consecutive_failures = 0;
+        }
+    }
+
+    /// Health as reported to callers, taking supervision into account
+    fn adjust_health(&self, health: ServiceHealth) -> ServiceHealth {
+        let reason = self
+            .info
+            .last_failure_reason
+            .as_deref()
+            .unwrap_or("unknown failure");
+
+        if self.info.gave_up {
+            return ServiceHealth::Unhealthy(format!(
+                "Restart budget exhausted, service stopped: {}",
+                reason
+            ));
+        }
+        if let Some(at) = self.next_attempt {
+            return ServiceHealth::Unhealthy(format!(
+                "Failed, restarting in {}s: {}",
+                at.saturating_duration_since(Instant::now()).as_secs(),
+                reason
+            ));
+        }
+        let recently_restarted = self
+            .last_restart
+            .map_or(false, |at| at.elapsed() < RESTART_WINDOW);
+        if recently_restarted && health.is_healthy() {
+            return ServiceHealth::Degraded(format!(
+                "Restarted {} time(s), last failure: {}",
+                self.info.restart_count, reason
+            ));
+        }
+        health
+    }
+
+    fn snapshot(&self) -> ServiceRestartInfo {
+        let mut info = self.info.clone();
+        info.next_restart_in_secs = self
+            .next_attempt
+            .map(|at| at.saturating_duration_since(Instant::now()).as_secs());
+        info
+    }
+}
+
+/// Restart delay after `consecutive_failures` failures in a row (1-based)
+fn backoff_delay(consecutive_failures: u32) -> Duration {
+    let exponent = consecutive_failures.saturating_sub(1).min(16);
+    BACKOFF_BASE.saturating_mul(1 << exponent).min(BACKOFF_MAX)
+}
+
+fn within_budget(restarts_in_window: usize) -> bool {
+    restarts_in_window < RESTART_BUDGET
+}
+
+/// Describe why a finished task ended
+fn describe_task_exit(result: Result<(), JoinError>) -> Option<String> {
+    match result {
+        Ok(()) => None,
+        Err(e) if e.is_panic() => {
+            let payload = e.into_panic();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic payload".to_string());
+            Some(format!("task panicked: {}", message))
+        }
+        Err(e) => Some(format!("task cancelled: {}", e)),
+    }
+}
+
+impl ServiceManager {
+    /// Cheap check whether `supervise` has anything to do
+    pub fn needs_supervision(&self) -> bool {
+        if self.shutting_down {
+            return false;
+        }
+        let now = Instant::now();
+        self.handles
+            .values()
+            .any(|handles| handles.iter().any(|h| h.is_finished()))
+            || self.supervision.values().any(|state| state.is_due(now))
+    }
+
+    /// Detect failed services and restart those whose backoff has elapsed
+    pub async fn supervise(&mut self) {
+        if self.shutting_down {
+            return;
+        }
+
+        for (name, reason) in self.collect_failures().await {
+            self.handle_failure(name, &reason).await;
+        }
+
+        self.restart_due_services().await;
+    }
+
+    /// Restart statistics for a service
+    pub fn get_restart_info(&self, name: &str) -> ServiceRestartInfo {
+        self.supervision
+            .get(name)
+            .map(|state| state.snapshot())
+            .unwrap_or_default()
+    }
+
+    /// Apply supervision state to a health value reported by the service
+    pub(super) fn supervised_health(&self, name: &str, health: ServiceHealth) -> ServiceHealth {
+        match self.supervision.get(name) {
+            Some(state) => state.adjust_health(health),
+            None => health,
+        }
+    }
+
+    /// Collect services with a panicked or cancelled task
+    async fn collect_failures(&mut self) -> Vec<(&'static str, String)> {
+        let finished: Vec<&'static str> = self
+            .handles
+            .iter()
+            .filter(|(_, handles)| handles.iter().any(|h| h.is_finished()))
+            .map(|(name, _)| *name)
+            .collect();
+
+        let mut failures = Vec::new();
+        for name in finished {
+            let Some(handles) = self.handles.remove(name) else {
+                continue;
+            };
+            let (done, running): (Vec<_>, Vec<_>) =
+                handles.into_iter().partition(|h| h.is_finished());
+
+            let mut reason = None;
+            for handle in done {
+                if let Some(exit) = describe_task_exit(handle.await) {
+                    reason.get_or_insert(exit);
+                }
+            }
+
+            match reason {
+                Some(reason) => {
+                    for handle in running {
+                        handle.abort();
+                    }
+                    failures.push((name, reason));
+                }
+                None => {
+                    // Tasks that completed normally are not restarted
+                    self.handles.insert(name, running);
+                }
+            }
+        }
+        failures
+    }
+
+    /// Stop a failed service and its running dependents, then schedule restarts
+    async fn handle_failure(&mut self, name: &'static str, reason: &str) {
+        self.stop_supervised(name).await;
+
+        let state = self.supervision.entry(name).or_default();
+        let delay = state.record_failure(reason, true);
+        let next_attempt = state.next_attempt;
+
+        logger::error(
+            LogTag::System,
+            &format!(
+                "Service '{}' failed: {} - restarting in {}s",
+                name,
+                reason,
+                delay.as_secs()
+            ),
+        );
+
+        let dependents = self.running_dependents(name);
+        for &dependent in &dependents {
+            if let Some(handles) = self.handles.remove(dependent) {
+                for handle in handles {
+                    handle.abort();
+                }
+            }
+            self.stop_supervised(dependent).await;
+
+            let state = self.supervision.entry(dependent).or_default();
+            state.record_failure(&format!("dependency '{}' failed", name), false);
+            state.next_attempt = next_attempt;
+        }
+
+        record_system_event(
+            name,
+            "failed",
+            Severity::Error,
+            Some(json!({
+                "service": name,
+                "reason": reason,
+                "restart_in_secs": delay.as_secs(),
+                "stopped_dependents": dependents,
+            })),
+        )
+        .await;
+    }
+
+    /// Restart services whose backoff has elapsed, dependencies first
+    async fn restart_due_services(&mut self) {
+        let now = Instant::now();
+        let due: Vec<&'static str> = self
+            .supervision
+            .iter()
+            .filter(|(_, state)| state.is_due(now))
+            .map(|(name, _)| *name)
+            .collect();
+        if due.is_empty() {
+            return;
+        }
+
+        let ordered = match self.resolve_startup_order(&due) {
+            Ok(ordered) => ordered,
+            Err(e) => {
+                logger::error(
+                    LogTag::System,
+                    &format!("Service supervisor could not order restarts: {}", e),
+                );
+                return;
+            }
+        };
+
+        for name in ordered {
+            if self.handles.contains_key(name) {
+                continue;
+            }
+            let Some(state) = self.supervision.get(name) else {
+                // Dependency pulled in by ordering, never failed
+                continue;
+            };
+            if !state.is_due(now) {
+                continue;
+            }
+
+            if let Some(dependency) = self.stopped_dependency(name) {
+                self.wait_for_dependency(name, dependency);
+                continue;
+            }
+
+            let state = self.supervision.entry(name).or_default();
+            if !state.has_budget() {
+                state.info.gave_up = true;
+                state.next_attempt = None;
+                let reason = state.info.last_failure_reason.clone();
+                logger::error(
+                    LogTag::System,
+                    &format!(
+                        "Service '{}' exceeded {} restarts in {}s - leaving it stopped",
+                        name,
+                        RESTART_BUDGET,
+                        RESTART_WINDOW.as_secs()
+                    ),
+                );
+                record_system_event(
+                    name,
+                    "restart_budget_exhausted",
+                    Severity::Error,
+                    Some(json!({
+                        "service": name,
+                        "restart_budget": RESTART_BUDGET,
+                        "window_secs": RESTART_WINDOW.as_secs(),
+                        "last_failure": reason,
+                    })),
+                )
+                .await;
+                continue;
+            }
+
+            match self.restart_service(name).await {
+                Ok(handle_count) => {
+                    let state = self.supervision.entry(name).or_default();
+                    state.record_restart();
+                    let restart_count = state.info.restart_count;
+                    log_service_notice(
+                        name,
+                        "restarted",
+                        Some(&format!(
+                            "handles={} restart_count={}",
+                            handle_count, restart_count
+                        )),
+                        true,
+                    );
+                    record_system_event(
+                        name,
+                        "restarted",
+                        Severity::Warn,
+                        Some(json!({
+                            "service": name,
+                            "restart_count": restart_count,
+                        })),
+                    )
+                    .await;
+                }
+                Err(e) => {
+                    let reason = format!("restart failed: {}", e);
+                    let delay = self
+                        .supervision
+                        .entry(name)
+                        .or_default()
+                        .record_failure(&reason, true);
+                    logger::error(
+                        LogTag::System,
+                        &format!(
+                            "Service '{}' {} - retrying in {}s",
+                            name,
+                            reason,
+                            delay.as_secs()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Initialize and start a single service again
+    async fn restart_service(&mut self, name: &'static str) -> Result<usize, String> {
+        let monitor = self.get_task_monitor(name);
+        let shutdown = self.shutdown.clone();
+        let service = self
+            .services
+            .get_mut(name)
+            .ok_or_else(|| format!("service '{}' is not registered", name))?;
+
+        service.initialize().await?;
+        let handles = service.start(shutdown, monitor).await?;
+        let handle_count = handles.len();
+        self.handles.insert(name, handles);
+        Ok(handle_count)
+    }
+
+    async fn stop_supervised(&mut self, name: &'static str) {
+        if let Some(service) = self.services.get_mut(name) {
+            if let Err(e) = service.stop().await {
+                logger::warning(
+                    LogTag::System,
+                    &format!("Service stop error for {}: {}", name, e),
+                );
+            }
+        }
+    }
+
+    /// Running services that depend on `name`, directly or transitively
+    fn running_dependents(&self, name: &'static str) -> Vec<&'static str> {
+        let mut affected: HashSet<&'static str> = HashSet::from([name]);
+        let mut dependents = Vec::new();
+        loop {
+            let next: Vec<&'static str> = self
+                .handles
+                .keys()
+                .copied()
+                .filter(|candidate| !affected.contains(candidate))
+                .filter(|candidate| {
+                    self.services.get(candidate).map_or(false, |service| {
+                        service
+                            .dependencies()
+                            .iter()
+                            .any(|dep| affected.contains(dep))
+                    })
+                })
+                .collect();
+            if next.is_empty() {
+                break;
+            }
+            affected.extend(next.iter().copied());
+            dependents.extend(next);
+        }
+        dependents
+    }
+
+    /// First dependency of `name` that is stopped under supervision
+    fn stopped_dependency(&self, name: &'static str) -> Option<&'static str> {
+        self.services
+            .get(name)?
+            .dependencies()
+            .into_iter()
+            .find(|dep| !self.handles.contains_key(dep) && self.supervision.contains_key(dep))
+    }
+
+    /// Keep a dependent stopped until its dependency is back
+    fn wait_for_dependency(&mut self, name: &'static str, dependency: &'static str) {
+        let (dependency_gave_up, dependency_next) = self
+            .supervision
+            .get(dependency)
+            .map(|state| (state.info.gave_up, state.next_attempt))
+            .unwrap_or((false, None));
+
+        let state = self.supervision.entry(name).or_default();
+        if dependency_gave_up {
+            state.info.gave_up = true;
+            state.next_attempt = None;
+            state.info.last_failure_reason =
+                Some(format!("dependency '{}' was not restarted", dependency));
+        } else {
+            state.next_attempt = dependency_next;
+        }
+    }
+}
+
+/// Spawn the background task that supervises the global ServiceManager
+///
+/// Ticks while the manager is temporarily taken out of the global slot (during
+/// startup and shutdown) are skipped; the task ends on the shutdown signal.
+pub(super) fn spawn_supervisor(
+    manager: Arc<tokio::sync::RwLock<Option<ServiceManager>>>,
+    shutdown: Arc<Notify>,
+) {
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = shutdown.notified() => {
+                    logger::debug(LogTag::System, "Service supervisor stopped");
+                    break;
+                }
+                _ = tokio::time::sleep(SUPERVISOR_INTERVAL) => {}
+            }
+
+            let needs_supervision = match manager.read().await.as_ref() {
+                Some(manager) => manager.needs_supervision(),
+                None => false,
+            };
+            if !needs_supervision {
+                continue;
+            }
+
+            if let Some(manager) = manager.write().await.as_mut() {
+                manager.supervise().await;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Service;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::task::JoinHandle;
+
+    /// Service whose only task finishes (or panics) right after start
+    struct ShortLivedService {
+        starts: Arc<AtomicUsize>,
+        panics: bool,
+    }
+
+    #[async_trait]
+    impl Service for ShortLivedService {
+        fn name(&self) -> &'static str {
+            "short_lived"
+        }
+
+        async fn start(
+            &mut self,
+            _shutdown: Arc<Notify>,
+            _monitor: tokio_metrics::TaskMonitor,
+        ) -> Result<Vec<JoinHandle<()>>, String> {
+            self.starts.fetch_add(1, Ordering::SeqCst);
+            let panics = self.panics;
+            Ok(vec![tokio::spawn(async move {
+                if panics {
+                    panic!("boom");
+                }
+            })])
+        }
+    }
+
+    async fn started_manager(panics: bool) -> (ServiceManager, Arc<AtomicUsize>) {
+        let starts = Arc::new(AtomicUsize::new(0));
+        let mut manager = ServiceManager::new().await.unwrap();
+        manager.register(Box::new(ShortLivedService {
+            starts: starts.clone(),
+            panics,
+        }));
+        manager.restart_service("short_lived").await.unwrap();
+        while !manager.needs_supervision() {
+            tokio::task::yield_now().await;
+        }
+        (manager, starts)
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_cap() {
+        assert_eq!(backoff_delay(1), Duration::from_secs(2));
+        assert_eq!(backoff_delay(2), Duration::from_secs(4));
+        assert_eq!(backoff_delay(5), Duration::from_secs(32));
+        assert_eq!(backoff_delay(9), BACKOFF_MAX);
+        assert_eq!(backoff_delay(u32::MAX), BACKOFF_MAX);
+    }
+
+    #[test]
+    fn budget_limits_restarts_in_window() {
+        assert!(within_budget(0));
+        assert!(within_budget(RESTART_BUDGET - 1));
+        assert!(!within_budget(RESTART_BUDGET));
+    }
+
+    #[test]
+    fn health_reflects_supervision_state() {
+        let mut state = SupervisionState::default();
+        assert!(state.adjust_health(ServiceHealth::Healthy).is_healthy());
+
+        state.record_failure("task panicked: boom", true);
+        assert!(state.adjust_health(ServiceHealth::Healthy).is_unhealthy());
+
+        state.record_restart();
+        assert!(state.adjust_health(ServiceHealth::Healthy).is_degraded());
+
+        state.info.gave_up = true;
+        assert!(state.adjust_health(ServiceHealth::Healthy).is_unhealthy());
+    }
+
+    #[tokio::test]
+    async fn finished_task_is_not_a_failure() {
+        let (mut manager, starts) = started_manager(false).await;
+
+        assert!(manager.collect_failures().await.is_empty());
+        manager.restart_due_services().await;
+
+        assert_eq!(starts.load(Ordering::SeqCst), 1);
+        assert!(manager.supervision.is_empty());
+        assert!(manager.handles.contains_key("short_lived"));
+        assert!(!manager.needs_supervision());
+    }
+
+    #[tokio::test]
+    async fn panicked_task_is_a_failure() {
+        let (mut manager, _) = started_manager(true).await;
+
+        let failures = manager.collect_failures().await;
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, "short_lived");
+        assert!(failures[0].1.contains("boom"));
+        assert!(!manager.handles.contains_key("short_lived"));
+    }
+}
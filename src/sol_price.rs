@@ -282,6 +282,10 @@ async fn fetch_and_update_sol_price(consecutive_errors: &mut u32) {
         Ok(price) => {
             if validate_price_change(price) {
                 update_price_cache(price, "jupiter_api".to_string(), true).await;
+                // Per-minute history used to value trades in accounting exports
+                // (fails quietly until the positions database is initialized)
+                let _ =
+                    crate::positions::db::record_sol_price_sample(chrono::Utc::now(), price).await;
                 *consecutive_errors = 0; // Reset error counter on success
                 logger::debug(
                     LogTag::SolPrice,
@@ -0,0 +1,223 @@
+//! Strategy backtesting over stored OHLCV history
+//!
+//! Replays 1m candles from the OHLCV database through `StrategyEngine::evaluate_strategy`,
+//! simulates fills with a slippage/fee model and applies the trader's built-in exit
+//! rules (stop loss, trailing stop, ROI target, time override).
+//!
+//! ## Module Structure
+//!
+//! - `types`: Config, fill model, trade and report types
+//! - `replay`: Look-ahead free multi-timeframe bundle reconstruction
+//! - `simulator`: Per-token position simulation
+//! - `report`: Win rate, equity curve and drawdown statistics
+
+mod replay;
+mod report;
+mod simulator;
+pub mod types;
+
+pub use types::{
+    BacktestConfig, BacktestExitReason, BacktestReport, BacktestTrade, EquityPoint, ExitRules,
+    FillModel, MintReplaySummary,
+};
+
+use crate::logger::{self, LogTag};
+use crate::ohlcvs::{self, Timeframe};
+use crate::strategies::db;
+use crate::strategies::engine::{EngineConfig, StrategyEngine};
+use crate::strategies::types::{Strategy, StrategyType};
+use chrono::{TimeZone, Utc};
+use std::time::Instant;
+
+/// Engine settings for replay: no cache (every candle is a new context) and a
+/// generous timeout since evaluation runs offline.
+fn backtest_engine_config() -> EngineConfig {
+    EngineConfig {
+        evaluation_timeout_ms: 1_000,
+        cache_ttl_seconds: 0,
+        max_concurrent_evaluations: 1,
+    }
+}
+
+fn load_strategy(strategy_id: &str) -> Result<Strategy, String> {
+    db::get_strategy(strategy_id)?.ok_or_else(|| format!("Strategy not found: {}", strategy_id))
+}
+
+/// Run a backtest for a strategy and persist the report in `strategy_backtests`
+///
+/// ENTRY strategies open positions and exit via the built-in rules (plus an
+/// optional `exit_strategy_id`). EXIT strategies require an `entry_strategy_id`
+/// to generate entries.
+pub async fn run_backtest(
+    strategy_id: &str,
+    config: BacktestConfig,
+) -> Result<BacktestReport, String> {
+    config.validate()?;
+
+    let strategy = load_strategy(strategy_id)?;
+    let (entry_strategy, exit_strategy) = match strategy.strategy_type {
+        StrategyType::Entry => {
+            let exit = match &config.exit_strategy_id {
+                Some(id) => Some(load_strategy(id)?),
+                None => None,
+            };
+            (strategy.clone(), exit)
+        }
+        StrategyType::Exit => {
+            let entry_id = config.entry_strategy_id.as_deref().ok_or_else(|| {
+                "Backtesting an EXIT strategy requires entry_strategy_id".to_string()
+            })?;
+            (load_strategy(entry_id)?, Some(strategy.clone()))
+        }
+    };
+
+    if entry_strategy.strategy_type != StrategyType::Entry {
+        return Err(format!(
+            "Strategy {} is not an ENTRY strategy",
+            entry_strategy.id
+        ));
+    }
+    if let Some(exit) = &exit_strategy {
+        if exit.strategy_type != StrategyType::Exit {
+            return Err(format!("Strategy {} is not an EXIT strategy", exit.id));
+        }
+    }
+
+    let engine = StrategyEngine::new(backtest_engine_config());
+    engine.validate_strategy(&entry_strategy)?;
+    if let Some(exit) = &exit_strategy {
+        engine.validate_strategy(exit)?;
+    }
+
+    logger::info(
+        LogTag::System,
+        &format!(
+            "Backtest started: strategy={}, mints={}, window={}..{}",
+            strategy.id,
+            config.mints.len(),
+            config.start_timestamp,
+            config.end_timestamp
+        ),
+    );
+
+    let started = Instant::now();
+    let mut trades = Vec::new();
+    let mut mint_summaries = Vec::new();
+    let mut evaluation_errors = 0u64;
+
+    for mint in &config.mints {
+        let mut candles = match ohlcvs::get_candles_in_range(
+            mint,
+            Timeframe::Minute1,
+            Some(config.start_timestamp),
+            Some(config.end_timestamp),
+        )
+        .await
+        {
+            Ok(candles) => candles,
+            Err(e) => {
+                mint_summaries.push(MintReplaySummary {
+                    mint: mint.clone(),
+                    candles: 0,
+                    trades: 0,
+                    pnl_sol: 0.0,
+                    skipped_reason: Some(format!("Failed to load candles: {}", e)),
+                });
+                continue;
+            }
+        };
+
+        candles.retain(|c| c.is_valid() && c.open > 0.0);
+        candles.truncate(types::MAX_BACKTEST_CANDLES);
+
+        if candles.len() <= config.warmup_candles {
+            mint_summaries.push(MintReplaySummary {
+                mint: mint.clone(),
+                candles: candles.len(),
+                trades: 0,
+                pnl_sol: 0.0,
+                skipped_reason: Some(format!(
+                    "Not enough 1m candles ({} <= warmup {})",
+                    candles.len(),
+                    config.warmup_candles
+                )),
+            });
+            continue;
+        }
+
+        let result = simulator::simulate_mint(
+            &engine,
+            &entry_strategy,
+            exit_strategy.as_ref(),
+            &config,
+            mint,
+            &candles,
+        )
+        .await;
+
+        evaluation_errors += result.evaluation_errors;
+        mint_summaries.push(MintReplaySummary {
+            mint: mint.clone(),
+            candles: candles.len(),
+            trades: result.trades.len(),
+            pnl_sol: result.trades.iter().map(|t| t.pnl_sol).sum(),
+            skipped_reason: None,
+        });
+        trades.extend(result.trades);
+    }
+
+    trades.sort_by_key(|t| t.exit_time);
+
+    let stats = report::compute_trade_stats(&trades);
+    let equity_curve = report::build_equity_curve(&trades, config.initial_balance_sol);
+    let (max_drawdown_sol, max_drawdown_pct) =
+        report::max_drawdown(&equity_curve, config.initial_balance_sol);
+
+    let report = BacktestReport {
+        id: uuid::Uuid::new_v4().to_string(),
+        strategy_id: strategy.id.clone(),
+        strategy_name: strategy.name.clone(),
+        created_at: Utc::now(),
+        start_time: Utc
+            .timestamp_opt(config.start_timestamp, 0)
+            .single()
+            .unwrap_or_else(Utc::now),
+        end_time: Utc
+            .timestamp_opt(config.end_timestamp, 0)
+            .single()
+            .unwrap_or_else(Utc::now),
+        config,
+        total_trades: stats.total_trades,
+        win_trades: stats.win_trades,
+        loss_trades: stats.loss_trades,
+        win_rate_pct: stats.win_rate_pct,
+        total_profit_sol: stats.total_profit_sol,
+        avg_pnl_pct: stats.avg_pnl_pct,
+        best_trade_pct: stats.best_trade_pct,
+        worst_trade_pct: stats.worst_trade_pct,
+        avg_hold_seconds: stats.avg_hold_seconds,
+        max_drawdown_sol,
+        max_drawdown_pct,
+        evaluation_errors,
+        mints: mint_summaries,
+        equity_curve,
+        trades,
+    };
+
+    db::insert_backtest(&report)?;
+
+    logger::info(
+        LogTag::System,
+        &format!(
+            "Backtest finished: strategy={}, trades={}, win_rate={:.1}%, pnl={:.6} SOL, max_dd={:.2}%, elapsed={}ms",
+            report.strategy_id,
+            report.total_trades,
+            report.win_rate_pct,
+            report.total_profit_sol,
+            report.max_drawdown_pct,
+            started.elapsed().as_millis()
+        ),
+    );
+
+    Ok(report)
+}
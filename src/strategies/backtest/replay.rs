@@ -0,0 +1,118 @@
+use crate::ohlcvs::{Candle, Timeframe, TimeframeBundle, BUNDLE_CANDLE_COUNT};
+use chrono::{TimeZone, Utc};
+
+/// Rolling candle series for one timeframe, built incrementally from 1m candles
+struct RollingSeries {
+    timeframe: Timeframe,
+    closed: Vec<Candle>,
+    current: Option<Candle>,
+}
+
+impl RollingSeries {
+    fn new(timeframe: Timeframe) -> Self {
+        Self {
+            timeframe,
+            closed: Vec::new(),
+            current: None,
+        }
+    }
+
+    fn push(&mut self, candle: &Candle) {
+        let bucket_size = self.timeframe.to_seconds();
+        let bucket_start = (candle.timestamp / bucket_size) * bucket_size;
+
+        match self.current.as_mut() {
+            Some(current) if current.timestamp == bucket_start => {
+                current.high = current.high.max(candle.high);
+                current.low = current.low.min(candle.low);
+                current.close = candle.close;
+                current.volume += candle.volume;
+            }
+            _ => {
+                if let Some(done) = self.current.take() {
+                    self.closed.push(done);
+                    // Keep memory bounded - the bundle never needs more than this
+                    if self.closed.len() > BUNDLE_CANDLE_COUNT * 2 {
+                        let excess = self.closed.len() - BUNDLE_CANDLE_COUNT;
+                        self.closed.drain(..excess);
+                    }
+                }
+                self.current = Some(Candle::new(
+                    bucket_start,
+                    candle.open,
+                    candle.high,
+                    candle.low,
+                    candle.close,
+                    candle.volume,
+                ));
+            }
+        }
+    }
+
+    /// Last BUNDLE_CANDLE_COUNT candles including the in-progress bucket
+    fn snapshot(&self) -> Vec<Candle> {
+        let mut out: Vec<Candle> = Vec::with_capacity(BUNDLE_CANDLE_COUNT);
+        let take_closed = if self.current.is_some() {
+            BUNDLE_CANDLE_COUNT - 1
+        } else {
+            BUNDLE_CANDLE_COUNT
+        };
+        let start = self.closed.len().saturating_sub(take_closed);
+        out.extend_from_slice(&self.closed[start..]);
+        if let Some(current) = &self.current {
+            out.push(current.clone());
+        }
+        out
+    }
+}
+
+/// Replays 1m candles and produces the `TimeframeBundle` the live system would
+/// have seen at each point in time (no look-ahead: higher timeframes only contain
+/// data up to the current 1m candle, with the last bucket still forming).
+pub struct BundleReplayer {
+    mint: String,
+    series: Vec<RollingSeries>,
+}
+
+impl BundleReplayer {
+    pub fn new(mint: &str) -> Self {
+        Self {
+            mint: mint.to_string(),
+            series: Timeframe::all()
+                .into_iter()
+                .map(RollingSeries::new)
+                .collect(),
+        }
+    }
+
+    /// Advance the replay by one 1m candle
+    pub fn push(&mut self, candle: &Candle) {
+        for series in self.series.iter_mut() {
+            series.push(candle);
+        }
+    }
+
+    /// Build the bundle as of the last pushed candle
+    pub fn bundle(&self, as_of_timestamp: i64) -> TimeframeBundle {
+        let mut bundle = TimeframeBundle::new(self.mint.clone(), "backtest".to_string());
+        bundle.timestamp = Utc
+            .timestamp_opt(as_of_timestamp, 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+
+        for series in &self.series {
+            let candles = series.snapshot();
+            match series.timeframe {
+                Timeframe::Minute1 => bundle.m1 = candles,
+                Timeframe::Minute5 => bundle.m5 = candles,
+                Timeframe::Minute15 => bundle.m15 = candles,
+                Timeframe::Hour1 => bundle.h1 = candles,
+                Timeframe::Hour4 => bundle.h4 = candles,
+                Timeframe::Hour12 => bundle.h12 = candles,
+                Timeframe::Day1 => bundle.d1 = candles,
+            }
+        }
+
+        bundle
+    }
+}
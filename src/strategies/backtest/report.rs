@@ -0,0 +1,151 @@
+use crate::strategies::backtest::types::{BacktestTrade, EquityPoint};
+
+/// Aggregated trade statistics
+#[derive(Debug, Clone, Default)]
+pub struct TradeStats {
+    pub total_trades: usize,
+    pub win_trades: usize,
+    pub loss_trades: usize,
+    pub win_rate_pct: f64,
+    pub total_profit_sol: f64,
+    pub avg_pnl_pct: f64,
+    pub best_trade_pct: Option<f64>,
+    pub worst_trade_pct: Option<f64>,
+    pub avg_hold_seconds: f64,
+}
+
+/// Compute win rate, PnL and hold time statistics
+pub fn compute_trade_stats(trades: &[BacktestTrade]) -> TradeStats {
+    if trades.is_empty() {
+        return TradeStats::default();
+    }
+
+    let total_trades = trades.len();
+    let win_trades = trades.iter().filter(|t| t.pnl_sol > 0.0).count();
+    let loss_trades = total_trades - win_trades;
+    let total_profit_sol: f64 = trades.iter().map(|t| t.pnl_sol).sum();
+    let avg_pnl_pct = trades.iter().map(|t| t.pnl_pct).sum::<f64>() / total_trades as f64;
+    let avg_hold_seconds =
+        trades.iter().map(|t| t.hold_seconds as f64).sum::<f64>() / total_trades as f64;
+
+    let best_trade_pct = trades
+        .iter()
+        .map(|t| t.pnl_pct)
+        .fold(None, |acc, v| match acc {
+            Some(best) if best >= v => Some(best),
+            _ => Some(v),
+        });
+    let worst_trade_pct = trades
+        .iter()
+        .map(|t| t.pnl_pct)
+        .fold(None, |acc, v| match acc {
+            Some(worst) if worst <= v => Some(worst),
+            _ => Some(v),
+        });
+
+    TradeStats {
+        total_trades,
+        win_trades,
+        loss_trades,
+        win_rate_pct: (win_trades as f64 / total_trades as f64) * 100.0,
+        total_profit_sol,
+        avg_pnl_pct,
+        best_trade_pct,
+        worst_trade_pct,
+        avg_hold_seconds,
+    }
+}
+
+/// Build the equity curve from trades ordered by exit time
+pub fn build_equity_curve(trades: &[BacktestTrade], initial_balance_sol: f64) -> Vec<EquityPoint> {
+    let mut cumulative = 0.0;
+    trades
+        .iter()
+        .map(|trade| {
+            cumulative += trade.pnl_sol;
+            EquityPoint {
+                timestamp: trade.exit_time,
+                equity_sol: initial_balance_sol + cumulative,
+                cumulative_pnl_sol: cumulative,
+            }
+        })
+        .collect()
+}
+
+/// Maximum peak-to-trough decline of the equity curve as (SOL, percent of peak)
+pub fn max_drawdown(curve: &[EquityPoint], initial_balance_sol: f64) -> (f64, f64) {
+    let mut peak = initial_balance_sol;
+    let mut max_dd_sol = 0.0;
+    let mut max_dd_pct = 0.0;
+
+    for point in curve {
+        if point.equity_sol > peak {
+            peak = point.equity_sol;
+        }
+        let dd_sol = peak - point.equity_sol;
+        if dd_sol > max_dd_sol {
+            max_dd_sol = dd_sol;
+        }
+        if peak > 0.0 {
+            let dd_pct = (dd_sol / peak) * 100.0;
+            if dd_pct > max_dd_pct {
+                max_dd_pct = dd_pct;
+            }
+        }
+    }
+
+    (max_dd_sol, max_dd_pct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::backtest::types::BacktestExitReason;
+    use chrono::{TimeZone, Utc};
+
+    fn trade(exit_ts: i64, pnl_sol: f64) -> BacktestTrade {
+        BacktestTrade {
+            mint: "mint".to_string(),
+            entry_time: Utc.timestamp_opt(exit_ts - 60, 0).unwrap(),
+            exit_time: Utc.timestamp_opt(exit_ts, 0).unwrap(),
+            entry_price: 1.0,
+            exit_price: 1.0,
+            size_sol: 0.1,
+            proceeds_sol: 0.1 + pnl_sol,
+            pnl_sol,
+            pnl_pct: pnl_sol / 0.1 * 100.0,
+            peak_price: 1.0,
+            hold_seconds: 60,
+            exit_reason: BacktestExitReason::TakeProfit,
+        }
+    }
+
+    #[test]
+    fn stats_count_wins_and_losses() {
+        let trades = vec![trade(60, 0.02), trade(120, -0.01), trade(180, 0.03)];
+        let stats = compute_trade_stats(&trades);
+
+        assert_eq!(stats.total_trades, 3);
+        assert_eq!(stats.win_trades, 2);
+        assert_eq!(stats.loss_trades, 1);
+        assert!((stats.total_profit_sol - 0.04).abs() < 1e-12);
+        assert_eq!(stats.best_trade_pct, Some(30.0));
+        assert_eq!(stats.worst_trade_pct, Some(-10.0));
+    }
+
+    #[test]
+    fn drawdown_measures_peak_to_trough() {
+        let trades = vec![
+            trade(60, 0.5),
+            trade(120, -0.3),
+            trade(180, -0.2),
+            trade(240, 0.6),
+        ];
+        let curve = build_equity_curve(&trades, 1.0);
+        let (dd_sol, dd_pct) = max_drawdown(&curve, 1.0);
+
+        assert!((dd_sol - 0.5).abs() < 1e-12);
+        assert!((dd_pct - 100.0 / 3.0).abs() < 1e-9);
+        assert!((curve.last().unwrap().equity_sol - 1.6).abs() < 1e-12);
+    }
+}
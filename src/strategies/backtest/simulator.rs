@@ -0,0 +1,358 @@
+use crate::ohlcvs::Candle;
+use crate::strategies::backtest::replay::BundleReplayer;
+use crate::strategies::backtest::types::{
+    BacktestConfig, BacktestExitReason, BacktestTrade, ExitRules, FillModel,
+};
+use crate::strategies::engine::StrategyEngine;
+use crate::strategies::types::{EvaluationContext, PositionData, Strategy};
+use crate::trader::evaluators::exit_trailing::trailing_stop_triggered;
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Outcome of replaying a single token
+pub struct MintSimulationResult {
+    pub trades: Vec<BacktestTrade>,
+    pub evaluation_errors: u64,
+}
+
+/// Simulated open position
+struct OpenPosition {
+    entry_timestamp: i64,
+    entry_price: f64,
+    tokens: f64,
+    size_sol: f64,
+    peak_price: f64,
+}
+
+/// Replay one token's 1m candles through the entry/exit strategies
+///
+/// Signals are evaluated at candle close and filled at the next candle's open,
+/// so a strategy can never trade on data it has not seen yet. Built-in exit
+/// rules are checked intra-candle in the same priority order as the live exit
+/// evaluator (stop loss, trailing stop, ROI target, time override).
+pub async fn simulate_mint(
+    engine: &StrategyEngine,
+    entry_strategy: &Strategy,
+    exit_strategy: Option<&Strategy>,
+    config: &BacktestConfig,
+    mint: &str,
+    candles: &[Candle],
+) -> MintSimulationResult {
+    let mut replayer = BundleReplayer::new(mint);
+    let mut trades = Vec::new();
+    let mut evaluation_errors = 0u64;
+
+    let mut position: Option<OpenPosition> = None;
+    let mut pending_entry = false;
+    let mut pending_exit = false;
+
+    for (index, candle) in candles.iter().enumerate() {
+        replayer.push(candle);
+
+        // Fill signals raised on the previous candle at this candle's open
+        if pending_entry && position.is_none() {
+            pending_entry = false;
+            position = open_position(&config.fill_model, config.trade_size_sol, candle);
+        }
+        if pending_exit {
+            pending_exit = false;
+            if let Some(open) = position.take() {
+                trades.push(close_position(
+                    &config.fill_model,
+                    mint,
+                    open,
+                    candle.open,
+                    candle.timestamp,
+                    BacktestExitReason::StrategyExit,
+                ));
+            }
+        }
+
+        // Built-in exit rules against this candle's range
+        if let Some(open) = position.as_mut() {
+            if let Some((quote, reason)) = check_exit_rules(&config.exit_rules, open, candle) {
+                let open = position.take().expect("position checked above");
+                trades.push(close_position(
+                    &config.fill_model,
+                    mint,
+                    open,
+                    quote,
+                    candle.timestamp + 60,
+                    reason,
+                ));
+            } else {
+                open.peak_price = open.peak_price.max(candle.high);
+            }
+        }
+
+        let is_last = index + 1 == candles.len();
+        if index + 1 < config.warmup_candles || is_last {
+            continue;
+        }
+
+        let bundle = replayer.bundle(candle.timestamp + 60);
+
+        match position.as_ref() {
+            None => {
+                let context = EvaluationContext {
+                    token_mint: mint.to_string(),
+                    current_price: Some(candle.close),
+                    position_data: None,
+                    market_data: None,
+                    timeframe_bundle: Some(bundle),
+                    strategy_timeframe: entry_strategy.timeframe.clone(),
+                };
+                match engine.evaluate_strategy(entry_strategy, &context).await {
+                    Ok(result) => pending_entry = result.result,
+                    Err(_) => evaluation_errors += 1,
+                }
+            }
+            Some(open) => {
+                if let Some(exit_strategy) = exit_strategy {
+                    let age_seconds = (candle.timestamp + 60 - open.entry_timestamp).max(0);
+                    let context = EvaluationContext {
+                        token_mint: mint.to_string(),
+                        current_price: Some(candle.close),
+                        position_data: Some(PositionData {
+                            entry_price: open.entry_price,
+                            entry_time: to_datetime(open.entry_timestamp),
+                            current_size_sol: open.size_sol,
+                            unrealized_profit_pct: Some(
+                                (candle.close / open.entry_price - 1.0) * 100.0,
+                            ),
+                            position_age_hours: age_seconds as f64 / 3600.0,
+                        }),
+                        market_data: None,
+                        timeframe_bundle: Some(bundle),
+                        strategy_timeframe: exit_strategy.timeframe.clone(),
+                    };
+                    match engine.evaluate_strategy(exit_strategy, &context).await {
+                        Ok(result) => pending_exit = result.result,
+                        Err(_) => evaluation_errors += 1,
+                    }
+                }
+            }
+        }
+    }
+
+    // Anything still open is marked to market at the final close
+    if let (Some(open), Some(last)) = (position.take(), candles.last()) {
+        trades.push(close_position(
+            &config.fill_model,
+            mint,
+            open,
+            last.close,
+            last.timestamp + 60,
+            BacktestExitReason::EndOfData,
+        ));
+    }
+
+    MintSimulationResult {
+        trades,
+        evaluation_errors,
+    }
+}
+
+fn open_position(fill_model: &FillModel, size_sol: f64, candle: &Candle) -> Option<OpenPosition> {
+    if !candle.open.is_finite() || candle.open <= 0.0 {
+        return None;
+    }
+
+    let entry_price = fill_model.buy_price(candle.open);
+    let tokens = fill_model.tokens_for(size_sol, entry_price);
+    if tokens <= 0.0 {
+        return None;
+    }
+
+    Some(OpenPosition {
+        entry_timestamp: candle.timestamp,
+        entry_price,
+        tokens,
+        size_sol,
+        peak_price: candle.open,
+    })
+}
+
+fn close_position(
+    fill_model: &FillModel,
+    mint: &str,
+    open: OpenPosition,
+    quote_price: f64,
+    exit_timestamp: i64,
+    reason: BacktestExitReason,
+) -> BacktestTrade {
+    let exit_price = fill_model.sell_price(quote_price);
+    let proceeds_sol = fill_model.proceeds_for(open.tokens, exit_price);
+    let pnl_sol = proceeds_sol - open.size_sol;
+
+    BacktestTrade {
+        mint: mint.to_string(),
+        entry_time: to_datetime(open.entry_timestamp),
+        exit_time: to_datetime(exit_timestamp),
+        entry_price: open.entry_price,
+        exit_price,
+        size_sol: open.size_sol,
+        proceeds_sol,
+        pnl_sol,
+        pnl_pct: (pnl_sol / open.size_sol) * 100.0,
+        peak_price: open.peak_price,
+        hold_seconds: (exit_timestamp - open.entry_timestamp).max(0),
+        exit_reason: reason,
+    }
+}
+
+/// Check built-in exit rules for one candle, returning the quoted exit price
+///
+/// The candle path is unknown, so checks run pessimistically: losses are
+/// assumed to happen before gains within the same candle.
+fn check_exit_rules(
+    rules: &ExitRules,
+    position: &OpenPosition,
+    candle: &Candle,
+) -> Option<(f64, BacktestExitReason)> {
+    let entry = position.entry_price;
+    let age_seconds = candle.timestamp + 60 - position.entry_timestamp;
+
+    // Stop loss (fixed threshold from entry)
+    if rules.stop_loss_enabled && age_seconds >= rules.stop_loss_min_hold_seconds as i64 {
+        let stop_price = entry * (1.0 - rules.stop_loss_pct / 100.0);
+        if candle.low <= stop_price {
+            return Some((candle.open.min(stop_price), BacktestExitReason::StopLoss));
+        }
+    }
+
+    // Trailing stop - the live rule, checked at the open and then at the stop
+    // price on the way down to the low (lower prices only reduce the profit)
+    if rules.trailing_enabled && rules.trailing_distance_pct < rules.trailing_activation_pct {
+        let peak = position.peak_price.max(candle.open);
+        let stop_price = peak * (1.0 - rules.trailing_distance_pct / 100.0);
+        let mut path = vec![candle.open];
+        if stop_price < candle.open && stop_price >= candle.low {
+            path.push(stop_price);
+        }
+
+        for price in path {
+            if trailing_stop_triggered(
+                entry,
+                peak,
+                price,
+                rules.trailing_activation_pct,
+                rules.trailing_distance_pct,
+            ) {
+                return Some((price, BacktestExitReason::TrailingStop));
+            }
+        }
+    }
+
+    // ROI target
+    if rules.roi_enabled {
+        let target_price = entry * (1.0 + rules.roi_target_pct / 100.0);
+        if candle.high >= target_price {
+            return Some((
+                candle.open.max(target_price),
+                BacktestExitReason::TakeProfit,
+            ));
+        }
+    }
+
+    // Time override (held too long at a loss)
+    if rules.time_override_enabled
+        && rules.time_override_seconds > 0.0
+        && age_seconds as f64 >= rules.time_override_seconds
+    {
+        let pnl_pct = (candle.close / entry - 1.0) * 100.0;
+        if pnl_pct <= rules.time_override_loss_pct {
+            return Some((candle.close, BacktestExitReason::TimeOverride));
+        }
+    }
+
+    None
+}
+
+fn to_datetime(timestamp: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(timestamp, 0)
+        .single()
+        .unwrap_or_else(Utc::now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trailing_rules() -> ExitRules {
+        ExitRules {
+            roi_enabled: false,
+            roi_target_pct: 0.0,
+            stop_loss_enabled: false,
+            stop_loss_pct: 0.0,
+            stop_loss_min_hold_seconds: 0,
+            trailing_enabled: true,
+            trailing_activation_pct: 20.0,
+            trailing_distance_pct: 10.0,
+            time_override_enabled: false,
+            time_override_seconds: 0.0,
+            time_override_loss_pct: 0.0,
+        }
+    }
+
+    fn position(entry_price: f64, peak_price: f64) -> OpenPosition {
+        OpenPosition {
+            entry_timestamp: 0,
+            entry_price,
+            tokens: 1.0,
+            size_sol: entry_price,
+            peak_price,
+        }
+    }
+
+    fn flat_candle(price: f64) -> Candle {
+        Candle::new(600, price, price, price, price, 0.0)
+    }
+
+    #[test]
+    fn trailing_stop_matches_live_rule() {
+        let rules = trailing_rules();
+        let entry = 1.0;
+
+        for peak in [1.0f64, 1.1, 1.25, 1.4, 2.0] {
+            for step in 0..=30 {
+                let price = 0.9 + step as f64 * 0.05;
+                let live = trailing_stop_triggered(
+                    entry,
+                    peak.max(price),
+                    price,
+                    rules.trailing_activation_pct,
+                    rules.trailing_distance_pct,
+                );
+                let simulated =
+                    check_exit_rules(&rules, &position(entry, peak), &flat_candle(price));
+
+                assert_eq!(
+                    simulated.is_some(),
+                    live,
+                    "peak {} price {}: backtest and live trailing stop disagree",
+                    peak,
+                    price
+                );
+                if let Some((quote, reason)) = simulated {
+                    assert_eq!(reason, BacktestExitReason::TrailingStop);
+                    assert_eq!(quote, price);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn trailing_stop_fills_at_stop_inside_candle() {
+        let rules = trailing_rules();
+
+        // Peak 1.5 -> stop 1.35, still above the 1.2 activation price
+        let candle = Candle::new(600, 1.45, 1.45, 1.1, 1.15, 0.0);
+        let (quote, reason) = check_exit_rules(&rules, &position(1.0, 1.5), &candle).unwrap();
+        assert_eq!(reason, BacktestExitReason::TrailingStop);
+        assert!((quote - 1.35).abs() < 1e-9);
+
+        // Peak 1.3 -> stop 1.17 is below activation: live never fires, neither does replay
+        let candle = Candle::new(600, 1.25, 1.25, 1.1, 1.15, 0.0);
+        assert!(check_exit_rules(&rules, &position(1.0, 1.3), &candle).is_none());
+    }
+}
@@ -0,0 +1,248 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of 1m candles replayed per token in a single run
+pub const MAX_BACKTEST_CANDLES: usize = 60_000;
+
+/// Default number of 1m candles loaded before the first evaluation
+pub const DEFAULT_WARMUP_CANDLES: usize = 30;
+
+/// Backtest run configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestConfig {
+    /// Tokens to replay (each token is simulated independently)
+    pub mints: Vec<String>,
+    /// Start of the replay window (unix seconds, inclusive)
+    pub start_timestamp: i64,
+    /// End of the replay window (unix seconds, inclusive)
+    pub end_timestamp: i64,
+    /// SOL spent per simulated entry
+    pub trade_size_sol: f64,
+    /// Starting balance used for the equity curve and drawdown
+    pub initial_balance_sol: f64,
+    /// 1m candles required before the strategy is evaluated
+    pub warmup_candles: usize,
+    /// Optional entry strategy (required when backtesting an EXIT strategy)
+    pub entry_strategy_id: Option<String>,
+    /// Optional exit strategy evaluated alongside the built-in exit rules
+    pub exit_strategy_id: Option<String>,
+    pub fill_model: FillModel,
+    pub exit_rules: ExitRules,
+}
+
+impl BacktestConfig {
+    /// Build a config for the given window using the live trader/swap settings as defaults
+    pub fn from_config(mints: Vec<String>, start_timestamp: i64, end_timestamp: i64) -> Self {
+        let (trade_size_sol, slippage_pct) = crate::config::with_config(|cfg| {
+            (
+                cfg.trader.trade_size_sol,
+                cfg.swaps.slippage.quote_default_pct,
+            )
+        });
+
+        Self {
+            mints,
+            start_timestamp,
+            end_timestamp,
+            trade_size_sol,
+            initial_balance_sol: 1.0,
+            warmup_candles: DEFAULT_WARMUP_CANDLES,
+            entry_strategy_id: None,
+            exit_strategy_id: None,
+            fill_model: FillModel {
+                slippage_pct,
+                ..FillModel::default()
+            },
+            exit_rules: ExitRules::from_config(),
+        }
+    }
+
+    /// Validate the configuration before running
+    pub fn validate(&self) -> Result<(), String> {
+        if self.mints.is_empty() {
+            return Err("At least one token mint is required".to_string());
+        }
+        if self.end_timestamp <= self.start_timestamp {
+            return Err("end_timestamp must be after start_timestamp".to_string());
+        }
+        if !self.trade_size_sol.is_finite() || self.trade_size_sol <= 0.0 {
+            return Err(format!("Invalid trade_size_sol: {}", self.trade_size_sol));
+        }
+        if !self.initial_balance_sol.is_finite() || self.initial_balance_sol <= 0.0 {
+            return Err(format!(
+                "Invalid initial_balance_sol: {}",
+                self.initial_balance_sol
+            ));
+        }
+        if !(0.0..50.0).contains(&self.fill_model.slippage_pct) {
+            return Err("slippage_pct must be between 0 and 50".to_string());
+        }
+        if !(0.0..50.0).contains(&self.fill_model.swap_fee_pct) {
+            return Err("swap_fee_pct must be between 0 and 50".to_string());
+        }
+        if self.fill_model.network_fee_sol < 0.0 {
+            return Err("network_fee_sol must be non-negative".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Slippage and fee model applied to every simulated fill
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FillModel {
+    /// Adverse price movement applied to each fill (1.0 = 1%)
+    pub slippage_pct: f64,
+    /// DEX/router fee taken from each swap (0.25 = 0.25%)
+    pub swap_fee_pct: f64,
+    /// Network + priority fee per transaction in SOL
+    pub network_fee_sol: f64,
+}
+
+impl Default for FillModel {
+    fn default() -> Self {
+        Self {
+            slippage_pct: 1.0,
+            swap_fee_pct: 0.25,
+            network_fee_sol: 0.000_015,
+        }
+    }
+}
+
+impl FillModel {
+    /// Effective price paid for a buy at the quoted price
+    pub fn buy_price(&self, quoted_price: f64) -> f64 {
+        quoted_price * (1.0 + self.slippage_pct / 100.0)
+    }
+
+    /// Effective price received for a sell at the quoted price
+    pub fn sell_price(&self, quoted_price: f64) -> f64 {
+        quoted_price * (1.0 - self.slippage_pct / 100.0)
+    }
+
+    /// Tokens received for spending `size_sol` at `fill_price`
+    pub fn tokens_for(&self, size_sol: f64, fill_price: f64) -> f64 {
+        let spend = (size_sol - self.network_fee_sol).max(0.0);
+        spend * (1.0 - self.swap_fee_pct / 100.0) / fill_price
+    }
+
+    /// SOL received for selling `tokens` at `fill_price`
+    pub fn proceeds_for(&self, tokens: f64, fill_price: f64) -> f64 {
+        let gross = tokens * fill_price * (1.0 - self.swap_fee_pct / 100.0);
+        (gross - self.network_fee_sol).max(0.0)
+    }
+}
+
+/// Built-in trader exit rules applied during replay
+///
+/// Mirrors the live evaluators in `trader::evaluators` (stop loss, trailing stop,
+/// ROI target, time override). Defaults are taken from the current trader config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExitRules {
+    pub roi_enabled: bool,
+    pub roi_target_pct: f64,
+    pub stop_loss_enabled: bool,
+    pub stop_loss_pct: f64,
+    pub stop_loss_min_hold_seconds: u64,
+    pub trailing_enabled: bool,
+    pub trailing_activation_pct: f64,
+    pub trailing_distance_pct: f64,
+    pub time_override_enabled: bool,
+    pub time_override_seconds: f64,
+    pub time_override_loss_pct: f64,
+}
+
+impl ExitRules {
+    /// Snapshot the exit rules currently configured for the live trader
+    pub fn from_config() -> Self {
+        use crate::trader::config;
+        use crate::trader::evaluators::exit_stop_loss;
+
+        Self {
+            roi_enabled: config::is_roi_exit_enabled(),
+            roi_target_pct: config::get_target_profit_pct(),
+            stop_loss_enabled: exit_stop_loss::is_stop_loss_enabled(),
+            stop_loss_pct: exit_stop_loss::get_stop_loss_threshold_pct(),
+            stop_loss_min_hold_seconds: exit_stop_loss::get_stop_loss_min_hold_seconds(),
+            trailing_enabled: config::is_trailing_stop_enabled(),
+            trailing_activation_pct: config::get_trailing_stop_activation_pct(),
+            trailing_distance_pct: config::get_trailing_stop_distance_pct(),
+            time_override_enabled: config::is_time_override_enabled(),
+            time_override_seconds: config::get_time_override_duration_seconds(),
+            time_override_loss_pct: config::get_time_override_loss_threshold_pct(),
+        }
+    }
+}
+
+/// Reason a simulated trade was closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BacktestExitReason {
+    StopLoss,
+    TrailingStop,
+    TakeProfit,
+    TimeOverride,
+    StrategyExit,
+    EndOfData,
+}
+
+/// A single simulated round trip
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestTrade {
+    pub mint: String,
+    pub entry_time: DateTime<Utc>,
+    pub exit_time: DateTime<Utc>,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub size_sol: f64,
+    pub proceeds_sol: f64,
+    pub pnl_sol: f64,
+    pub pnl_pct: f64,
+    pub peak_price: f64,
+    pub hold_seconds: i64,
+    pub exit_reason: BacktestExitReason,
+}
+
+/// Point on the cumulative equity curve (recorded at each trade exit)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquityPoint {
+    pub timestamp: DateTime<Utc>,
+    pub equity_sol: f64,
+    pub cumulative_pnl_sol: f64,
+}
+
+/// Per-token replay summary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MintReplaySummary {
+    pub mint: String,
+    pub candles: usize,
+    pub trades: usize,
+    pub pnl_sol: f64,
+    pub skipped_reason: Option<String>,
+}
+
+/// Complete backtest result for one strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestReport {
+    pub id: String,
+    pub strategy_id: String,
+    pub strategy_name: String,
+    pub created_at: DateTime<Utc>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub config: BacktestConfig,
+    pub total_trades: usize,
+    pub win_trades: usize,
+    pub loss_trades: usize,
+    pub win_rate_pct: f64,
+    pub total_profit_sol: f64,
+    pub avg_pnl_pct: f64,
+    pub best_trade_pct: Option<f64>,
+    pub worst_trade_pct: Option<f64>,
+    pub avg_hold_seconds: f64,
+    pub max_drawdown_sol: f64,
+    pub max_drawdown_pct: f64,
+    pub evaluation_errors: u64,
+    pub mints: Vec<MintReplaySummary>,
+    pub equity_curve: Vec<EquityPoint>,
+    pub trades: Vec<BacktestTrade>,
+}
@@ -0,0 +1,480 @@
+//! Portable strategy bundles
+//!
+//! A bundle packs strategies and templates into a single versioned JSON
+//! document so they can be moved between machines. The bundle carries a
+//! SHA-256 content hash over its payload; imports refuse bundles whose content
+//! no longer matches the hash, and check every condition type against the
+//! local `ConditionRegistry` before anything is written.
+
+use crate::strategies::conditions::ConditionRegistry;
+use crate::strategies::types::{RuleTree, Strategy, StrategyTemplate};
+use crate::strategies::{self, db};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+
+/// Identifies a JSON document as a strategy bundle
+pub const BUNDLE_FORMAT: &str = "screenerbot-strategy-bundle";
+
+/// Current bundle schema version (bump when the payload layout changes)
+pub const BUNDLE_SCHEMA_VERSION: u32 = 1;
+
+/// Versioned export of strategies and templates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyBundle {
+    pub format: String,
+    pub schema_version: u32,
+    /// Application version that produced the bundle (informational)
+    pub app_version: String,
+    pub exported_at: DateTime<Utc>,
+    pub strategies: Vec<Strategy>,
+    #[serde(default)]
+    pub templates: Vec<StrategyTemplate>,
+    /// `sha256:<hex>` over the canonical payload (format, schema version, strategies, templates)
+    pub content_hash: String,
+}
+
+impl StrategyBundle {
+    /// Build a bundle and compute its content hash
+    pub fn new(
+        strategies: Vec<Strategy>,
+        templates: Vec<StrategyTemplate>,
+    ) -> Result<Self, String> {
+        let mut bundle = Self {
+            format: BUNDLE_FORMAT.to_string(),
+            schema_version: BUNDLE_SCHEMA_VERSION,
+            app_version: crate::version::VERSION.to_string(),
+            exported_at: Utc::now(),
+            strategies,
+            templates,
+            content_hash: String::new(),
+        };
+        bundle.content_hash = bundle.compute_hash()?;
+        Ok(bundle)
+    }
+
+    /// Hash the bundle payload
+    ///
+    /// Serializing through `serde_json::Value` sorts object keys, so the hash
+    /// does not depend on `HashMap` iteration order.
+    pub fn compute_hash(&self) -> Result<String, String> {
+        let payload = json!({
+            "format": self.format,
+            "schema_version": self.schema_version,
+            "strategies": serde_json::to_value(&self.strategies)
+                .map_err(|e| format!("Failed to serialize strategies: {}", e))?,
+            "templates": serde_json::to_value(&self.templates)
+                .map_err(|e| format!("Failed to serialize templates: {}", e))?,
+        });
+        let bytes = serde_json::to_vec(&payload)
+            .map_err(|e| format!("Failed to serialize bundle: {}", e))?;
+
+        Ok(format!("sha256:{:x}", Sha256::digest(bytes)))
+    }
+
+    /// Check format, schema version and content hash
+    pub fn verify(&self) -> Result<(), String> {
+        if self.format != BUNDLE_FORMAT {
+            return Err(format!("Not a strategy bundle (format '{}')", self.format));
+        }
+        if self.schema_version > BUNDLE_SCHEMA_VERSION {
+            return Err(format!(
+                "Bundle schema version {} is newer than supported version {}",
+                self.schema_version, BUNDLE_SCHEMA_VERSION
+            ));
+        }
+        let expected = self.compute_hash()?;
+        if expected != self.content_hash {
+            return Err(
+                "Bundle content hash mismatch (file was modified or corrupted)".to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Import behaviour
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportOptions {
+    /// Explicit source ID -> local ID mapping (applies to strategies and templates)
+    #[serde(default)]
+    pub id_map: HashMap<String, String>,
+    /// Pick a free `<id>-imported[-N]` ID instead of reporting a conflict
+    #[serde(default)]
+    pub remap_conflicts: bool,
+    /// Store imported strategies disabled regardless of their exported state
+    #[serde(default)]
+    pub import_disabled: bool,
+    /// Only report what would happen
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleItemKind {
+    Strategy,
+    Template,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportStatus {
+    /// Would be imported (dry run)
+    Ready,
+    Imported,
+    /// Target ID already exists locally
+    Conflict,
+    /// Uses condition types or parameters this installation does not accept
+    Incompatible,
+    Failed,
+}
+
+/// Outcome for one bundle item
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportItemReport {
+    pub kind: BundleItemKind,
+    pub source_id: String,
+    pub target_id: String,
+    pub name: String,
+    pub status: ImportStatus,
+    pub issues: Vec<String>,
+}
+
+/// Import result
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub schema_version: u32,
+    pub imported: usize,
+    pub skipped: usize,
+    pub items: Vec<ImportItemReport>,
+}
+
+/// Collect condition problems in a rule tree against a registry
+///
+/// Unlike engine validation this does not stop at the first problem, so the
+/// import report can list every unknown type and invalid parameter at once.
+pub fn rule_issues(rules: &RuleTree, registry: &ConditionRegistry) -> Vec<String> {
+    let mut issues = Vec::new();
+    collect_rule_issues(rules, registry, &mut issues);
+    issues
+}
+
+fn collect_rule_issues(rules: &RuleTree, registry: &ConditionRegistry, issues: &mut Vec<String>) {
+    if let Some(condition) = &rules.condition {
+        match registry.get(&condition.condition_type) {
+            Some(evaluator) => {
+                if let Err(e) = evaluator.validate(condition) {
+                    issues.push(format!("{}: {}", condition.condition_type, e));
+                }
+            }
+            None => issues.push(format!(
+                "Unknown condition type: {}",
+                condition.condition_type
+            )),
+        }
+    }
+
+    for child in rules.conditions.iter().flatten() {
+        collect_rule_issues(child, registry, issues);
+    }
+}
+
+/// Decide the local ID for an imported item
+///
+/// `taken` reports whether an ID is already used locally (or earlier in the
+/// same import).
+pub fn resolve_target_id(
+    source_id: &str,
+    options: &ImportOptions,
+    taken: impl Fn(&str) -> bool,
+) -> Result<String, String> {
+    let target = options
+        .id_map
+        .get(source_id)
+        .map(|id| id.trim().to_string())
+        .unwrap_or_else(|| source_id.to_string());
+
+    if target.is_empty() {
+        return Err(format!("Empty target ID for '{}'", source_id));
+    }
+
+    if !taken(&target) {
+        return Ok(target);
+    }
+
+    if !options.remap_conflicts {
+        return Err(format!(
+            "ID '{}' already exists (map it to a new ID or enable remap_conflicts)",
+            target
+        ));
+    }
+
+    let base = format!("{}-imported", target);
+    if !taken(&base) {
+        return Ok(base);
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|candidate| !taken(candidate))
+        .ok_or_else(|| format!("No free ID for '{}'", target))
+}
+
+/// Export strategies and templates into a bundle
+///
+/// `None` exports everything of that kind; unknown IDs are an error.
+pub fn export_bundle(
+    strategy_ids: Option<&[String]>,
+    template_ids: Option<&[String]>,
+) -> Result<StrategyBundle, String> {
+    let strategies = match strategy_ids {
+        None => db::get_all_strategies()?,
+        Some(ids) => ids
+            .iter()
+            .map(|id| db::get_strategy(id)?.ok_or_else(|| format!("Strategy not found: {}", id)))
+            .collect::<Result<Vec<_>, String>>()?,
+    };
+
+    let templates = match template_ids {
+        None => db::get_all_templates()?,
+        Some(ids) => ids
+            .iter()
+            .map(|id| db::get_template(id)?.ok_or_else(|| format!("Template not found: {}", id)))
+            .collect::<Result<Vec<_>, String>>()?,
+    };
+
+    StrategyBundle::new(strategies, templates)
+}
+
+/// Validate and import a bundle
+///
+/// Items are handled independently: incompatible or conflicting items are
+/// reported and skipped while the rest are imported. Imported strategies start
+/// a fresh revision history at version 1.
+pub async fn import_bundle(
+    bundle: &StrategyBundle,
+    options: &ImportOptions,
+) -> Result<ImportReport, String> {
+    bundle.verify()?;
+
+    let now = Utc::now();
+    let mut items = Vec::new();
+
+    let mut used_strategy_ids: HashSet<String> = HashSet::new();
+    for source in &bundle.strategies {
+        let issues = strategies::check_rules_compatibility(&source.rules).await?;
+
+        let target = resolve_target_id(&source.id, options, |id| {
+            used_strategy_ids.contains(id) || matches!(db::get_strategy(id), Ok(Some(_)))
+        });
+
+        let mut report = ImportItemReport {
+            kind: BundleItemKind::Strategy,
+            source_id: source.id.clone(),
+            target_id: target.clone().unwrap_or_else(|_| source.id.clone()),
+            name: source.name.clone(),
+            status: ImportStatus::Ready,
+            issues,
+        };
+
+        if !report.issues.is_empty() {
+            report.status = ImportStatus::Incompatible;
+        } else if let Err(e) = target {
+            report.status = ImportStatus::Conflict;
+            report.issues.push(e);
+        } else {
+            used_strategy_ids.insert(report.target_id.clone());
+
+            if !options.dry_run {
+                let strategy = Strategy {
+                    id: report.target_id.clone(),
+                    enabled: source.enabled && !options.import_disabled,
+                    created_at: now,
+                    updated_at: now,
+                    version: 1,
+                    ..source.clone()
+                };
+                match db::insert_strategy(&strategy) {
+                    Ok(()) => report.status = ImportStatus::Imported,
+                    Err(e) => {
+                        report.status = ImportStatus::Failed;
+                        report.issues.push(e);
+                    }
+                }
+            }
+        }
+
+        items.push(report);
+    }
+
+    let mut used_template_ids: HashSet<String> = HashSet::new();
+    for source in &bundle.templates {
+        let issues = strategies::check_rules_compatibility(&source.rules).await?;
+
+        let target = resolve_target_id(&source.id, options, |id| {
+            used_template_ids.contains(id) || matches!(db::get_template(id), Ok(Some(_)))
+        });
+
+        let mut report = ImportItemReport {
+            kind: BundleItemKind::Template,
+            source_id: source.id.clone(),
+            target_id: target.clone().unwrap_or_else(|_| source.id.clone()),
+            name: source.name.clone(),
+            status: ImportStatus::Ready,
+            issues,
+        };
+
+        if !report.issues.is_empty() {
+            report.status = ImportStatus::Incompatible;
+        } else if let Err(e) = target {
+            report.status = ImportStatus::Conflict;
+            report.issues.push(e);
+        } else {
+            used_template_ids.insert(report.target_id.clone());
+
+            if !options.dry_run {
+                let template = StrategyTemplate {
+                    id: report.target_id.clone(),
+                    ..source.clone()
+                };
+                match db::insert_template(&template) {
+                    Ok(()) => report.status = ImportStatus::Imported,
+                    Err(e) => {
+                        report.status = ImportStatus::Failed;
+                        report.issues.push(e);
+                    }
+                }
+            }
+        }
+
+        items.push(report);
+    }
+
+    let imported = items
+        .iter()
+        .filter(|item| matches!(item.status, ImportStatus::Imported | ImportStatus::Ready))
+        .count();
+
+    Ok(ImportReport {
+        dry_run: options.dry_run,
+        schema_version: bundle.schema_version,
+        imported,
+        skipped: items.len() - imported,
+        items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::types::{Condition, LogicalOperator, Parameter, StrategyType};
+
+    fn condition(condition_type: &str, params: &[(&str, serde_json::Value)]) -> RuleTree {
+        RuleTree::leaf(Condition {
+            condition_type: condition_type.to_string(),
+            parameters: params
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.to_string(),
+                        Parameter {
+                            value: value.clone(),
+                            default: value.clone(),
+                            constraints: None,
+                        },
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    fn strategy(id: &str) -> Strategy {
+        let now = Utc::now();
+        Strategy {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            strategy_type: StrategyType::Entry,
+            enabled: true,
+            priority: 10,
+            timeframe: "5m".to_string(),
+            rules: condition(
+                "Formula",
+                &[("expression", json!("close > sma(close, 20)"))],
+            ),
+            parameters: HashMap::from([("a".to_string(), json!(1)), ("b".to_string(), json!(2))]),
+            created_at: now,
+            updated_at: now,
+            author: None,
+            version: 3,
+        }
+    }
+
+    #[test]
+    fn hash_detects_modification() {
+        let mut bundle =
+            StrategyBundle::new(vec![strategy("one"), strategy("two")], vec![]).expect("bundle");
+        assert!(bundle.content_hash.starts_with("sha256:"));
+        assert!(bundle.verify().is_ok());
+
+        // Round trip through JSON keeps the hash valid
+        let text = serde_json::to_string(&bundle).unwrap();
+        let parsed: StrategyBundle = serde_json::from_str(&text).unwrap();
+        assert!(parsed.verify().is_ok());
+
+        bundle.strategies[0].enabled = false;
+        assert!(bundle.verify().is_err());
+
+        let mut newer = parsed.clone();
+        newer.schema_version = BUNDLE_SCHEMA_VERSION + 1;
+        assert!(newer.verify().unwrap_err().contains("newer"));
+    }
+
+    #[test]
+    fn reports_every_incompatible_condition() {
+        let registry = ConditionRegistry::new();
+        let rules = RuleTree::branch(
+            LogicalOperator::And,
+            vec![
+                condition("Formula", &[("expression", json!("close > open"))]),
+                condition("NotARealCondition", &[]),
+                condition("Formula", &[("expression", json!("close +"))]),
+            ],
+        );
+
+        let issues = rule_issues(&rules, &registry);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].contains("Unknown condition type: NotARealCondition"));
+        assert!(issues[1].starts_with("Formula:"));
+    }
+
+    #[test]
+    fn resolves_ids_with_map_and_conflict_remapping() {
+        let existing = ["alpha", "alpha-imported", "beta"];
+        let taken = |id: &str| existing.contains(&id);
+
+        let mut options = ImportOptions::default();
+        assert_eq!(
+            resolve_target_id("gamma", &options, taken).unwrap(),
+            "gamma"
+        );
+        assert!(resolve_target_id("alpha", &options, taken).is_err());
+
+        options
+            .id_map
+            .insert("beta".to_string(), "beta-team".to_string());
+        assert_eq!(
+            resolve_target_id("beta", &options, taken).unwrap(),
+            "beta-team"
+        );
+
+        options.remap_conflicts = true;
+        assert_eq!(
+            resolve_target_id("alpha", &options, taken).unwrap(),
+            "alpha-imported-2"
+        );
+    }
+}
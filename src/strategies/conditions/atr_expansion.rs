@@ -0,0 +1,130 @@
+use crate::strategies::conditions::{
+    get_candles_for_timeframe, get_param_f64, get_param_string_optional, validate_timeframe_param,
+    ConditionEvaluator,
+};
+use crate::strategies::indicators;
+use crate::strategies::types::{Condition, EvaluationContext};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Detect volatility expansion: current ATR well above its recent average
+pub struct AtrExpansionCondition;
+
+#[async_trait]
+impl ConditionEvaluator for AtrExpansionCondition {
+    fn condition_type(&self) -> &'static str {
+        "AtrExpansion"
+    }
+
+    async fn evaluate(
+        &self,
+        condition: &Condition,
+        context: &EvaluationContext,
+    ) -> Result<bool, String> {
+        let period = get_param_f64(condition, "period")? as usize;
+        let lookback = get_param_f64(condition, "lookback")? as usize;
+        let multiplier = get_param_f64(condition, "multiplier")?;
+        let timeframe = get_param_string_optional(condition, "timeframe");
+
+        let candles = get_candles_for_timeframe(context, timeframe.as_deref())?;
+
+        let atr = indicators::atr_series(&candles, period);
+        if atr.len() < lookback + 1 {
+            return Err(format!(
+                "Not enough candles for ATR expansion: {} < {}",
+                candles.len(),
+                period + lookback + 1
+            ));
+        }
+
+        // Average ATR over the lookback window (excluding current)
+        let current_atr = atr[atr.len() - 1];
+        let baseline = &atr[atr.len() - 1 - lookback..atr.len() - 1];
+        let avg_atr = baseline.iter().sum::<f64>() / baseline.len() as f64;
+
+        if avg_atr <= 0.0 {
+            return Err("Average ATR is zero or negative".to_string());
+        }
+
+        Ok(current_atr / avg_atr >= multiplier)
+    }
+
+    fn validate(&self, condition: &Condition) -> Result<(), String> {
+        // Validate timeframe if provided
+        validate_timeframe_param(condition)?;
+
+        let period = get_param_f64(condition, "period")?;
+        if period < 2.0 || period > 100.0 {
+            return Err("Period must be between 2 and 100".to_string());
+        }
+
+        let lookback = get_param_f64(condition, "lookback")?;
+        if lookback < 2.0 || lookback > 100.0 {
+            return Err("Lookback must be between 2 and 100".to_string());
+        }
+
+        let multiplier = get_param_f64(condition, "multiplier")?;
+        if multiplier < 1.0 || multiplier > 10.0 {
+            return Err("Multiplier must be between 1.0 and 10.0".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn parameter_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "AtrExpansion",
+            "name": "ATR Expansion",
+            "category": "Technical Indicators",
+            "tags": ["atr", "volatility", "expansion", "technical"],
+            "icon": "icon-maximize",
+            "origin": "strategy",
+            "description": "Detect volatility expansion: current Average True Range above its recent average",
+            "parameters": {
+                "timeframe": {
+                    "type": "enum",
+                    "name": "Timeframe",
+                    "description": "Candle timeframe to analyze (defaults to strategy timeframe if not set)",
+                    "default": null,
+                    "optional": true,
+                    "options": [
+                        { "value": "1m", "label": "1 Minute" },
+                        { "value": "5m", "label": "5 Minutes" },
+                        { "value": "15m", "label": "15 Minutes" },
+                        { "value": "1h", "label": "1 Hour" },
+                        { "value": "4h", "label": "4 Hours" },
+                        { "value": "12h", "label": "12 Hours" },
+                        { "value": "1d", "label": "1 Day" }
+                    ]
+                },
+                "period": {
+                    "type": "number",
+                    "name": "ATR Period",
+                    "description": "Number of candles for ATR smoothing",
+                    "default": 14,
+                    "min": 2,
+                    "max": 100,
+                    "step": 1
+                },
+                "lookback": {
+                    "type": "number",
+                    "name": "Lookback Period",
+                    "description": "Number of previous ATR values to average as the baseline",
+                    "default": 20,
+                    "min": 2,
+                    "max": 100,
+                    "step": 1
+                },
+                "multiplier": {
+                    "type": "number",
+                    "name": "ATR Multiplier",
+                    "description": "How many times above the baseline ATR (e.g., 1.5 = 150% of average)",
+                    "default": 1.5,
+                    "min": 1.0,
+                    "max": 10.0,
+                    "step": 0.1
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,153 @@
+use crate::strategies::conditions::{
+    get_candles_for_timeframe, get_param_f64, get_param_string, get_param_string_optional,
+    validate_timeframe_param, ConditionEvaluator,
+};
+use crate::strategies::indicators;
+use crate::strategies::types::{Condition, EvaluationContext};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Bollinger Band squeeze and breakout detection
+pub struct BollingerBandsCondition;
+
+#[async_trait]
+impl ConditionEvaluator for BollingerBandsCondition {
+    fn condition_type(&self) -> &'static str {
+        "BollingerBands"
+    }
+
+    async fn evaluate(
+        &self,
+        condition: &Condition,
+        context: &EvaluationContext,
+    ) -> Result<bool, String> {
+        let period = get_param_f64(condition, "period")? as usize;
+        let std_dev = get_param_f64(condition, "std_dev")?;
+        let mode = get_param_string(condition, "mode")?;
+        let squeeze_width = get_param_f64(condition, "squeeze_width")?;
+        let timeframe = get_param_string_optional(condition, "timeframe");
+
+        let candles = get_candles_for_timeframe(context, timeframe.as_deref())?;
+        let closes = indicators::closes(&candles);
+
+        if closes.len() < period {
+            return Err(format!(
+                "Not enough candles for Bollinger Bands: {} < {}",
+                closes.len(),
+                period
+            ));
+        }
+
+        let bands = indicators::bollinger(&closes, period, std_dev)
+            .ok_or_else(|| "Bollinger Bands unavailable (non-positive prices)".to_string())?;
+
+        let price = context
+            .current_price
+            .or_else(|| closes.last().copied())
+            .ok_or_else(|| "Current price not available".to_string())?;
+
+        let result = match mode.as_str() {
+            "SQUEEZE" => bands.bandwidth_pct <= squeeze_width,
+            "BREAKOUT_UPPER" => price > bands.upper,
+            "BREAKOUT_LOWER" => price < bands.lower,
+            _ => return Err(format!("Invalid mode: {}", mode)),
+        };
+
+        Ok(result)
+    }
+
+    fn validate(&self, condition: &Condition) -> Result<(), String> {
+        // Validate timeframe if provided
+        validate_timeframe_param(condition)?;
+
+        let period = get_param_f64(condition, "period")?;
+        if period < 5.0 || period > 200.0 {
+            return Err("Period must be between 5 and 200".to_string());
+        }
+
+        let std_dev = get_param_f64(condition, "std_dev")?;
+        if std_dev < 0.5 || std_dev > 5.0 {
+            return Err("Standard deviations must be between 0.5 and 5.0".to_string());
+        }
+
+        let squeeze_width = get_param_f64(condition, "squeeze_width")?;
+        if squeeze_width <= 0.0 || squeeze_width > 100.0 {
+            return Err("Squeeze width must be between 0 and 100%".to_string());
+        }
+
+        let mode = get_param_string(condition, "mode")?;
+        if !["SQUEEZE", "BREAKOUT_UPPER", "BREAKOUT_LOWER"].contains(&mode.as_str()) {
+            return Err(format!("Invalid mode: {}", mode));
+        }
+
+        Ok(())
+    }
+
+    fn parameter_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "BollingerBands",
+            "name": "Bollinger Bands",
+            "category": "Technical Indicators",
+            "tags": ["bollinger", "volatility", "squeeze", "breakout", "technical"],
+            "icon": "icon-columns-2",
+            "origin": "strategy",
+            "description": "Detect a volatility squeeze (narrow bands) or price breaking out of the upper/lower band",
+            "parameters": {
+                "timeframe": {
+                    "type": "enum",
+                    "name": "Timeframe",
+                    "description": "Candle timeframe to analyze (defaults to strategy timeframe if not set)",
+                    "default": null,
+                    "optional": true,
+                    "options": [
+                        { "value": "1m", "label": "1 Minute" },
+                        { "value": "5m", "label": "5 Minutes" },
+                        { "value": "15m", "label": "15 Minutes" },
+                        { "value": "1h", "label": "1 Hour" },
+                        { "value": "4h", "label": "4 Hours" },
+                        { "value": "12h", "label": "12 Hours" },
+                        { "value": "1d", "label": "1 Day" }
+                    ]
+                },
+                "period": {
+                    "type": "number",
+                    "name": "Period",
+                    "description": "Number of candles for the middle band (SMA)",
+                    "default": 20,
+                    "min": 5,
+                    "max": 200,
+                    "step": 1
+                },
+                "std_dev": {
+                    "type": "number",
+                    "name": "Standard Deviations",
+                    "description": "Band distance from the middle band in standard deviations",
+                    "default": 2.0,
+                    "min": 0.5,
+                    "max": 5.0,
+                    "step": 0.5
+                },
+                "mode": {
+                    "type": "enum",
+                    "name": "Mode",
+                    "description": "Band condition to detect",
+                    "default": "BREAKOUT_UPPER",
+                    "options": [
+                        { "value": "SQUEEZE", "label": "Squeeze (bands narrower than width)" },
+                        { "value": "BREAKOUT_UPPER", "label": "Price Above Upper Band" },
+                        { "value": "BREAKOUT_LOWER", "label": "Price Below Lower Band" }
+                    ]
+                },
+                "squeeze_width": {
+                    "type": "percent",
+                    "name": "Squeeze Width %",
+                    "description": "Maximum band width (upper - lower, % of middle) that counts as a squeeze",
+                    "default": 5.0,
+                    "min": 0.5,
+                    "max": 100.0,
+                    "step": 0.5
+                }
+            }
+        })
+    }
+}
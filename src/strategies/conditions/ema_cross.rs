@@ -0,0 +1,147 @@
+use crate::strategies::conditions::{
+    get_candles_for_timeframe, get_param_f64, get_param_string, get_param_string_optional,
+    validate_timeframe_param, ConditionEvaluator,
+};
+use crate::strategies::indicators;
+use crate::strategies::types::{Condition, EvaluationContext};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Fast EMA crossing the slow EMA (golden / death cross)
+pub struct EmaCrossCondition;
+
+#[async_trait]
+impl ConditionEvaluator for EmaCrossCondition {
+    fn condition_type(&self) -> &'static str {
+        "EmaCross"
+    }
+
+    async fn evaluate(
+        &self,
+        condition: &Condition,
+        context: &EvaluationContext,
+    ) -> Result<bool, String> {
+        let fast = get_param_f64(condition, "fast_period")? as usize;
+        let slow = get_param_f64(condition, "slow_period")? as usize;
+        let direction = get_param_string(condition, "direction")?;
+        let within = get_param_f64(condition, "within_candles")? as usize;
+        let timeframe = get_param_string_optional(condition, "timeframe");
+
+        let candles = get_candles_for_timeframe(context, timeframe.as_deref())?;
+        let closes = indicators::closes(&candles);
+
+        let fast_ema = indicators::ema_series(&closes, fast);
+        let slow_ema = indicators::ema_series(&closes, slow);
+        if slow_ema.len() < 2 {
+            return Err(format!(
+                "Not enough candles for EMA cross: {} < {}",
+                candles.len(),
+                slow + 1
+            ));
+        }
+
+        let result = match direction.as_str() {
+            "BULLISH" => indicators::crossed_within(&fast_ema, &slow_ema, within, true),
+            "BEARISH" => indicators::crossed_within(&fast_ema, &slow_ema, within, false),
+            _ => return Err(format!("Invalid direction: {}", direction)),
+        };
+
+        Ok(result)
+    }
+
+    fn validate(&self, condition: &Condition) -> Result<(), String> {
+        // Validate timeframe if provided
+        validate_timeframe_param(condition)?;
+
+        let fast = get_param_f64(condition, "fast_period")?;
+        let slow = get_param_f64(condition, "slow_period")?;
+        if fast < 2.0 || fast > 100.0 {
+            return Err("Fast period must be between 2 and 100".to_string());
+        }
+        if slow < 3.0 || slow > 200.0 {
+            return Err("Slow period must be between 3 and 200".to_string());
+        }
+        if fast >= slow {
+            return Err("Fast period must be less than slow period".to_string());
+        }
+
+        let within = get_param_f64(condition, "within_candles")?;
+        if within < 1.0 || within > 20.0 {
+            return Err("Within candles must be between 1 and 20".to_string());
+        }
+
+        let direction = get_param_string(condition, "direction")?;
+        if !["BULLISH", "BEARISH"].contains(&direction.as_str()) {
+            return Err(format!("Invalid direction: {}", direction));
+        }
+
+        Ok(())
+    }
+
+    fn parameter_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "EmaCross",
+            "name": "EMA Crossover",
+            "category": "Technical Indicators",
+            "tags": ["ema", "crossover", "trend", "golden cross", "technical"],
+            "icon": "icon-git-merge",
+            "origin": "strategy",
+            "description": "Detect a fast EMA crossing above (bullish) or below (bearish) a slow EMA",
+            "parameters": {
+                "timeframe": {
+                    "type": "enum",
+                    "name": "Timeframe",
+                    "description": "Candle timeframe to analyze (defaults to strategy timeframe if not set)",
+                    "default": null,
+                    "optional": true,
+                    "options": [
+                        { "value": "1m", "label": "1 Minute" },
+                        { "value": "5m", "label": "5 Minutes" },
+                        { "value": "15m", "label": "15 Minutes" },
+                        { "value": "1h", "label": "1 Hour" },
+                        { "value": "4h", "label": "4 Hours" },
+                        { "value": "12h", "label": "12 Hours" },
+                        { "value": "1d", "label": "1 Day" }
+                    ]
+                },
+                "fast_period": {
+                    "type": "number",
+                    "name": "Fast EMA",
+                    "description": "Fast EMA period",
+                    "default": 9,
+                    "min": 2,
+                    "max": 100,
+                    "step": 1
+                },
+                "slow_period": {
+                    "type": "number",
+                    "name": "Slow EMA",
+                    "description": "Slow EMA period (must be greater than fast)",
+                    "default": 21,
+                    "min": 3,
+                    "max": 200,
+                    "step": 1
+                },
+                "direction": {
+                    "type": "enum",
+                    "name": "Cross Direction",
+                    "description": "Which way the fast EMA crosses the slow EMA",
+                    "default": "BULLISH",
+                    "options": [
+                        { "value": "BULLISH", "label": "Bullish (fast crosses above slow)" },
+                        { "value": "BEARISH", "label": "Bearish (fast crosses below slow)" }
+                    ]
+                },
+                "within_candles": {
+                    "type": "number",
+                    "name": "Within Candles",
+                    "description": "Cross must have happened within this many recent candles",
+                    "default": 1,
+                    "min": 1,
+                    "max": 20,
+                    "step": 1
+                }
+            }
+        })
+    }
+}
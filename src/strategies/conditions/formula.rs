@@ -0,0 +1,112 @@
+use crate::strategies::conditions::{
+    get_candles_for_timeframe, get_param_string, get_param_string_optional,
+    validate_timeframe_param, ConditionEvaluator,
+};
+use crate::strategies::expression::{self, ExpressionContext, Value};
+use crate::strategies::types::{Condition, EvaluationContext};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// User-written boolean expression over candle series and context fields
+pub struct FormulaCondition;
+
+#[async_trait]
+impl ConditionEvaluator for FormulaCondition {
+    fn condition_type(&self) -> &'static str {
+        "Formula"
+    }
+
+    async fn evaluate(
+        &self,
+        condition: &Condition,
+        context: &EvaluationContext,
+    ) -> Result<bool, String> {
+        let source = get_param_string(condition, "expression")?;
+        let timeframe = get_param_string_optional(condition, "timeframe");
+
+        let expr = expression::parse(&source)?;
+
+        // Only require OHLCV data when the formula references candles
+        let candles = if expr.uses_candles() {
+            get_candles_for_timeframe(context, timeframe.as_deref())?
+        } else {
+            Vec::new()
+        };
+
+        let ctx = ExpressionContext {
+            candles: &candles,
+            current_price: context.current_price,
+            position: context.position_data.as_ref(),
+            market: context.market_data.as_ref(),
+        };
+
+        match expr.evaluate(&ctx)? {
+            Value::Bool(result) => Ok(result),
+            Value::Number(_) => Err(
+                "Formula must evaluate to true/false (use a comparison such as '>')".to_string(),
+            ),
+        }
+    }
+
+    fn validate(&self, condition: &Condition) -> Result<(), String> {
+        // Validate timeframe if provided
+        validate_timeframe_param(condition)?;
+
+        let source = get_param_string(condition, "expression")?;
+        let expr = expression::parse(&source).map_err(|e| format!("Invalid formula: {}", e))?;
+
+        // Reject formulas that can never produce true/false
+        match expr {
+            expression::Expr::Number(_)
+            | expression::Expr::Series { .. }
+            | expression::Expr::Field(_)
+            | expression::Expr::Call { .. } => {
+                Err("Formula must be a comparison or logical expression".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn parameter_schema(&self) -> serde_json::Value {
+        let functions: Vec<&str> = expression::FUNCTIONS.iter().map(|(_, doc)| *doc).collect();
+
+        json!({
+            "type": "Formula",
+            "name": "Custom Formula",
+            "category": "Technical Indicators",
+            "tags": ["formula", "expression", "custom", "advanced"],
+            "icon": "icon-sigma",
+            "origin": "strategy",
+            "description": "Write your own rule, e.g. close > sma(close, 50) * 1.05 and volume > 2 * avg(volume, 20)",
+            "parameters": {
+                "timeframe": {
+                    "type": "enum",
+                    "name": "Timeframe",
+                    "description": "Candle timeframe for series and indicator functions (defaults to strategy timeframe if not set)",
+                    "default": null,
+                    "optional": true,
+                    "options": [
+                        { "value": "1m", "label": "1 Minute" },
+                        { "value": "5m", "label": "5 Minutes" },
+                        { "value": "15m", "label": "15 Minutes" },
+                        { "value": "1h", "label": "1 Hour" },
+                        { "value": "4h", "label": "4 Hours" },
+                        { "value": "12h", "label": "12 Hours" },
+                        { "value": "1d", "label": "1 Day" }
+                    ]
+                },
+                "expression": {
+                    "type": "string",
+                    "name": "Formula",
+                    "description": format!(
+                        "Series: open, high, low, close, volume (close[3] = 3 candles ago). Fields: {}. Operators: + - * / % < <= > >= == != and or not. Functions: {}",
+                        expression::FIELDS.join(", "),
+                        functions.join("; ")
+                    ),
+                    "default": "close > sma(close, 20)",
+                    "max_length": expression::MAX_EXPRESSION_LENGTH
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,163 @@
+use crate::strategies::conditions::{
+    get_candles_for_timeframe, get_param_f64, get_param_string, get_param_string_optional,
+    validate_timeframe_param, ConditionEvaluator,
+};
+use crate::strategies::indicators;
+use crate::strategies::types::{Condition, EvaluationContext};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// MACD line crossing its signal line
+pub struct MacdCrossCondition;
+
+#[async_trait]
+impl ConditionEvaluator for MacdCrossCondition {
+    fn condition_type(&self) -> &'static str {
+        "MacdCross"
+    }
+
+    async fn evaluate(
+        &self,
+        condition: &Condition,
+        context: &EvaluationContext,
+    ) -> Result<bool, String> {
+        let fast = get_param_f64(condition, "fast_period")? as usize;
+        let slow = get_param_f64(condition, "slow_period")? as usize;
+        let signal = get_param_f64(condition, "signal_period")? as usize;
+        let direction = get_param_string(condition, "direction")?;
+        let within = get_param_f64(condition, "within_candles")? as usize;
+        let timeframe = get_param_string_optional(condition, "timeframe");
+
+        let candles = get_candles_for_timeframe(context, timeframe.as_deref())?;
+        let closes = indicators::closes(&candles);
+
+        let series = indicators::macd_series(&closes, fast, slow, signal);
+        if series.len() < 2 {
+            return Err(format!(
+                "Not enough candles for MACD calculation: {} < {}",
+                candles.len(),
+                slow + signal
+            ));
+        }
+
+        let macd: Vec<f64> = series.iter().map(|p| p.macd).collect();
+        let signal_line: Vec<f64> = series.iter().map(|p| p.signal).collect();
+
+        let result = match direction.as_str() {
+            "BULLISH" => indicators::crossed_within(&macd, &signal_line, within, true),
+            "BEARISH" => indicators::crossed_within(&macd, &signal_line, within, false),
+            _ => return Err(format!("Invalid direction: {}", direction)),
+        };
+
+        Ok(result)
+    }
+
+    fn validate(&self, condition: &Condition) -> Result<(), String> {
+        // Validate timeframe if provided
+        validate_timeframe_param(condition)?;
+
+        let fast = get_param_f64(condition, "fast_period")?;
+        let slow = get_param_f64(condition, "slow_period")?;
+        let signal = get_param_f64(condition, "signal_period")?;
+        if fast < 2.0 || fast > 100.0 {
+            return Err("Fast period must be between 2 and 100".to_string());
+        }
+        if slow < 3.0 || slow > 200.0 {
+            return Err("Slow period must be between 3 and 200".to_string());
+        }
+        if fast >= slow {
+            return Err("Fast period must be less than slow period".to_string());
+        }
+        if signal < 2.0 || signal > 50.0 {
+            return Err("Signal period must be between 2 and 50".to_string());
+        }
+
+        let within = get_param_f64(condition, "within_candles")?;
+        if within < 1.0 || within > 20.0 {
+            return Err("Within candles must be between 1 and 20".to_string());
+        }
+
+        let direction = get_param_string(condition, "direction")?;
+        if !["BULLISH", "BEARISH"].contains(&direction.as_str()) {
+            return Err(format!("Invalid direction: {}", direction));
+        }
+
+        Ok(())
+    }
+
+    fn parameter_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "MacdCross",
+            "name": "MACD Cross",
+            "category": "Technical Indicators",
+            "tags": ["macd", "crossover", "momentum", "technical"],
+            "icon": "icon-activity",
+            "origin": "strategy",
+            "description": "Detect the MACD line crossing above (bullish) or below (bearish) its signal line",
+            "parameters": {
+                "timeframe": {
+                    "type": "enum",
+                    "name": "Timeframe",
+                    "description": "Candle timeframe to analyze (defaults to strategy timeframe if not set)",
+                    "default": null,
+                    "optional": true,
+                    "options": [
+                        { "value": "1m", "label": "1 Minute" },
+                        { "value": "5m", "label": "5 Minutes" },
+                        { "value": "15m", "label": "15 Minutes" },
+                        { "value": "1h", "label": "1 Hour" },
+                        { "value": "4h", "label": "4 Hours" },
+                        { "value": "12h", "label": "12 Hours" },
+                        { "value": "1d", "label": "1 Day" }
+                    ]
+                },
+                "fast_period": {
+                    "type": "number",
+                    "name": "Fast EMA",
+                    "description": "Fast EMA period",
+                    "default": 12,
+                    "min": 2,
+                    "max": 100,
+                    "step": 1
+                },
+                "slow_period": {
+                    "type": "number",
+                    "name": "Slow EMA",
+                    "description": "Slow EMA period (must be greater than fast)",
+                    "default": 26,
+                    "min": 3,
+                    "max": 200,
+                    "step": 1
+                },
+                "signal_period": {
+                    "type": "number",
+                    "name": "Signal EMA",
+                    "description": "Signal line EMA period",
+                    "default": 9,
+                    "min": 2,
+                    "max": 50,
+                    "step": 1
+                },
+                "direction": {
+                    "type": "enum",
+                    "name": "Cross Direction",
+                    "description": "Which way MACD crosses the signal line",
+                    "default": "BULLISH",
+                    "options": [
+                        { "value": "BULLISH", "label": "Bullish (MACD crosses above signal)" },
+                        { "value": "BEARISH", "label": "Bearish (MACD crosses below signal)" }
+                    ]
+                },
+                "within_candles": {
+                    "type": "number",
+                    "name": "Within Candles",
+                    "description": "Cross must have happened within this many recent candles",
+                    "default": 1,
+                    "min": 1,
+                    "max": 20,
+                    "step": 1
+                }
+            }
+        })
+    }
+}
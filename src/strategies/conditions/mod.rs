@@ -1,19 +1,35 @@
+mod atr_expansion;
+mod bollinger_bands;
 mod candle_size;
 mod consecutive_candles;
+mod ema_cross;
+mod formula;
 mod liquidity_level;
+mod macd_cross;
 mod position_holding_time;
 mod price_breakout;
 mod price_change_percent;
 mod price_to_ma;
+mod price_to_vwap;
+mod rsi_divergence;
+mod rsi_threshold;
 mod volume_spike;
 
+pub use atr_expansion::AtrExpansionCondition;
+pub use bollinger_bands::BollingerBandsCondition;
 pub use candle_size::CandleSizeCondition;
 pub use consecutive_candles::ConsecutiveCandlesCondition;
+pub use ema_cross::EmaCrossCondition;
+pub use formula::FormulaCondition;
 pub use liquidity_level::LiquidityLevelCondition;
+pub use macd_cross::MacdCrossCondition;
 pub use position_holding_time::PositionHoldingTimeCondition;
 pub use price_breakout::PriceBreakoutCondition;
 pub use price_change_percent::PriceChangePercentCondition;
 pub use price_to_ma::PriceToMaCondition;
+pub use price_to_vwap::PriceToVwapCondition;
+pub use rsi_divergence::RsiDivergenceCondition;
+pub use rsi_threshold::RsiThresholdCondition;
 pub use volume_spike::VolumeSpikeCondition;
 
 use crate::ohlcvs::Candle;
@@ -129,6 +145,14 @@ impl ConditionRegistry {
         registry.register(Box::new(VolumeSpikeCondition));
         registry.register(Box::new(LiquidityLevelCondition));
         registry.register(Box::new(PositionHoldingTimeCondition));
+        registry.register(Box::new(RsiThresholdCondition));
+        registry.register(Box::new(RsiDivergenceCondition));
+        registry.register(Box::new(MacdCrossCondition));
+        registry.register(Box::new(BollingerBandsCondition));
+        registry.register(Box::new(EmaCrossCondition));
+        registry.register(Box::new(AtrExpansionCondition));
+        registry.register(Box::new(PriceToVwapCondition));
+        registry.register(Box::new(FormulaCondition));
 
         registry
     }
@@ -0,0 +1,157 @@
+use crate::strategies::conditions::{
+    get_candles_for_timeframe, get_param_f64, get_param_string, get_param_string_optional,
+    validate_timeframe_param, ConditionEvaluator,
+};
+use crate::strategies::indicators;
+use crate::strategies::types::{Condition, EvaluationContext};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Detect divergence between price and RSI swings
+pub struct RsiDivergenceCondition;
+
+#[async_trait]
+impl ConditionEvaluator for RsiDivergenceCondition {
+    fn condition_type(&self) -> &'static str {
+        "RsiDivergence"
+    }
+
+    async fn evaluate(
+        &self,
+        condition: &Condition,
+        context: &EvaluationContext,
+    ) -> Result<bool, String> {
+        let period = get_param_f64(condition, "period")? as usize;
+        let lookback = get_param_f64(condition, "lookback")? as usize;
+        let direction = get_param_string(condition, "direction")?;
+        let timeframe = get_param_string_optional(condition, "timeframe");
+
+        let candles = get_candles_for_timeframe(context, timeframe.as_deref())?;
+        let closes = indicators::closes(&candles);
+
+        let rsi = indicators::rsi_series(&closes, period);
+        if rsi.len() < lookback {
+            return Err(format!(
+                "Not enough candles for RSI divergence: {} < {}",
+                candles.len(),
+                period + lookback
+            ));
+        }
+
+        // Compare the extreme of the older half of the window with the recent half
+        let prices = &closes[closes.len() - lookback..];
+        let rsi = &rsi[rsi.len() - lookback..];
+        let split = lookback / 2;
+
+        let extreme_index = |range: std::ops::Range<usize>, lowest: bool| -> usize {
+            range
+                .max_by(|&a, &b| {
+                    let ordering = prices[a].total_cmp(&prices[b]);
+                    if lowest {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                })
+                .unwrap_or(0)
+        };
+
+        let result = match direction.as_str() {
+            // Price makes a lower low while RSI makes a higher low
+            "BULLISH" => {
+                let older = extreme_index(0..split, true);
+                let recent = extreme_index(split..lookback, true);
+                prices[recent] < prices[older] && rsi[recent] > rsi[older]
+            }
+            // Price makes a higher high while RSI makes a lower high
+            "BEARISH" => {
+                let older = extreme_index(0..split, false);
+                let recent = extreme_index(split..lookback, false);
+                prices[recent] > prices[older] && rsi[recent] < rsi[older]
+            }
+            _ => return Err(format!("Invalid direction: {}", direction)),
+        };
+
+        Ok(result)
+    }
+
+    fn validate(&self, condition: &Condition) -> Result<(), String> {
+        // Validate timeframe if provided
+        validate_timeframe_param(condition)?;
+
+        let period = get_param_f64(condition, "period")?;
+        if period < 2.0 || period > 100.0 {
+            return Err("Period must be between 2 and 100".to_string());
+        }
+
+        let lookback = get_param_f64(condition, "lookback")?;
+        if lookback < 6.0 || lookback > 100.0 {
+            return Err("Lookback must be between 6 and 100".to_string());
+        }
+
+        let direction = get_param_string(condition, "direction")?;
+        if !["BULLISH", "BEARISH"].contains(&direction.as_str()) {
+            return Err(format!("Invalid direction: {}", direction));
+        }
+
+        Ok(())
+    }
+
+    fn parameter_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "RsiDivergence",
+            "name": "RSI Divergence",
+            "category": "Technical Indicators",
+            "tags": ["rsi", "divergence", "reversal", "technical"],
+            "icon": "icon-git-compare",
+            "origin": "strategy",
+            "description": "Detect price making a new low/high that RSI does not confirm (potential reversal)",
+            "parameters": {
+                "timeframe": {
+                    "type": "enum",
+                    "name": "Timeframe",
+                    "description": "Candle timeframe to analyze (defaults to strategy timeframe if not set)",
+                    "default": null,
+                    "optional": true,
+                    "options": [
+                        { "value": "1m", "label": "1 Minute" },
+                        { "value": "5m", "label": "5 Minutes" },
+                        { "value": "15m", "label": "15 Minutes" },
+                        { "value": "1h", "label": "1 Hour" },
+                        { "value": "4h", "label": "4 Hours" },
+                        { "value": "12h", "label": "12 Hours" },
+                        { "value": "1d", "label": "1 Day" }
+                    ]
+                },
+                "period": {
+                    "type": "number",
+                    "name": "RSI Period",
+                    "description": "Number of candles for RSI smoothing",
+                    "default": 14,
+                    "min": 2,
+                    "max": 100,
+                    "step": 1
+                },
+                "lookback": {
+                    "type": "number",
+                    "name": "Lookback Candles",
+                    "description": "Window split in two halves; the swing in the recent half is compared with the older half",
+                    "default": 20,
+                    "min": 6,
+                    "max": 100,
+                    "step": 1
+                },
+                "direction": {
+                    "type": "enum",
+                    "name": "Divergence Type",
+                    "description": "Bullish: lower price low with higher RSI low. Bearish: higher price high with lower RSI high",
+                    "default": "BULLISH",
+                    "options": [
+                        { "value": "BULLISH", "label": "Bullish Divergence" },
+                        { "value": "BEARISH", "label": "Bearish Divergence" }
+                    ]
+                }
+            }
+        })
+    }
+}
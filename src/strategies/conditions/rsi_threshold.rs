@@ -0,0 +1,136 @@
+use crate::strategies::conditions::{
+    get_candles_for_timeframe, get_param_f64, get_param_string, get_param_string_optional,
+    validate_timeframe_param, ConditionEvaluator,
+};
+use crate::strategies::indicators;
+use crate::strategies::types::{Condition, EvaluationContext};
+use async_trait::async_trait;
+use serde_json::json;
+
+/// RSI level and level-cross checks (oversold / overbought)
+pub struct RsiThresholdCondition;
+
+#[async_trait]
+impl ConditionEvaluator for RsiThresholdCondition {
+    fn condition_type(&self) -> &'static str {
+        "RsiThreshold"
+    }
+
+    async fn evaluate(
+        &self,
+        condition: &Condition,
+        context: &EvaluationContext,
+    ) -> Result<bool, String> {
+        let period = get_param_f64(condition, "period")? as usize;
+        let mode = get_param_string(condition, "mode")?;
+        let level = get_param_f64(condition, "level")?;
+        let timeframe = get_param_string_optional(condition, "timeframe");
+
+        let candles = get_candles_for_timeframe(context, timeframe.as_deref())?;
+        let closes = indicators::closes(&candles);
+
+        let rsi = indicators::rsi_series(&closes, period);
+        if rsi.len() < 2 {
+            return Err(format!(
+                "Not enough candles for RSI calculation: {} < {}",
+                candles.len(),
+                period + 2
+            ));
+        }
+
+        let current = rsi[rsi.len() - 1];
+        let previous = rsi[rsi.len() - 2];
+
+        let result = match mode.as_str() {
+            "ABOVE" => current >= level,
+            "BELOW" => current <= level,
+            "CROSS_ABOVE" => indicators::crossed_above(previous, current, level, level),
+            "CROSS_BELOW" => indicators::crossed_below(previous, current, level, level),
+            _ => return Err(format!("Invalid mode: {}", mode)),
+        };
+
+        Ok(result)
+    }
+
+    fn validate(&self, condition: &Condition) -> Result<(), String> {
+        // Validate timeframe if provided
+        validate_timeframe_param(condition)?;
+
+        let period = get_param_f64(condition, "period")?;
+        if period < 2.0 || period > 100.0 {
+            return Err("Period must be between 2 and 100".to_string());
+        }
+
+        let level = get_param_f64(condition, "level")?;
+        if level <= 0.0 || level >= 100.0 {
+            return Err("Level must be between 0 and 100".to_string());
+        }
+
+        let mode = get_param_string(condition, "mode")?;
+        if !["ABOVE", "BELOW", "CROSS_ABOVE", "CROSS_BELOW"].contains(&mode.as_str()) {
+            return Err(format!("Invalid mode: {}", mode));
+        }
+
+        Ok(())
+    }
+
+    fn parameter_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "RsiThreshold",
+            "name": "RSI Level",
+            "category": "Technical Indicators",
+            "tags": ["rsi", "oscillator", "oversold", "overbought", "technical"],
+            "icon": "icon-gauge",
+            "origin": "strategy",
+            "description": "Check the Relative Strength Index against a level or detect it crossing that level",
+            "parameters": {
+                "timeframe": {
+                    "type": "enum",
+                    "name": "Timeframe",
+                    "description": "Candle timeframe to analyze (defaults to strategy timeframe if not set)",
+                    "default": null,
+                    "optional": true,
+                    "options": [
+                        { "value": "1m", "label": "1 Minute" },
+                        { "value": "5m", "label": "5 Minutes" },
+                        { "value": "15m", "label": "15 Minutes" },
+                        { "value": "1h", "label": "1 Hour" },
+                        { "value": "4h", "label": "4 Hours" },
+                        { "value": "12h", "label": "12 Hours" },
+                        { "value": "1d", "label": "1 Day" }
+                    ]
+                },
+                "period": {
+                    "type": "number",
+                    "name": "RSI Period",
+                    "description": "Number of candles for RSI smoothing",
+                    "default": 14,
+                    "min": 2,
+                    "max": 100,
+                    "step": 1
+                },
+                "mode": {
+                    "type": "enum",
+                    "name": "Mode",
+                    "description": "How RSI is compared with the level",
+                    "default": "BELOW",
+                    "options": [
+                        { "value": "ABOVE", "label": "RSI Above Level" },
+                        { "value": "BELOW", "label": "RSI Below Level" },
+                        { "value": "CROSS_ABOVE", "label": "RSI Crosses Above Level" },
+                        { "value": "CROSS_BELOW", "label": "RSI Crosses Below Level" }
+                    ]
+                },
+                "level": {
+                    "type": "number",
+                    "name": "RSI Level",
+                    "description": "RSI threshold (30 = classic oversold, 70 = classic overbought)",
+                    "default": 30,
+                    "min": 1,
+                    "max": 99,
+                    "step": 1
+                }
+            }
+        })
+    }
+}
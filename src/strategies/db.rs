@@ -1,6 +1,9 @@
 use crate::logger::{self, LogTag};
+use crate::migrations::{self, add_column_if_missing, Migration, MigrationSet};
+use crate::strategies::backtest::BacktestReport;
 use crate::strategies::types::{
-    EvaluationResult, RiskLevel, Strategy, StrategyPerformance, StrategyTemplate, StrategyType,
+    EvaluationResult, RiskLevel, Strategy, StrategyAssignment, StrategyPerformance,
+    StrategyRevision, StrategyTemplate, StrategyType,
 };
 use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
@@ -13,8 +16,19 @@ use std::sync::atomic::{AtomicBool, Ordering};
 // Static flag to track if database has been initialized
 static STRATEGIES_DB_INITIALIZED: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(false));
 
-// Database schema version
-const STRATEGIES_SCHEMA_VERSION: u32 = 1;
+/// Ordered schema migrations of strategies.db
+pub const STRATEGIES_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "strategies",
+    migrations: &[
+        Migration::apply(1, "Baseline schema", baseline_schema),
+        Migration::apply(2, "Strategy revision history", add_revision_history),
+        Migration::sql(
+            3,
+            "Drop pre-versioning schema_version table",
+            "DROP TABLE IF EXISTS schema_version;",
+        ),
+    ],
+};
 
 // =============================================================================
 // DATABASE SCHEMA DEFINITIONS
@@ -66,6 +80,8 @@ CREATE TABLE IF NOT EXISTS strategy_assignments (
     position_id TEXT NOT NULL,
     strategy_id TEXT NOT NULL,
     assigned_at TEXT NOT NULL,
+    strategy_version INTEGER, -- Strategy revision that opened/closed the position
+    role TEXT, -- 'ENTRY' or 'EXIT'
     PRIMARY KEY (position_id, strategy_id)
 );
 
@@ -73,6 +89,41 @@ CREATE INDEX IF NOT EXISTS idx_assignments_position ON strategy_assignments(posi
 CREATE INDEX IF NOT EXISTS idx_assignments_strategy ON strategy_assignments(strategy_id);
 "#;
 
+// Revision columns for assignments created before versioning
+const MIGRATION_ASSIGNMENT_REVISION_COLUMNS: &[(&str, &str)] =
+    &[("strategy_version", "INTEGER"), ("role", "TEXT")];
+
+// Immutable history: one row per saved version, never updated or deleted
+// (kept after strategy deletion so positions can still resolve their revision)
+const SCHEMA_STRATEGY_REVISIONS: &str = r#"
+CREATE TABLE IF NOT EXISTS strategy_revisions (
+    strategy_id TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    type TEXT NOT NULL,
+    enabled INTEGER NOT NULL,
+    priority INTEGER NOT NULL,
+    timeframe TEXT NOT NULL,
+    rules_json TEXT NOT NULL,
+    parameters_json TEXT,
+    author TEXT,
+    created_at TEXT NOT NULL,
+    note TEXT,
+    PRIMARY KEY (strategy_id, version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_revisions_created ON strategy_revisions(created_at);
+"#;
+
+// Seed history with the current state of strategies saved before versioning
+const MIGRATION_BACKFILL_REVISIONS: &str = r#"
+INSERT OR IGNORE INTO strategy_revisions
+    (strategy_id, version, name, description, type, enabled, priority, timeframe, rules_json, parameters_json, author, created_at, note)
+SELECT id, version, name, description, type, enabled, priority, timeframe, rules_json, parameters_json, author, updated_at, 'Imported from existing strategy'
+FROM strategies;
+"#;
+
 const SCHEMA_STRATEGY_TEMPLATES: &str = r#"
 CREATE TABLE IF NOT EXISTS strategy_templates (
     id TEXT PRIMARY KEY,
@@ -109,13 +160,6 @@ CREATE INDEX IF NOT EXISTS idx_backtests_strategy ON strategy_backtests(strategy
 CREATE INDEX IF NOT EXISTS idx_backtests_start ON strategy_backtests(start_time);
 "#;
 
-const SCHEMA_VERSION_TABLE: &str = r#"
-CREATE TABLE IF NOT EXISTS schema_version (
-    version INTEGER PRIMARY KEY,
-    applied_at TEXT NOT NULL
-);
-"#;
-
 // =============================================================================
 // CONNECTION POOL
 // =============================================================================
@@ -160,55 +204,41 @@ pub fn init_strategies_db() -> Result<(), String> {
     )
     .map_err(|e| format!("Failed to set pragmas: {}", e))?;
 
-    // Create version table first
-    conn.execute_batch(SCHEMA_VERSION_TABLE)
-        .map_err(|e| format!("Failed to create version table: {}", e))?;
+    migrations::run_migrations(&conn, &STRATEGIES_MIGRATIONS)?;
 
-    // Check current schema version
-    let current_version: Option<u32> = conn
-        .query_row(
-            "SELECT version FROM schema_version ORDER BY version DESC LIMIT 1",
-            [],
-            |row| row.get(0),
-        )
-        .optional()
-        .map_err(|e| format!("Failed to check schema version: {}", e))?;
+    STRATEGIES_DB_INITIALIZED.store(true, Ordering::Relaxed);
+    Ok(())
+}
 
-    if current_version.is_none() || current_version.unwrap() < STRATEGIES_SCHEMA_VERSION {
-        // Create all tables
-        conn.execute_batch(SCHEMA_STRATEGIES)
-            .map_err(|e| format!("Failed to create strategies table: {}", e))?;
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(SCHEMA_STRATEGIES)
+        .map_err(|e| format!("Failed to create strategies table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_STRATEGY_PERFORMANCE)
-            .map_err(|e| format!("Failed to create performance table: {}", e))?;
+    conn.execute_batch(SCHEMA_STRATEGY_PERFORMANCE)
+        .map_err(|e| format!("Failed to create performance table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_STRATEGY_ASSIGNMENTS)
-            .map_err(|e| format!("Failed to create assignments table: {}", e))?;
+    conn.execute_batch(SCHEMA_STRATEGY_ASSIGNMENTS)
+        .map_err(|e| format!("Failed to create assignments table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_STRATEGY_TEMPLATES)
-            .map_err(|e| format!("Failed to create templates table: {}", e))?;
+    conn.execute_batch(SCHEMA_STRATEGY_TEMPLATES)
+        .map_err(|e| format!("Failed to create templates table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_STRATEGY_BACKTESTS)
-            .map_err(|e| format!("Failed to create backtests table: {}", e))?;
+    conn.execute_batch(SCHEMA_STRATEGY_BACKTESTS)
+        .map_err(|e| format!("Failed to create backtests table: {}", e))
+}
 
-        // Update version
-        conn.execute(
-            "INSERT INTO schema_version (version, applied_at) VALUES (?1, ?2)",
-            params![STRATEGIES_SCHEMA_VERSION, Utc::now().to_rfc3339()],
-        )
-        .map_err(|e| format!("Failed to update schema version: {}", e))?;
-
-        logger::info(
-            LogTag::System,
-            &format!(
-                "Strategies database initialized with schema version {}",
-                STRATEGIES_SCHEMA_VERSION
-            ),
-        );
+/// Migration 2: immutable revision history, seeded from the current strategies
+fn add_revision_history(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(SCHEMA_STRATEGY_REVISIONS)
+        .map_err(|e| format!("Failed to create revisions table: {}", e))?;
+
+    for (column, definition) in MIGRATION_ASSIGNMENT_REVISION_COLUMNS {
+        add_column_if_missing(conn, "strategy_assignments", column, definition)?;
     }
 
-    STRATEGIES_DB_INITIALIZED.store(true, Ordering::Relaxed);
-    Ok(())
+    conn.execute_batch(MIGRATION_BACKFILL_REVISIONS)
+        .map_err(|e| format!("Failed to backfill strategy revisions: {}", e))
 }
 
 // =============================================================================
@@ -225,7 +255,11 @@ pub fn insert_strategy(strategy: &Strategy) -> Result<(), String> {
     let parameters_json = serde_json::to_string(&strategy.parameters)
         .map_err(|e| format!("Failed to serialize parameters: {}", e))?;
 
-    conn.execute(
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+    tx.execute(
         "INSERT INTO strategies (id, name, description, type, enabled, priority, timeframe, rules_json, parameters_json, created_at, updated_at, author, version)
          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
         params![
@@ -246,6 +280,11 @@ pub fn insert_strategy(strategy: &Strategy) -> Result<(), String> {
     )
     .map_err(|e| format!("Failed to insert strategy: {}", e))?;
 
+    insert_revision(&tx, strategy, &rules_json, &parameters_json, None)?;
+
+    tx.commit()
+        .map_err(|e| format!("Failed to commit strategy insert: {}", e))?;
+
     logger::info(
         LogTag::System,
         &format!(
@@ -258,7 +297,15 @@ pub fn insert_strategy(strategy: &Strategy) -> Result<(), String> {
 }
 
 /// Update an existing strategy
+///
+/// Callers bump `strategy.version`; the new version is recorded as an immutable
+/// revision and saving a version that already exists fails (concurrent edit).
 pub fn update_strategy(strategy: &Strategy) -> Result<(), String> {
+    update_strategy_with_note(strategy, None)
+}
+
+/// Update an existing strategy, annotating the recorded revision (e.g. rollbacks)
+pub fn update_strategy_with_note(strategy: &Strategy, note: Option<&str>) -> Result<(), String> {
     let conn = get_connection()?;
 
     let rules_json = serde_json::to_string(&strategy.rules)
@@ -267,7 +314,11 @@ pub fn update_strategy(strategy: &Strategy) -> Result<(), String> {
     let parameters_json = serde_json::to_string(&strategy.parameters)
         .map_err(|e| format!("Failed to serialize parameters: {}", e))?;
 
-    let rows_affected = conn
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+    let rows_affected = tx
         .execute(
             "UPDATE strategies 
              SET name = ?2, description = ?3, type = ?4, enabled = ?5, priority = ?6, 
@@ -294,17 +345,63 @@ pub fn update_strategy(strategy: &Strategy) -> Result<(), String> {
         return Err(format!("Strategy not found: {}", strategy.id));
     }
 
+    insert_revision(&tx, strategy, &rules_json, &parameters_json, note)?;
+
+    tx.commit()
+        .map_err(|e| format!("Failed to commit strategy update: {}", e))?;
+
     logger::info(
         LogTag::System,
         &format!(
-            "Updated strategy: id={}, name={}",
-            strategy.id, strategy.name
+            "Updated strategy: id={}, name={}, version={}",
+            strategy.id, strategy.name, strategy.version
         ),
     );
 
     Ok(())
 }
 
+/// Record a strategy version in the revision history
+fn insert_revision(
+    conn: &Connection,
+    strategy: &Strategy,
+    rules_json: &str,
+    parameters_json: &str,
+    note: Option<&str>,
+) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO strategy_revisions (strategy_id, version, name, description, type, enabled, priority, timeframe, rules_json, parameters_json, author, created_at, note)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        params![
+            strategy.id,
+            strategy.version,
+            strategy.name,
+            strategy.description,
+            strategy.strategy_type.to_string(),
+            strategy.enabled,
+            strategy.priority,
+            strategy.timeframe,
+            rules_json,
+            parameters_json,
+            strategy.author,
+            strategy.updated_at.to_rfc3339(),
+            note,
+        ],
+    )
+    .map_err(|e| {
+        if e.to_string().contains("UNIQUE constraint failed") {
+            format!(
+                "Strategy {} version {} already exists (modified concurrently?)",
+                strategy.id, strategy.version
+            )
+        } else {
+            format!("Failed to record strategy revision: {}", e)
+        }
+    })?;
+
+    Ok(())
+}
+
 /// Delete a strategy
 pub fn delete_strategy(strategy_id: &str) -> Result<(), String> {
     let conn = get_connection()?;
@@ -598,6 +695,240 @@ pub fn get_enabled_strategies(strategy_type: StrategyType) -> Result<Vec<Strateg
     Ok(result)
 }
 
+// =============================================================================
+// REVISION HISTORY
+// =============================================================================
+
+const REVISION_COLUMNS: &str = "strategy_id, version, name, description, type, enabled, priority, timeframe, rules_json, parameters_json, author, created_at, note";
+
+fn row_to_revision(row: &rusqlite::Row) -> SqliteResult<Result<StrategyRevision, String>> {
+    let strategy_id: String = row.get(0)?;
+    let type_str: String = row.get(4)?;
+    let rules_json: String = row.get(8)?;
+    let parameters_json: Option<String> = row.get(9)?;
+    let created_at_str: String = row.get(11)?;
+
+    let (version, name, description, enabled, priority, timeframe, author, note) = (
+        row.get(1)?,
+        row.get(2)?,
+        row.get(3)?,
+        row.get(5)?,
+        row.get(6)?,
+        row.get(7)?,
+        row.get(10)?,
+        row.get(12)?,
+    );
+
+    Ok((|| {
+        let strategy_type = match type_str.as_str() {
+            "ENTRY" => StrategyType::Entry,
+            "EXIT" => StrategyType::Exit,
+            _ => return Err(format!("Invalid strategy type: {}", type_str)),
+        };
+        let rules = serde_json::from_str(&rules_json)
+            .map_err(|e| format!("Failed to deserialize rules: {}", e))?;
+        let parameters = match parameters_json {
+            Some(json) => serde_json::from_str(&json)
+                .map_err(|e| format!("Failed to deserialize parameters: {}", e))?,
+            None => Default::default(),
+        };
+        let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+            .map_err(|e| format!("Failed to parse created_at: {}", e))?
+            .with_timezone(&Utc);
+
+        Ok(StrategyRevision {
+            strategy_id,
+            version,
+            name,
+            description,
+            strategy_type,
+            enabled,
+            priority,
+            timeframe,
+            rules,
+            parameters,
+            author,
+            created_at,
+            note,
+        })
+    })())
+}
+
+/// Get the revision history of a strategy (newest first)
+pub fn get_strategy_revisions(
+    strategy_id: &str,
+    limit: usize,
+) -> Result<Vec<StrategyRevision>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM strategy_revisions
+             WHERE strategy_id = ?1
+             ORDER BY version DESC
+             LIMIT ?2",
+            REVISION_COLUMNS
+        ))
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map(params![strategy_id, limit as i64], row_to_revision)
+        .map_err(|e| format!("Failed to query revisions: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect revisions: {}", e))?;
+
+    rows.into_iter().collect()
+}
+
+/// Get a single strategy revision
+pub fn get_strategy_revision(
+    strategy_id: &str,
+    version: i32,
+) -> Result<Option<StrategyRevision>, String> {
+    let conn = get_connection()?;
+
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM strategy_revisions WHERE strategy_id = ?1 AND version = ?2",
+            REVISION_COLUMNS
+        ),
+        params![strategy_id, version],
+        row_to_revision,
+    )
+    .optional()
+    .map_err(|e| format!("Failed to get revision: {}", e))?
+    .transpose()
+}
+
+// =============================================================================
+// TEMPLATES
+// =============================================================================
+
+const TEMPLATE_COLUMNS: &str =
+    "id, name, description, category, risk_level, rules_json, parameters_json, created_at, author";
+
+fn row_to_template(row: &rusqlite::Row) -> SqliteResult<Result<StrategyTemplate, String>> {
+    let risk_str: String = row.get(4)?;
+    let rules_json: String = row.get(5)?;
+    let parameters_json: Option<String> = row.get(6)?;
+    let created_at_str: String = row.get(7)?;
+
+    let (id, name, description, category, author) = (
+        row.get(0)?,
+        row.get(1)?,
+        row.get(2)?,
+        row.get(3)?,
+        row.get(8)?,
+    );
+
+    Ok((|| {
+        let risk_level = match risk_str.as_str() {
+            "LOW" => RiskLevel::Low,
+            "MEDIUM" => RiskLevel::Medium,
+            "HIGH" => RiskLevel::High,
+            _ => return Err(format!("Invalid risk level: {}", risk_str)),
+        };
+        let rules = serde_json::from_str(&rules_json)
+            .map_err(|e| format!("Failed to deserialize rules: {}", e))?;
+        let parameters = match parameters_json {
+            Some(json) => serde_json::from_str(&json)
+                .map_err(|e| format!("Failed to deserialize parameters: {}", e))?,
+            None => Default::default(),
+        };
+        let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+            .map_err(|e| format!("Failed to parse created_at: {}", e))?
+            .with_timezone(&Utc);
+
+        Ok(StrategyTemplate {
+            id,
+            name,
+            description,
+            category,
+            risk_level,
+            rules,
+            parameters,
+            created_at,
+            author,
+        })
+    })())
+}
+
+/// Insert a strategy template
+pub fn insert_template(template: &StrategyTemplate) -> Result<(), String> {
+    let conn = get_connection()?;
+
+    let rules_json = serde_json::to_string(&template.rules)
+        .map_err(|e| format!("Failed to serialize rules: {}", e))?;
+
+    let parameters_json = serde_json::to_string(&template.parameters)
+        .map_err(|e| format!("Failed to serialize parameters: {}", e))?;
+
+    conn.execute(
+        "INSERT INTO strategy_templates (id, name, description, category, risk_level, rules_json, parameters_json, created_at, updated_at, author)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            template.id,
+            template.name,
+            template.description,
+            template.category,
+            template.risk_level.to_string(),
+            rules_json,
+            parameters_json,
+            template.created_at.to_rfc3339(),
+            Utc::now().to_rfc3339(),
+            template.author,
+        ],
+    )
+    .map_err(|e| format!("Failed to insert template: {}", e))?;
+
+    logger::info(
+        LogTag::System,
+        &format!(
+            "Inserted strategy template: id={}, name={}",
+            template.id, template.name
+        ),
+    );
+
+    Ok(())
+}
+
+/// Get a strategy template by ID
+pub fn get_template(template_id: &str) -> Result<Option<StrategyTemplate>, String> {
+    let conn = get_connection()?;
+
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM strategy_templates WHERE id = ?1",
+            TEMPLATE_COLUMNS
+        ),
+        params![template_id],
+        row_to_template,
+    )
+    .optional()
+    .map_err(|e| format!("Failed to get template: {}", e))?
+    .transpose()
+}
+
+/// Get all strategy templates (newest first)
+pub fn get_all_templates() -> Result<Vec<StrategyTemplate>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM strategy_templates ORDER BY created_at DESC",
+            TEMPLATE_COLUMNS
+        ))
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map([], row_to_template)
+        .map_err(|e| format!("Failed to query templates: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect templates: {}", e))?;
+
+    rows.into_iter().collect()
+}
+
 // =============================================================================
 // PERFORMANCE TRACKING
 // =============================================================================
@@ -678,20 +1009,84 @@ pub fn get_strategy_performance(strategy_id: &str) -> Result<Option<StrategyPerf
 // STRATEGY ASSIGNMENTS (Position to Strategy mapping)
 // =============================================================================
 
-/// Assign a strategy to a position
-pub fn assign_strategy_to_position(position_id: &str, strategy_id: &str) -> Result<(), String> {
+/// Assign a strategy to a position, recording the exact revision and whether it opened
+/// (`StrategyType::Entry`) or closed (`StrategyType::Exit`) the position
+pub fn assign_strategy_to_position(
+    position_id: &str,
+    strategy_id: &str,
+    strategy_version: i32,
+    role: StrategyType,
+) -> Result<(), String> {
     let conn = get_connection()?;
 
     conn.execute(
-        "INSERT OR REPLACE INTO strategy_assignments (position_id, strategy_id, assigned_at)
-         VALUES (?1, ?2, ?3)",
-        params![position_id, strategy_id, Utc::now().to_rfc3339()],
+        "INSERT OR REPLACE INTO strategy_assignments (position_id, strategy_id, assigned_at, strategy_version, role)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            position_id,
+            strategy_id,
+            Utc::now().to_rfc3339(),
+            strategy_version,
+            role.to_string()
+        ],
     )
     .map_err(|e| format!("Failed to assign strategy: {}", e))?;
 
     Ok(())
 }
 
+/// Get strategy revisions linked to a position (oldest first)
+pub fn get_position_strategy_assignments(
+    position_id: &str,
+) -> Result<Vec<StrategyAssignment>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT position_id, strategy_id, strategy_version, role, assigned_at
+             FROM strategy_assignments
+             WHERE position_id = ?1
+             ORDER BY assigned_at ASC",
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map(params![position_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<i32>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to query assignments: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect assignments: {}", e))?;
+
+    rows.into_iter()
+        .map(
+            |(position_id, strategy_id, strategy_version, role, assigned_at)| {
+                let assigned_at = DateTime::parse_from_rfc3339(&assigned_at)
+                    .map_err(|e| format!("Failed to parse assigned_at: {}", e))?
+                    .with_timezone(&Utc);
+                let role = match role.as_deref() {
+                    Some("ENTRY") => Some(StrategyType::Entry),
+                    Some("EXIT") => Some(StrategyType::Exit),
+                    _ => None,
+                };
+                Ok(StrategyAssignment {
+                    position_id,
+                    strategy_id,
+                    strategy_version,
+                    role,
+                    assigned_at,
+                })
+            },
+        )
+        .collect()
+}
+
 /// Get strategies assigned to a position
 pub fn get_position_strategies(position_id: &str) -> Result<Vec<String>, String> {
     let conn = get_connection()?;
@@ -708,3 +1103,106 @@ pub fn get_position_strategies(position_id: &str) -> Result<Vec<String>, String>
 
     Ok(strategies)
 }
+
+/// Get positions a strategy opened (newest first)
+pub fn get_strategy_position_ids(strategy_id: &str, limit: usize) -> Result<Vec<String>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT position_id FROM strategy_assignments
+             WHERE strategy_id = ?1
+             ORDER BY assigned_at DESC
+             LIMIT ?2",
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let positions = stmt
+        .query_map(params![strategy_id, limit as i64], |row| row.get(0))
+        .map_err(|e| format!("Failed to query assignments: {}", e))?
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|e| format!("Failed to collect assignments: {}", e))?;
+
+    Ok(positions)
+}
+
+// =============================================================================
+// BACKTESTS
+// =============================================================================
+
+/// Store a completed backtest report
+pub fn insert_backtest(report: &BacktestReport) -> Result<(), String> {
+    let conn = get_connection()?;
+
+    let results_json = serde_json::to_string(report)
+        .map_err(|e| format!("Failed to serialize backtest report: {}", e))?;
+
+    conn.execute(
+        "INSERT INTO strategy_backtests (id, strategy_id, start_time, end_time, total_trades, win_trades, loss_trades, total_profit_sol, results_json)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            report.id,
+            report.strategy_id,
+            report.start_time.to_rfc3339(),
+            report.end_time.to_rfc3339(),
+            report.total_trades as i64,
+            report.win_trades as i64,
+            report.loss_trades as i64,
+            report.total_profit_sol,
+            results_json,
+        ],
+    )
+    .map_err(|e| format!("Failed to insert backtest: {}", e))?;
+
+    Ok(())
+}
+
+/// Get a stored backtest report by ID
+pub fn get_backtest(backtest_id: &str) -> Result<Option<BacktestReport>, String> {
+    let conn = get_connection()?;
+
+    let results_json: Option<String> = conn
+        .query_row(
+            "SELECT results_json FROM strategy_backtests WHERE id = ?1",
+            params![backtest_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get backtest: {}", e))?;
+
+    results_json
+        .map(|json| {
+            serde_json::from_str(&json)
+                .map_err(|e| format!("Failed to deserialize backtest report: {}", e))
+        })
+        .transpose()
+}
+
+/// Get stored backtest reports for a strategy (newest first)
+pub fn get_backtests(strategy_id: &str, limit: usize) -> Result<Vec<BacktestReport>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT results_json FROM strategy_backtests
+             WHERE strategy_id = ?1
+             ORDER BY rowid DESC
+             LIMIT ?2",
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map(params![strategy_id, limit as i64], |row| {
+            row.get::<_, String>(0)
+        })
+        .map_err(|e| format!("Failed to query backtests: {}", e))?
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|e| format!("Failed to collect backtests: {}", e))?;
+
+    rows.iter()
+        .map(|json| {
+            serde_json::from_str(json)
+                .map_err(|e| format!("Failed to deserialize backtest report: {}", e))
+        })
+        .collect()
+}
@@ -250,11 +250,11 @@ impl StrategyEngine {
 
     /// Validate a strategy without evaluating
     pub fn validate_strategy(&self, strategy: &Strategy) -> Result<(), String> {
-        self.validate_rule_tree(&strategy.rules)
+        self.validate_rules(&strategy.rules)
     }
 
     /// Validate a rule tree recursively
-    fn validate_rule_tree(&self, rule_tree: &RuleTree) -> Result<(), String> {
+    pub fn validate_rules(&self, rule_tree: &RuleTree) -> Result<(), String> {
         // Leaf node - validate condition
         if rule_tree.is_leaf() {
             if let Some(condition) = &rule_tree.condition {
@@ -292,7 +292,7 @@ impl StrategyEngine {
 
             // Validate all children recursively
             for child in conditions {
-                self.validate_rule_tree(child)?;
+                self.validate_rules(child)?;
             }
 
             return Ok(());
@@ -0,0 +1,907 @@
+//! Formula expressions for strategy conditions
+//!
+//! A small, side-effect free interpreter used by the `Formula` condition. It
+//! supports arithmetic, comparisons and boolean logic over candle series,
+//! indicator functions and a fixed set of position / market fields:
+//!
+//! ```text
+//! close > sma(close, 50) * 1.05 and volume > 2 * avg(volume, 20)
+//! rsi(close, 14) < 30 or position.pnl_pct <= -10
+//! ```
+//!
+//! - Series: `open`, `high`, `low`, `close`, `volume` (latest candle); `close[3]` is 3 candles ago
+//! - Scalars: `price`, `position.*`, `market.*` (see [`FIELDS`])
+//! - Functions: see [`FUNCTIONS`]
+//!
+//! Expressions are fully checked at parse time (unknown names, arity, literal
+//! periods), so a strategy that validates can only fail at evaluation time
+//! because data is missing.
+
+use crate::ohlcvs::Candle;
+use crate::strategies::indicators;
+use crate::strategies::types::{MarketData, PositionData};
+
+/// Maximum accepted expression length in characters
+pub const MAX_EXPRESSION_LENGTH: usize = 1000;
+
+/// Maximum nesting depth (parentheses, unary operators, function calls)
+const MAX_DEPTH: usize = 32;
+
+/// Maximum period / offset accepted in series functions
+const MAX_PERIOD: usize = 500;
+
+/// Candle series that can be referenced by name
+const SERIES: &[&str] = &["open", "high", "low", "close", "volume"];
+
+/// Scalar fields resolved from the evaluation context
+pub const FIELDS: &[&str] = &[
+    "price",
+    "position.pnl_pct",
+    "position.entry_price",
+    "position.size_sol",
+    "position.age_hours",
+    "market.liquidity_sol",
+    "market.volume_24h",
+    "market.market_cap",
+    "market.holder_count",
+    "market.token_age_hours",
+];
+
+/// Supported functions with their signatures (for docs and UI hints)
+pub const FUNCTIONS: &[(&str, &str)] = &[
+    ("sma", "sma(series, period) - simple moving average"),
+    ("avg", "avg(series, period) - alias of sma"),
+    ("ema", "ema(series, period) - exponential moving average"),
+    (
+        "highest",
+        "highest(series, period) - highest value in window",
+    ),
+    ("lowest", "lowest(series, period) - lowest value in window"),
+    ("sum", "sum(series, period) - sum over window"),
+    (
+        "stdev",
+        "stdev(series, period) - standard deviation over window",
+    ),
+    (
+        "change",
+        "change(series, period) - % change over period candles",
+    ),
+    ("rsi", "rsi(series, period) - Wilder RSI (0-100)"),
+    ("atr", "atr(period) - average true range"),
+    ("vwap", "vwap(period) - volume-weighted average price"),
+    ("abs", "abs(x) - absolute value"),
+    ("min", "min(a, b) - smaller of two values"),
+    ("max", "max(a, b) - larger of two values"),
+];
+
+/// Parsed expression tree
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Bool(bool),
+    /// Candle series value `offset` candles back from the latest
+    Series {
+        name: String,
+        offset: usize,
+    },
+    /// Scalar context field (`price`, `position.*`, `market.*`)
+    Field(String),
+    Call {
+        name: String,
+        args: Vec<Expr>,
+    },
+    Unary {
+        op: UnaryOp,
+        expr: Box<Expr>,
+    },
+    Binary {
+        op: BinaryOp,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+    And,
+    Or,
+}
+
+/// Runtime value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Bool(bool),
+}
+
+impl Value {
+    fn as_number(self) -> Result<f64, String> {
+        match self {
+            Value::Number(n) => Ok(n),
+            Value::Bool(_) => Err("Expected a number, found a boolean".to_string()),
+        }
+    }
+
+    fn as_bool(self) -> Result<bool, String> {
+        match self {
+            Value::Bool(b) => Ok(b),
+            Value::Number(_) => Err("Expected a boolean, found a number".to_string()),
+        }
+    }
+}
+
+/// Data an expression is evaluated against
+pub struct ExpressionContext<'a> {
+    /// Oldest-first candles for the condition timeframe (empty if not needed)
+    pub candles: &'a [Candle],
+    pub current_price: Option<f64>,
+    pub position: Option<&'a PositionData>,
+    pub market: Option<&'a MarketData>,
+}
+
+impl Expr {
+    /// Whether evaluating this expression needs candle data
+    pub fn uses_candles(&self) -> bool {
+        match self {
+            Expr::Number(_) | Expr::Bool(_) | Expr::Field(_) => false,
+            Expr::Series { .. } => true,
+            Expr::Call { name, args } => {
+                matches!(name.as_str(), "atr" | "vwap") || args.iter().any(Expr::uses_candles)
+            }
+            Expr::Unary { expr, .. } => expr.uses_candles(),
+            Expr::Binary { left, right, .. } => left.uses_candles() || right.uses_candles(),
+        }
+    }
+
+    /// Evaluate the expression
+    pub fn evaluate(&self, ctx: &ExpressionContext) -> Result<Value, String> {
+        match self {
+            Expr::Number(n) => Ok(Value::Number(*n)),
+            Expr::Bool(b) => Ok(Value::Bool(*b)),
+            Expr::Series { name, offset } => {
+                let values = series_values(ctx.candles, name, *offset)?;
+                values
+                    .last()
+                    .copied()
+                    .map(Value::Number)
+                    .ok_or_else(|| format!("Not enough candles for {}[{}]", name, offset))
+            }
+            Expr::Field(name) => resolve_field(name, ctx).map(Value::Number),
+            Expr::Call { name, args } => evaluate_call(name, args, ctx).map(Value::Number),
+            Expr::Unary { op, expr } => {
+                let value = expr.evaluate(ctx)?;
+                match op {
+                    UnaryOp::Neg => Ok(Value::Number(-value.as_number()?)),
+                    UnaryOp::Not => Ok(Value::Bool(!value.as_bool()?)),
+                }
+            }
+            Expr::Binary { op, left, right } => evaluate_binary(*op, left, right, ctx),
+        }
+    }
+}
+
+/// Parse an expression, validating names, arity and literal arguments
+pub fn parse(source: &str) -> Result<Expr, String> {
+    if source.trim().is_empty() {
+        return Err("Expression is empty".to_string());
+    }
+    if source.len() > MAX_EXPRESSION_LENGTH {
+        return Err(format!(
+            "Expression too long: {} > {} characters",
+            source.len(),
+            MAX_EXPRESSION_LENGTH
+        ));
+    }
+
+    let tokens = tokenize(source)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        depth: 0,
+    };
+    let expr = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+        return Err(format!("Unexpected '{}' after end of expression", token));
+    }
+    Ok(expr)
+}
+
+// =============================================================================
+// Tokenizer
+// =============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(&'static str),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Ident(s) => write!(f, "{}", s),
+            Token::Op(op) => write!(f, "{}", op),
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    const OPERATORS: &[&str] = &[
+        "<=", ">=", "==", "!=", "&&", "||", "<", ">", "=", "!", "+", "-", "*", "/", "%", "(", ")",
+        "[", "]", ",",
+    ];
+
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit()
+            || (c == '.' && matches!(chars.get(i + 1), Some(n) if n.is_ascii_digit()))
+        {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            let number = text
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid number '{}'", text))?;
+            tokens.push(Token::Number(number));
+            continue;
+        }
+
+        if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+            {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            continue;
+        }
+
+        let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+        let op = OPERATORS
+            .iter()
+            .find(|op| rest.starts_with(**op))
+            .ok_or_else(|| format!("Unexpected character '{}' at position {}", c, i + 1))?;
+        tokens.push(Token::Op(op));
+        i += op.len();
+    }
+
+    Ok(tokens)
+}
+
+// =============================================================================
+// Parser (precedence climbing: or < and < not < comparison < additive < multiplicative < unary)
+// =============================================================================
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek_op(&self, ops: &[&str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Op(op)) if ops.contains(op) => Some(*op),
+            _ => None,
+        }
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(s)) if s.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_op(&mut self, op: &str) -> Result<(), String> {
+        match self.next() {
+            Some(Token::Op(found)) if found == op => Ok(()),
+            Some(token) => Err(format!("Expected '{}', found '{}'", op, token)),
+            None => Err(format!("Expected '{}', found end of expression", op)),
+        }
+    }
+
+    fn enter(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(format!("Expression nested too deeply (max {})", MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_and()?;
+        while self.peek_keyword("or") || self.peek_op(&["||"]).is_some() {
+            self.pos += 1;
+            let right = self.parse_and()?;
+            left = binary(BinaryOp::Or, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_not()?;
+        while self.peek_keyword("and") || self.peek_op(&["&&"]).is_some() {
+            self.pos += 1;
+            let right = self.parse_not()?;
+            left = binary(BinaryOp::And, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, String> {
+        if self.peek_keyword("not") || self.peek_op(&["!"]).is_some() {
+            self.pos += 1;
+            self.enter()?;
+            let expr = self.parse_not()?;
+            self.depth -= 1;
+            return Ok(Expr::Unary {
+                op: UnaryOp::Not,
+                expr: Box::new(expr),
+            });
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, String> {
+        let left = self.parse_additive()?;
+        let Some(op) = self.peek_op(&["<", "<=", ">", ">=", "==", "=", "!="]) else {
+            return Ok(left);
+        };
+        self.pos += 1;
+        let right = self.parse_additive()?;
+        let op = match op {
+            "<" => BinaryOp::Lt,
+            "<=" => BinaryOp::Le,
+            ">" => BinaryOp::Gt,
+            ">=" => BinaryOp::Ge,
+            "!=" => BinaryOp::Ne,
+            _ => BinaryOp::Eq,
+        };
+        if self
+            .peek_op(&["<", "<=", ">", ">=", "==", "=", "!="])
+            .is_some()
+        {
+            return Err("Chained comparisons are not supported; use 'and'".to_string());
+        }
+        Ok(binary(op, left, right))
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_multiplicative()?;
+        while let Some(op) = self.peek_op(&["+", "-"]) {
+            self.pos += 1;
+            let right = self.parse_multiplicative()?;
+            let op = if op == "+" {
+                BinaryOp::Add
+            } else {
+                BinaryOp::Sub
+            };
+            left = binary(op, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_unary()?;
+        while let Some(op) = self.peek_op(&["*", "/", "%"]) {
+            self.pos += 1;
+            let right = self.parse_unary()?;
+            let op = match op {
+                "*" => BinaryOp::Mul,
+                "/" => BinaryOp::Div,
+                _ => BinaryOp::Mod,
+            };
+            left = binary(op, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, String> {
+        if self.peek_op(&["-"]).is_some() {
+            self.pos += 1;
+            self.enter()?;
+            let expr = self.parse_unary()?;
+            self.depth -= 1;
+            return Ok(Expr::Unary {
+                op: UnaryOp::Neg,
+                expr: Box::new(expr),
+            });
+        }
+        if self.peek_op(&["+"]).is_some() {
+            self.pos += 1;
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Number(n)),
+            Some(Token::Op("(")) => {
+                self.enter()?;
+                let expr = self.parse_or()?;
+                self.depth -= 1;
+                self.expect_op(")")?;
+                Ok(expr)
+            }
+            Some(Token::Ident(name)) => self.parse_identifier(name),
+            Some(token) => Err(format!("Unexpected '{}'", token)),
+            None => Err("Unexpected end of expression".to_string()),
+        }
+    }
+
+    fn parse_identifier(&mut self, name: String) -> Result<Expr, String> {
+        let lower = name.to_ascii_lowercase();
+
+        match lower.as_str() {
+            "true" => return Ok(Expr::Bool(true)),
+            "false" => return Ok(Expr::Bool(false)),
+            "and" | "or" | "not" => return Err(format!("Unexpected keyword '{}'", name)),
+            _ => {}
+        }
+
+        if self.peek_op(&["("]).is_some() {
+            self.pos += 1;
+            self.enter()?;
+            let mut args = Vec::new();
+            if self.peek_op(&[")"]).is_none() {
+                loop {
+                    args.push(self.parse_or()?);
+                    if self.peek_op(&[","]).is_some() {
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+            self.expect_op(")")?;
+            self.depth -= 1;
+            validate_call(&lower, &args)?;
+            return Ok(Expr::Call { name: lower, args });
+        }
+
+        if SERIES.contains(&lower.as_str()) {
+            let mut offset = 0;
+            if self.peek_op(&["["]).is_some() {
+                self.pos += 1;
+                offset = match self.next() {
+                    Some(Token::Number(n)) => literal_count(n, "Series offset", 0)?,
+                    _ => return Err(format!("{}[..] offset must be a number literal", lower)),
+                };
+                self.expect_op("]")?;
+            }
+            return Ok(Expr::Series {
+                name: lower,
+                offset,
+            });
+        }
+
+        if FIELDS.contains(&lower.as_str()) {
+            return Ok(Expr::Field(lower));
+        }
+
+        Err(format!(
+            "Unknown name '{}' (series: {}; fields: {})",
+            name,
+            SERIES.join(", "),
+            FIELDS.join(", ")
+        ))
+    }
+}
+
+fn binary(op: BinaryOp, left: Expr, right: Expr) -> Expr {
+    Expr::Binary {
+        op,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+/// Validate a literal used as a period / offset
+fn literal_count(value: f64, what: &str, min: usize) -> Result<usize, String> {
+    if value.fract() != 0.0 || value < min as f64 || value > MAX_PERIOD as f64 {
+        return Err(format!(
+            "{} must be a whole number between {} and {}",
+            what, min, MAX_PERIOD
+        ));
+    }
+    Ok(value as usize)
+}
+
+fn validate_call(name: &str, args: &[Expr]) -> Result<(), String> {
+    let expect_args = |count: usize| -> Result<(), String> {
+        if args.len() != count {
+            return Err(format!(
+                "{}() expects {} argument(s), got {}",
+                name,
+                count,
+                args.len()
+            ));
+        }
+        Ok(())
+    };
+    let expect_period = |arg: &Expr| -> Result<(), String> {
+        match arg {
+            Expr::Number(n) => literal_count(*n, &format!("{}() period", name), 1).map(|_| ()),
+            _ => Err(format!("{}() period must be a number literal", name)),
+        }
+    };
+
+    match name {
+        "sma" | "avg" | "ema" | "highest" | "lowest" | "sum" | "stdev" | "change" | "rsi" => {
+            expect_args(2)?;
+            if !matches!(args[0], Expr::Series { .. }) {
+                return Err(format!(
+                    "{}() first argument must be a series ({})",
+                    name,
+                    SERIES.join(", ")
+                ));
+            }
+            expect_period(&args[1])
+        }
+        "atr" | "vwap" => {
+            expect_args(1)?;
+            expect_period(&args[0])
+        }
+        "abs" => expect_args(1),
+        "min" | "max" => expect_args(2),
+        _ => Err(format!(
+            "Unknown function '{}' (available: {})",
+            name,
+            FUNCTIONS
+                .iter()
+                .map(|(f, _)| *f)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+// =============================================================================
+// Evaluation
+// =============================================================================
+
+/// Series values up to (and including) the candle `offset` back from the latest
+fn series_values(candles: &[Candle], name: &str, offset: usize) -> Result<Vec<f64>, String> {
+    if candles.len() <= offset {
+        return Err(format!(
+            "Not enough candles for {}[{}]: {} available",
+            name,
+            offset,
+            candles.len()
+        ));
+    }
+    let window = &candles[..candles.len() - offset];
+    let values = window
+        .iter()
+        .map(|c| match name {
+            "open" => c.open,
+            "high" => c.high,
+            "low" => c.low,
+            "volume" => c.volume,
+            _ => c.close,
+        })
+        .collect();
+    Ok(values)
+}
+
+fn resolve_field(name: &str, ctx: &ExpressionContext) -> Result<f64, String> {
+    let missing = || format!("{} not available", name);
+
+    if name == "price" {
+        return ctx.current_price.ok_or_else(missing);
+    }
+
+    if let Some(field) = name.strip_prefix("position.") {
+        let position = ctx
+            .position
+            .ok_or_else(|| format!("{} requires an open position", name))?;
+        return match field {
+            "pnl_pct" => position.unrealized_profit_pct.ok_or_else(missing),
+            "entry_price" => Ok(position.entry_price),
+            "size_sol" => Ok(position.current_size_sol),
+            "age_hours" => Ok(position.position_age_hours),
+            _ => Err(format!("Unknown field '{}'", name)),
+        };
+    }
+
+    if let Some(field) = name.strip_prefix("market.") {
+        let market = ctx.market.ok_or_else(missing)?;
+        return match field {
+            "liquidity_sol" => market.liquidity_sol.ok_or_else(missing),
+            "volume_24h" => market.volume_24h.ok_or_else(missing),
+            "market_cap" => market.market_cap.ok_or_else(missing),
+            "holder_count" => market.holder_count.map(|v| v as f64).ok_or_else(missing),
+            "token_age_hours" => market.token_age_hours.ok_or_else(missing),
+            _ => Err(format!("Unknown field '{}'", name)),
+        };
+    }
+
+    Err(format!("Unknown field '{}'", name))
+}
+
+fn evaluate_call(name: &str, args: &[Expr], ctx: &ExpressionContext) -> Result<f64, String> {
+    let period = |arg: &Expr| match arg {
+        Expr::Number(n) => *n as usize,
+        _ => 0,
+    };
+    let not_enough = |needed: usize, available: usize| {
+        format!(
+            "Not enough candles for {}(): {} < {}",
+            name, available, needed
+        )
+    };
+
+    match name {
+        "abs" => Ok(args[0].evaluate(ctx)?.as_number()?.abs()),
+        "min" => Ok(args[0]
+            .evaluate(ctx)?
+            .as_number()?
+            .min(args[1].evaluate(ctx)?.as_number()?)),
+        "max" => Ok(args[0]
+            .evaluate(ctx)?
+            .as_number()?
+            .max(args[1].evaluate(ctx)?.as_number()?)),
+        "atr" => {
+            let period = period(&args[0]);
+            indicators::atr_series(ctx.candles, period)
+                .last()
+                .copied()
+                .ok_or_else(|| not_enough(period + 1, ctx.candles.len()))
+        }
+        "vwap" => {
+            let period = period(&args[0]);
+            if ctx.candles.len() < period {
+                return Err(not_enough(period, ctx.candles.len()));
+            }
+            indicators::vwap(ctx.candles, period)
+                .ok_or_else(|| "vwap(): no volume in window".to_string())
+        }
+        _ => {
+            let Expr::Series {
+                name: series,
+                offset,
+            } = &args[0]
+            else {
+                return Err(format!("{}() first argument must be a series", name));
+            };
+            let values = series_values(ctx.candles, series, *offset)?;
+            let period = period(&args[1]);
+
+            let window = || {
+                values
+                    .len()
+                    .checked_sub(period)
+                    .map(|start| &values[start..])
+                    .ok_or_else(|| not_enough(period, values.len()))
+            };
+
+            match name {
+                "sma" | "avg" => {
+                    indicators::sma(&values, period).ok_or_else(|| not_enough(period, values.len()))
+                }
+                "ema" => indicators::ema_series(&values, period)
+                    .last()
+                    .copied()
+                    .ok_or_else(|| not_enough(period, values.len())),
+                "rsi" => indicators::rsi_series(&values, period)
+                    .last()
+                    .copied()
+                    .ok_or_else(|| not_enough(period + 1, values.len())),
+                "highest" => Ok(window()?.iter().copied().fold(f64::MIN, f64::max)),
+                "lowest" => Ok(window()?.iter().copied().fold(f64::MAX, f64::min)),
+                "sum" => Ok(window()?.iter().sum()),
+                "stdev" => {
+                    let window = window()?;
+                    let mean = window.iter().sum::<f64>() / period as f64;
+                    let variance =
+                        window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / period as f64;
+                    Ok(variance.sqrt())
+                }
+                "change" => {
+                    if values.len() <= period {
+                        return Err(not_enough(period + 1, values.len()));
+                    }
+                    let current = values[values.len() - 1];
+                    let past = values[values.len() - 1 - period];
+                    if past == 0.0 {
+                        return Err("change(): past value is zero".to_string());
+                    }
+                    Ok((current - past) / past * 100.0)
+                }
+                _ => Err(format!("Unknown function '{}'", name)),
+            }
+        }
+    }
+}
+
+fn evaluate_binary(
+    op: BinaryOp,
+    left: &Expr,
+    right: &Expr,
+    ctx: &ExpressionContext,
+) -> Result<Value, String> {
+    // Boolean operators short-circuit
+    match op {
+        BinaryOp::And => {
+            if !left.evaluate(ctx)?.as_bool()? {
+                return Ok(Value::Bool(false));
+            }
+            return Ok(Value::Bool(right.evaluate(ctx)?.as_bool()?));
+        }
+        BinaryOp::Or => {
+            if left.evaluate(ctx)?.as_bool()? {
+                return Ok(Value::Bool(true));
+            }
+            return Ok(Value::Bool(right.evaluate(ctx)?.as_bool()?));
+        }
+        _ => {}
+    }
+
+    let left = left.evaluate(ctx)?;
+    let right = right.evaluate(ctx)?;
+
+    // Equality also works between booleans
+    if let (Value::Bool(a), Value::Bool(b)) = (left, right) {
+        return match op {
+            BinaryOp::Eq => Ok(Value::Bool(a == b)),
+            BinaryOp::Ne => Ok(Value::Bool(a != b)),
+            _ => Err("Arithmetic and ordering are not defined for booleans".to_string()),
+        };
+    }
+
+    let (a, b) = (left.as_number()?, right.as_number()?);
+    let value = match op {
+        BinaryOp::Add => Value::Number(a + b),
+        BinaryOp::Sub => Value::Number(a - b),
+        BinaryOp::Mul => Value::Number(a * b),
+        BinaryOp::Div | BinaryOp::Mod if b == 0.0 => {
+            return Err("Division by zero".to_string());
+        }
+        BinaryOp::Div => Value::Number(a / b),
+        BinaryOp::Mod => Value::Number(a % b),
+        BinaryOp::Lt => Value::Bool(a < b),
+        BinaryOp::Le => Value::Bool(a <= b),
+        BinaryOp::Gt => Value::Bool(a > b),
+        BinaryOp::Ge => Value::Bool(a >= b),
+        BinaryOp::Eq => Value::Bool(a == b),
+        BinaryOp::Ne => Value::Bool(a != b),
+        BinaryOp::And | BinaryOp::Or => unreachable!("handled above"),
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candles(closes: &[f64]) -> Vec<Candle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Candle::new(i as i64, *c, *c, *c, *c, 10.0 + i as f64))
+            .collect()
+    }
+
+    fn eval(source: &str, candles: &[Candle], price: Option<f64>) -> Result<Value, String> {
+        let ctx = ExpressionContext {
+            candles,
+            current_price: price,
+            position: None,
+            market: None,
+        };
+        parse(source)?.evaluate(&ctx)
+    }
+
+    #[test]
+    fn precedence_and_logic() {
+        let data = candles(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(eval("1 + 2 * 3 == 7", &data, None), Ok(Value::Bool(true)));
+        assert_eq!(eval("-(1 + 2) * 2", &data, None), Ok(Value::Number(-6.0)));
+        assert_eq!(
+            eval("not (1 > 2) and (false or 2 >= 2)", &data, None),
+            Ok(Value::Bool(true))
+        );
+    }
+
+    #[test]
+    fn series_and_functions() {
+        let data = candles(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(eval("close", &data, None), Ok(Value::Number(4.0)));
+        assert_eq!(eval("close[3]", &data, None), Ok(Value::Number(1.0)));
+        assert_eq!(eval("sma(close, 2)", &data, None), Ok(Value::Number(3.5)));
+        assert_eq!(
+            eval("sma(close[1], 2)", &data, None),
+            Ok(Value::Number(2.5))
+        );
+        assert_eq!(
+            eval("highest(close, 4)", &data, None),
+            Ok(Value::Number(4.0))
+        );
+        assert_eq!(
+            eval("change(close, 3)", &data, None),
+            Ok(Value::Number(300.0))
+        );
+        assert_eq!(
+            eval(
+                "close > sma(close, 4) * 1.05 and volume > avg(volume, 3)",
+                &data,
+                None
+            ),
+            Ok(Value::Bool(true))
+        );
+        assert!(eval("sma(close, 10)", &data, None).is_err());
+        assert!(eval("close[4]", &data, None).is_err());
+    }
+
+    #[test]
+    fn fields_require_context() {
+        assert_eq!(eval("price * 2", &[], Some(1.5)), Ok(Value::Number(3.0)));
+        assert!(eval("price > 1", &[], None).is_err());
+        assert!(eval("position.pnl_pct > 1", &[], None).is_err());
+    }
+
+    #[test]
+    fn parse_time_validation() {
+        assert!(parse("").is_err());
+        assert!(parse("close >").is_err());
+        assert!(parse("foo > 1").is_err());
+        assert!(parse("sma(close)").is_err());
+        assert!(parse("sma(2, 20)").is_err());
+        assert!(parse("sma(close, 2.5)").is_err());
+        assert!(parse("sma(close, price)").is_err());
+        assert!(parse("bogus(close, 2)").is_err());
+        assert!(parse("1 < 2 < 3").is_err());
+        assert!(parse("close $ 2").is_err());
+        assert!(parse(&"(".repeat(40)).is_err());
+        assert!(parse("rsi(close, 14) < 30 or position.pnl_pct <= -10").is_ok());
+        assert!(!parse("price > 1").unwrap().uses_candles());
+        assert!(parse("atr(14) > 0").unwrap().uses_candles());
+    }
+
+    #[test]
+    fn runtime_type_errors() {
+        assert!(eval("1 + true", &[], None).is_err());
+        assert!(eval("1 / 0", &[], None).is_err());
+        assert_eq!(eval("true == (1 < 2)", &[], None), Ok(Value::Bool(true)));
+    }
+}
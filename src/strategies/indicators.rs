@@ -0,0 +1,320 @@
+//! Technical indicator library shared by strategy conditions
+//!
+//! All functions take oldest-first series (the order candles are stored in a
+//! `TimeframeBundle`). Series outputs are aligned to the END of the input:
+//! the last element always corresponds to the most recent candle, and the
+//! warm-up period at the start is dropped rather than padded.
+
+use crate::ohlcvs::Candle;
+
+/// Close prices of a candle series
+pub fn closes(candles: &[Candle]) -> Vec<f64> {
+    candles.iter().map(|c| c.close).collect()
+}
+
+/// Simple moving average of the last `period` values
+pub fn sma(values: &[f64], period: usize) -> Option<f64> {
+    if period == 0 || values.len() < period {
+        return None;
+    }
+    let window = &values[values.len() - period..];
+    Some(window.iter().sum::<f64>() / period as f64)
+}
+
+/// Exponential moving average series, seeded with the SMA of the first `period` values
+///
+/// Output length is `values.len() - period + 1`.
+pub fn ema_series(values: &[f64], period: usize) -> Vec<f64> {
+    if period == 0 || values.len() < period {
+        return Vec::new();
+    }
+
+    let k = 2.0 / (period as f64 + 1.0);
+    let mut current = values[..period].iter().sum::<f64>() / period as f64;
+    let mut series = Vec::with_capacity(values.len() - period + 1);
+    series.push(current);
+
+    for value in &values[period..] {
+        current = (value - current) * k + current;
+        series.push(current);
+    }
+
+    series
+}
+
+/// Wilder's RSI series (0-100)
+///
+/// Output length is `closes.len() - period`; element `i` corresponds to `closes[i + period]`.
+pub fn rsi_series(closes: &[f64], period: usize) -> Vec<f64> {
+    if period == 0 || closes.len() <= period {
+        return Vec::new();
+    }
+
+    let changes: Vec<f64> = closes.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let mut avg_gain = changes[..period].iter().map(|c| c.max(0.0)).sum::<f64>() / period as f64;
+    let mut avg_loss = changes[..period].iter().map(|c| (-c).max(0.0)).sum::<f64>() / period as f64;
+
+    let to_rsi = |gain: f64, loss: f64| {
+        if loss == 0.0 {
+            if gain == 0.0 {
+                50.0
+            } else {
+                100.0
+            }
+        } else {
+            100.0 - 100.0 / (1.0 + gain / loss)
+        }
+    };
+
+    let mut series = Vec::with_capacity(changes.len() - period + 1);
+    series.push(to_rsi(avg_gain, avg_loss));
+
+    for change in &changes[period..] {
+        avg_gain = (avg_gain * (period as f64 - 1.0) + change.max(0.0)) / period as f64;
+        avg_loss = (avg_loss * (period as f64 - 1.0) + (-change).max(0.0)) / period as f64;
+        series.push(to_rsi(avg_gain, avg_loss));
+    }
+
+    series
+}
+
+/// One MACD sample
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MacdPoint {
+    pub macd: f64,
+    pub signal: f64,
+    pub histogram: f64,
+}
+
+/// MACD series (fast EMA - slow EMA) with its signal line
+///
+/// Output length is `closes.len() - slow - signal + 2`.
+pub fn macd_series(closes: &[f64], fast: usize, slow: usize, signal: usize) -> Vec<MacdPoint> {
+    if fast == 0 || signal == 0 || fast >= slow {
+        return Vec::new();
+    }
+
+    let fast_ema = ema_series(closes, fast);
+    let slow_ema = ema_series(closes, slow);
+    if slow_ema.is_empty() {
+        return Vec::new();
+    }
+
+    // Align fast EMA to the (shorter) slow EMA series
+    let offset = fast_ema.len() - slow_ema.len();
+    let macd_line: Vec<f64> = slow_ema
+        .iter()
+        .enumerate()
+        .map(|(i, slow)| fast_ema[i + offset] - slow)
+        .collect();
+
+    let signal_line = ema_series(&macd_line, signal);
+    if signal_line.is_empty() {
+        return Vec::new();
+    }
+
+    let offset = macd_line.len() - signal_line.len();
+    signal_line
+        .iter()
+        .enumerate()
+        .map(|(i, signal)| {
+            let macd = macd_line[i + offset];
+            MacdPoint {
+                macd,
+                signal: *signal,
+                histogram: macd - signal,
+            }
+        })
+        .collect()
+}
+
+/// Bollinger Bands for a single window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BollingerBands {
+    pub middle: f64,
+    pub upper: f64,
+    pub lower: f64,
+    /// Band width as % of the middle band
+    pub bandwidth_pct: f64,
+}
+
+/// Bollinger Bands over the last `period` values (population standard deviation)
+pub fn bollinger(values: &[f64], period: usize, std_dev: f64) -> Option<BollingerBands> {
+    let middle = sma(values, period)?;
+    if middle <= 0.0 {
+        return None;
+    }
+
+    let window = &values[values.len() - period..];
+    let variance = window.iter().map(|v| (v - middle).powi(2)).sum::<f64>() / period as f64;
+    let deviation = variance.sqrt() * std_dev;
+
+    Some(BollingerBands {
+        middle,
+        upper: middle + deviation,
+        lower: middle - deviation,
+        bandwidth_pct: (2.0 * deviation) / middle * 100.0,
+    })
+}
+
+/// True range series; element `i` corresponds to `candles[i + 1]`
+pub fn true_ranges(candles: &[Candle]) -> Vec<f64> {
+    candles
+        .windows(2)
+        .map(|pair| {
+            let (prev, candle) = (&pair[0], &pair[1]);
+            (candle.high - candle.low)
+                .max((candle.high - prev.close).abs())
+                .max((candle.low - prev.close).abs())
+        })
+        .collect()
+}
+
+/// Wilder's Average True Range series
+///
+/// Output length is `candles.len() - period`.
+pub fn atr_series(candles: &[Candle], period: usize) -> Vec<f64> {
+    let ranges = true_ranges(candles);
+    if period == 0 || ranges.len() < period {
+        return Vec::new();
+    }
+
+    let mut current = ranges[..period].iter().sum::<f64>() / period as f64;
+    let mut series = Vec::with_capacity(ranges.len() - period + 1);
+    series.push(current);
+
+    for range in &ranges[period..] {
+        current = (current * (period as f64 - 1.0) + range) / period as f64;
+        series.push(current);
+    }
+
+    series
+}
+
+/// Volume-weighted average price over the last `period` candles (typical price)
+pub fn vwap(candles: &[Candle], period: usize) -> Option<f64> {
+    if period == 0 || candles.len() < period {
+        return None;
+    }
+
+    let window = &candles[candles.len() - period..];
+    let (weighted, volume) = window.iter().fold((0.0, 0.0), |(w, v), c| {
+        let typical = (c.high + c.low + c.close) / 3.0;
+        (w + typical * c.volume, v + c.volume)
+    });
+
+    if volume <= 0.0 {
+        return None;
+    }
+    Some(weighted / volume)
+}
+
+/// Whether series `a` crossed above series `b` between the previous and current sample
+pub fn crossed_above(a_prev: f64, a_curr: f64, b_prev: f64, b_curr: f64) -> bool {
+    a_prev <= b_prev && a_curr > b_curr
+}
+
+/// Whether series `a` crossed below series `b` between the previous and current sample
+pub fn crossed_below(a_prev: f64, a_curr: f64, b_prev: f64, b_curr: f64) -> bool {
+    a_prev >= b_prev && a_curr < b_curr
+}
+
+/// Whether `a` crossed `b` (upward or downward) within the last `within` samples
+///
+/// Both series must be aligned to the end; only their common tail is compared.
+pub fn crossed_within(a: &[f64], b: &[f64], within: usize, upward: bool) -> bool {
+    let len = a.len().min(b.len());
+    if len < 2 || within == 0 {
+        return false;
+    }
+
+    let a = &a[a.len() - len..];
+    let b = &b[b.len() - len..];
+    let first = len.saturating_sub(within).max(1);
+
+    (first..len).any(|i| {
+        if upward {
+            crossed_above(a[i - 1], a[i], b[i - 1], b[i])
+        } else {
+            crossed_below(a[i - 1], a[i], b[i - 1], b[i])
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(high: f64, low: f64, close: f64, volume: f64) -> Candle {
+        Candle::new(0, close, high, low, close, volume)
+    }
+
+    #[test]
+    fn ema_seeds_with_sma_and_aligns_to_end() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let ema = ema_series(&values, 3);
+        assert_eq!(ema.len(), 3);
+        assert!((ema[0] - 2.0).abs() < 1e-9);
+        assert!((ema[1] - 3.0).abs() < 1e-9);
+        assert!((ema[2] - 4.0).abs() < 1e-9);
+        assert!(ema_series(&values, 6).is_empty());
+    }
+
+    #[test]
+    fn rsi_extremes_and_flat() {
+        let rising: Vec<f64> = (1..=20).map(|v| v as f64).collect();
+        assert_eq!(rsi_series(&rising, 14).last().copied(), Some(100.0));
+
+        let falling: Vec<f64> = rising.iter().rev().copied().collect();
+        assert_eq!(rsi_series(&falling, 14).last().copied(), Some(0.0));
+
+        let flat = vec![1.0; 20];
+        assert_eq!(rsi_series(&flat, 14).last().copied(), Some(50.0));
+        assert_eq!(rsi_series(&flat, 14).len(), 6);
+    }
+
+    #[test]
+    fn macd_tracks_trend_direction() {
+        let rising: Vec<f64> = (1..=60).map(|v| v as f64).collect();
+        let series = macd_series(&rising, 12, 26, 9);
+        assert_eq!(series.len(), 60 - 26 - 9 + 2);
+        assert!(series.last().unwrap().macd > 0.0);
+        assert!(macd_series(&rising, 26, 12, 9).is_empty());
+    }
+
+    #[test]
+    fn bollinger_flat_series_has_zero_width() {
+        let bands = bollinger(&[2.0; 20], 20, 2.0).unwrap();
+        assert_eq!(bands.middle, 2.0);
+        assert_eq!(bands.upper, 2.0);
+        assert_eq!(bands.bandwidth_pct, 0.0);
+    }
+
+    #[test]
+    fn atr_and_vwap() {
+        let candles = vec![
+            candle(11.0, 9.0, 10.0, 1.0),
+            candle(12.0, 10.0, 11.0, 1.0),
+            candle(13.0, 11.0, 12.0, 3.0),
+        ];
+        assert_eq!(true_ranges(&candles), vec![2.0, 2.0]);
+        assert_eq!(atr_series(&candles, 2), vec![2.0]);
+
+        let vwap = vwap(&candles, 2).unwrap();
+        assert!((vwap - (11.0 * 1.0 + 12.0 * 3.0) / 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn crosses() {
+        assert!(crossed_above(1.0, 3.0, 2.0, 2.0));
+        assert!(!crossed_above(3.0, 4.0, 2.0, 2.0));
+        assert!(crossed_below(3.0, 1.0, 2.0, 2.0));
+
+        let fast = [1.0, 1.0, 3.0, 4.0, 5.0];
+        let slow = [2.0, 2.0, 2.0, 2.0];
+        assert!(crossed_within(&fast, &slow, 3, true));
+        assert!(!crossed_within(&fast, &slow, 2, true));
+        assert!(!crossed_within(&fast, &slow, 3, false));
+    }
+}
@@ -1,7 +1,12 @@
+pub mod backtest;
+pub mod bundle;
 pub mod conditions;
 pub mod db;
 pub mod engine;
+pub mod expression;
+pub mod indicators;
 pub mod types;
+pub mod versions;
 
 use crate::logger::{self, LogTag};
 use crate::ohlcvs::TimeframeBundle;
@@ -57,7 +62,7 @@ async fn get_engine() -> Result<Arc<RwLock<Option<StrategyEngine>>>, String> {
 /// * `timeframe_bundle` - Optional multi-timeframe OHLCV bundle
 ///
 /// # Returns
-/// * `Ok(Some(strategy_id))` - If a strategy signals entry
+/// * `Ok(Some((strategy_id, version)))` - If a strategy signals entry (version = revision evaluated)
 /// * `Ok(None)` - If no strategy signals entry
 /// * `Err(e)` - If evaluation fails
 pub async fn evaluate_entry_strategies(
@@ -65,7 +70,7 @@ pub async fn evaluate_entry_strategies(
     current_price: f64,
     market_data: Option<MarketData>,
     timeframe_bundle: Option<TimeframeBundle>,
-) -> Result<Option<String>, String> {
+) -> Result<Option<(String, i32)>, String> {
     let engine_lock = get_engine().await?;
     let engine_guard = engine_lock.read().await;
     let engine = engine_guard
@@ -111,7 +116,7 @@ pub async fn evaluate_entry_strategies(
                             strategy.name, token_mint, current_price
                         ),
                     );
-                    return Ok(Some(strategy.id.clone()));
+                    return Ok(Some((strategy.id.clone(), strategy.version)));
                 }
             }
             Err(e) => {
@@ -142,7 +147,7 @@ pub async fn evaluate_entry_strategies(
 /// * `timeframe_bundle` - Optional multi-timeframe OHLCV bundle
 ///
 /// # Returns
-/// * `Ok(Some(strategy_id))` - If a strategy signals exit
+/// * `Ok(Some((strategy_id, version)))` - If a strategy signals exit (version = revision evaluated)
 /// * `Ok(None)` - If no strategy signals exit
 /// * `Err(e)` - If evaluation fails
 pub async fn evaluate_exit_strategies(
@@ -151,7 +156,7 @@ pub async fn evaluate_exit_strategies(
     position_data: PositionData,
     market_data: Option<MarketData>,
     timeframe_bundle: Option<TimeframeBundle>,
-) -> Result<Option<String>, String> {
+) -> Result<Option<(String, i32)>, String> {
     let engine_lock = get_engine().await?;
     let engine_guard = engine_lock.read().await;
     let engine = engine_guard
@@ -194,7 +199,7 @@ pub async fn evaluate_exit_strategies(
                             strategy.name, token_mint, current_price, position_data.entry_price,
                             position_data.unrealized_profit_pct.unwrap_or(0.0)),
                     );
-                    return Ok(Some(strategy.id.clone()));
+                    return Ok(Some((strategy.id.clone(), strategy.version)));
                 }
             }
             Err(e) => {
@@ -223,6 +228,27 @@ pub async fn validate_strategy(strategy: &Strategy) -> Result<(), String> {
     engine.validate_strategy(strategy)
 }
 
+/// List every condition problem in a rule tree (empty when compatible)
+///
+/// Used by bundle import to report all unknown condition types and invalid
+/// parameters at once instead of failing on the first.
+pub async fn check_rules_compatibility(rules: &RuleTree) -> Result<Vec<String>, String> {
+    let engine_lock = get_engine().await?;
+    let engine_guard = engine_lock.read().await;
+    let engine = engine_guard
+        .as_ref()
+        .ok_or_else(|| "Strategy engine not available".to_string())?;
+
+    let mut issues = bundle::rule_issues(rules, engine.get_condition_registry());
+    if issues.is_empty() {
+        // Structural problems (empty branches, NOT arity)
+        if let Err(e) = engine.validate_rules(rules) {
+            issues.push(e);
+        }
+    }
+    Ok(issues)
+}
+
 /// Clear the evaluation cache
 pub async fn clear_evaluation_cache() -> Result<(), String> {
     let engine_lock = get_engine().await?;
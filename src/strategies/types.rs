@@ -40,6 +40,78 @@ pub struct Strategy {
     pub version: i32,
 }
 
+/// Immutable snapshot of a strategy as saved at a given version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyRevision {
+    pub strategy_id: String,
+    pub version: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub strategy_type: StrategyType,
+    pub enabled: bool,
+    pub priority: i32,
+    pub timeframe: String,
+    pub rules: RuleTree,
+    pub parameters: HashMap<String, serde_json::Value>,
+    pub author: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Why this revision was written (e.g. "Rollback to v3"), if not a plain save
+    pub note: Option<String>,
+}
+
+impl StrategyRevision {
+    /// Snapshot the current state of a strategy
+    pub fn from_strategy(strategy: &Strategy, note: Option<String>) -> Self {
+        Self {
+            strategy_id: strategy.id.clone(),
+            version: strategy.version,
+            name: strategy.name.clone(),
+            description: strategy.description.clone(),
+            strategy_type: strategy.strategy_type,
+            enabled: strategy.enabled,
+            priority: strategy.priority,
+            timeframe: strategy.timeframe.clone(),
+            rules: strategy.rules.clone(),
+            parameters: strategy.parameters.clone(),
+            author: strategy.author.clone(),
+            created_at: strategy.updated_at,
+            note,
+        }
+    }
+}
+
+/// Single field difference between two strategy revisions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RevisionChange {
+    /// JSON path of the changed value (e.g. `rules.conditions[1].condition.parameters.period.value`)
+    pub path: String,
+    /// Value in the older revision (null when added)
+    pub before: serde_json::Value,
+    /// Value in the newer revision (null when removed)
+    pub after: serde_json::Value,
+}
+
+/// Differences between two revisions of the same strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyRevisionDiff {
+    pub strategy_id: String,
+    pub from_version: i32,
+    pub to_version: i32,
+    pub changes: Vec<RevisionChange>,
+}
+
+/// Link between a position and the strategy revision that opened or closed it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyAssignment {
+    pub position_id: String,
+    pub strategy_id: String,
+    /// Strategy version at signal time (None for assignments recorded before versioning)
+    pub strategy_version: Option<i32>,
+    /// ENTRY when the strategy opened the position, EXIT when it closed it
+    pub role: Option<StrategyType>,
+    pub assigned_at: DateTime<Utc>,
+}
+
 /// Rule tree structure supporting logical operators
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuleTree {
@@ -0,0 +1,151 @@
+//! Strategy revision history helpers
+//!
+//! Every save of a strategy is stored as an immutable [`StrategyRevision`] (see
+//! `db::insert_strategy` / `db::update_strategy`). This module compares two
+//! revisions field by field and builds rollbacks.
+
+use crate::strategies::types::{RevisionChange, Strategy, StrategyRevision, StrategyRevisionDiff};
+use chrono::Utc;
+use serde_json::{json, Value};
+
+/// Compare two revisions of the same strategy
+///
+/// Rules and parameters are compared structurally, so a change to a single
+/// condition parameter is reported at its JSON path rather than as a whole
+/// rule tree replacement.
+pub fn diff_revisions(from: &StrategyRevision, to: &StrategyRevision) -> StrategyRevisionDiff {
+    let mut changes = Vec::new();
+
+    diff_values(
+        "",
+        &revision_fields(from),
+        &revision_fields(to),
+        &mut changes,
+    );
+
+    StrategyRevisionDiff {
+        strategy_id: to.strategy_id.clone(),
+        from_version: from.version,
+        to_version: to.version,
+        changes,
+    }
+}
+
+/// Build the strategy state for rolling back to `target`
+///
+/// The rollback is itself a new revision (`current.version + 1`) so history is
+/// never rewritten; only the content is copied from the target revision.
+pub fn rollback_strategy(current: &Strategy, target: &StrategyRevision) -> Strategy {
+    Strategy {
+        id: current.id.clone(),
+        name: target.name.clone(),
+        description: target.description.clone(),
+        strategy_type: target.strategy_type,
+        enabled: target.enabled,
+        priority: target.priority,
+        timeframe: target.timeframe.clone(),
+        rules: target.rules.clone(),
+        parameters: target.parameters.clone(),
+        created_at: current.created_at,
+        updated_at: Utc::now(),
+        author: target.author.clone(),
+        version: current.version + 1,
+    }
+}
+
+/// Fields of a revision that are meaningful to diff (metadata like author/timestamp excluded)
+fn revision_fields(revision: &StrategyRevision) -> Value {
+    json!({
+        "name": revision.name,
+        "description": revision.description,
+        "type": revision.strategy_type,
+        "enabled": revision.enabled,
+        "priority": revision.priority,
+        "timeframe": revision.timeframe,
+        "rules": revision.rules,
+        "parameters": revision.parameters,
+    })
+}
+
+fn join_path(base: &str, key: &str) -> String {
+    if base.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", base, key)
+    }
+}
+
+/// Recursively collect differences between two JSON values
+fn diff_values(path: &str, before: &Value, after: &Value, changes: &mut Vec<RevisionChange>) {
+    match (before, after) {
+        (Value::Object(a), Value::Object(b)) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff_values(
+                    &join_path(path, key),
+                    a.get(key).unwrap_or(&Value::Null),
+                    b.get(key).unwrap_or(&Value::Null),
+                    changes,
+                );
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                diff_values(
+                    &format!("{}[{}]", path, i),
+                    a.get(i).unwrap_or(&Value::Null),
+                    b.get(i).unwrap_or(&Value::Null),
+                    changes,
+                );
+            }
+        }
+        _ if before != after => changes.push(RevisionChange {
+            path: path.to_string(),
+            before: before.clone(),
+            after: after.clone(),
+        }),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_nested_changes_by_path() {
+        let before = json!({
+            "name": "a",
+            "rules": { "conditions": [ { "period": 14 }, { "period": 20 } ] },
+            "parameters": { "x": 1 }
+        });
+        let after = json!({
+            "name": "a",
+            "rules": { "conditions": [ { "period": 14 } ] },
+            "parameters": { "x": 2, "y": true }
+        });
+
+        let mut changes = Vec::new();
+        diff_values("", &before, &after, &mut changes);
+
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["parameters.x", "parameters.y", "rules.conditions[1]"]
+        );
+        assert_eq!(changes[0].before, json!(1));
+        assert_eq!(changes[0].after, json!(2));
+        assert_eq!(changes[1].before, Value::Null);
+        assert_eq!(changes[2].after, Value::Null);
+    }
+
+    #[test]
+    fn identical_values_have_no_changes() {
+        let value = json!({ "rules": { "operator": "AND", "conditions": [] } });
+        let mut changes = Vec::new();
+        diff_values("", &value, &value, &mut changes);
+        assert!(changes.is_empty());
+    }
+}
@@ -1,15 +1,24 @@
 pub mod operations;
+pub mod priority_fees;
 pub mod registry;
 /// Swap module for handling multiple DEX routers
 /// Trait-based router architecture for extensibility
 pub mod router;
 pub mod routers;
+pub mod simulation;
 pub mod types;
 
 // Re-export router system
-pub use operations::{execute_swap_with_fallback, get_best_quote, get_best_quote_for_opening};
+pub use operations::{
+    execute_swap_with_fallback, get_best_quote, get_best_quote_for_opening, preflight_swap,
+};
+pub use priority_fees::{
+    estimate_priority_fee, get_priority_fee_stats, record_fee_outcome, PriorityFeeEstimate,
+    PriorityFeeStats,
+};
 pub use registry::{get_registry, RouterRegistry};
 pub use router::{Quote, QuoteRequest, SwapMode, SwapResult, SwapRouter};
+pub use simulation::{simulate_swap, SimulationFailure, SimulationReport, SimulationRequest};
 
 // Re-export shared types
 pub use types::{ExitType, RouterType};
@@ -4,7 +4,8 @@ use crate::constants::SOL_MINT;
 use crate::errors::ScreenerBotError;
 use crate::logger::{self, LogTag};
 use crate::swaps::registry::get_registry;
-use crate::swaps::router::{Quote, QuoteRequest, SwapResult};
+use crate::swaps::router::{Quote, QuoteRequest, SwapMode, SwapResult};
+use crate::swaps::simulation::{simulate_swap, SimulationReport, SimulationRequest};
 use crate::tokens::Token;
 use futures::future;
 use std::time::Instant;
@@ -215,6 +216,7 @@ pub async fn execute_swap_with_fallback(
                     wallet_address: quote.wallet_address.clone(),
                     slippage_pct: (quote.slippage_bps as f64) / 100.0,
                     swap_mode: quote.swap_mode,
+                    priority: quote.priority.clone(),
                 };
 
                 let fallback_quote = match fallback_router.get_quote(&fallback_request).await {
@@ -260,6 +262,38 @@ pub async fn execute_swap_with_fallback(
     }
 }
 
+// ============================================================================
+// PRE-FLIGHT SIMULATION
+// ============================================================================
+
+/// Simulate a router-built swap transaction before it is signed and sent
+/// Rejects the swap when the simulation fails or the simulated output is below
+/// the quote minus its slippage tolerance
+pub async fn preflight_swap(
+    quote: &Quote,
+    transaction_base64: &str,
+) -> Result<Option<SimulationReport>, ScreenerBotError> {
+    let min_output = match quote.swap_mode {
+        SwapMode::ExactIn => {
+            let tolerance_bps = 10_000u64.saturating_sub(quote.slippage_bps as u64);
+            ((quote.output_amount as u128 * tolerance_bps as u128) / 10_000) as u64
+        }
+        SwapMode::ExactOut => quote.output_amount,
+    };
+
+    simulate_swap(&SimulationRequest {
+        source: quote.router_name.clone(),
+        transaction_base64: transaction_base64.to_string(),
+        wallet_address: quote.wallet_address.clone(),
+        input_mint: quote.input_mint.clone(),
+        output_mint: quote.output_mint.clone(),
+        input_amount: quote.input_amount,
+        expected_output: quote.output_amount,
+        min_output,
+    })
+    .await
+}
+
 // ============================================================================
 // HELPER FUNCTIONS
 // ============================================================================
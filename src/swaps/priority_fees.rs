@@ -0,0 +1,478 @@
+/// Dynamic priority fee estimation
+/// Samples getRecentPrioritizationFees for the writable accounts of the traded pool,
+/// picks a percentile by trade urgency and caps the result per trade.
+/// Fees paid and landing latency of every swap are kept so the estimator can be
+/// evaluated on the dashboard.
+use crate::config::with_config;
+use crate::constants::SOL_MINT;
+use crate::logger::{self, LogTag};
+use crate::rpc::{get_rpc_client, RpcClientMethods};
+use crate::trader::TradePriority;
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{Notify, RwLock};
+
+/// Fee outcomes kept for dashboard statistics
+const MAX_FEE_OUTCOMES: usize = 200;
+
+/// Cached account sets (oldest entries are dropped past this size)
+const MAX_CACHED_ACCOUNT_SETS: usize = 256;
+
+/// Network-wide samples are considered stale after this many seconds
+const NETWORK_SAMPLES_MAX_AGE_SECS: u64 = 120;
+
+/// Interval between network-wide sample refreshes
+const NETWORK_REFRESH_INTERVAL_SECS: u64 = 30;
+
+// ============================================================================
+// STATE
+// ============================================================================
+
+/// Fee samples (micro-lamports per CU) per writable account set
+static ACCOUNT_SAMPLES: Lazy<RwLock<HashMap<String, (Instant, Vec<u64>)>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Network-wide fee samples, refreshed by the priority fee service
+static NETWORK_SAMPLES: Lazy<RwLock<Option<(Instant, Vec<u64>)>>> = Lazy::new(|| RwLock::new(None));
+
+/// Last network refresh error, cleared on success
+static LAST_REFRESH_ERROR: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
+
+/// Recent fee outcomes (newest last)
+///
+/// Kept in memory only, so the history starts cold after every restart.
+static FEE_OUTCOMES: Lazy<RwLock<VecDeque<FeeOutcome>>> =
+    Lazy::new(|| RwLock::new(VecDeque::with_capacity(MAX_FEE_OUTCOMES)));
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+/// Priority fee chosen for a swap
+#[derive(Debug, Clone)]
+pub struct PriorityFeeEstimate {
+    /// Compute unit price (micro-lamports per CU)
+    pub compute_unit_price: u64,
+    /// Compute unit limit the total was computed for
+    pub compute_unit_limit: u32,
+    /// Total priority fee (lamports)
+    pub total_lamports: u64,
+    /// Percentile used (None for the static fee)
+    pub percentile: Option<f64>,
+    /// Number of recent slots sampled
+    pub sample_count: usize,
+    /// Whether the per-trade cap was applied
+    pub capped: bool,
+}
+
+/// Fee paid by a swap and how long it took to land
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeOutcome {
+    pub timestamp: String,
+    pub router: String,
+    pub priority: String,
+    pub fee_lamports: u64,
+    pub landing_ms: u64,
+    pub landed: bool,
+}
+
+/// Average fee and latency for one urgency level
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PriorityFeeBucket {
+    pub priority: String,
+    pub count: usize,
+    pub landed: usize,
+    pub avg_fee_lamports: f64,
+    pub avg_landing_ms: f64,
+}
+
+/// Priority fee statistics for the dashboard
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PriorityFeeStats {
+    pub enabled: bool,
+    /// Network-wide percentiles (micro-lamports per CU)
+    pub network_p50: Option<u64>,
+    pub network_p75: Option<u64>,
+    pub network_p90: Option<u64>,
+    pub network_sample_count: usize,
+    pub swaps_recorded: usize,
+    pub landed_rate: f64,
+    pub avg_fee_lamports: f64,
+    pub avg_landing_ms: f64,
+    pub by_priority: Vec<PriorityFeeBucket>,
+    pub recent: Vec<FeeOutcome>,
+}
+
+// ============================================================================
+// ESTIMATION
+// ============================================================================
+
+/// Estimate the priority fee for a swap touching `writable_accounts`
+///
+/// Falls back to network-wide samples when the account query fails, and to
+/// `swaps.jupiter.default_priority_fee` when no samples are available or dynamic
+/// fees are disabled. The default fee is also the floor of the estimate.
+pub async fn estimate_priority_fee(
+    writable_accounts: &[String],
+    priority: &TradePriority,
+) -> PriorityFeeEstimate {
+    let (enabled, default_fee, max_fee_sol, cu_limit, ttl_secs, pct) = with_config(|cfg| {
+        let fees = &cfg.swaps.priority_fees;
+        (
+            fees.enabled,
+            cfg.swaps.jupiter.default_priority_fee,
+            fees.max_fee_sol,
+            fees.compute_unit_limit.max(1),
+            fees.cache_ttl_secs,
+            match priority {
+                TradePriority::Emergency => fees.emergency_percentile,
+                TradePriority::High => fees.high_percentile,
+                TradePriority::Normal => fees.normal_percentile,
+                TradePriority::Low => fees.low_percentile,
+            },
+        )
+    });
+    let max_lamports = (max_fee_sol * 1_000_000_000.0) as u64;
+
+    if !enabled {
+        return static_estimate(default_fee.min(max_lamports), cu_limit);
+    }
+
+    let mut samples = account_samples(writable_accounts, ttl_secs).await;
+    if samples.is_empty() {
+        samples = network_samples().await;
+    }
+    if samples.is_empty() {
+        return static_estimate(default_fee.min(max_lamports), cu_limit);
+    }
+
+    samples.sort_unstable();
+    let price = percentile(&samples, pct);
+    let (total_lamports, capped) =
+        clamp_total(fee_lamports(price, cu_limit), default_fee, max_lamports);
+
+    let estimate = PriorityFeeEstimate {
+        compute_unit_price: compute_unit_price(total_lamports, cu_limit),
+        compute_unit_limit: cu_limit,
+        total_lamports,
+        percentile: Some(pct),
+        sample_count: samples.len(),
+        capped,
+    };
+
+    logger::debug(
+        LogTag::Swap,
+        &format!(
+            "Priority fee {:?}: p{:.0}={} µL/CU over {} slots → {} lamports{}",
+            priority,
+            pct,
+            price,
+            estimate.sample_count,
+            estimate.total_lamports,
+            if capped { " (capped)" } else { "" }
+        ),
+    );
+
+    estimate
+}
+
+/// Estimate the priority fee for a swap between `input_mint` and `output_mint`
+///
+/// Samples the writable accounts of the pool holding the non-SOL side of the swap.
+pub async fn estimate_swap_priority_fee(
+    input_mint: &str,
+    output_mint: &str,
+    pool_address: Option<&str>,
+    priority: &TradePriority,
+) -> PriorityFeeEstimate {
+    let token_mint = if input_mint == SOL_MINT {
+        output_mint
+    } else {
+        input_mint
+    };
+    let accounts = pool_writable_accounts(token_mint, pool_address);
+    estimate_priority_fee(&accounts, priority).await
+}
+
+/// Writable accounts of the pool a swap will trade against
+///
+/// Uses `pool_address` when it is one of the token's known pools, otherwise the
+/// canonical pool. Returns an empty list when no pool is known.
+pub fn pool_writable_accounts(token_mint: &str, pool_address: Option<&str>) -> Vec<String> {
+    let pools = crate::pools::get_token_pools(token_mint);
+    let pool = pool_address
+        .and_then(|address| pools.iter().find(|p| p.pool_id.to_string() == address))
+        .or_else(|| pools.first());
+
+    match pool {
+        Some(pool) => std::iter::once(pool.pool_id)
+            .chain(pool.reserve_accounts.iter().copied())
+            .map(|key| key.to_string())
+            .collect(),
+        None => pool_address
+            .map(|a| vec![a.to_string()])
+            .unwrap_or_default(),
+    }
+}
+
+/// Refresh network-wide fee samples (called by the priority fee service)
+pub async fn refresh_network_fees() -> Result<usize, String> {
+    let fees = get_rpc_client().get_recent_prioritization_fees(&[]).await?;
+    let samples: Vec<u64> = fees.iter().map(|f| f.prioritization_fee).collect();
+    let count = samples.len();
+    *NETWORK_SAMPLES.write().await = Some((Instant::now(), samples));
+    Ok(count)
+}
+
+async fn account_samples(writable_accounts: &[String], ttl_secs: u64) -> Vec<u64> {
+    if writable_accounts.is_empty() {
+        return Vec::new();
+    }
+
+    let key = writable_accounts.join(",");
+    if let Some((fetched_at, samples)) = ACCOUNT_SAMPLES.read().await.get(&key) {
+        if fetched_at.elapsed().as_secs() < ttl_secs {
+            return samples.clone();
+        }
+    }
+
+    match get_rpc_client()
+        .get_recent_prioritization_fees(writable_accounts)
+        .await
+    {
+        Ok(fees) => {
+            let samples: Vec<u64> = fees.iter().map(|f| f.prioritization_fee).collect();
+            let mut cache = ACCOUNT_SAMPLES.write().await;
+            if cache.len() >= MAX_CACHED_ACCOUNT_SETS {
+                if let Some(oldest) = cache
+                    .iter()
+                    .min_by_key(|(_, (fetched_at, _))| *fetched_at)
+                    .map(|(k, _)| k.clone())
+                {
+                    cache.remove(&oldest);
+                }
+            }
+            cache.insert(key, (Instant::now(), samples.clone()));
+            samples
+        }
+        Err(e) => {
+            logger::debug(
+                LogTag::Swap,
+                &format!("Recent prioritization fees unavailable: {}", e),
+            );
+            Vec::new()
+        }
+    }
+}
+
+async fn network_samples() -> Vec<u64> {
+    match NETWORK_SAMPLES.read().await.as_ref() {
+        Some((fetched_at, samples))
+            if fetched_at.elapsed().as_secs() < NETWORK_SAMPLES_MAX_AGE_SECS =>
+        {
+            samples.clone()
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn static_estimate(total_lamports: u64, cu_limit: u32) -> PriorityFeeEstimate {
+    PriorityFeeEstimate {
+        compute_unit_price: compute_unit_price(total_lamports, cu_limit),
+        compute_unit_limit: cu_limit,
+        total_lamports,
+        percentile: None,
+        sample_count: 0,
+        capped: false,
+    }
+}
+
+/// Nearest-rank percentile of sorted samples
+fn percentile(sorted: &[u64], pct: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = ((pct.clamp(0.0, 100.0) / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Total lamports for a compute unit price (micro-lamports per CU)
+fn fee_lamports(compute_unit_price: u64, cu_limit: u32) -> u64 {
+    ((compute_unit_price as u128 * cu_limit as u128) / 1_000_000) as u64
+}
+
+/// Compute unit price (micro-lamports per CU) that pays `total_lamports`
+fn compute_unit_price(total_lamports: u64, cu_limit: u32) -> u64 {
+    ((total_lamports as u128 * 1_000_000) / cu_limit.max(1) as u128) as u64
+}
+
+/// Apply the default fee floor and the per-trade cap
+fn clamp_total(total: u64, floor: u64, cap: u64) -> (u64, bool) {
+    if total > cap {
+        (cap, true)
+    } else {
+        (total.max(floor).min(cap), false)
+    }
+}
+
+/// Periodically refresh network-wide fee samples until shutdown
+pub async fn start_priority_fee_service(shutdown: Arc<Notify>) {
+    logger::info(LogTag::Swap, "Priority fee sampler started");
+
+    loop {
+        if with_config(|cfg| cfg.swaps.priority_fees.enabled) {
+            match refresh_network_fees().await {
+                Ok(count) => {
+                    *LAST_REFRESH_ERROR.write().await = None;
+                    logger::debug(
+                        LogTag::Swap,
+                        &format!("Priority fee samples refreshed ({} slots)", count),
+                    );
+                }
+                Err(e) => {
+                    logger::warning(
+                        LogTag::Swap,
+                        &format!("Priority fee sample refresh failed: {}", e),
+                    );
+                    *LAST_REFRESH_ERROR.write().await = Some(e);
+                }
+            }
+        }
+
+        tokio::select! {
+            _ = shutdown.notified() => break,
+            _ = tokio::time::sleep(Duration::from_secs(NETWORK_REFRESH_INTERVAL_SECS)) => {}
+        }
+    }
+
+    logger::info(LogTag::Swap, "Priority fee sampler stopped");
+}
+
+/// Error of the last failed network refresh, if the latest one failed
+pub async fn get_last_refresh_error() -> Option<String> {
+    LAST_REFRESH_ERROR.read().await.clone()
+}
+
+// ============================================================================
+// OUTCOMES
+// ============================================================================
+
+/// Record the fee paid by a swap and how long it took to land
+pub async fn record_fee_outcome(
+    router: &str,
+    priority: &TradePriority,
+    fee_lamports: u64,
+    landing_ms: u64,
+    landed: bool,
+) {
+    let mut outcomes = FEE_OUTCOMES.write().await;
+    if outcomes.len() >= MAX_FEE_OUTCOMES {
+        outcomes.pop_front();
+    }
+    outcomes.push_back(FeeOutcome {
+        timestamp: Utc::now().to_rfc3339(),
+        router: router.to_string(),
+        priority: format!("{:?}", priority),
+        fee_lamports,
+        landing_ms,
+        landed,
+    });
+}
+
+/// Priority fee statistics for the dashboard
+pub async fn get_priority_fee_stats() -> PriorityFeeStats {
+    let enabled = with_config(|cfg| cfg.swaps.priority_fees.enabled);
+
+    let mut network = network_samples().await;
+    network.sort_unstable();
+    let network_pct = |pct: f64| (!network.is_empty()).then(|| percentile(&network, pct));
+
+    let outcomes = FEE_OUTCOMES.read().await;
+    let landed: Vec<&FeeOutcome> = outcomes.iter().filter(|o| o.landed).collect();
+
+    let mut by_priority: Vec<PriorityFeeBucket> = Vec::new();
+    for outcome in outcomes.iter() {
+        let bucket = match by_priority
+            .iter_mut()
+            .find(|b| b.priority == outcome.priority)
+        {
+            Some(bucket) => bucket,
+            None => {
+                by_priority.push(PriorityFeeBucket {
+                    priority: outcome.priority.clone(),
+                    ..Default::default()
+                });
+                by_priority.last_mut().expect("bucket was just pushed")
+            }
+        };
+        bucket.count += 1;
+        bucket.avg_fee_lamports += outcome.fee_lamports as f64;
+        if outcome.landed {
+            bucket.landed += 1;
+            bucket.avg_landing_ms += outcome.landing_ms as f64;
+        }
+    }
+    for bucket in &mut by_priority {
+        bucket.avg_fee_lamports /= bucket.count as f64;
+        if bucket.landed > 0 {
+            bucket.avg_landing_ms /= bucket.landed as f64;
+        }
+    }
+
+    PriorityFeeStats {
+        enabled,
+        network_p50: network_pct(50.0),
+        network_p75: network_pct(75.0),
+        network_p90: network_pct(90.0),
+        network_sample_count: network.len(),
+        swaps_recorded: outcomes.len(),
+        landed_rate: if outcomes.is_empty() {
+            0.0
+        } else {
+            landed.len() as f64 / outcomes.len() as f64 * 100.0
+        },
+        avg_fee_lamports: average(outcomes.iter().map(|o| o.fee_lamports)),
+        avg_landing_ms: average(landed.iter().map(|o| o.landing_ms)),
+        by_priority,
+        recent: outcomes.iter().rev().take(20).cloned().collect(),
+    }
+}
+
+fn average(values: impl Iterator<Item = u64>) -> f64 {
+    let (sum, count) = values.fold((0u64, 0usize), |(s, c), v| (s.saturating_add(v), c + 1));
+    if count == 0 {
+        0.0
+    } else {
+        sum as f64 / count as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let samples = vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90];
+        assert_eq!(percentile(&samples, 50.0), 40);
+        assert_eq!(percentile(&samples, 90.0), 80);
+        assert_eq!(percentile(&samples, 100.0), 90);
+        assert_eq!(percentile(&samples, 0.0), 0);
+        assert_eq!(percentile(&[], 50.0), 0);
+    }
+
+    #[test]
+    fn test_fee_conversion_and_clamp() {
+        // 5_000 µL/CU over 200k CU = 1_000 lamports
+        assert_eq!(fee_lamports(5_000, 200_000), 1_000);
+        assert_eq!(compute_unit_price(1_000, 200_000), 5_000);
+
+        assert_eq!(clamp_total(500, 1_000, 2_000_000), (1_000, false));
+        assert_eq!(clamp_total(5_000_000, 1_000, 2_000_000), (2_000_000, true));
+        assert_eq!(clamp_total(50_000, 1_000, 2_000_000), (50_000, false));
+    }
+}
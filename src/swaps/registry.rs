@@ -18,13 +18,14 @@ impl RouterRegistry {
     /// Create registry with all routers
     /// Add new routers here - this is the ONLY place that needs changing
     pub fn new() -> Self {
-        use crate::swaps::routers::{GmgnRouter, JupiterRouter, RaydiumRouter};
+        use crate::swaps::routers::{DirectRouter, GmgnRouter, JupiterRouter, RaydiumRouter};
 
         Self {
             routers: vec![
                 Arc::new(JupiterRouter::new()),
                 Arc::new(GmgnRouter::new()),
                 Arc::new(RaydiumRouter::new()),
+                Arc::new(DirectRouter::new()),
                 // Add new routers here - ONLY change needed to add router
             ],
         }
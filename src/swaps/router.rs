@@ -1,5 +1,6 @@
 use crate::errors::ScreenerBotError;
 use crate::tokens::Token;
+use crate::trader::TradePriority;
 /// Router Trait - Unified swap router interface
 /// All swap routers (Jupiter, GMGN, Raydium) must implement this trait
 use async_trait::async_trait;
@@ -50,6 +51,8 @@ pub struct QuoteRequest {
     pub wallet_address: String,
     pub slippage_pct: f64,
     pub swap_mode: SwapMode,
+    /// Trade urgency, drives the priority fee
+    pub priority: TradePriority,
 }
 
 /// Swap mode enum
@@ -83,6 +86,7 @@ pub struct Quote {
     pub route_plan: String,
     pub swap_mode: SwapMode,
     pub wallet_address: String,
+    pub priority: TradePriority,
     pub execution_data: Vec<u8>, // Serialized router-specific data
 }
 
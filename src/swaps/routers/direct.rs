@@ -0,0 +1,371 @@
+/// Direct Router - On-chain swaps against known pools without an aggregator API
+///
+/// Quotes are computed locally from decoded pool state (pools::swap programs) and the
+/// swap transaction is built from the same state at execution time, floored by the
+/// quoted minimum output so a stale quote can never fill worse than promised.
+use crate::config::with_config;
+use crate::constants::{SOL_DECIMALS, SOL_MINT};
+use crate::errors::ScreenerBotError;
+use crate::logger::{self, LogTag};
+use crate::pools::swap::{SwapBuilder, SwapDirection, SwapError, SwapParams, SwapRequest};
+use crate::rpc::RpcClientMethods;
+use crate::swaps::operations::preflight_swap;
+use crate::swaps::priority_fees::{estimate_swap_priority_fee, record_fee_outcome};
+use crate::swaps::router::{Quote, QuoteRequest, SwapMode, SwapResult, SwapRouter};
+use crate::tokens::Token;
+use async_trait::async_trait;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use std::time::Instant;
+
+// ============================================================================
+// DIRECT-SPECIFIC TYPES
+// ============================================================================
+
+/// Execution data carried from quote to execution
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct DirectExecutionData {
+    pool_address: String,
+    program: String,
+    token_mint: String,
+    direction: String,
+    amount_raw: u64,
+    amount_ui: f64,
+    slippage_bps: u16,
+    min_output_raw: u64,
+}
+
+pub struct DirectRouter;
+
+impl DirectRouter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Map pool swap errors onto bot errors (RPC failures stay retryable for fallback)
+    fn map_swap_error(context: &str, error: SwapError) -> ScreenerBotError {
+        match error {
+            SwapError::RpcError(_) | SwapError::ExecutionError(_) => {
+                ScreenerBotError::network_error(format!("{}: {}", context, error))
+            }
+            _ => ScreenerBotError::api_error(format!("{}: {}", context, error)),
+        }
+    }
+
+    /// Price impact against the current pool spot price (0 when no price is known)
+    fn price_impact_pct(token_mint: &str, direction: SwapDirection, params: &SwapParams) -> f64 {
+        let spot = match crate::pools::get_pool_price(token_mint) {
+            Some(price) if price.price_sol > 0.0 => price.price_sol,
+            _ => return 0.0,
+        };
+
+        match direction {
+            SwapDirection::Buy if params.expected_output > 0.0 => {
+                let effective = params.input_amount / params.expected_output;
+                ((effective / spot - 1.0) * 100.0).max(0.0)
+            }
+            SwapDirection::Sell if params.input_amount > 0.0 => {
+                let effective = params.expected_output / params.input_amount;
+                ((1.0 - effective / spot) * 100.0).max(0.0)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+#[async_trait]
+impl SwapRouter for DirectRouter {
+    fn id(&self) -> &'static str {
+        "direct"
+    }
+
+    fn name(&self) -> &'static str {
+        "Direct"
+    }
+
+    fn is_enabled(&self) -> bool {
+        with_config(|cfg| cfg.swaps.direct.enabled)
+    }
+
+    fn priority(&self) -> u8 {
+        3 // Last resort after aggregators
+    }
+
+    async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, ScreenerBotError> {
+        if request.swap_mode != SwapMode::ExactIn {
+            return Err(ScreenerBotError::api_error(
+                "Direct router only supports ExactIn swaps",
+            ));
+        }
+
+        let (token_mint, direction) = if request.input_mint == SOL_MINT {
+            (request.output_mint.as_str(), SwapDirection::Buy)
+        } else if request.output_mint == SOL_MINT {
+            (request.input_mint.as_str(), SwapDirection::Sell)
+        } else {
+            return Err(ScreenerBotError::api_error(
+                "Direct router requires SOL on one side of the swap",
+            ));
+        };
+
+        let input_decimals = match direction {
+            SwapDirection::Buy => SOL_DECIMALS,
+            SwapDirection::Sell => {
+                crate::tokens::get_decimals(token_mint)
+                    .await
+                    .ok_or_else(|| {
+                        ScreenerBotError::api_error(format!(
+                            "Token decimals not available for {}",
+                            token_mint
+                        ))
+                    })?
+            }
+        };
+        let amount_ui = request.input_amount as f64 / 10_f64.powi(input_decimals as i32);
+        let slippage_bps = (request.slippage_pct * 100.0).round().clamp(0.0, 10_000.0) as u16;
+
+        let (max_pools, max_impact) = with_config(|cfg| {
+            (
+                cfg.swaps.direct.max_pools,
+                cfg.swaps.direct.max_price_impact_pct,
+            )
+        });
+
+        let pools: Vec<_> = crate::pools::get_token_pools(token_mint)
+            .into_iter()
+            .filter(|pool| SwapBuilder::supports_program(pool.program_kind))
+            .take(max_pools)
+            .collect();
+
+        if pools.is_empty() {
+            return Err(ScreenerBotError::api_error(format!(
+                "Direct router has no supported pool for {}",
+                token_mint
+            )));
+        }
+
+        let mut best: Option<(SwapParams, String, String)> = None;
+        let mut last_error = None;
+
+        for pool in pools {
+            let swap_request = SwapRequest {
+                pool_address: pool.pool_id,
+                token_mint: Pubkey::from_str(token_mint).map_err(|e| {
+                    ScreenerBotError::parse_error(format!("Invalid token mint: {}", e))
+                })?,
+                amount: amount_ui,
+                direction,
+                slippage_bps,
+                amount_raw: Some(request.input_amount),
+                min_output_raw: None,
+                compute_unit_limit: None,
+                compute_unit_price: None,
+            };
+
+            match SwapBuilder::quote(&swap_request).await {
+                Ok(params) => {
+                    logger::debug(
+                        LogTag::Swap,
+                        &format!(
+                            "Direct quote {} via {}: {} → {}",
+                            pool.pool_id,
+                            pool.program_kind.display_name(),
+                            params.input_amount_raw,
+                            params.expected_output_raw
+                        ),
+                    );
+                    let better = best
+                        .as_ref()
+                        .map(|(b, _, _)| params.expected_output_raw > b.expected_output_raw)
+                        .unwrap_or(true);
+                    if better {
+                        best = Some((
+                            params,
+                            pool.pool_id.to_string(),
+                            pool.program_kind.display_name().to_string(),
+                        ));
+                    }
+                }
+                Err(e) => {
+                    logger::debug(
+                        LogTag::Swap,
+                        &format!("Direct quote failed for pool {}: {}", pool.pool_id, e),
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        let (params, pool_address, program) = match best {
+            Some(best) => best,
+            None => {
+                return Err(Self::map_swap_error(
+                    "Direct quote failed",
+                    last_error.unwrap_or_else(|| {
+                        SwapError::InvalidPool("No pool produced a quote".to_string())
+                    }),
+                ))
+            }
+        };
+
+        let price_impact_pct = Self::price_impact_pct(token_mint, direction, &params);
+        if price_impact_pct > max_impact {
+            return Err(ScreenerBotError::api_error(format!(
+                "Direct quote price impact {:.2}% exceeds limit {:.2}%",
+                price_impact_pct, max_impact
+            )));
+        }
+
+        let execution_data = serde_json::to_vec(&DirectExecutionData {
+            pool_address: pool_address.clone(),
+            program: program.clone(),
+            token_mint: token_mint.to_string(),
+            direction: format!("{:?}", direction),
+            amount_raw: params.input_amount_raw,
+            amount_ui,
+            slippage_bps,
+            min_output_raw: params.minimum_output_raw,
+        })
+        .map_err(|e| {
+            ScreenerBotError::internal_error(format!("Swap data serialization failed: {}", e))
+        })?;
+
+        Ok(Quote {
+            router_id: self.id().to_string(),
+            router_name: self.name().to_string(),
+            input_mint: request.input_mint.clone(),
+            output_mint: request.output_mint.clone(),
+            input_amount: params.input_amount_raw,
+            output_amount: params.expected_output_raw,
+            price_impact_pct,
+            fee_lamports: 0,
+            slippage_bps,
+            route_plan: format!(
+                "{} ({})",
+                program,
+                pool_address.chars().take(8).collect::<String>()
+            ),
+            wallet_address: request.wallet_address.clone(),
+            priority: request.priority.clone(),
+            swap_mode: request.swap_mode,
+            execution_data,
+        })
+    }
+
+    async fn execute_swap(
+        &self,
+        token: &Token,
+        quote: &Quote,
+    ) -> Result<SwapResult, ScreenerBotError> {
+        let start = Instant::now();
+
+        let data: DirectExecutionData =
+            serde_json::from_slice(&quote.execution_data).map_err(|e| {
+                ScreenerBotError::internal_error(format!("Swap data deserialization failed: {}", e))
+            })?;
+
+        if let Some(unhealthy) =
+            crate::connectivity::check_endpoints_healthy(&["internet", "rpc"]).await
+        {
+            return Err(ScreenerBotError::connectivity_error(format!(
+                "Cannot send direct transaction - Unhealthy endpoints: {}",
+                unhealthy
+            )));
+        }
+
+        let direction = if quote.input_mint == SOL_MINT {
+            SwapDirection::Buy
+        } else {
+            SwapDirection::Sell
+        };
+
+        let fee_estimate = estimate_swap_priority_fee(
+            &quote.input_mint,
+            &quote.output_mint,
+            Some(&data.pool_address),
+            &quote.priority,
+        )
+        .await;
+
+        let swap_request = SwapRequest {
+            pool_address: Pubkey::from_str(&data.pool_address).map_err(|e| {
+                ScreenerBotError::parse_error(format!("Invalid pool address: {}", e))
+            })?,
+            token_mint: Pubkey::from_str(&data.token_mint)
+                .map_err(|e| ScreenerBotError::parse_error(format!("Invalid token mint: {}", e)))?,
+            amount: data.amount_ui,
+            direction,
+            slippage_bps: data.slippage_bps,
+            amount_raw: Some(data.amount_raw),
+            min_output_raw: Some(data.min_output_raw),
+            compute_unit_limit: Some(fee_estimate.compute_unit_limit),
+            compute_unit_price: Some(fee_estimate.compute_unit_price),
+        };
+
+        let (transaction, params) = SwapBuilder::build_transaction(&swap_request)
+            .await
+            .map_err(|e| Self::map_swap_error("Direct swap build failed", e))?;
+
+        let serialized = bincode::serialize(&transaction).map_err(|e| {
+            ScreenerBotError::internal_error(format!("Failed to serialize transaction: {}", e))
+        })?;
+        let transaction_base64 = base64::engine::general_purpose::STANDARD.encode(&serialized);
+
+        logger::debug(
+            LogTag::Swap,
+            &format!(
+                "Direct swap for {} via {} ({})",
+                token.symbol, data.program, data.direction
+            ),
+        );
+
+        preflight_swap(quote, &transaction_base64).await?;
+
+        let rpc_client = crate::rpc::get_rpc_client();
+        let send_start = Instant::now();
+        let send_result = rpc_client
+            .sign_send_and_confirm_transaction_simple(&transaction_base64)
+            .await;
+        record_fee_outcome(
+            self.id(),
+            &quote.priority,
+            fee_estimate.total_lamports,
+            send_start.elapsed().as_millis() as u64,
+            send_result.is_ok(),
+        )
+        .await;
+        let signature = send_result?;
+
+        let sig_str = signature.to_string();
+        logger::info(
+            LogTag::Swap,
+            &format!("Direct swap confirmed: {}", &sig_str[..8]),
+        );
+
+        crate::events::record_swap_event(
+            &sig_str,
+            &quote.input_mint,
+            &quote.output_mint,
+            params.input_amount_raw,
+            params.expected_output_raw,
+            true,
+            None,
+        )
+        .await;
+
+        Ok(SwapResult {
+            success: true,
+            router_id: self.id().to_string(),
+            router_name: self.name().to_string(),
+            transaction_signature: sig_str,
+            input_amount: params.input_amount_raw,
+            output_amount: params.expected_output_raw,
+            price_impact_pct: quote.price_impact_pct,
+            fee_lamports: fee_estimate.total_lamports,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            effective_price_sol: None,
+        })
+    }
+}
@@ -4,6 +4,8 @@ use crate::constants::SOL_MINT;
 use crate::errors::ScreenerBotError;
 use crate::logger::{self, LogTag};
 use crate::rpc::RpcClientMethods;
+use crate::swaps::operations::preflight_swap;
+use crate::swaps::priority_fees::{estimate_swap_priority_fee, record_fee_outcome};
 use crate::swaps::router::{Quote, QuoteRequest, SwapResult, SwapRouter};
 use crate::swaps::types::deserialize_optional_string_or_number;
 use crate::tokens::Token;
@@ -112,6 +114,7 @@ impl GmgnRouter {
         from_address: &str,
         slippage: f64,
         swap_mode: &str,
+        priority_fee_sol: f64,
     ) -> Result<SwapData, ScreenerBotError> {
         if let Some(unhealthy) =
             crate::connectivity::check_endpoints_healthy(&["internet", "rpc"]).await
@@ -122,7 +125,6 @@ impl GmgnRouter {
             )));
         }
 
-        let gmgn_fee_sol = with_config(|cfg| cfg.swaps.gmgn.fee_sol);
         let gmgn_anti_mev = with_config(|cfg| cfg.swaps.gmgn.anti_mev);
         let gmgn_partner = with_config(|cfg| cfg.swaps.gmgn.partner.clone());
 
@@ -135,7 +137,7 @@ impl GmgnRouter {
             from_address,
             slippage,
             swap_mode,
-            gmgn_fee_sol,
+            priority_fee_sol,
             gmgn_anti_mev,
             gmgn_partner
         );
@@ -372,6 +374,17 @@ impl SwapRouter for GmgnRouter {
     }
 
     async fn get_quote(&self, request: &QuoteRequest) -> Result<Quote, ScreenerBotError> {
+        // Configured fee acts as a floor under the dynamic estimate
+        let fee_estimate = estimate_swap_priority_fee(
+            &request.input_mint,
+            &request.output_mint,
+            None,
+            &request.priority,
+        )
+        .await;
+        let priority_fee_sol = with_config(|cfg| cfg.swaps.gmgn.fee_sol)
+            .max(fee_estimate.total_lamports as f64 / 1_000_000_000.0);
+
         let swap_data = self
             .fetch_gmgn_quote_internal(
                 &request.input_mint,
@@ -380,6 +393,7 @@ impl SwapRouter for GmgnRouter {
                 &request.wallet_address,
                 request.slippage_pct,
                 request.swap_mode.as_str(),
+                priority_fee_sol,
             )
             .await?;
 
@@ -409,6 +423,7 @@ impl SwapRouter for GmgnRouter {
             slippage_bps: (request.slippage_pct * 100.0) as u16,
             route_plan: "GMGN Anti-MEV".to_string(),
             wallet_address: request.wallet_address.clone(),
+            priority: request.priority.clone(),
             swap_mode: request.swap_mode,
             execution_data,
         })
@@ -425,14 +440,26 @@ impl SwapRouter for GmgnRouter {
             ScreenerBotError::internal_error(format!("Swap data deserialization failed: {}", e))
         })?;
 
-        let signature = self
+        preflight_swap(quote, &swap_data.raw_tx.swap_transaction).await?;
+
+        let send_start = Instant::now();
+        let send_result = self
             .execute_gmgn_swap_internal(
                 token,
                 &quote.input_mint,
                 &quote.output_mint,
                 swap_data.clone(),
             )
-            .await?;
+            .await;
+        record_fee_outcome(
+            self.id(),
+            &quote.priority,
+            quote.fee_lamports,
+            send_start.elapsed().as_millis() as u64,
+            send_result.is_ok(),
+        )
+        .await;
+        let signature = send_result?;
 
         Ok(SwapResult {
             success: true,
@@ -4,6 +4,8 @@ use crate::config::with_config;
 use crate::errors::ScreenerBotError;
 use crate::logger::{self, LogTag};
 use crate::rpc::RpcClientMethods;
+use crate::swaps::operations::preflight_swap;
+use crate::swaps::priority_fees::{estimate_swap_priority_fee, record_fee_outcome};
 use crate::swaps::router::{Quote, QuoteRequest, SwapMode, SwapResult, SwapRouter};
 use crate::tokens::decimals::is_token_2022;
 use crate::tokens::Token;
@@ -332,6 +334,7 @@ impl SwapRouter for JupiterRouter {
             route_plan,
             swap_mode: request.swap_mode,
             wallet_address: request.wallet_address.clone(),
+            priority: request.priority.clone(),
             execution_data,
         })
     }
@@ -372,15 +375,21 @@ impl SwapRouter for JupiterRouter {
             get_referral_token_account_for_swap(&quote.input_mint, &quote.output_mint)
         };
 
+        let fee_estimate = estimate_swap_priority_fee(
+            &quote.input_mint,
+            &quote.output_mint,
+            None,
+            &quote.priority,
+        )
+        .await;
+
         let swap_req = JupiterSwapRequest {
             user_public_key: quote.wallet_address.clone(),
             quote_response,
             dynamic_compute_unit_limit: Some(with_config(|cfg| {
                 cfg.swaps.jupiter.dynamic_compute_unit_limit
             })),
-            prioritization_fee_lamports: Some(with_config(|cfg| {
-                cfg.swaps.jupiter.default_priority_fee
-            })),
+            prioritization_fee_lamports: Some(fee_estimate.total_lamports),
             platform_fee_bps: None, // Already set in quote request
             fee_account: fee_account.clone(),
         };
@@ -424,14 +433,26 @@ impl SwapRouter for JupiterRouter {
             ScreenerBotError::parse_error(format!("Jupiter swap response parse failed: {}", e))
         })?;
 
+        // Simulate before signing so failing swaps never pay fees
+        preflight_swap(quote, &swap_response.swap_transaction).await?;
+
         // Transaction is already base64 encoded, send it directly
         let rpc_client = crate::rpc::get_rpc_client();
-        let signature = rpc_client
+        let send_start = Instant::now();
+        let send_result = rpc_client
             .sign_send_and_confirm_transaction_simple(&swap_response.swap_transaction)
-            .await
-            .map_err(|e| {
-                ScreenerBotError::network_error(format!("Transaction send failed: {}", e))
-            })?;
+            .await;
+        record_fee_outcome(
+            self.id(),
+            &quote.priority,
+            fee_estimate.total_lamports,
+            send_start.elapsed().as_millis() as u64,
+            send_result.is_ok(),
+        )
+        .await;
+        let signature = send_result.map_err(|e| {
+            ScreenerBotError::network_error(format!("Transaction send failed: {}", e))
+        })?;
 
         let elapsed = start.elapsed();
 
@@ -1,9 +1,11 @@
 /// Router Implementations Module
 /// Exports all swap router implementations
+mod direct;
 mod gmgn;
 mod jupiter;
 mod raydium;
 
+pub use direct::DirectRouter;
 pub use gmgn::GmgnRouter;
 pub use jupiter::JupiterRouter;
 pub use raydium::RaydiumRouter;
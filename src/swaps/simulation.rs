@@ -0,0 +1,566 @@
+/// Pre-flight swap simulation
+/// Runs every swap transaction through simulateTransaction before it is signed and sent,
+/// so failing swaps (insufficient funds, slippage, frozen accounts, Token-2022 transfer
+/// hooks) are rejected without paying fees.
+use crate::config::with_config;
+use crate::constants::{COMPUTE_BUDGET_PROGRAM_ID, SOL_MINT};
+use crate::errors::{parse_structured_solana_error, BlockchainError, ScreenerBotError};
+use crate::logger::{self, LogTag};
+use crate::rpc::{get_rpc_client, RpcClientMethods, SimulationResult};
+use base64::Engine;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+use std::str::FromStr;
+
+/// Offset of the amount field inside a token account
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+/// Base fee charged per transaction signature
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Compute units granted per instruction when no SetComputeUnitLimit is present
+const DEFAULT_INSTRUCTION_COMPUTE_UNITS: u64 = 200_000;
+
+/// Highest compute unit limit a transaction can request
+const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+
+/// Log lines kept in the recorded event
+const MAX_EVENT_LOG_LINES: usize = 20;
+
+// ============================================================================
+// TYPES
+// ============================================================================
+
+/// Swap transaction to simulate
+#[derive(Debug, Clone)]
+pub struct SimulationRequest {
+    /// Router or program that built the transaction (for logs and events)
+    pub source: String,
+    /// Base64-encoded (unsigned) transaction
+    pub transaction_base64: String,
+    pub wallet_address: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub input_amount: u64,
+    /// Output promised by the quote (raw units)
+    pub expected_output: u64,
+    /// Lowest acceptable output after slippage (raw units)
+    pub min_output: u64,
+}
+
+/// Why a simulated swap was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimulationFailure {
+    InsufficientFunds,
+    SlippageExceeded,
+    AccountFrozen,
+    TransferHook,
+    ProgramError,
+    OutputBelowQuote,
+}
+
+impl SimulationFailure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SimulationFailure::InsufficientFunds => "insufficient_funds",
+            SimulationFailure::SlippageExceeded => "slippage_exceeded",
+            SimulationFailure::AccountFrozen => "account_frozen",
+            SimulationFailure::TransferHook => "transfer_hook",
+            SimulationFailure::ProgramError => "program_error",
+            SimulationFailure::OutputBelowQuote => "output_below_quote",
+        }
+    }
+}
+
+/// Outcome of a passed simulation
+#[derive(Debug, Clone)]
+pub struct SimulationReport {
+    pub units_consumed: Option<u64>,
+    /// Output received by the wallet in the simulation (None when it could not be measured)
+    pub simulated_output: Option<u64>,
+}
+
+// ============================================================================
+// SIMULATION
+// ============================================================================
+
+/// Simulate a swap transaction and reject it when it would fail or under-deliver
+///
+/// Returns Ok immediately when simulation is disabled. RPC failures of the simulation
+/// request itself are tolerated when `swaps.simulation.allow_on_rpc_error` is set.
+pub async fn simulate_swap(
+    request: &SimulationRequest,
+) -> Result<Option<SimulationReport>, ScreenerBotError> {
+    let (enabled, check_output, allow_on_rpc_error) = with_config(|cfg| {
+        (
+            cfg.swaps.simulation.enabled,
+            cfg.swaps.simulation.check_output,
+            cfg.swaps.simulation.allow_on_rpc_error,
+        )
+    });
+
+    if !enabled {
+        return Ok(None);
+    }
+
+    match run_simulation(request, check_output).await {
+        Ok(report) => {
+            logger::debug(
+                LogTag::Swap,
+                &format!(
+                    "{} simulation passed: output={:?} (min {}), units={:?}",
+                    request.source,
+                    report.simulated_output,
+                    request.min_output,
+                    report.units_consumed
+                ),
+            );
+            crate::events::record_swap_simulation_event(
+                &request.input_mint,
+                &request.output_mint,
+                true,
+                json!({
+                    "source": request.source,
+                    "input_amount": request.input_amount,
+                    "expected_output": request.expected_output,
+                    "min_output": request.min_output,
+                    "simulated_output": report.simulated_output,
+                    "units_consumed": report.units_consumed,
+                }),
+            )
+            .await;
+            Ok(Some(report))
+        }
+        Err(SimulationError::Rpc(e)) => {
+            logger::warning(
+                LogTag::Swap,
+                &format!("{} simulation request failed: {}", request.source, e),
+            );
+            if allow_on_rpc_error {
+                Ok(None)
+            } else {
+                Err(ScreenerBotError::network_error(format!(
+                    "Swap simulation failed: {}",
+                    e
+                )))
+            }
+        }
+        Err(SimulationError::Rejected {
+            failure,
+            message,
+            error,
+            logs,
+            simulated_output,
+            units_consumed,
+        }) => {
+            logger::warning(
+                LogTag::Swap,
+                &format!(
+                    "{} swap rejected by simulation ({}): {}",
+                    request.source,
+                    failure.as_str(),
+                    message
+                ),
+            );
+            let log_tail: Vec<&String> = logs
+                .iter()
+                .skip(logs.len().saturating_sub(MAX_EVENT_LOG_LINES))
+                .collect();
+            crate::events::record_swap_simulation_event(
+                &request.input_mint,
+                &request.output_mint,
+                false,
+                json!({
+                    "source": request.source,
+                    "input_amount": request.input_amount,
+                    "expected_output": request.expected_output,
+                    "min_output": request.min_output,
+                    "simulated_output": simulated_output,
+                    "units_consumed": units_consumed,
+                    "failure": failure.as_str(),
+                    "error_message": message,
+                    "error": error.to_string(),
+                    "logs": log_tail,
+                }),
+            )
+            .await;
+            Err(error)
+        }
+    }
+}
+
+/// Internal simulation error
+enum SimulationError {
+    /// The simulation request itself failed
+    Rpc(String),
+    /// The transaction would fail or under-deliver
+    Rejected {
+        failure: SimulationFailure,
+        message: String,
+        error: ScreenerBotError,
+        logs: Vec<String>,
+        simulated_output: Option<u64>,
+        units_consumed: Option<u64>,
+    },
+}
+
+async fn run_simulation(
+    request: &SimulationRequest,
+    check_output: bool,
+) -> Result<SimulationReport, SimulationError> {
+    let rpc_client = get_rpc_client();
+
+    // Track the account receiving the output along with its balance before the swap
+    let tracked = if check_output {
+        output_account(request)
+            .await
+            .map_err(SimulationError::Rpc)?
+    } else {
+        None
+    };
+
+    let addresses: Vec<String> = tracked
+        .iter()
+        .map(|(address, _)| address.to_string())
+        .collect();
+
+    let result = rpc_client
+        .simulate_transaction(&request.transaction_base64, &addresses)
+        .await
+        .map_err(SimulationError::Rpc)?;
+
+    if let Some(err) = &result.err {
+        let (failure, message) = classify_simulation_error(err, &result.logs);
+        return Err(SimulationError::Rejected {
+            failure,
+            error: failure_to_error(failure, &message, &result),
+            message,
+            logs: result.logs,
+            simulated_output: None,
+            units_consumed: result.units_consumed,
+        });
+    }
+
+    let simulated_output = tracked.map(|(_, pre_balance)| {
+        let post = result.accounts.first().cloned().flatten();
+        let post_balance = if request.output_mint == SOL_MINT {
+            post.map(|a| a.lamports).unwrap_or(0)
+        } else {
+            post.and_then(|a| token_amount(&a.data)).unwrap_or(0)
+        };
+        post_balance.saturating_sub(pre_balance)
+    });
+
+    if let Some(output) = simulated_output {
+        // The simulated wallet balance already has the base and priority fees deducted
+        let allowance = if request.output_mint == SOL_MINT {
+            transaction_fee_lamports(&request.transaction_base64).map_err(SimulationError::Rpc)?
+        } else {
+            0
+        };
+        if output.saturating_add(allowance) < request.min_output {
+            let failure = SimulationFailure::OutputBelowQuote;
+            let message = format!(
+                "Simulated output {} is below the quote minimum {} (expected {})",
+                output, request.min_output, request.expected_output
+            );
+            return Err(SimulationError::Rejected {
+                failure,
+                error: failure_to_error(failure, &message, &result),
+                message,
+                logs: result.logs,
+                simulated_output: Some(output),
+                units_consumed: result.units_consumed,
+            });
+        }
+    }
+
+    Ok(SimulationReport {
+        units_consumed: result.units_consumed,
+        simulated_output,
+    })
+}
+
+/// Account that receives the swap output and its current balance
+///
+/// SOL output is measured on the wallet's lamports, token output on the wallet's
+/// associated token account for the mint's token program.
+async fn output_account(request: &SimulationRequest) -> Result<Option<(Pubkey, u64)>, String> {
+    let rpc_client = get_rpc_client();
+    let wallet = Pubkey::from_str(&request.wallet_address)
+        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+
+    if request.output_mint == SOL_MINT {
+        let lamports = rpc_client
+            .get_account(&wallet)
+            .await?
+            .map(|a| a.lamports)
+            .unwrap_or(0);
+        return Ok(Some((wallet, lamports)));
+    }
+
+    let mint = Pubkey::from_str(&request.output_mint)
+        .map_err(|e| format!("Invalid output mint: {}", e))?;
+    let token_program = match rpc_client.get_account(&mint).await? {
+        Some(account) => account.owner,
+        None => return Ok(None),
+    };
+    let ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &wallet,
+        &mint,
+        &token_program,
+    );
+    let balance = rpc_client
+        .get_account(&ata)
+        .await?
+        .and_then(|a| token_amount(&a.data))
+        .unwrap_or(0);
+
+    Ok(Some((ata, balance)))
+}
+
+/// Read the amount field of a token account
+fn token_amount(data: &[u8]) -> Option<u64> {
+    data.get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+}
+
+/// Network fee (base + priority) the transaction pays, read from its signature count
+/// and ComputeBudget instructions
+fn transaction_fee_lamports(transaction_base64: &str) -> Result<u64, String> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(transaction_base64)
+        .map_err(|e| format!("Failed to decode transaction: {}", e))?;
+    let transaction: VersionedTransaction = bincode::deserialize(&bytes)
+        .map_err(|e| format!("Failed to deserialize transaction: {}", e))?;
+    let compute_budget = Pubkey::from_str(COMPUTE_BUDGET_PROGRAM_ID).map_err(|e| e.to_string())?;
+
+    let message = &transaction.message;
+    let account_keys = message.static_account_keys();
+    let mut unit_limit = None;
+    let mut unit_price = 0u64;
+    let mut other_instructions = 0u64;
+
+    for instruction in message.instructions() {
+        if account_keys.get(instruction.program_id_index as usize) != Some(&compute_budget) {
+            other_instructions += 1;
+            continue;
+        }
+        match instruction.data.split_first() {
+            // SetComputeUnitLimit = 2, u32 little endian
+            Some((2, rest)) => {
+                unit_limit = rest
+                    .get(..4)
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .map(|bytes| u32::from_le_bytes(bytes) as u64);
+            }
+            // SetComputeUnitPrice = 3, u64 little endian (micro-lamports per CU)
+            Some((3, rest)) => {
+                unit_price = rest
+                    .get(..8)
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .map(u64::from_le_bytes)
+                    .unwrap_or(0);
+            }
+            _ => {}
+        }
+    }
+
+    let unit_limit = unit_limit
+        .unwrap_or(other_instructions * DEFAULT_INSTRUCTION_COMPUTE_UNITS)
+        .min(MAX_COMPUTE_UNIT_LIMIT);
+    let priority_fee = (unit_price as u128 * unit_limit as u128).div_ceil(1_000_000) as u64;
+    let base_fee = message.header().num_required_signatures as u64 * LAMPORTS_PER_SIGNATURE;
+
+    Ok(base_fee + priority_fee)
+}
+
+// ============================================================================
+// CLASSIFICATION
+// ============================================================================
+
+/// Classify a simulation error from `err` and the program logs
+fn classify_simulation_error(
+    err: &serde_json::Value,
+    logs: &[String],
+) -> (SimulationFailure, String) {
+    let parsed = parse_structured_solana_error(err, None);
+    let logs_lower = logs.join("\n").to_lowercase();
+
+    let failure = if logs_lower.contains("transfer hook")
+        || logs_lower.contains("transferhook")
+        || logs_lower.contains("extraaccountmeta")
+    {
+        SimulationFailure::TransferHook
+    } else if logs_lower.contains("account is frozen") || parsed.error_name == "TokenAccountFrozen"
+    {
+        SimulationFailure::AccountFrozen
+    } else if logs_lower.contains("insufficient funds")
+        || logs_lower.contains("insufficient lamports")
+        || matches!(
+            parsed.error_name.as_str(),
+            "InsufficientFunds" | "InsufficientFundsForFee" | "TokenInsufficientFunds"
+        )
+    {
+        SimulationFailure::InsufficientFunds
+    } else if logs_lower.contains("slippage")
+        || logs_lower.contains("toolittlesolreceived")
+        || logs_lower.contains("toomuchsolrequired")
+        || logs_lower.contains("exceedsdesiredslippagelimit")
+        || parsed.error_name.contains("Slippage")
+    {
+        SimulationFailure::SlippageExceeded
+    } else {
+        SimulationFailure::ProgramError
+    };
+
+    let message = match logs
+        .iter()
+        .rev()
+        .find(|l| l.contains("Error") || l.contains("failed"))
+    {
+        Some(line) => format!("{} ({})", parsed.description, line.trim()),
+        None => parsed.description,
+    };
+
+    (failure, message)
+}
+
+/// Map a classified failure to the matching ScreenerBotError
+fn failure_to_error(
+    failure: SimulationFailure,
+    message: &str,
+    result: &SimulationResult,
+) -> ScreenerBotError {
+    let message = format!("Swap simulation failed: {}", message);
+    match failure {
+        SimulationFailure::InsufficientFunds => ScreenerBotError::insufficient_balance(message),
+        SimulationFailure::SlippageExceeded | SimulationFailure::OutputBelowQuote => {
+            ScreenerBotError::slippage_exceeded(message)
+        }
+        SimulationFailure::AccountFrozen
+        | SimulationFailure::TransferHook
+        | SimulationFailure::ProgramError => {
+            let parsed = result
+                .err
+                .as_ref()
+                .map(|err| parse_structured_solana_error(err, None));
+            ScreenerBotError::Blockchain(BlockchainError::ProgramError {
+                signature: "simulation".to_string(),
+                program_id: failed_program(&result.logs).unwrap_or_default(),
+                error_code: parsed.and_then(|p| p.error_code).unwrap_or(0),
+                instruction_data: Some(message),
+                logs: result.logs.clone(),
+            })
+        }
+    }
+}
+
+/// Program id from the last "Program <id> failed" log line
+fn failed_program(logs: &[String]) -> Option<String> {
+    logs.iter().rev().find_map(|line| {
+        let rest = line.strip_prefix("Program ")?;
+        let (program_id, tail) = rest.split_once(' ')?;
+        tail.starts_with("failed").then(|| program_id.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_classify_from_logs() {
+        let err = json!({"InstructionError": [2, {"Custom": 1}]});
+
+        let (failure, _) =
+            classify_simulation_error(&err, &logs(&["Program log: Error: insufficient funds"]));
+        assert_eq!(failure, SimulationFailure::InsufficientFunds);
+
+        let (failure, _) =
+            classify_simulation_error(&err, &logs(&["Program log: Error: Account is frozen"]));
+        assert_eq!(failure, SimulationFailure::AccountFrozen);
+
+        let (failure, _) =
+            classify_simulation_error(&err, &logs(&["Program log: Error: TooLittleSolReceived"]));
+        assert_eq!(failure, SimulationFailure::SlippageExceeded);
+
+        let (failure, _) = classify_simulation_error(
+            &err,
+            &logs(&["Program log: Failed to execute transfer hook program"]),
+        );
+        assert_eq!(failure, SimulationFailure::TransferHook);
+    }
+
+    #[test]
+    fn test_classify_from_error_code() {
+        let err = json!({"InstructionError": [1, {"Custom": 6001}]});
+        let (failure, _) = classify_simulation_error(&err, &[]);
+        assert_eq!(failure, SimulationFailure::SlippageExceeded);
+
+        let err = json!({"InstructionError": [1, {"Custom": 4242}]});
+        let (failure, _) = classify_simulation_error(&err, &[]);
+        assert_eq!(failure, SimulationFailure::ProgramError);
+    }
+
+    #[test]
+    fn test_failed_program_and_token_amount() {
+        let lines = logs(&[
+            "Program ComputeBudget111111111111111111111111111111 success",
+            "Program 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 failed: custom program error: 0x1",
+        ]);
+        assert_eq!(
+            failed_program(&lines).as_deref(),
+            Some("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8")
+        );
+
+        let mut data = vec![0u8; 165];
+        data[64..72].copy_from_slice(&1_234_567u64.to_le_bytes());
+        assert_eq!(token_amount(&data), Some(1_234_567));
+        assert_eq!(token_amount(&data[..70]), None);
+    }
+
+    #[test]
+    fn test_transaction_fee_lamports() {
+        use solana_sdk::instruction::Instruction;
+        use solana_sdk::message::Message;
+        use solana_sdk::transaction::Transaction;
+
+        let payer = Pubkey::new_unique();
+        let compute_budget = Pubkey::from_str(COMPUTE_BUDGET_PROGRAM_ID).unwrap();
+        let swap = Instruction::new_with_bytes(Pubkey::new_unique(), &[9], vec![]);
+        let encode = |instructions: &[Instruction]| {
+            let transaction = Transaction::new_unsigned(Message::new(instructions, Some(&payer)));
+            base64::engine::general_purpose::STANDARD
+                .encode(bincode::serialize(&transaction).unwrap())
+        };
+
+        // 300k CU at 10_000 µL/CU = 3_000 lamports priority + one signature
+        let mut limit = vec![2u8];
+        limit.extend_from_slice(&300_000u32.to_le_bytes());
+        let mut price = vec![3u8];
+        price.extend_from_slice(&10_000u64.to_le_bytes());
+        let priced = encode(&[
+            Instruction::new_with_bytes(compute_budget, &limit, vec![]),
+            Instruction::new_with_bytes(compute_budget, &price, vec![]),
+            swap.clone(),
+        ]);
+        assert_eq!(transaction_fee_lamports(&priced), Ok(3_000 + 5_000));
+
+        // Without a limit the default 200k CU per instruction applies
+        let unlimited = encode(&[
+            Instruction::new_with_bytes(compute_budget, &price, vec![]),
+            swap.clone(),
+        ]);
+        assert_eq!(transaction_fee_lamports(&unlimited), Ok(2_000 + 5_000));
+
+        assert_eq!(transaction_fee_lamports(&encode(&[swap])), Ok(5_000));
+        assert!(transaction_fee_lamports("not base64!").is_err());
+    }
+}
@@ -3,6 +3,7 @@
 //! Handles button clicks from inline keyboards.
 
 use super::check_auth;
+use super::manual::handle_trade_confirmation;
 use super::menu::{send_main_menu, send_positions_menu, send_settings_menu};
 use super::status::{handle_balance_command, handle_stats_command, handle_status_command};
 use super::trading::{execute_force_stop, handle_pause_entries_command, handle_stop_command};
@@ -38,7 +39,8 @@ pub async fn handle_callback_query(
             || parts[0] == "close"
             || parts[0] == "bl"
             || parts[0] == "toggle"
-            || parts[0] == "token");
+            || parts[0] == "token"
+            || parts[0] == "trade");
 
     if is_sensitive_callback && !check_auth(bot, chat_id, user_id).await {
         return Ok(()); // Auth check failed, message already sent
@@ -204,6 +206,10 @@ pub async fn handle_callback_query(
         }
         ["exec", "tokenbl", mint_short] => execute_token_blacklist(bot, chat_id, mint_short).await,
 
+        // Quoted manual trades (/buy, /sell, /dca, /close_all)
+        ["trade", "ok", id] => handle_trade_confirmation(bot, chat_id, user_id, id, true).await,
+        ["trade", "no", id] => handle_trade_confirmation(bot, chat_id, user_id, id, false).await,
+
         _ => {
             logger::debug(LogTag::Telegram, &format!("Unknown callback: {}", data));
             Ok(())
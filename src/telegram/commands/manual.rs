@@ -0,0 +1,882 @@
+//! Manual trading commands
+//!
+//! `/buy`, `/sell`, `/dca` and `/close_all` resolve the token, show a quote
+//! preview with confirm/cancel buttons and execute through `trader::manual`
+//! once confirmed. Replies are sent after the transaction is confirmed on-chain.
+
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use solana_sdk::pubkey::Pubkey;
+use teloxide::prelude::*;
+use teloxide::types::{ChatId, InlineKeyboardMarkup, ParseMode};
+use uuid::Uuid;
+
+use crate::config::with_config;
+use crate::constants::SOL_MINT;
+use crate::logger::{self, LogTag};
+use crate::positions::{self, Position};
+use crate::rpc::{get_rpc_client, RpcClientMethods};
+use crate::swaps::{get_best_quote, Quote, QuoteRequest, SwapMode};
+use crate::telegram::{formatters, keyboards};
+use crate::trader::manual::{manual_add, manual_buy, manual_sell};
+use crate::trader::{TradePriority, TradeResult};
+
+/// How long a quote preview can be confirmed
+const CONFIRM_TTL: Duration = Duration::from_secs(30);
+
+/// Attempts to find a submitted transaction on-chain
+const VERIFY_ATTEMPTS: u32 = 10;
+
+/// Pause between verification attempts
+const VERIFY_DELAY: Duration = Duration::from_secs(3);
+
+/// A trade waiting for the user to press confirm
+#[derive(Debug, Clone)]
+enum PendingTrade {
+    Buy {
+        mint: String,
+        symbol: String,
+        size_sol: f64,
+    },
+    Sell {
+        mint: String,
+        symbol: String,
+        percent: f64,
+    },
+    Dca {
+        mint: String,
+        symbol: String,
+        size_sol: f64,
+    },
+    CloseAll {
+        positions: Vec<(String, String)>,
+    },
+}
+
+struct PendingEntry {
+    trade: PendingTrade,
+    user_id: i64,
+    created_at: Instant,
+}
+
+/// Pending confirmations keyed by the id carried in the callback data
+static PENDING_TRADES: Lazy<DashMap<String, PendingEntry>> = Lazy::new(DashMap::new);
+
+fn store_pending(trade: PendingTrade, user_id: i64) -> String {
+    PENDING_TRADES.retain(|_, entry| entry.created_at.elapsed() < CONFIRM_TTL);
+
+    let id = Uuid::new_v4().simple().to_string();
+    PENDING_TRADES.insert(
+        id.clone(),
+        PendingEntry {
+            trade,
+            user_id,
+            created_at: Instant::now(),
+        },
+    );
+    id
+}
+
+/// Take a pending trade (each confirmation executes at most once)
+///
+/// Presses from another user are rejected without consuming the confirmation.
+fn take_pending(id: &str, user_id: i64) -> Result<PendingTrade, String> {
+    let (_, entry) = match PENDING_TRADES.remove_if(id, |_, entry| entry.user_id == user_id) {
+        Some(removed) => removed,
+        None if PENDING_TRADES.contains_key(id) => {
+            return Err("This confirmation belongs to another user.".to_string())
+        }
+        None => return Err("This confirmation has expired or was already used.".to_string()),
+    };
+
+    if entry.created_at.elapsed() >= CONFIRM_TTL {
+        return Err("Quote expired. Send the command again for a fresh quote.".to_string());
+    }
+    Ok(entry.trade)
+}
+
+// ============================================================================
+// ARGUMENT PARSING
+// ============================================================================
+
+/// Split `/cmd <token> <amount>` into its two arguments
+fn parse_token_amount(text: &str) -> Option<(&str, &str)> {
+    let mut args = text.split_whitespace().skip(1);
+    let token = args.next()?;
+    let amount = args.next()?;
+    if args.next().is_some() {
+        return None;
+    }
+    Some((token, amount))
+}
+
+/// Parse `/cmd <token> <sol>` (used by /buy and /dca)
+fn parse_sol_args(text: &str) -> Option<(&str, f64)> {
+    let (token, amount) = parse_token_amount(text)?;
+    Some((token, parse_sol_amount(amount)?))
+}
+
+/// Parse `/sell <token> <pct>`
+fn parse_sell_args(text: &str) -> Option<(&str, f64)> {
+    let (token, amount) = parse_token_amount(text)?;
+    Some((token, parse_percent(amount)?))
+}
+
+/// Parse a positive SOL amount
+fn parse_sol_amount(value: &str) -> Option<f64> {
+    value
+        .trim_end_matches("SOL")
+        .trim_end_matches("sol")
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite() && *v > 0.0)
+}
+
+/// Parse a sell percentage in (0, 100]; `all` means 100
+fn parse_percent(value: &str) -> Option<f64> {
+    if value.eq_ignore_ascii_case("all") {
+        return Some(100.0);
+    }
+    value
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite() && *v > 0.0 && *v <= 100.0)
+}
+
+// ============================================================================
+// TOKEN RESOLUTION
+// ============================================================================
+
+fn normalize_symbol(query: &str) -> String {
+    query.trim_start_matches('$').to_lowercase()
+}
+
+/// Resolve a mint address or symbol to (mint, symbol) via token search
+async fn resolve_token(query: &str) -> Result<(String, String), String> {
+    if Pubkey::from_str(query).is_ok() {
+        let symbol = crate::tokens::get_full_token_async(query)
+            .await
+            .ok()
+            .flatten()
+            .map(|t| t.symbol);
+        let symbol = match symbol {
+            Some(symbol) => symbol,
+            None => crate::tokens::search_tokens(query, Some(1))
+                .await
+                .ok()
+                .and_then(|r| r.results.into_iter().next())
+                .map(|r| r.symbol)
+                .unwrap_or_else(|| keyboards::mint_short(query)),
+        };
+        return Ok((query.to_string(), symbol));
+    }
+
+    let wanted = normalize_symbol(query);
+    let results = crate::tokens::search_tokens(query.trim_start_matches('$'), Some(10)).await?;
+
+    // Several tokens share popular symbols - pick the most liquid exact match
+    results
+        .results
+        .into_iter()
+        .filter(|r| r.symbol.to_lowercase() == wanted)
+        .max_by(|a, b| {
+            a.liquidity_usd
+                .unwrap_or(0.0)
+                .total_cmp(&b.liquidity_usd.unwrap_or(0.0))
+        })
+        .map(|r| (r.mint, r.symbol))
+        .ok_or_else(|| format!("No token found for '{}'. Try the mint address.", query))
+}
+
+/// Find an open position by mint, mint prefix or symbol
+async fn resolve_position(query: &str) -> Result<Position, String> {
+    let open = positions::get_open_positions().await;
+    let wanted = normalize_symbol(query);
+
+    if let Some(position) = open.iter().find(|p| p.mint == query) {
+        return Ok(position.clone());
+    }
+
+    let matches: Vec<&Position> = open
+        .iter()
+        .filter(|p| {
+            p.symbol.to_lowercase() == wanted || (query.len() >= 4 && p.mint.starts_with(query))
+        })
+        .collect();
+
+    match matches.as_slice() {
+        [position] => Ok((*position).clone()),
+        [] => Err(format!("No open position for '{}'.", query)),
+        _ => Err(format!(
+            "Several open positions match '{}'. Use the mint address.",
+            query
+        )),
+    }
+}
+
+fn remaining_tokens(position: &Position) -> u64 {
+    position
+        .remaining_token_amount
+        .or(position.token_amount)
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// QUOTES
+// ============================================================================
+
+async fn fetch_quote(
+    input_mint: &str,
+    output_mint: &str,
+    input_amount: u64,
+) -> Result<Quote, String> {
+    let wallet_address = crate::utils::get_wallet_address().map_err(|e| e.to_string())?;
+
+    let request = QuoteRequest {
+        input_mint: input_mint.to_string(),
+        output_mint: output_mint.to_string(),
+        input_amount,
+        wallet_address,
+        slippage_pct: with_config(|cfg| cfg.swaps.slippage.quote_default_pct),
+        swap_mode: SwapMode::ExactIn,
+        priority: TradePriority::High,
+    };
+
+    get_best_quote(request)
+        .await
+        .map_err(|e| format!("Quote failed: {}", e))
+}
+
+/// Quote a SOL -> token buy and return (quote, tokens out in UI units)
+async fn quote_buy(mint: &str, size_sol: f64) -> Result<(Quote, f64), String> {
+    let lamports = (size_sol * 1_000_000_000.0) as u64;
+    let quote = fetch_quote(SOL_MINT, mint, lamports).await?;
+    let decimals = crate::tokens::decimals::get(mint).await.unwrap_or(9);
+    let tokens_out = quote.output_amount as f64 / 10f64.powi(decimals as i32);
+    Ok((quote, tokens_out))
+}
+
+async fn send_preview(
+    bot: &Bot,
+    chat_id: ChatId,
+    message: &str,
+    keyboard: InlineKeyboardMarkup,
+) -> Result<(), String> {
+    bot.send_message(chat_id, message)
+        .parse_mode(ParseMode::Html)
+        .reply_markup(keyboard)
+        .await
+        .map_err(|e| format!("Failed to send trade preview: {}", e))?;
+    Ok(())
+}
+
+async fn send_text(bot: &Bot, chat_id: ChatId, message: &str) -> Result<(), String> {
+    bot.send_message(chat_id, message)
+        .parse_mode(ParseMode::Html)
+        .await
+        .map_err(|e| format!("Failed to send message: {}", e))?;
+    Ok(())
+}
+
+// ============================================================================
+// COMMANDS
+// ============================================================================
+
+/// Handle /buy <mint|symbol> <sol> - quote preview for opening a position
+pub async fn handle_buy_command(
+    bot: &Bot,
+    chat_id: ChatId,
+    user_id: i64,
+    text: &str,
+) -> Result<(), String> {
+    let (token, size_sol) = match parse_sol_args(text) {
+        Some(args) => args,
+        None => return send_text(
+            bot,
+            chat_id,
+            "Usage: /buy &lt;mint|symbol&gt; &lt;sol&gt;\n\nExample: <code>/buy BONK 0.1</code>",
+        )
+        .await,
+    };
+
+    let (mint, symbol) = match resolve_token(token).await {
+        Ok(resolved) => resolved,
+        Err(e) => return send_text(bot, chat_id, &format!("❌ {}", e)).await,
+    };
+
+    if positions::get_position_by_mint(&mint).await.is_some() {
+        return send_text(
+            bot,
+            chat_id,
+            &format!(
+                "ℹ️ Already holding <b>${}</b>.\n\nUse /dca to add to the position.",
+                formatters::html_escape(&symbol)
+            ),
+        )
+        .await;
+    }
+
+    let (quote, tokens_out) = match quote_buy(&mint, size_sol).await {
+        Ok(quoted) => quoted,
+        Err(e) => return send_text(bot, chat_id, &format!("❌ {}", e)).await,
+    };
+
+    let msg = formatters::msg_trade_preview(
+        "Confirm Buy",
+        &symbol,
+        &mint,
+        &format!("{} SOL", formatters::format_sol(size_sol)),
+        &format!("~{} tokens", formatters::format_tokens_f64(tokens_out)),
+        &quote,
+    );
+    let id = store_pending(
+        PendingTrade::Buy {
+            mint,
+            symbol,
+            size_sol,
+        },
+        user_id,
+    );
+    send_preview(
+        bot,
+        chat_id,
+        &msg,
+        keyboards::confirm_trade(&id, &format!("✅ Buy {} SOL", size_sol)),
+    )
+    .await
+}
+
+/// Handle /dca <mint|symbol> <sol> - quote preview for adding to a position
+pub async fn handle_dca_command(
+    bot: &Bot,
+    chat_id: ChatId,
+    user_id: i64,
+    text: &str,
+) -> Result<(), String> {
+    let (token, size_sol) = match parse_sol_args(text) {
+        Some(args) => args,
+        None => return send_text(
+            bot,
+            chat_id,
+            "Usage: /dca &lt;mint|symbol&gt; &lt;sol&gt;\n\nExample: <code>/dca BONK 0.05</code>",
+        )
+        .await,
+    };
+
+    let position = match resolve_position(token).await {
+        Ok(position) => position,
+        Err(e) => return send_text(bot, chat_id, &format!("❌ {}", e)).await,
+    };
+
+    let (quote, tokens_out) = match quote_buy(&position.mint, size_sol).await {
+        Ok(quoted) => quoted,
+        Err(e) => return send_text(bot, chat_id, &format!("❌ {}", e)).await,
+    };
+
+    let msg = formatters::msg_trade_preview(
+        "Confirm DCA",
+        &position.symbol,
+        &position.mint,
+        &format!("{} SOL", formatters::format_sol(size_sol)),
+        &format!("~{} tokens", formatters::format_tokens_f64(tokens_out)),
+        &quote,
+    );
+    let id = store_pending(
+        PendingTrade::Dca {
+            mint: position.mint,
+            symbol: position.symbol,
+            size_sol,
+        },
+        user_id,
+    );
+    send_preview(
+        bot,
+        chat_id,
+        &msg,
+        keyboards::confirm_trade(&id, &format!("✅ DCA {} SOL", size_sol)),
+    )
+    .await
+}
+
+/// Handle /sell <mint|symbol> <pct> - quote preview for a (partial) exit
+pub async fn handle_sell_command(
+    bot: &Bot,
+    chat_id: ChatId,
+    user_id: i64,
+    text: &str,
+) -> Result<(), String> {
+    let (token, percent) = match parse_sell_args(text) {
+        Some(args) => args,
+        None => return send_text(
+            bot,
+            chat_id,
+            "Usage: /sell &lt;mint|symbol&gt; &lt;pct&gt;\n\nExample: <code>/sell BONK 50</code>",
+        )
+        .await,
+    };
+
+    let position = match resolve_position(token).await {
+        Ok(position) => position,
+        Err(e) => return send_text(bot, chat_id, &format!("❌ {}", e)).await,
+    };
+
+    let tokens_raw = remaining_tokens(&position);
+    let sell_raw = ((tokens_raw as f64) * percent / 100.0) as u64;
+    if sell_raw == 0 {
+        return send_text(bot, chat_id, "❌ Position has no tokens to sell.").await;
+    }
+
+    let quote = match fetch_quote(&position.mint, SOL_MINT, sell_raw).await {
+        Ok(quote) => quote,
+        Err(e) => return send_text(bot, chat_id, &format!("❌ {}", e)).await,
+    };
+    let sol_out = quote.output_amount as f64 / 1_000_000_000.0;
+
+    let msg = formatters::msg_trade_preview(
+        "Confirm Sell",
+        &position.symbol,
+        &position.mint,
+        &format!("{}% of position", percent),
+        &format!("~{} SOL", formatters::format_sol(sol_out)),
+        &quote,
+    );
+    let id = store_pending(
+        PendingTrade::Sell {
+            mint: position.mint,
+            symbol: position.symbol,
+            percent,
+        },
+        user_id,
+    );
+    send_preview(
+        bot,
+        chat_id,
+        &msg,
+        keyboards::confirm_trade(&id, &format!("✅ Sell {}%", percent)),
+    )
+    .await
+}
+
+/// Handle /close_all - preview of every open position's estimated exit
+pub async fn handle_close_all_command(
+    bot: &Bot,
+    chat_id: ChatId,
+    user_id: i64,
+) -> Result<(), String> {
+    let open = positions::get_open_positions().await;
+    if open.is_empty() {
+        return send_text(bot, chat_id, "✅ No open positions to close.").await;
+    }
+
+    let mut lines = Vec::with_capacity(open.len());
+    let mut total_sol = 0.0;
+    for position in &open {
+        let line = match fetch_quote(&position.mint, SOL_MINT, remaining_tokens(position)).await {
+            Ok(quote) => {
+                let sol_out = quote.output_amount as f64 / 1_000_000_000.0;
+                total_sol += sol_out;
+                format!(
+                    "• <b>${}</b> — ~{} SOL ({:.2}% impact)",
+                    formatters::html_escape(&position.symbol),
+                    formatters::format_sol(sol_out),
+                    quote.price_impact_pct
+                )
+            }
+            Err(_) => format!(
+                "• <b>${}</b> — quote unavailable",
+                formatters::html_escape(&position.symbol)
+            ),
+        };
+        lines.push(line);
+    }
+
+    let msg = format!(
+        "⚠️ <b>Close All Positions?</b>\n\n\
+         {}\n\n\
+         Estimated — <b>{} SOL</b>\n\n\
+         <i>⏰ Confirm within 30 seconds</i>",
+        lines.join("\n"),
+        formatters::format_sol(total_sol)
+    );
+    let id = store_pending(
+        PendingTrade::CloseAll {
+            positions: open.into_iter().map(|p| (p.mint, p.symbol)).collect(),
+        },
+        user_id,
+    );
+    send_preview(
+        bot,
+        chat_id,
+        &msg,
+        keyboards::confirm_trade(&id, "✅ Close ALL Positions"),
+    )
+    .await
+}
+
+// ============================================================================
+// CONFIRMATION & EXECUTION
+// ============================================================================
+
+/// Handle the confirm/cancel buttons of a trade preview
+pub async fn handle_trade_confirmation(
+    bot: &Bot,
+    chat_id: ChatId,
+    user_id: i64,
+    id: &str,
+    confirmed: bool,
+) -> Result<(), String> {
+    let trade = match take_pending(id, user_id) {
+        Ok(trade) => trade,
+        Err(e) => return send_text(bot, chat_id, &format!("⚠️ {}", e)).await,
+    };
+
+    if !confirmed {
+        return send_text(bot, chat_id, "❌ Trade cancelled.").await;
+    }
+
+    logger::info(
+        LogTag::Telegram,
+        &format!("Executing Telegram trade: {:?}", trade),
+    );
+
+    match trade {
+        PendingTrade::Buy {
+            mint,
+            symbol,
+            size_sol,
+        } => {
+            send_text(
+                bot,
+                chat_id,
+                &format!(
+                    "⏳ Buying {} SOL of ${}...",
+                    size_sol,
+                    formatters::html_escape(&symbol)
+                ),
+            )
+            .await?;
+            let outcome = verify_trade(manual_buy(&mint, size_sol).await).await;
+            reply_trade_outcome(bot, chat_id, "Buy", &mint, &symbol, outcome, |sig| {
+                keyboards::on_position_opened(&mint, sig)
+            })
+            .await
+        }
+        PendingTrade::Dca {
+            mint,
+            symbol,
+            size_sol,
+        } => {
+            send_text(
+                bot,
+                chat_id,
+                &format!(
+                    "⏳ Adding {} SOL to ${}...",
+                    size_sol,
+                    formatters::html_escape(&symbol)
+                ),
+            )
+            .await?;
+            let outcome = verify_trade(manual_add(&mint, size_sol).await).await;
+            reply_trade_outcome(bot, chat_id, "DCA", &mint, &symbol, outcome, |sig| {
+                keyboards::on_dca_executed(&mint, sig)
+            })
+            .await
+        }
+        PendingTrade::Sell {
+            mint,
+            symbol,
+            percent,
+        } => {
+            send_text(
+                bot,
+                chat_id,
+                &format!(
+                    "⏳ Selling {}% of ${}...",
+                    percent,
+                    formatters::html_escape(&symbol)
+                ),
+            )
+            .await?;
+            let pct = if percent >= 100.0 {
+                None
+            } else {
+                Some(percent)
+            };
+            let outcome = verify_trade(manual_sell(&mint, pct).await).await;
+            reply_trade_outcome(bot, chat_id, "Sell", &mint, &symbol, outcome, |sig| {
+                if percent >= 100.0 {
+                    keyboards::on_position_closed(&mint, sig)
+                } else {
+                    keyboards::on_partial_exit(&mint, sig)
+                }
+            })
+            .await
+        }
+        PendingTrade::CloseAll { positions } => {
+            send_text(
+                bot,
+                chat_id,
+                &format!("⏳ Closing {} positions...", positions.len()),
+            )
+            .await?;
+
+            let mut lines = Vec::with_capacity(positions.len());
+            let mut closed = 0;
+            let mut received_sol = 0.0;
+            for (mint, symbol) in &positions {
+                let symbol = formatters::html_escape(symbol);
+                match verify_trade(manual_sell(mint, None).await).await {
+                    Ok(result) => {
+                        closed += 1;
+                        received_sol += result.executed_size_sol.unwrap_or(0.0);
+                        lines.push(format!(
+                            "✅ ${} — {} SOL",
+                            symbol,
+                            formatters::format_sol(result.executed_size_sol.unwrap_or(0.0))
+                        ));
+                    }
+                    Err(e) => {
+                        lines.push(format!("❌ ${} — {}", symbol, formatters::html_escape(&e)))
+                    }
+                }
+            }
+
+            let msg = format!(
+                "📊 <b>Close All Complete</b>\n\n\
+                 {}\n\n\
+                 Closed — {}/{}\n\
+                 Received — <b>{} SOL</b>",
+                lines.join("\n"),
+                closed,
+                positions.len(),
+                formatters::format_sol(received_sol)
+            );
+            bot.send_message(chat_id, msg)
+                .parse_mode(ParseMode::Html)
+                .reply_markup(keyboards::main_menu_compact())
+                .await
+                .map_err(|e| format!("Failed to send close all result: {}", e))?;
+            Ok(())
+        }
+    }
+}
+
+/// Turn a manual trade result into a verified outcome
+///
+/// Waits until the transaction is found on-chain without an error. Paper
+/// trades have no on-chain transaction and are accepted as executed.
+async fn verify_trade(result: Result<TradeResult, String>) -> Result<TradeResult, String> {
+    let result = result?;
+    if !result.success {
+        return Err(result
+            .error
+            .clone()
+            .unwrap_or_else(|| "Trade failed".to_string()));
+    }
+
+    let signature = match result.tx_signature.as_deref() {
+        Some(signature) => signature,
+        None => return Err("Trade returned no transaction signature".to_string()),
+    };
+    if crate::global::is_paper_trading() {
+        return Ok(result);
+    }
+
+    for attempt in 1..=VERIFY_ATTEMPTS {
+        if let Ok(details) = get_rpc_client().get_transaction_details(signature).await {
+            return match details.meta.as_ref().and_then(|meta| meta.err.as_ref()) {
+                None => Ok(result),
+                Some(err) => Err(format!("Transaction failed on-chain: {:?}", err)),
+            };
+        }
+        if attempt < VERIFY_ATTEMPTS {
+            tokio::time::sleep(VERIFY_DELAY).await;
+        }
+    }
+
+    Err(format!(
+        "Transaction {} not confirmed after {}s - check /positions",
+        signature,
+        VERIFY_ATTEMPTS as u64 * VERIFY_DELAY.as_secs()
+    ))
+}
+
+async fn reply_trade_outcome<F>(
+    bot: &Bot,
+    chat_id: ChatId,
+    action: &str,
+    mint: &str,
+    symbol: &str,
+    outcome: Result<TradeResult, String>,
+    keyboard: F,
+) -> Result<(), String>
+where
+    F: FnOnce(&str) -> InlineKeyboardMarkup,
+{
+    let (msg, markup) = match outcome {
+        Ok(result) => {
+            let signature = result.tx_signature.clone().unwrap_or_default();
+            let msg = formatters::msg_trade_result(
+                action,
+                symbol,
+                mint,
+                result.executed_size_sol,
+                result.executed_price_sol,
+                &signature,
+            );
+            (msg, keyboard(&signature))
+        }
+        Err(e) => {
+            logger::warning(
+                LogTag::Telegram,
+                &format!("Telegram {} of {} failed: {}", action, mint, e),
+            );
+            (
+                format!(
+                    "❌ <b>{} Failed</b>\n\n<b>${}</b>\n{}",
+                    action,
+                    formatters::html_escape(symbol),
+                    formatters::html_escape(&e)
+                ),
+                keyboards::main_menu_compact(),
+            )
+        }
+    };
+
+    bot.send_message(chat_id, msg)
+        .parse_mode(ParseMode::Html)
+        .reply_markup(markup)
+        .await
+        .map_err(|e| format!("Failed to send trade result: {}", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_trade_arguments() {
+        assert_eq!(parse_token_amount("/buy BONK 0.1"), Some(("BONK", "0.1")));
+        assert_eq!(parse_token_amount("/buy BONK"), None);
+        assert_eq!(parse_token_amount("/buy BONK 0.1 extra"), None);
+
+        assert_eq!(parse_sol_amount("0.25"), Some(0.25));
+        assert_eq!(parse_sol_amount("0.25sol"), Some(0.25));
+        assert_eq!(parse_sol_amount("-1"), None);
+        assert_eq!(parse_sol_amount("abc"), None);
+
+        assert_eq!(parse_percent("50"), Some(50.0));
+        assert_eq!(parse_percent("25%"), Some(25.0));
+        assert_eq!(parse_percent("all"), Some(100.0));
+        assert_eq!(parse_percent("150"), None);
+        assert_eq!(parse_percent("0"), None);
+    }
+
+    #[test]
+    fn test_parse_command_arguments() {
+        assert_eq!(
+            parse_sol_args("/buy  $BONK   0.5SOL "),
+            Some(("$BONK", 0.5))
+        );
+        assert_eq!(parse_sol_args("/dca BONK 0.05"), Some(("BONK", 0.05)));
+        assert_eq!(parse_sol_args("/buy BONK inf"), None);
+        assert_eq!(parse_sol_args("/buy BONK NaN"), None);
+        assert_eq!(parse_sol_args("/buy 0.1"), None);
+        assert_eq!(parse_sol_args("/buy"), None);
+
+        assert_eq!(parse_sell_args("/sell BONK 100%"), Some(("BONK", 100.0)));
+        assert_eq!(parse_sell_args("/sell BONK ALL"), Some(("BONK", 100.0)));
+        assert_eq!(parse_sell_args("/sell BONK 0.5"), Some(("BONK", 0.5)));
+        assert_eq!(parse_sell_args("/sell BONK 100.1"), None);
+        assert_eq!(parse_sell_args("/sell BONK -5"), None);
+        assert_eq!(parse_sell_args("/sell BONK"), None);
+    }
+
+    fn buy(size_sol: f64) -> PendingTrade {
+        PendingTrade::Buy {
+            mint: "mint".to_string(),
+            symbol: "TEST".to_string(),
+            size_sol,
+        }
+    }
+
+    /// Insert a pending trade created `age` ago
+    fn insert_aged(trade: PendingTrade, user_id: i64, age: Duration) -> String {
+        let id = Uuid::new_v4().simple().to_string();
+        PENDING_TRADES.insert(
+            id.clone(),
+            PendingEntry {
+                trade,
+                user_id,
+                created_at: Instant::now().checked_sub(age).unwrap(),
+            },
+        );
+        id
+    }
+
+    #[test]
+    fn test_confirmation_round_trip() {
+        let id = store_pending(buy(0.25), 7);
+
+        // The confirm button carries the id in `trade:ok:<id>` (within Telegram's 64 bytes)
+        let keyboard = keyboards::confirm_trade(&id, "✅ Buy");
+        let data = match &keyboard.inline_keyboard[0][0].kind {
+            teloxide::types::InlineKeyboardButtonKind::CallbackData(data) => data.clone(),
+            other => panic!("unexpected button kind: {:?}", other),
+        };
+        assert!(data.len() <= 64);
+        let parts: Vec<&str> = data.split(':').collect();
+        assert_eq!(parts, ["trade", "ok", id.as_str()]);
+
+        match take_pending(parts[2], 7) {
+            Ok(PendingTrade::Buy { size_sol, .. }) => assert_eq!(size_sol, 0.25),
+            other => panic!("unexpected pending trade: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_expired_quote_is_rejected_and_dropped() {
+        let id = insert_aged(buy(0.1), 7, CONFIRM_TTL + Duration::from_secs(1));
+
+        assert!(take_pending(&id, 7).is_err());
+        assert!(!PENDING_TRADES.contains_key(&id));
+    }
+
+    #[test]
+    fn test_stale_confirmation_is_rejected() {
+        let id = store_pending(buy(0.1), 7);
+        assert!(take_pending(&id, 7).is_ok());
+
+        // A second press (or a replayed callback) finds nothing to execute
+        assert_eq!(
+            take_pending(&id, 7).unwrap_err(),
+            "This confirmation has expired or was already used."
+        );
+        assert!(take_pending("unknown", 7).is_err());
+    }
+
+    #[test]
+    fn test_other_user_cannot_consume_confirmation() {
+        let id = store_pending(buy(0.1), 7);
+
+        assert_eq!(
+            take_pending(&id, 8).unwrap_err(),
+            "This confirmation belongs to another user."
+        );
+        assert!(take_pending(&id, 7).is_ok());
+    }
+
+    #[test]
+    fn test_store_prunes_expired_entries() {
+        let expired = insert_aged(buy(0.1), 7, CONFIRM_TTL * 2);
+        let fresh = store_pending(buy(0.2), 7);
+
+        assert!(!PENDING_TRADES.contains_key(&expired));
+        assert!(PENDING_TRADES.contains_key(&fresh));
+    }
+}
@@ -3,19 +3,25 @@
 //! Organized command handlers for different functionality areas.
 
 mod callbacks;
+mod manual;
 mod menu;
 mod status;
 mod trading;
 
 pub use callbacks::{handle_callback_query, send_token_detail, send_tokens_list, send_tokens_menu};
+pub use manual::{
+    handle_buy_command, handle_close_all_command, handle_dca_command, handle_sell_command,
+    handle_trade_confirmation,
+};
 pub use menu::{handle_menu_command, send_main_menu};
 pub use status::{
-    handle_balance_command, handle_positions_command, handle_stats_command, handle_status_command,
+    handle_balance_command, handle_orders_command, handle_positions_command, handle_stats_command,
+    handle_status_command,
 };
 pub use trading::{
-    handle_force_stop_command, handle_help_command, handle_login_command,
-    handle_pause_entries_command, handle_resume_command, handle_resume_entries_command,
-    handle_start_command, handle_stop_command,
+    handle_cancel_order_command, handle_force_stop_command, handle_help_command,
+    handle_login_command, handle_pause_entries_command, handle_resume_command,
+    handle_resume_entries_command, handle_start_command, handle_stop_command,
 };
 
 use crate::config::with_config;
@@ -91,6 +97,12 @@ pub async fn handle_command(
             | "/stop"
             | "/tokens"
             | "/rejected"
+            | "/orders"
+            | "/cancel_order"
+            | "/buy"
+            | "/sell"
+            | "/dca"
+            | "/close_all"
     );
 
     if is_sensitive && !check_auth(bot, chat_id, user_id).await {
@@ -129,6 +141,18 @@ pub async fn handle_command(
         "/rejected" => {
             return callbacks::send_tokens_list(bot, chat_id, "rejected").await;
         }
+        "/buy" => {
+            return handle_buy_command(bot, chat_id, user_id, text).await;
+        }
+        "/sell" => {
+            return handle_sell_command(bot, chat_id, user_id, text).await;
+        }
+        "/dca" => {
+            return handle_dca_command(bot, chat_id, user_id, text).await;
+        }
+        "/close_all" => {
+            return handle_close_all_command(bot, chat_id, user_id).await;
+        }
         _ => {}
     }
 
@@ -138,6 +162,8 @@ pub async fn handle_command(
         "/positions" => handle_positions_command().await,
         "/balance" => handle_balance_command().await,
         "/stats" => handle_stats_command().await,
+        "/orders" => handle_orders_command().await,
+        "/cancel_order" => handle_cancel_order_command(text),
         "/pause" | "/pause_entries" => handle_pause_entries_command().await,
         "/resume" | "/resume_entries" => handle_resume_entries_command().await,
         "/resume_trading" => handle_resume_command().await,
@@ -5,7 +5,7 @@
 use crate::config::with_config;
 use crate::positions;
 use crate::sol_price;
-use crate::telegram::formatters::{format_duration, format_mint_display, format_sol};
+use crate::telegram::formatters::{format_duration, format_mint_display, format_price, format_sol};
 use crate::utils::get_sol_balance;
 use crate::version::VERSION;
 
@@ -164,3 +164,53 @@ pub async fn handle_stats_command() -> String {
         pnl_emoji,
     )
 }
+
+/// Handle /orders command - List pending limit/stop/OCO orders
+pub async fn handle_orders_command() -> String {
+    let orders = match crate::trader::orders::get_pending_orders() {
+        Ok(orders) => orders,
+        Err(e) => return format!("❌ Failed to load orders: {}", e),
+    };
+
+    if orders.is_empty() {
+        return "📋 <b>No Pending Orders</b>".to_string();
+    }
+
+    let mut response = format!("📋 <b>Pending Orders ({})</b>\n\n", orders.len());
+
+    for order in orders.iter().take(15) {
+        let symbol = order
+            .symbol
+            .clone()
+            .unwrap_or_else(|| format_mint_display(&order.mint));
+        let trigger = match order.stop_price_sol {
+            Some(stop) => format!(
+                "TP {} / SL {}",
+                format_price(order.trigger_price_sol),
+                format_price(stop)
+            ),
+            None => format_price(order.trigger_price_sol),
+        };
+        let size = match (order.size_sol, order.sell_percentage) {
+            (Some(size_sol), _) => format!("{} SOL", format_sol(size_sol)),
+            (_, Some(percentage)) => format!("{:.0}%", percentage),
+            _ => String::new(),
+        };
+
+        response.push_str(&format!(
+            "#{} <b>{}</b> {}\n   {} — {}\n",
+            order.id,
+            symbol,
+            order.order_type.as_str(),
+            trigger,
+            size
+        ));
+    }
+
+    if orders.len() > 15 {
+        response.push_str(&format!("\n<i>+{} more...</i>\n", orders.len() - 15));
+    }
+
+    response.push_str("\nCancel with /cancel_order &lt;id&gt;");
+    response
+}
@@ -125,6 +125,32 @@ pub async fn handle_resume_command() -> String {
     "✅ <b>Trading Resumed</b>\n\nForce stop flag has been cleared.\nNormal trading operations can now resume.".to_string()
 }
 
+/// Handle /cancel_order command - Cancel a pending order by ID
+pub fn handle_cancel_order_command(text: &str) -> String {
+    let id = match text
+        .split_whitespace()
+        .nth(1)
+        .map(|arg| arg.trim_start_matches('#').parse::<i64>())
+    {
+        Some(Ok(id)) => id,
+        _ => {
+            return "Usage: /cancel_order &lt;id&gt;\n\nUse /orders to list pending orders."
+                .to_string()
+        }
+    };
+
+    match crate::trader::orders::cancel_order(id) {
+        Ok(_) => {
+            logger::info(
+                LogTag::Telegram,
+                &format!("Order #{} cancelled via Telegram", id),
+            );
+            format!("🚫 <b>Order #{} Cancelled</b>", id)
+        }
+        Err(e) => format!("❌ {}", e),
+    }
+}
+
 /// Handle /help command
 pub fn handle_help_command() -> String {
     "🤖 <b>ScreenerBot Help</b>\n\n\
@@ -141,7 +167,14 @@ pub fn handle_help_command() -> String {
      /stop — Disable trading system\n\
      /pause — Pause new entries\n\
      /resume — Resume new entries\n\
+     /orders — Pending limit/stop orders\n\
+     /cancel_order — Cancel an order by ID\n\
      /menu — Interactive menu\n\n\
+     <b>💱 Manual Trades</b>\n\
+     /buy &lt;token&gt; &lt;sol&gt; — Open a position\n\
+     /sell &lt;token&gt; &lt;pct&gt; — Sell part of a position\n\
+     /dca &lt;token&gt; &lt;sol&gt; — Add to a position\n\
+     /close_all — Sell every open position\n\n\
      <b>🚨 Safety</b>\n\
      /force_stop — <b>EMERGENCY HALT</b>\n\
      /resume_trading — Clear emergency status\n\n\
@@ -6,6 +6,9 @@
 //! - 📈 buy/increase, 📉 sell/decrease
 //! - 💰 balance, 💎 value, 🎯 target, 🛡️ protection
 
+use super::types::PerformanceSummary;
+use crate::swaps::Quote;
+
 /// Escape HTML special characters
 pub fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -302,11 +305,16 @@ pub fn msg_partial_exit(
     pnl_pct: f64,
     received_sol: f64,
     remaining_pct: f64,
+    take_profit_tier: Option<(u32, u32)>,
 ) -> String {
     let emoji = if pnl_sol >= 0.0 { "🟡" } else { "🟠" };
+    let title = match take_profit_tier {
+        Some((tier, total)) => format!("Take Profit {}/{}", tier, total),
+        None => "Partial Exit".to_string(),
+    };
 
     format!(
-        r#"{} <b>Partial Exit</b>
+        r#"{} <b>{}</b>
 
 <b>${}</b> — Sold {:.0}%
 
@@ -314,6 +322,7 @@ pub fn msg_partial_exit(
 📊 P&L — {}
 📦 Remaining — {:.0}%"#,
         emoji,
+        title,
         html_escape(symbol),
         exit_pct,
         format_sol(received_sol),
@@ -430,43 +439,86 @@ pub fn msg_bot_stopped(
     )
 }
 
-/// Format daily summary notification
-pub fn msg_daily_summary(
-    date: &str,
-    total_trades: u32,
-    winning: u32,
-    losing: u32,
-    total_pnl_sol: f64,
-    open_positions: u32,
-) -> String {
-    let win_rate = if total_trades > 0 {
-        (winning as f64 / total_trades as f64) * 100.0
+/// Format daily or weekly summary notification
+pub fn msg_daily_summary(title: &str, summary: &PerformanceSummary) -> String {
+    let win_rate = if summary.closed_trades > 0 {
+        (summary.winning_trades as f64 / summary.closed_trades as f64) * 100.0
     } else {
         0.0
     };
 
-    let emoji = if total_pnl_sol >= 0.0 { "📈" } else { "📉" };
-    let pnl_emoji = if total_pnl_sol >= 0.0 { "🟢" } else { "🔴" };
+    let net_pnl = summary.realized_pnl_sol - summary.fees_sol;
+    let emoji = if summary.realized_pnl_sol >= 0.0 {
+        "📈"
+    } else {
+        "📉"
+    };
+    let pnl_emoji = if summary.realized_pnl_sol >= 0.0 {
+        "🟢"
+    } else {
+        "🔴"
+    };
 
-    format!(
-        r#"{} <b>Daily Summary</b> — {}
+    let mut msg = format!(
+        r#"{} <b>{}</b> — {}
 
 <b>Performance</b>
 Trades — {} ({}🟢 {}🔴)
 Win Rate — {:.0}%
-P&L — <b>{} SOL</b> {}
+Realized — <b>{} SOL</b> {}
+Fees — {} SOL
+Net — {} SOL
 
-📦 Open Positions — {}"#,
+📦 Open Positions — {}
+Unrealized — {} SOL"#,
         emoji,
-        html_escape(date),
-        total_trades,
-        winning,
-        losing,
+        html_escape(title),
+        html_escape(&summary.period),
+        summary.closed_trades,
+        summary.winning_trades,
+        summary.losing_trades,
         win_rate,
-        format_sol(total_pnl_sol),
+        format_sol(summary.realized_pnl_sol),
         pnl_emoji,
-        open_positions,
-    )
+        format_sol(summary.fees_sol),
+        format_sol(net_pnl),
+        summary.open_positions,
+        format_sol(summary.unrealized_pnl_sol),
+    );
+
+    if summary.best_trade.is_some() || summary.worst_trade.is_some() {
+        msg.push_str("\n\n<b>Highlights</b>");
+        if let Some((symbol, pnl_sol, pnl_pct)) = &summary.best_trade {
+            msg.push_str(&format!(
+                "\nBest — ${} {}",
+                html_escape(symbol),
+                format_pnl(*pnl_sol, *pnl_pct)
+            ));
+        }
+        if let Some((symbol, pnl_sol, pnl_pct)) = &summary.worst_trade {
+            msg.push_str(&format!(
+                "\nWorst — ${} {}",
+                html_escape(symbol),
+                format_pnl(*pnl_sol, *pnl_pct)
+            ));
+        }
+    }
+
+    if let Some(loss_limit) = &summary.loss_limit {
+        let status = if loss_limit.is_limited {
+            "⛔ Entries paused"
+        } else {
+            "✅ OK"
+        };
+        msg.push_str(&format!(
+            "\n\n🛡️ Loss Limit — {} / {} SOL ({})",
+            format_sol(loss_limit.cumulative_loss_sol),
+            format_sol(loss_limit.limit_sol),
+            status
+        ));
+    }
+
+    msg
 }
 
 /// Format status message
@@ -643,6 +695,73 @@ Estimated — <b>{} SOL</b>
     )
 }
 
+/// Format quote preview for a manual trade confirmation
+pub fn msg_trade_preview(
+    title: &str,
+    symbol: &str,
+    mint: &str,
+    spend: &str,
+    receive: &str,
+    quote: &Quote,
+) -> String {
+    format!(
+        r#"⚠️ <b>{}</b>
+
+<b>${}</b>
+<code>{}</code>
+
+Spend — {}
+Receive — <b>{}</b>
+Price Impact — {:.2}%
+Slippage — {:.1}%
+Fees — {} SOL
+Route — {} ({})
+
+<i>⏰ Confirm within 30 seconds</i>"#,
+        title,
+        html_escape(symbol),
+        mint,
+        spend,
+        receive,
+        quote.price_impact_pct,
+        quote.slippage_bps as f64 / 100.0,
+        format_sol(quote.fee_lamports as f64 / 1_000_000_000.0),
+        html_escape(&quote.router_name),
+        html_escape(&quote.route_plan),
+    )
+}
+
+/// Format verified result of a manual trade
+pub fn msg_trade_result(
+    action: &str,
+    symbol: &str,
+    mint: &str,
+    size_sol: Option<f64>,
+    price_sol: Option<f64>,
+    signature: &str,
+) -> String {
+    format!(
+        r#"✅ <b>{} Confirmed</b>
+
+<b>${}</b>
+<code>{}</code>
+
+Amount — {}
+Price — {}
+Tx — <code>{}</code>"#,
+        action,
+        html_escape(symbol),
+        format_mint_display(mint),
+        size_sol
+            .map(|s| format!("{} SOL", format_sol(s)))
+            .unwrap_or_else(|| "—".to_string()),
+        price_sol
+            .map(|p| format!("{} SOL", format_price(p)))
+            .unwrap_or_else(|| "—".to_string()),
+        format_mint_display(signature),
+    )
+}
+
 /// Format PIN prompt
 pub fn msg_pin_prompt() -> String {
     "🔐 <b>Authentication Required</b>\n\nPlease enter your PIN:".to_string()
@@ -675,6 +794,7 @@ pub fn msg_locked_out(minutes: u32) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::telegram::types::LossLimitSummary;
 
     #[test]
     fn test_html_escape() {
@@ -702,6 +822,42 @@ mod tests {
         assert_eq!(format_duration(3700), "1h 1m");
         assert_eq!(format_duration(90000), "1d 1h");
     }
+
+    #[test]
+    fn test_msg_partial_exit_take_profit_tier() {
+        let ladder = msg_partial_exit("ABC", "mint", 30.0, 0.1, 0.0, 0.0, 70.0, Some((1, 2)));
+        assert!(ladder.contains("<b>Take Profit 1/2</b>"));
+
+        let plain = msg_partial_exit("ABC", "mint", 30.0, 0.1, 0.0, 0.0, 70.0, None);
+        assert!(plain.contains("<b>Partial Exit</b>"));
+    }
+
+    #[test]
+    fn test_msg_daily_summary_sections() {
+        let empty = msg_daily_summary("Daily Summary", &PerformanceSummary::default());
+        assert!(!empty.contains("Highlights"));
+        assert!(!empty.contains("Loss Limit"));
+
+        let summary = PerformanceSummary {
+            period: "2026-01-01".to_string(),
+            closed_trades: 2,
+            winning_trades: 1,
+            losing_trades: 1,
+            best_trade: Some(("WIN".to_string(), 0.5, 50.0)),
+            worst_trade: Some(("LOSS".to_string(), -0.2, -20.0)),
+            loss_limit: Some(LossLimitSummary {
+                limit_sol: 1.0,
+                cumulative_loss_sol: 0.2,
+                is_limited: false,
+            }),
+            ..Default::default()
+        };
+        let msg = msg_daily_summary("Weekly Summary", &summary);
+        assert!(msg.contains("Win Rate — 50%"));
+        assert!(msg.contains("Best — $WIN"));
+        assert!(msg.contains("Worst — $LOSS"));
+        assert!(msg.contains("Loss Limit"));
+    }
 }
 
 use crate::filtering::types::PassedToken;
@@ -254,6 +254,14 @@ pub fn confirm_token_buy(mint: &str, symbol: &str, amount: f64) -> InlineKeyboar
     ]])
 }
 
+/// Confirmation for a quoted manual trade (/buy, /sell, /dca, /close_all)
+pub fn confirm_trade(pending_id: &str, label: &str) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![vec![
+        btn(label, &format!("trade:ok:{}", pending_id)),
+        btn("❌ Cancel", &format!("trade:no:{}", pending_id)),
+    ]])
+}
+
 // === SETTINGS ===
 
 /// Quick settings menu
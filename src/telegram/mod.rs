@@ -20,6 +20,7 @@
 //! ├── session.rs       # Session & auth management
 //! ├── discovery.rs     # Chat ID discovery
 //! ├── polling.rs       # Update polling
+//! ├── summary.rs       # Scheduled daily/weekly summaries
 //! │
 //! ├── keyboards.rs     # Inline keyboards
 //! ├── formatters.rs    # HTML message formatters
@@ -27,6 +28,7 @@
 //! └── commands/        # Command handlers
 //!     ├── mod.rs       # Command router
 //!     ├── trading.rs   # Trading controls
+//!     ├── manual.rs    # Quoted /buy, /sell, /dca, /close_all
 //!     ├── status.rs    # Status commands
 //!     ├── menu.rs      # Interactive menus
 //!     └── callbacks.rs # Button click handlers
@@ -85,6 +87,7 @@ pub mod pagination;
 pub mod polling;
 pub mod service;
 pub mod session;
+pub mod summary;
 pub mod types;
 
 // ============================================================================
@@ -93,8 +96,8 @@ pub mod types;
 
 // Core types
 pub use types::{
-    BotState, DiscoveredChat, ErrorSeverity, Notification, NotificationType, SessionState,
-    TelegramSession,
+    BotState, DiscoveredChat, ErrorSeverity, LossLimitSummary, Notification, NotificationType,
+    PerformanceSummary, SessionState, TelegramSession,
 };
 
 // Session management
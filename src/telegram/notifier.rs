@@ -85,7 +85,10 @@ impl TelegramNotifier {
             }
         }
 
-        let message = self.format_notification(notification);
+        let mut message = self.format_notification(notification);
+        if crate::global::is_paper_trading() {
+            message = format!("📝 <b>[PAPER]</b>\n{}", message);
+        }
         self.send_message(&message).await
     }
 
@@ -170,6 +173,92 @@ impl TelegramNotifier {
                 )
             }
 
+            NotificationType::HolderAlert {
+                token_symbol,
+                token_mint,
+                alert_type,
+                details,
+            } => {
+                let (emoji, title) = match alert_type.as_str() {
+                    "holder_drop" => ("📉", "Holder Drop"),
+                    "new_holders" => ("📈", "New Holders"),
+                    _ => ("🐋", "Whale Accumulation"),
+                };
+                format!(
+                    "{} <b>{}</b>\n\n\
+                     Token: <code>${}</code>\n\
+                     Mint: <code>{}</code>\n\
+                     {}",
+                    emoji, title, token_symbol, token_mint, details
+                )
+            }
+
+            NotificationType::PriceDivergence {
+                token_symbol,
+                token_mint,
+                divergence_pct,
+                details,
+            } => format!(
+                "⚖️ <b>Pool Price Divergence</b>\n\n\
+                 Token: <code>${}</code>\n\
+                 Mint: <code>{}</code>\n\
+                 Divergence: {:.2}%\n\n\
+                 {}",
+                token_symbol, token_mint, divergence_pct, details
+            ),
+
+            NotificationType::OrderUpdate {
+                token_symbol,
+                token_mint,
+                order_id,
+                order_type,
+                status,
+                details,
+            } => {
+                let (emoji, title) = match status.as_str() {
+                    "filled" => ("✅", "Order Filled"),
+                    "failed" => ("❌", "Order Failed"),
+                    "expired" => ("⌛", "Order Expired"),
+                    _ => ("🚫", "Order Cancelled"),
+                };
+                format!(
+                    "{} <b>{}</b> #{}\n\n\
+                     Token: <code>${}</code>\n\
+                     Mint: <code>{}</code>\n\
+                     Type: {}\n\
+                     {}",
+                    emoji, title, order_id, token_symbol, token_mint, order_type, details
+                )
+            }
+
+            NotificationType::CopyTrade {
+                token_symbol,
+                token_mint,
+                leader,
+                side,
+                status,
+                details,
+            } => {
+                let (emoji, title) = match (status.as_str(), side.as_str()) {
+                    ("executed", "buy") => ("🟢", "Copy Buy"),
+                    ("executed", _) => ("🔴", "Copy Sell"),
+                    _ => ("❌", "Copy Trade Failed"),
+                };
+                format!(
+                    "{} <b>{}</b>\n\n\
+                     Token: <code>${}</code>\n\
+                     Mint: <code>{}</code>\n\
+                     Leader: <code>{}</code>\n\
+                     {}",
+                    emoji,
+                    title,
+                    token_symbol,
+                    token_mint,
+                    Self::truncate_address(leader),
+                    details
+                )
+            }
+
             NotificationType::PositionOpened {
                 token_symbol,
                 token_mint,
@@ -236,6 +325,7 @@ impl TelegramNotifier {
                 exit_percent,
                 pnl_sol,
                 remaining_percent,
+                take_profit_tier,
             } => formatters::msg_partial_exit(
                 token_symbol,
                 token_mint,
@@ -244,6 +334,7 @@ impl TelegramNotifier {
                 0.0, // pnl_pct not provided
                 0.0, // received_sol not provided
                 *remaining_percent,
+                *take_profit_tier,
             ),
 
             NotificationType::DcaExecuted {
@@ -265,21 +356,13 @@ impl TelegramNotifier {
                 formatters::msg_system_error(&severity.to_string(), message)
             }
 
-            NotificationType::DailySummary {
-                date,
-                total_trades,
-                winning_trades,
-                losing_trades,
-                total_pnl_sol,
-                open_positions,
-            } => formatters::msg_daily_summary(
-                date,
-                *total_trades,
-                *winning_trades,
-                *losing_trades,
-                *total_pnl_sol,
-                *open_positions,
-            ),
+            NotificationType::DailySummary { summary } => {
+                formatters::msg_daily_summary("Daily Summary", summary)
+            }
+
+            NotificationType::WeeklySummary { summary } => {
+                formatters::msg_daily_summary("Weekly Summary", summary)
+            }
 
             NotificationType::BotCommand { command, response } => {
                 format!("📟 <b>Command:</b> /{}\n\n{}", command, response)
@@ -436,6 +519,12 @@ fn should_send_notification(notification: &Notification) -> bool {
         NotificationType::TradeAlert { amount_sol, .. } => {
             config.notify_trade_alerts && *amount_sol >= config.trade_alert_min_sol
         }
+        NotificationType::HolderAlert { .. } => config.notify_trade_alerts,
+        // Opt-in via pools.multi_pool_divergence_telegram at the source
+        NotificationType::PriceDivergence { .. } => true,
+        NotificationType::OrderUpdate { .. } => config.notify_order_updates,
+        // Opt-in via copy_trade.notify at the source
+        NotificationType::CopyTrade { .. } => true,
         NotificationType::PositionOpened { .. } => config.notify_position_opened,
         NotificationType::PositionClosed { .. } => config.notify_position_closed,
         NotificationType::PartialExit { .. } => config.notify_partial_exit,
@@ -446,6 +535,7 @@ fn should_send_notification(notification: &Notification) -> bool {
             ErrorSeverity::Info => false, // Don't send info level unless explicitly enabled
         },
         NotificationType::DailySummary { .. } => config.notify_daily_summary,
+        NotificationType::WeeklySummary { .. } => config.notify_weekly_summary,
         NotificationType::BotCommand { .. } => true, // Always send command responses
         NotificationType::BotStarted { .. } => config.notify_on_startup,
         NotificationType::BotStopped { .. } => config.notify_on_shutdown,
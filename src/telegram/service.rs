@@ -169,6 +169,12 @@ impl Service for TelegramService {
                 }
             }
 
+            // Start daily/weekly summary scheduler
+            let summary_handle = tokio::spawn(monitor.instrument(
+                crate::telegram::summary::run_summary_scheduler(shutdown.clone()),
+            ));
+            handles.push(summary_handle);
+
             // Send startup notification
             let startup_notification = Notification::bot_started(
                 crate::version::VERSION.to_string(),
@@ -0,0 +1,212 @@
+//! Scheduled performance summaries
+//!
+//! Builds daily and weekly summaries from the positions database and queues them
+//! at the configured local hour. The last sent date of each summary is stored in
+//! the positions metadata table so restarts never send the same summary twice.
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Timelike, Utc};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+use crate::config::with_config;
+use crate::logger::{self, LogTag};
+use crate::positions;
+use crate::telegram::notifier::queue_notification;
+use crate::telegram::types::{LossLimitSummary, Notification, PerformanceSummary};
+use crate::utils::lamports_to_sol;
+
+/// How often the scheduler checks whether a summary is due
+const CHECK_INTERVAL_SECS: u64 = 60;
+
+/// Positions metadata keys holding the last sent local date
+const LAST_DAILY_SUMMARY_KEY: &str = "telegram_last_daily_summary";
+const LAST_WEEKLY_SUMMARY_KEY: &str = "telegram_last_weekly_summary";
+
+/// Run the summary scheduler until shutdown
+pub async fn run_summary_scheduler(shutdown: Arc<Notify>) {
+    logger::info(LogTag::Telegram, "Summary scheduler started");
+
+    loop {
+        tokio::select! {
+            _ = shutdown.notified() => {
+                logger::info(LogTag::Telegram, "Summary scheduler shutting down");
+                break;
+            }
+            _ = tokio::time::sleep(std::time::Duration::from_secs(CHECK_INTERVAL_SECS)) => {
+                if let Err(e) = send_due_summaries().await {
+                    logger::warning(
+                        LogTag::Telegram,
+                        &format!("Failed to send scheduled summary: {}", e),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Queue the daily and weekly summaries when their scheduled time has passed
+async fn send_due_summaries() -> Result<(), String> {
+    let (notify_daily, notify_weekly, hour, offset_minutes, weekday) = with_config(|cfg| {
+        (
+            cfg.telegram.notify_daily_summary,
+            cfg.telegram.notify_weekly_summary,
+            cfg.telegram.summary_hour.min(23),
+            cfg.telegram.summary_utc_offset_minutes,
+            cfg.telegram.weekly_summary_weekday.min(6),
+        )
+    });
+
+    if !notify_daily && !notify_weekly {
+        return Ok(());
+    }
+
+    let offset = FixedOffset::east_opt(offset_minutes * 60)
+        .ok_or_else(|| format!("Invalid summary UTC offset: {} minutes", offset_minutes))?;
+    let now_local = Utc::now().with_timezone(&offset);
+    if now_local.hour() < hour {
+        return Ok(());
+    }
+
+    let today = now_local.date_naive();
+    let period_end = scheduled_time(today, hour, &offset)?;
+
+    if notify_daily && !already_sent(LAST_DAILY_SUMMARY_KEY, today).await {
+        let summary = build_summary(
+            period_end - Duration::days(1),
+            period_end,
+            today.format("%Y-%m-%d").to_string(),
+        )
+        .await?;
+        queue_notification(Notification::daily_summary(summary));
+        mark_sent(LAST_DAILY_SUMMARY_KEY, today).await?;
+        logger::info(LogTag::Telegram, "Daily summary queued");
+    }
+
+    if notify_weekly
+        && today.weekday().num_days_from_monday() == weekday
+        && !already_sent(LAST_WEEKLY_SUMMARY_KEY, today).await
+    {
+        let week_start = today - Duration::days(7);
+        let summary = build_summary(
+            period_end - Duration::days(7),
+            period_end,
+            format!(
+                "{} → {}",
+                week_start.format("%Y-%m-%d"),
+                today.format("%Y-%m-%d")
+            ),
+        )
+        .await?;
+        queue_notification(Notification::weekly_summary(summary));
+        mark_sent(LAST_WEEKLY_SUMMARY_KEY, today).await?;
+        logger::info(LogTag::Telegram, "Weekly summary queued");
+    }
+
+    Ok(())
+}
+
+/// Scheduled send time for a local date, in UTC
+fn scheduled_time(
+    date: NaiveDate,
+    hour: u32,
+    offset: &FixedOffset,
+) -> Result<DateTime<Utc>, String> {
+    let local = date
+        .and_hms_opt(hour, 0, 0)
+        .ok_or_else(|| format!("Invalid summary hour: {}", hour))?;
+    offset
+        .from_local_datetime(&local)
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| "Ambiguous summary time".to_string())
+}
+
+async fn already_sent(key: &str, date: NaiveDate) -> bool {
+    matches!(
+        positions::db::get_metadata(key).await,
+        Ok(Some(value)) if value == date.to_string()
+    )
+}
+
+async fn mark_sent(key: &str, date: NaiveDate) -> Result<(), String> {
+    positions::db::set_metadata(key, &date.to_string()).await
+}
+
+/// Build a performance summary for positions closed in `[start, end)`
+pub async fn build_summary(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    period: String,
+) -> Result<PerformanceSummary, String> {
+    let closed = positions::db::get_closed_positions().await?;
+    let open = positions::get_open_positions().await;
+
+    let mut summary = summarize_positions(&closed, &open, start, end);
+    summary.period = period;
+
+    if crate::trader::config::is_loss_limit_enabled() {
+        let status = crate::trader::safety::get_loss_limit_status();
+        summary.loss_limit = Some(LossLimitSummary {
+            limit_sol: crate::trader::config::get_loss_limit_sol(),
+            cumulative_loss_sol: status.cumulative_loss_sol,
+            is_limited: status.is_limited,
+        });
+    }
+
+    Ok(summary)
+}
+
+/// Aggregate closed positions in `[start, end)` and the unrealized P&L of open positions
+fn summarize_positions(
+    closed: &[positions::Position],
+    open: &[positions::Position],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> PerformanceSummary {
+    let mut summary = PerformanceSummary {
+        open_positions: open.len() as u32,
+        unrealized_pnl_sol: open.iter().filter_map(|p| p.unrealized_pnl).sum(),
+        ..Default::default()
+    };
+
+    for position in closed {
+        let in_period = position
+            .exit_time
+            .map(|t| t >= start && t < end)
+            .unwrap_or(false);
+        if !in_period || !position.transaction_exit_verified {
+            continue;
+        }
+
+        let pnl = position.pnl.unwrap_or(0.0);
+        let pnl_pct = position.pnl_percent.unwrap_or(0.0);
+
+        summary.closed_trades += 1;
+        if pnl > 0.0 {
+            summary.winning_trades += 1;
+        } else if pnl < 0.0 {
+            summary.losing_trades += 1;
+        }
+        summary.realized_pnl_sol += pnl;
+        summary.fees_sol += lamports_to_sol(
+            position.entry_fee_lamports.unwrap_or(0) + position.exit_fee_lamports.unwrap_or(0),
+        );
+
+        if summary
+            .best_trade
+            .as_ref()
+            .map_or(true, |(_, best, _)| pnl > *best)
+        {
+            summary.best_trade = Some((position.symbol.clone(), pnl, pnl_pct));
+        }
+        if summary
+            .worst_trade
+            .as_ref()
+            .map_or(true, |(_, worst, _)| pnl < *worst)
+        {
+            summary.worst_trade = Some((position.symbol.clone(), pnl, pnl_pct));
+        }
+    }
+
+    summary
+}
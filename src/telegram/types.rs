@@ -22,6 +22,42 @@ pub enum NotificationType {
         wallet: String, // external wallet that traded
     },
 
+    /// Alert from Holder Watch (holder drop, new holders, whale accumulation)
+    HolderAlert {
+        token_symbol: String,
+        token_mint: String,
+        alert_type: String, // "holder_drop", "new_holders" or "whale_accumulation"
+        details: String,
+    },
+
+    /// Alert when pools of the same token price it differently
+    PriceDivergence {
+        token_symbol: String,
+        token_mint: String,
+        divergence_pct: f64,
+        details: String,
+    },
+
+    /// Pending order filled, failed, expired or was auto-cancelled
+    OrderUpdate {
+        token_symbol: String,
+        token_mint: String,
+        order_id: i64,
+        order_type: String,
+        status: String, // "filled", "failed", "expired" or "cancelled"
+        details: String,
+    },
+
+    /// Copy Trader mirrored (or failed to mirror) a followed wallet's swap
+    CopyTrade {
+        token_symbol: String,
+        token_mint: String,
+        leader: String,
+        side: String,   // "buy" or "sell"
+        status: String, // "executed" or "failed"
+        details: String,
+    },
+
     /// Notification when a new position is opened
     PositionOpened {
         token_symbol: String,
@@ -53,6 +89,8 @@ pub enum NotificationType {
         exit_percent: f64,
         pnl_sol: f64,
         remaining_percent: f64,
+        /// Take-profit ladder tier (tier, total tiers) when the exit came from the ladder
+        take_profit_tier: Option<(u32, u32)>,
     },
 
     /// Notification when DCA is executed
@@ -71,14 +109,10 @@ pub enum NotificationType {
     },
 
     /// Daily summary of trading activity
-    DailySummary {
-        date: String,
-        total_trades: u32,
-        winning_trades: u32,
-        losing_trades: u32,
-        total_pnl_sol: f64,
-        open_positions: u32,
-    },
+    DailySummary { summary: PerformanceSummary },
+
+    /// Weekly rollup of trading activity
+    WeeklySummary { summary: PerformanceSummary },
 
     /// Response to a bot command
     BotCommand { command: String, response: String },
@@ -96,6 +130,38 @@ pub enum NotificationType {
     },
 }
 
+/// Aggregated trading performance for a summary period
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PerformanceSummary {
+    /// Human readable period (e.g. "2026-10-16" or "2026-10-09 → 2026-10-16")
+    pub period: String,
+    /// Positions closed during the period
+    pub closed_trades: u32,
+    pub winning_trades: u32,
+    pub losing_trades: u32,
+    /// Realized P&L of positions closed during the period (SOL)
+    pub realized_pnl_sol: f64,
+    /// Unrealized P&L of currently open positions (SOL)
+    pub unrealized_pnl_sol: f64,
+    /// Entry and exit transaction fees of closed positions (SOL)
+    pub fees_sol: f64,
+    pub open_positions: u32,
+    /// Best closed trade of the period (symbol, P&L SOL, P&L %)
+    pub best_trade: Option<(String, f64, f64)>,
+    /// Worst closed trade of the period (symbol, P&L SOL, P&L %)
+    pub worst_trade: Option<(String, f64, f64)>,
+    /// Loss limit state when the limit is enabled
+    pub loss_limit: Option<LossLimitSummary>,
+}
+
+/// Loss limit status included in summaries
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LossLimitSummary {
+    pub limit_sol: f64,
+    pub cumulative_loss_sol: f64,
+    pub is_limited: bool,
+}
+
 /// Severity levels for system errors
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ErrorSeverity {
@@ -149,6 +215,74 @@ impl Notification {
         })
     }
 
+    /// Create a holder watch alert notification
+    pub fn holder_alert(
+        token_symbol: String,
+        token_mint: String,
+        alert_type: &str,
+        details: String,
+    ) -> Self {
+        Self::new(NotificationType::HolderAlert {
+            token_symbol,
+            token_mint,
+            alert_type: alert_type.to_string(),
+            details,
+        })
+    }
+
+    /// Create a multi-pool price divergence alert notification
+    pub fn price_divergence(
+        token_symbol: String,
+        token_mint: String,
+        divergence_pct: f64,
+        details: String,
+    ) -> Self {
+        Self::new(NotificationType::PriceDivergence {
+            token_symbol,
+            token_mint,
+            divergence_pct,
+            details,
+        })
+    }
+
+    /// Create a pending order update notification
+    pub fn order_update(
+        token_symbol: String,
+        token_mint: String,
+        order_id: i64,
+        order_type: &str,
+        status: &str,
+        details: String,
+    ) -> Self {
+        Self::new(NotificationType::OrderUpdate {
+            token_symbol,
+            token_mint,
+            order_id,
+            order_type: order_type.to_string(),
+            status: status.to_string(),
+            details,
+        })
+    }
+
+    /// Create a copy trade notification
+    pub fn copy_trade(
+        token_symbol: String,
+        token_mint: String,
+        leader: String,
+        side: &str,
+        status: &str,
+        details: String,
+    ) -> Self {
+        Self::new(NotificationType::CopyTrade {
+            token_symbol,
+            token_mint,
+            leader,
+            side: side.to_string(),
+            status: status.to_string(),
+            details,
+        })
+    }
+
     /// Create a position opened notification
     pub fn position_opened(
         token_symbol: String,
@@ -246,6 +380,7 @@ impl Notification {
         exit_percent: f64,
         pnl_sol: f64,
         remaining_percent: f64,
+        take_profit_tier: Option<(u32, u32)>,
     ) -> Self {
         Self::new(NotificationType::PartialExit {
             token_symbol,
@@ -253,6 +388,7 @@ impl Notification {
             exit_percent,
             pnl_sol,
             remaining_percent,
+            take_profit_tier,
         })
     }
 
@@ -279,22 +415,13 @@ impl Notification {
     }
 
     /// Create a daily summary notification
-    pub fn daily_summary(
-        date: String,
-        total_trades: u32,
-        winning_trades: u32,
-        losing_trades: u32,
-        total_pnl_sol: f64,
-        open_positions: u32,
-    ) -> Self {
-        Self::new(NotificationType::DailySummary {
-            date,
-            total_trades,
-            winning_trades,
-            losing_trades,
-            total_pnl_sol,
-            open_positions,
-        })
+    pub fn daily_summary(summary: PerformanceSummary) -> Self {
+        Self::new(NotificationType::DailySummary { summary })
+    }
+
+    /// Create a weekly summary notification
+    pub fn weekly_summary(summary: PerformanceSummary) -> Self {
+        Self::new(NotificationType::WeeklySummary { summary })
     }
 
     /// Create a bot command response notification
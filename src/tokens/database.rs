@@ -10,9 +10,9 @@ use crate::logger::{self, LogTag};
 use crate::tokens::pools;
 use crate::tokens::store;
 use crate::tokens::types::{
-    DataSource, DexScreenerData, GeckoTerminalData, Priority, RugcheckData, SecurityRisk,
-    SocialLink, Token, TokenError, TokenHolder, TokenMetadata, TokenPoolInfo, TokenPoolSources,
-    TokenPoolsSnapshot, TokenResult, UpdateTrackingInfo, WebsiteLink,
+    DataSource, DexScreenerData, GeckoTerminalData, OnChainSecurityData, Priority, RugcheckData,
+    SecurityRisk, SocialLink, Token, TokenError, TokenHolder, TokenMetadata, TokenPoolInfo,
+    TokenPoolSources, TokenPoolsSnapshot, TokenResult, UpdateTrackingInfo, WebsiteLink,
 };
 
 // Global database instance for easy access
@@ -1404,6 +1404,182 @@ impl TokenDatabase {
         }
     }
 
+    // ========================================================================
+    // ON-CHAIN SECURITY OPERATIONS
+    // ========================================================================
+
+    /// Store native on-chain security analysis (first_fetched_at preserved on updates)
+    pub fn upsert_onchain_security(
+        &self,
+        mint: &str,
+        data: &OnChainSecurityData,
+    ) -> TokenResult<()> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| TokenError::Database(format!("Lock failed: {}", e)))?;
+
+        let extensions_json = serde_json::to_string(&data.extensions)
+            .map_err(|e| TokenError::Database(format!("Failed to serialize extensions: {}", e)))?;
+        let now_ts = data.security_data_last_fetched_at.timestamp();
+
+        conn.execute(
+            "INSERT INTO security_onchain (
+                mint,
+                token_program,
+                decimals,
+                total_supply,
+                mint_authority,
+                freeze_authority,
+                extensions,
+                transfer_fee_bps,
+                transfer_fee_max_amount,
+                transfer_fee_authority,
+                transfer_hook_program,
+                permanent_delegate,
+                mint_close_authority,
+                non_transferable,
+                default_account_frozen,
+                canonical_pool,
+                pool_program,
+                lp_burned_pct,
+                lp_locked_pct,
+                top_holder_pct,
+                top_10_holders_pct,
+                security_data_last_fetched_at,
+                security_data_first_fetched_at
+             ) VALUES (
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12,
+                ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?22
+             )
+             ON CONFLICT(mint) DO UPDATE SET
+                token_program = excluded.token_program,
+                decimals = excluded.decimals,
+                total_supply = excluded.total_supply,
+                mint_authority = excluded.mint_authority,
+                freeze_authority = excluded.freeze_authority,
+                extensions = excluded.extensions,
+                transfer_fee_bps = excluded.transfer_fee_bps,
+                transfer_fee_max_amount = excluded.transfer_fee_max_amount,
+                transfer_fee_authority = excluded.transfer_fee_authority,
+                transfer_hook_program = excluded.transfer_hook_program,
+                permanent_delegate = excluded.permanent_delegate,
+                mint_close_authority = excluded.mint_close_authority,
+                non_transferable = excluded.non_transferable,
+                default_account_frozen = excluded.default_account_frozen,
+                canonical_pool = excluded.canonical_pool,
+                pool_program = excluded.pool_program,
+                lp_burned_pct = excluded.lp_burned_pct,
+                lp_locked_pct = excluded.lp_locked_pct,
+                top_holder_pct = excluded.top_holder_pct,
+                top_10_holders_pct = excluded.top_10_holders_pct,
+                security_data_last_fetched_at = excluded.security_data_last_fetched_at",
+            params![
+                mint,
+                &data.token_program,
+                data.decimals,
+                &data.total_supply,
+                &data.mint_authority,
+                &data.freeze_authority,
+                extensions_json,
+                data.transfer_fee_bps,
+                data.transfer_fee_max_amount.map(|v| v.to_string()),
+                &data.transfer_fee_authority,
+                &data.transfer_hook_program,
+                &data.permanent_delegate,
+                &data.mint_close_authority,
+                data.non_transferable as i64,
+                data.default_account_frozen as i64,
+                &data.canonical_pool,
+                &data.pool_program,
+                data.lp_burned_pct,
+                data.lp_locked_pct,
+                data.top_holder_pct,
+                data.top_10_holders_pct,
+                now_ts,
+            ],
+        )
+        .map_err(|e| TokenError::Database(format!("Failed to upsert on-chain security: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Get native on-chain security analysis for a token
+    pub fn get_onchain_security(&self, mint: &str) -> TokenResult<Option<OnChainSecurityData>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| TokenError::Database(format!("Lock failed: {}", e)))?;
+
+        let sql = format!("{} WHERE mint = ?1", ONCHAIN_SECURITY_SELECT);
+        let result = conn.query_row(&sql, params![mint], map_onchain_security_row);
+
+        match result {
+            Ok((_, data)) => Ok(Some(data)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(TokenError::Database(format!("Query failed: {}", e))),
+        }
+    }
+
+    /// Load every stored on-chain analysis keyed by mint (used once per filtering pass)
+    pub fn get_all_onchain_security(&self) -> TokenResult<HashMap<String, OnChainSecurityData>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| TokenError::Database(format!("Lock failed: {}", e)))?;
+
+        let mut stmt = conn
+            .prepare(ONCHAIN_SECURITY_SELECT)
+            .map_err(|e| TokenError::Database(format!("Failed to prepare: {}", e)))?;
+
+        let rows = stmt
+            .query_map([], map_onchain_security_row)
+            .map_err(|e| TokenError::Database(format!("Query failed: {}", e)))?;
+
+        rows.collect::<Result<HashMap<_, _>, _>>()
+            .map_err(|e| TokenError::Database(format!("Failed to collect: {}", e)))
+    }
+
+    /// Tokens with market data whose on-chain analysis is missing or older than `stale_before_ts`
+    ///
+    /// Missing rows come first (newest discoveries first), then the stalest rows.
+    pub fn get_tokens_for_onchain_security(
+        &self,
+        limit: usize,
+        stale_before_ts: i64,
+    ) -> TokenResult<Vec<String>> {
+        let conn = self
+            .conn
+            .lock()
+            .map_err(|e| TokenError::Database(format!("Lock failed: {}", e)))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT t.mint FROM tokens t
+             LEFT JOIN security_onchain so ON t.mint = so.mint
+             LEFT JOIN blacklist b ON t.mint = b.mint
+             LEFT JOIN market_dexscreener md ON t.mint = md.mint
+             LEFT JOIN market_geckoterminal mg ON t.mint = mg.mint
+             WHERE b.mint IS NULL
+             AND (md.mint IS NOT NULL OR mg.mint IS NOT NULL)
+             AND (so.mint IS NULL OR so.security_data_last_fetched_at < ?1)
+             ORDER BY
+                 CASE WHEN so.mint IS NULL THEN 0 ELSE 1 END,
+                 COALESCE(so.security_data_last_fetched_at, 0) ASC,
+                 t.first_discovered_at DESC
+             LIMIT ?2",
+            )
+            .map_err(|e| TokenError::Database(format!("Failed to prepare: {}", e)))?;
+
+        let mints = stmt
+            .query_map(params![stale_before_ts, limit], |row| row.get(0))
+            .map_err(|e| TokenError::Database(format!("Query failed: {}", e)))?;
+
+        mints
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| TokenError::Database(format!("Failed to collect: {}", e)))
+    }
+
     // ========================================================================
     // UPDATE TRACKING OPERATIONS
     // ========================================================================
@@ -4344,6 +4520,25 @@ pub async fn get_all_tokens_for_filtering_async() -> TokenResult<Vec<Token>> {
     .map_err(|e| TokenError::Database(format!("Join error: {}", e)))?
 }
 
+/// Async: load all on-chain security analyses keyed by mint
+pub async fn get_all_onchain_security_async() -> TokenResult<HashMap<String, OnChainSecurityData>> {
+    let db = get_global_database()
+        .ok_or_else(|| TokenError::Database("Global database not initialized".to_string()))?;
+    tokio::task::spawn_blocking(move || db.get_all_onchain_security())
+        .await
+        .map_err(|e| TokenError::Database(format!("Join error: {}", e)))?
+}
+
+/// Async: get on-chain security analysis for a token
+pub async fn get_onchain_security_async(mint: &str) -> TokenResult<Option<OnChainSecurityData>> {
+    let db = get_global_database()
+        .ok_or_else(|| TokenError::Database("Global database not initialized".to_string()))?;
+    let mint_owned = mint.to_string();
+    tokio::task::spawn_blocking(move || db.get_onchain_security(&mint_owned))
+        .await
+        .map_err(|e| TokenError::Database(format!("Join error: {}", e)))?
+}
+
 /// Async: count tokens with no market
 pub async fn count_tokens_no_market_async() -> TokenResult<usize> {
     let db = get_global_database()
@@ -4654,6 +4849,70 @@ fn map_tracking_row(row: &rusqlite::Row) -> rusqlite::Result<UpdateTrackingInfo>
     })
 }
 
+const ONCHAIN_SECURITY_SELECT: &str = "SELECT
+        mint,
+        token_program,
+        decimals,
+        total_supply,
+        mint_authority,
+        freeze_authority,
+        extensions,
+        transfer_fee_bps,
+        transfer_fee_max_amount,
+        transfer_fee_authority,
+        transfer_hook_program,
+        permanent_delegate,
+        mint_close_authority,
+        non_transferable,
+        default_account_frozen,
+        canonical_pool,
+        pool_program,
+        lp_burned_pct,
+        lp_locked_pct,
+        top_holder_pct,
+        top_10_holders_pct,
+        security_data_last_fetched_at
+     FROM security_onchain";
+
+fn map_onchain_security_row(
+    row: &rusqlite::Row,
+) -> rusqlite::Result<(String, OnChainSecurityData)> {
+    let mint: String = row.get(0)?;
+    let extensions_json: Option<String> = row.get(6)?;
+    let extensions = extensions_json
+        .and_then(|j| serde_json::from_str(&j).ok())
+        .unwrap_or_default();
+    let fee_max: Option<String> = row.get(8)?;
+    let fetched_ts: i64 = row.get(21)?;
+
+    let data = OnChainSecurityData {
+        token_program: row.get(1)?,
+        decimals: row.get(2)?,
+        total_supply: row.get(3)?,
+        mint_authority: row.get(4)?,
+        freeze_authority: row.get(5)?,
+        extensions,
+        transfer_fee_bps: row.get(7)?,
+        transfer_fee_max_amount: fee_max.and_then(|v| v.parse().ok()),
+        transfer_fee_authority: row.get(9)?,
+        transfer_hook_program: row.get(10)?,
+        permanent_delegate: row.get(11)?,
+        mint_close_authority: row.get(12)?,
+        non_transferable: row.get::<_, i64>(13)? != 0,
+        default_account_frozen: row.get::<_, i64>(14)? != 0,
+        canonical_pool: row.get(15)?,
+        pool_program: row.get(16)?,
+        lp_burned_pct: row.get(17)?,
+        lp_locked_pct: row.get(18)?,
+        top_holder_pct: row.get(19)?,
+        top_10_holders_pct: row.get(20)?,
+        security_data_last_fetched_at: DateTime::from_timestamp(fetched_ts, 0)
+            .unwrap_or_else(Utc::now),
+    };
+
+    Ok((mint, data))
+}
+
 fn ts_to_datetime(ts: Option<i64>) -> Option<DateTime<Utc>> {
     ts.and_then(|value| DateTime::from_timestamp(value, 0))
 }
@@ -44,11 +44,13 @@ pub use database::{
     clear_rejection_status_async,
     count_tokens_async,
     count_tokens_no_market_async,
+    get_all_onchain_security_async,
     get_all_tokens_for_filtering_async,
     get_all_tokens_optional_market_async,
     get_full_token_async,
     get_full_token_for_source_async,
     get_global_database,
+    get_onchain_security_async,
     get_recent_rejections_async,
     get_rejected_tokens_async,
     get_rejection_stats_aggregated_async,
@@ -80,9 +82,10 @@ pub use security::rugcheck;
 
 // Domain types from types.rs
 pub use types::{
-    ApiError, DataSource, DexScreenerData, GeckoTerminalData, MarketDataBundle, RugcheckData,
-    SecurityBundle, SecurityLevel, SecurityRisk, SecurityScore, SocialLink, Token, TokenError,
-    TokenHolder, TokenMetadata, TokenResult, UpdateTrackingInfo, WebsiteLink,
+    ApiError, DataSource, DexScreenerData, GeckoTerminalData, MarketDataBundle,
+    OnChainSecurityData, RugcheckData, SecurityBundle, SecurityLevel, SecurityRisk, SecurityScore,
+    SocialLink, Token, TokenError, TokenHolder, TokenMetadata, TokenResult, UpdateTrackingInfo,
+    WebsiteLink,
 };
 
 // API parsing types from api modules (now in crate::apis)
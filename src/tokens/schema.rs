@@ -1,15 +1,24 @@
 /// Database schema for tokens system
-/// Clean slate implementation (no migrations/ALTER fallbacks)
+/// Versioned through `crate::migrations`; schema changes go into a new entry of `TOKENS_MIGRATIONS`
 ///
 /// TIMESTAMP NAMING CONVENTION: {what}_{when}_{action}_at
 /// - {what}: Specific data type (market_data, security_data, metadata, pool_price, etc.)
 /// - {when}: last / first / blockchain
 /// - {action}: fetched / calculated / updated / created / discovered
 /// - _at: Suffix for all timestamps (consistent)
+use crate::migrations::{self, add_column_if_missing, Migration, MigrationSet};
 use rusqlite::Connection;
 use std::time::Duration;
 
-pub const SCHEMA_VERSION: i32 = 2;
+/// Ordered schema migrations of tokens.db
+pub const TOKENS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "tokens",
+    migrations: &[Migration::apply(
+        1,
+        "Baseline schema (tables, late-added columns, indexes)",
+        baseline_schema,
+    )],
+};
 
 /// All CREATE TABLE statements
 pub const CREATE_TABLES: &[&str] = &[
@@ -147,6 +156,35 @@ pub const CREATE_TABLES: &[&str] = &[
         FOREIGN KEY (mint) REFERENCES tokens(mint) ON DELETE RESTRICT
     )
     "#,
+    // Native on-chain security analysis (per token)
+    r#"
+    CREATE TABLE IF NOT EXISTS security_onchain (
+        mint TEXT PRIMARY KEY,
+        token_program TEXT NOT NULL,
+        decimals INTEGER NOT NULL,
+        total_supply TEXT NOT NULL,
+        mint_authority TEXT,
+        freeze_authority TEXT,
+        extensions TEXT,
+        transfer_fee_bps INTEGER,
+        transfer_fee_max_amount TEXT,
+        transfer_fee_authority TEXT,
+        transfer_hook_program TEXT,
+        permanent_delegate TEXT,
+        mint_close_authority TEXT,
+        non_transferable INTEGER NOT NULL DEFAULT 0,
+        default_account_frozen INTEGER NOT NULL DEFAULT 0,
+        canonical_pool TEXT,
+        pool_program TEXT,
+        lp_burned_pct REAL,
+        lp_locked_pct REAL,
+        top_holder_pct REAL,
+        top_10_holders_pct REAL,
+        security_data_last_fetched_at INTEGER NOT NULL,
+        security_data_first_fetched_at INTEGER NOT NULL,
+        FOREIGN KEY (mint) REFERENCES tokens(mint) ON DELETE CASCADE
+    )
+    "#,
     // Blacklist
     r#"
     CREATE TABLE IF NOT EXISTS blacklist (
@@ -254,6 +292,7 @@ pub const CREATE_INDEXES: &[&str] = &[
     "CREATE INDEX IF NOT EXISTS idx_security_rug_last_fetch ON security_rugcheck(security_data_last_fetched_at DESC)",
     "CREATE INDEX IF NOT EXISTS idx_security_rug_first_fetch ON security_rugcheck(security_data_first_fetched_at DESC)",
     "CREATE INDEX IF NOT EXISTS idx_security_rug_score ON security_rugcheck(score DESC)",
+    "CREATE INDEX IF NOT EXISTS idx_security_onchain_last_fetch ON security_onchain(security_data_last_fetched_at DESC)",
 
     // Blacklist indexes
     "CREATE INDEX IF NOT EXISTS idx_blacklist_added ON blacklist(added_at DESC)",
@@ -286,19 +325,22 @@ pub const CREATE_INDEXES: &[&str] = &[
     "CREATE INDEX IF NOT EXISTS idx_tracking_active_priority ON update_tracking(priority DESC, market_data_last_updated_at ASC) WHERE last_rejection_at IS NULL",
 ];
 
-/// ALTER TABLE statements for schema migrations (existing databases)
-pub const ALTER_STATEMENTS: &[&str] = &[
-    // Add score_normalised column to security_rugcheck (0-100, HIGHER = MORE RISKY)
-    "ALTER TABLE security_rugcheck ADD COLUMN score_normalised INTEGER",
-    // Add rejection tracking columns to update_tracking
-    "ALTER TABLE update_tracking ADD COLUMN last_rejection_reason TEXT",
-    "ALTER TABLE update_tracking ADD COLUMN last_rejection_source TEXT",
-    "ALTER TABLE update_tracking ADD COLUMN last_rejection_at INTEGER",
-    // Add mutable metadata tracking to security_rugcheck
-    "ALTER TABLE security_rugcheck ADD COLUMN update_authority TEXT",
-    "ALTER TABLE security_rugcheck ADD COLUMN is_mutable INTEGER",
-    // Add permanent failure tracking for market data (similar to security_error_type)
-    "ALTER TABLE update_tracking ADD COLUMN market_error_type TEXT",
+/// Columns added after the first release (table, column, definition)
+///
+/// Databases from before versioning may already have some of them, so the
+/// baseline migration only adds the missing ones.
+pub const BASELINE_COLUMNS: &[(&str, &str, &str)] = &[
+    // 0-100, HIGHER = MORE RISKY
+    ("security_rugcheck", "score_normalised", "INTEGER"),
+    // Rejection tracking
+    ("update_tracking", "last_rejection_reason", "TEXT"),
+    ("update_tracking", "last_rejection_source", "TEXT"),
+    ("update_tracking", "last_rejection_at", "INTEGER"),
+    // Mutable metadata tracking
+    ("security_rugcheck", "update_authority", "TEXT"),
+    ("security_rugcheck", "is_mutable", "INTEGER"),
+    // Permanent failure tracking for market data (similar to security_error_type)
+    ("update_tracking", "market_error_type", "TEXT"),
 ];
 
 /// Performance PRAGMAs
@@ -328,19 +370,22 @@ pub fn initialize_schema(conn: &Connection) -> Result<(), String> {
     conn.busy_timeout(Duration::from_millis(30000))
         .map_err(|e| format!("Failed to set busy_timeout: {}", e))?;
 
-    // Create tables
+    migrations::run_migrations(conn, &TOKENS_MIGRATIONS)?;
+    Ok(())
+}
+
+/// Migration 1: the schema as it stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
     for statement in CREATE_TABLES {
         conn.execute(statement, [])
             .map_err(|e| format!("Failed to create table: {}", e))?;
     }
 
-    // Apply ALTER statements BEFORE indexes (for existing databases - columns must exist before indexes)
-    // Ignore errors if column already exists
-    for statement in ALTER_STATEMENTS {
-        let _ = conn.execute(statement, []);
+    // Columns before indexes - some indexes cover late-added columns
+    for (table, column, definition) in BASELINE_COLUMNS {
+        add_column_if_missing(conn, table, column, definition)?;
     }
 
-    // Create indexes (after ALTERs so new columns exist)
     for statement in CREATE_INDEXES {
         conn.execute(statement, [])
             .map_err(|e| format!("Failed to create index: {}", e))?;
@@ -350,10 +395,8 @@ pub fn initialize_schema(conn: &Connection) -> Result<(), String> {
 }
 
 /// Get current schema version from database
-pub fn get_schema_version(conn: &Connection) -> Result<i32, String> {
-    // Try to get version from a metadata table (if we add one in future)
-    // For now, just return current version
-    Ok(SCHEMA_VERSION)
+pub fn get_schema_version(conn: &Connection) -> Result<u32, String> {
+    migrations::read_user_version(conn)
 }
 
 /// Check if database is initialized
@@ -2,7 +2,9 @@
 ///
 /// Each module handles one security analysis source:
 /// - rugcheck: Rugcheck API (comprehensive security analysis)
-/// - onchain: Future on-chain verification
+/// - onchain: Native analysis from RPC (mint, Token-2022 extensions, LP, holders)
+pub mod onchain;
 pub mod rugcheck;
 
+pub use onchain::fetch_onchain_security;
 pub use rugcheck::fetch_rugcheck_data;
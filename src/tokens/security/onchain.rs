@@ -0,0 +1,439 @@
+/// Native on-chain security analysis (no third-party API)
+///
+/// Reads everything straight from RPC so security filtering keeps working when
+/// Rugcheck is unavailable:
+/// - Mint account: mint/freeze authority, supply, decimals
+/// - Token-2022 extensions: transfer fee, transfer hook, permanent delegate,
+///   non-transferable, default frozen state, close authority
+/// - Canonical pool LP: share of LP burned or held by known locker programs
+/// - Holder concentration via getTokenLargestAccounts (program-owned accounts
+///   such as pool vaults and bonding curves are excluded)
+///
+/// Results are persisted in the `security_onchain` table.
+use crate::logger::{self, LogTag};
+use crate::pools::decoders::pumpfun_amm;
+use crate::pools::types::ProgramKind;
+use crate::rpc::{get_rpc_client, RpcClientMethods};
+use crate::tokens::database::TokenDatabase;
+use crate::tokens::types::{OnChainSecurityData, TokenError, TokenResult};
+use chrono::Utc;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::extension::default_account_state::DefaultAccountState;
+use spl_token_2022::extension::mint_close_authority::MintCloseAuthority;
+use spl_token_2022::extension::non_transferable::NonTransferable;
+use spl_token_2022::extension::permanent_delegate::PermanentDelegate;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::transfer_hook::TransferHook;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::{AccountState, Mint};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// Address commonly used to "burn" LP tokens by sending them to an unspendable owner
+const INCINERATOR: &str = "1nc1nerator11111111111111111111111111111111";
+
+/// Programs that custody LP tokens under a time lock
+const LP_LOCKER_PROGRAMS: &[&str] = &[
+    "LockrWmn6K5twhz3y9w1dQERbmgSaRkfnTeTKbpofwE", // Raydium LP locker (Burn & Earn)
+    "strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m", // Streamflow
+    "LocpQgucEQHbqNABEYvBvwoxCPsSbG91A1QaQhQQqjn", // Jupiter Lock
+];
+
+/// Owner and balance of a token account (same prefix layout in both token programs)
+struct TokenAccountInfo {
+    owner: Pubkey,
+    amount: u64,
+}
+
+fn parse_token_account(data: &[u8]) -> Option<TokenAccountInfo> {
+    if data.len() < 72 {
+        return None;
+    }
+    let owner = Pubkey::new_from_array(data[32..64].try_into().ok()?);
+    let amount = u64::from_le_bytes(data[64..72].try_into().ok()?);
+    Some(TokenAccountInfo { owner, amount })
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    let bytes: [u8; 32] = data.get(offset..offset + 32)?.try_into().ok()?;
+    Some(Pubkey::new_from_array(bytes))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    let bytes: [u8; 8] = data.get(offset..offset + 8)?.try_into().ok()?;
+    Some(u64::from_le_bytes(bytes))
+}
+
+/// Decode the mint account owned by `owner` (SPL Token or Token-2022)
+///
+/// Pool and holder fields are left empty; they are filled by [`fetch_onchain_security`].
+fn parse_mint_account(owner: &Pubkey, data: &[u8]) -> Result<OnChainSecurityData, String> {
+    let token_program = if *owner == spl_token::id() {
+        "spl-token"
+    } else if *owner == spl_token_2022::id() {
+        "token-2022"
+    } else {
+        return Err(format!("Mint owner is not a token program: {}", owner));
+    };
+
+    // Token-2022 base layout is identical to SPL Token, so one parser covers both
+    let state = StateWithExtensions::<Mint>::unpack(data)
+        .map_err(|e| format!("Failed to unpack mint: {}", e))?;
+
+    let mut data = OnChainSecurityData {
+        token_program: token_program.to_string(),
+        decimals: state.base.decimals,
+        total_supply: state.base.supply.to_string(),
+        mint_authority: Option::<Pubkey>::from(state.base.mint_authority).map(|k| k.to_string()),
+        freeze_authority: Option::<Pubkey>::from(state.base.freeze_authority)
+            .map(|k| k.to_string()),
+        extensions: Vec::new(),
+        transfer_fee_bps: None,
+        transfer_fee_max_amount: None,
+        transfer_fee_authority: None,
+        transfer_hook_program: None,
+        permanent_delegate: None,
+        mint_close_authority: None,
+        non_transferable: false,
+        default_account_frozen: false,
+        canonical_pool: None,
+        pool_program: None,
+        lp_burned_pct: None,
+        lp_locked_pct: None,
+        top_holder_pct: None,
+        top_10_holders_pct: None,
+        security_data_last_fetched_at: Utc::now(),
+    };
+
+    if token_program == "spl-token" {
+        return Ok(data);
+    }
+
+    data.extensions = state
+        .get_extension_types()
+        .map_err(|e| format!("Failed to read mint extensions: {}", e))?
+        .iter()
+        .map(|ext| format!("{:?}", ext))
+        .collect();
+
+    if let Ok(fee) = state.get_extension::<TransferFeeConfig>() {
+        // Use the higher of the current and scheduled fee so a pending increase is not missed
+        let older = &fee.older_transfer_fee;
+        let newer = &fee.newer_transfer_fee;
+        data.transfer_fee_bps = Some(
+            u16::from(older.transfer_fee_basis_points)
+                .max(u16::from(newer.transfer_fee_basis_points)),
+        );
+        data.transfer_fee_max_amount =
+            Some(u64::from(older.maximum_fee).max(u64::from(newer.maximum_fee)));
+        data.transfer_fee_authority =
+            Option::<Pubkey>::from(fee.transfer_fee_config_authority).map(|k| k.to_string());
+    }
+    if let Ok(hook) = state.get_extension::<TransferHook>() {
+        data.transfer_hook_program = Option::<Pubkey>::from(hook.program_id).map(|k| k.to_string());
+    }
+    if let Ok(delegate) = state.get_extension::<PermanentDelegate>() {
+        data.permanent_delegate = Option::<Pubkey>::from(delegate.delegate).map(|k| k.to_string());
+    }
+    if let Ok(close) = state.get_extension::<MintCloseAuthority>() {
+        data.mint_close_authority =
+            Option::<Pubkey>::from(close.close_authority).map(|k| k.to_string());
+    }
+    data.non_transferable = state.get_extension::<NonTransferable>().is_ok();
+    data.default_account_frozen = state
+        .get_extension::<DefaultAccountState>()
+        .map(|ext| ext.state == AccountState::Frozen as u8)
+        .unwrap_or(false);
+
+    Ok(data)
+}
+
+/// LP mint and total LP ever issued for pool types with a fungible LP token
+///
+/// Returns None for concentrated-liquidity / bin pools and bonding curves, where
+/// liquidity is not represented by an LP mint.
+fn pool_lp_info(kind: ProgramKind, data: &[u8]) -> Option<(Pubkey, u64)> {
+    match kind {
+        // AMM v4: lp_mint @ 464, lp_reserve @ 720
+        ProgramKind::RaydiumLegacyAmm => Some((read_pubkey(data, 464)?, read_u64(data, 720)?)),
+        // CPMM: discriminator + 4 pubkeys, lp_supply @ 333
+        ProgramKind::RaydiumCpmm => Some((read_pubkey(data, 136)?, read_u64(data, 333)?)),
+        // PumpSwap: discriminator + bump + index + creator + base + quote, lp_mint @ 107
+        ProgramKind::PumpFunAmm => Some((
+            read_pubkey(data, 107)?,
+            read_u64(data, pumpfun_amm::LP_SUPPLY_OFFSET)?,
+        )),
+        _ => None,
+    }
+}
+
+/// Percentage of LP burned and locked
+///
+/// Burned LP is what left the mint supply (`lp_total - lp_mint_supply`) plus LP
+/// sent to the incinerator; locked LP is what sits in locker program custody.
+fn lp_distribution(
+    lp_total: u64,
+    lp_mint_supply: u64,
+    incinerated: u64,
+    locked: u64,
+) -> Option<(f64, f64)> {
+    if lp_total == 0 {
+        return None;
+    }
+    let total = lp_total as f64;
+    let burned = lp_total.saturating_sub(lp_mint_supply) as f64 + incinerated as f64;
+    let burned_pct = (burned / total * 100.0).min(100.0);
+    let locked_pct = (locked as f64 / total * 100.0).min(100.0 - burned_pct);
+    Some((burned_pct, locked_pct))
+}
+
+/// Largest holder and top-10 share of supply from balances sorted descending
+fn holder_concentration(supply: u64, balances: &[u64]) -> (Option<f64>, Option<f64>) {
+    if supply == 0 || balances.is_empty() {
+        return (None, None);
+    }
+    let pct = |amount: u64| amount as f64 / supply as f64 * 100.0;
+    let top_10: u64 = balances.iter().take(10).sum();
+    (Some(pct(balances[0])), Some(pct(top_10)))
+}
+
+/// Fetch token accounts, skipping any that are missing or not token accounts
+async fn load_token_accounts(addresses: &[Pubkey]) -> Result<Vec<TokenAccountInfo>, String> {
+    if addresses.is_empty() {
+        return Ok(Vec::new());
+    }
+    let accounts = get_rpc_client().get_multiple_accounts(addresses).await?;
+    Ok(accounts
+        .into_iter()
+        .flatten()
+        .filter_map(|account| parse_token_account(&account.data))
+        .collect())
+}
+
+/// Holder concentration excluding program-controlled (off-curve) owners and the incinerator
+async fn analyze_holders(mint: &Pubkey, supply: u64) -> Result<(Option<f64>, Option<f64>), String> {
+    let largest = get_rpc_client().get_token_largest_accounts(mint).await?;
+    let addresses: Vec<Pubkey> = largest.iter().map(|entry| entry.address).collect();
+    let incinerator = Pubkey::from_str(INCINERATOR).map_err(|e| e.to_string())?;
+
+    let mut balances: Vec<u64> = load_token_accounts(&addresses)
+        .await?
+        .into_iter()
+        .filter(|account| account.owner.is_on_curve() && account.owner != incinerator)
+        .map(|account| account.amount)
+        .collect();
+    balances.sort_unstable_by(|a, b| b.cmp(a));
+
+    Ok(holder_concentration(supply, &balances))
+}
+
+/// LP burned/locked percentages for the canonical pool (None if not applicable)
+async fn analyze_lp(kind: ProgramKind, pool_data: &[u8]) -> Result<Option<(f64, f64)>, String> {
+    let (lp_mint, lp_total) = match pool_lp_info(kind, pool_data) {
+        Some(info) => info,
+        None => return Ok(None),
+    };
+
+    let rpc = get_rpc_client();
+    let lp_mint_account = rpc
+        .get_account(&lp_mint)
+        .await?
+        .ok_or_else(|| format!("LP mint {} not found", lp_mint))?;
+    let lp_mint_supply = StateWithExtensions::<Mint>::unpack(&lp_mint_account.data)
+        .map_err(|e| format!("Failed to unpack LP mint: {}", e))?
+        .base
+        .supply;
+
+    let largest = rpc.get_token_largest_accounts(&lp_mint).await?;
+    let addresses: Vec<Pubkey> = largest.iter().map(|entry| entry.address).collect();
+    let lp_accounts = load_token_accounts(&addresses).await?;
+
+    let incinerator = Pubkey::from_str(INCINERATOR).map_err(|e| e.to_string())?;
+    let lockers: HashSet<Pubkey> = LP_LOCKER_PROGRAMS
+        .iter()
+        .filter_map(|id| Pubkey::from_str(id).ok())
+        .collect();
+
+    // Owners may be PDAs of a locker program (escrow accounts); check their program owner
+    let owners: Vec<Pubkey> = lp_accounts.iter().map(|account| account.owner).collect();
+    let owner_programs = rpc.get_multiple_accounts(&owners).await?;
+
+    let mut incinerated = 0u64;
+    let mut locked = 0u64;
+    for (account, owner_account) in lp_accounts.iter().zip(owner_programs.iter()) {
+        if account.owner == incinerator {
+            incinerated = incinerated.saturating_add(account.amount);
+        } else if lockers.contains(&account.owner)
+            || owner_account
+                .as_ref()
+                .map(|acc| lockers.contains(&acc.owner))
+                .unwrap_or(false)
+        {
+            locked = locked.saturating_add(account.amount);
+        }
+    }
+
+    Ok(lp_distribution(
+        lp_total,
+        lp_mint_supply,
+        incinerated,
+        locked,
+    ))
+}
+
+/// Resolve the canonical pool address: pool service first, then the stored pool snapshot
+fn canonical_pool_address(mint: &str, db: &TokenDatabase) -> Option<Pubkey> {
+    if let Some(pool) = crate::pools::get_token_pools(mint).first() {
+        return Some(pool.pool_id);
+    }
+    db.get_token_pools(mint)
+        .ok()
+        .flatten()
+        .and_then(|snapshot| snapshot.canonical_pool_address)
+        .and_then(|address| Pubkey::from_str(&address).ok())
+}
+
+/// Run the full on-chain analysis for a token and persist it
+///
+/// Mint parsing failures are errors; pool and holder lookups are best-effort and
+/// leave their fields empty when they fail.
+pub async fn fetch_onchain_security(
+    mint: &str,
+    db: &TokenDatabase,
+) -> TokenResult<OnChainSecurityData> {
+    let mint_pubkey = Pubkey::from_str(mint).map_err(|e| TokenError::InvalidMint(e.to_string()))?;
+    let rpc = get_rpc_client();
+    let rpc_error = |message: String| TokenError::Api {
+        source: "RPC".to_string(),
+        message,
+    };
+
+    let mint_account = rpc
+        .get_account(&mint_pubkey)
+        .await
+        .map_err(rpc_error)?
+        .ok_or_else(|| TokenError::NotFound(format!("Mint account {} not found", mint)))?;
+    let mut data =
+        parse_mint_account(&mint_account.owner, &mint_account.data).map_err(rpc_error)?;
+
+    let supply = data.total_supply.parse::<u64>().unwrap_or(0);
+    match analyze_holders(&mint_pubkey, supply).await {
+        Ok((top, top_10)) => {
+            data.top_holder_pct = top;
+            data.top_10_holders_pct = top_10;
+        }
+        Err(e) => logger::debug(
+            LogTag::Tokens,
+            &format!("[ONCHAIN] Holder analysis failed for {}: {}", mint, e),
+        ),
+    }
+
+    if let Some(pool_address) = canonical_pool_address(mint, db) {
+        match rpc.get_account(&pool_address).await {
+            Ok(Some(pool_account)) => {
+                let kind = ProgramKind::classify(&pool_account.owner);
+                data.canonical_pool = Some(pool_address.to_string());
+                data.pool_program = Some(kind.display_name().to_string());
+                match analyze_lp(kind, &pool_account.data).await {
+                    Ok(Some((burned, locked))) => {
+                        data.lp_burned_pct = Some(burned);
+                        data.lp_locked_pct = Some(locked);
+                    }
+                    Ok(None) => {}
+                    Err(e) => logger::debug(
+                        LogTag::Tokens,
+                        &format!("[ONCHAIN] LP analysis failed for {}: {}", mint, e),
+                    ),
+                }
+            }
+            Ok(None) => {}
+            Err(e) => logger::debug(
+                LogTag::Tokens,
+                &format!("[ONCHAIN] Pool fetch failed for {}: {}", mint, e),
+            ),
+        }
+    }
+
+    data.security_data_last_fetched_at = Utc::now();
+    db.upsert_onchain_security(mint, &data)?;
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lp_distribution_counts_supply_burn_and_incinerator() {
+        // 1000 issued, 600 still in supply (400 burned), 100 incinerated, 200 locked
+        let (burned, locked) = lp_distribution(1000, 600, 100, 200).unwrap();
+        assert!((burned - 50.0).abs() < 1e-9);
+        assert!((locked - 20.0).abs() < 1e-9);
+
+        assert!(lp_distribution(0, 0, 0, 0).is_none());
+
+        // Never exceeds 100% combined
+        let (burned, locked) = lp_distribution(100, 0, 50, 50).unwrap();
+        assert_eq!(burned, 100.0);
+        assert_eq!(locked, 0.0);
+    }
+
+    #[test]
+    fn holder_concentration_uses_top_ten() {
+        let balances: Vec<u64> = vec![300, 100, 50, 50, 50, 50, 50, 50, 50, 50, 50];
+        let (top, top_10) = holder_concentration(1000, &balances);
+        assert!((top.unwrap() - 30.0).abs() < 1e-9);
+        assert!((top_10.unwrap() - 80.0).abs() < 1e-9);
+        assert_eq!(holder_concentration(0, &balances), (None, None));
+    }
+
+    #[test]
+    fn parses_legacy_mint_account() {
+        let authority = Pubkey::new_unique();
+        let mut raw = vec![0u8; 82];
+        // mint_authority: COption tag (1) + pubkey
+        raw[0..4].copy_from_slice(&1u32.to_le_bytes());
+        raw[4..36].copy_from_slice(authority.as_ref());
+        raw[36..44].copy_from_slice(&1_000_000u64.to_le_bytes());
+        raw[44] = 6; // decimals
+        raw[45] = 1; // is_initialized, freeze_authority left as None
+
+        let data = parse_mint_account(&spl_token::id(), &raw).unwrap();
+        assert_eq!(data.token_program, "spl-token");
+        assert_eq!(data.decimals, 6);
+        assert_eq!(data.total_supply, "1000000");
+        assert_eq!(data.mint_authority, Some(authority.to_string()));
+        assert!(data.freeze_authority.is_none());
+        assert!(data.extensions.is_empty());
+
+        assert!(parse_mint_account(&Pubkey::new_unique(), &raw).is_err());
+    }
+
+    #[test]
+    fn lp_info_only_for_fungible_lp_pools() {
+        let lp_mint = Pubkey::new_unique();
+        let mut raw = vec![0u8; 800];
+        raw[136..168].copy_from_slice(lp_mint.as_ref());
+        raw[333..341].copy_from_slice(&42u64.to_le_bytes());
+
+        assert_eq!(
+            pool_lp_info(ProgramKind::RaydiumCpmm, &raw),
+            Some((lp_mint, 42))
+        );
+        assert_eq!(pool_lp_info(ProgramKind::RaydiumClmm, &raw), None);
+        assert_eq!(pool_lp_info(ProgramKind::RaydiumCpmm, &raw[..200]), None);
+
+        // PumpSwap: lp_supply @ 203 is followed by coin_creator, which must not be read
+        let mut raw = vec![0u8; 300];
+        raw[107..139].copy_from_slice(lp_mint.as_ref());
+        raw[203..211].copy_from_slice(&7u64.to_le_bytes());
+        raw[211..243].fill(0xff);
+
+        assert_eq!(
+            pool_lp_info(ProgramKind::PumpFunAmm, &raw),
+            Some((lp_mint, 7))
+        );
+        assert_eq!(pool_lp_info(ProgramKind::PumpFunAmm, &raw[..208]), None);
+    }
+}
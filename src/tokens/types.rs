@@ -555,14 +555,54 @@ pub struct RugcheckData {
     pub security_data_first_fetched_at: DateTime<Utc>,
 }
 
-/// On-chain security verification (future)
+/// On-chain security analysis (mint account, Token-2022 extensions, canonical pool LP, holders)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OnChainSecurityData {
+    /// "spl-token" or "token-2022"
+    pub token_program: String,
+    pub decimals: u8,
+    pub total_supply: String,
     pub mint_authority: Option<String>,
     pub freeze_authority: Option<String>,
-    pub total_supply: String,
-    pub holder_count: Option<u64>,
-    pub verified_at: DateTime<Utc>,
+
+    // Token-2022 extensions (names as reported by the token program)
+    pub extensions: Vec<String>,
+    /// Current transfer fee in basis points (TransferFeeConfig)
+    pub transfer_fee_bps: Option<u16>,
+    pub transfer_fee_max_amount: Option<u64>,
+    pub transfer_fee_authority: Option<String>,
+    pub transfer_hook_program: Option<String>,
+    pub permanent_delegate: Option<String>,
+    pub mint_close_authority: Option<String>,
+    pub non_transferable: bool,
+    pub default_account_frozen: bool,
+
+    // Canonical pool liquidity (None when the pool type has no fungible LP token)
+    pub canonical_pool: Option<String>,
+    pub pool_program: Option<String>,
+    pub lp_burned_pct: Option<f64>,
+    pub lp_locked_pct: Option<f64>,
+
+    // Holder concentration (pool vaults excluded)
+    pub top_holder_pct: Option<f64>,
+    pub top_10_holders_pct: Option<f64>,
+
+    pub security_data_last_fetched_at: DateTime<Utc>,
+}
+
+impl OnChainSecurityData {
+    /// Share of LP that can no longer be withdrawn (burned + held by lockers)
+    pub fn lp_secured_pct(&self) -> Option<f64> {
+        match (self.lp_burned_pct, self.lp_locked_pct) {
+            (None, None) => None,
+            (burned, locked) => Some((burned.unwrap_or(0.0) + locked.unwrap_or(0.0)).min(100.0)),
+        }
+    }
+
+    /// Current transfer fee as a percentage
+    pub fn transfer_fee_pct(&self) -> Option<f64> {
+        self.transfer_fee_bps.map(|bps| bps as f64 / 100.0)
+    }
 }
 
 /// Combined security assessment
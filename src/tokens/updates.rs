@@ -29,7 +29,7 @@ use crate::pools;
 use crate::tokens::database::TokenDatabase;
 use crate::tokens::market::{dexscreener, geckoterminal};
 use crate::tokens::priorities::Priority;
-use crate::tokens::security::rugcheck;
+use crate::tokens::security::{onchain, rugcheck};
 use crate::tokens::types::{TokenError, TokenResult};
 use futures::future::join_all;
 use std::collections::{HashMap, HashSet};
@@ -807,6 +807,53 @@ async fn update_security_data(db: &TokenDatabase, coordinator: &RateLimitCoordin
     clear_in_flight(mint);
 }
 
+/// Tokens analyzed per on-chain security cycle (each costs several RPC calls)
+const ONCHAIN_SECURITY_BATCH_SIZE: usize = 3;
+
+/// Run native on-chain security analysis for tokens with missing or stale results
+///
+/// Only active when the On-Chain filter source is enabled. Uses RPC instead of
+/// the Rugcheck rate limiter, so it keeps running while Rugcheck is down.
+async fn update_onchain_security(db: &TokenDatabase) {
+    if !with_config(|cfg| cfg.filtering.onchain.enabled) {
+        return;
+    }
+
+    let max_age = with_config(|cfg| cfg.tokens.update_intervals.onchain_security_max_age_seconds);
+    let stale_before = chrono::Utc::now().timestamp() - max_age as i64;
+
+    let tokens = match db.get_tokens_for_onchain_security(ONCHAIN_SECURITY_BATCH_SIZE, stale_before)
+    {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            logger::error(
+                LogTag::Tokens,
+                &format!("Failed to load tokens for on-chain security: {}", e),
+            );
+            return;
+        }
+    };
+
+    for mint in &tokens {
+        if !try_mark_in_flight(mint) {
+            continue;
+        }
+
+        match onchain::fetch_onchain_security(mint, db).await {
+            Ok(_) => logger::debug(
+                LogTag::Tokens,
+                &format!("On-chain security analyzed for {}", mint),
+            ),
+            Err(e) => logger::warning(
+                LogTag::Tokens,
+                &format!("On-chain security analysis failed for {}: {}", mint, e),
+            ),
+        }
+
+        clear_in_flight(mint);
+    }
+}
+
 // ============================================================================
 // PRIORITY-BASED UPDATE LOOPS
 // ============================================================================
@@ -836,6 +883,20 @@ pub fn start_update_loop(
         }
     }));
 
+    // On-chain security loop (native analysis, refreshed when stale)
+    let db_onchain = db.clone();
+    let shutdown_onchain = shutdown.clone();
+    handles.push(tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = shutdown_onchain.notified() => break,
+                _ = sleep(Duration::from_secs(with_config(|cfg| cfg.tokens.update_intervals.onchain_security_seconds))) => {
+                    update_onchain_security(&db_onchain).await;
+                }
+            }
+        }
+    }));
+
     // Immediate seeding loop for tokens that have no market data yet
     let db_seed = db.clone();
     let coord_seed = coordinator.clone();
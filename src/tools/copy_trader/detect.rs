@@ -0,0 +1,136 @@
+//! Leader swap detection
+//!
+//! Fetches a followed wallet's transaction, classifies it with the transaction
+//! analyzer and measures the swap from the leader's own balance changes.
+
+use std::time::Duration;
+
+use super::types::{CopySide, LeaderSwap};
+use crate::rpc::{get_rpc_client, RpcClientMethods};
+use crate::transactions::analyzer::balance::{extract_balance_changes, BalanceAnalysis};
+use crate::transactions::analyzer::classify::{
+    classify_transaction, ClassifiedType, SwapDirection,
+};
+use crate::transactions::analyzer::dex::detect_dex_interactions;
+use crate::transactions::types::Transaction;
+use crate::transactions::utils::WSOL_MINT;
+
+/// Attempts to fetch a freshly notified transaction (RPC indexing lags the websocket)
+const FETCH_ATTEMPTS: u32 = 4;
+
+/// Pause between fetch attempts (milliseconds)
+const FETCH_RETRY_DELAY_MS: u64 = 1500;
+
+/// Fetch and classify a leader transaction
+///
+/// Returns `Ok(None)` for failed transactions and anything that is not a
+/// SOL <-> token swap made by the leader.
+pub async fn detect_leader_swap(
+    leader: &str,
+    signature: &str,
+) -> Result<Option<LeaderSwap>, String> {
+    let details = fetch_transaction(signature).await?;
+
+    if details
+        .meta
+        .as_ref()
+        .map_or(true, |meta| meta.err.is_some())
+    {
+        return Ok(None);
+    }
+
+    let transaction = Transaction::new(signature.to_string());
+    let balance = extract_balance_changes(&transaction, &details).await?;
+    let dex = detect_dex_interactions(&transaction, &details, &balance).await?;
+    let class = classify_transaction(&transaction, &details, &balance, &dex).await?;
+
+    let side = match (&class.transaction_type, &class.direction) {
+        (ClassifiedType::Buy, _) | (_, Some(SwapDirection::SolToToken)) => CopySide::Buy,
+        (ClassifiedType::Sell, _) | (_, Some(SwapDirection::TokenToSol)) => CopySide::Sell,
+        _ => return Ok(None),
+    };
+    let mint = match class.primary_token {
+        Some(mint) if mint != WSOL_MINT => mint,
+        _ => return Ok(None),
+    };
+
+    Ok(measure_leader_swap(
+        leader, signature, &mint, side, &balance,
+    ))
+}
+
+async fn fetch_transaction(signature: &str) -> Result<crate::rpc::TransactionDetails, String> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match get_rpc_client().get_transaction_details(signature).await {
+            Ok(details) => return Ok(details),
+            Err(e) if attempt >= FETCH_ATTEMPTS => {
+                return Err(format!("Failed to fetch transaction {}: {}", signature, e))
+            }
+            Err(_) => tokio::time::sleep(Duration::from_millis(FETCH_RETRY_DELAY_MS)).await,
+        }
+    }
+}
+
+/// Measure the swap from the leader's SOL (including WSOL) and token balance changes
+///
+/// The classifier looks at the whole transaction; requiring the leader's own
+/// balances to move in opposite directions filters out transfers and swaps the
+/// leader was merely mentioned in.
+fn measure_leader_swap(
+    leader: &str,
+    signature: &str,
+    mint: &str,
+    side: CopySide,
+    balance: &BalanceAnalysis,
+) -> Option<LeaderSwap> {
+    let token_changes = balance.token_changes.get(leader);
+    let sum_token = |target: &str| -> (f64, f64) {
+        token_changes
+            .map(|changes| {
+                changes
+                    .iter()
+                    .filter(|c| c.mint == target)
+                    .fold((0.0, 0.0), |(change, pre), c| {
+                        (change + c.change, pre + c.pre_balance.unwrap_or(0.0))
+                    })
+            })
+            .unwrap_or((0.0, 0.0))
+    };
+
+    let sol_change = balance
+        .sol_changes
+        .get(leader)
+        .map(|c| c.change)
+        .unwrap_or(0.0)
+        + sum_token(WSOL_MINT).0;
+    let (token_change, token_pre) = sum_token(mint);
+
+    let (sol_amount, token_amount, sell_pct) = match side {
+        CopySide::Buy => (-sol_change, token_change, None),
+        CopySide::Sell => {
+            let sold = -token_change;
+            let pct = if token_pre > 0.0 {
+                (sold / token_pre * 100.0).clamp(0.0, 100.0)
+            } else {
+                100.0
+            };
+            (sol_change, sold, Some(pct))
+        }
+    };
+
+    if sol_amount <= 0.0 || token_amount <= 0.0 {
+        return None;
+    }
+
+    Some(LeaderSwap {
+        leader: leader.to_string(),
+        signature: signature.to_string(),
+        mint: mint.to_string(),
+        side,
+        sol_amount,
+        token_amount,
+        sell_pct,
+    })
+}
@@ -0,0 +1,61 @@
+//! Copy Trader Tool
+//!
+//! Follow wallet addresses and mirror their swaps into our own positions:
+//! - One logs subscription per active leader via the transactions websocket
+//! - Swaps classified with the transaction analyzer and measured from the
+//!   leader's own balance changes
+//! - Fixed or proportional sizing, max-slippage guard, copy delay and an
+//!   optional filtering pass before buys
+//! - Sells only mirrored into positions the same leader's copies opened
+//! - Per-leader PnL attribution over the copied positions
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use screenerbot::tools::copy_trader::{
+//!     add_copy_leader, get_copy_trader_status, get_leader_pnl, trigger_copy_trader_resync,
+//! };
+//!
+//! // Follow a wallet with the default sizing from `copy_trade` config
+//! let id = add_copy_leader("Wallet...", Some("whale"), None, None)?;
+//!
+//! // Subscribe now instead of waiting for the next sync
+//! trigger_copy_trader_resync();
+//!
+//! let pnl = get_leader_pnl().await?;
+//! ```
+//!
+//! ## Configuration
+//!
+//! Sizing, slippage, delay and filter settings live in `copy_trade` config.
+//! The monitor is started by the `copy_trader` service and holds no
+//! subscriptions while disabled.
+
+mod detect;
+mod monitor;
+mod pnl;
+mod types;
+
+// Re-export types
+pub use types::{
+    attribute_pnl, compute_copy_size, exceeds_slippage, CopySide, CopyTraderStatus, LeaderPnl,
+    LeaderSwap, SizingMode, SizingParams,
+};
+
+// Re-export detection
+pub use detect::detect_leader_swap;
+
+// Re-export monitor functions
+pub use monitor::{
+    get_copy_trader_counters, get_copy_trader_status, start_copy_trader_service,
+    trigger_copy_trader_resync,
+};
+
+// Re-export PnL attribution
+pub use pnl::get_leader_pnl;
+
+// Re-export database types and functions for convenience
+pub use crate::tools::database::{
+    add_copy_leader, delete_copy_leader, get_copy_leader, get_copy_leaders, get_copy_trades,
+    update_copy_leader_status, CopyLeaderRow, CopyTradeOutcome, CopyTradeRow,
+};
@@ -0,0 +1,622 @@
+//! Copy Trader monitor
+//!
+//! Keeps one logs subscription per active leader, classifies every notified
+//! transaction and mirrors leader swaps into our own positions through the
+//! manual trading API.
+
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Notify, RwLock};
+use tokio::task::JoinHandle;
+
+use super::detect::detect_leader_swap;
+use super::types::{
+    compute_copy_size, exceeds_slippage, CopySide, CopyTraderStatus, LeaderSwap, SizingMode,
+    SizingParams,
+};
+use crate::config::{with_config, CopyTradeConfig};
+use crate::events::{record_trader_event, Severity};
+use crate::logger::{self, LogTag};
+use crate::positions;
+use crate::telegram::{queue_notification, Notification};
+use crate::tools::database::{
+    claim_copy_trade, complete_copy_trade, fail_stale_copy_trades, get_copy_leader,
+    get_copy_leaders, leader_funded_position, CopyLeaderRow, CopyTradeOutcome,
+};
+use crate::trader::manual;
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Delay before subscribing to let RPC, pools and filtering initialize (seconds)
+const STARTUP_DELAY_SECS: u64 = 30;
+
+/// How often subscriptions are reconciled with the leader list (seconds)
+const SYNC_INTERVAL_SECS: u64 = 30;
+
+// =============================================================================
+// GLOBAL STATE
+// =============================================================================
+
+static COPY_MONITOR: Lazy<Arc<RwLock<CopyMonitorState>>> =
+    Lazy::new(|| Arc::new(RwLock::new(CopyMonitorState::default())));
+
+/// Wakes the monitor loop to reconcile subscriptions immediately
+static RESYNC_NOW: Lazy<Arc<Notify>> = Lazy::new(|| Arc::new(Notify::new()));
+
+/// Copy monitor runtime state
+#[derive(Default)]
+struct CopyMonitorState {
+    is_running: bool,
+    subscribed_leaders: usize,
+    swaps_detected: u64,
+    trades_copied: u64,
+    trades_skipped: u64,
+    last_trade_at: Option<String>,
+    last_error: Option<String>,
+}
+
+/// Live logs subscription for one leader
+struct LeaderSubscription {
+    stop: Arc<Notify>,
+    forwarder: JoinHandle<()>,
+}
+
+impl LeaderSubscription {
+    fn stop(self) {
+        self.stop.notify_waiters();
+        self.forwarder.abort();
+    }
+}
+
+// =============================================================================
+// SERVICE LOOP
+// =============================================================================
+
+/// Run the Copy Trader monitor until shutdown
+///
+/// The loop stays alive while `copy_trade.enabled` is off (with no
+/// subscriptions) so toggling the setting takes effect on the next sync.
+pub async fn start_copy_trader_service(shutdown: Arc<Notify>) {
+    COPY_MONITOR.write().await.is_running = true;
+    logger::info(LogTag::Tools, "[COPY_TRADER] Copy trader started");
+
+    // A restart while a copy was executing leaves it `pending`; never re-fire those
+    match fail_stale_copy_trades() {
+        Ok(0) => {}
+        Ok(count) => logger::warning(
+            LogTag::Tools,
+            &format!(
+                "[COPY_TRADER] Marked {} interrupted copy trade(s) as failed - check positions",
+                count
+            ),
+        ),
+        Err(e) => logger::error(
+            LogTag::Tools,
+            &format!(
+                "[COPY_TRADER] Failed to reset interrupted copy trades: {}",
+                e
+            ),
+        ),
+    }
+
+    tokio::select! {
+        _ = shutdown.notified() => {
+            COPY_MONITOR.write().await.is_running = false;
+            return;
+        }
+        _ = tokio::time::sleep(Duration::from_secs(STARTUP_DELAY_SECS)) => {}
+    }
+
+    let (signature_tx, mut signature_rx) = mpsc::unbounded_channel::<(String, String)>();
+    let mut subscriptions: HashMap<String, LeaderSubscription> = HashMap::new();
+    let mut sync_timer = tokio::time::interval(Duration::from_secs(SYNC_INTERVAL_SECS));
+
+    loop {
+        tokio::select! {
+            _ = shutdown.notified() => break,
+            _ = RESYNC_NOW.notified() => {
+                sync_subscriptions(&mut subscriptions, &signature_tx).await;
+            }
+            _ = sync_timer.tick() => {
+                sync_subscriptions(&mut subscriptions, &signature_tx).await;
+            }
+            Some((leader, signature)) = signature_rx.recv() => {
+                tokio::spawn(handle_leader_signature(leader, signature));
+            }
+        }
+    }
+
+    for (_, subscription) in subscriptions.drain() {
+        subscription.stop();
+    }
+
+    let mut state = COPY_MONITOR.write().await;
+    state.is_running = false;
+    state.subscribed_leaders = 0;
+    drop(state);
+    logger::info(LogTag::Tools, "[COPY_TRADER] Copy trader stopped");
+}
+
+/// Reconcile subscriptions after the leader list or the enabled flag changed
+pub fn trigger_copy_trader_resync() {
+    RESYNC_NOW.notify_one();
+}
+
+/// Get Copy Trader status
+pub async fn get_copy_trader_status() -> CopyTraderStatus {
+    let (enabled, max_leaders) = with_config(|cfg| {
+        (
+            cfg.copy_trade.enabled,
+            cfg.copy_trade.max_leaders.max(1) as usize,
+        )
+    });
+    let active_leaders = get_copy_leaders(true).map(|l| l.len()).unwrap_or(0);
+
+    let state = COPY_MONITOR.read().await;
+    CopyTraderStatus {
+        enabled,
+        is_running: state.is_running,
+        active_leaders,
+        subscribed_leaders: state.subscribed_leaders,
+        max_leaders,
+        swaps_detected: state.swaps_detected,
+        trades_copied: state.trades_copied,
+        trades_skipped: state.trades_skipped,
+        last_trade_at: state.last_trade_at.clone(),
+        last_error: state.last_error.clone(),
+    }
+}
+
+/// Get (swaps detected, trades copied) counters for service metrics
+pub async fn get_copy_trader_counters() -> (u64, u64) {
+    let state = COPY_MONITOR.read().await;
+    (state.swaps_detected, state.trades_copied)
+}
+
+// =============================================================================
+// SUBSCRIPTIONS
+// =============================================================================
+
+/// Subscribe to new active leaders and drop removed or paused ones
+async fn sync_subscriptions(
+    subscriptions: &mut HashMap<String, LeaderSubscription>,
+    signature_tx: &mpsc::UnboundedSender<(String, String)>,
+) {
+    let (enabled, max_leaders) = with_config(|cfg| {
+        (
+            cfg.copy_trade.enabled,
+            cfg.copy_trade.max_leaders.max(1) as usize,
+        )
+    });
+
+    let wanted: Vec<String> = if enabled {
+        match get_copy_leaders(true) {
+            Ok(leaders) => leaders
+                .into_iter()
+                .take(max_leaders)
+                .map(|l| l.address)
+                .collect(),
+            Err(e) => {
+                logger::error(
+                    LogTag::Tools,
+                    &format!("[COPY_TRADER] Failed to load leaders: {}", e),
+                );
+                COPY_MONITOR.write().await.last_error = Some(e);
+                return;
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    let removed: Vec<String> = subscriptions
+        .keys()
+        .filter(|address| !wanted.contains(address))
+        .cloned()
+        .collect();
+    for address in removed {
+        if let Some(subscription) = subscriptions.remove(&address) {
+            subscription.stop();
+            logger::info(
+                LogTag::Tools,
+                &format!("[COPY_TRADER] Unsubscribed from {}", address),
+            );
+        }
+    }
+
+    let own_wallet = crate::utils::get_wallet_address().ok();
+    for address in wanted {
+        if subscriptions.contains_key(&address) || own_wallet.as_deref() == Some(address.as_str()) {
+            continue;
+        }
+        match subscribe_leader(&address, signature_tx.clone()).await {
+            Ok(subscription) => {
+                logger::info(
+                    LogTag::Tools,
+                    &format!("[COPY_TRADER] Subscribed to {}", address),
+                );
+                subscriptions.insert(address, subscription);
+            }
+            Err(e) => {
+                logger::warning(
+                    LogTag::Tools,
+                    &format!("[COPY_TRADER] Failed to subscribe to {}: {}", address, e),
+                );
+                COPY_MONITOR.write().await.last_error = Some(format!("{}: {}", address, e));
+            }
+        }
+    }
+
+    COPY_MONITOR.write().await.subscribed_leaders = subscriptions.len();
+}
+
+/// Start a logs subscription for a leader and forward its signatures to the monitor
+async fn subscribe_leader(
+    address: &str,
+    signature_tx: mpsc::UnboundedSender<(String, String)>,
+) -> Result<LeaderSubscription, String> {
+    let ws_url = crate::rpc::websocket::get_websocket_url().ok();
+    let stop = Arc::new(Notify::new());
+    let mut receiver = crate::transactions::websocket::start_websocket_monitoring(
+        address.to_string(),
+        ws_url,
+        stop.clone(),
+    )
+    .await?;
+
+    let leader = address.to_string();
+    let forwarder = tokio::spawn(async move {
+        while let Some(signature) = receiver.recv().await {
+            if signature_tx.send((leader.clone(), signature)).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(LeaderSubscription { stop, forwarder })
+}
+
+// =============================================================================
+// COPY EXECUTION
+// =============================================================================
+
+/// Classify a notified leader transaction and mirror it if it is a swap
+async fn handle_leader_signature(leader: String, signature: String) {
+    let swap = match detect_leader_swap(&leader, &signature).await {
+        Ok(Some(swap)) => swap,
+        Ok(None) => return,
+        Err(e) => {
+            logger::debug(
+                LogTag::Tools,
+                &format!(
+                    "[COPY_TRADER] Skipping {} from {}: {}",
+                    signature, leader, e
+                ),
+            );
+            return;
+        }
+    };
+
+    // The leader may have been removed while the transaction was being fetched
+    let leader_row = match get_copy_leader(&leader) {
+        Ok(Some(row)) if row.is_active => row,
+        _ => return,
+    };
+
+    // Claim first so duplicate notifications can never copy the same swap twice
+    let claim_id = match claim_copy_trade(
+        &swap.leader,
+        &swap.signature,
+        &swap.mint,
+        swap.side.as_str(),
+        swap.sol_amount,
+        swap.price_sol(),
+        swap.sell_pct,
+    ) {
+        Ok(Some(id)) => id,
+        Ok(None) => return,
+        Err(e) => {
+            logger::error(
+                LogTag::Tools,
+                &format!("[COPY_TRADER] Failed to record {}: {}", swap.signature, e),
+            );
+            return;
+        }
+    };
+
+    COPY_MONITOR.write().await.swaps_detected += 1;
+
+    logger::info(
+        LogTag::Tools,
+        &format!(
+            "[COPY_TRADER] {} {} {} for {:.4} SOL ({})",
+            display_leader(&leader_row),
+            if swap.side == CopySide::Buy {
+                "bought"
+            } else {
+                "sold"
+            },
+            swap.mint,
+            swap.sol_amount,
+            swap.signature
+        ),
+    );
+
+    let config = with_config(|cfg| cfg.copy_trade.clone());
+    let mut outcome = mirror_swap(&leader_row, &swap, &config).await;
+    outcome.symbol = crate::tokens::get_cached_token(&swap.mint).map(|t| t.symbol);
+
+    if let Err(e) = complete_copy_trade(claim_id, &outcome) {
+        logger::error(
+            LogTag::Tools,
+            &format!(
+                "[COPY_TRADER] Failed to update copy trade #{}: {}",
+                claim_id, e
+            ),
+        );
+    }
+
+    {
+        let mut state = COPY_MONITOR.write().await;
+        if outcome.status == "executed" {
+            state.trades_copied += 1;
+            state.last_trade_at = Some(Utc::now().to_rfc3339());
+        } else {
+            state.trades_skipped += 1;
+        }
+        if outcome.status == "failed" {
+            state.last_error = outcome.reason.clone();
+        }
+    }
+
+    let details = describe_outcome(&swap, &outcome);
+    logger::info(
+        LogTag::Tools,
+        &format!(
+            "[COPY_TRADER] Copy {} of {} {}: {}",
+            swap.side.as_str(),
+            swap.mint,
+            outcome.status,
+            details
+        ),
+    );
+
+    record_trader_event(
+        &format!("copy_trade_{}", outcome.status),
+        match outcome.status.as_str() {
+            "executed" | "skipped" => Severity::Info,
+            _ => Severity::Warn,
+        },
+        Some(&swap.mint),
+        outcome.our_signature.as_deref(),
+        json!({
+            "copy_trade_id": claim_id,
+            "leader": swap.leader,
+            "leader_signature": swap.signature,
+            "side": swap.side.as_str(),
+            "leader_sol": swap.sol_amount,
+            "leader_price_sol": swap.price_sol(),
+            "leader_sell_pct": swap.sell_pct,
+            "our_size_sol": outcome.our_size_sol,
+            "our_sell_pct": outcome.our_sell_pct,
+            "position_id": outcome.position_id,
+            "reason": outcome.reason,
+        }),
+    )
+    .await;
+
+    if config.notify && outcome.status != "skipped" {
+        queue_notification(Notification::copy_trade(
+            outcome.symbol.clone().unwrap_or_else(|| swap.mint.clone()),
+            swap.mint.clone(),
+            swap.leader.clone(),
+            swap.side.as_str(),
+            &outcome.status,
+            details,
+        ));
+    }
+}
+
+/// Apply the copy rules to a leader swap and execute the mirrored trade
+async fn mirror_swap(
+    leader: &CopyLeaderRow,
+    swap: &LeaderSwap,
+    config: &CopyTradeConfig,
+) -> CopyTradeOutcome {
+    if !config.enabled {
+        return skipped("Copy trading is disabled");
+    }
+    if crate::global::is_force_stopped() {
+        return skipped("Trading is force-stopped");
+    }
+    if !crate::trader::config::is_trader_enabled() {
+        return skipped("Trader is disabled");
+    }
+
+    if config.delay_secs > 0 {
+        tokio::time::sleep(Duration::from_secs(config.delay_secs as u64)).await;
+    }
+
+    match swap.side {
+        CopySide::Buy => mirror_buy(leader, swap, config).await,
+        CopySide::Sell => mirror_sell(leader, swap, config).await,
+    }
+}
+
+async fn mirror_buy(
+    leader: &CopyLeaderRow,
+    swap: &LeaderSwap,
+    config: &CopyTradeConfig,
+) -> CopyTradeOutcome {
+    if swap.sol_amount < config.min_leader_trade_sol {
+        return skipped(&format!(
+            "Leader trade {:.4} SOL is below the {:.4} SOL minimum",
+            swap.sol_amount, config.min_leader_trade_sol
+        ));
+    }
+
+    if config.require_filter_pass {
+        if crate::tokens::get_blacklisted_tokens().contains(&swap.mint) {
+            return skipped("Token is blacklisted");
+        }
+        let passed = crate::filtering::get_filtered_token_mints()
+            .await
+            .unwrap_or_default();
+        if !passed.contains(&swap.mint) {
+            return skipped("Token has not passed filtering");
+        }
+    }
+
+    let leader_price = match swap.price_sol() {
+        Some(price) => price,
+        None => return skipped("Could not determine the leader's fill price"),
+    };
+    let our_price = match crate::pools::get_pool_price(&swap.mint) {
+        Some(price) => price.price_sol,
+        None => return skipped("No pool price to check slippage against"),
+    };
+    if exceeds_slippage(leader_price, our_price, config.max_slippage_pct) {
+        return skipped(&format!(
+            "Price {:.9} SOL is more than {:.1}% above the leader's fill {:.9} SOL",
+            our_price, config.max_slippage_pct, leader_price
+        ));
+    }
+
+    let size_sol = compute_copy_size(swap.sol_amount, &sizing_params(leader, config));
+    let result = if positions::get_position_by_mint(&swap.mint).await.is_some() {
+        manual::manual_add(&swap.mint, size_sol).await
+    } else {
+        manual::manual_buy(&swap.mint, size_sol).await
+    };
+
+    let mut outcome = trade_outcome(result);
+    outcome.our_size_sol = Some(size_sol);
+    if outcome.status == "executed" {
+        outcome.position_id = positions::get_position_by_mint(&swap.mint)
+            .await
+            .and_then(|p| p.id);
+    }
+    outcome
+}
+
+async fn mirror_sell(
+    leader: &CopyLeaderRow,
+    swap: &LeaderSwap,
+    config: &CopyTradeConfig,
+) -> CopyTradeOutcome {
+    if !config.mirror_sells {
+        return skipped("Mirroring sells is disabled");
+    }
+
+    let position_id = match positions::get_position_by_mint(&swap.mint).await {
+        Some(position) => position.id,
+        None => return skipped("No open position for this token"),
+    };
+    // Only sell what this leader got us into, never strategy or manual positions
+    let funded = match position_id {
+        Some(id) => leader_funded_position(&leader.address, id).unwrap_or(false),
+        None => false,
+    };
+    if !funded {
+        return skipped("Open position was not copied from this leader");
+    }
+
+    let sell_pct = swap.sell_pct.unwrap_or(100.0);
+    // Leaders selling (nearly) everything close the position outright
+    let percentage = if sell_pct >= 99.0 {
+        None
+    } else {
+        Some(sell_pct)
+    };
+
+    let mut outcome = trade_outcome(manual::manual_sell(&swap.mint, percentage).await);
+    outcome.our_sell_pct = Some(percentage.unwrap_or(100.0));
+    outcome.position_id = position_id;
+    outcome
+}
+
+/// Resolve sizing from config, applying the leader's overrides
+fn sizing_params(leader: &CopyLeaderRow, config: &CopyTradeConfig) -> SizingParams {
+    let mode = leader
+        .sizing_mode
+        .as_deref()
+        .and_then(SizingMode::parse)
+        .or_else(|| SizingMode::parse(&config.sizing_mode))
+        .unwrap_or(SizingMode::Fixed);
+
+    let mut params = SizingParams {
+        mode,
+        fixed_size_sol: config.fixed_size_sol,
+        proportional_percent: config.proportional_percent,
+        min_size_sol: config.min_size_sol,
+        max_size_sol: config.max_size_sol,
+    };
+    if let Some(value) = leader.size_value.filter(|v| *v > 0.0) {
+        match mode {
+            SizingMode::Fixed => params.fixed_size_sol = value,
+            SizingMode::Proportional => params.proportional_percent = value,
+        }
+    }
+    params
+}
+
+fn trade_outcome(result: Result<crate::trader::TradeResult, String>) -> CopyTradeOutcome {
+    match result {
+        Ok(result) if result.success => CopyTradeOutcome {
+            status: "executed".to_string(),
+            our_signature: result.tx_signature,
+            ..Default::default()
+        },
+        Ok(result) => CopyTradeOutcome {
+            status: "failed".to_string(),
+            our_signature: result.tx_signature,
+            reason: Some(result.error.unwrap_or_else(|| "Trade failed".to_string())),
+            ..Default::default()
+        },
+        Err(e) => CopyTradeOutcome {
+            status: "failed".to_string(),
+            reason: Some(e),
+            ..Default::default()
+        },
+    }
+}
+
+fn skipped(reason: &str) -> CopyTradeOutcome {
+    CopyTradeOutcome {
+        status: "skipped".to_string(),
+        reason: Some(reason.to_string()),
+        ..Default::default()
+    }
+}
+
+fn display_leader(leader: &CopyLeaderRow) -> &str {
+    leader.label.as_deref().unwrap_or(&leader.address)
+}
+
+/// Human readable summary of a copy trade outcome
+fn describe_outcome(swap: &LeaderSwap, outcome: &CopyTradeOutcome) -> String {
+    let leader_part = match swap.side {
+        CopySide::Buy => format!("Leader bought {:.4} SOL", swap.sol_amount),
+        CopySide::Sell => format!(
+            "Leader sold {:.0}% for {:.4} SOL",
+            swap.sell_pct.unwrap_or(100.0),
+            swap.sol_amount
+        ),
+    };
+    let our_part = match (outcome.status.as_str(), swap.side) {
+        ("executed", CopySide::Buy) => {
+            format!("Copied: {:.4} SOL", outcome.our_size_sol.unwrap_or(0.0))
+        }
+        ("executed", CopySide::Sell) => {
+            format!("Copied: sold {:.0}%", outcome.our_sell_pct.unwrap_or(100.0))
+        }
+        _ => outcome.reason.clone().unwrap_or_default(),
+    };
+    format!("{}\n{}", leader_part, our_part)
+}
@@ -0,0 +1,69 @@
+//! Per-leader PnL attribution
+//!
+//! Every executed copy buy is linked to the position it opened or added to.
+//! A leader is credited with the share of each position's PnL matching the
+//! SOL its copied buys contributed to the position's cost basis.
+
+use std::collections::HashMap;
+
+use super::types::{attribute_pnl, LeaderPnl};
+use crate::tools::database::{get_copy_leaders, get_executed_copy_buys};
+
+/// Attribute realized and unrealized PnL of copied positions to their leaders
+pub async fn get_leader_pnl() -> Result<Vec<LeaderPnl>, String> {
+    let buys = get_executed_copy_buys()?;
+    let labels: HashMap<String, Option<String>> = get_copy_leaders(false)?
+        .into_iter()
+        .map(|leader| (leader.address, leader.label))
+        .collect();
+
+    // position id -> leader -> SOL contributed
+    let mut contributions: HashMap<i64, HashMap<String, f64>> = HashMap::new();
+    for buy in buys {
+        if let Some(position_id) = buy.position_id {
+            *contributions
+                .entry(position_id)
+                .or_default()
+                .entry(buy.leader_address)
+                .or_insert(0.0) += buy.our_size_sol.unwrap_or(0.0);
+        }
+    }
+
+    let mut by_leader: HashMap<String, LeaderPnl> = HashMap::new();
+    for (position_id, leaders) in contributions {
+        let position = match crate::positions::get_db_position_by_id(position_id).await? {
+            Some(position) => position,
+            None => continue,
+        };
+        let is_open = position.exit_time.is_none();
+        let position_pnl = if is_open {
+            position.unrealized_pnl.unwrap_or(0.0)
+        } else {
+            position.pnl.unwrap_or(0.0)
+        };
+
+        for (leader, contributed_sol) in leaders {
+            let share = attribute_pnl(contributed_sol, position.total_size_sol, position_pnl);
+            let entry = by_leader
+                .entry(leader.clone())
+                .or_insert_with(|| LeaderPnl {
+                    label: labels.get(&leader).cloned().flatten(),
+                    leader_address: leader,
+                    ..Default::default()
+                });
+            entry.positions += 1;
+            entry.invested_sol += contributed_sol;
+            if is_open {
+                entry.open_positions += 1;
+                entry.unrealized_pnl_sol += share;
+            } else {
+                entry.realized_pnl_sol += share;
+            }
+            entry.total_pnl_sol += share;
+        }
+    }
+
+    let mut result: Vec<LeaderPnl> = by_leader.into_values().collect();
+    result.sort_by(|a, b| b.total_pnl_sol.total_cmp(&a.total_pnl_sol));
+    Ok(result)
+}
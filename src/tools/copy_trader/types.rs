@@ -0,0 +1,168 @@
+//! Copy Trader types and sizing helpers
+
+use serde::Serialize;
+
+/// Direction of a leader swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CopySide {
+    Buy,
+    Sell,
+}
+
+impl CopySide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CopySide::Buy => "buy",
+            CopySide::Sell => "sell",
+        }
+    }
+}
+
+/// A swap detected in a followed wallet's transaction
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderSwap {
+    pub leader: String,
+    pub signature: String,
+    pub mint: String,
+    pub side: CopySide,
+    /// SOL spent (buy) or received (sell) by the leader
+    pub sol_amount: f64,
+    /// Tokens bought or sold by the leader (UI amount)
+    pub token_amount: f64,
+    /// Share of the leader's holding that was sold (sells only)
+    pub sell_pct: Option<f64>,
+}
+
+impl LeaderSwap {
+    /// Effective fill price of the leader in SOL per token
+    pub fn price_sol(&self) -> Option<f64> {
+        if self.sol_amount > 0.0 && self.token_amount > 0.0 {
+            Some(self.sol_amount / self.token_amount)
+        } else {
+            None
+        }
+    }
+}
+
+/// How the size of a mirrored buy is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SizingMode {
+    /// Always spend the same SOL amount
+    Fixed,
+    /// Spend a percentage of the leader's SOL amount
+    Proportional,
+}
+
+impl SizingMode {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "fixed" => Some(SizingMode::Fixed),
+            "proportional" => Some(SizingMode::Proportional),
+            _ => None,
+        }
+    }
+}
+
+/// Resolved sizing for one leader (config defaults merged with leader overrides)
+#[derive(Debug, Clone, Copy)]
+pub struct SizingParams {
+    pub mode: SizingMode,
+    pub fixed_size_sol: f64,
+    pub proportional_percent: f64,
+    pub min_size_sol: f64,
+    pub max_size_sol: f64,
+}
+
+/// SOL to spend when mirroring a leader buy of `leader_sol`
+///
+/// Fixed sizes are only capped; proportional sizes are clamped to the min/max band.
+pub fn compute_copy_size(leader_sol: f64, params: &SizingParams) -> f64 {
+    match params.mode {
+        SizingMode::Fixed => params.fixed_size_sol.min(params.max_size_sol),
+        SizingMode::Proportional => (leader_sol * params.proportional_percent / 100.0)
+            .clamp(params.min_size_sol, params.max_size_sol),
+    }
+}
+
+/// Whether our current price moved too far above the leader's fill to still buy
+pub fn exceeds_slippage(leader_price_sol: f64, our_price_sol: f64, max_slippage_pct: f64) -> bool {
+    our_price_sol > leader_price_sol * (1.0 + max_slippage_pct / 100.0)
+}
+
+/// Share of a position's PnL that belongs to `contributed_sol` of its cost basis
+pub fn attribute_pnl(contributed_sol: f64, total_size_sol: f64, position_pnl: f64) -> f64 {
+    if total_size_sol <= 0.0 {
+        return 0.0;
+    }
+    position_pnl * (contributed_sol / total_size_sol).min(1.0)
+}
+
+/// Copy Trader status for the API
+#[derive(Debug, Clone, Serialize)]
+pub struct CopyTraderStatus {
+    pub enabled: bool,
+    pub is_running: bool,
+    pub active_leaders: usize,
+    pub subscribed_leaders: usize,
+    pub max_leaders: usize,
+    pub swaps_detected: u64,
+    pub trades_copied: u64,
+    pub trades_skipped: u64,
+    pub last_trade_at: Option<String>,
+    pub last_error: Option<String>,
+}
+
+/// PnL of our positions attributed to one leader
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LeaderPnl {
+    pub leader_address: String,
+    pub label: Option<String>,
+    pub positions: usize,
+    pub open_positions: usize,
+    pub invested_sol: f64,
+    pub realized_pnl_sol: f64,
+    pub unrealized_pnl_sol: f64,
+    pub total_pnl_sol: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(mode: SizingMode) -> SizingParams {
+        SizingParams {
+            mode,
+            fixed_size_sol: 0.05,
+            proportional_percent: 10.0,
+            min_size_sol: 0.01,
+            max_size_sol: 0.2,
+        }
+    }
+
+    #[test]
+    fn test_compute_copy_size() {
+        assert_eq!(compute_copy_size(5.0, &params(SizingMode::Fixed)), 0.05);
+        assert!((compute_copy_size(1.0, &params(SizingMode::Proportional)) - 0.1).abs() < 1e-9);
+        assert_eq!(
+            compute_copy_size(0.01, &params(SizingMode::Proportional)),
+            0.01
+        );
+        assert_eq!(
+            compute_copy_size(50.0, &params(SizingMode::Proportional)),
+            0.2
+        );
+    }
+
+    #[test]
+    fn test_slippage_and_attribution() {
+        assert!(!exceeds_slippage(1.0, 1.09, 10.0));
+        assert!(exceeds_slippage(1.0, 1.11, 10.0));
+        assert!(!exceeds_slippage(1.0, 0.5, 10.0));
+
+        assert!((attribute_pnl(0.5, 1.0, 0.2) - 0.1).abs() < 1e-9);
+        assert_eq!(attribute_pnl(2.0, 1.0, 0.2), 0.2);
+        assert_eq!(attribute_pnl(1.0, 0.0, 0.2), 0.0);
+    }
+}
@@ -4,15 +4,18 @@
 //! - Volume Aggregator sessions and swaps
 //! - ATA cleanup sessions and closures
 //! - Failed ATA cache
+//! - Holder Watch tokens, snapshots and events
+//! - Copy Trader leaders and mirrored trades
 
 use chrono::{DateTime, Utc};
 use once_cell::sync::Lazy;
 use r2d2::{Pool, PooledConnection};
 use r2d2_sqlite::SqliteConnectionManager;
-use rusqlite::{params, OptionalExtension};
+use rusqlite::{params, Connection, OptionalExtension};
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::logger::{self, LogTag};
+use crate::migrations::{self, Migration, MigrationSet};
 use crate::paths::get_tools_db_path;
 
 use super::types::{DelayConfig, DistributionStrategy, SizingConfig, ToolStatus, WalletMode};
@@ -21,8 +24,18 @@ use super::types::{DelayConfig, DistributionStrategy, SizingConfig, ToolStatus,
 // CONSTANTS
 // =============================================================================
 
-/// Schema version for migrations
-const TOOLS_SCHEMA_VERSION: u32 = 1;
+/// Ordered schema migrations of tools.db
+pub const TOOLS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "tools",
+    migrations: &[
+        Migration::apply(1, "Baseline schema", baseline_schema),
+        Migration::sql(
+            2,
+            "Drop pre-versioning schema_version table",
+            "DROP TABLE IF EXISTS schema_version;",
+        ),
+    ],
+};
 
 /// Connection pool configuration
 const POOL_MAX_SIZE: u32 = 10;
@@ -36,13 +49,6 @@ static TOOLS_DB_INITIALIZED: AtomicBool = AtomicBool::new(false);
 // SCHEMA DEFINITIONS
 // =============================================================================
 
-const SCHEMA_VERSION_TABLE: &str = r#"
-CREATE TABLE IF NOT EXISTS schema_version (
-    version INTEGER PRIMARY KEY,
-    applied_at TEXT NOT NULL
-);
-"#;
-
 /// Volume Aggregator sessions table
 const SCHEMA_VA_SESSIONS: &str = r#"
 CREATE TABLE IF NOT EXISTS va_sessions (
@@ -345,6 +351,117 @@ CREATE INDEX IF NOT EXISTS idx_watched_tokens_mint ON watched_tokens(mint);
 CREATE INDEX IF NOT EXISTS idx_watched_tokens_active ON watched_tokens(is_active);
 "#;
 
+/// Holder Watch tokens table
+const SCHEMA_HOLDER_WATCH_TOKENS: &str = r#"
+CREATE TABLE IF NOT EXISTS holder_watch_tokens (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    mint TEXT NOT NULL UNIQUE,
+    symbol TEXT,
+    is_active INTEGER NOT NULL DEFAULT 1,
+    
+    -- Tracking
+    holder_count INTEGER,
+    top10_pct REAL,
+    last_checked_at TEXT,
+    alerts_raised INTEGER DEFAULT 0,
+    
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_holder_watch_tokens_active ON holder_watch_tokens(is_active);
+"#;
+
+/// Holder Watch snapshots table (holder set per check)
+const SCHEMA_HOLDER_SNAPSHOTS: &str = r#"
+CREATE TABLE IF NOT EXISTS holder_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    mint TEXT NOT NULL,
+    holder_count INTEGER,
+    supply_ui REAL NOT NULL DEFAULT 0,
+    top10_pct REAL NOT NULL DEFAULT 0,
+    top_holders_json TEXT NOT NULL,
+    captured_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_holder_snapshots_mint ON holder_snapshots(mint);
+CREATE INDEX IF NOT EXISTS idx_holder_snapshots_captured ON holder_snapshots(captured_at);
+"#;
+
+/// Holder Watch events table (alerts raised from snapshot diffs)
+const SCHEMA_HOLDER_EVENTS: &str = r#"
+CREATE TABLE IF NOT EXISTS holder_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    mint TEXT NOT NULL,
+    symbol TEXT,
+    event_type TEXT NOT NULL,
+    holder_count INTEGER,
+    previous_count INTEGER,
+    change_pct REAL NOT NULL DEFAULT 0,
+    wallet TEXT,
+    share_pct REAL,
+    message TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_holder_events_mint ON holder_events(mint);
+CREATE INDEX IF NOT EXISTS idx_holder_events_created ON holder_events(created_at);
+"#;
+
+/// Copy Trader leaders table (followed wallets)
+const SCHEMA_COPY_LEADERS: &str = r#"
+CREATE TABLE IF NOT EXISTS copy_leaders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    address TEXT NOT NULL UNIQUE,
+    label TEXT,
+    is_active INTEGER NOT NULL DEFAULT 1,
+    
+    -- Per-leader sizing overrides (NULL = use copy_trade config)
+    sizing_mode TEXT,
+    size_value REAL,
+    
+    -- Tracking
+    trades_copied INTEGER DEFAULT 0,
+    trades_skipped INTEGER DEFAULT 0,
+    last_trade_at TEXT,
+    
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_copy_leaders_active ON copy_leaders(is_active);
+"#;
+
+/// Copy Trader trades table (one row per detected leader swap)
+const SCHEMA_COPY_TRADES: &str = r#"
+CREATE TABLE IF NOT EXISTS copy_trades (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    leader_address TEXT NOT NULL,
+    leader_signature TEXT NOT NULL UNIQUE,
+    mint TEXT NOT NULL,
+    symbol TEXT,
+    side TEXT NOT NULL, -- 'buy' or 'sell'
+    leader_sol REAL NOT NULL DEFAULT 0,
+    leader_price_sol REAL,
+    leader_sell_pct REAL,
+    
+    -- Our mirrored trade
+    our_size_sol REAL,
+    our_sell_pct REAL,
+    our_signature TEXT,
+    position_id INTEGER,
+    status TEXT NOT NULL, -- 'pending', 'executed', 'skipped', 'failed'
+    reason TEXT,
+    
+    created_at TEXT NOT NULL,
+    completed_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_copy_trades_leader ON copy_trades(leader_address);
+CREATE INDEX IF NOT EXISTS idx_copy_trades_mint ON copy_trades(mint);
+CREATE INDEX IF NOT EXISTS idx_copy_trades_created ON copy_trades(created_at);
+"#;
+
 // =============================================================================
 // CONNECTION POOL
 // =============================================================================
@@ -399,68 +516,55 @@ pub fn init_tools_db() -> Result<(), String> {
     )
     .map_err(|e| format!("Failed to set pragmas: {}", e))?;
 
-    // Create version table first
-    conn.execute_batch(SCHEMA_VERSION_TABLE)
-        .map_err(|e| format!("Failed to create version table: {}", e))?;
+    migrations::run_migrations(&conn, &TOOLS_MIGRATIONS)?;
 
-    // Check current schema version
-    let current_version: Option<u32> = conn
-        .query_row(
-            "SELECT version FROM schema_version ORDER BY version DESC LIMIT 1",
-            [],
-            |row| row.get(0),
-        )
-        .optional()
-        .map_err(|e| format!("Failed to check schema version: {}", e))?;
+    TOOLS_DB_INITIALIZED.store(true, Ordering::SeqCst);
+    Ok(())
+}
 
-    if current_version.is_none() || current_version.unwrap() < TOOLS_SCHEMA_VERSION {
-        // Create all tables
-        conn.execute_batch(SCHEMA_VA_SESSIONS)
-            .map_err(|e| format!("Failed to create va_sessions table: {}", e))?;
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(SCHEMA_VA_SESSIONS)
+        .map_err(|e| format!("Failed to create va_sessions table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_VA_SWAPS)
-            .map_err(|e| format!("Failed to create va_swaps table: {}", e))?;
+    conn.execute_batch(SCHEMA_VA_SWAPS)
+        .map_err(|e| format!("Failed to create va_swaps table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_ATA_SESSIONS)
-            .map_err(|e| format!("Failed to create ata_sessions table: {}", e))?;
+    conn.execute_batch(SCHEMA_ATA_SESSIONS)
+        .map_err(|e| format!("Failed to create ata_sessions table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_ATA_CLOSURES)
-            .map_err(|e| format!("Failed to create ata_closures table: {}", e))?;
+    conn.execute_batch(SCHEMA_ATA_CLOSURES)
+        .map_err(|e| format!("Failed to create ata_closures table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_ATA_FAILED_CACHE)
-            .map_err(|e| format!("Failed to create ata_failed_cache table: {}", e))?;
+    conn.execute_batch(SCHEMA_ATA_FAILED_CACHE)
+        .map_err(|e| format!("Failed to create ata_failed_cache table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_TOOL_FAVORITES)
-            .map_err(|e| format!("Failed to create tool_favorites table: {}", e))?;
+    conn.execute_batch(SCHEMA_TOOL_FAVORITES)
+        .map_err(|e| format!("Failed to create tool_favorites table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_MW_SESSIONS)
-            .map_err(|e| format!("Failed to create mw_sessions table: {}", e))?;
+    conn.execute_batch(SCHEMA_MW_SESSIONS)
+        .map_err(|e| format!("Failed to create mw_sessions table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_MW_WALLET_OPS)
-            .map_err(|e| format!("Failed to create mw_wallet_ops table: {}", e))?;
+    conn.execute_batch(SCHEMA_MW_WALLET_OPS)
+        .map_err(|e| format!("Failed to create mw_wallet_ops table: {}", e))?;
 
-        conn.execute_batch(SCHEMA_WATCHED_TOKENS)
-            .map_err(|e| format!("Failed to create watched_tokens table: {}", e))?;
+    conn.execute_batch(SCHEMA_WATCHED_TOKENS)
+        .map_err(|e| format!("Failed to create watched_tokens table: {}", e))?;
 
-        // Update version
-        conn.execute(
-            "INSERT INTO schema_version (version, applied_at) VALUES (?1, ?2)",
-            params![TOOLS_SCHEMA_VERSION, Utc::now().to_rfc3339()],
-        )
-        .map_err(|e| format!("Failed to update schema version: {}", e))?;
-
-        logger::info(
-            LogTag::System,
-            &format!(
-                "Tools database initialized at {} (schema v{})",
-                get_tools_db_path().display(),
-                TOOLS_SCHEMA_VERSION
-            ),
-        );
-    }
+    conn.execute_batch(SCHEMA_HOLDER_WATCH_TOKENS)
+        .map_err(|e| format!("Failed to create holder_watch_tokens table: {}", e))?;
 
-    TOOLS_DB_INITIALIZED.store(true, Ordering::SeqCst);
-    Ok(())
+    conn.execute_batch(SCHEMA_HOLDER_SNAPSHOTS)
+        .map_err(|e| format!("Failed to create holder_snapshots table: {}", e))?;
+
+    conn.execute_batch(SCHEMA_HOLDER_EVENTS)
+        .map_err(|e| format!("Failed to create holder_events table: {}", e))?;
+
+    conn.execute_batch(SCHEMA_COPY_LEADERS)
+        .map_err(|e| format!("Failed to create copy_leaders table: {}", e))?;
+
+    conn.execute_batch(SCHEMA_COPY_TRADES)
+        .map_err(|e| format!("Failed to create copy_trades table: {}", e))
 }
 
 // =============================================================================
@@ -1907,3 +2011,718 @@ pub fn update_watched_token_tracking(
 
     Ok(())
 }
+
+// =============================================================================
+// HOLDER WATCH OPERATIONS
+// =============================================================================
+
+/// Holder Watch token database row
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HolderWatchToken {
+    pub id: i64,
+    pub mint: String,
+    pub symbol: Option<String>,
+    pub is_active: bool,
+    pub holder_count: Option<i64>,
+    pub top10_pct: Option<f64>,
+    pub last_checked_at: Option<String>,
+    pub alerts_raised: i32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl HolderWatchToken {
+    fn from_row(row: &rusqlite::Row<'_>) -> Result<Self, String> {
+        let is_active_int: i32 = row.get(3).map_err(|e| e.to_string())?;
+        Ok(Self {
+            id: row.get(0).map_err(|e| e.to_string())?,
+            mint: row.get(1).map_err(|e| e.to_string())?,
+            symbol: row.get(2).map_err(|e| e.to_string())?,
+            is_active: is_active_int != 0,
+            holder_count: row.get(4).map_err(|e| e.to_string())?,
+            top10_pct: row.get(5).map_err(|e| e.to_string())?,
+            last_checked_at: row.get(6).map_err(|e| e.to_string())?,
+            alerts_raised: row.get(7).map_err(|e| e.to_string())?,
+            created_at: row.get(8).map_err(|e| e.to_string())?,
+            updated_at: row.get(9).map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+/// Holder snapshot database row
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HolderSnapshotRow {
+    pub id: i64,
+    pub mint: String,
+    pub holder_count: Option<i64>,
+    pub supply_ui: f64,
+    pub top10_pct: f64,
+    pub top_holders_json: String,
+    pub captured_at: String,
+}
+
+/// Holder event database row
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct HolderEventRow {
+    pub id: i64,
+    pub mint: String,
+    pub symbol: Option<String>,
+    pub event_type: String,
+    pub holder_count: Option<i64>,
+    pub previous_count: Option<i64>,
+    pub change_pct: f64,
+    pub wallet: Option<String>,
+    pub share_pct: Option<f64>,
+    pub message: String,
+    pub created_at: String,
+}
+
+const HOLDER_WATCH_TOKEN_COLUMNS: &str = "id, mint, symbol, is_active, holder_count, top10_pct, \
+     last_checked_at, alerts_raised, created_at, updated_at";
+
+/// Add a token to Holder Watch (re-activates an existing entry for the same mint)
+pub fn add_holder_watch_token(mint: &str, symbol: Option<&str>) -> Result<i64, String> {
+    let conn = get_connection()?;
+    let now = Utc::now().to_rfc3339();
+
+    conn.execute(
+        r#"
+        INSERT INTO holder_watch_tokens (mint, symbol, is_active, created_at, updated_at)
+        VALUES (?1, ?2, 1, ?3, ?3)
+        ON CONFLICT(mint) DO UPDATE SET
+            symbol = COALESCE(excluded.symbol, symbol),
+            is_active = 1,
+            updated_at = excluded.updated_at
+        "#,
+        params![mint, symbol, now],
+    )
+    .map_err(|e| format!("Failed to add holder watch token: {}", e))?;
+
+    conn.query_row(
+        "SELECT id FROM holder_watch_tokens WHERE mint = ?1",
+        params![mint],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Failed to read holder watch token id: {}", e))
+}
+
+/// Get Holder Watch tokens, optionally only active ones
+pub fn get_holder_watch_tokens(active_only: bool) -> Result<Vec<HolderWatchToken>, String> {
+    let conn = get_connection()?;
+
+    let query = format!(
+        "SELECT {} FROM holder_watch_tokens {} ORDER BY created_at ASC",
+        HOLDER_WATCH_TOKEN_COLUMNS,
+        if active_only {
+            "WHERE is_active = 1"
+        } else {
+            ""
+        }
+    );
+
+    let mut stmt = conn
+        .prepare(&query)
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map([], |row| Ok(HolderWatchToken::from_row(row)))
+        .map_err(|e| format!("Failed to query holder watch tokens: {}", e))?;
+
+    let mut tokens = Vec::new();
+    for row in rows {
+        match row {
+            Ok(Ok(token)) => tokens.push(token),
+            Ok(Err(e)) => return Err(e),
+            Err(e) => return Err(format!("Failed to read row: {}", e)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Update Holder Watch token active status
+pub fn update_holder_watch_token_status(id: i64, is_active: bool) -> Result<(), String> {
+    let conn = get_connection()?;
+    let now = Utc::now().to_rfc3339();
+
+    conn.execute(
+        "UPDATE holder_watch_tokens SET is_active = ?1, updated_at = ?2 WHERE id = ?3",
+        params![is_active as i32, now, id],
+    )
+    .map_err(|e| format!("Failed to update holder watch token status: {}", e))?;
+
+    Ok(())
+}
+
+/// Delete a Holder Watch token and its stored history
+pub fn delete_holder_watch_token(id: i64) -> Result<(), String> {
+    let conn = get_connection()?;
+
+    let mint: Option<String> = conn
+        .query_row(
+            "SELECT mint FROM holder_watch_tokens WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to look up holder watch token: {}", e))?;
+
+    if let Some(mint) = mint {
+        conn.execute(
+            "DELETE FROM holder_snapshots WHERE mint = ?1",
+            params![mint],
+        )
+        .map_err(|e| format!("Failed to delete holder snapshots: {}", e))?;
+        conn.execute("DELETE FROM holder_events WHERE mint = ?1", params![mint])
+            .map_err(|e| format!("Failed to delete holder events: {}", e))?;
+    }
+
+    conn.execute("DELETE FROM holder_watch_tokens WHERE id = ?1", params![id])
+        .map_err(|e| format!("Failed to delete holder watch token: {}", e))?;
+
+    Ok(())
+}
+
+/// Update Holder Watch token tracking after a check
+pub fn update_holder_watch_tracking(
+    id: i64,
+    holder_count: Option<i64>,
+    top10_pct: f64,
+    alerts_raised: i32,
+) -> Result<(), String> {
+    let conn = get_connection()?;
+    let now = Utc::now().to_rfc3339();
+
+    conn.execute(
+        r#"
+        UPDATE holder_watch_tokens SET
+            holder_count = COALESCE(?1, holder_count),
+            top10_pct = ?2,
+            last_checked_at = ?3,
+            alerts_raised = alerts_raised + ?4,
+            updated_at = ?3
+        WHERE id = ?5
+        "#,
+        params![holder_count, top10_pct, now, alerts_raised, id],
+    )
+    .map_err(|e| format!("Failed to update holder watch tracking: {}", e))?;
+
+    Ok(())
+}
+
+/// Insert a holder snapshot and trim history beyond `keep` snapshots for the mint
+pub fn insert_holder_snapshot(
+    mint: &str,
+    holder_count: Option<i64>,
+    supply_ui: f64,
+    top10_pct: f64,
+    top_holders_json: &str,
+    keep: i64,
+) -> Result<i64, String> {
+    let conn = get_connection()?;
+
+    conn.execute(
+        r#"
+        INSERT INTO holder_snapshots (
+            mint, holder_count, supply_ui, top10_pct, top_holders_json, captured_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        "#,
+        params![
+            mint,
+            holder_count,
+            supply_ui,
+            top10_pct,
+            top_holders_json,
+            Utc::now().to_rfc3339()
+        ],
+    )
+    .map_err(|e| format!("Failed to insert holder snapshot: {}", e))?;
+
+    let id = conn.last_insert_rowid();
+
+    conn.execute(
+        r#"
+        DELETE FROM holder_snapshots
+        WHERE mint = ?1 AND id NOT IN (
+            SELECT id FROM holder_snapshots WHERE mint = ?1 ORDER BY id DESC LIMIT ?2
+        )
+        "#,
+        params![mint, keep],
+    )
+    .map_err(|e| format!("Failed to trim holder snapshots: {}", e))?;
+
+    Ok(id)
+}
+
+/// Get the most recent holder snapshots for a mint (newest first)
+pub fn get_holder_snapshots(mint: &str, limit: i64) -> Result<Vec<HolderSnapshotRow>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT id, mint, holder_count, supply_ui, top10_pct, top_holders_json, captured_at
+            FROM holder_snapshots
+            WHERE mint = ?1
+            ORDER BY id DESC
+            LIMIT ?2
+            "#,
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map(params![mint, limit], |row| {
+            Ok(HolderSnapshotRow {
+                id: row.get(0)?,
+                mint: row.get(1)?,
+                holder_count: row.get(2)?,
+                supply_ui: row.get(3)?,
+                top10_pct: row.get(4)?,
+                top_holders_json: row.get(5)?,
+                captured_at: row.get(6)?,
+            })
+        })
+        .map_err(|e| format!("Failed to query holder snapshots: {}", e))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read row: {}", e))
+}
+
+/// Record a holder event raised from a snapshot diff
+pub fn insert_holder_event(event: &HolderEventRow) -> Result<i64, String> {
+    let conn = get_connection()?;
+
+    conn.execute(
+        r#"
+        INSERT INTO holder_events (
+            mint, symbol, event_type, holder_count, previous_count, change_pct,
+            wallet, share_pct, message, created_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+        "#,
+        params![
+            event.mint,
+            event.symbol,
+            event.event_type,
+            event.holder_count,
+            event.previous_count,
+            event.change_pct,
+            event.wallet,
+            event.share_pct,
+            event.message,
+            event.created_at,
+        ],
+    )
+    .map_err(|e| format!("Failed to insert holder event: {}", e))?;
+
+    Ok(conn.last_insert_rowid())
+}
+
+/// Get recent holder events, optionally filtered by mint (newest first)
+pub fn get_holder_events(mint: Option<&str>, limit: i64) -> Result<Vec<HolderEventRow>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT id, mint, symbol, event_type, holder_count, previous_count, change_pct,
+                   wallet, share_pct, message, created_at
+            FROM holder_events
+            WHERE ?1 IS NULL OR mint = ?1
+            ORDER BY id DESC
+            LIMIT ?2
+            "#,
+        )
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map(params![mint, limit], |row| {
+            Ok(HolderEventRow {
+                id: row.get(0)?,
+                mint: row.get(1)?,
+                symbol: row.get(2)?,
+                event_type: row.get(3)?,
+                holder_count: row.get(4)?,
+                previous_count: row.get(5)?,
+                change_pct: row.get(6)?,
+                wallet: row.get(7)?,
+                share_pct: row.get(8)?,
+                message: row.get(9)?,
+                created_at: row.get(10)?,
+            })
+        })
+        .map_err(|e| format!("Failed to query holder events: {}", e))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read row: {}", e))
+}
+
+// =============================================================================
+// COPY TRADE OPERATIONS
+// =============================================================================
+
+/// Copy Trader leader database row
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CopyLeaderRow {
+    pub id: i64,
+    pub address: String,
+    pub label: Option<String>,
+    pub is_active: bool,
+    pub sizing_mode: Option<String>,
+    pub size_value: Option<f64>,
+    pub trades_copied: i32,
+    pub trades_skipped: i32,
+    pub last_trade_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl CopyLeaderRow {
+    fn from_row(row: &rusqlite::Row<'_>) -> Result<Self, String> {
+        let is_active_int: i32 = row.get(3).map_err(|e| e.to_string())?;
+        Ok(Self {
+            id: row.get(0).map_err(|e| e.to_string())?,
+            address: row.get(1).map_err(|e| e.to_string())?,
+            label: row.get(2).map_err(|e| e.to_string())?,
+            is_active: is_active_int != 0,
+            sizing_mode: row.get(4).map_err(|e| e.to_string())?,
+            size_value: row.get(5).map_err(|e| e.to_string())?,
+            trades_copied: row.get(6).map_err(|e| e.to_string())?,
+            trades_skipped: row.get(7).map_err(|e| e.to_string())?,
+            last_trade_at: row.get(8).map_err(|e| e.to_string())?,
+            created_at: row.get(9).map_err(|e| e.to_string())?,
+            updated_at: row.get(10).map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+/// Copy Trader trade database row
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CopyTradeRow {
+    pub id: i64,
+    pub leader_address: String,
+    pub leader_signature: String,
+    pub mint: String,
+    pub symbol: Option<String>,
+    pub side: String,
+    pub leader_sol: f64,
+    pub leader_price_sol: Option<f64>,
+    pub leader_sell_pct: Option<f64>,
+    pub our_size_sol: Option<f64>,
+    pub our_sell_pct: Option<f64>,
+    pub our_signature: Option<String>,
+    pub position_id: Option<i64>,
+    pub status: String,
+    pub reason: Option<String>,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+}
+
+impl CopyTradeRow {
+    fn from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            leader_address: row.get(1)?,
+            leader_signature: row.get(2)?,
+            mint: row.get(3)?,
+            symbol: row.get(4)?,
+            side: row.get(5)?,
+            leader_sol: row.get(6)?,
+            leader_price_sol: row.get(7)?,
+            leader_sell_pct: row.get(8)?,
+            our_size_sol: row.get(9)?,
+            our_sell_pct: row.get(10)?,
+            our_signature: row.get(11)?,
+            position_id: row.get(12)?,
+            status: row.get(13)?,
+            reason: row.get(14)?,
+            created_at: row.get(15)?,
+            completed_at: row.get(16)?,
+        })
+    }
+}
+
+/// Final state of a copy trade
+#[derive(Debug, Clone, Default)]
+pub struct CopyTradeOutcome {
+    /// 'executed', 'skipped' or 'failed'
+    pub status: String,
+    pub symbol: Option<String>,
+    pub our_size_sol: Option<f64>,
+    pub our_sell_pct: Option<f64>,
+    pub our_signature: Option<String>,
+    pub position_id: Option<i64>,
+    pub reason: Option<String>,
+}
+
+const COPY_LEADER_COLUMNS: &str = "id, address, label, is_active, sizing_mode, size_value, \
+     trades_copied, trades_skipped, last_trade_at, created_at, updated_at";
+
+const COPY_TRADE_COLUMNS: &str = "id, leader_address, leader_signature, mint, symbol, side, \
+     leader_sol, leader_price_sol, leader_sell_pct, our_size_sol, our_sell_pct, our_signature, \
+     position_id, status, reason, created_at, completed_at";
+
+/// Follow a wallet (re-activates and updates an existing entry for the same address)
+pub fn add_copy_leader(
+    address: &str,
+    label: Option<&str>,
+    sizing_mode: Option<&str>,
+    size_value: Option<f64>,
+) -> Result<i64, String> {
+    let conn = get_connection()?;
+    let now = Utc::now().to_rfc3339();
+
+    conn.execute(
+        r#"
+        INSERT INTO copy_leaders (address, label, is_active, sizing_mode, size_value, created_at, updated_at)
+        VALUES (?1, ?2, 1, ?3, ?4, ?5, ?5)
+        ON CONFLICT(address) DO UPDATE SET
+            label = COALESCE(excluded.label, label),
+            is_active = 1,
+            sizing_mode = excluded.sizing_mode,
+            size_value = excluded.size_value,
+            updated_at = excluded.updated_at
+        "#,
+        params![address, label, sizing_mode, size_value, now],
+    )
+    .map_err(|e| format!("Failed to add copy leader: {}", e))?;
+
+    conn.query_row(
+        "SELECT id FROM copy_leaders WHERE address = ?1",
+        params![address],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Failed to read copy leader id: {}", e))
+}
+
+/// Get followed wallets, optionally only active ones
+pub fn get_copy_leaders(active_only: bool) -> Result<Vec<CopyLeaderRow>, String> {
+    let conn = get_connection()?;
+
+    let query = format!(
+        "SELECT {} FROM copy_leaders {} ORDER BY created_at ASC",
+        COPY_LEADER_COLUMNS,
+        if active_only {
+            "WHERE is_active = 1"
+        } else {
+            ""
+        }
+    );
+
+    let mut stmt = conn
+        .prepare(&query)
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map([], |row| Ok(CopyLeaderRow::from_row(row)))
+        .map_err(|e| format!("Failed to query copy leaders: {}", e))?;
+
+    let mut leaders = Vec::new();
+    for row in rows {
+        match row {
+            Ok(Ok(leader)) => leaders.push(leader),
+            Ok(Err(e)) => return Err(e),
+            Err(e) => return Err(format!("Failed to read row: {}", e)),
+        }
+    }
+
+    Ok(leaders)
+}
+
+/// Get a followed wallet by address
+pub fn get_copy_leader(address: &str) -> Result<Option<CopyLeaderRow>, String> {
+    let conn = get_connection()?;
+
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM copy_leaders WHERE address = ?1",
+            COPY_LEADER_COLUMNS
+        ),
+        params![address],
+        |row| Ok(CopyLeaderRow::from_row(row)),
+    )
+    .optional()
+    .map_err(|e| format!("Failed to query copy leader: {}", e))?
+    .transpose()
+}
+
+/// Update a followed wallet's active status
+pub fn update_copy_leader_status(id: i64, is_active: bool) -> Result<(), String> {
+    let conn = get_connection()?;
+    let now = Utc::now().to_rfc3339();
+
+    conn.execute(
+        "UPDATE copy_leaders SET is_active = ?1, updated_at = ?2 WHERE id = ?3",
+        params![is_active as i32, now, id],
+    )
+    .map_err(|e| format!("Failed to update copy leader status: {}", e))?;
+
+    Ok(())
+}
+
+/// Stop following a wallet (its trade history is kept for PnL attribution)
+pub fn delete_copy_leader(id: i64) -> Result<(), String> {
+    let conn = get_connection()?;
+
+    conn.execute("DELETE FROM copy_leaders WHERE id = ?1", params![id])
+        .map_err(|e| format!("Failed to delete copy leader: {}", e))?;
+
+    Ok(())
+}
+
+/// Claim a leader swap for processing. Returns the new row id, or None if the
+/// signature was already seen (duplicate websocket notification or restart).
+pub fn claim_copy_trade(
+    leader_address: &str,
+    leader_signature: &str,
+    mint: &str,
+    side: &str,
+    leader_sol: f64,
+    leader_price_sol: Option<f64>,
+    leader_sell_pct: Option<f64>,
+) -> Result<Option<i64>, String> {
+    let conn = get_connection()?;
+
+    let inserted = conn
+        .execute(
+            r#"
+            INSERT OR IGNORE INTO copy_trades (
+                leader_address, leader_signature, mint, side, leader_sol,
+                leader_price_sol, leader_sell_pct, status, created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 'pending', ?8)
+            "#,
+            params![
+                leader_address,
+                leader_signature,
+                mint,
+                side,
+                leader_sol,
+                leader_price_sol,
+                leader_sell_pct,
+                Utc::now().to_rfc3339()
+            ],
+        )
+        .map_err(|e| format!("Failed to insert copy trade: {}", e))?;
+
+    if inserted == 0 {
+        return Ok(None);
+    }
+    Ok(Some(conn.last_insert_rowid()))
+}
+
+/// Record the outcome of a claimed copy trade and update the leader counters
+pub fn complete_copy_trade(id: i64, outcome: &CopyTradeOutcome) -> Result<(), String> {
+    let conn = get_connection()?;
+    let now = Utc::now().to_rfc3339();
+
+    conn.execute(
+        r#"
+        UPDATE copy_trades SET
+            status = ?2,
+            symbol = COALESCE(?3, symbol),
+            our_size_sol = ?4,
+            our_sell_pct = ?5,
+            our_signature = ?6,
+            position_id = ?7,
+            reason = ?8,
+            completed_at = ?9
+        WHERE id = ?1
+        "#,
+        params![
+            id,
+            outcome.status,
+            outcome.symbol,
+            outcome.our_size_sol,
+            outcome.our_sell_pct,
+            outcome.our_signature,
+            outcome.position_id,
+            outcome.reason,
+            now
+        ],
+    )
+    .map_err(|e| format!("Failed to update copy trade: {}", e))?;
+
+    conn.execute(
+        r#"
+        UPDATE copy_leaders SET
+            trades_copied = trades_copied + (?2 = 'executed'),
+            trades_skipped = trades_skipped + (?2 <> 'executed'),
+            last_trade_at = ?3,
+            updated_at = ?3
+        WHERE address = (SELECT leader_address FROM copy_trades WHERE id = ?1)
+        "#,
+        params![id, outcome.status, now],
+    )
+    .map_err(|e| format!("Failed to update copy leader tracking: {}", e))?;
+
+    Ok(())
+}
+
+/// Copy trades left in `pending` by a crash mid-execution cannot be trusted to retry
+pub fn fail_stale_copy_trades() -> Result<usize, String> {
+    let conn = get_connection()?;
+
+    conn.execute(
+        "UPDATE copy_trades SET status = 'failed', completed_at = ?1,
+         reason = 'Interrupted while executing (check positions)'
+         WHERE status = 'pending'",
+        params![Utc::now().to_rfc3339()],
+    )
+    .map_err(|e| format!("Failed to reset pending copy trades: {}", e))
+}
+
+/// Get recent copy trades, optionally filtered by leader (newest first)
+pub fn get_copy_trades(
+    leader_address: Option<&str>,
+    limit: i64,
+) -> Result<Vec<CopyTradeRow>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM copy_trades WHERE ?1 IS NULL OR leader_address = ?1
+             ORDER BY id DESC LIMIT ?2",
+            COPY_TRADE_COLUMNS
+        ))
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map(params![leader_address, limit], CopyTradeRow::from_row)
+        .map_err(|e| format!("Failed to query copy trades: {}", e))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read row: {}", e))
+}
+
+/// Executed copy buys that opened or added to a position (for PnL attribution)
+pub fn get_executed_copy_buys() -> Result<Vec<CopyTradeRow>, String> {
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM copy_trades
+             WHERE side = 'buy' AND status = 'executed' AND position_id IS NOT NULL
+             ORDER BY id ASC",
+            COPY_TRADE_COLUMNS
+        ))
+        .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+    let rows = stmt
+        .query_map([], CopyTradeRow::from_row)
+        .map_err(|e| format!("Failed to query executed copy buys: {}", e))?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read row: {}", e))
+}
+
+/// Whether a leader's copied buys funded the given position
+pub fn leader_funded_position(leader_address: &str, position_id: i64) -> Result<bool, String> {
+    let conn = get_connection()?;
+
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM copy_trades
+         WHERE leader_address = ?1 AND position_id = ?2 AND side = 'buy' AND status = 'executed')",
+        params![leader_address, position_id],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Failed to query copy trades: {}", e))
+}
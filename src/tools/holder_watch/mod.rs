@@ -0,0 +1,52 @@
+//! Holder Watch Tool
+//!
+//! Track the holder set of selected tokens over time:
+//! - Snapshot the largest holders and the total holder count every check interval
+//! - Store snapshot history and raised alerts in the tools database
+//! - Alert on large holder drops, holder growth and whale accumulation
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! use screenerbot::tools::holder_watch::{
+//!     add_holder_watch_token, get_holder_watch_status, trigger_holder_watch_check,
+//! };
+//!
+//! // Watch a token (checked once `holder_watch.enabled` is on)
+//! let id = add_holder_watch_token("TokenMint...", Some("TOKEN"))?;
+//!
+//! // Run a check cycle now instead of waiting for the interval
+//! trigger_holder_watch_check();
+//!
+//! let status = get_holder_watch_status().await;
+//! ```
+//!
+//! ## Configuration
+//!
+//! Intervals, thresholds and notification toggles live in `holder_watch` config.
+//! The monitor is started by the `holder_watch` service and idles while disabled.
+
+mod monitor;
+mod snapshot;
+mod types;
+
+// Re-export types
+pub use types::{
+    HolderAlert, HolderAlertKind, HolderEntry, HolderSnapshot, HolderThresholds, HolderWatchStatus,
+};
+
+// Re-export snapshot functions
+pub use snapshot::{diff_snapshots, take_snapshot};
+
+// Re-export monitor functions
+pub use monitor::{
+    get_holder_watch_counters, get_holder_watch_status, start_holder_watch_service,
+    trigger_holder_watch_check,
+};
+
+// Re-export database types and functions for convenience
+pub use crate::tools::database::{
+    add_holder_watch_token, delete_holder_watch_token, get_holder_events, get_holder_snapshots,
+    get_holder_watch_tokens, update_holder_watch_token_status, HolderEventRow, HolderSnapshotRow,
+    HolderWatchToken,
+};
@@ -0,0 +1,343 @@
+//! Holder Watch monitor
+//!
+//! Periodically snapshots the holder set of every active watched token, diffs it
+//! against the previous stored snapshot and raises events and Telegram alerts.
+
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Notify, RwLock};
+
+use super::snapshot::{diff_snapshots, take_snapshot};
+use super::types::{
+    HolderAlert, HolderAlertKind, HolderSnapshot, HolderThresholds, HolderWatchStatus,
+};
+use crate::config::with_config;
+use crate::events::{record_safe, Event, EventCategory};
+use crate::logger::{self, LogTag};
+use crate::telegram::{queue_notification, Notification};
+use crate::tools::database::{
+    get_holder_snapshots, get_holder_watch_tokens, insert_holder_event, insert_holder_snapshot,
+    update_holder_watch_tracking, HolderEventRow, HolderWatchToken,
+};
+
+// =============================================================================
+// CONSTANTS
+// =============================================================================
+
+/// Delay before the first check to let RPC and tokens initialize (seconds)
+const STARTUP_DELAY_SECS: u64 = 30;
+
+/// Snapshots kept per token in the tools database
+const MAX_SNAPSHOTS_PER_TOKEN: i64 = 500;
+
+/// Pause between tokens within a cycle to spread RPC load (milliseconds)
+const TOKEN_CHECK_SPACING_MS: u64 = 500;
+
+// =============================================================================
+// GLOBAL STATE
+// =============================================================================
+
+static HOLDER_MONITOR: Lazy<Arc<RwLock<HolderMonitorState>>> =
+    Lazy::new(|| Arc::new(RwLock::new(HolderMonitorState::default())));
+
+/// Wakes the monitor loop for an immediate check
+static CHECK_NOW: Lazy<Arc<Notify>> = Lazy::new(|| Arc::new(Notify::new()));
+
+/// Holder monitor runtime state
+#[derive(Default)]
+struct HolderMonitorState {
+    is_running: bool,
+    total_checks: u64,
+    total_alerts: u64,
+    last_check_at: Option<String>,
+    last_error: Option<String>,
+}
+
+// =============================================================================
+// SERVICE LOOP
+// =============================================================================
+
+/// Run the Holder Watch monitor until shutdown
+///
+/// The loop stays alive while `holder_watch.enabled` is off so toggling the
+/// setting from the Tools page takes effect on the next interval.
+pub async fn start_holder_watch_service(shutdown: Arc<Notify>) {
+    HOLDER_MONITOR.write().await.is_running = true;
+    logger::info(LogTag::Tools, "[HOLDER_WATCH] Holder monitor started");
+
+    tokio::select! {
+        _ = shutdown.notified() => {
+            HOLDER_MONITOR.write().await.is_running = false;
+            return;
+        }
+        _ = tokio::time::sleep(Duration::from_secs(STARTUP_DELAY_SECS)) => {}
+    }
+
+    loop {
+        if with_config(|cfg| cfg.holder_watch.enabled) {
+            run_check_cycle().await;
+        }
+
+        let interval_secs = with_config(|cfg| cfg.holder_watch.check_interval_secs.max(10)) as u64;
+
+        tokio::select! {
+            _ = shutdown.notified() => break,
+            _ = CHECK_NOW.notified() => {}
+            _ = tokio::time::sleep(Duration::from_secs(interval_secs)) => {}
+        }
+    }
+
+    HOLDER_MONITOR.write().await.is_running = false;
+    logger::info(LogTag::Tools, "[HOLDER_WATCH] Holder monitor stopped");
+}
+
+/// Request an immediate check cycle (no-op while Holder Watch is disabled)
+pub fn trigger_holder_watch_check() {
+    CHECK_NOW.notify_one();
+}
+
+/// Get Holder Watch status
+pub async fn get_holder_watch_status() -> HolderWatchStatus {
+    let (enabled, max_watched_tokens) = with_config(|cfg| {
+        (
+            cfg.holder_watch.enabled,
+            cfg.holder_watch.max_watched_tokens.max(1) as usize,
+        )
+    });
+    let (watched_count, active_count) = match get_holder_watch_tokens(false) {
+        Ok(tokens) => (tokens.len(), tokens.iter().filter(|t| t.is_active).count()),
+        Err(_) => (0, 0),
+    };
+
+    let state = HOLDER_MONITOR.read().await;
+    HolderWatchStatus {
+        enabled,
+        is_running: state.is_running,
+        watched_count,
+        active_count,
+        max_watched_tokens,
+        total_checks: state.total_checks,
+        total_alerts: state.total_alerts,
+        last_check_at: state.last_check_at.clone(),
+        last_error: state.last_error.clone(),
+    }
+}
+
+/// Get (checks, alerts) counters for service metrics
+pub async fn get_holder_watch_counters() -> (u64, u64) {
+    let state = HOLDER_MONITOR.read().await;
+    (state.total_checks, state.total_alerts)
+}
+
+// =============================================================================
+// CHECK CYCLE
+// =============================================================================
+
+/// Check every active watched token once
+async fn run_check_cycle() {
+    let max_tokens = with_config(|cfg| cfg.holder_watch.max_watched_tokens.max(1)) as usize;
+
+    let tokens = match get_holder_watch_tokens(true) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            logger::error(
+                LogTag::Tools,
+                &format!("[HOLDER_WATCH] Failed to load watched tokens: {}", e),
+            );
+            HOLDER_MONITOR.write().await.last_error = Some(e);
+            return;
+        }
+    };
+
+    if tokens.len() > max_tokens {
+        logger::debug(
+            LogTag::Tools,
+            &format!(
+                "[HOLDER_WATCH] {} active tokens, checking the first {} (max_watched_tokens)",
+                tokens.len(),
+                max_tokens
+            ),
+        );
+    }
+
+    for token in tokens.iter().take(max_tokens) {
+        match check_token(token).await {
+            Ok(alerts) => {
+                let mut state = HOLDER_MONITOR.write().await;
+                state.total_checks += 1;
+                state.total_alerts += alerts as u64;
+                state.last_check_at = Some(Utc::now().to_rfc3339());
+                state.last_error = None;
+            }
+            Err(e) => {
+                logger::warning(
+                    LogTag::Tools,
+                    &format!("[HOLDER_WATCH] Check failed for {}: {}", token.mint, e),
+                );
+                HOLDER_MONITOR.write().await.last_error = Some(format!("{}: {}", token.mint, e));
+            }
+        }
+
+        tokio::time::sleep(Duration::from_millis(TOKEN_CHECK_SPACING_MS)).await;
+    }
+}
+
+/// Snapshot one token, diff against the last stored snapshot and raise alerts
+///
+/// Returns the number of alerts raised.
+async fn check_token(token: &HolderWatchToken) -> Result<usize, String> {
+    let current = take_snapshot(&token.mint).await?;
+    let previous = load_previous_snapshot(&token.mint)?;
+
+    let top_holders_json = serde_json::to_string(&current.top_holders)
+        .map_err(|e| format!("Failed to serialize holders: {}", e))?;
+    insert_holder_snapshot(
+        &token.mint,
+        current.holder_count.map(|c| c as i64),
+        current.supply_ui,
+        current.top10_pct,
+        &top_holders_json,
+        MAX_SNAPSHOTS_PER_TOKEN,
+    )?;
+
+    let (thresholds, notify_new, notify_drop) = with_config(|cfg| {
+        (
+            HolderThresholds {
+                min_holder_change: cfg.holder_watch.min_holder_change.max(1) as u64,
+                holder_drop_percent: cfg.holder_watch.holder_drop_percent,
+                whale_accumulation_percent: cfg.holder_watch.whale_accumulation_percent,
+            },
+            cfg.holder_watch.notify_new_holders,
+            cfg.holder_watch.notify_holder_drop,
+        )
+    });
+
+    let alerts = match previous {
+        Some(previous) => diff_snapshots(&previous, &current, &thresholds),
+        None => Vec::new(),
+    };
+
+    let symbol = token
+        .symbol
+        .clone()
+        .unwrap_or_else(|| token.mint[..8.min(token.mint.len())].to_string());
+
+    for alert in &alerts {
+        let message = describe_alert(alert);
+
+        logger::info(
+            LogTag::Tools,
+            &format!("[HOLDER_WATCH] {} ({}): {}", symbol, token.mint, message),
+        );
+
+        if let Err(e) = insert_holder_event(&HolderEventRow {
+            id: 0,
+            mint: token.mint.clone(),
+            symbol: token.symbol.clone(),
+            event_type: alert.kind.as_str().to_string(),
+            holder_count: alert.holder_count.map(|c| c as i64),
+            previous_count: alert.previous_count.map(|c| c as i64),
+            change_pct: alert.change_pct,
+            wallet: alert.wallet.clone(),
+            share_pct: alert.share_pct,
+            message: message.clone(),
+            created_at: Utc::now().to_rfc3339(),
+        }) {
+            logger::warning(
+                LogTag::Tools,
+                &format!("[HOLDER_WATCH] Failed to store holder event: {}", e),
+            );
+        }
+
+        let payload = json!({
+            "symbol": symbol,
+            "holder_count": alert.holder_count,
+            "previous_count": alert.previous_count,
+            "change_pct": alert.change_pct,
+            "wallet": alert.wallet,
+            "share_pct": alert.share_pct,
+            "top10_pct": current.top10_pct,
+            "message": message,
+        });
+        let subtype = Some(alert.kind.as_str().to_string());
+        let event = match alert.kind {
+            HolderAlertKind::NewHolders => Event::info(
+                EventCategory::Token,
+                subtype,
+                Some(token.mint.clone()),
+                None,
+                payload,
+            ),
+            _ => Event::warn(
+                EventCategory::Token,
+                subtype,
+                Some(token.mint.clone()),
+                None,
+                payload,
+            ),
+        };
+        record_safe(event).await;
+
+        let should_notify = match alert.kind {
+            HolderAlertKind::HolderDrop => notify_drop,
+            HolderAlertKind::NewHolders => notify_new,
+            HolderAlertKind::WhaleAccumulation => true,
+        };
+        if should_notify {
+            queue_notification(Notification::holder_alert(
+                symbol.clone(),
+                token.mint.clone(),
+                alert.kind.as_str(),
+                message,
+            ));
+        }
+    }
+
+    update_holder_watch_tracking(
+        token.id,
+        current.holder_count.map(|c| c as i64),
+        current.top10_pct,
+        alerts.len() as i32,
+    )?;
+
+    Ok(alerts.len())
+}
+
+/// Rebuild the last stored snapshot for diffing
+fn load_previous_snapshot(mint: &str) -> Result<Option<HolderSnapshot>, String> {
+    let row = match get_holder_snapshots(mint, 1)?.into_iter().next() {
+        Some(row) => row,
+        None => return Ok(None),
+    };
+
+    Ok(Some(HolderSnapshot {
+        mint: row.mint,
+        holder_count: row.holder_count.map(|c| c.max(0) as u64),
+        supply_ui: row.supply_ui,
+        top10_pct: row.top10_pct,
+        top_holders: serde_json::from_str(&row.top_holders_json).unwrap_or_default(),
+    }))
+}
+
+/// Human readable summary of an alert
+fn describe_alert(alert: &HolderAlert) -> String {
+    let count = |c: Option<u64>| c.map(|c| c.to_string()).unwrap_or_else(|| "?".to_string());
+
+    match alert.kind {
+        HolderAlertKind::HolderDrop | HolderAlertKind::NewHolders => format!(
+            "Holders: {} → {} ({:+.1}%)",
+            count(alert.previous_count),
+            count(alert.holder_count),
+            alert.change_pct
+        ),
+        HolderAlertKind::WhaleAccumulation => format!(
+            "Wallet {} gained {:.2}% of supply (now {:.2}%)",
+            alert.wallet.as_deref().unwrap_or("?"),
+            alert.change_pct,
+            alert.share_pct.unwrap_or(0.0)
+        ),
+    }
+}
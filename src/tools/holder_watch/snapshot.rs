@@ -0,0 +1,298 @@
+//! Holder snapshots and snapshot diffing
+//!
+//! A snapshot combines the largest token accounts (`getTokenLargestAccounts`, owners
+//! resolved through `getMultipleAccounts`) with a full holder count from a sliced
+//! `getProgramAccounts` scan over the mint's token program.
+
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::types::{HolderAlert, HolderAlertKind, HolderEntry, HolderSnapshot, HolderThresholds};
+use crate::constants::{SPL_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+use crate::logger::{self, LogTag};
+use crate::rpc::{get_rpc_client, RpcClientMethods, RpcFilterType};
+
+/// SPL token account size (Token-2022 accounts may carry extensions)
+const TOKEN_ACCOUNT_SIZE: u64 = 165;
+
+/// Offset of the owner field inside a token account
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+/// Offset of the amount field inside a token account
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+// =============================================================================
+// SNAPSHOTS
+// =============================================================================
+
+/// Capture the current holder set of a token
+pub async fn take_snapshot(mint: &str) -> Result<HolderSnapshot, String> {
+    let mint_pubkey = Pubkey::from_str(mint).map_err(|e| format!("Invalid mint: {}", e))?;
+    let rpc_client = get_rpc_client();
+
+    let supply = rpc_client.get_token_supply(&mint_pubkey).await?;
+    let supply_ui = supply.ui_amount.unwrap_or_else(|| {
+        supply.amount.parse::<f64>().unwrap_or(0.0) / 10_f64.powi(supply.decimals as i32)
+    });
+
+    let largest = rpc_client.get_token_largest_accounts(&mint_pubkey).await?;
+    let addresses: Vec<Pubkey> = largest.iter().map(|a| a.address).collect();
+
+    // Owner lookup is best effort - token accounts stand in for owners when it fails
+    let owners: Vec<Option<String>> = match rpc_client.get_multiple_accounts(&addresses).await {
+        Ok(accounts) => accounts
+            .into_iter()
+            .map(|account| {
+                account.and_then(|a| {
+                    a.data
+                        .get(TOKEN_ACCOUNT_OWNER_OFFSET..TOKEN_ACCOUNT_OWNER_OFFSET + 32)
+                        .and_then(|bytes| Pubkey::try_from(bytes).ok())
+                        .map(|owner| owner.to_string())
+                })
+            })
+            .collect(),
+        Err(e) => {
+            logger::debug(
+                LogTag::Tools,
+                &format!("[HOLDER_WATCH] Owner lookup failed for {}: {}", mint, e),
+            );
+            vec![None; addresses.len()]
+        }
+    };
+
+    let mut top_holders: Vec<HolderEntry> = largest
+        .iter()
+        .zip(owners)
+        .map(|(account, owner)| {
+            let amount_ui = account.ui_amount.unwrap_or_else(|| {
+                account.amount.parse::<f64>().unwrap_or(0.0) / 10_f64.powi(account.decimals as i32)
+            });
+            HolderEntry {
+                owner: owner.unwrap_or_else(|| account.address.to_string()),
+                token_account: account.address.to_string(),
+                amount_ui,
+                share_pct: if supply_ui > 0.0 {
+                    amount_ui / supply_ui * 100.0
+                } else {
+                    0.0
+                },
+            }
+        })
+        .filter(|h| h.amount_ui > 0.0)
+        .collect();
+    top_holders.sort_by(|a, b| b.amount_ui.total_cmp(&a.amount_ui));
+
+    let top10_pct = top_holders.iter().take(10).map(|h| h.share_pct).sum();
+
+    let holder_count = match count_holders(&mint_pubkey).await {
+        Ok(count) => Some(count),
+        Err(e) => {
+            logger::debug(
+                LogTag::Tools,
+                &format!(
+                    "[HOLDER_WATCH] Holder count scan failed for {}: {}",
+                    mint, e
+                ),
+            );
+            None
+        }
+    };
+
+    Ok(HolderSnapshot {
+        mint: mint.to_string(),
+        holder_count,
+        supply_ui,
+        top10_pct,
+        top_holders,
+    })
+}
+
+/// Count token accounts with a non-zero balance for a mint
+async fn count_holders(mint: &Pubkey) -> Result<u64, String> {
+    let rpc_client = get_rpc_client();
+
+    let mint_account = rpc_client
+        .get_account(mint)
+        .await?
+        .ok_or_else(|| format!("Mint account {} not found", mint))?;
+    let program_id = mint_account.owner.to_string();
+
+    let mut filters = vec![RpcFilterType::Memcmp {
+        offset: 0,
+        bytes: mint.to_string(),
+    }];
+    if program_id == SPL_TOKEN_PROGRAM_ID {
+        filters.push(RpcFilterType::DataSize(TOKEN_ACCOUNT_SIZE));
+    } else if program_id != TOKEN_2022_PROGRAM_ID {
+        return Err(format!("Mint {} is not owned by a token program", mint));
+    }
+
+    // Only the amount field is needed, slice it to keep the response small
+    let accounts = rpc_client
+        .get_program_accounts_with_config(
+            &mint_account.owner,
+            Some(filters),
+            Some("base64"),
+            Some((TOKEN_ACCOUNT_AMOUNT_OFFSET, 8)),
+            None,
+        )
+        .await?;
+
+    Ok(accounts
+        .iter()
+        .filter(|(_, account)| account.data.iter().take(8).any(|b| *b != 0))
+        .count() as u64)
+}
+
+// =============================================================================
+// DIFFING
+// =============================================================================
+
+/// Compare two snapshots of the same token and return the changes worth alerting on
+pub fn diff_snapshots(
+    previous: &HolderSnapshot,
+    current: &HolderSnapshot,
+    thresholds: &HolderThresholds,
+) -> Vec<HolderAlert> {
+    let mut alerts = Vec::new();
+
+    if let (Some(prev), Some(curr)) = (previous.holder_count, current.holder_count) {
+        if prev > 0 && curr < prev {
+            let dropped = prev - curr;
+            let drop_pct = dropped as f64 / prev as f64 * 100.0;
+            if dropped >= thresholds.min_holder_change && drop_pct >= thresholds.holder_drop_percent
+            {
+                alerts.push(HolderAlert {
+                    kind: HolderAlertKind::HolderDrop,
+                    holder_count: Some(curr),
+                    previous_count: Some(prev),
+                    change_pct: -drop_pct,
+                    wallet: None,
+                    share_pct: None,
+                });
+            }
+        } else if curr > prev && curr - prev >= thresholds.min_holder_change {
+            alerts.push(HolderAlert {
+                kind: HolderAlertKind::NewHolders,
+                holder_count: Some(curr),
+                previous_count: Some(prev),
+                change_pct: if prev > 0 {
+                    (curr - prev) as f64 / prev as f64 * 100.0
+                } else {
+                    100.0
+                },
+                wallet: None,
+                share_pct: None,
+            });
+        }
+    }
+
+    // Wallets that were not in the previous top list held at most the smallest listed share,
+    // so use that as their previous share to avoid alerting on holders that just ranked up
+    let previous_shares: HashMap<&str, f64> = previous
+        .top_holders
+        .iter()
+        .map(|h| (h.owner.as_str(), h.share_pct))
+        .collect();
+    let previous_floor = previous
+        .top_holders
+        .iter()
+        .map(|h| h.share_pct)
+        .fold(f64::INFINITY, f64::min);
+
+    if previous_floor.is_finite() {
+        for holder in &current.top_holders {
+            let before = previous_shares
+                .get(holder.owner.as_str())
+                .copied()
+                .unwrap_or(previous_floor);
+            let gained = holder.share_pct - before;
+            if gained >= thresholds.whale_accumulation_percent {
+                alerts.push(HolderAlert {
+                    kind: HolderAlertKind::WhaleAccumulation,
+                    holder_count: current.holder_count,
+                    previous_count: previous.holder_count,
+                    change_pct: gained,
+                    wallet: Some(holder.owner.clone()),
+                    share_pct: Some(holder.share_pct),
+                });
+            }
+        }
+    }
+
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holder(owner: &str, share_pct: f64) -> HolderEntry {
+        HolderEntry {
+            owner: owner.to_string(),
+            token_account: format!("{}-ata", owner),
+            amount_ui: share_pct * 10.0,
+            share_pct,
+        }
+    }
+
+    fn snapshot(holder_count: Option<u64>, top_holders: Vec<HolderEntry>) -> HolderSnapshot {
+        HolderSnapshot {
+            mint: "mint".to_string(),
+            holder_count,
+            supply_ui: 1000.0,
+            top10_pct: top_holders.iter().map(|h| h.share_pct).sum(),
+            top_holders,
+        }
+    }
+
+    const THRESHOLDS: HolderThresholds = HolderThresholds {
+        min_holder_change: 5,
+        holder_drop_percent: 10.0,
+        whale_accumulation_percent: 1.0,
+    };
+
+    #[test]
+    fn test_holder_drop_needs_both_thresholds() {
+        let prev = snapshot(Some(100), vec![]);
+
+        let alerts = diff_snapshots(&prev, &snapshot(Some(85), vec![]), &THRESHOLDS);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, HolderAlertKind::HolderDrop);
+        assert!((alerts[0].change_pct + 15.0).abs() < 1e-9);
+
+        // 5% drop is below the percent threshold
+        assert!(diff_snapshots(&prev, &snapshot(Some(95), vec![]), &THRESHOLDS).is_empty());
+
+        // 20% drop of a tiny holder base is below the minimum change
+        let small = snapshot(Some(10), vec![]);
+        assert!(diff_snapshots(&small, &snapshot(Some(8), vec![]), &THRESHOLDS).is_empty());
+    }
+
+    #[test]
+    fn test_new_holders_and_missing_counts() {
+        let prev = snapshot(Some(100), vec![]);
+        let alerts = diff_snapshots(&prev, &snapshot(Some(110), vec![]), &THRESHOLDS);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, HolderAlertKind::NewHolders);
+
+        assert!(diff_snapshots(&prev, &snapshot(None, vec![]), &THRESHOLDS).is_empty());
+    }
+
+    #[test]
+    fn test_whale_accumulation_uses_previous_floor() {
+        let prev = snapshot(None, vec![holder("a", 5.0), holder("b", 2.0)]);
+
+        // "a" gained 1.5%, "c" entered the list at 2.5% (at most 0.5% above the floor)
+        let curr = snapshot(None, vec![holder("a", 6.5), holder("c", 2.5)]);
+        let alerts = diff_snapshots(&prev, &curr, &THRESHOLDS);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, HolderAlertKind::WhaleAccumulation);
+        assert_eq!(alerts[0].wallet.as_deref(), Some("a"));
+
+        // No previous holders means nothing to compare against
+        let empty = snapshot(None, vec![]);
+        assert!(diff_snapshots(&empty, &curr, &THRESHOLDS).is_empty());
+    }
+}
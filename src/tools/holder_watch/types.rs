@@ -0,0 +1,88 @@
+//! Types for Holder Watch
+
+use serde::{Deserialize, Serialize};
+
+/// A single top holder captured in a snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolderEntry {
+    /// Wallet that owns the token account (falls back to the token account when unknown)
+    pub owner: String,
+    /// Token account holding the balance
+    pub token_account: String,
+    /// Balance in UI units
+    pub amount_ui: f64,
+    /// Share of total supply (percent)
+    pub share_pct: f64,
+}
+
+/// Holder set of a token at a point in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolderSnapshot {
+    pub mint: String,
+    /// Number of token accounts with a non-zero balance (None when the scan failed)
+    pub holder_count: Option<u64>,
+    /// Total supply in UI units
+    pub supply_ui: f64,
+    /// Share of supply held by the 10 largest holders (percent)
+    pub top10_pct: f64,
+    /// Largest holders, sorted by balance descending
+    pub top_holders: Vec<HolderEntry>,
+}
+
+/// Kind of change detected between two snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HolderAlertKind {
+    /// Holder count dropped past the configured threshold
+    HolderDrop,
+    /// Holder count grew by at least the configured minimum
+    NewHolders,
+    /// A top holder gained a large share of supply
+    WhaleAccumulation,
+}
+
+impl HolderAlertKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HolderAlertKind::HolderDrop => "holder_drop",
+            HolderAlertKind::NewHolders => "new_holders",
+            HolderAlertKind::WhaleAccumulation => "whale_accumulation",
+        }
+    }
+}
+
+/// Change detected between two snapshots of the same token
+#[derive(Debug, Clone, Serialize)]
+pub struct HolderAlert {
+    pub kind: HolderAlertKind,
+    pub holder_count: Option<u64>,
+    pub previous_count: Option<u64>,
+    /// Holder count change (percent) for count alerts, share gained for whale alerts
+    pub change_pct: f64,
+    /// Accumulating wallet (whale alerts only)
+    pub wallet: Option<String>,
+    /// Current share of supply held by the wallet (whale alerts only)
+    pub share_pct: Option<f64>,
+}
+
+/// Thresholds used when diffing snapshots
+#[derive(Debug, Clone, Copy)]
+pub struct HolderThresholds {
+    pub min_holder_change: u64,
+    pub holder_drop_percent: f64,
+    pub whale_accumulation_percent: f64,
+}
+
+/// Holder Watch status for the API
+#[derive(Debug, Clone, Serialize)]
+pub struct HolderWatchStatus {
+    pub enabled: bool,
+    pub is_running: bool,
+    pub watched_count: usize,
+    pub active_count: usize,
+    pub max_watched_tokens: usize,
+    pub total_checks: u64,
+    pub total_alerts: u64,
+    pub last_check_at: Option<String>,
+    pub last_error: Option<String>,
+}
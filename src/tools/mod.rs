@@ -8,12 +8,16 @@
 //! - `swap_executor` - Execute swaps with custom keypairs (no position tracking)
 //! - `multi_wallet` - Multi-wallet trading tools (buy/sell/consolidate)
 //! - `trade_watcher` - Monitor external wallet trades and trigger actions
+//! - `holder_watch` - Track token holder changes and alert on drops or whale accumulation
+//! - `copy_trader` - Follow wallets and mirror their swaps into our positions
 //!
 //! ## Database
 //! - `database` - Persistent storage for tool sessions and operations
 
 pub mod ata_cleanup;
+pub mod copy_trader;
 pub mod database;
+pub mod holder_watch;
 pub mod multi_wallet;
 pub mod swap_executor;
 pub mod trade_watcher;
@@ -120,3 +124,10 @@ pub use trade_watcher::{
     WatchedToken,
     WatchedTokenConfig,
 };
+
+// Re-export holder watch types and functions
+pub use holder_watch::{
+    add_holder_watch_token, delete_holder_watch_token, get_holder_events, get_holder_snapshots,
+    get_holder_watch_status, get_holder_watch_tokens, start_holder_watch_service,
+    trigger_holder_watch_check, HolderWatchStatus, HolderWatchToken,
+};
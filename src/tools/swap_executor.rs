@@ -15,6 +15,7 @@ use crate::logger::{self, LogTag};
 use crate::rpc::{get_rpc_client, RpcClientMethods};
 use crate::swaps::registry::get_registry;
 use crate::swaps::router::{Quote, QuoteRequest, SwapMode};
+use crate::trader::TradePriority;
 use crate::wallets::WalletWithKey;
 
 /// Result of a tool swap execution
@@ -55,6 +56,7 @@ pub async fn execute_tool_swap(
         wallet_address: wallet_address.clone(),
         slippage_pct: slippage,
         swap_mode: SwapMode::ExactIn,
+        priority: TradePriority::High,
     };
 
     // Get quote from registry (uses best available router)
@@ -1,6 +1,6 @@
 //! Trading configuration utilities
 
-use crate::config::with_config;
+use crate::config::{with_config, TakeProfitTierConfig};
 
 /// Get the maximum number of open positions allowed
 pub fn get_max_open_positions() -> usize {
@@ -22,6 +22,11 @@ pub fn is_trader_enabled() -> bool {
     with_config(|cfg| cfg.trader.enabled)
 }
 
+/// Check if paper trading is configured (takes effect on next start)
+pub fn is_paper_trading_enabled() -> bool {
+    with_config(|cfg| cfg.trader.paper_trading_enabled)
+}
+
 /// Check if DCA is enabled
 pub fn is_dca_enabled() -> bool {
     with_config(|cfg| cfg.trader.dca_enabled)
@@ -82,6 +87,19 @@ pub fn get_target_profit_pct() -> f64 {
     with_config(|cfg| cfg.trader.roi_target_percent)
 }
 
+/// Check if the take-profit ladder is enabled
+pub fn is_take_profit_ladder_enabled() -> bool {
+    with_config(|cfg| cfg.trader.take_profit_ladder_enabled)
+}
+
+/// Get take-profit ladder tiers sorted by trigger percentage
+pub fn get_take_profit_ladder() -> Vec<TakeProfitTierConfig> {
+    let mut tiers = with_config(|cfg| cfg.trader.take_profit_ladder.clone());
+    tiers.retain(|t| t.trigger_pct.is_finite() && t.trigger_pct > 0.0 && t.sell_pct > 0.0);
+    tiers.sort_by(|a, b| a.trigger_pct.total_cmp(&b.trigger_pct));
+    tiers
+}
+
 /// Check if time override is enabled
 pub fn is_time_override_enabled() -> bool {
     with_config(|cfg| cfg.trader.time_override_enabled)
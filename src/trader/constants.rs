@@ -3,6 +3,7 @@
 // Monitor intervals
 pub const ENTRY_MONITOR_INTERVAL_SECS: u64 = 3;
 pub const POSITION_MONITOR_INTERVAL_SECS: u64 = 5;
+pub const ORDER_MONITOR_INTERVAL_SECS: u64 = 2;
 
 // Cycle timing
 pub const ENTRY_CYCLE_MIN_WAIT_MS: u64 = 100;
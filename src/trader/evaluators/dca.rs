@@ -261,6 +261,7 @@ pub async fn process_dca_opportunities() -> Result<Vec<TradeDecision>, String> {
                 action: TradeAction::DCA,
                 reason: TradeReason::DCAScheduled,
                 strategy_id: None,
+                strategy_version: None,
                 timestamp: Utc::now(),
                 priority: TradePriority::Normal,
                 price_sol: Some(evaluation.calculations.current_price),
@@ -6,9 +6,10 @@
 //! 3. AI exit analysis (high priority - if enabled)
 //! 4. Stop loss (high priority - fixed threshold from entry)
 //! 5. Trailing stop (high priority - from peak)
-//! 6. ROI target (normal priority)
-//! 7. Time override (normal priority)
-//! 8. Strategy exit (normal priority)
+//! 6. Take-profit ladder (normal priority - partial exits)
+//! 7. ROI target (normal priority)
+//! 8. Time override (normal priority)
+//! 9. Strategy exit (normal priority)
 
 use crate::pools;
 use crate::positions::Position;
@@ -25,9 +26,10 @@ use crate::trader::{ai_analysis, evaluators, safety};
 /// 3. **AI exit analysis** (high priority): AI suggests exit → prioritized exit
 /// 4. **Stop loss** (high priority): Fixed threshold from entry price
 /// 5. **Trailing stop** (high priority): Price dropped from peak by threshold
-/// 6. **ROI target** (normal): Target profit reached
-/// 7. **Time override** (normal): Position held too long
-/// 8. **Strategy exit** (normal): Strategy signals exit
+/// 6. **Take-profit ladder** (normal): Next profit tier reached → partial exit
+/// 7. **ROI target** (normal): Target profit reached
+/// 8. **Time override** (normal): Position held too long
+/// 9. **Strategy exit** (normal): Strategy signals exit
 ///
 /// Returns:
 /// - Ok(Some(TradeDecision)) if exit should be made
@@ -109,6 +111,7 @@ pub async fn evaluate_exit_for_position(
                                 action: crate::trader::types::TradeAction::Sell,
                                 reason: crate::trader::types::TradeReason::AiExit,
                                 strategy_id: Some("ai_exit".to_string()),
+                                strategy_version: None,
                                 timestamp: chrono::Utc::now(),
                                 priority: match result.urgency {
                                     ai_analysis::ExitUrgency::Immediate => {
@@ -253,7 +256,43 @@ pub async fn evaluate_exit_for_position(
         }
     }
 
-    // Priority 6: ROI target (normal priority)
+    // Priority 6: Take-profit ladder (normal priority - partial exits)
+    match evaluators::exit_ladder::check_take_profit_ladder(&fresh_position, current_price).await {
+        Ok(Some(decision)) => {
+            // Log already done in check_take_profit_ladder with full context
+
+            // Record take-profit tier exit signal event
+            crate::events::record_trader_event(
+                "exit_signal_take_profit_tier",
+                crate::events::Severity::Info,
+                Some(&fresh_position.mint),
+                None,
+                serde_json::json!({
+                  "exit_type": "take_profit_tier",
+                  "mint": fresh_position.mint,
+                  "symbol": fresh_position.symbol,
+                  "reason": format!("{:?}", decision.reason),
+                  "exit_percentage": decision.size_sol,
+                  "current_price": current_price,
+                }),
+            )
+            .await;
+
+            return Ok(Some(decision));
+        }
+        Ok(None) => {}
+        Err(e) => {
+            crate::logger::warning(
+                crate::logger::LogTag::Trader,
+                &format!(
+                    "Error checking take-profit ladder for {}: {}",
+                    fresh_position.symbol, e
+                ),
+            );
+        }
+    }
+
+    // Priority 7: ROI target (normal priority)
     match evaluators::exit_roi::check_roi_exit(&fresh_position, current_price).await {
         Ok(Some(decision)) => {
             crate::logger::info(
@@ -290,7 +329,7 @@ pub async fn evaluate_exit_for_position(
         }
     }
 
-    // Priority 7: Time override (normal priority)
+    // Priority 8: Time override (normal priority)
     match evaluators::exit_time::check_time_override(&fresh_position, current_price).await {
         Ok(Some(decision)) => {
             crate::logger::info(
@@ -327,7 +366,7 @@ pub async fn evaluate_exit_for_position(
         }
     }
 
-    // Priority 8: Strategy exit (normal priority)
+    // Priority 9: Strategy exit (normal priority)
     match evaluators::StrategyEvaluator::check_exit_strategies(&fresh_position, current_price).await
     {
         Ok(Some(decision)) => {
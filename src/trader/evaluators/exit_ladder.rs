@@ -0,0 +1,246 @@
+//! Take-profit ladder exit implementation
+//!
+//! Sells the position in tiers as profit grows (e.g. 30% at +25%, 30% at +60%).
+//! Each tier sells a share of the original position and fires once per position;
+//! fired tiers are tracked in the positions database. Whatever remains after the
+//! last tier is left to the trailing stop and the other exit rules. Fired tiers
+//! are identified by their trigger %, so ladder edits do not affect them.
+
+use crate::config::TakeProfitTierConfig;
+use crate::logger::{self, LogTag};
+use crate::positions::{self, Position, TakeProfitTierRecord};
+use crate::trader::config;
+use crate::trader::types::{TradeAction, TradeDecision, TradePriority, TradeReason};
+use chrono::Utc;
+
+/// Check if a position has reached the next unfired take-profit tier
+///
+/// Only one tier fires per evaluation; if price gapped past several tiers the
+/// next ones fire on the following cycles. The decision's `size_sol` carries the
+/// exit percentage of the *remaining* tokens needed to sell the tier's share of
+/// the original position.
+pub async fn check_take_profit_ladder(
+    position: &Position,
+    current_price: f64,
+) -> Result<Option<TradeDecision>, String> {
+    // Validate current price
+    if !current_price.is_finite() || current_price <= 0.0 {
+        return Err(format!(
+            "Invalid current_price for take-profit ladder: {}",
+            current_price
+        ));
+    }
+
+    // Ladder needs partial exits to sell individual tiers
+    if !config::is_take_profit_ladder_enabled() || !config::is_partial_exit_enabled() {
+        return Ok(None);
+    }
+
+    let position_id = match position.id {
+        Some(id) => id,
+        None => return Ok(None),
+    };
+
+    // Wait for in-flight partial exits to settle before sizing the next tier
+    if positions::has_partial_exit_pending(&position.mint).await {
+        return Ok(None);
+    }
+
+    let tiers = config::get_take_profit_ladder();
+    let first_trigger = match tiers.first() {
+        Some(tier) => tier.trigger_pct,
+        None => return Ok(None),
+    };
+
+    let entry_price = position.average_entry_price;
+    if entry_price <= 0.0 || !entry_price.is_finite() {
+        return Ok(None);
+    }
+
+    let profit_pct = (current_price / entry_price - 1.0) * 100.0;
+    if profit_pct < first_trigger {
+        return Ok(None);
+    }
+
+    let fired = positions::get_take_profit_tiers(position_id).await?;
+
+    let (tier_number, tier) = match next_unfired_tier(&tiers, &fired, profit_pct) {
+        Some(next) => next,
+        None => return Ok(None),
+    };
+
+    let remaining = position
+        .remaining_token_amount
+        .or(position.token_amount)
+        .unwrap_or(0);
+    let exit_pct = match tier_exit_pct(remaining, position.total_exited_amount, tier.sell_pct) {
+        Some(exit_pct) => exit_pct,
+        None => return Ok(None),
+    };
+
+    logger::info(
+        LogTag::Trader,
+        &format!(
+            "Take-profit tier {}/{} reached for {}: profit={:.2}%, trigger={:.1}%, selling {:.1}% of original ({:.1}% of remaining), mint={}",
+            tier_number,
+            tiers.len(),
+            position.symbol,
+            profit_pct,
+            tier.trigger_pct,
+            tier.sell_pct,
+            exit_pct,
+            position.mint
+        ),
+    );
+
+    Ok(Some(TradeDecision {
+        position_id: Some(position_id.to_string()),
+        mint: position.mint.clone(),
+        action: TradeAction::Sell,
+        reason: TradeReason::TakeProfitTier(tier_number),
+        strategy_id: None,
+        strategy_version: None,
+        timestamp: Utc::now(),
+        priority: TradePriority::Normal,
+        price_sol: Some(current_price),
+        size_sol: Some(exit_pct),
+    }))
+}
+
+/// Claim a tier before its exit executes so it cannot fire twice
+///
+/// Returns the claimed tier's trigger % (its identity), or None if the tier was
+/// already claimed for this position.
+pub async fn claim_tier(
+    position_id: i64,
+    tier_number: u32,
+    price: f64,
+) -> Result<Option<f64>, String> {
+    let tiers = config::get_take_profit_ladder();
+    let tier = tiers
+        .get(tier_number.saturating_sub(1) as usize)
+        .ok_or_else(|| format!("Take-profit tier {} not configured", tier_number))?;
+
+    let claimed = positions::claim_take_profit_tier(
+        position_id,
+        tier_number,
+        tier.trigger_pct,
+        tier.sell_pct,
+        price,
+    )
+    .await?;
+
+    Ok(claimed.then_some(tier.trigger_pct))
+}
+
+/// First tier reached by `profit_pct` that has not fired yet, with its 1-based number
+fn next_unfired_tier<'a>(
+    tiers: &'a [TakeProfitTierConfig],
+    fired: &[TakeProfitTierRecord],
+    profit_pct: f64,
+) -> Option<(u32, &'a TakeProfitTierConfig)> {
+    tiers
+        .iter()
+        .enumerate()
+        .map(|(i, tier)| (i as u32 + 1, tier))
+        .find(|(_, tier)| {
+            profit_pct >= tier.trigger_pct
+                && !fired.iter().any(|r| r.trigger_pct == tier.trigger_pct)
+        })
+}
+
+/// Exit % of the remaining tokens that sells `sell_pct` of the original position
+fn tier_exit_pct(remaining: u64, total_exited: u64, sell_pct: f64) -> Option<f64> {
+    let original = remaining + total_exited;
+    if remaining == 0 || original == 0 {
+        return None;
+    }
+
+    let sell_tokens = original as f64 * sell_pct.min(100.0) / 100.0;
+    Some((sell_tokens / remaining as f64 * 100.0).min(100.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ladder() -> Vec<TakeProfitTierConfig> {
+        vec![
+            TakeProfitTierConfig {
+                trigger_pct: 25.0,
+                sell_pct: 30.0,
+            },
+            TakeProfitTierConfig {
+                trigger_pct: 60.0,
+                sell_pct: 30.0,
+            },
+        ]
+    }
+
+    fn fired_tier(tier: u32, trigger_pct: f64) -> TakeProfitTierRecord {
+        TakeProfitTierRecord {
+            position_id: 1,
+            tier,
+            trigger_pct,
+            sell_pct: 30.0,
+            trigger_price: 1.0,
+            transaction_signature: Some("sig".to_string()),
+            triggered_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_next_unfired_tier() {
+        let tiers = ladder();
+
+        assert!(next_unfired_tier(&tiers, &[], 10.0).is_none());
+        assert_eq!(next_unfired_tier(&tiers, &[], 30.0).unwrap().0, 1);
+        // Price gapped past both tiers: the lower one fires first
+        assert_eq!(next_unfired_tier(&tiers, &[], 80.0).unwrap().0, 1);
+        assert_eq!(
+            next_unfired_tier(&tiers, &[fired_tier(1, 25.0)], 80.0)
+                .unwrap()
+                .0,
+            2
+        );
+        assert!(
+            next_unfired_tier(&tiers, &[fired_tier(1, 25.0), fired_tier(2, 60.0)], 80.0).is_none()
+        );
+    }
+
+    #[test]
+    fn test_fired_tiers_follow_trigger_after_ladder_edit() {
+        // +25% fired as tier 1, then a +10% tier was added in front of it
+        let mut tiers = ladder();
+        tiers.insert(
+            0,
+            TakeProfitTierConfig {
+                trigger_pct: 10.0,
+                sell_pct: 20.0,
+            },
+        );
+        let fired = [fired_tier(1, 25.0)];
+
+        let (number, tier) = next_unfired_tier(&tiers, &fired, 30.0).unwrap();
+        assert_eq!(number, 1);
+        assert_eq!(tier.trigger_pct, 10.0);
+
+        // +25% (now tier 2) is not sold again
+        let fired = [fired[0].clone(), fired_tier(1, 10.0)];
+        assert!(next_unfired_tier(&tiers, &fired, 30.0).is_none());
+    }
+
+    #[test]
+    fn test_tier_exit_pct_sizes_against_original_position() {
+        // Nothing sold yet: 30% of the original is 30% of what remains
+        assert_eq!(tier_exit_pct(1_000, 0, 30.0), Some(30.0));
+
+        // 300 of 1000 sold: the next 30% of the original is 300 of the 700 left
+        let pct = tier_exit_pct(700, 300, 30.0).unwrap();
+        assert!((pct - 300.0 / 700.0 * 100.0).abs() < 1e-9);
+
+        // A tier larger than what remains sells everything
+        assert_eq!(tier_exit_pct(100, 900, 30.0), Some(100.0));
+        assert_eq!(tier_exit_pct(0, 1_000, 30.0), None);
+    }
+}
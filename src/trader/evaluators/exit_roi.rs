@@ -43,6 +43,7 @@ pub async fn check_roi_exit(
             action: TradeAction::Sell,
             reason: TradeReason::TakeProfit,
             strategy_id: None,
+            strategy_version: None,
             timestamp: Utc::now(),
             priority: TradePriority::Normal,
             price_sol: Some(current_price),
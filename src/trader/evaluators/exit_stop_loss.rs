@@ -113,8 +113,9 @@ pub async fn check_stop_loss(
             action: TradeAction::Sell,
             reason: TradeReason::StopLoss,
             strategy_id: None,
+            strategy_version: None,
             timestamp: Utc::now(),
-            priority: TradePriority::High, // High priority for stop loss
+            priority: TradePriority::Emergency, // Stop losses get the emergency fee percentile
             price_sol: Some(current_price),
             size_sol,
         }));
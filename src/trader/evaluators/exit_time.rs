@@ -77,6 +77,7 @@ pub async fn check_time_override(
                 action: TradeAction::Sell,
                 reason: TradeReason::TimeOverride,
                 strategy_id: None,
+                strategy_version: None,
                 timestamp: Utc::now(),
                 priority: TradePriority::High,
                 price_sol: Some(current_price),
@@ -41,37 +41,61 @@ pub async fn check_trailing_stop(
         ));
     }
 
-    // Calculate unrealized profit percentage using average entry price
+    // Profit is measured against the average entry price
     let entry_price = position.average_entry_price;
     if entry_price <= 0.0 || !entry_price.is_finite() {
         return Ok(None);
     }
 
-    let profit_pct = (current_price / entry_price - 1.0) * 100.0;
+    if trailing_stop_triggered(
+        entry_price,
+        position.price_highest,
+        current_price,
+        activation_pct,
+        distance_pct,
+    ) {
+        return Ok(Some(TradeDecision {
+            position_id: position.id.map(|id| id.to_string()),
+            mint: position.mint.clone(),
+            action: TradeAction::Sell,
+            reason: TradeReason::TrailingStop,
+            strategy_id: None,
+            strategy_version: None,
+            timestamp: Utc::now(),
+            priority: TradePriority::High, // High priority for trailing stops
+            price_sol: Some(current_price),
+            size_sol: None, // Will sell entire position
+        }));
+    }
 
-    // Check if profit exceeds activation threshold
-    if profit_pct >= activation_pct {
-        // Calculate stop price based on highest recorded price
-        let stop_price = position.price_highest * (1.0 - distance_pct / 100.0);
+    Ok(None)
+}
 
-        // Only trigger if still profitable - prevents exits at loss after price retracement
-        let current_profit_pct = (current_price / entry_price - 1.0) * 100.0;
+/// Trailing stop rule, shared with the backtest simulator
+///
+/// Active once profit at `current_price` reaches `activation_pct`; fires when the
+/// price is at or below `distance_pct` under the highest price and still in profit.
+pub fn trailing_stop_triggered(
+    entry_price: f64,
+    highest_price: f64,
+    current_price: f64,
+    activation_pct: f64,
+    distance_pct: f64,
+) -> bool {
+    if entry_price <= 0.0 || !entry_price.is_finite() {
+        return false;
+    }
+
+    let profit_pct = (current_price / entry_price - 1.0) * 100.0;
 
-        // Check if current price fell below stop price AND position is still profitable
-        if current_price <= stop_price && current_profit_pct > 0.0 {
-            return Ok(Some(TradeDecision {
-                position_id: position.id.map(|id| id.to_string()),
-                mint: position.mint.clone(),
-                action: TradeAction::Sell,
-                reason: TradeReason::TrailingStop,
-                strategy_id: None,
-                timestamp: Utc::now(),
-                priority: TradePriority::High, // High priority for trailing stops
-                price_sol: Some(current_price),
-                size_sol: None, // Will sell entire position
-            }));
-        }
+    // Check if profit exceeds activation threshold
+    if profit_pct < activation_pct {
+        return false;
     }
 
-    Ok(None)
+    // Calculate stop price based on highest recorded price
+    let stop_price = highest_price * (1.0 - distance_pct / 100.0);
+
+    // Only trigger if still profitable - prevents exits at loss after price retracement
+    current_price <= stop_price && profit_pct > 0.0
 }
@@ -119,7 +119,7 @@ impl StrategyEvaluator {
         .await;
 
         match evaluation_result {
-            Ok(Ok(Some(strategy_id))) => {
+            Ok(Ok(Some((strategy_id, strategy_version)))) => {
                 logger::info(
                     LogTag::Trader,
                     &format!(
@@ -134,6 +134,7 @@ impl StrategyEvaluator {
                     action: TradeAction::Buy,
                     reason: TradeReason::StrategySignal,
                     strategy_id: Some(strategy_id),
+                    strategy_version: Some(strategy_version),
                     timestamp: Utc::now(),
                     priority: TradePriority::Normal,
                     price_sol: Some(price_info.price_sol),
@@ -304,7 +305,7 @@ impl StrategyEvaluator {
         .await;
 
         match evaluation_result {
-            Ok(Ok(Some(strategy_id))) => {
+            Ok(Ok(Some((strategy_id, strategy_version)))) => {
                 logger::info(
                     LogTag::Trader,
                     &format!(
@@ -319,6 +320,7 @@ impl StrategyEvaluator {
                     action: TradeAction::Sell,
                     reason: TradeReason::StrategySignal,
                     strategy_id: Some(strategy_id),
+                    strategy_version: Some(strategy_version),
                     timestamp: Utc::now(),
                     priority: TradePriority::Normal,
                     price_sol: Some(current_price),
@@ -2,7 +2,9 @@
 
 use crate::logger::{self, LogTag};
 use crate::positions;
+use crate::strategies::types::StrategyType;
 use crate::trader::config;
+use crate::trader::sizing::{self, SizingDecision, SizingMode};
 use crate::trader::types::{TradeDecision, TradeResult};
 
 /// Execute a buy trade
@@ -22,18 +24,53 @@ pub async fn execute_buy(decision: &TradeDecision) -> Result<TradeResult, String
         ),
     );
 
-    // Note: Trade size is read from config by open_position_direct
-    // decision.size_sol is informational only - actual size comes from cfg.trader.trade_size_sol
-    let trade_size_sol = decision
-        .size_sol
-        .unwrap_or_else(|| config::get_trade_size_sol());
+    // Manual buys carry an explicit size; automated entries go through the sizing mode
+    let sizing = match decision.size_sol {
+        Some(size_sol) => SizingDecision {
+            mode: SizingMode::Fixed,
+            size_sol,
+            reason: format!("explicit size {:.4} SOL ({:?})", size_sol, decision.reason),
+        },
+        None => sizing::compute_entry_size(decision).await,
+    };
+
+    // A zero size means sizing declined the entry (or a manual buy had no size)
+    if sizing.is_skip() || !sizing.size_sol.is_finite() {
+        let message = format!(
+            "Entry skipped for {}: {} [{}]",
+            decision.mint,
+            sizing.reason,
+            sizing.mode.as_str()
+        );
+        logger::info(LogTag::Trader, &message);
+        return Ok(TradeResult::failure(decision.clone(), message, 0));
+    }
 
     // Enforce maximum trade size limit
-    let max_allowed = config::get_trade_size_sol() * crate::trader::constants::MAX_TRADE_SIZE_MULTIPLIER;
-    let trade_size_sol = trade_size_sol.min(max_allowed);
+    let max_allowed =
+        config::get_trade_size_sol() * crate::trader::constants::MAX_TRADE_SIZE_MULTIPLIER;
+    let trade_size_sol = sizing.size_sol.min(max_allowed);
+
+    logger::info(
+        LogTag::Trader,
+        &format!(
+            "Entry size for {}: {:.4} SOL [{}] {}",
+            decision.mint,
+            trade_size_sol,
+            sizing.mode.as_str(),
+            sizing.reason
+        ),
+    );
 
     // Call positions open with size so manual size is honored
-    match positions::open_position_with_size(&decision.mint, trade_size_sol).await {
+    match positions::open_position_with_size(
+        &decision.mint,
+        trade_size_sol,
+        decision.priority.clone(),
+        Some(sizing.to_entry_sizing()),
+    )
+    .await
+    {
         Ok(transaction_signature) => {
             logger::info(
                 LogTag::Trader,
@@ -43,6 +80,20 @@ pub async fn execute_buy(decision: &TradeDecision) -> Result<TradeResult, String
                 ),
             );
 
+            // Link the new position to the strategy revision (also feeds Kelly sizing)
+            if decision.strategy_version.is_some() {
+                if let Some(position_id) = positions::get_position_by_mint(&decision.mint)
+                    .await
+                    .and_then(|p| p.id)
+                {
+                    super::record_strategy_revision(
+                        &position_id.to_string(),
+                        decision,
+                        StrategyType::Entry,
+                    );
+                }
+            }
+
             Ok(TradeResult::success(
                 decision.clone(),
                 transaction_signature,
@@ -101,7 +152,9 @@ pub async fn execute_dca(decision: &TradeDecision) -> Result<TradeResult, String
         .unwrap_or_else(|| config::get_trade_size_sol() * 0.5); // Default to 50% of initial size
 
     // Call positions::add_to_position to handle DCA entry
-    match positions::add_to_position(&decision.mint, dca_amount_sol).await {
+    match positions::add_to_position(&decision.mint, dca_amount_sol, decision.priority.clone())
+        .await
+    {
         Ok(transaction_signature) => {
             logger::info(
                 LogTag::Trader,
@@ -7,6 +7,7 @@ pub use buy::{execute_buy, execute_dca};
 pub use sell::execute_sell;
 
 use crate::logger::{self, LogTag};
+use crate::strategies::types::StrategyType;
 use crate::trader::types::{TradeAction, TradeDecision, TradeResult};
 
 /// Initialize the execution system
@@ -24,3 +25,23 @@ pub async fn execute_trade(decision: &TradeDecision) -> Result<TradeResult, Stri
         TradeAction::DCA => buy::execute_dca(decision).await,
     }
 }
+
+/// Link a position to the strategy revision behind `decision` (no-op for non-strategy decisions)
+fn record_strategy_revision(position_id: &str, decision: &TradeDecision, role: StrategyType) {
+    let (Some(strategy_id), Some(version)) = (&decision.strategy_id, decision.strategy_version)
+    else {
+        return;
+    };
+
+    if let Err(e) =
+        crate::strategies::db::assign_strategy_to_position(position_id, strategy_id, version, role)
+    {
+        logger::warning(
+            LogTag::Trader,
+            &format!(
+                "Failed to link strategy {} v{} to position {}: {}",
+                strategy_id, version, position_id, e
+            ),
+        );
+    }
+}
@@ -3,6 +3,8 @@
 use crate::config::with_config;
 use crate::logger::{self, LogTag};
 use crate::positions;
+use crate::strategies::types::StrategyType;
+use crate::trader::evaluators;
 use crate::trader::types::{TradeDecision, TradeReason, TradeResult};
 
 /// Execute a sell trade
@@ -39,12 +41,36 @@ pub async fn execute_sell(decision: &TradeDecision) -> Result<TradeResult, Strin
     // Emergency exits are always full exits, otherwise check config and percentage
     let exit_reason = format!("{:?}", decision.reason);
 
-    if partial_exit_enabled && !is_emergency_exit && exit_percentage < 100.0 {
+    // Take-profit ladder tiers are claimed up front so a tier cannot fire twice
+    let ladder_tier = match (&decision.reason, decision.position_id.as_deref()) {
+        (TradeReason::TakeProfitTier(tier), Some(id)) => {
+            let position_id = id
+                .parse::<i64>()
+                .map_err(|e| format!("Invalid position id {}: {}", id, e))?;
+            let price = decision.price_sol.unwrap_or(0.0);
+            match evaluators::exit_ladder::claim_tier(position_id, *tier, price).await? {
+                Some(trigger_pct) => Some((position_id, trigger_pct)),
+                None => {
+                    let error = format!(
+                        "Take-profit tier {} already fired for position {}",
+                        tier, position_id
+                    );
+                    logger::debug(LogTag::Trader, &error);
+                    return Ok(TradeResult::failure(decision.clone(), error, 0));
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let result = if partial_exit_enabled && !is_emergency_exit && exit_percentage < 100.0 {
         // Partial exit
         match positions::partial_close_position(
             &decision.mint,
             exit_percentage,
             &exit_reason.clone(),
+            decision.priority.clone(),
+            ladder_tier.map(|(_, trigger_pct)| trigger_pct),
         )
         .await
         {
@@ -73,7 +99,13 @@ pub async fn execute_sell(decision: &TradeDecision) -> Result<TradeResult, Strin
         }
     } else {
         // Full exit (either disabled, emergency exit, or 100%)
-        match positions::close_position_direct(&decision.mint, exit_reason.clone()).await {
+        match positions::close_position_direct(
+            &decision.mint,
+            exit_reason.clone(),
+            decision.priority.clone(),
+        )
+        .await
+        {
             Ok(transaction_signature) => {
                 logger::info(
                     LogTag::Trader,
@@ -97,5 +129,43 @@ pub async fn execute_sell(decision: &TradeDecision) -> Result<TradeResult, Strin
                 Ok(TradeResult::failure(decision.clone(), error, 0))
             }
         }
+    };
+
+    if let Some((position_id, trigger_pct)) = ladder_tier {
+        finalize_ladder_tier(position_id, trigger_pct, &result).await;
+    }
+
+    if let (Ok(TradeResult { success: true, .. }), Some(position_id)) =
+        (&result, decision.position_id.as_deref())
+    {
+        super::record_strategy_revision(position_id, decision, StrategyType::Exit);
+    }
+
+    result
+}
+
+/// Attach the exit signature to a claimed ladder tier, or release the claim if the sell failed
+async fn finalize_ladder_tier(
+    position_id: i64,
+    trigger_pct: f64,
+    result: &Result<TradeResult, String>,
+) {
+    let outcome = match result {
+        Ok(TradeResult {
+            success: true,
+            tx_signature: Some(signature),
+            ..
+        }) => positions::set_take_profit_tier_signature(position_id, trigger_pct, signature).await,
+        _ => positions::release_take_profit_tier(position_id, trigger_pct).await,
+    };
+
+    if let Err(e) = outcome {
+        logger::error(
+            LogTag::Trader,
+            &format!(
+                "Failed to update take-profit tier +{}% for position {}: {}",
+                trigger_pct, position_id, e
+            ),
+        );
     }
 }
@@ -75,6 +75,7 @@ pub async fn manual_buy(mint: &str, size_sol: f64) -> Result<TradeResult, String
         action: TradeAction::Buy,
         reason: TradeReason::ManualEntry,
         strategy_id: None,
+        strategy_version: None,
         timestamp: Utc::now(),
         priority: TradePriority::High,
         price_sol: None,
@@ -206,6 +207,7 @@ pub async fn manual_sell(mint: &str, percentage: Option<f64>) -> Result<TradeRes
         action: TradeAction::Sell,
         reason: TradeReason::ManualExit,
         strategy_id: None,
+        strategy_version: None,
         timestamp: Utc::now(),
         priority: TradePriority::High,
         price_sol: None,
@@ -345,6 +347,7 @@ pub async fn manual_add(mint: &str, size_sol: f64) -> Result<TradeResult, String
         action: TradeAction::DCA,
         reason: TradeReason::ManualEntry,
         strategy_id: None,
+        strategy_version: None,
         timestamp: Utc::now(),
         priority: TradePriority::High,
         price_sol: None,
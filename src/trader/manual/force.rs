@@ -79,6 +79,7 @@ pub async fn force_buy(mint: &str, size_sol: f64) -> Result<TradeResult, String>
         action: TradeAction::Buy,
         reason: TradeReason::ForceBuy,
         strategy_id: None,
+        strategy_version: None,
         timestamp: Utc::now(),
         priority: TradePriority::High,
         price_sol: None,
@@ -208,6 +209,7 @@ pub async fn force_sell(mint: &str, percentage: Option<f64>) -> Result<TradeResu
         action: TradeAction::Sell,
         reason: TradeReason::ForceSell,
         strategy_id: None,
+        strategy_version: None,
         timestamp: Utc::now(),
         priority: TradePriority::Emergency,
         price_sol: None,
@@ -17,7 +17,9 @@
 //! - `evaluators/`: Entry/exit evaluation logic, DCA, strategies
 //! - `executors/`: Trade execution, retry mechanism, decision cache
 //! - `safety/`: Safety checks (limits, blacklist, cooldown, risk)
+//! - `sizing`: Risk-based entry sizing (fixed, wallet %, liquidity cap, volatility, Kelly)
 //! - `manual/`: Manual trading API (normal + force operations)
+//! - `orders/`: Persistent limit, stop and OCO orders
 //! - `constants`: All trader constants consolidated
 //! - `config`: Configuration accessors
 //! - `controller`: Start/stop trader control
@@ -33,8 +35,10 @@ pub mod evaluators;
 pub mod executors;
 pub mod manual;
 pub mod monitors;
+pub mod orders;
 pub mod safety;
 mod service;
+pub mod sizing;
 mod types;
 
 // Re-exports for common usage
@@ -53,6 +57,7 @@ pub async fn init_trader_system() -> Result<(), String> {
     // Initialize subsystems
     executors::init_execution_system().await?;
     safety::init_safety_system().await?;
+    orders::init_orders_db()?;
 
     logger::info(LogTag::Trader, "Trader system initialized");
     Ok(())
@@ -3,14 +3,17 @@
 //! This module contains orchestration-only code:
 //! - Entry monitor: Loops through available tokens, calls evaluators, executes trades
 //! - Exit monitor: Loops through open positions, calls evaluators, executes trades
+//! - Order monitor: Watches pending limit/stop/OCO orders and executes them when triggered
 //!
 //! All business logic (safety checks, strategy evaluation, exit conditions) is in evaluators module.
 
 mod entry;
 mod exit;
+mod orders;
 
 pub use entry::monitor_entries;
 pub use exit::monitor_positions;
+pub use orders::monitor_orders;
 
 use crate::events::{record_trader_event, Severity};
 use crate::logger::{self, LogTag};
@@ -38,6 +41,7 @@ pub async fn start_automated_trading(
     // Clone shutdown receiver for multiple tasks
     let entry_shutdown = shutdown.clone();
     let exit_shutdown = shutdown.clone();
+    let order_shutdown = shutdown.clone();
 
     // Spawn entry monitor
     let entry_task = tokio::spawn(async move {
@@ -79,8 +83,28 @@ pub async fn start_automated_trading(
         }
     });
 
-    // Wait for both tasks
-    let _ = tokio::try_join!(entry_task, exit_task);
+    // Spawn order monitor
+    let order_task = tokio::spawn(async move {
+        if let Err(e) = monitor_orders(order_shutdown).await {
+            logger::error(LogTag::Trader, &format!("Order monitor error: {}", e));
+
+            // Record order monitor error
+            record_trader_event(
+                "order_monitor_error",
+                Severity::Error,
+                None,
+                None,
+                json!({
+                    "monitor": "orders",
+                    "error": e.to_string(),
+                }),
+            )
+            .await;
+        }
+    });
+
+    // Wait for all tasks
+    let _ = tokio::try_join!(entry_task, exit_task, order_task);
 
     logger::info(LogTag::Trader, "Automated trading monitors stopped");
 
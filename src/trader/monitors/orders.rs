@@ -0,0 +1,296 @@
+//! Pending order monitoring - orchestration only
+//!
+//! This module handles:
+//! - Expiring overdue orders and cancelling sell orders whose position closed
+//! - Checking pool prices against order triggers
+//! - Executing triggered orders through the manual trading API
+//! - Event recording and Telegram notifications
+
+use crate::events::{record_trader_event, Severity};
+use crate::logger::{self, LogTag};
+use crate::positions;
+use crate::telegram::{queue_notification, Notification};
+use crate::trader::orders::{self, Order, OrderLeg, OrderStatus, OrderType};
+use crate::trader::{config, constants, manual};
+use chrono::Utc;
+use serde_json::json;
+use tokio::time::{sleep, Duration};
+
+/// Monitor pending orders and execute them when their trigger price is hit
+pub async fn monitor_orders(
+    mut shutdown: tokio::sync::watch::Receiver<bool>,
+) -> Result<(), String> {
+    logger::info(LogTag::Trader, "Starting order monitor");
+
+    // A restart while an order was executing leaves it in `triggered`; never re-fire those
+    match orders::fail_stale_triggered_orders() {
+        Ok(0) => {}
+        Ok(count) => logger::warning(
+            LogTag::Trader,
+            &format!(
+                "Marked {} interrupted order(s) as failed - check positions",
+                count
+            ),
+        ),
+        Err(e) => logger::error(
+            LogTag::Trader,
+            &format!("Failed to reset interrupted orders: {}", e),
+        ),
+    }
+
+    let mut was_paused = false;
+
+    loop {
+        if *shutdown.borrow() {
+            logger::info(LogTag::Trader, "Order monitor shutting down");
+            break;
+        }
+
+        // Orders stay pending while the trader is halted or disabled
+        if crate::global::is_force_stopped() || !config::is_trader_enabled() {
+            if !was_paused {
+                logger::info(
+                    LogTag::Trader,
+                    "Order monitor paused - trader stopped or disabled",
+                );
+                was_paused = true;
+            }
+            sleep(Duration::from_secs(1)).await;
+            continue;
+        }
+
+        if was_paused {
+            logger::info(LogTag::Trader, "Order monitor resumed");
+            was_paused = false;
+        }
+
+        match orders::get_pending_orders() {
+            Ok(pending) => {
+                for order in pending {
+                    if *shutdown.borrow() {
+                        break;
+                    }
+                    process_order(order).await;
+                }
+            }
+            Err(e) => {
+                logger::error(
+                    LogTag::Trader,
+                    &format!("Failed to load pending orders: {}", e),
+                );
+            }
+        }
+
+        tokio::select! {
+          _ = sleep(Duration::from_secs(constants::ORDER_MONITOR_INTERVAL_SECS)) => {},
+          _ = shutdown.changed() => {
+            if *shutdown.borrow() {
+              logger::info(LogTag::Trader, "Order monitor shutting down");
+              break;
+            }
+          }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check a single pending order and execute it if triggered
+async fn process_order(order: Order) {
+    if order.is_expired(Utc::now()) {
+        close_order(
+            &order,
+            OrderStatus::Expired,
+            "Order expired before triggering",
+        )
+        .await;
+        return;
+    }
+
+    if !order.order_type.is_buy() && positions::get_position_by_mint(&order.mint).await.is_none() {
+        close_order(
+            &order,
+            OrderStatus::Cancelled,
+            "Position closed before the order triggered",
+        )
+        .await;
+        return;
+    }
+
+    let price = match crate::pools::get_pool_price(&order.mint) {
+        Some(price) => price,
+        None => return,
+    };
+
+    let leg = match order.check_trigger(price.price_sol) {
+        Some(leg) => leg,
+        None => return,
+    };
+
+    // Claim first so a concurrent cancel or a second loop can never double-execute
+    match orders::claim_order(order.id, leg, price.price_sol) {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(e) => {
+            logger::error(
+                LogTag::Trader,
+                &format!("Failed to claim order #{}: {}", order.id, e),
+            );
+            return;
+        }
+    }
+
+    logger::info(
+        LogTag::Trader,
+        &format!(
+            "Order #{} ({}) triggered for {} at {:.9} SOL",
+            order.id,
+            describe_leg(&order, leg),
+            display_symbol(&order),
+            price.price_sol
+        ),
+    );
+
+    let result = if order.order_type.is_buy() {
+        let size_sol = order.size_sol.unwrap_or(0.0);
+        if positions::get_position_by_mint(&order.mint).await.is_some() {
+            manual::manual_add(&order.mint, size_sol).await
+        } else {
+            manual::manual_buy(&order.mint, size_sol).await
+        }
+    } else {
+        manual::manual_sell(&order.mint, order.sell_percentage).await
+    };
+
+    let (status, signature, error) = match result {
+        Ok(result) if result.success => (OrderStatus::Filled, result.tx_signature, None),
+        Ok(result) => (
+            OrderStatus::Failed,
+            result.tx_signature,
+            Some(result.error.unwrap_or_else(|| "Trade failed".to_string())),
+        ),
+        Err(e) => (OrderStatus::Failed, None, Some(e)),
+    };
+
+    if let Err(e) = orders::complete_order(order.id, status, signature.as_deref(), error.as_deref())
+    {
+        logger::error(
+            LogTag::Trader,
+            &format!("Failed to update order #{}: {}", order.id, e),
+        );
+    }
+
+    let details = match &error {
+        Some(error) => format!(
+            "Trigger: {} at {:.9} SOL\nError: {}",
+            describe_leg(&order, leg),
+            price.price_sol,
+            error
+        ),
+        None => format!(
+            "Trigger: {} at {:.9} SOL\n{}",
+            describe_leg(&order, leg),
+            price.price_sol,
+            describe_size(&order)
+        ),
+    };
+
+    record_trader_event(
+        &format!("order_{}", status.as_str()),
+        if status == OrderStatus::Filled {
+            Severity::Info
+        } else {
+            Severity::Warn
+        },
+        Some(&order.mint),
+        signature.as_deref(),
+        json!({
+            "order_id": order.id,
+            "order_type": order.order_type.as_str(),
+            "leg": leg,
+            "trigger_price_sol": order.trigger_price_sol,
+            "stop_price_sol": order.stop_price_sol,
+            "executed_at_price_sol": price.price_sol,
+            "size_sol": order.size_sol,
+            "sell_percentage": order.sell_percentage,
+            "error": error,
+        }),
+    )
+    .await;
+
+    notify(&order, status, details);
+}
+
+/// Close a pending order without executing it (expiry, position gone)
+async fn close_order(order: &Order, status: OrderStatus, reason: &str) {
+    match orders::close_pending_order(order.id, status, Some(reason)) {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(e) => {
+            logger::error(
+                LogTag::Trader,
+                &format!("Failed to close order #{}: {}", order.id, e),
+            );
+            return;
+        }
+    }
+
+    logger::info(
+        LogTag::Trader,
+        &format!(
+            "Order #{} for {} {}: {}",
+            order.id,
+            display_symbol(order),
+            status.as_str(),
+            reason
+        ),
+    );
+
+    record_trader_event(
+        &format!("order_{}", status.as_str()),
+        Severity::Info,
+        Some(&order.mint),
+        None,
+        json!({
+            "order_id": order.id,
+            "order_type": order.order_type.as_str(),
+            "reason": reason,
+        }),
+    )
+    .await;
+
+    notify(order, status, reason.to_string());
+}
+
+fn notify(order: &Order, status: OrderStatus, details: String) {
+    queue_notification(Notification::order_update(
+        display_symbol(order).to_string(),
+        order.mint.clone(),
+        order.id,
+        order.order_type.as_str(),
+        status.as_str(),
+        details,
+    ));
+}
+
+fn display_symbol(order: &Order) -> &str {
+    order.symbol.as_deref().unwrap_or(&order.mint)
+}
+
+fn describe_leg(order: &Order, leg: OrderLeg) -> &'static str {
+    match (order.order_type, leg) {
+        (OrderType::Oco, OrderLeg::Stop) => "stop",
+        (OrderType::Oco, _) => "take-profit",
+        (OrderType::BuyBelow, _) => "buy below",
+        (OrderType::SellAbove, _) => "sell above",
+        (OrderType::StopSell, _) => "stop",
+    }
+}
+
+fn describe_size(order: &Order) -> String {
+    match (order.size_sol, order.sell_percentage) {
+        (Some(size_sol), _) => format!("Size: {:.4} SOL", size_sol),
+        (_, Some(percentage)) => format!("Sold: {:.0}% of position", percentage),
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,341 @@
+//! Pending orders database
+//!
+//! Orders are scoped to the wallet that created them, like positions.
+
+use super::types::{NewOrder, Order, OrderLeg, OrderStatus, OrderType};
+use crate::logger::{self, LogTag};
+use crate::migrations::{self, Migration, MigrationSet};
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use r2d2::{Pool, PooledConnection};
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{params, OptionalExtension};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ORDERS_DB_INITIALIZED: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(false));
+
+const SCHEMA_ORDERS: &str = r#"
+CREATE TABLE IF NOT EXISTS orders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    wallet_address TEXT NOT NULL,
+    mint TEXT NOT NULL,
+    symbol TEXT,
+    order_type TEXT NOT NULL, -- 'buy_below', 'sell_above', 'stop_sell', 'oco'
+    status TEXT NOT NULL, -- 'pending', 'triggered', 'filled', 'failed', 'cancelled', 'expired'
+    trigger_price_sol REAL NOT NULL,
+    stop_price_sol REAL, -- OCO stop leg
+    size_sol REAL, -- Buy orders
+    sell_percentage REAL, -- Sell orders
+    created_at TEXT NOT NULL,
+    expires_at TEXT,
+    triggered_at TEXT,
+    triggered_leg TEXT,
+    triggered_price_sol REAL,
+    completed_at TEXT,
+    tx_signature TEXT,
+    error TEXT,
+    note TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_orders_wallet_status ON orders(wallet_address, status);
+CREATE INDEX IF NOT EXISTS idx_orders_mint ON orders(mint, status);
+CREATE INDEX IF NOT EXISTS idx_orders_created ON orders(created_at DESC);
+"#;
+
+/// Ordered schema migrations of orders.db
+pub const ORDERS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "orders",
+    migrations: &[Migration::sql(1, "Baseline schema", SCHEMA_ORDERS)],
+};
+
+const ORDER_COLUMNS: &str = "id, mint, symbol, order_type, status, trigger_price_sol, stop_price_sol, size_sol, sell_percentage, created_at, expires_at, triggered_at, triggered_leg, triggered_price_sol, completed_at, tx_signature, error, note";
+
+// =============================================================================
+// CONNECTION POOL
+// =============================================================================
+
+static DB_POOL: Lazy<Pool<SqliteConnectionManager>> = Lazy::new(|| {
+    let db_path = crate::paths::get_orders_db_path();
+    let manager = SqliteConnectionManager::file(&db_path);
+    Pool::builder()
+        .max_size(5)
+        .build(manager)
+        .expect("Failed to create orders database pool")
+});
+
+fn get_connection() -> Result<PooledConnection<SqliteConnectionManager>, String> {
+    if !ORDERS_DB_INITIALIZED.load(Ordering::Relaxed) {
+        init_orders_db()?;
+    }
+    DB_POOL
+        .get()
+        .map_err(|e| format!("Failed to get orders database connection: {}", e))
+}
+
+fn current_wallet() -> Result<String, String> {
+    crate::utils::get_wallet_address().map_err(|e| e.to_string())
+}
+
+/// Initialize the orders database
+pub fn init_orders_db() -> Result<(), String> {
+    if ORDERS_DB_INITIALIZED.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+
+    let conn = DB_POOL
+        .get()
+        .map_err(|e| format!("Failed to get orders database connection: {}", e))?;
+
+    conn.execute_batch(
+        "
+        PRAGMA journal_mode = WAL;
+        PRAGMA synchronous = NORMAL;
+        PRAGMA busy_timeout = 30000;
+    ",
+    )
+    .map_err(|e| format!("Failed to set pragmas: {}", e))?;
+
+    migrations::run_migrations(&conn, &ORDERS_MIGRATIONS)?;
+
+    ORDERS_DB_INITIALIZED.store(true, Ordering::Relaxed);
+    logger::debug(LogTag::Trader, "Orders database initialized");
+    Ok(())
+}
+
+fn parse_time(value: Option<String>) -> Option<DateTime<Utc>> {
+    value
+        .and_then(|v| DateTime::parse_from_rfc3339(&v).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn row_to_order(row: &rusqlite::Row) -> rusqlite::Result<Order> {
+    let order_type: String = row.get(3)?;
+    let status: String = row.get(4)?;
+    let triggered_leg: Option<String> = row.get(12)?;
+
+    Ok(Order {
+        id: row.get(0)?,
+        mint: row.get(1)?,
+        symbol: row.get(2)?,
+        order_type: order_type.parse().unwrap_or(OrderType::SellAbove),
+        status: status.parse().unwrap_or(OrderStatus::Failed),
+        trigger_price_sol: row.get(5)?,
+        stop_price_sol: row.get(6)?,
+        size_sol: row.get(7)?,
+        sell_percentage: row.get(8)?,
+        created_at: parse_time(row.get(9)?).unwrap_or_else(Utc::now),
+        expires_at: parse_time(row.get(10)?),
+        triggered_at: parse_time(row.get(11)?),
+        triggered_leg: triggered_leg.and_then(|leg| {
+            serde_json::from_value::<OrderLeg>(serde_json::Value::String(leg)).ok()
+        }),
+        triggered_price_sol: row.get(13)?,
+        completed_at: parse_time(row.get(14)?),
+        tx_signature: row.get(15)?,
+        error: row.get(16)?,
+        note: row.get(17)?,
+    })
+}
+
+// =============================================================================
+// ORDER OPERATIONS
+// =============================================================================
+
+/// Insert a new pending order and return it
+pub fn insert_order(order: &NewOrder, symbol: Option<&str>) -> Result<Order, String> {
+    let conn = get_connection()?;
+    let wallet_address = current_wallet()?;
+
+    conn.execute(
+        "INSERT INTO orders (wallet_address, mint, symbol, order_type, status, trigger_price_sol,
+         stop_price_sol, size_sol, sell_percentage, created_at, expires_at, note)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        params![
+            wallet_address,
+            order.mint,
+            symbol,
+            order.order_type.as_str(),
+            OrderStatus::Pending.as_str(),
+            order.trigger_price_sol,
+            order.stop_price_sol,
+            order.size_sol,
+            order.sell_percentage,
+            Utc::now().to_rfc3339(),
+            order.expires_at.map(|t| t.to_rfc3339()),
+            order.note,
+        ],
+    )
+    .map_err(|e| format!("Failed to insert order: {}", e))?;
+
+    let id = conn.last_insert_rowid();
+    get_order(id)?.ok_or_else(|| format!("Order {} not found after insert", id))
+}
+
+/// Get an order of the current wallet by ID
+pub fn get_order(id: i64) -> Result<Option<Order>, String> {
+    let conn = get_connection()?;
+    let wallet_address = current_wallet()?;
+
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM orders WHERE id = ?1 AND wallet_address = ?2",
+            ORDER_COLUMNS
+        ),
+        params![id, wallet_address],
+        row_to_order,
+    )
+    .optional()
+    .map_err(|e| format!("Failed to load order {}: {}", id, e))
+}
+
+/// List orders of the current wallet, newest first
+pub fn get_orders(status: Option<OrderStatus>, limit: usize) -> Result<Vec<Order>, String> {
+    let conn = get_connection()?;
+    let wallet_address = current_wallet()?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM orders WHERE wallet_address = ?1 AND (?2 IS NULL OR status = ?2)
+             ORDER BY created_at DESC LIMIT ?3",
+            ORDER_COLUMNS
+        ))
+        .map_err(|e| format!("Failed to prepare orders query: {}", e))?;
+
+    let orders = stmt
+        .query_map(
+            params![wallet_address, status.map(|s| s.as_str()), limit as i64],
+            row_to_order,
+        )
+        .map_err(|e| format!("Failed to query orders: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect orders: {}", e))?;
+
+    Ok(orders)
+}
+
+/// Pending orders of the current wallet, oldest first
+pub fn get_pending_orders() -> Result<Vec<Order>, String> {
+    let conn = get_connection()?;
+    let wallet_address = current_wallet()?;
+
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM orders WHERE wallet_address = ?1 AND status = 'pending'
+             ORDER BY created_at ASC",
+            ORDER_COLUMNS
+        ))
+        .map_err(|e| format!("Failed to prepare pending orders query: {}", e))?;
+
+    let orders = stmt
+        .query_map(params![wallet_address], row_to_order)
+        .map_err(|e| format!("Failed to query pending orders: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect pending orders: {}", e))?;
+
+    Ok(orders)
+}
+
+/// Distinct mints with pending orders for the current wallet
+pub fn get_pending_order_mints(buy_only: bool) -> Result<Vec<String>, String> {
+    let conn = get_connection()?;
+    let wallet_address = current_wallet()?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT DISTINCT mint FROM orders
+             WHERE wallet_address = ?1 AND status = 'pending' AND (?2 = 0 OR order_type = 'buy_below')",
+        )
+        .map_err(|e| format!("Failed to prepare pending mints query: {}", e))?;
+
+    let mints = stmt
+        .query_map(params![wallet_address, buy_only], |row| row.get(0))
+        .map_err(|e| format!("Failed to query pending mints: {}", e))?
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|e| format!("Failed to collect pending mints: {}", e))?;
+
+    Ok(mints)
+}
+
+/// Atomically move a pending order to triggered. Returns false if it was no longer pending.
+pub fn claim_order(id: i64, leg: OrderLeg, price_sol: f64) -> Result<bool, String> {
+    let conn = get_connection()?;
+    let leg = serde_json::to_value(leg)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string));
+
+    let updated = conn
+        .execute(
+            "UPDATE orders SET status = 'triggered', triggered_at = ?2, triggered_leg = ?3,
+             triggered_price_sol = ?4 WHERE id = ?1 AND status = 'pending'",
+            params![id, Utc::now().to_rfc3339(), leg, price_sol],
+        )
+        .map_err(|e| format!("Failed to claim order {}: {}", id, e))?;
+
+    Ok(updated > 0)
+}
+
+/// Record the final state of an order
+pub fn complete_order(
+    id: i64,
+    status: OrderStatus,
+    tx_signature: Option<&str>,
+    error: Option<&str>,
+) -> Result<(), String> {
+    let conn = get_connection()?;
+
+    conn.execute(
+        "UPDATE orders SET status = ?2, completed_at = ?3, tx_signature = ?4, error = ?5
+         WHERE id = ?1",
+        params![
+            id,
+            status.as_str(),
+            Utc::now().to_rfc3339(),
+            tx_signature,
+            error
+        ],
+    )
+    .map_err(|e| format!("Failed to update order {}: {}", id, e))?;
+
+    Ok(())
+}
+
+/// Move a pending order of the current wallet to a final state (cancelled/expired).
+/// Returns false if it was no longer pending.
+pub fn close_pending_order(
+    id: i64,
+    status: OrderStatus,
+    reason: Option<&str>,
+) -> Result<bool, String> {
+    let conn = get_connection()?;
+    let wallet_address = current_wallet()?;
+
+    let updated = conn
+        .execute(
+            "UPDATE orders SET status = ?3, completed_at = ?4, error = ?5
+             WHERE id = ?1 AND wallet_address = ?2 AND status = 'pending'",
+            params![
+                id,
+                wallet_address,
+                status.as_str(),
+                Utc::now().to_rfc3339(),
+                reason
+            ],
+        )
+        .map_err(|e| format!("Failed to close order {}: {}", id, e))?;
+
+    Ok(updated > 0)
+}
+
+/// Orders left in `triggered` by a crash mid-execution cannot be trusted to retry
+pub fn fail_stale_triggered_orders() -> Result<usize, String> {
+    let conn = get_connection()?;
+    let wallet_address = current_wallet()?;
+
+    conn.execute(
+        "UPDATE orders SET status = 'failed', completed_at = ?2,
+         error = 'Interrupted while executing (check positions before re-creating)'
+         WHERE wallet_address = ?1 AND status = 'triggered'",
+        params![wallet_address, Utc::now().to_rfc3339()],
+    )
+    .map_err(|e| format!("Failed to reset triggered orders: {}", e))
+}
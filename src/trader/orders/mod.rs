@@ -0,0 +1,133 @@
+//! Persistent pending orders (limit buy, take-profit, stop, OCO)
+//!
+//! Orders are stored in `orders.db` and survive restarts. The order monitor
+//! (`monitors::orders`) watches pool prices and executes triggered orders
+//! through the manual trading API.
+
+mod db;
+mod types;
+
+pub use db::{
+    claim_order, close_pending_order, complete_order, fail_stale_triggered_orders, get_order,
+    get_orders, get_pending_order_mints, get_pending_orders, init_orders_db, ORDERS_MIGRATIONS,
+};
+pub use types::{NewOrder, Order, OrderLeg, OrderStatus, OrderType};
+
+use crate::config::with_config;
+use crate::logger::{self, LogTag};
+use crate::trader::constants::MAX_TRADE_SIZE_MULTIPLIER;
+use chrono::Utc;
+
+/// Validate and persist a new pending order
+pub async fn create_order(mut order: NewOrder) -> Result<Order, String> {
+    order.mint = order.mint.trim().to_string();
+    if order.mint.is_empty() {
+        return Err("Mint is required".to_string());
+    }
+    if !order.trigger_price_sol.is_finite() || order.trigger_price_sol <= 0.0 {
+        return Err(format!(
+            "Invalid trigger price: {}. Must be positive",
+            order.trigger_price_sol
+        ));
+    }
+    if let Some(expires_at) = order.expires_at {
+        if expires_at <= Utc::now() {
+            return Err("Expiry must be in the future".to_string());
+        }
+    }
+
+    if order.order_type.is_buy() {
+        let size_sol = order
+            .size_sol
+            .ok_or_else(|| "size_sol is required for buy orders".to_string())?;
+        if !size_sol.is_finite() || size_sol <= 0.0 {
+            return Err(format!(
+                "Invalid SOL amount: {}. Must be positive",
+                size_sol
+            ));
+        }
+        let max_trade_size =
+            with_config(|cfg| cfg.trader.trade_size_sol) * MAX_TRADE_SIZE_MULTIPLIER;
+        if size_sol > max_trade_size {
+            return Err(format!(
+                "SOL amount {:.4} exceeds maximum trade size of {:.4} SOL",
+                size_sol, max_trade_size
+            ));
+        }
+        order.sell_percentage = None;
+        order.stop_price_sol = None;
+    } else {
+        if crate::positions::get_position_by_mint(&order.mint)
+            .await
+            .is_none()
+        {
+            return Err(format!("No open position for {}", order.mint));
+        }
+        let percentage = order.sell_percentage.unwrap_or(100.0);
+        if !percentage.is_finite() || percentage <= 0.0 || percentage > 100.0 {
+            return Err(format!(
+                "Invalid sell percentage: {}. Must be between 0 and 100",
+                percentage
+            ));
+        }
+        order.sell_percentage = Some(percentage);
+        order.size_sol = None;
+
+        if order.order_type == OrderType::Oco {
+            let stop = order
+                .stop_price_sol
+                .ok_or_else(|| "stop_price_sol is required for OCO orders".to_string())?;
+            if !stop.is_finite() || stop <= 0.0 || stop >= order.trigger_price_sol {
+                return Err(format!(
+                    "OCO stop price {} must be positive and below the take-profit price {}",
+                    stop, order.trigger_price_sol
+                ));
+            }
+        } else {
+            order.stop_price_sol = None;
+        }
+    }
+
+    let symbol = crate::tokens::get_full_token_async(&order.mint)
+        .await
+        .ok()
+        .flatten()
+        .map(|t| t.symbol);
+
+    let created = db::insert_order(&order, symbol.as_deref())?;
+
+    logger::info(
+        LogTag::Trader,
+        &format!(
+            "Created {} order #{} for {} at {:.9} SOL",
+            created.order_type.as_str(),
+            created.id,
+            created.symbol.as_deref().unwrap_or(&created.mint),
+            created.trigger_price_sol
+        ),
+    );
+
+    Ok(created)
+}
+
+/// Cancel a pending order
+pub fn cancel_order(id: i64) -> Result<Order, String> {
+    let order = db::get_order(id)?.ok_or_else(|| format!("Order {} not found", id))?;
+    if order.status != OrderStatus::Pending {
+        return Err(format!(
+            "Order {} is {} and can no longer be cancelled",
+            id,
+            order.status.as_str()
+        ));
+    }
+    if !db::close_pending_order(id, OrderStatus::Cancelled, None)? {
+        return Err(format!(
+            "Order {} was triggered before it could be cancelled",
+            id
+        ));
+    }
+
+    logger::info(LogTag::Trader, &format!("Cancelled order #{}", id));
+
+    db::get_order(id)?.ok_or_else(|| format!("Order {} not found", id))
+}
@@ -0,0 +1,233 @@
+//! Pending order types and trigger logic
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kind of pending order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    /// Buy once the price drops to or below `trigger_price_sol`
+    BuyBelow,
+    /// Sell once the price rises to or above `trigger_price_sol`
+    SellAbove,
+    /// Sell once the price drops to or below `trigger_price_sol`
+    StopSell,
+    /// Take-profit at `trigger_price_sol` or stop at `stop_price_sol`, whichever fills first
+    Oco,
+}
+
+impl OrderType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::BuyBelow => "buy_below",
+            OrderType::SellAbove => "sell_above",
+            OrderType::StopSell => "stop_sell",
+            OrderType::Oco => "oco",
+        }
+    }
+
+    pub fn is_buy(&self) -> bool {
+        matches!(self, OrderType::BuyBelow)
+    }
+}
+
+impl std::str::FromStr for OrderType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "buy_below" => Ok(OrderType::BuyBelow),
+            "sell_above" => Ok(OrderType::SellAbove),
+            "stop_sell" => Ok(OrderType::StopSell),
+            "oco" => Ok(OrderType::Oco),
+            _ => Err(format!("Unknown order type: {}", s)),
+        }
+    }
+}
+
+/// Lifecycle of a pending order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    Pending,
+    /// Trigger hit, trade in flight
+    Triggered,
+    Filled,
+    Failed,
+    Cancelled,
+    Expired,
+}
+
+impl OrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Pending => "pending",
+            OrderStatus::Triggered => "triggered",
+            OrderStatus::Filled => "filled",
+            OrderStatus::Failed => "failed",
+            OrderStatus::Cancelled => "cancelled",
+            OrderStatus::Expired => "expired",
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        matches!(self, OrderStatus::Pending | OrderStatus::Triggered)
+    }
+}
+
+impl std::str::FromStr for OrderStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(OrderStatus::Pending),
+            "triggered" => Ok(OrderStatus::Triggered),
+            "filled" => Ok(OrderStatus::Filled),
+            "failed" => Ok(OrderStatus::Failed),
+            "cancelled" => Ok(OrderStatus::Cancelled),
+            "expired" => Ok(OrderStatus::Expired),
+            _ => Err(format!("Unknown order status: {}", s)),
+        }
+    }
+}
+
+/// Which side of an order fired
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderLeg {
+    /// Single-trigger orders
+    Trigger,
+    /// OCO take-profit side
+    TakeProfit,
+    /// OCO stop side
+    Stop,
+}
+
+/// A persisted pending order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    pub id: i64,
+    pub mint: String,
+    pub symbol: Option<String>,
+    pub order_type: OrderType,
+    pub status: OrderStatus,
+    /// Buy-below / sell-above / stop threshold, or the OCO take-profit price
+    pub trigger_price_sol: f64,
+    /// OCO stop price
+    pub stop_price_sol: Option<f64>,
+    /// SOL to spend (buy orders)
+    pub size_sol: Option<f64>,
+    /// Percentage of the position to sell (sell orders)
+    pub sell_percentage: Option<f64>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub triggered_at: Option<DateTime<Utc>>,
+    pub triggered_leg: Option<OrderLeg>,
+    pub triggered_price_sol: Option<f64>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub tx_signature: Option<String>,
+    pub error: Option<String>,
+    pub note: Option<String>,
+}
+
+impl Order {
+    /// Leg that fires at `price_sol`, if any
+    pub fn check_trigger(&self, price_sol: f64) -> Option<OrderLeg> {
+        if !price_sol.is_finite() || price_sol <= 0.0 {
+            return None;
+        }
+
+        match self.order_type {
+            OrderType::BuyBelow | OrderType::StopSell => {
+                (price_sol <= self.trigger_price_sol).then_some(OrderLeg::Trigger)
+            }
+            OrderType::SellAbove => {
+                (price_sol >= self.trigger_price_sol).then_some(OrderLeg::Trigger)
+            }
+            OrderType::Oco => {
+                if price_sol >= self.trigger_price_sol {
+                    Some(OrderLeg::TakeProfit)
+                } else if self.stop_price_sol.is_some_and(|stop| price_sol <= stop) {
+                    Some(OrderLeg::Stop)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+/// Parameters for a new order
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewOrder {
+    pub mint: String,
+    pub order_type: OrderType,
+    pub trigger_price_sol: f64,
+    #[serde(default)]
+    pub stop_price_sol: Option<f64>,
+    #[serde(default)]
+    pub size_sol: Option<f64>,
+    #[serde(default)]
+    pub sell_percentage: Option<f64>,
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(order_type: OrderType, trigger: f64, stop: Option<f64>) -> Order {
+        Order {
+            id: 1,
+            mint: "mint".to_string(),
+            symbol: None,
+            order_type,
+            status: OrderStatus::Pending,
+            trigger_price_sol: trigger,
+            stop_price_sol: stop,
+            size_sol: Some(0.1),
+            sell_percentage: None,
+            created_at: Utc::now(),
+            expires_at: None,
+            triggered_at: None,
+            triggered_leg: None,
+            triggered_price_sol: None,
+            completed_at: None,
+            tx_signature: None,
+            error: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn single_trigger_orders_fire_on_the_right_side() {
+        let buy = order(OrderType::BuyBelow, 1.0, None);
+        assert_eq!(buy.check_trigger(1.1), None);
+        assert_eq!(buy.check_trigger(1.0), Some(OrderLeg::Trigger));
+
+        let sell = order(OrderType::SellAbove, 2.0, None);
+        assert_eq!(sell.check_trigger(1.9), None);
+        assert_eq!(sell.check_trigger(2.5), Some(OrderLeg::Trigger));
+
+        let stop = order(OrderType::StopSell, 0.5, None);
+        assert_eq!(stop.check_trigger(0.6), None);
+        assert_eq!(stop.check_trigger(0.4), Some(OrderLeg::Trigger));
+        assert_eq!(stop.check_trigger(0.0), None);
+    }
+
+    #[test]
+    fn oco_fires_whichever_leg_is_hit() {
+        let oco = order(OrderType::Oco, 2.0, Some(0.5));
+        assert_eq!(oco.check_trigger(1.0), None);
+        assert_eq!(oco.check_trigger(2.0), Some(OrderLeg::TakeProfit));
+        assert_eq!(oco.check_trigger(0.5), Some(OrderLeg::Stop));
+    }
+}
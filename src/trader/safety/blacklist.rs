@@ -40,6 +40,7 @@ pub fn check_blacklist_exit(position: &Position, current_price: f64) -> Option<T
             action: TradeAction::Sell,
             reason: TradeReason::Blacklisted,
             strategy_id: None,
+            strategy_version: None,
             timestamp: Utc::now(),
             priority: TradePriority::Emergency,
             price_sol: Some(current_price),
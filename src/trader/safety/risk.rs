@@ -34,6 +34,7 @@ pub async fn check_risk_limits(
             action: TradeAction::Sell,
             reason: TradeReason::RiskManagement,
             strategy_id: None,
+            strategy_version: None,
             timestamp: Utc::now(),
             priority: TradePriority::Emergency,
             price_sol: Some(current_price),
@@ -0,0 +1,540 @@
+//! Risk-based position sizing
+//!
+//! Picks the SOL size of automated entries according to `trader.sizing_mode`:
+//! - `fixed`: `trader.trade_size_sol`
+//! - `wallet_percent`: a percentage of the wallet SOL balance
+//! - `liquidity_capped`: trade size capped to a percentage of the pool SOL reserve
+//! - `volatility`: trade size scaled by target ATR % / current ATR %
+//! - `kelly`: fractional Kelly bet from the strategy's closed trades (or latest backtest)
+//!
+//! Every mode falls back to the fixed trade size when its inputs are unavailable, and
+//! the result is clamped to [`sizing_min_size_sol`, trade size × MAX_TRADE_SIZE_MULTIPLIER].
+//! A mode that finds no reason to trade (Kelly without edge, a pool too shallow for the
+//! minimum size) returns a zero size, which skips the entry instead of being clamped up.
+//! In paper mode the wallet-based modes use the simulated paper balance.
+
+use crate::config::with_config;
+use crate::logger::{self, LogTag};
+use crate::ohlcvs::{Candle, Timeframe};
+use crate::positions::EntrySizing;
+use crate::strategies::indicators;
+use crate::trader::constants::MAX_TRADE_SIZE_MULTIPLIER;
+use crate::trader::types::TradeDecision;
+use std::collections::HashSet;
+
+/// Strategy positions considered for Kelly statistics
+const KELLY_LOOKBACK_POSITIONS: usize = 200;
+
+/// Volatility scaling never moves the size outside [base / 4, base × 4]
+const VOLATILITY_SCALE_LIMIT: f64 = 4.0;
+
+/// Entry sizing mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizingMode {
+    Fixed,
+    WalletPercent,
+    LiquidityCapped,
+    Volatility,
+    Kelly,
+}
+
+impl SizingMode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "fixed" => Some(SizingMode::Fixed),
+            "wallet_percent" => Some(SizingMode::WalletPercent),
+            "liquidity_capped" => Some(SizingMode::LiquidityCapped),
+            "volatility" => Some(SizingMode::Volatility),
+            "kelly" => Some(SizingMode::Kelly),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SizingMode::Fixed => "fixed",
+            SizingMode::WalletPercent => "wallet_percent",
+            SizingMode::LiquidityCapped => "liquidity_capped",
+            SizingMode::Volatility => "volatility",
+            SizingMode::Kelly => "kelly",
+        }
+    }
+}
+
+/// Chosen entry size with the reasoning behind it
+#[derive(Debug, Clone)]
+pub struct SizingDecision {
+    pub mode: SizingMode,
+    pub size_sol: f64,
+    pub reason: String,
+}
+
+impl SizingDecision {
+    /// Decision not to enter at all
+    pub fn skip(mode: SizingMode, reason: String) -> Self {
+        Self {
+            mode,
+            size_sol: 0.0,
+            reason,
+        }
+    }
+
+    /// Whether this decision skips the entry
+    pub fn is_skip(&self) -> bool {
+        self.size_sol <= 0.0
+    }
+
+    /// Sizing record stored on the opened position
+    pub fn to_entry_sizing(&self) -> EntrySizing {
+        EntrySizing {
+            mode: self.mode.as_str().to_string(),
+            reason: self.reason.clone(),
+        }
+    }
+}
+
+/// Compute the entry size for an automated buy decision
+pub async fn compute_entry_size(decision: &TradeDecision) -> SizingDecision {
+    let (mode_str, base_size, min_size) = with_config(|cfg| {
+        (
+            cfg.trader.sizing_mode.clone(),
+            cfg.trader.trade_size_sol,
+            cfg.trader.sizing_min_size_sol,
+        )
+    });
+    let mode = SizingMode::parse(&mode_str).unwrap_or(SizingMode::Fixed);
+
+    let (raw_size, reason) = match mode {
+        SizingMode::Fixed => (base_size, format!("fixed trade size {:.4} SOL", base_size)),
+        SizingMode::WalletPercent => wallet_percent_size(base_size).await,
+        SizingMode::LiquidityCapped => liquidity_capped_size(&decision.mint, base_size, min_size),
+        SizingMode::Volatility => volatility_size(&decision.mint, base_size).await,
+        SizingMode::Kelly => kelly_size(decision.strategy_id.as_deref(), base_size).await,
+    };
+
+    let max_size = base_size * MAX_TRADE_SIZE_MULTIPLIER;
+    let sizing = resolve_size(mode, raw_size, reason, min_size, max_size);
+
+    if sizing.is_skip() {
+        logger::info(
+            LogTag::Trader,
+            &format!(
+                "Skipping entry for {} ({}: {})",
+                decision.mint,
+                mode.as_str(),
+                sizing.reason
+            ),
+        );
+    } else {
+        logger::debug(
+            LogTag::Trader,
+            &format!(
+                "Entry size for {}: {:.4} SOL ({}: {})",
+                decision.mint,
+                sizing.size_sol,
+                mode.as_str(),
+                sizing.reason
+            ),
+        );
+    }
+
+    sizing
+}
+
+/// Turn a mode's raw size into the final decision
+///
+/// A raw size of zero or less is the mode declining the trade and stays a skip; any
+/// other size is clamped to [min_size, max_size].
+fn resolve_size(
+    mode: SizingMode,
+    raw_size: f64,
+    reason: String,
+    min_size: f64,
+    max_size: f64,
+) -> SizingDecision {
+    if raw_size <= 0.0 {
+        return SizingDecision::skip(mode, reason);
+    }
+
+    let size_sol = clamp_size(raw_size, min_size, max_size);
+    let reason = if (size_sol - raw_size).abs() > f64::EPSILON {
+        format!(
+            "{}; clamped to {:.4} SOL (min {:.4}, max {:.4})",
+            reason, size_sol, min_size, max_size
+        )
+    } else {
+        reason
+    };
+
+    SizingDecision {
+        mode,
+        size_sol,
+        reason,
+    }
+}
+
+// ============================================================================
+// MODES
+// ============================================================================
+
+async fn wallet_percent_size(base_size: f64) -> (f64, String) {
+    let percent = with_config(|cfg| cfg.trader.sizing_wallet_percent);
+    let (balance, account) = sizing_balance().await;
+
+    match balance {
+        Ok(balance) if balance > 0.0 => (
+            balance * percent / 100.0,
+            format!("{:.2}% of {:.4} SOL {} balance", percent, balance, account),
+        ),
+        Ok(_) => (
+            base_size,
+            format!("{} balance is zero, using trade size", account),
+        ),
+        Err(e) => (
+            base_size,
+            format!("{} balance unavailable ({}), using trade size", account, e),
+        ),
+    }
+}
+
+fn liquidity_capped_size(mint: &str, base_size: f64, min_size: f64) -> (f64, String) {
+    let max_pool_percent = with_config(|cfg| cfg.trader.sizing_max_pool_percent);
+
+    match crate::pools::get_pool_price(mint) {
+        Some(price) if price.sol_reserves > 0.0 => {
+            liquidity_size(price.sol_reserves, max_pool_percent, base_size, min_size)
+        }
+        _ => (
+            base_size,
+            "pool reserve unknown, using trade size".to_string(),
+        ),
+    }
+}
+
+async fn volatility_size(mint: &str, base_size: f64) -> (f64, String) {
+    let (timeframe, period, target_atr) = with_config(|cfg| {
+        (
+            cfg.trader.sizing_atr_timeframe.clone(),
+            cfg.trader.sizing_atr_period,
+            cfg.trader.sizing_target_atr_percent,
+        )
+    });
+    let Some(tf) = Timeframe::from_str(&timeframe) else {
+        return (
+            base_size,
+            format!("invalid ATR timeframe '{}', using trade size", timeframe),
+        );
+    };
+
+    let candles = match crate::ohlcvs::get_ohlcv_data(mint, tf, None, period + 1, None, None).await
+    {
+        Ok(candles) => candles,
+        Err(e) => {
+            return (
+                base_size,
+                format!("OHLCV unavailable ({}), using trade size", e),
+            )
+        }
+    };
+
+    match average_true_range_pct(&candles, period) {
+        Some(atr) if atr > 0.0 => {
+            let scale =
+                (target_atr / atr).clamp(1.0 / VOLATILITY_SCALE_LIMIT, VOLATILITY_SCALE_LIMIT);
+            (
+                base_size * scale,
+                format!(
+                    "{} ATR({}) {:.2}% vs target {:.2}% → ×{:.2}",
+                    timeframe, period, atr, target_atr, scale
+                ),
+            )
+        }
+        _ => (
+            base_size,
+            format!(
+                "not enough {} candles for ATR({}), using trade size",
+                timeframe, period
+            ),
+        ),
+    }
+}
+
+async fn kelly_size(strategy_id: Option<&str>, base_size: f64) -> (f64, String) {
+    let (fraction, min_trades) = with_config(|cfg| {
+        (
+            cfg.trader.sizing_kelly_fraction,
+            cfg.trader.sizing_kelly_min_trades,
+        )
+    });
+    let Some(strategy_id) = strategy_id else {
+        return (
+            base_size,
+            "no strategy on decision, using trade size".to_string(),
+        );
+    };
+
+    let (returns, source) = strategy_returns(strategy_id, min_trades).await;
+    if returns.len() < min_trades {
+        return (
+            base_size,
+            format!(
+                "only {} closed trades for strategy (need {}), using trade size",
+                returns.len(),
+                min_trades
+            ),
+        );
+    }
+
+    let Some(kelly) = kelly_fraction(&returns) else {
+        return (
+            base_size,
+            "strategy has no wins or no losses yet, using trade size".to_string(),
+        );
+    };
+    if kelly <= 0.0 {
+        return (
+            0.0,
+            format!(
+                "no edge over {} {} trades (Kelly {:.3}), skipping entry",
+                returns.len(),
+                source,
+                kelly
+            ),
+        );
+    }
+
+    let (balance, account) = sizing_balance().await;
+    match balance {
+        Ok(balance) if balance > 0.0 => (
+            balance * kelly * fraction,
+            format!(
+                "{:.2}× Kelly {:.3} over {} {} trades of {:.4} SOL {} balance",
+                fraction,
+                kelly,
+                returns.len(),
+                source,
+                balance,
+                account
+            ),
+        ),
+        _ => (
+            base_size,
+            format!("{} balance unavailable, using trade size", account),
+        ),
+    }
+}
+
+/// SOL balance the wallet-based modes size against: the simulated paper balance in
+/// paper mode, the real wallet otherwise
+async fn sizing_balance() -> (Result<f64, String>, &'static str) {
+    if crate::positions::is_paper_trading() {
+        return (crate::positions::paper_sol_balance().await, "paper");
+    }
+
+    let balance = match crate::utils::get_wallet_address() {
+        Ok(address) => crate::utils::get_sol_balance(&address)
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    (balance, "wallet")
+}
+
+/// Closed-trade returns (%) of a strategy: live positions first, latest backtest otherwise
+async fn strategy_returns(strategy_id: &str, min_trades: usize) -> (Vec<f64>, &'static str) {
+    let position_ids: HashSet<i64> =
+        crate::strategies::db::get_strategy_position_ids(strategy_id, KELLY_LOOKBACK_POSITIONS)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|id| id.parse().ok())
+            .collect();
+
+    if !position_ids.is_empty() {
+        if let Ok(closed) = crate::positions::get_db_closed_positions().await {
+            let returns: Vec<f64> = closed
+                .iter()
+                .filter(|p| p.id.map(|id| position_ids.contains(&id)).unwrap_or(false))
+                .filter_map(|p| p.pnl_percent)
+                .collect();
+            if returns.len() >= min_trades {
+                return (returns, "live");
+            }
+        }
+    }
+
+    match crate::strategies::db::get_backtests(strategy_id, 1) {
+        Ok(reports) => match reports.into_iter().next() {
+            Some(report) => (
+                report.trades.iter().map(|t| t.pnl_pct).collect(),
+                "backtest",
+            ),
+            None => (Vec::new(), "live"),
+        },
+        Err(_) => (Vec::new(), "live"),
+    }
+}
+
+// ============================================================================
+// CALCULATIONS
+// ============================================================================
+
+/// Largest entry (SOL) that stays within `max_pool_percent` of the pool SOL reserve
+fn liquidity_cap_sol(sol_reserve: f64, max_pool_percent: f64) -> f64 {
+    sol_reserve * max_pool_percent / 100.0
+}
+
+/// Trade size limited to `max_pool_percent` of the pool SOL reserve
+///
+/// A cap below `min_size` skips the entry: raising it to the minimum would exceed
+/// the price impact the cap exists to prevent.
+fn liquidity_size(
+    sol_reserve: f64,
+    max_pool_percent: f64,
+    base_size: f64,
+    min_size: f64,
+) -> (f64, String) {
+    let cap = liquidity_cap_sol(sol_reserve, max_pool_percent);
+    if cap < min_size {
+        (
+            0.0,
+            format!(
+                "{:.2}% of {:.2} SOL pool reserve is {:.4} SOL, below minimum {:.4} SOL, skipping entry",
+                max_pool_percent, sol_reserve, cap, min_size
+            ),
+        )
+    } else if cap < base_size {
+        (
+            cap,
+            format!(
+                "capped to {:.2}% of {:.2} SOL pool reserve",
+                max_pool_percent, sol_reserve
+            ),
+        )
+    } else {
+        (
+            base_size,
+            format!(
+                "trade size within {:.2}% of {:.2} SOL pool reserve",
+                max_pool_percent, sol_reserve
+            ),
+        )
+    }
+}
+
+/// Latest ATR(`period`) as % of the last close
+fn average_true_range_pct(candles: &[Candle], period: usize) -> Option<f64> {
+    let mut sorted = candles.to_vec();
+    sorted.sort_by_key(|c| c.timestamp);
+
+    let atr = *indicators::atr_series(&sorted, period).last()?;
+    let last_close = sorted.last()?.close;
+    if last_close <= 0.0 || !atr.is_finite() {
+        return None;
+    }
+    Some(atr / last_close * 100.0)
+}
+
+/// Full Kelly fraction `W - (1 - W) / R` from trade returns in percent
+///
+/// Returns None when there are no wins or no losses to estimate the payoff ratio.
+fn kelly_fraction(returns_pct: &[f64]) -> Option<f64> {
+    let wins: Vec<f64> = returns_pct.iter().copied().filter(|r| *r > 0.0).collect();
+    let losses: Vec<f64> = returns_pct
+        .iter()
+        .copied()
+        .filter(|r| *r <= 0.0)
+        .map(f64::abs)
+        .collect();
+    if wins.is_empty() || losses.is_empty() {
+        return None;
+    }
+
+    let avg_loss = losses.iter().sum::<f64>() / losses.len() as f64;
+    if avg_loss <= 0.0 {
+        return None;
+    }
+    let avg_win = wins.iter().sum::<f64>() / wins.len() as f64;
+    let win_rate = wins.len() as f64 / returns_pct.len() as f64;
+    let payoff = avg_win / avg_loss;
+
+    Some(win_rate - (1.0 - win_rate) / payoff)
+}
+
+fn clamp_size(size: f64, min_size: f64, max_size: f64) -> f64 {
+    if !size.is_finite() {
+        return min_size;
+    }
+    size.max(min_size).min(max_size.max(min_size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kelly_fraction() {
+        // 60% win rate, wins +20%, losses -10% → R = 2 → 0.6 - 0.4 / 2 = 0.4
+        let returns = vec![20.0, 20.0, 20.0, -10.0, -10.0];
+        let kelly = kelly_fraction(&returns).unwrap();
+        assert!((kelly - 0.4).abs() < 1e-9);
+
+        assert!(kelly_fraction(&[10.0, 5.0]).is_none());
+        assert!(kelly_fraction(&[10.0, -30.0, -30.0]).unwrap() < 0.0);
+    }
+
+    #[test]
+    fn test_average_true_range_pct() {
+        let candles: Vec<Candle> = (0..5)
+            .map(|i| Candle::new(i * 60, 100.0, 105.0, 95.0, 100.0, 1.0))
+            .collect();
+        let atr = average_true_range_pct(&candles, 4).unwrap();
+        assert!((atr - 10.0).abs() < 1e-9);
+
+        assert!(average_true_range_pct(&candles, 5).is_none());
+    }
+
+    #[test]
+    fn test_resolve_size_skips_without_edge() {
+        let decision = resolve_size(SizingMode::Kelly, 0.0, "no edge".to_string(), 0.01, 0.5);
+        assert!(decision.is_skip());
+        assert_eq!(decision.size_sol, 0.0);
+        assert_eq!(decision.reason, "no edge");
+
+        assert!(resolve_size(SizingMode::Kelly, -0.2, String::new(), 0.01, 0.5).is_skip());
+    }
+
+    #[test]
+    fn test_resolve_size_clamps_positive_sizes() {
+        let decision = resolve_size(SizingMode::Kelly, 0.001, "tiny".to_string(), 0.01, 0.5);
+        assert!(!decision.is_skip());
+        assert_eq!(decision.size_sol, 0.01);
+        assert!(decision.reason.starts_with("tiny; clamped to 0.0100 SOL"));
+
+        let decision = resolve_size(SizingMode::Fixed, 0.1, "fixed".to_string(), 0.01, 0.5);
+        assert_eq!(decision.size_sol, 0.1);
+        assert_eq!(decision.reason, "fixed");
+    }
+
+    #[test]
+    fn test_liquidity_size_skips_below_minimum() {
+        // 1% of 0.5 SOL = 0.005 SOL, below the 0.01 minimum
+        let (size, reason) = liquidity_size(0.5, 1.0, 0.1, 0.01);
+        assert_eq!(size, 0.0);
+        assert!(reason.ends_with("skipping entry"));
+        assert!(resolve_size(SizingMode::LiquidityCapped, size, reason, 0.01, 0.5).is_skip());
+
+        let (size, _) = liquidity_size(5.0, 1.0, 0.1, 0.01);
+        assert!((size - 0.05).abs() < 1e-12);
+
+        let (size, _) = liquidity_size(500.0, 1.0, 0.1, 0.01);
+        assert_eq!(size, 0.1);
+    }
+
+    #[test]
+    fn test_clamp_size() {
+        assert_eq!(clamp_size(0.0, 0.001, 0.5), 0.001);
+        assert_eq!(clamp_size(1.0, 0.001, 0.5), 0.5);
+        assert_eq!(clamp_size(f64::NAN, 0.001, 0.5), 0.001);
+        assert!((liquidity_cap_sol(50.0, 1.0) - 0.5).abs() < 1e-12);
+    }
+}
@@ -10,6 +10,8 @@ pub struct TradeDecision {
     pub action: TradeAction,
     pub reason: TradeReason,
     pub strategy_id: Option<String>,
+    /// Revision of `strategy_id` that produced the signal (None for non-strategy decisions)
+    pub strategy_version: Option<i32>,
     pub timestamp: DateTime<Utc>,
     pub priority: TradePriority,
     pub price_sol: Option<f64>,
@@ -33,6 +35,7 @@ pub enum TradeReason {
 
     // Exit reasons
     TakeProfit,
+    TakeProfitTier(u32), // Take-profit ladder tier (1-based)
     StopLoss,
     TrailingStop,
     TimeOverride,
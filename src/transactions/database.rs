@@ -16,6 +16,7 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 
 use crate::logger::{self, LogTag};
+use crate::migrations::{self, add_column_if_missing, column_exists, Migration, MigrationSet};
 use crate::transactions::{types::*, utils::*};
 
 // =============================================================================
@@ -235,6 +236,120 @@ const INDEXES: &[&str] = &[
     "CREATE INDEX IF NOT EXISTS idx_pending_transactions_added_at ON pending_transactions(added_at DESC);",
 ];
 
+// =============================================================================
+// SCHEMA MIGRATIONS
+// =============================================================================
+
+/// Ordered schema migrations of transactions.db
+pub const TRANSACTIONS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "transactions",
+    migrations: &[Migration::apply(
+        1,
+        "Baseline schema (tables, fee_sol and sol_delta columns, indexes)",
+        baseline_schema,
+    )],
+};
+
+/// Migration 1: the schema as it stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    let tables = [
+        SCHEMA_RAW_TRANSACTIONS,
+        SCHEMA_PROCESSED_TRANSACTIONS,
+        SCHEMA_KNOWN_SIGNATURES,
+        SCHEMA_DEFERRED_RETRIES,
+        SCHEMA_PENDING_TRANSACTIONS,
+        SCHEMA_METADATA,
+        SCHEMA_BOOTSTRAP_STATE,
+    ];
+
+    for table_sql in &tables {
+        conn.execute(table_sql, [])
+            .map_err(|e| format!("Failed to create table: {}", e))?;
+    }
+
+    // fee_sol for MCP tools compatibility
+    add_column_if_missing(
+        conn,
+        "processed_transactions",
+        "fee_sol",
+        "REAL NOT NULL DEFAULT 0",
+    )?;
+
+    if !column_exists(conn, "processed_transactions", "sol_delta")? {
+        add_column_if_missing(conn, "processed_transactions", "sol_delta", "REAL")?;
+        backfill_processed_sol_delta(conn)?;
+    }
+
+    for index_sql in INDEXES {
+        conn.execute(index_sql, [])
+            .map_err(|e| format!("Failed to create index: {}", e))?;
+    }
+
+    // Ensure the single bootstrap_state row exists
+    conn.execute(
+        "INSERT OR IGNORE INTO bootstrap_state (id, full_history_completed) VALUES (1, 0)",
+        [],
+    )
+    .map_err(|e| format!("Failed to initialize bootstrap_state row: {}", e))?;
+
+    Ok(())
+}
+
+/// Fill sol_delta for rows processed before the column existed (current wallet only)
+///
+/// Runs inside the migration transaction.
+fn backfill_processed_sol_delta(conn: &Connection) -> Result<(), String> {
+    const BATCH_SIZE: i64 = 1000;
+    let mut total_updated = 0usize;
+
+    let wallet_address = crate::utils::get_wallet_address()
+        .map_err(|e| format!("Failed to get wallet address for sol_delta backfill: {}", e))?;
+
+    loop {
+        let mut stmt = conn
+            .prepare(
+                "SELECT signature, sol_balance_change FROM processed_transactions WHERE wallet_address = ?1 AND sol_delta IS NULL LIMIT ?2",
+            )
+            .map_err(|e| format!("Failed to prepare sol_delta backfill query: {}", e))?;
+
+        let batch = stmt
+            .query_map(params![wallet_address, BATCH_SIZE], |row| {
+                let signature: String = row.get(0)?;
+                let change_json: Option<String> = row.get(1)?;
+                Ok((signature, change_json))
+            })
+            .map_err(|e| format!("Failed to iterate sol_delta backfill rows: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read sol_delta row: {}", e))?;
+
+        if batch.is_empty() {
+            break;
+        }
+
+        for (signature, change_json) in batch {
+            let delta = TransactionDatabase::compute_sol_delta_from_json(change_json.as_deref());
+            conn.execute(
+                "UPDATE processed_transactions SET sol_delta = ?1 WHERE signature = ?2 AND wallet_address = ?3",
+                params![delta, signature, wallet_address],
+            )
+            .map_err(|e| format!("Failed to update sol_delta: {}", e))?;
+            total_updated += 1;
+        }
+    }
+
+    if total_updated > 0 {
+        logger::info(
+            LogTag::Transactions,
+            &format!(
+                "Backfilled sol_delta for {} processed transactions",
+                total_updated
+            ),
+        );
+    }
+
+    Ok(())
+}
+
 // =============================================================================
 // DATABASE STATISTICS AND REPORTING
 // =============================================================================
@@ -361,34 +476,11 @@ impl TransactionDatabase {
 
     /// Initialize database schema and indexes
     async fn initialize_schema(&mut self) -> Result<(), String> {
-        let mut conn = self
+        let conn = self
             .get_connection()
             .map_err(|e| format!("Failed to get database connection: {}", e))?;
 
-        // Create all tables
-        let tables = [
-            SCHEMA_RAW_TRANSACTIONS,
-            SCHEMA_PROCESSED_TRANSACTIONS,
-            SCHEMA_KNOWN_SIGNATURES,
-            SCHEMA_DEFERRED_RETRIES,
-            SCHEMA_PENDING_TRANSACTIONS,
-            SCHEMA_METADATA,
-            SCHEMA_BOOTSTRAP_STATE,
-        ];
-
-        for table_sql in &tables {
-            conn.execute(table_sql, [])
-                .map_err(|e| format!("Failed to create table: {}", e))?;
-        }
-
-        // Create all indexes
-        for index_sql in INDEXES {
-            conn.execute(index_sql, [])
-                .map_err(|e| format!("Failed to create index: {}", e))?;
-        }
-
-        // Apply lightweight migrations for existing databases
-        self.apply_migrations(&mut conn)?;
+        migrations::run_migrations(&conn, &TRANSACTIONS_MIGRATIONS)?;
 
         // Set or update schema version
         conn.execute(
@@ -409,127 +501,6 @@ impl TransactionDatabase {
         Ok(())
     }
 
-    /// Apply schema migrations when upgrading versions
-    fn apply_migrations(&self, conn: &mut Connection) -> Result<(), String> {
-        // Ensure processed_transactions has fee_sol column for MCP tools compatibility
-        let mut has_fee_sol = false;
-        let mut has_sol_delta = false;
-        let mut stmt = conn
-            .prepare("PRAGMA table_info(processed_transactions)")
-            .map_err(|e| format!("Failed to inspect processed_transactions schema: {}", e))?;
-        let rows = stmt
-            .query_map([], |row| {
-                let name: String = row.get(1)?;
-                Ok(name)
-            })
-            .map_err(|e| format!("Failed to read processed_transactions schema: {}", e))?;
-        for r in rows {
-            let name = r.map_err(|e| format!("Failed to parse schema row: {}", e))?;
-            if name.eq_ignore_ascii_case("fee_sol") {
-                has_fee_sol = true;
-            } else if name.eq_ignore_ascii_case("sol_delta") {
-                has_sol_delta = true;
-            }
-        }
-        drop(stmt);
-        if !has_fee_sol {
-            conn.execute(
-                "ALTER TABLE processed_transactions ADD COLUMN fee_sol REAL NOT NULL DEFAULT 0",
-                [],
-            )
-            .map_err(|e| format!("Failed to add fee_sol column: {}", e))?;
-        }
-
-        if !has_sol_delta {
-            conn.execute(
-                "ALTER TABLE processed_transactions ADD COLUMN sol_delta REAL",
-                [],
-            )
-            .map_err(|e| format!("Failed to add sol_delta column: {}", e))?;
-
-            self.backfill_processed_sol_delta(conn)?;
-        }
-
-        // Ensure bootstrap_state table exists (idempotent)
-        conn.execute(SCHEMA_BOOTSTRAP_STATE, [])
-            .map_err(|e| format!("Failed to ensure bootstrap_state table: {}", e))?;
-
-        // Ensure the single row exists
-        conn.execute(
-            "INSERT OR IGNORE INTO bootstrap_state (id, full_history_completed) VALUES (1, 0)",
-            [],
-        )
-        .map_err(|e| format!("Failed to initialize bootstrap_state row: {}", e))?;
-        Ok(())
-    }
-
-    fn backfill_processed_sol_delta(&self, conn: &mut Connection) -> Result<(), String> {
-        const BATCH_SIZE: i64 = 1000;
-        let mut total_updated = 0usize;
-
-        // Get wallet address for filtering (this is a migration function, so it operates on current wallet data only)
-        let wallet_address = crate::utils::get_wallet_address()
-            .map_err(|e| format!("Failed to get wallet address for sol_delta backfill: {}", e))?;
-
-        loop {
-            let mut stmt = conn
-                .prepare(
-                    "SELECT signature, sol_balance_change FROM processed_transactions WHERE wallet_address = ?1 AND sol_delta IS NULL LIMIT ?2",
-                )
-                .map_err(|e| format!("Failed to prepare sol_delta backfill query: {}", e))?;
-
-            let rows = stmt
-                .query_map(params![wallet_address, BATCH_SIZE], |row| {
-                    let signature: String = row.get(0)?;
-                    let change_json: Option<String> = row.get(1)?;
-                    Ok((signature, change_json))
-                })
-                .map_err(|e| format!("Failed to iterate sol_delta backfill rows: {}", e))?;
-
-            let mut batch: Vec<(String, Option<String>)> = Vec::new();
-            for row in rows {
-                let (signature, change_json) =
-                    row.map_err(|e| format!("Failed to read sol_delta row: {}", e))?;
-                batch.push((signature, change_json));
-            }
-
-            if batch.is_empty() {
-                break;
-            }
-
-            drop(stmt);
-
-            let tx = conn
-                .transaction()
-                .map_err(|e| format!("Failed to start sol_delta backfill transaction: {}", e))?;
-
-            for (signature, change_json) in batch.into_iter() {
-                let delta = Self::compute_sol_delta_from_json(change_json.as_deref());
-                tx.execute(
-                    "UPDATE processed_transactions SET sol_delta = ?1 WHERE signature = ?2 AND wallet_address = ?3",
-                    params![delta, signature, wallet_address],
-                )
-                .map_err(|e| format!("Failed to update sol_delta: {}", e))?;
-                total_updated += 1;
-            }
-
-            tx.commit()
-                .map_err(|e| format!("Failed to commit sol_delta backfill: {}", e))?;
-        }
-
-        if total_updated > 0 {
-            logger::info(
-                LogTag::Transactions,
-                &format!(
-                    "Backfilled sol_delta for {} processed transactions",
-                    total_updated
-                ),
-            );
-        }
-
-        Ok(())
-    }
-
     fn compute_sol_delta_from_json(payload: Option<&str>) -> f64 {
         let Some(raw) = payload else {
             return 0.0;
@@ -2233,6 +2204,7 @@ mod tests {
             token_symbol: None,
             router: router.map(|s| s.to_string()),
             sol_delta,
+            token_amount: None,
             fee_sol: 0.0,
             fee_lamports: None,
             ata_rents: 0.0,
@@ -27,6 +27,7 @@ use tokio::sync::{Mutex, Notify, RwLock};
 
 use crate::config::with_config;
 use crate::logger::{self, LogTag};
+use crate::migrations::{self, add_column_if_missing, Migration, MigrationSet};
 use crate::nfts::fetch_nft_metadata_batch;
 use crate::rpc::{get_rpc_client, RpcClientMethods, TokenAccountInfo};
 // Use tokens::store accessors directly when needed
@@ -145,6 +146,59 @@ const CIRCUIT_BREAKER_THRESHOLD: u32 = 3;
 const CIRCUIT_BREAKER_COOLDOWN_SECS: u64 = 300;
 const TOKEN_METADATA_CONCURRENCY: usize = 20;
 
+/// Ordered schema migrations of wallet.db
+pub const WALLET_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "wallet",
+    migrations: &[Migration::apply(1, "Baseline schema", baseline_schema)],
+};
+
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    conn.execute(SCHEMA_WALLET_SNAPSHOTS, [])
+        .map_err(|e| format!("Failed to create wallet_snapshots table: {}", e))?;
+
+    conn.execute(SCHEMA_TOKEN_BALANCES, [])
+        .map_err(|e| format!("Failed to create token_balances table: {}", e))?;
+
+    conn.execute(SCHEMA_NFT_BALANCES, [])
+        .map_err(|e| format!("Failed to create nft_balances table: {}", e))?;
+
+    conn.execute(SCHEMA_WALLET_METADATA, [])
+        .map_err(|e| format!("Failed to create wallet_metadata table: {}", e))?;
+
+    // Flow cache tables
+    conn.execute(SCHEMA_SOL_FLOW_CACHE, [])
+        .map_err(|e| format!("Failed to create sol_flow_cache table: {}", e))?;
+
+    conn.execute(SCHEMA_WALLET_DASHBOARD_METRICS, [])
+        .map_err(|e| format!("Failed to create wallet_dashboard_metrics table: {}", e))?;
+
+    // Added after the first release; older databases may lack it
+    add_column_if_missing(
+        conn,
+        "wallet_snapshots",
+        "total_nfts_count",
+        "INTEGER NOT NULL DEFAULT 0",
+    )?;
+
+    // Create all indexes
+    for index_sql in WALLET_INDEXES {
+        conn.execute(index_sql, [])
+            .map_err(|e| format!("Failed to create wallet index: {}", e))?;
+    }
+    for index_sql in FLOW_CACHE_INDEXES {
+        conn.execute(index_sql, [])
+            .map_err(|e| format!("Failed to create flow cache index: {}", e))?;
+    }
+
+    for index_sql in DASHBOARD_METRICS_INDEXES {
+        conn.execute(index_sql, [])
+            .map_err(|e| format!("Failed to create dashboard metrics index: {}", e))?;
+    }
+
+    Ok(())
+}
+
 // =============================================================================
 // DATA STRUCTURES
 // =============================================================================
@@ -1536,47 +1590,7 @@ impl WalletDatabase {
         conn.pragma_update(None, "mmap_size", &30000000000i64)
             .map_err(|e| format!("Failed to set mmap_size: {}", e))?;
 
-        // Create all tables
-        conn.execute(SCHEMA_WALLET_SNAPSHOTS, [])
-            .map_err(|e| format!("Failed to create wallet_snapshots table: {}", e))?;
-
-        conn.execute(SCHEMA_TOKEN_BALANCES, [])
-            .map_err(|e| format!("Failed to create token_balances table: {}", e))?;
-
-        conn.execute(SCHEMA_NFT_BALANCES, [])
-            .map_err(|e| format!("Failed to create nft_balances table: {}", e))?;
-
-        conn.execute(SCHEMA_WALLET_METADATA, [])
-            .map_err(|e| format!("Failed to create wallet_metadata table: {}", e))?;
-
-        // Flow cache tables
-        conn.execute(SCHEMA_SOL_FLOW_CACHE, [])
-            .map_err(|e| format!("Failed to create sol_flow_cache table: {}", e))?;
-
-        conn.execute(SCHEMA_WALLET_DASHBOARD_METRICS, [])
-            .map_err(|e| format!("Failed to create wallet_dashboard_metrics table: {}", e))?;
-
-        // Migrate existing schema if needed (add missing columns)
-        conn.execute(
-            "ALTER TABLE wallet_snapshots ADD COLUMN total_nfts_count INTEGER NOT NULL DEFAULT 0",
-            [],
-        )
-        .ok(); // Ignore error if column already exists
-
-        // Create all indexes
-        for index_sql in WALLET_INDEXES {
-            conn.execute(index_sql, [])
-                .map_err(|e| format!("Failed to create wallet index: {}", e))?;
-        }
-        for index_sql in FLOW_CACHE_INDEXES {
-            conn.execute(index_sql, [])
-                .map_err(|e| format!("Failed to create flow cache index: {}", e))?;
-        }
-
-        for index_sql in DASHBOARD_METRICS_INDEXES {
-            conn.execute(index_sql, [])
-                .map_err(|e| format!("Failed to create dashboard metrics index: {}", e))?;
-        }
+        migrations::run_migrations(&conn, &WALLET_MIGRATIONS)?;
 
         // Set schema version
         conn.execute(
@@ -5,16 +5,27 @@
 use chrono::{DateTime, Utc};
 use r2d2::{Pool, PooledConnection};
 use r2d2_sqlite::SqliteConnectionManager;
-use rusqlite::{params, OptionalExtension};
+use rusqlite::{params, Connection, OptionalExtension, Transaction, TransactionBehavior};
 use std::collections::HashMap;
 
-use super::types::{TokenBalance, Wallet, WalletRole, WalletType};
-use crate::paths::get_data_directory;
+use super::types::{KeystoreRecord, TokenBalance, Wallet, WalletRole, WalletType};
+use crate::migrations::{self, Migration, MigrationSet};
+use crate::paths::get_wallets_db_path;
+use crate::secure_storage::{EncryptedData, KdfParams};
 
 // =============================================================================
 // DATABASE SCHEMA
 // =============================================================================
 
+/// Ordered schema migrations of wallets.db
+pub const WALLETS_MIGRATIONS: MigrationSet = MigrationSet {
+    store: "wallets",
+    migrations: &[
+        Migration::apply(1, "Baseline schema", baseline_schema),
+        Migration::sql(2, "Add master password keystore", KEYSTORE_SCHEMA),
+    ],
+};
+
 const WALLETS_SCHEMA: &str = r#"
 CREATE TABLE IF NOT EXISTS wallets (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -47,6 +58,21 @@ CREATE TABLE IF NOT EXISTS wallet_token_balances (
 );
 "#;
 
+/// Master password settings; no row means keys use the machine key
+const KEYSTORE_SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS wallet_keystore (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    kdf TEXT NOT NULL DEFAULT 'argon2id',
+    kdf_salt TEXT NOT NULL,
+    kdf_memory_kib INTEGER NOT NULL,
+    kdf_iterations INTEGER NOT NULL,
+    kdf_parallelism INTEGER NOT NULL,
+    check_ciphertext TEXT NOT NULL,
+    check_nonce TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+"#;
+
 const WALLETS_INDEXES: &[&str] = &[
     "CREATE INDEX IF NOT EXISTS idx_wallets_address ON wallets(address);",
     "CREATE INDEX IF NOT EXISTS idx_wallets_role ON wallets(role);",
@@ -55,6 +81,22 @@ const WALLETS_INDEXES: &[&str] = &[
     "CREATE INDEX IF NOT EXISTS idx_token_balances_mint ON wallet_token_balances(mint);",
 ];
 
+/// Migration 1: tables as they stood when versioning was introduced
+fn baseline_schema(conn: &Connection) -> Result<(), String> {
+    conn.execute(WALLETS_SCHEMA, [])
+        .map_err(|e| format!("Failed to create wallets table: {}", e))?;
+
+    conn.execute(TOKEN_BALANCES_SCHEMA, [])
+        .map_err(|e| format!("Failed to create token_balances table: {}", e))?;
+
+    for index_sql in WALLETS_INDEXES {
+        conn.execute(index_sql, [])
+            .map_err(|e| format!("Failed to create index: {}", e))?;
+    }
+
+    Ok(())
+}
+
 // =============================================================================
 // DATABASE STRUCT
 // =============================================================================
@@ -67,7 +109,7 @@ pub struct WalletsDatabase {
 impl WalletsDatabase {
     /// Create or open the wallets database
     pub fn new() -> Result<Self, String> {
-        let db_path = get_data_directory().join("wallets.db");
+        let db_path = get_wallets_db_path();
 
         // Ensure data directory exists
         if let Some(parent) = db_path.parent() {
@@ -108,19 +150,7 @@ impl WalletsDatabase {
         )
         .map_err(|e| format!("Failed to set pragmas: {}", e))?;
 
-        // Create tables
-        conn.execute(WALLETS_SCHEMA, [])
-            .map_err(|e| format!("Failed to create wallets table: {}", e))?;
-
-        conn.execute(TOKEN_BALANCES_SCHEMA, [])
-            .map_err(|e| format!("Failed to create token_balances table: {}", e))?;
-
-        // Create indexes
-        for index_sql in WALLETS_INDEXES {
-            conn.execute(index_sql, [])
-                .map_err(|e| format!("Failed to create index: {}", e))?;
-        }
-
+        migrations::run_migrations(&conn, &WALLETS_MIGRATIONS)?;
         Ok(())
     }
 
@@ -539,6 +569,131 @@ impl WalletsDatabase {
         Ok(count > 0)
     }
 
+    // =========================================================================
+    // KEYSTORE (MASTER PASSWORD)
+    // =========================================================================
+
+    /// Get master password settings (None when keys use the machine key)
+    pub fn get_keystore(&self) -> Result<Option<KeystoreRecord>, String> {
+        let conn = self.conn()?;
+
+        conn.query_row(
+            r#"
+            SELECT kdf_salt, kdf_memory_kib, kdf_iterations, kdf_parallelism,
+                   check_ciphertext, check_nonce, updated_at
+            FROM wallet_keystore WHERE id = 1
+            "#,
+            [],
+            |row| {
+                let updated_str: String = row.get(6)?;
+                Ok(KeystoreRecord {
+                    kdf_salt: row.get(0)?,
+                    kdf_params: KdfParams {
+                        memory_kib: row.get(1)?,
+                        iterations: row.get(2)?,
+                        parallelism: row.get(3)?,
+                    },
+                    key_check: EncryptedData {
+                        ciphertext: row.get(4)?,
+                        nonce: row.get(5)?,
+                    },
+                    updated_at: DateTime::parse_from_rfc3339(&updated_str)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                })
+            },
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get keystore: {}", e))
+    }
+
+    /// Get encrypted keys of all wallets, including archived ones
+    pub fn list_encrypted_keys(&self) -> Result<Vec<(i64, EncryptedData)>, String> {
+        let conn = self.conn()?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, encrypted_key, nonce FROM wallets ORDER BY id")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let keys = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    EncryptedData {
+                        ciphertext: row.get(1)?,
+                        nonce: row.get(2)?,
+                    },
+                ))
+            })
+            .map_err(|e| format!("Failed to query encrypted keys: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect encrypted keys: {}", e))?;
+
+        Ok(keys)
+    }
+
+    /// Replace the encrypted keys and the keystore in a single transaction
+    ///
+    /// `keystore` None removes the master password (machine key mode). Either
+    /// every wallet is re-encrypted or none is.
+    pub fn replace_encrypted_keys(
+        &self,
+        keys: &[(i64, EncryptedData)],
+        keystore: Option<&KeystoreRecord>,
+    ) -> Result<(), String> {
+        let conn = self.conn()?;
+        let tx = Transaction::new_unchecked(&conn, TransactionBehavior::Immediate)
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        let stored: u32 = tx
+            .query_row("SELECT COUNT(*) FROM wallets", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to count wallets: {}", e))?;
+        if stored as usize != keys.len() {
+            return Err(format!(
+                "Wallets changed during re-encryption ({} stored, {} re-encrypted)",
+                stored,
+                keys.len()
+            ));
+        }
+
+        for (id, encrypted) in keys {
+            tx.execute(
+                "UPDATE wallets SET encrypted_key = ?1, nonce = ?2 WHERE id = ?3",
+                params![encrypted.ciphertext, encrypted.nonce, id],
+            )
+            .map_err(|e| format!("Failed to update wallet {}: {}", id, e))?;
+        }
+
+        tx.execute("DELETE FROM wallet_keystore", [])
+            .map_err(|e| format!("Failed to clear keystore: {}", e))?;
+
+        if let Some(record) = keystore {
+            tx.execute(
+                r#"
+                INSERT INTO wallet_keystore
+                    (id, kdf, kdf_salt, kdf_memory_kib, kdf_iterations, kdf_parallelism,
+                     check_ciphertext, check_nonce, updated_at)
+                VALUES (1, 'argon2id', ?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                "#,
+                params![
+                    record.kdf_salt,
+                    record.kdf_params.memory_kib,
+                    record.kdf_params.iterations,
+                    record.kdf_params.parallelism,
+                    record.key_check.ciphertext,
+                    record.key_check.nonce,
+                    record.updated_at.to_rfc3339(),
+                ],
+            )
+            .map_err(|e| format!("Failed to save keystore: {}", e))?;
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit re-encryption: {}", e))?;
+
+        Ok(())
+    }
+
     // =========================================================================
     // HELPERS
     // =========================================================================
@@ -0,0 +1,272 @@
+//! Portable encrypted wallet backups
+//!
+//! A backup file holds wallets with their private keys, encrypted with a key
+//! derived from a backup password (Argon2id + AES-256-GCM). It does not depend
+//! on the machine key or the master password, so it can be restored on any
+//! machine.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::crypto::{keypair_to_address, parse_private_key};
+use super::keystore::{derive_key, validate_password};
+use super::manager::{
+    archive_wallet, get_existing_wallet_addresses, has_main_wallet, import_wallet, WALLETS_DB,
+};
+use super::types::{ImportWalletRequest, WalletBackupFile, WalletBackupImportResult, WalletRole};
+use crate::logger::{self, LogTag};
+use crate::secure_storage::{self, EncryptedData, KdfParams};
+
+/// Value of `WalletBackupFile::format`
+const BACKUP_FORMAT: &str = "screenerbot-wallet-backup";
+
+/// Current backup file version
+const BACKUP_VERSION: u32 = 1;
+
+/// One wallet inside the encrypted payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackupEntry {
+    name: String,
+    address: String,
+    /// Private key in base58 format
+    private_key: String,
+    role: WalletRole,
+    notes: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+// =============================================================================
+// EXPORT / IMPORT
+// =============================================================================
+
+/// Export wallets as an encrypted backup file
+///
+/// Requires unlocked wallet keys. Fails instead of skipping when a wallet
+/// cannot be decrypted, so a backup is never silently incomplete.
+pub async fn export_wallet_backup(
+    password: &str,
+    include_inactive: bool,
+) -> Result<WalletBackupFile, String> {
+    validate_password(password)?;
+
+    let entries = {
+        let db_guard = WALLETS_DB.read().await;
+        let db = db_guard.as_ref().ok_or("Wallet database not initialized")?;
+
+        let keys = db.list_encrypted_keys()?;
+        let mut entries = Vec::new();
+
+        for wallet in db.list_wallets(include_inactive)? {
+            let encrypted = keys
+                .iter()
+                .find(|(id, _)| *id == wallet.id)
+                .map(|(_, encrypted)| encrypted)
+                .ok_or_else(|| format!("Encrypted key of wallet {} not found", wallet.name))?;
+            let private_key = secure_storage::decrypt_private_key(encrypted)
+                .map_err(|e| format!("Failed to decrypt wallet {}: {}", wallet.name, e))?;
+
+            entries.push(BackupEntry {
+                name: wallet.name,
+                address: wallet.address,
+                private_key,
+                role: wallet.role,
+                notes: wallet.notes,
+                created_at: wallet.created_at,
+            });
+        }
+        entries
+    };
+
+    let kdf_params = KdfParams::default();
+    let kdf_salt = secure_storage::generate_password_salt();
+    let key = derive_key(password, &kdf_salt, kdf_params).await?;
+    let backup = seal_backup(&entries, &key, kdf_salt, kdf_params)?;
+
+    logger::warning(
+        LogTag::Wallet,
+        &format!("Exported encrypted backup of {} wallets", entries.len()),
+    );
+
+    Ok(backup)
+}
+
+/// Import wallets from an encrypted backup file
+///
+/// Wallets that already exist are skipped. The main wallet of the backup only
+/// becomes main when no main wallet is configured yet.
+pub async fn import_wallet_backup(
+    backup: &WalletBackupFile,
+    password: &str,
+) -> Result<WalletBackupImportResult, String> {
+    check_backup_header(backup)?;
+
+    let key = derive_key(password, &backup.kdf_salt, backup.kdf_params).await?;
+    let entries = open_backup(backup, &key)?;
+
+    let existing = get_existing_wallet_addresses().await?;
+    let mut result = WalletBackupImportResult::default();
+
+    for entry in entries {
+        if existing.contains(&entry.address) {
+            result.skipped.push(entry.address);
+            continue;
+        }
+
+        match import_entry(&entry).await {
+            Ok(()) => result.imported.push(entry.address),
+            Err(e) => result.failed.push(format!("{}: {}", entry.address, e)),
+        }
+    }
+
+    logger::info(
+        LogTag::Wallet,
+        &format!(
+            "Wallet backup import: {} imported, {} skipped, {} failed",
+            result.imported.len(),
+            result.skipped.len(),
+            result.failed.len()
+        ),
+    );
+
+    Ok(result)
+}
+
+async fn import_entry(entry: &BackupEntry) -> Result<(), String> {
+    // Guard against a tampered entry whose key does not match its address
+    let keypair = parse_private_key(&entry.private_key)?;
+    if keypair_to_address(&keypair) != entry.address {
+        return Err("Private key does not match the wallet address".to_string());
+    }
+
+    let set_as_main = entry.role == WalletRole::Main && !has_main_wallet().await;
+    let wallet = import_wallet(ImportWalletRequest {
+        name: entry.name.clone(),
+        private_key: entry.private_key.clone(),
+        notes: entry.notes.clone(),
+        set_as_main,
+    })
+    .await?;
+
+    if entry.role == WalletRole::Archive {
+        archive_wallet(wallet.id).await?;
+    }
+    Ok(())
+}
+
+// =============================================================================
+// FILE FORMAT
+// =============================================================================
+
+fn check_backup_header(backup: &WalletBackupFile) -> Result<(), String> {
+    if backup.format != BACKUP_FORMAT {
+        return Err("Not a ScreenerBot wallet backup file".to_string());
+    }
+    if backup.version > BACKUP_VERSION {
+        return Err(format!(
+            "Backup file version {} is newer than supported ({})",
+            backup.version, BACKUP_VERSION
+        ));
+    }
+    Ok(())
+}
+
+fn seal_backup(
+    entries: &[BackupEntry],
+    key: &[u8; 32],
+    kdf_salt: String,
+    kdf_params: KdfParams,
+) -> Result<WalletBackupFile, String> {
+    let payload = serde_json::to_string(entries)
+        .map_err(|e| format!("Failed to serialize wallets: {}", e))?;
+    let encrypted = secure_storage::encrypt_with_key(&payload, key)?;
+
+    Ok(WalletBackupFile {
+        format: BACKUP_FORMAT.to_string(),
+        version: BACKUP_VERSION,
+        created_at: Utc::now(),
+        wallet_count: entries.len() as u32,
+        kdf_params,
+        kdf_salt,
+        ciphertext: encrypted.ciphertext,
+        nonce: encrypted.nonce,
+    })
+}
+
+fn open_backup(backup: &WalletBackupFile, key: &[u8; 32]) -> Result<Vec<BackupEntry>, String> {
+    let encrypted = EncryptedData {
+        ciphertext: backup.ciphertext.clone(),
+        nonce: backup.nonce.clone(),
+    };
+    let payload = secure_storage::decrypt_with_key(&encrypted, key)
+        .map_err(|_| "Incorrect backup password or corrupted backup file".to_string())?;
+
+    serde_json::from_str(&payload).map_err(|e| format!("Invalid backup contents: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_kdf_params() -> KdfParams {
+        KdfParams {
+            memory_kib: 1024,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    fn test_entry() -> BackupEntry {
+        let keypair = super::super::crypto::generate_keypair();
+        BackupEntry {
+            name: "Trading".to_string(),
+            address: keypair_to_address(&keypair),
+            private_key: bs58::encode(keypair.to_bytes()).into_string(),
+            role: WalletRole::Main,
+            notes: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_seal_open_roundtrip() {
+        let salt = secure_storage::generate_password_salt();
+        let key =
+            secure_storage::derive_password_key("backup-pass", &salt, &test_kdf_params()).unwrap();
+        let entry = test_entry();
+
+        let backup = seal_backup(&[entry.clone()], &key, salt.clone(), test_kdf_params()).unwrap();
+        assert!(check_backup_header(&backup).is_ok());
+        assert_eq!(backup.wallet_count, 1);
+
+        // The file round-trips through JSON and decrypts with the same password
+        let json = serde_json::to_string(&backup).unwrap();
+        let parsed: WalletBackupFile = serde_json::from_str(&json).unwrap();
+        let rederived = secure_storage::derive_password_key(
+            "backup-pass",
+            &parsed.kdf_salt,
+            &parsed.kdf_params,
+        )
+        .unwrap();
+        let entries = open_backup(&parsed, &rederived).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].address, entry.address);
+        assert_eq!(entries[0].private_key, entry.private_key);
+
+        let wrong =
+            secure_storage::derive_password_key("wrong-pass", &salt, &test_kdf_params()).unwrap();
+        assert!(open_backup(&parsed, &wrong).is_err());
+    }
+
+    #[test]
+    fn test_rejects_foreign_files() {
+        let salt = secure_storage::generate_password_salt();
+        let mut backup = seal_backup(&[], &[1u8; 32], salt, test_kdf_params()).unwrap();
+
+        backup.version = BACKUP_VERSION + 1;
+        assert!(check_backup_header(&backup).is_err());
+
+        backup.version = BACKUP_VERSION;
+        backup.format = "something-else".to_string();
+        assert!(check_backup_header(&backup).is_err());
+    }
+}
@@ -0,0 +1,267 @@
+//! Master password protection of wallet private keys
+//!
+//! Wallets are encrypted with the machine key by default. With a master
+//! password the key is derived with Argon2id instead, so the database is no
+//! longer readable from a copied disk, but the password has to be entered after
+//! every start. Setting, changing or removing the password re-encrypts every
+//! wallet in a single transaction.
+
+use chrono::Utc;
+
+use super::database::WalletsDatabase;
+use super::manager::{refresh_main_wallet_cache, WALLETS_DB};
+use super::types::{KeyProtection, KeystoreRecord, KeystoreStatus};
+use crate::logger::{self, LogTag};
+use crate::secure_storage::{self, KdfParams};
+
+/// Minimum length of master and backup passwords
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Maximum length of master and backup passwords
+const MAX_PASSWORD_LENGTH: usize = 256;
+
+// =============================================================================
+// STARTUP
+// =============================================================================
+
+/// Apply the stored protection mode when the wallet database is opened
+///
+/// With a master password the keys stay locked until `unlock_wallets`.
+pub(super) fn load_key_protection(db: &WalletsDatabase) -> Result<(), String> {
+    match db.get_keystore()? {
+        Some(_) => {
+            if !secure_storage::is_master_password_mode() {
+                secure_storage::lock_wallet_key();
+            }
+        }
+        None => secure_storage::use_machine_wallet_key(),
+    }
+    Ok(())
+}
+
+/// Whether wallet keys are waiting for the master password
+pub fn is_locked() -> bool {
+    secure_storage::is_wallet_key_locked()
+}
+
+/// Get the current key protection status
+pub async fn get_keystore_status() -> Result<KeystoreStatus, String> {
+    let db_guard = WALLETS_DB.read().await;
+    let db = db_guard.as_ref().ok_or("Wallet database not initialized")?;
+
+    let status = match db.get_keystore()? {
+        Some(record) => KeystoreStatus {
+            protection: KeyProtection::MasterPassword,
+            locked: is_locked(),
+            kdf_params: Some(record.kdf_params),
+            updated_at: Some(record.updated_at),
+        },
+        None => KeystoreStatus {
+            protection: KeyProtection::Machine,
+            locked: false,
+            kdf_params: None,
+            updated_at: None,
+        },
+    };
+
+    Ok(status)
+}
+
+/// Unlock wallet keys with the master password
+pub async fn unlock_wallets(password: &str) -> Result<(), String> {
+    {
+        let db_guard = WALLETS_DB.read().await;
+        let db = db_guard.as_ref().ok_or("Wallet database not initialized")?;
+
+        let record = db.get_keystore()?.ok_or("No master password is set")?;
+        let key = derive_checked_key(password, &record).await?;
+        secure_storage::unlock_wallet_key(key);
+    }
+
+    refresh_main_wallet_cache().await?;
+
+    logger::info(LogTag::Wallet, "Wallet keys unlocked with master password");
+    Ok(())
+}
+
+// =============================================================================
+// PASSWORD MANAGEMENT / KEY ROTATION
+// =============================================================================
+
+/// Protect all wallets with a master password
+///
+/// Also removes the machine-encrypted legacy copy of the main wallet from
+/// config.toml, which would otherwise bypass the password.
+pub async fn enable_master_password(password: &str) -> Result<(), String> {
+    validate_password(password)?;
+
+    // Write lock: no wallet may be added while keys are re-encrypted
+    let db_guard = WALLETS_DB.write().await;
+    let db = db_guard.as_ref().ok_or("Wallet database not initialized")?;
+
+    if db.get_keystore()?.is_some() {
+        return Err("A master password is already set".to_string());
+    }
+
+    let old_key = secure_storage::derive_machine_key()?;
+    let (record, new_key) = new_keystore_record(password).await?;
+    let count = reencrypt_all(db, &old_key, &new_key, Some(&record))?;
+    secure_storage::unlock_wallet_key(new_key);
+    drop(db_guard);
+
+    clear_config_wallet_copy();
+
+    logger::info(
+        LogTag::Wallet,
+        &format!("Master password enabled - re-encrypted {} wallets", count),
+    );
+    Ok(())
+}
+
+/// Change the master password, re-encrypting all wallets with a fresh salt
+pub async fn change_master_password(
+    current_password: &str,
+    new_password: &str,
+) -> Result<(), String> {
+    validate_password(new_password)?;
+
+    let db_guard = WALLETS_DB.write().await;
+    let db = db_guard.as_ref().ok_or("Wallet database not initialized")?;
+
+    let record = db.get_keystore()?.ok_or("No master password is set")?;
+    let old_key = derive_checked_key(current_password, &record).await?;
+    let (new_record, new_key) = new_keystore_record(new_password).await?;
+    let count = reencrypt_all(db, &old_key, &new_key, Some(&new_record))?;
+    secure_storage::unlock_wallet_key(new_key);
+
+    logger::info(
+        LogTag::Wallet,
+        &format!("Master password changed - re-encrypted {} wallets", count),
+    );
+    Ok(())
+}
+
+/// Remove the master password and return to machine-bound encryption
+pub async fn disable_master_password(current_password: &str) -> Result<(), String> {
+    let db_guard = WALLETS_DB.write().await;
+    let db = db_guard.as_ref().ok_or("Wallet database not initialized")?;
+
+    let record = db.get_keystore()?.ok_or("No master password is set")?;
+    let old_key = derive_checked_key(current_password, &record).await?;
+    let new_key = secure_storage::derive_machine_key()?;
+    let count = reencrypt_all(db, &old_key, &new_key, None)?;
+    secure_storage::use_machine_wallet_key();
+
+    logger::warning(
+        LogTag::Wallet,
+        &format!(
+            "Master password removed - {} wallets are now encrypted with the machine key",
+            count
+        ),
+    );
+    Ok(())
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Check length limits of a master or backup password
+pub(super) fn validate_password(password: &str) -> Result<(), String> {
+    let length = password.chars().count();
+    if length < MIN_PASSWORD_LENGTH {
+        return Err(format!(
+            "Password must be at least {} characters",
+            MIN_PASSWORD_LENGTH
+        ));
+    }
+    if length > MAX_PASSWORD_LENGTH {
+        return Err(format!(
+            "Password must be at most {} characters",
+            MAX_PASSWORD_LENGTH
+        ));
+    }
+    Ok(())
+}
+
+/// Run Argon2id off the async runtime (it takes a noticeable fraction of a second)
+pub(super) async fn derive_key(
+    password: &str,
+    salt: &str,
+    params: KdfParams,
+) -> Result<[u8; 32], String> {
+    let password = password.to_string();
+    let salt = salt.to_string();
+
+    tokio::task::spawn_blocking(move || {
+        secure_storage::derive_password_key(&password, &salt, &params)
+    })
+    .await
+    .map_err(|e| format!("Key derivation task failed: {}", e))?
+}
+
+/// Derive the key for `password` and check it against the stored key check
+async fn derive_checked_key(password: &str, record: &KeystoreRecord) -> Result<[u8; 32], String> {
+    let key = derive_key(password, &record.kdf_salt, record.kdf_params).await?;
+
+    if !secure_storage::verify_key_check(&key, &record.key_check) {
+        return Err("Incorrect master password".to_string());
+    }
+    Ok(key)
+}
+
+/// Create keystore settings for a new password with a fresh salt
+async fn new_keystore_record(password: &str) -> Result<(KeystoreRecord, [u8; 32]), String> {
+    let kdf_params = KdfParams::default();
+    let kdf_salt = secure_storage::generate_password_salt();
+    let key = derive_key(password, &kdf_salt, kdf_params).await?;
+
+    let record = KeystoreRecord {
+        kdf_salt,
+        kdf_params,
+        key_check: secure_storage::create_key_check(&key)?,
+        updated_at: Utc::now(),
+    };
+    Ok((record, key))
+}
+
+/// Decrypt every wallet with `old_key` and store it encrypted with `new_key`
+fn reencrypt_all(
+    db: &WalletsDatabase,
+    old_key: &[u8; 32],
+    new_key: &[u8; 32],
+    keystore: Option<&KeystoreRecord>,
+) -> Result<usize, String> {
+    let keys = db.list_encrypted_keys()?;
+    let mut reencrypted = Vec::with_capacity(keys.len());
+
+    for (id, encrypted) in keys {
+        let private_key = secure_storage::decrypt_with_key(&encrypted, old_key)
+            .map_err(|e| format!("Failed to decrypt wallet {}: {}", id, e))?;
+        reencrypted.push((id, secure_storage::encrypt_with_key(&private_key, new_key)?));
+    }
+
+    db.replace_encrypted_keys(&reencrypted, keystore)?;
+    Ok(reencrypted.len())
+}
+
+/// Drop the legacy config.toml wallet copy (machine key encrypted)
+fn clear_config_wallet_copy() {
+    let has_copy = crate::config::with_config(|cfg| !cfg.wallet_encrypted.is_empty());
+    if !has_copy {
+        return;
+    }
+
+    if let Err(e) = crate::config::update_config_section(
+        |cfg| {
+            cfg.wallet_encrypted = String::new();
+            cfg.wallet_nonce = String::new();
+        },
+        true,
+    ) {
+        logger::warning(
+            LogTag::Wallet,
+            &format!("Failed to remove legacy wallet key from config.toml: {}", e),
+        );
+    }
+}
@@ -27,7 +27,7 @@ use crate::rpc::{get_rpc_client, RpcClientMethods};
 // =============================================================================
 
 /// Global wallet database instance
-static WALLETS_DB: Lazy<Arc<RwLock<Option<WalletsDatabase>>>> =
+pub(super) static WALLETS_DB: Lazy<Arc<RwLock<Option<WalletsDatabase>>>> =
     Lazy::new(|| Arc::new(RwLock::new(None)));
 
 /// Cached main wallet keypair for fast access
@@ -49,12 +49,22 @@ struct CachedMainWallet {
 /// Must be called once at startup before using any wallet functions
 pub async fn initialize() -> Result<(), String> {
     let db = WalletsDatabase::new()?;
+    super::keystore::load_key_protection(&db)?;
 
     {
         let mut guard = WALLETS_DB.write().await;
         *guard = Some(db);
     }
 
+    // Master password set: keys are decrypted once it is entered (see unlock_wallets)
+    if super::keystore::is_locked() {
+        logger::info(
+            LogTag::Wallet,
+            "Wallet manager initialized - keys locked until the master password is entered",
+        );
+        return Ok(());
+    }
+
     // Try to migrate from config.toml if no wallets exist
     migrate_from_config().await?;
 
@@ -119,7 +129,7 @@ async fn migrate_from_config() -> Result<(), String> {
 }
 
 /// Refresh the cached main wallet
-async fn refresh_main_wallet_cache() -> Result<(), String> {
+pub(super) async fn refresh_main_wallet_cache() -> Result<(), String> {
     let db_guard = WALLETS_DB.read().await;
     let db = db_guard.as_ref().ok_or("Wallet database not initialized")?;
 
@@ -1,11 +1,13 @@
 //! Multi-wallet management module
 //!
 //! Provides secure multi-wallet storage, generation, import/export,
-//! and management with machine-bound encryption.
+//! and management with machine-bound or master password encryption.
 //!
 //! ## Features
 //! - Secure wallet generation using Solana SDK
 //! - AES-256-GCM encryption with machine-derived keys
+//! - Optional master password (Argon2id) with key rotation
+//! - Portable encrypted backup files
 //! - Main wallet designation for trading
 //! - Secondary wallets for tools/volume aggregator
 //! - Import/export functionality
@@ -32,14 +34,28 @@
 pub mod bulk;
 mod crypto;
 mod database;
+mod encrypted_backup;
+mod keystore;
 mod manager;
 mod types;
 
+pub use database::WALLETS_MIGRATIONS;
+
 // Re-export types
 pub use types::{
-    CreateWalletRequest, ExportWalletResponse, ImportWalletRequest, SimpleTokenBalance,
-    TokenBalance, UpdateWalletRequest, Wallet, WalletBalanceSummary, WalletRole, WalletType,
-    WalletWithKey, WalletWithTokenBalance, WalletsSummary,
+    ChangeMasterPasswordRequest, CreateWalletRequest, DisableMasterPasswordRequest,
+    EnableMasterPasswordRequest, ExportWalletBackupRequest, ExportWalletResponse,
+    ImportWalletBackupRequest, ImportWalletRequest, KeyProtection, KeystoreStatus,
+    SimpleTokenBalance, TokenBalance, UpdateWalletRequest, Wallet, WalletBackupFile,
+    WalletBackupImportResult, WalletBalanceSummary, WalletRole, WalletType, WalletWithKey,
+    WalletWithTokenBalance, WalletsSummary,
+};
+
+// Re-export master password and encrypted backup functions
+pub use encrypted_backup::{export_wallet_backup, import_wallet_backup};
+pub use keystore::{
+    change_master_password, disable_master_password, enable_master_password, get_keystore_status,
+    is_locked, unlock_wallets,
 };
 
 // Re-export manager functions
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 
+use crate::secure_storage::{EncryptedData, KdfParams};
+
 // =============================================================================
 // ENUMS
 // =============================================================================
@@ -279,3 +281,124 @@ pub struct WalletBalanceSummary {
     /// Reclaimable SOL from closing empty ATAs (~0.00089088 per ATA)
     pub reclaimable_sol: f64,
 }
+
+// =============================================================================
+// MASTER PASSWORD TYPES
+// =============================================================================
+
+/// How wallet private keys are protected at rest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyProtection {
+    /// Key derived from the machine ID - only this machine can decrypt
+    Machine,
+    /// Key derived from a master password with Argon2id
+    MasterPassword,
+}
+
+/// Stored master password settings (single row of wallet_keystore)
+#[derive(Debug, Clone)]
+pub struct KeystoreRecord {
+    /// Base64-encoded Argon2id salt
+    pub kdf_salt: String,
+    /// Argon2id cost parameters used for this salt
+    pub kdf_params: KdfParams,
+    /// Known value encrypted with the derived key, used to check the password
+    pub key_check: EncryptedData,
+    /// When the master password was set or last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Master password status for the dashboard
+#[derive(Debug, Clone, Serialize)]
+pub struct KeystoreStatus {
+    /// Current protection mode
+    pub protection: KeyProtection,
+    /// Whether the master password still has to be entered
+    pub locked: bool,
+    /// Argon2id parameters (master password mode only)
+    pub kdf_params: Option<KdfParams>,
+    /// When the master password was set or last changed
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Request to protect wallet keys with a master password
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnableMasterPasswordRequest {
+    /// New master password
+    pub password: String,
+}
+
+/// Request to change the master password (re-encrypts all wallets)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeMasterPasswordRequest {
+    /// Current master password
+    pub current_password: String,
+    /// New master password
+    pub new_password: String,
+}
+
+/// Request to go back to machine-bound encryption
+#[derive(Debug, Clone, Deserialize)]
+pub struct DisableMasterPasswordRequest {
+    /// Current master password
+    pub current_password: String,
+}
+
+// =============================================================================
+// ENCRYPTED BACKUP TYPES
+// =============================================================================
+
+/// Portable encrypted wallet backup file
+///
+/// Private keys are encrypted with a key derived from the backup password, so
+/// the file can be restored on any machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletBackupFile {
+    /// Always "screenerbot-wallet-backup"
+    pub format: String,
+    /// File format version
+    pub version: u32,
+    /// When the backup was created
+    pub created_at: DateTime<Utc>,
+    /// Number of wallets in the backup
+    pub wallet_count: u32,
+    /// Argon2id parameters for the backup password
+    pub kdf_params: KdfParams,
+    /// Base64-encoded Argon2id salt
+    pub kdf_salt: String,
+    /// Base64-encoded AES-256-GCM ciphertext of the wallet list
+    pub ciphertext: String,
+    /// Base64-encoded 12-byte nonce
+    pub nonce: String,
+}
+
+/// Request to export an encrypted wallet backup
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportWalletBackupRequest {
+    /// Password that will protect the backup file
+    pub password: String,
+    /// Include archived wallets
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+/// Request to import an encrypted wallet backup
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportWalletBackupRequest {
+    /// Backup file contents
+    pub backup: WalletBackupFile,
+    /// Password the backup was exported with
+    pub password: String,
+}
+
+/// Result of importing an encrypted wallet backup
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WalletBackupImportResult {
+    /// Addresses of imported wallets
+    pub imported: Vec<String>,
+    /// Addresses skipped because they already exist
+    pub skipped: Vec<String>,
+    /// Wallets that failed to import, with the reason
+    pub failed: Vec<String>,
+}
@@ -16,6 +16,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 
 use chrono::{Duration, Utc};
 
+use crate::swaps::PriorityFeeStats;
 use crate::webserver::routes::dashboard::{
     BlacklistInfo, DashboardOverview, HomeDashboardResponse, MonitoringInfo, OpenPositionDetail,
     PositionPerformer, PositionsSnapshot, PositionsSummary, RpcInfo, ServiceStatus, SystemInfo,
@@ -515,6 +516,18 @@ pub fn get_demo_home_dashboard() -> HomeDashboardResponse {
         system,
         tokens,
         trader_status: TraderStatusInfo { running: true }, // Demo mode: always running
+        priority_fees: PriorityFeeStats {
+            enabled: true,
+            network_p50: Some(12_500),
+            network_p75: Some(48_000),
+            network_p90: Some(210_000),
+            network_sample_count: 150,
+            swaps_recorded: 24,
+            landed_rate: 95.8,
+            avg_fee_lamports: 38_400.0,
+            avg_landing_ms: 1_850.0,
+            ..Default::default()
+        },
         timestamp: now.to_rfc3339(),
     }
 }
@@ -666,6 +679,9 @@ pub fn get_demo_positions(status: Option<&str>) -> Vec<PositionResponse> {
                 average_exit_price: None,
                 remaining_token_amount: Some((size / entry * 1e9) as u64),
                 total_exited_amount: 0,
+                sizing_mode: Some("fixed".to_string()),
+                sizing_reason: Some(format!("fixed trade size {:.4} SOL", size)),
+                paper: false,
             });
             id_counter += 1;
         }
@@ -724,6 +740,9 @@ pub fn get_demo_positions(status: Option<&str>) -> Vec<PositionResponse> {
                 average_exit_price: Some(*exit),
                 remaining_token_amount: None,
                 total_exited_amount: (size / entry * 1e9) as u64,
+                sizing_mode: Some("fixed".to_string()),
+                sizing_reason: Some(format!("fixed trade size {:.4} SOL", size)),
+                paper: false,
             });
             id_counter += 1;
         }
@@ -836,6 +855,7 @@ pub fn get_demo_header_metrics() -> HeaderMetricsResponse {
     let trader = TraderHeaderInfo {
         running: true,
         enabled: true,
+        paper_trading: false,
         today_pnl_sol: 0.265,
         today_pnl_percent: 3.12,
         uptime_seconds: 3 * 24 * 3600 + 7 * 3600 + 23 * 60 + 45, // 3d 7h 23m 45s
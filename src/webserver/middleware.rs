@@ -117,6 +117,7 @@ pub async fn security_gate(request: Request, next: Next) -> Response {
 /// Blocks all non-initialization API endpoints until INITIALIZATION_COMPLETE is true.
 /// Allows:
 /// - /api/initialization/* (all initialization endpoints)
+/// - /api/lockscreen/* and /api/auth/* while wallet keys await the master password
 /// - Static resources (HTML pages, scripts, styles)
 /// - Root paths (/, /services, /tokens, etc. - for page HTML)
 ///
@@ -149,6 +150,13 @@ pub async fn initialization_gate(request: Request, next: Next) -> Response {
         return next.run(request).await;
     }
 
+    // Allow login and master password unlock while startup waits for the wallet keys
+    if crate::wallets::is_locked()
+        && (path.starts_with("/api/lockscreen") || path.starts_with("/api/auth/"))
+    {
+        return next.run(request).await;
+    }
+
     // Allow static resources (scripts, styles, page HTML)
     if path.starts_with("/scripts/")
         || path.starts_with("/styles/")
@@ -0,0 +1,196 @@
+//! Backup API routes
+//!
+//! Create, list, inspect and delete backup archives, and stage selective
+//! restores. Restores replace database files, so they are applied on the next
+//! restart rather than under the running services.
+
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::Response,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::backup::{
+    self, BackupInfo, BackupManifest, BackupStatus, BackupTrigger, PendingRestore,
+    BACKUP_COMPONENTS,
+};
+use crate::logger::{self, LogTag};
+use crate::webserver::state::AppState;
+use crate::webserver::utils::{error_response, success_response};
+
+// =============================================================================
+// REQUEST / RESPONSE TYPES
+// =============================================================================
+
+#[derive(Debug, Default, Deserialize)]
+struct ComponentsRequest {
+    /// Component names; empty or missing means all
+    #[serde(default)]
+    components: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct BackupsListResponse {
+    backups: Vec<BackupInfo>,
+    total: usize,
+    status: BackupStatus,
+    pending_restore: Option<PendingRestore>,
+    /// Component names accepted by create and restore
+    components: Vec<&'static str>,
+    directory: String,
+}
+
+#[derive(Serialize)]
+struct RestoreStagedResponse {
+    message: String,
+    pending_restore: PendingRestore,
+    restart_required: bool,
+}
+
+#[derive(Serialize)]
+struct MessageResponse {
+    message: String,
+}
+
+// =============================================================================
+// ROUTES
+// =============================================================================
+
+/// Create backup routes
+pub fn routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_backups))
+        .route("/", post(create_backup))
+        .route("/pending-restore", delete(cancel_pending_restore))
+        .route("/:name", get(get_backup))
+        .route("/:name", delete(delete_backup))
+        .route("/:name/restore", post(stage_restore))
+}
+
+// =============================================================================
+// HANDLERS
+// =============================================================================
+
+/// List archives with scheduler status and any staged restore
+async fn list_backups() -> Response {
+    match tokio::task::spawn_blocking(backup::list_backups).await {
+        Ok(Ok(backups)) => success_response(BackupsListResponse {
+            total: backups.len(),
+            backups,
+            status: backup::get_backup_status().await,
+            pending_restore: backup::get_pending_restore(),
+            components: BACKUP_COMPONENTS.iter().map(|c| c.name).collect(),
+            directory: crate::paths::get_backups_directory().display().to_string(),
+        }),
+        Ok(Err(e)) => internal_error("LIST_ERROR", "Failed to list backups", &e),
+        Err(e) => internal_error("LIST_ERROR", "Failed to list backups", &e.to_string()),
+    }
+}
+
+/// Create a backup of the requested components (all when empty)
+async fn create_backup(request: Option<Json<ComponentsRequest>>) -> Response {
+    let request = request.map(|Json(r)| r).unwrap_or_default();
+    if let Err(e) = backup::resolve_components(&request.components) {
+        return error_response(StatusCode::BAD_REQUEST, "INVALID_COMPONENT", &e, None);
+    }
+
+    match backup::run_backup(BackupTrigger::Manual, request.components).await {
+        Ok(info) => success_response(info),
+        Err(e) => internal_error("BACKUP_ERROR", "Failed to create backup", &e),
+    }
+}
+
+/// Manifest of one archive
+async fn get_backup(Path(name): Path<String>) -> Response {
+    let result: Result<BackupManifest, String> =
+        tokio::task::spawn_blocking(move || backup::read_backup_manifest(&name))
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|result| result);
+
+    match result {
+        Ok(manifest) => success_response(manifest),
+        Err(e) => error_response(
+            StatusCode::NOT_FOUND,
+            "NOT_FOUND",
+            "Backup not found",
+            Some(&e),
+        ),
+    }
+}
+
+async fn delete_backup(Path(name): Path<String>) -> Response {
+    match backup::delete_backup(&name) {
+        Ok(()) => {
+            logger::info(LogTag::System, &format!("Backup deleted: {}", name));
+            success_response(MessageResponse {
+                message: format!("Backup {} deleted", name),
+            })
+        }
+        Err(e) => error_response(
+            StatusCode::NOT_FOUND,
+            "DELETE_ERROR",
+            "Failed to delete backup",
+            Some(&e),
+        ),
+    }
+}
+
+/// Verify an archive and stage a restore of the requested components for the next restart
+async fn stage_restore(
+    Path(name): Path<String>,
+    request: Option<Json<ComponentsRequest>>,
+) -> Response {
+    let request = request.map(|Json(r)| r).unwrap_or_default();
+    let result =
+        tokio::task::spawn_blocking(move || backup::stage_restore(&name, &request.components))
+            .await
+            .map_err(|e| e.to_string())
+            .and_then(|result| result);
+
+    match result {
+        Ok(pending_restore) => success_response(RestoreStagedResponse {
+            message: format!(
+                "Restore of {} staged. Restart the bot to apply it; current files are saved to a pre-restore backup first.",
+                pending_restore.components.join(", ")
+            ),
+            pending_restore,
+            restart_required: true,
+        }),
+        Err(e) => error_response(
+            StatusCode::BAD_REQUEST,
+            "RESTORE_ERROR",
+            "Backup cannot be restored",
+            Some(&e),
+        ),
+    }
+}
+
+async fn cancel_pending_restore() -> Response {
+    match backup::cancel_pending_restore() {
+        Ok(true) => success_response(MessageResponse {
+            message: "Staged restore cancelled".to_string(),
+        }),
+        Ok(false) => error_response(
+            StatusCode::NOT_FOUND,
+            "NO_PENDING_RESTORE",
+            "No restore is staged",
+            None,
+        ),
+        Err(e) => internal_error("CANCEL_ERROR", "Failed to cancel staged restore", &e),
+    }
+}
+
+fn internal_error(code: &str, message: &str, details: &str) -> Response {
+    logger::error(LogTag::System, &format!("{}: {}", message, details));
+    error_response(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        code,
+        message,
+        Some(details),
+    )
+}
@@ -77,6 +77,8 @@ pub fn routes() -> Router<Arc<AppState>> {
         .route("/config/gui/defaults", get(get_gui_defaults))
         .route("/config/telegram", get(get_telegram_config))
         .route("/config/ai", get(get_ai_config))
+        .route("/config/copy_trade", get(get_copy_trade_config))
+        .route("/config/backup", get(get_backup_config))
         .route("/config/metadata", get(get_config_metadata))
         // PATCH endpoints - Partial updates (use JSON with only fields to update)
         .route(
@@ -126,6 +128,14 @@ pub fn routes() -> Router<Arc<AppState>> {
             patch(patch_any_config::<config::TelegramConfig>),
         )
         .route("/config/ai", patch(patch_any_config::<config::AiConfig>))
+        .route(
+            "/config/copy_trade",
+            patch(patch_any_config::<config::CopyTradeConfig>),
+        )
+        .route(
+            "/config/backup",
+            patch(patch_any_config::<config::BackupConfig>),
+        )
         // Import/Export endpoints
         .route("/config/export", post(export_config))
         .route("/config/import/preview", post(import_config_preview))
@@ -339,6 +349,26 @@ async fn get_ai_config() -> Response {
     success_response(data)
 }
 
+/// GET /api/config/copy_trade - Get copy trader configuration
+async fn get_copy_trade_config() -> Response {
+    let data = config::with_config(|cfg| ConfigResponse {
+        data: cfg.copy_trade.clone(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    });
+
+    success_response(data)
+}
+
+/// GET /api/config/backup - Get scheduled backup configuration
+async fn get_backup_config() -> Response {
+    let data = config::with_config(|cfg| ConfigResponse {
+        data: cfg.backup.clone(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    });
+
+    success_response(data)
+}
+
 /// GET /api/config/metadata - Get configuration metadata for UI rendering
 async fn get_config_metadata() -> Response {
     let response = ConfigMetadataResponse {
@@ -383,6 +413,8 @@ where
             "GuiConfig" => serde_json::to_value(&cfg.gui).ok(),
             "TelegramConfig" => serde_json::to_value(&cfg.telegram).ok(),
             "AiConfig" => serde_json::to_value(&cfg.ai).ok(),
+            "CopyTradeConfig" => serde_json::to_value(&cfg.copy_trade).ok(),
+            "BackupConfig" => serde_json::to_value(&cfg.backup).ok(),
             _ => None,
         });
 
@@ -543,6 +575,26 @@ where
                     true,
                 )?;
             }
+            "CopyTradeConfig" => {
+                let new_config: config::CopyTradeConfig = serde_json::from_value(section_json)
+                    .map_err(|e| format!("Invalid CopyTradeConfig: {}", e))?;
+                config::update_config_section(
+                    |cfg| {
+                        cfg.copy_trade = new_config;
+                    },
+                    true,
+                )?;
+            }
+            "BackupConfig" => {
+                let new_config: config::BackupConfig = serde_json::from_value(section_json)
+                    .map_err(|e| format!("Invalid BackupConfig: {}", e))?;
+                config::update_config_section(
+                    |cfg| {
+                        cfg.backup = new_config;
+                    },
+                    true,
+                )?;
+            }
             _ => {
                 return Err(format!("Unknown config section: {}", section_name));
             }
@@ -7,6 +7,7 @@ use crate::global::{
 };
 use crate::positions;
 use crate::rpc::get_global_rpc_stats;
+use crate::swaps::{get_priority_fee_stats, PriorityFeeStats};
 use crate::tokens::cleanup::get_blacklist_summary;
 use crate::tokens::database::get_global_database;
 use crate::trader::is_trader_running;
@@ -342,6 +343,7 @@ pub struct HomeDashboardResponse {
     pub system: SystemMetrics,
     pub tokens: TokenStatistics,
     pub trader_status: TraderStatusInfo,
+    pub priority_fees: PriorityFeeStats,
     pub timestamp: String,
 }
 
@@ -776,6 +778,7 @@ async fn get_home_dashboard(State(state): State<Arc<AppState>>) -> Json<HomeDash
         system,
         tokens,
         trader_status,
+        priority_fees: get_priority_fee_stats().await,
         timestamp: now.to_rfc3339(),
     })
 }
@@ -206,6 +206,18 @@ fn get_rejection_display_label(reason: &str) -> String {
         "rug_lp_providers_missing" => "LP providers missing",
         "rug_lp_lock_low" => "LP lock too low",
         "rug_lp_lock_missing" => "LP lock missing",
+        "chain_data_missing" => "On-chain data missing",
+        "chain_mint_authority" => "Mint authority present",
+        "chain_freeze_authority" => "Freeze authority present",
+        "chain_transfer_fee_high" => "Transfer fee too high",
+        "chain_transfer_hook" => "Transfer hook present",
+        "chain_permanent_delegate" => "Permanent delegate present",
+        "chain_non_transferable" => "Non-transferable token",
+        "chain_default_frozen" => "Accounts frozen by default",
+        "chain_lp_secured_low" => "LP burned/locked too low",
+        "chain_lp_missing" => "LP status unknown",
+        "chain_top_holder" => "Top holder % too high",
+        "chain_top10_holders" => "Top 10 holders % too high",
         _ => reason, // Return original if not mapped
     }
     .to_string()
@@ -284,7 +296,7 @@ async fn get_rejection_stats() -> Response {
 
 /// Categorize rejection reason into high-level category
 fn get_rejection_category(reason: &str) -> &'static str {
-    if reason.starts_with("rug_") {
+    if reason.starts_with("rug_") || reason.starts_with("chain_") {
         if reason.contains("authority")
             || reason.contains("rugged")
             || reason.contains("level_danger")
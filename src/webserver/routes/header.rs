@@ -28,6 +28,7 @@ pub struct HeaderMetricsResponse {
 pub struct TraderHeaderInfo {
     pub running: bool,
     pub enabled: bool,
+    pub paper_trading: bool,
     pub today_pnl_sol: f64,
     pub today_pnl_percent: f64,
     pub uptime_seconds: u64,
@@ -104,6 +105,7 @@ async fn get_header_metrics() -> Json<HeaderMetricsResponse> {
     let trader = TraderHeaderInfo {
         running: trader_running,
         enabled: trader_enabled,
+        paper_trading: crate::global::is_paper_trading(),
         today_pnl_sol,
         today_pnl_percent,
         uptime_seconds,
@@ -1,6 +1,7 @@
 //! Lockscreen API routes for dashboard security
 //!
-//! Provides REST API endpoints for managing lockscreen password and settings.
+//! Provides REST API endpoints for managing lockscreen password and settings,
+//! and for entering the wallet master password after a restart.
 
 use axum::{
     response::Response,
@@ -13,6 +14,7 @@ use std::sync::Arc;
 use crate::config;
 use crate::secure_storage::{generate_password_salt, hash_password, verify_password};
 use crate::webserver::state::AppState;
+use crate::webserver::totp;
 use crate::webserver::utils::{error_response, success_response};
 use axum::http::StatusCode;
 
@@ -33,6 +35,8 @@ pub struct LockscreenStatusResponse {
     pub auto_lock_timeout_secs: u64,
     /// Lock on app blur/minimize
     pub lock_on_blur: bool,
+    /// Whether wallet keys are waiting for the master password
+    pub wallet_keys_locked: bool,
     /// Timestamp of response
     pub timestamp: String,
 }
@@ -53,6 +57,27 @@ pub struct VerifyPasswordResponse {
     pub timestamp: String,
 }
 
+/// Wallet master password unlock request
+#[derive(Debug, Deserialize)]
+pub struct UnlockWalletsRequest {
+    /// The master password
+    pub password: String,
+    /// TOTP code (required if 2FA is enabled)
+    pub totp_code: Option<String>,
+}
+
+/// Wallet master password unlock response
+#[derive(Debug, Serialize)]
+pub struct UnlockWalletsResponse {
+    /// Whether the wallet keys were unlocked
+    pub unlocked: bool,
+    /// Whether a TOTP code is required before unlocking
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_totp: Option<bool>,
+    /// Timestamp of response
+    pub timestamp: String,
+}
+
 /// Set password request
 #[derive(Debug, Deserialize)]
 pub struct SetPasswordRequest {
@@ -98,6 +123,7 @@ pub fn routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/status", get(get_status))
         .route("/verify", post(verify_password_handler))
+        .route("/unlock-wallets", post(unlock_wallets))
         .route("/set-password", post(set_password))
         .route("/clear-password", post(clear_password))
         .route("/settings", post(update_settings))
@@ -117,6 +143,7 @@ async fn get_status() -> Response {
             has_password: !lockscreen.password_hash.is_empty(),
             auto_lock_timeout_secs: lockscreen.auto_lock_timeout_secs,
             lock_on_blur: lockscreen.lock_on_blur,
+            wallet_keys_locked: crate::wallets::is_locked(),
             timestamp: chrono::Utc::now().to_rfc3339(),
         }
     });
@@ -152,6 +179,84 @@ async fn verify_password_handler(Json(req): Json<VerifyPasswordRequest>) -> Resp
     })
 }
 
+/// POST /api/lockscreen/unlock-wallets - Enter the wallet master password
+///
+/// Requires the TOTP code as well when 2FA is enabled. Startup continues once
+/// the wallet keys are unlocked.
+async fn unlock_wallets(Json(req): Json<UnlockWalletsRequest>) -> Response {
+    if !crate::wallets::is_locked() {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "NOT_LOCKED",
+            "Wallet keys are not locked",
+            None,
+        );
+    }
+
+    let (totp_enabled, totp_secret) = config::with_config(|cfg| {
+        (
+            cfg.webserver.auth_totp_enabled && !cfg.webserver.auth_totp_secret.is_empty(),
+            cfg.webserver.auth_totp_secret.clone(),
+        )
+    });
+
+    if totp_enabled {
+        let code = match &req.totp_code {
+            Some(code) => code,
+            None => {
+                return success_response(UnlockWalletsResponse {
+                    unlocked: false,
+                    requires_totp: Some(true),
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                });
+            }
+        };
+
+        match totp::verify_totp(&totp_secret, code) {
+            Ok(true) => {}
+            Ok(false) => {
+                return error_response(
+                    StatusCode::UNAUTHORIZED,
+                    "INVALID_TOTP",
+                    "Invalid or expired 2FA code",
+                    None,
+                );
+            }
+            Err(e) => {
+                return error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "TOTP_ERROR",
+                    "Failed to verify 2FA code",
+                    Some(&e),
+                );
+            }
+        }
+    }
+
+    if let Err(e) = crate::wallets::unlock_wallets(&req.password).await {
+        if e.contains("Incorrect master password") {
+            return error_response(
+                StatusCode::UNAUTHORIZED,
+                "INVALID_PASSWORD",
+                "Incorrect master password",
+                None,
+            );
+        }
+        return error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "UNLOCK_ERROR",
+            "Failed to unlock wallet keys",
+            Some(&e),
+        );
+    }
+
+    success_response(UnlockWalletsResponse {
+        unlocked: true,
+        requires_totp: None,
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
 /// POST /api/lockscreen/set-password - Set or change password
 async fn set_password(Json(req): Json<SetPasswordRequest>) -> Response {
     // Validate password type
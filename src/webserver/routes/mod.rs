@@ -9,6 +9,7 @@ use std::sync::Arc;
 pub mod actions;
 pub mod ai;
 pub mod auth;
+pub mod backups;
 pub mod billboard;
 pub mod blacklist;
 pub mod config;
@@ -211,6 +212,7 @@ fn api_routes() -> Router<Arc<AppState>> {
         .nest("/strategies", strategies::routes())
         .nest("/tools", tools::routes())
         .nest("/wallets", wallets::routes())
+        .nest("/backups", backups::routes())
         .nest("/lockscreen", lockscreen::routes())
         .nest("/auth", auth::routes())
         .nest("/telegram", telegram::routes())
@@ -1,11 +1,11 @@
 use axum::{
     extract::{Path, Query},
-    http::StatusCode,
-    response::Response,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     routing::get,
     Json, Router,
 };
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -30,6 +30,15 @@ pub struct PositionsQuery {
     pub mint: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PositionsExportQuery {
+    pub format: Option<String>, // "json" (default), "csv"
+    pub rows: Option<String>,   // CSV only: "trades" (default), "lots"
+    pub from: Option<String>,   // RFC3339 or YYYY-MM-DD
+    pub to: Option<String>,     // RFC3339 or YYYY-MM-DD (whole day included)
+    pub wallet: Option<String>, // Defaults to the current wallet
+}
+
 #[derive(Debug, Serialize)]
 pub struct PositionResponse {
     pub id: Option<i64>,
@@ -76,6 +85,11 @@ pub struct PositionResponse {
     pub average_exit_price: Option<f64>,
     pub remaining_token_amount: Option<u64>,
     pub total_exited_amount: u64,
+    // Entry sizing
+    pub sizing_mode: Option<String>,
+    pub sizing_reason: Option<String>,
+    // Simulated (paper trading) position
+    pub paper: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -112,6 +126,18 @@ pub struct ExitRecordResponse {
     pub fees_sol: Option<f64>,
 }
 
+/// Take-profit ladder tier status for position detail view
+#[derive(Debug, Serialize)]
+pub struct TakeProfitTierResponse {
+    pub tier: u32,
+    pub trigger_pct: f64,
+    pub sell_pct: f64,
+    pub status: String, // "pending", "in_flight" or "filled"
+    pub trigger_price: Option<f64>,
+    pub transaction_signature: Option<String>,
+    pub triggered_at: Option<i64>,
+}
+
 /// Token information for position detail view
 #[derive(Debug, Serialize)]
 pub struct PositionTokenInfo {
@@ -180,6 +206,7 @@ pub struct PositionDetailResponse {
     pub position: Option<PositionDetail>,
     pub entries: Vec<EntryRecordResponse>,
     pub exits: Vec<ExitRecordResponse>,
+    pub take_profit_ladder: Vec<TakeProfitTierResponse>,
     pub executions: Vec<PositionExecutionRow>,
     pub transactions: Vec<PositionTransactionSummary>,
     pub state_history: Vec<PositionStateTimelineEntry>,
@@ -259,6 +286,7 @@ pub fn routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/positions", get(get_positions))
         .route("/positions/stats", get(get_positions_stats))
+        .route("/positions/export", get(export_positions))
         .route("/positions/:key/details", get(get_position_details))
         .route("/positions/:mint/debug", get(get_position_debug_info))
 }
@@ -278,6 +306,109 @@ async fn get_positions(Query(params): Query<PositionsQuery>) -> Json<Vec<Positio
     Json(responses)
 }
 
+/// Accounting export of realized trades
+///
+/// GET /api/positions/export?format=json|csv&rows=trades|lots&from=&to=&wallet=
+async fn export_positions(Query(params): Query<PositionsExportQuery>) -> Response {
+    let from = match params.from.as_deref().map(|v| parse_export_date(v, false)) {
+        Some(Err(e)) => {
+            return error_response(StatusCode::BAD_REQUEST, "INVALID_FROM", &e, None);
+        }
+        Some(Ok(date)) => Some(date),
+        None => None,
+    };
+    let to = match params.to.as_deref().map(|v| parse_export_date(v, true)) {
+        Some(Err(e)) => {
+            return error_response(StatusCode::BAD_REQUEST, "INVALID_TO", &e, None);
+        }
+        Some(Ok(date)) => Some(date),
+        None => None,
+    };
+
+    let export = match positions::export::build_trade_export(params.wallet.clone(), from, to).await
+    {
+        Ok(export) => export,
+        Err(e) => {
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "EXPORT_FAILED",
+                "Failed to build trade export",
+                Some(&e),
+            );
+        }
+    };
+
+    logger::info(
+        LogTag::Positions,
+        &format!(
+            "Exported {} trades for wallet {}",
+            export.trades.len(),
+            export.wallet_address
+        ),
+    );
+
+    match params.format.as_deref().unwrap_or("json") {
+        "json" => success_response(export),
+        "csv" => {
+            let lots = params.rows.as_deref() == Some("lots");
+            let csv = if lots {
+                positions::export::lots_to_csv(&export)
+            } else {
+                positions::export::trades_to_csv(&export)
+            };
+            match csv {
+                Ok(content) => {
+                    let filename = format!(
+                        "screenerbot_{}_{}.csv",
+                        if lots { "lots" } else { "trades" },
+                        Utc::now().format("%Y%m%d_%H%M%S")
+                    );
+                    (
+                        StatusCode::OK,
+                        [
+                            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                            (
+                                header::CONTENT_DISPOSITION,
+                                format!("attachment; filename=\"{}\"", filename),
+                            ),
+                        ],
+                        content,
+                    )
+                        .into_response()
+                }
+                Err(e) => error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "EXPORT_FAILED",
+                    "Failed to write CSV",
+                    Some(&e),
+                ),
+            }
+        }
+        other => error_response(
+            StatusCode::BAD_REQUEST,
+            "INVALID_FORMAT",
+            &format!("Unsupported export format: {}", other),
+            None,
+        ),
+    }
+}
+
+/// Parse an RFC3339 timestamp or a plain date (start or end of that UTC day)
+fn parse_export_date(value: &str, end_of_day: bool) -> Result<DateTime<Utc>, String> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Ok(date.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{}', expected YYYY-MM-DD or RFC3339", value))?;
+    let time = if end_of_day {
+        date.and_hms_opt(23, 59, 59)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    };
+    time.map(|t| t.and_utc())
+        .ok_or_else(|| format!("Invalid date '{}'", value))
+}
+
 pub async fn load_positions_with_filters(
     status: &str,
     limit: usize,
@@ -370,6 +501,13 @@ fn map_position_to_response_with_logo(
         average_exit_price: p.average_exit_price,
         remaining_token_amount: p.remaining_token_amount,
         total_exited_amount: p.total_exited_amount,
+        sizing_mode: p.sizing_mode.clone(),
+        sizing_reason: p.sizing_reason.clone(),
+        paper: p
+            .entry_transaction_signature
+            .as_deref()
+            .map(positions::is_paper_signature)
+            .unwrap_or(false),
     }
 }
 
@@ -396,6 +534,7 @@ async fn get_position_details(Path(key): Path<String>) -> Response {
             let transactions = build_transaction_summaries(&position).await;
             let state_history = load_state_history_entries(&position).await;
             let (entries, exits) = load_entry_exit_history(&position).await;
+            let take_profit_ladder = load_take_profit_ladder(&position).await;
 
             // Fetch token data from database
             let token_data = tokens::database::get_full_token_async(mint)
@@ -495,6 +634,7 @@ async fn get_position_details(Path(key): Path<String>) -> Response {
                 position: Some(detail),
                 entries,
                 exits,
+                take_profit_ladder,
                 executions,
                 transactions,
                 state_history,
@@ -768,6 +908,77 @@ async fn load_entry_exit_history(
     (entries, exits)
 }
 
+/// Load take-profit ladder tiers with their fill status for a position
+async fn load_take_profit_ladder(position: &positions::Position) -> Vec<TakeProfitTierResponse> {
+    let Some(id) = position.id else {
+        return Vec::new();
+    };
+
+    let fired = match positions::get_take_profit_tiers(id).await {
+        Ok(records) => records,
+        Err(err) => {
+            logger::debug(
+                LogTag::Webserver,
+                &format!(
+                    "Failed to load take-profit tiers for position {}: {}",
+                    id, err
+                ),
+            );
+            Vec::new()
+        }
+    };
+
+    // Nothing to show when the ladder is off and no tier ever fired
+    if fired.is_empty() && !crate::trader::config::is_take_profit_ladder_enabled() {
+        return Vec::new();
+    }
+
+    let mut tiers: Vec<TakeProfitTierResponse> = crate::trader::config::get_take_profit_ladder()
+        .into_iter()
+        .enumerate()
+        .map(|(i, tier)| TakeProfitTierResponse {
+            tier: i as u32 + 1,
+            trigger_pct: tier.trigger_pct,
+            sell_pct: tier.sell_pct,
+            status: "pending".to_string(),
+            trigger_price: None,
+            transaction_signature: None,
+            triggered_at: None,
+        })
+        .collect();
+
+    for record in fired {
+        let status = if record.transaction_signature.is_some() {
+            "filled"
+        } else {
+            "in_flight"
+        };
+        let response = TakeProfitTierResponse {
+            tier: record.tier,
+            trigger_pct: record.trigger_pct,
+            sell_pct: record.sell_pct,
+            status: status.to_string(),
+            trigger_price: Some(record.trigger_price),
+            transaction_signature: record.transaction_signature,
+            triggered_at: Some(record.triggered_at.timestamp()),
+        };
+        // Fired tiers are identified by trigger %, the ladder may have changed since
+        match tiers
+            .iter_mut()
+            .find(|t| t.trigger_pct == response.trigger_pct)
+        {
+            Some(slot) => *slot = response,
+            None => tiers.push(response),
+        }
+    }
+
+    tiers.sort_by(|a, b| a.trigger_pct.total_cmp(&b.trigger_pct));
+    for (i, tier) in tiers.iter_mut().enumerate() {
+        tier.tier = i as u32 + 1;
+    }
+    tiers
+}
+
 impl PositionTransactionSummary {
     fn from_transaction(
         kind: &str,
@@ -11,7 +11,7 @@ use std::sync::Arc;
 
 use crate::{
     logger::{self, LogTag},
-    services::{ServiceHealth, ServiceMetrics},
+    services::{ServiceHealth, ServiceMetrics, ServiceRestartInfo},
     webserver::{state::AppState, utils::success_response},
 };
 
@@ -29,6 +29,8 @@ pub struct ServiceDetailResponse {
     pub health: ServiceHealth,
     pub metrics: ServiceMetrics,
     pub uptime_seconds: u64,
+    /// Supervisor restart count and last failure reason
+    pub restarts: ServiceRestartInfo,
 }
 
 /// List of all services with their status
@@ -194,6 +196,7 @@ pub async fn gather_services_overview_snapshot() -> ServicesOverviewResponse {
                                 health,
                                 metrics,
                                 uptime_seconds,
+                                restarts: manager.get_restart_info(name),
                             });
                         }
                     }
@@ -13,7 +13,10 @@ use std::sync::Arc;
 use crate::{
     logger::{self, LogTag},
     strategies::{
-        self, db,
+        self,
+        backtest::{self, BacktestConfig, ExitRules, FillModel},
+        bundle::{self, ImportOptions, StrategyBundle},
+        db,
         db::{
             delete_strategy, get_all_strategies, get_enabled_strategies, get_strategy,
             get_strategy_performance, insert_strategy, update_strategy,
@@ -227,6 +230,87 @@ pub struct StrategyTemplateItem {
     pub author: Option<String>,
 }
 
+/// Strategy backtest request (omitted fields fall back to live trader/swap config)
+#[derive(Debug, Deserialize)]
+pub struct BacktestRequest {
+    pub mints: Vec<String>,
+    /// Unix seconds; defaults to `end_timestamp - 24h`
+    pub start_timestamp: Option<i64>,
+    /// Unix seconds; defaults to now
+    pub end_timestamp: Option<i64>,
+    pub trade_size_sol: Option<f64>,
+    pub initial_balance_sol: Option<f64>,
+    pub warmup_candles: Option<usize>,
+    pub entry_strategy_id: Option<String>,
+    pub exit_strategy_id: Option<String>,
+    pub fill_model: Option<FillModel>,
+    pub exit_rules: Option<ExitRules>,
+}
+
+/// Query parameters for stored backtest list
+#[derive(Debug, Deserialize)]
+pub struct BacktestListQuery {
+    #[serde(default = "default_backtest_limit")]
+    pub limit: usize,
+}
+
+fn default_backtest_limit() -> usize {
+    10
+}
+
+/// Query parameters for revision history
+#[derive(Debug, Deserialize)]
+pub struct RevisionListQuery {
+    #[serde(default = "default_revision_limit")]
+    pub limit: usize,
+}
+
+fn default_revision_limit() -> usize {
+    50
+}
+
+/// Query parameters for comparing two revisions
+#[derive(Debug, Deserialize)]
+pub struct RevisionDiffQuery {
+    pub from: i32,
+    /// Defaults to the current version
+    pub to: Option<i32>,
+}
+
+/// Rollback request
+#[derive(Debug, Deserialize)]
+pub struct RollbackRequest {
+    pub version: i32,
+}
+
+/// Revision history entry (rules omitted; fetch a single revision for full content)
+#[derive(Debug, Serialize)]
+pub struct RevisionSummary {
+    pub version: i32,
+    pub name: String,
+    pub enabled: bool,
+    pub author: Option<String>,
+    pub created_at: String,
+    pub note: Option<String>,
+}
+
+/// Bundle export request
+#[derive(Debug, Deserialize)]
+pub struct BundleExportRequest {
+    /// Strategies to export (omit for all)
+    pub strategy_ids: Option<Vec<String>>,
+    /// Templates to export (omit for all, `[]` for none)
+    pub template_ids: Option<Vec<String>>,
+}
+
+/// Bundle import request
+#[derive(Debug, Deserialize)]
+pub struct BundleImportRequest {
+    pub bundle: serde_json::Value,
+    #[serde(flatten)]
+    pub options: ImportOptions,
+}
+
 // =============================================================================
 // ROUTE HANDLERS
 // =============================================================================
@@ -753,6 +837,367 @@ async fn test_strategy(
     success_response(response)
 }
 
+/// POST /api/strategies/:id/backtest - Replay stored OHLCV history through a strategy
+async fn run_strategy_backtest(
+    Path(id): Path<String>,
+    Json(request): Json<BacktestRequest>,
+) -> Response {
+    logger::info(
+        LogTag::Webserver,
+        &format!(
+            "POST /api/strategies/{}/backtest - mints={}",
+            id,
+            request.mints.len()
+        ),
+    );
+
+    match get_strategy(&id) {
+        Ok(Some(_)) => {}
+        Ok(None) => return err(StatusCode::NOT_FOUND, "Strategy not found"),
+        Err(e) => {
+            return err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("Failed to get strategy: {}", e),
+            );
+        }
+    }
+
+    let end_timestamp = request
+        .end_timestamp
+        .unwrap_or_else(|| Utc::now().timestamp());
+    let start_timestamp = request.start_timestamp.unwrap_or(end_timestamp - 24 * 3600);
+
+    let mut config = BacktestConfig::from_config(request.mints, start_timestamp, end_timestamp);
+    if let Some(trade_size_sol) = request.trade_size_sol {
+        config.trade_size_sol = trade_size_sol;
+    }
+    if let Some(initial_balance_sol) = request.initial_balance_sol {
+        config.initial_balance_sol = initial_balance_sol;
+    }
+    if let Some(warmup_candles) = request.warmup_candles {
+        config.warmup_candles = warmup_candles;
+    }
+    if let Some(fill_model) = request.fill_model {
+        config.fill_model = fill_model;
+    }
+    if let Some(exit_rules) = request.exit_rules {
+        config.exit_rules = exit_rules;
+    }
+    config.entry_strategy_id = request.entry_strategy_id;
+    config.exit_strategy_id = request.exit_strategy_id;
+
+    if let Err(e) = config.validate() {
+        return err(StatusCode::BAD_REQUEST, &e);
+    }
+
+    match backtest::run_backtest(&id, config).await {
+        Ok(report) => success_response(report),
+        Err(e) => err(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("Backtest failed: {}", e),
+        ),
+    }
+}
+
+/// GET /api/strategies/:id/backtest - List stored backtest reports (newest first)
+async fn list_strategy_backtests(
+    Path(id): Path<String>,
+    Query(query): Query<BacktestListQuery>,
+) -> Response {
+    logger::info(
+        LogTag::Webserver,
+        &format!(
+            "GET /api/strategies/{}/backtest - limit={}",
+            id, query.limit
+        ),
+    );
+
+    match db::get_backtests(&id, query.limit.clamp(1, 100)) {
+        Ok(reports) => success_response(serde_json::json!({
+            "total": reports.len(),
+            "items": reports,
+            "timestamp": Utc::now().to_rfc3339(),
+        })),
+        Err(e) => err(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("Failed to get backtests: {}", e),
+        ),
+    }
+}
+
+/// GET /api/strategies/:id/revisions - List saved revisions (newest first)
+async fn list_strategy_revisions(
+    Path(id): Path<String>,
+    Query(query): Query<RevisionListQuery>,
+) -> Response {
+    logger::info(
+        LogTag::Webserver,
+        &format!(
+            "GET /api/strategies/{}/revisions - limit={}",
+            id, query.limit
+        ),
+    );
+
+    match db::get_strategy_revisions(&id, query.limit.clamp(1, 500)) {
+        Ok(revisions) => {
+            let items: Vec<RevisionSummary> = revisions
+                .into_iter()
+                .map(|r| RevisionSummary {
+                    version: r.version,
+                    name: r.name,
+                    enabled: r.enabled,
+                    author: r.author,
+                    created_at: r.created_at.to_rfc3339(),
+                    note: r.note,
+                })
+                .collect();
+            success_response(serde_json::json!({
+                "total": items.len(),
+                "items": items,
+                "timestamp": Utc::now().to_rfc3339(),
+            }))
+        }
+        Err(e) => err(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("Failed to get revisions: {}", e),
+        ),
+    }
+}
+
+/// GET /api/strategies/:id/revisions/:version - Get a single revision
+async fn get_strategy_revision_detail(Path((id, version)): Path<(String, i32)>) -> Response {
+    logger::info(
+        LogTag::Webserver,
+        &format!("GET /api/strategies/{}/revisions/{}", id, version),
+    );
+
+    match db::get_strategy_revision(&id, version) {
+        Ok(Some(revision)) => success_response(revision),
+        Ok(None) => err(StatusCode::NOT_FOUND, "Revision not found"),
+        Err(e) => err(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("Failed to get revision: {}", e),
+        ),
+    }
+}
+
+/// GET /api/strategies/:id/revisions/diff?from=&to= - Compare two revisions
+async fn diff_strategy_revisions(
+    Path(id): Path<String>,
+    Query(query): Query<RevisionDiffQuery>,
+) -> Response {
+    logger::info(
+        LogTag::Webserver,
+        &format!(
+            "GET /api/strategies/{}/revisions/diff - from={} to={:?}",
+            id, query.from, query.to
+        ),
+    );
+
+    let to_version = match query.to {
+        Some(version) => version,
+        None => match get_strategy(&id) {
+            Ok(Some(strategy)) => strategy.version,
+            Ok(None) => return err(StatusCode::NOT_FOUND, "Strategy not found"),
+            Err(e) => {
+                return err(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    &format!("Failed to get strategy: {}", e),
+                )
+            }
+        },
+    };
+
+    let load = |version: i32| match db::get_strategy_revision(&id, version) {
+        Ok(Some(revision)) => Ok(revision),
+        Ok(None) => Err(err(
+            StatusCode::NOT_FOUND,
+            &format!("Revision {} not found", version),
+        )),
+        Err(e) => Err(err(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("Failed to get revision: {}", e),
+        )),
+    };
+
+    let from = match load(query.from) {
+        Ok(revision) => revision,
+        Err(response) => return response,
+    };
+    let to = match load(to_version) {
+        Ok(revision) => revision,
+        Err(response) => return response,
+    };
+
+    success_response(strategies::versions::diff_revisions(&from, &to))
+}
+
+/// POST /api/strategies/:id/rollback - Restore a previous revision as a new version
+async fn rollback_strategy_handler(
+    Path(id): Path<String>,
+    Json(request): Json<RollbackRequest>,
+) -> Response {
+    logger::info(
+        LogTag::Webserver,
+        &format!(
+            "POST /api/strategies/{}/rollback - version={}",
+            id, request.version
+        ),
+    );
+
+    let current = match get_strategy(&id) {
+        Ok(Some(s)) => s,
+        Ok(None) => return err(StatusCode::NOT_FOUND, "Strategy not found"),
+        Err(e) => {
+            return err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("Failed to get strategy: {}", e),
+            );
+        }
+    };
+
+    let target = match db::get_strategy_revision(&id, request.version) {
+        Ok(Some(revision)) => revision,
+        Ok(None) => return err(StatusCode::NOT_FOUND, "Revision not found"),
+        Err(e) => {
+            return err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("Failed to get revision: {}", e),
+            );
+        }
+    };
+
+    let strategy = strategies::versions::rollback_strategy(&current, &target);
+
+    // Condition types may have changed since the revision was saved
+    if let Err(e) = strategies::validate_strategy(&strategy).await {
+        return err(
+            StatusCode::BAD_REQUEST,
+            &format!("Revision {} no longer validates: {}", request.version, e),
+        );
+    }
+
+    let note = format!("Rollback to v{}", request.version);
+    if let Err(e) = db::update_strategy_with_note(&strategy, Some(&note)) {
+        return err(
+            StatusCode::CONFLICT,
+            &format!("Failed to roll back strategy: {}", e),
+        );
+    }
+
+    if let Err(e) = strategies::clear_evaluation_cache().await {
+        logger::info(
+            LogTag::Webserver,
+            &format!("Failed to clear evaluation cache: {}", e),
+        );
+    }
+
+    logger::info(
+        LogTag::Webserver,
+        &format!(
+            "Strategy rolled back: id={}, to_version={}, new_version={}",
+            strategy.id, request.version, strategy.version
+        ),
+    );
+
+    success_response(serde_json::json!({
+        "id": strategy.id,
+        "version": strategy.version,
+        "restored_version": request.version,
+        "message": "Strategy rolled back successfully"
+    }))
+}
+
+/// GET /api/strategies/positions/:position_id - Strategy revisions linked to a position
+async fn get_position_strategy_revisions(Path(position_id): Path<String>) -> Response {
+    logger::info(
+        LogTag::Webserver,
+        &format!("GET /api/strategies/positions/{}", position_id),
+    );
+
+    match db::get_position_strategy_assignments(&position_id) {
+        Ok(assignments) => success_response(serde_json::json!({
+            "position_id": position_id,
+            "items": assignments,
+        })),
+        Err(e) => err(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("Failed to get position strategies: {}", e),
+        ),
+    }
+}
+
+/// POST /api/strategies/export - Export strategies and templates as a bundle
+async fn export_strategy_bundle(Json(request): Json<BundleExportRequest>) -> Response {
+    logger::info(
+        LogTag::Webserver,
+        &format!(
+            "POST /api/strategies/export - strategies={:?}, templates={:?}",
+            request.strategy_ids, request.template_ids
+        ),
+    );
+
+    match bundle::export_bundle(
+        request.strategy_ids.as_deref(),
+        request.template_ids.as_deref(),
+    ) {
+        Ok(bundle) => success_response(bundle),
+        Err(e) if e.contains("not found") => err(StatusCode::NOT_FOUND, &e),
+        Err(e) => err(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &format!("Failed to export strategies: {}", e),
+        ),
+    }
+}
+
+/// POST /api/strategies/import - Validate and import a strategy bundle
+async fn import_strategy_bundle(Json(request): Json<BundleImportRequest>) -> Response {
+    let bundle: StrategyBundle = match serde_json::from_value(request.bundle) {
+        Ok(bundle) => bundle,
+        Err(e) => {
+            return err(
+                StatusCode::BAD_REQUEST,
+                &format!("Invalid bundle JSON: {}", e),
+            );
+        }
+    };
+
+    logger::info(
+        LogTag::Webserver,
+        &format!(
+            "POST /api/strategies/import - strategies={}, templates={}, dry_run={}",
+            bundle.strategies.len(),
+            bundle.templates.len(),
+            request.options.dry_run
+        ),
+    );
+
+    if let Err(e) = bundle.verify() {
+        return err(StatusCode::BAD_REQUEST, &e);
+    }
+
+    let report = match bundle::import_bundle(&bundle, &request.options).await {
+        Ok(report) => report,
+        Err(e) => {
+            return err(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("Failed to import bundle: {}", e),
+            );
+        }
+    };
+
+    if !report.dry_run && report.imported > 0 {
+        if let Err(e) = strategies::clear_evaluation_cache().await {
+            logger::info(
+                LogTag::Webserver,
+                &format!("Failed to clear evaluation cache: {}", e),
+            );
+        }
+    }
+
+    success_response(report)
+}
+
 /// GET /api/strategies/conditions/schemas - Get all condition schemas
 async fn get_condition_schemas() -> Response {
     logger::info(LogTag::Webserver, "GET /api/strategies/conditions/schemas");
@@ -790,6 +1235,14 @@ pub fn routes() -> Router<Arc<AppState>> {
         .route("/conditions/schemas", get(get_condition_schemas))
         // Templates
         .route("/templates", get(list_templates))
+        // Bundle export/import
+        .route("/export", post(export_strategy_bundle))
+        .route("/import", post(import_strategy_bundle))
+        // Strategy revisions linked to a position
+        .route(
+            "/positions/:position_id",
+            get(get_position_strategy_revisions),
+        )
         // Routes with path parameters (must come after static routes)
         .route(
             "/:id",
@@ -800,6 +1253,15 @@ pub fn routes() -> Router<Arc<AppState>> {
         // Performance and testing
         .route("/:id/performance", get(get_strategy_performance_stats))
         .route("/:id/test", post(test_strategy))
+        .route(
+            "/:id/backtest",
+            get(list_strategy_backtests).post(run_strategy_backtest),
+        )
+        // Revision history
+        .route("/:id/revisions", get(list_strategy_revisions))
+        .route("/:id/revisions/diff", get(diff_strategy_revisions))
+        .route("/:id/revisions/:version", get(get_strategy_revision_detail))
+        .route("/:id/rollback", post(rollback_strategy_handler))
         // Validate / Deploy (by ID)
         .route("/:id/validate", post(validate_strategy_handler))
         .route("/:id/deploy", post(deploy_strategy_handler))
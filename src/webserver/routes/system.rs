@@ -517,6 +517,7 @@ async fn get_data_stats() -> Response {
         ("Pools", paths::get_pools_db_path()),
         ("Strategies", paths::get_strategies_db_path()),
         ("Actions", paths::get_actions_db_path()),
+        ("Orders", paths::get_orders_db_path()),
     ];
 
     for (name, path) in db_configs {
@@ -79,11 +79,13 @@ pub struct NotificationSettings {
     pub position_closed: bool,
     pub partial_exit: bool,
     pub dca_executed: bool,
+    pub order_updates: bool,
     pub errors: bool,
     pub startup_shutdown: bool,
     pub filtering_alerts: bool,
     pub trade_alerts: bool,
     pub daily_summary: bool,
+    pub weekly_summary: bool,
 }
 
 #[derive(Deserialize)]
@@ -104,11 +106,13 @@ pub struct UpdateNotificationSettings {
     pub position_closed: Option<bool>,
     pub partial_exit: Option<bool>,
     pub dca_executed: Option<bool>,
+    pub order_updates: Option<bool>,
     pub errors: Option<bool>,
     pub startup_shutdown: Option<bool>,
     pub filtering_alerts: Option<bool>,
     pub trade_alerts: Option<bool>,
     pub daily_summary: Option<bool>,
+    pub weekly_summary: Option<bool>,
 }
 
 // === ROUTES ===
@@ -215,11 +219,13 @@ async fn get_settings(State(_state): State<Arc<AppState>>) -> Response {
             position_closed: config.notify_position_closed,
             partial_exit: config.notify_partial_exit,
             dca_executed: config.notify_dca_executed,
+            order_updates: config.notify_order_updates,
             errors: config.notify_system_errors,
             startup_shutdown: config.notify_on_startup,
             filtering_alerts: config.notify_filtering_alerts,
             trade_alerts: config.notify_trade_alerts,
             daily_summary: config.notify_daily_summary,
+            weekly_summary: config.notify_weekly_summary,
         },
         commands_enabled: config.commands_enabled,
         inline_actions: config.inline_actions_enabled,
@@ -267,6 +273,9 @@ async fn update_settings(
                 if let Some(v) = notif.dca_executed {
                     cfg.telegram.notify_dca_executed = v;
                 }
+                if let Some(v) = notif.order_updates {
+                    cfg.telegram.notify_order_updates = v;
+                }
                 if let Some(v) = notif.errors {
                     cfg.telegram.notify_system_errors = v;
                 }
@@ -283,6 +292,9 @@ async fn update_settings(
                 if let Some(v) = notif.daily_summary {
                     cfg.telegram.notify_daily_summary = v;
                 }
+                if let Some(v) = notif.weekly_summary {
+                    cfg.telegram.notify_weekly_summary = v;
+                }
             }
             if let Some(commands) = req.commands_enabled {
                 cfg.telegram.commands_enabled = commands;
@@ -115,6 +115,11 @@ pub struct TokenPoolInfo {
     pub reserve_accounts: Vec<String>,
     pub is_canonical: bool,
     pub last_updated_unix: Option<i64>,
+    /// Per-pool price when multi-pool pricing is enabled
+    pub price_sol: Option<f64>,
+    pub price_weight_pct: Option<f64>,
+    pub price_deviation_pct: Option<f64>,
+    pub price_outlier: bool,
 }
 
 /// Top holder info for security display
@@ -227,6 +232,7 @@ pub struct TokenDetailResponse {
 
     // Pools
     pub pools: Vec<TokenPoolInfo>,
+    pub pool_price_breakdown: Option<pools::MultiPoolPrice>,
 
     // Metadata
     pub timestamp: String,
@@ -1911,6 +1917,7 @@ async fn get_token_detail(Path(mint): Path<String>) -> Json<TokenDetailResponse>
                 websites: vec![],
                 socials: vec![],
                 pools: vec![],
+                pool_price_breakdown: None,
                 has_ohlcv: false,
                 has_pool_price: false,
                 has_open_position: false,
@@ -1930,6 +1937,7 @@ async fn get_token_detail(Path(mint): Path<String>) -> Json<TokenDetailResponse>
         Ok(duration) => Some(duration.as_secs() as i64),
         Err(_) => None,
     };
+    let pool_price_breakdown = pools::get_pool_price_breakdown(&mint);
     let pool_infos: Vec<TokenPoolInfo> = pool_descriptors
         .into_iter()
         .map(|pool| {
@@ -1960,6 +1968,14 @@ async fn get_token_detail(Path(mint): Path<String>) -> Json<TokenDetailResponse>
 
             let last_updated_unix = now_unix_opt.map(|now| now.saturating_sub(age_i64));
 
+            let pool_id_str = pool.pool_id.to_string();
+            let price_point = pool_price_breakdown.as_ref().and_then(|breakdown| {
+                breakdown
+                    .pools
+                    .iter()
+                    .find(|p| p.pool_address == pool_id_str)
+            });
+
             TokenPoolInfo {
                 pool_id: pool.pool_id.to_string(),
                 program: pool.program_kind.display_name().to_string(),
@@ -1986,6 +2002,10 @@ async fn get_token_detail(Path(mint): Path<String>) -> Json<TokenDetailResponse>
                     .map(|canonical| canonical == pool.pool_id)
                     .unwrap_or(false),
                 last_updated_unix,
+                price_sol: price_point.map(|p| p.price_sol),
+                price_weight_pct: price_point.map(|p| p.weight_pct),
+                price_deviation_pct: price_point.map(|p| p.deviation_pct),
+                price_outlier: price_point.map(|p| p.is_outlier).unwrap_or(false),
             }
         })
         .collect();
@@ -2411,6 +2431,7 @@ async fn get_token_detail(Path(mint): Path<String>) -> Json<TokenDetailResponse>
         websites,
         socials,
         pools: pool_infos,
+        pool_price_breakdown,
         has_ohlcv,
         has_pool_price,
         has_open_position,
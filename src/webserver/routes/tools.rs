@@ -820,6 +820,37 @@ pub fn routes() -> Router<Arc<AppState>> {
             "/trade-watcher/status",
             get(get_trade_watcher_status_handler),
         )
+        // Holder watch
+        .route("/holder-watch/status", get(get_holder_watch_status_handler))
+        .route("/holder-watch/tokens", get(get_holder_watch_tokens_handler))
+        .route("/holder-watch/tokens", post(add_holder_watch_token_handler))
+        .route(
+            "/holder-watch/tokens/:id",
+            delete(delete_holder_watch_token_handler),
+        )
+        .route("/holder-watch/events", get(get_holder_events_handler))
+        .route(
+            "/holder-watch/snapshots/:mint",
+            get(get_holder_snapshots_handler),
+        )
+        .route(
+            "/holder-watch/check",
+            post(trigger_holder_watch_check_handler),
+        )
+        // Copy trader
+        .route("/copy-trader/status", get(get_copy_trader_status_handler))
+        .route("/copy-trader/leaders", get(get_copy_leaders_handler))
+        .route("/copy-trader/leaders", post(add_copy_leader_handler))
+        .route(
+            "/copy-trader/leaders/:id",
+            delete(delete_copy_leader_handler),
+        )
+        .route(
+            "/copy-trader/leaders/:id/status",
+            post(update_copy_leader_status_handler),
+        )
+        .route("/copy-trader/trades", get(get_copy_trades_handler))
+        .route("/copy-trader/pnl", get(get_copy_leader_pnl_handler))
         // Merge multi-wallet routes
         .merge(multi_wallet_routes())
 }
@@ -2448,6 +2479,411 @@ async fn get_trade_watcher_status_handler() -> Response {
     }))
 }
 
+// =============================================================================
+// Holder Watch Handlers
+// =============================================================================
+
+/// Request to add a token to Holder Watch
+#[derive(Debug, Deserialize)]
+struct AddHolderWatchTokenRequest {
+    mint: String,
+    symbol: Option<String>,
+}
+
+/// Query for holder watch history (events and snapshots)
+#[derive(Debug, Deserialize)]
+struct HolderHistoryQuery {
+    mint: Option<String>,
+    #[serde(default = "default_holder_history_limit")]
+    limit: i64,
+}
+
+fn default_holder_history_limit() -> i64 {
+    100
+}
+
+/// Get holder watch status
+async fn get_holder_watch_status_handler() -> Response {
+    use crate::tools::holder_watch::get_holder_watch_status;
+
+    success_response(serde_json::json!(get_holder_watch_status().await))
+}
+
+/// Get all holder watch tokens
+async fn get_holder_watch_tokens_handler() -> Response {
+    use crate::tools::holder_watch::get_holder_watch_tokens;
+
+    match get_holder_watch_tokens(false) {
+        Ok(tokens) => success_response(serde_json::json!({ "tokens": tokens })),
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Add a token to holder watch
+async fn add_holder_watch_token_handler(Json(req): Json<AddHolderWatchTokenRequest>) -> Response {
+    use crate::tools::holder_watch::{
+        add_holder_watch_token, get_holder_watch_tokens, trigger_holder_watch_check,
+    };
+
+    let mint = req.mint.trim().to_string();
+    if Pubkey::from_str(&mint).is_err() {
+        return error_response(
+            axum::http::StatusCode::BAD_REQUEST,
+            "INVALID_MINT",
+            "Invalid token mint address",
+            None,
+        );
+    }
+
+    let max_tokens = crate::config::with_config(|cfg| cfg.holder_watch.max_watched_tokens);
+    let active: Vec<_> = match get_holder_watch_tokens(true) {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            return error_response(
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "DATABASE_ERROR",
+                &e,
+                None,
+            )
+        }
+    };
+    if !active.iter().any(|t| t.mint == mint) && active.len() as i32 >= max_tokens {
+        return error_response(
+            axum::http::StatusCode::BAD_REQUEST,
+            "LIMIT_REACHED",
+            &format!("Holder Watch is limited to {} tokens", max_tokens),
+            None,
+        );
+    }
+
+    let symbol = req
+        .symbol
+        .or_else(|| crate::tokens::get_cached_token(&mint).map(|t| t.symbol));
+
+    match add_holder_watch_token(&mint, symbol.as_deref()) {
+        Ok(id) => {
+            logger::info(
+                LogTag::Tools,
+                &format!("[HOLDER_WATCH] Watching holders of {} (id={})", mint, id),
+            );
+            trigger_holder_watch_check();
+            success_response(serde_json::json!({ "id": id, "mint": mint }))
+        }
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Remove a token (and its history) from holder watch
+async fn delete_holder_watch_token_handler(Path(id): Path<i64>) -> Response {
+    use crate::tools::holder_watch::delete_holder_watch_token;
+
+    match delete_holder_watch_token(id) {
+        Ok(()) => {
+            logger::info(
+                LogTag::Tools,
+                &format!("[HOLDER_WATCH] Removed holder watch token id={}", id),
+            );
+            success_response(serde_json::json!({ "deleted": id }))
+        }
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Get recent holder events
+async fn get_holder_events_handler(
+    axum::extract::Query(query): axum::extract::Query<HolderHistoryQuery>,
+) -> Response {
+    use crate::tools::holder_watch::get_holder_events;
+
+    match get_holder_events(query.mint.as_deref(), query.limit.clamp(1, 500)) {
+        Ok(events) => success_response(serde_json::json!({ "events": events })),
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Get holder snapshot history for a token (newest first)
+async fn get_holder_snapshots_handler(
+    Path(mint): Path<String>,
+    axum::extract::Query(query): axum::extract::Query<HolderHistoryQuery>,
+) -> Response {
+    use crate::tools::holder_watch::{get_holder_snapshots, HolderEntry};
+
+    match get_holder_snapshots(&mint, query.limit.clamp(1, 500)) {
+        Ok(rows) => {
+            let snapshots: Vec<serde_json::Value> = rows
+                .into_iter()
+                .map(|row| {
+                    let top_holders: Vec<HolderEntry> =
+                        serde_json::from_str(&row.top_holders_json).unwrap_or_default();
+                    serde_json::json!({
+                        "holder_count": row.holder_count,
+                        "supply_ui": row.supply_ui,
+                        "top10_pct": row.top10_pct,
+                        "top_holders": top_holders,
+                        "captured_at": row.captured_at,
+                    })
+                })
+                .collect();
+            success_response(serde_json::json!({ "mint": mint, "snapshots": snapshots }))
+        }
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Run a holder check cycle now
+async fn trigger_holder_watch_check_handler() -> Response {
+    crate::tools::holder_watch::trigger_holder_watch_check();
+    success_response(serde_json::json!({ "message": "Holder check requested" }))
+}
+
+// =============================================================================
+// Copy Trader Handlers
+// =============================================================================
+
+/// Request to follow a wallet with the Copy Trader
+#[derive(Debug, Deserialize)]
+struct AddCopyLeaderRequest {
+    address: String,
+    label: Option<String>,
+    /// Per-leader override: "fixed" or "proportional"
+    sizing_mode: Option<String>,
+    /// SOL (fixed) or percent of the leader's amount (proportional)
+    size_value: Option<f64>,
+}
+
+/// Request to pause or resume a followed wallet
+#[derive(Debug, Deserialize)]
+struct UpdateCopyLeaderStatusRequest {
+    is_active: bool,
+}
+
+/// Query for copy trade history
+#[derive(Debug, Deserialize)]
+struct CopyTradesQuery {
+    leader: Option<String>,
+    #[serde(default = "default_copy_trades_limit")]
+    limit: i64,
+}
+
+fn default_copy_trades_limit() -> i64 {
+    100
+}
+
+/// Get copy trader status
+async fn get_copy_trader_status_handler() -> Response {
+    use crate::tools::copy_trader::get_copy_trader_status;
+
+    success_response(serde_json::json!(get_copy_trader_status().await))
+}
+
+/// Get all followed wallets
+async fn get_copy_leaders_handler() -> Response {
+    use crate::tools::copy_trader::get_copy_leaders;
+
+    match get_copy_leaders(false) {
+        Ok(leaders) => success_response(serde_json::json!({ "leaders": leaders })),
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Follow a wallet
+async fn add_copy_leader_handler(Json(req): Json<AddCopyLeaderRequest>) -> Response {
+    use crate::tools::copy_trader::{
+        add_copy_leader, get_copy_leaders, trigger_copy_trader_resync, SizingMode,
+    };
+
+    let address = req.address.trim().to_string();
+    if Pubkey::from_str(&address).is_err() {
+        return error_response(
+            axum::http::StatusCode::BAD_REQUEST,
+            "INVALID_ADDRESS",
+            "Invalid wallet address",
+            None,
+        );
+    }
+    if get_wallet_address().ok().as_deref() == Some(address.as_str()) {
+        return error_response(
+            axum::http::StatusCode::BAD_REQUEST,
+            "INVALID_ADDRESS",
+            "Cannot copy trade the bot's own wallet",
+            None,
+        );
+    }
+    if let Some(mode) = req.sizing_mode.as_deref() {
+        if SizingMode::parse(mode).is_none() {
+            return error_response(
+                axum::http::StatusCode::BAD_REQUEST,
+                "INVALID_SIZING",
+                "sizing_mode must be 'fixed' or 'proportional'",
+                None,
+            );
+        }
+    }
+    if let Some(value) = req.size_value {
+        if !value.is_finite() || value <= 0.0 {
+            return error_response(
+                axum::http::StatusCode::BAD_REQUEST,
+                "INVALID_SIZING",
+                "size_value must be positive",
+                None,
+            );
+        }
+    }
+
+    let max_leaders = crate::config::with_config(|cfg| cfg.copy_trade.max_leaders);
+    let active = match get_copy_leaders(true) {
+        Ok(leaders) => leaders,
+        Err(e) => {
+            return error_response(
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "DATABASE_ERROR",
+                &e,
+                None,
+            )
+        }
+    };
+    if !active.iter().any(|l| l.address == address) && active.len() as i32 >= max_leaders {
+        return error_response(
+            axum::http::StatusCode::BAD_REQUEST,
+            "LIMIT_REACHED",
+            &format!("Copy Trader is limited to {} leaders", max_leaders),
+            None,
+        );
+    }
+
+    let label = req
+        .label
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty());
+
+    match add_copy_leader(
+        &address,
+        label.as_deref(),
+        req.sizing_mode.as_deref(),
+        req.size_value,
+    ) {
+        Ok(id) => {
+            logger::info(
+                LogTag::Tools,
+                &format!("[COPY_TRADER] Following {} (id={})", address, id),
+            );
+            trigger_copy_trader_resync();
+            success_response(serde_json::json!({ "id": id, "address": address }))
+        }
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Pause or resume a followed wallet
+async fn update_copy_leader_status_handler(
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateCopyLeaderStatusRequest>,
+) -> Response {
+    use crate::tools::copy_trader::{trigger_copy_trader_resync, update_copy_leader_status};
+
+    match update_copy_leader_status(id, req.is_active) {
+        Ok(()) => {
+            trigger_copy_trader_resync();
+            success_response(serde_json::json!({ "id": id, "is_active": req.is_active }))
+        }
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Stop following a wallet (copy trade history is kept)
+async fn delete_copy_leader_handler(Path(id): Path<i64>) -> Response {
+    use crate::tools::copy_trader::{delete_copy_leader, trigger_copy_trader_resync};
+
+    match delete_copy_leader(id) {
+        Ok(()) => {
+            logger::info(
+                LogTag::Tools,
+                &format!("[COPY_TRADER] Removed copy leader id={}", id),
+            );
+            trigger_copy_trader_resync();
+            success_response(serde_json::json!({ "deleted": id }))
+        }
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Get recent copy trades
+async fn get_copy_trades_handler(
+    axum::extract::Query(query): axum::extract::Query<CopyTradesQuery>,
+) -> Response {
+    use crate::tools::copy_trader::get_copy_trades;
+
+    match get_copy_trades(query.leader.as_deref(), query.limit.clamp(1, 500)) {
+        Ok(trades) => success_response(serde_json::json!({ "trades": trades })),
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
+/// Get PnL attributed to each leader
+async fn get_copy_leader_pnl_handler() -> Response {
+    use crate::tools::copy_trader::get_leader_pnl;
+
+    match get_leader_pnl().await {
+        Ok(leaders) => success_response(serde_json::json!({ "leaders": leaders })),
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "DATABASE_ERROR",
+            &e,
+            None,
+        ),
+    }
+}
+
 // =============================================================================
 // Burn Tokens Handlers
 // =============================================================================
@@ -1,5 +1,5 @@
 use axum::{
-    extract::State,
+    extract::{Path, Query, State},
     http::StatusCode,
     response::Response,
     routing::{get, post},
@@ -162,6 +162,23 @@ pub struct TrailingStopPreviewQuery {
     pub distance_pct: Option<f64>,
 }
 
+// =============================================================================
+// PENDING ORDER TYPES
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct OrdersQuery {
+    /// Status filter (pending, filled, ...). Omit for all orders.
+    pub status: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrdersResponse {
+    pub orders: Vec<trader::orders::Order>,
+    pub count: usize,
+}
+
 // =============================================================================
 // QUOTE PREVIEW TYPES
 // =============================================================================
@@ -809,6 +826,12 @@ pub fn routes() -> Router<Arc<AppState>> {
         .route("/loss-limit/status", get(loss_limit_status_handler))
         .route("/loss-limit/resume", post(loss_limit_resume_handler))
         .route("/loss-limit/reset", post(loss_limit_reset_handler))
+        // Pending order endpoints
+        .route(
+            "/orders",
+            get(list_orders_handler).post(create_order_handler),
+        )
+        .route("/orders/:id/cancel", post(cancel_order_handler))
 }
 
 // =============================================================================
@@ -1428,6 +1451,7 @@ async fn quote_preview_handler(
         wallet_address,
         slippage_pct: with_config(|cfg| cfg.swaps.slippage.quote_default_pct),
         swap_mode: SwapMode::ExactIn,
+        priority: crate::trader::TradePriority::High,
     };
 
     // Fetch quote
@@ -1515,3 +1539,54 @@ async fn quote_preview_handler(
         ),
     }
 }
+
+// =============================================================================
+// PENDING ORDER HANDLERS
+// =============================================================================
+
+async fn list_orders_handler(Query(query): Query<OrdersQuery>) -> Response {
+    let status = match query.status.as_deref() {
+        Some(status) => match status.parse::<trader::orders::OrderStatus>() {
+            Ok(status) => Some(status),
+            Err(e) => return error_response(StatusCode::BAD_REQUEST, "InvalidStatus", &e, None),
+        },
+        None => None,
+    };
+    let limit = query.limit.unwrap_or(200).min(1000);
+
+    match trader::orders::get_orders(status, limit) {
+        Ok(orders) => success_response(OrdersResponse {
+            count: orders.len(),
+            orders,
+        }),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "OrdersQueryFailed",
+            &e,
+            None,
+        ),
+    }
+}
+
+async fn create_order_handler(Json(req): Json<trader::orders::NewOrder>) -> Response {
+    if Pubkey::from_str(req.mint.trim()).is_err() {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "InvalidMint",
+            "Invalid token mint address",
+            Some("Mint must be a valid base58 pubkey"),
+        );
+    }
+
+    match trader::orders::create_order(req).await {
+        Ok(order) => success_response(order),
+        Err(e) => error_response(StatusCode::BAD_REQUEST, "InvalidOrder", &e, None),
+    }
+}
+
+async fn cancel_order_handler(Path(id): Path<i64>) -> Response {
+    match trader::orders::cancel_order(id) {
+        Ok(order) => success_response(order),
+        Err(e) => error_response(StatusCode::BAD_REQUEST, "CancelFailed", &e, None),
+    }
+}
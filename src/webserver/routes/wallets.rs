@@ -24,8 +24,9 @@ use crate::wallets::{
         build_preview, detect_columns, parse_csv, parse_excel, BulkImportResult, ColumnMapping,
         ImportOptions, ImportPreview, ParsedWalletRow, WalletExportRow,
     },
-    CreateWalletRequest, ExportWalletResponse, ImportWalletRequest, UpdateWalletRequest, Wallet,
-    WalletsSummary,
+    ChangeMasterPasswordRequest, CreateWalletRequest, DisableMasterPasswordRequest,
+    EnableMasterPasswordRequest, ExportWalletBackupRequest, ExportWalletResponse,
+    ImportWalletBackupRequest, ImportWalletRequest, UpdateWalletRequest, Wallet, WalletsSummary,
 };
 use crate::webserver::state::AppState;
 use crate::webserver::utils::{error_response, success_response};
@@ -86,6 +87,11 @@ struct DeleteResponse {
     message: String,
 }
 
+#[derive(Serialize)]
+struct MessageResponse {
+    message: String,
+}
+
 /// Response for import preview
 #[derive(Serialize)]
 struct ImportPreviewResponse {
@@ -166,6 +172,12 @@ pub fn routes() -> Router<Arc<AppState>> {
         .route("/export/full", post(export_wallets_full))
         .route("/summary", get(get_summary))
         .route("/main", get(get_main_wallet))
+        .route("/keystore", get(get_keystore_status))
+        .route("/keystore/enable", post(enable_master_password))
+        .route("/keystore/change", post(change_master_password))
+        .route("/keystore/disable", post(disable_master_password))
+        .route("/backup/export", post(export_wallet_backup))
+        .route("/backup/import", post(import_wallet_backup))
         .route("/:id", get(get_wallet))
         .route("/:id", put(update_wallet))
         .route("/:id", delete(delete_wallet))
@@ -484,6 +496,138 @@ async fn restore_wallet(Path(id): Path<i64>) -> Response {
     }
 }
 
+// =============================================================================
+// MASTER PASSWORD HANDLERS
+// =============================================================================
+
+/// Get wallet key protection status
+async fn get_keystore_status() -> Response {
+    match wallets::get_keystore_status().await {
+        Ok(status) => success_response(status),
+        Err(e) => error_response(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            "KEYSTORE_ERROR",
+            "Failed to get key protection status",
+            Some(&e),
+        ),
+    }
+}
+
+/// Protect all wallets with a master password
+///
+/// POST /api/wallets/keystore/enable
+async fn enable_master_password(Json(request): Json<EnableMasterPasswordRequest>) -> Response {
+    match wallets::enable_master_password(&request.password).await {
+        Ok(()) => success_response(MessageResponse {
+            message: "Master password enabled - it will be required after every restart"
+                .to_string(),
+        }),
+        Err(e) => keystore_error("Failed to enable master password", e),
+    }
+}
+
+/// Change the master password (re-encrypts all wallets)
+///
+/// POST /api/wallets/keystore/change
+async fn change_master_password(Json(request): Json<ChangeMasterPasswordRequest>) -> Response {
+    match wallets::change_master_password(&request.current_password, &request.new_password).await {
+        Ok(()) => success_response(MessageResponse {
+            message: "Master password changed".to_string(),
+        }),
+        Err(e) => keystore_error("Failed to change master password", e),
+    }
+}
+
+/// Remove the master password (back to machine-bound encryption)
+///
+/// POST /api/wallets/keystore/disable
+async fn disable_master_password(Json(request): Json<DisableMasterPasswordRequest>) -> Response {
+    match wallets::disable_master_password(&request.current_password).await {
+        Ok(()) => success_response(MessageResponse {
+            message: "Master password removed".to_string(),
+        }),
+        Err(e) => keystore_error("Failed to remove master password", e),
+    }
+}
+
+/// Map keystore errors: wrong or invalid passwords are client errors
+fn keystore_error(message: &str, error: String) -> Response {
+    logger::error(LogTag::Wallet, &format!("{}: {}", message, error));
+
+    let status = if error.contains("Incorrect") || error.contains("Password must") {
+        axum::http::StatusCode::BAD_REQUEST
+    } else {
+        axum::http::StatusCode::INTERNAL_SERVER_ERROR
+    };
+    error_response(status, "KEYSTORE_ERROR", message, Some(&error))
+}
+
+// =============================================================================
+// ENCRYPTED BACKUP HANDLERS
+// =============================================================================
+
+/// Download an encrypted wallet backup file
+///
+/// POST /api/wallets/backup/export
+async fn export_wallet_backup(Json(request): Json<ExportWalletBackupRequest>) -> Response {
+    let backup =
+        match wallets::export_wallet_backup(&request.password, request.include_inactive).await {
+            Ok(backup) => backup,
+            Err(e) => return keystore_error("Failed to export wallet backup", e),
+        };
+
+    let body = match serde_json::to_string_pretty(&backup) {
+        Ok(body) => body,
+        Err(e) => {
+            return error_response(
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "EXPORT_ERROR",
+                "Failed to serialize wallet backup",
+                Some(&e.to_string()),
+            )
+        }
+    };
+
+    let filename = format!(
+        "screenerbot_wallets_{}.json",
+        backup.created_at.format("%Y%m%d_%H%M%S")
+    );
+
+    (
+        axum::http::StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// Import wallets from an encrypted backup file
+///
+/// POST /api/wallets/backup/import
+async fn import_wallet_backup(Json(request): Json<ImportWalletBackupRequest>) -> Response {
+    match wallets::import_wallet_backup(&request.backup, &request.password).await {
+        Ok(result) => success_response(result),
+        Err(e) => {
+            logger::error(
+                LogTag::Wallet,
+                &format!("Failed to import wallet backup: {}", e),
+            );
+            error_response(
+                axum::http::StatusCode::BAD_REQUEST,
+                "IMPORT_ERROR",
+                "Failed to import wallet backup",
+                Some(&e),
+            )
+        }
+    }
+}
+
 // =============================================================================
 // BULK IMPORT HANDLERS
 // =============================================================================
@@ -93,7 +93,7 @@ pub async fn start_server(
     let is_gui = global::is_gui_mode();
 
     // Get config values for headless mode (use defaults if config not loaded yet)
-    let (config_port, config_host) = if crate::global::is_initialization_complete() {
+    let (config_port, config_host) = if crate::config::is_config_initialized() {
         with_config(|cfg| (cfg.webserver.port, cfg.webserver.host.clone()))
     } else {
         // Use defaults during initialization (will fall back to defaults below anyway)
@@ -331,13 +331,13 @@ pub async fn test_port_binding(
     );
 
     // Get config values (use defaults if config not loaded yet)
-    let init_complete = crate::global::is_initialization_complete();
+    let config_loaded = crate::config::is_config_initialized();
     logger::debug(
         LogTag::Webserver,
-        &format!("[TEST-BIND] Initialization complete: {}", init_complete),
+        &format!("[TEST-BIND] Config loaded: {}", config_loaded),
     );
 
-    let (config_port, config_host) = if init_complete {
+    let (config_port, config_host) = if config_loaded {
         with_config(|cfg| (cfg.webserver.port, cfg.webserver.host.clone()))
     } else {
         (0, String::new())
@@ -221,7 +221,9 @@ pub fn base_template(title: &str, active_tab: &str, content: &str) -> String {
     html = html.replace("{{ASSET_VERSION}}", asset_version.as_str());
 
     // Inject initialization state for early DOM setup (prevents dashboard flash)
-    let needs_initialization = !global::is_initialization_complete();
+    // A startup waiting for the wallet master password shows the lockscreen instead
+    let needs_initialization =
+        !global::is_initialization_complete() && !crate::wallets::is_locked();
     html = html.replace(
         "{{NEEDS_INITIALIZATION}}",
         if needs_initialization {
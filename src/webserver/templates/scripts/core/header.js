@@ -288,10 +288,12 @@ function updateBotCard(trader) {
   if (!card || !status || !pnl) return;
 
   // Update status
-  const statusText = trader.running ? "RUNNING" : "STOPPED";
+  const runState = trader.running ? "RUNNING" : "STOPPED";
+  const statusText = trader.paper_trading ? `PAPER · ${runState}` : runState;
   const statusAttr = trader.running ? "running" : "stopped";
 
   card.setAttribute("data-status", statusAttr);
+  card.setAttribute("data-paper", trader.paper_trading ? "true" : "false");
   status.textContent = statusText;
 
   // Update P&L
@@ -358,6 +358,19 @@ Higher risk scores indicate more potential red flags.`,
       learnMoreUrl: "https://screenerbot.io/docs/dashboard/filtering",
     },
 
+    onchain: {
+      id: "filtering.onchain",
+      title: "On-Chain Filters",
+      content: `Security analysis read directly from the blockchain:
+
+• **Authorities** — mint/freeze authority on the mint account
+• **Token-2022** — transfer fee, transfer hook, permanent delegate, frozen by default
+• **LP Burn/Lock** — share of the main pool's LP burned or locked
+• **Top Holders** — concentration excluding pool vaults
+
+Works without Rugcheck and can stand in for it when Rugcheck data is missing.`,
+    },
+
     meta: {
       id: "filtering.meta",
       title: "Meta Filters",
@@ -1014,7 +1027,7 @@ Always verify security before trading significant amounts.`,
 • **Volume** — recent trading activity
 • **Price** — current pool price
 
-The Pool Service calculates prices from the highest-liquidity SOL pair.`,
+The Pool Service calculates prices from the highest-liquidity SOL pair. With **Multi-Pool Pricing** enabled it decodes several SOL pools, weights them by SOL reserves and drops outliers — each pool then shows its weight and deviation.`,
     },
   },
 
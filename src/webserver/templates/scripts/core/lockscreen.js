@@ -11,6 +11,7 @@
  * - Lock on blur (window loses focus)
  * - Error animation on wrong password
  * - Keyboard support for PIN entry
+ * - Wallet master password entry at startup (with optional 2FA code)
  */
 class LockscreenController {
   constructor() {
@@ -22,6 +23,7 @@ class LockscreenController {
     this.passwordInput = null;
     this.errorEl = null;
     this.versionEl = null;
+    this.subtitleEl = null;
 
     // State
     this.isLocked = false;
@@ -31,6 +33,10 @@ class LockscreenController {
     this.enteredPin = "";
     this.isVerifying = false;
 
+    // Wallet master password unlock
+    this.walletKeysLocked = false;
+    this.pendingWalletPassword = null; // kept while waiting for the 2FA code
+
     // Inactivity tracking
     this.inactivityTimeout = null;
     this.inactivityMs = 0; // 0 = disabled
@@ -61,6 +67,7 @@ class LockscreenController {
     this.passwordInput = document.getElementById("lockscreenPasswordInput");
     this.errorEl = document.getElementById("lockscreenError");
     this.versionEl = document.getElementById("lockscreenVersion");
+    this.subtitleEl = this.lockscreenEl?.querySelector(".lockscreen-subtitle") || null;
 
     if (!this.lockscreenEl) {
       console.warn("[Lockscreen] Lockscreen element not found");
@@ -76,6 +83,11 @@ class LockscreenController {
     // Bind event handlers
     this._bindEvents();
 
+    // Wallet keys need the master password before the bot can start
+    if (this.walletKeysLocked) {
+      this._lockForWalletUnlock();
+    }
+
     // Start inactivity tracking if enabled
     if (this.isEnabled && this.inactivityMs > 0) {
       this._startInactivityTracking();
@@ -109,6 +121,7 @@ class LockscreenController {
       this.passwordType = status.password_type || "pin6";
       this.inactivityMs = (status.auto_lock_timeout_secs || 0) * 1000;
       this.lockOnBlur = status.lock_on_blur || false;
+      this.walletKeysLocked = status.wallet_keys_locked || false;
 
       // Set PIN length based on type
       if (this.passwordType === "pin4") {
@@ -301,7 +314,7 @@ class LockscreenController {
    */
   lock() {
     if (this.isLocked) return;
-    if (!this.isEnabled || !this.hasPassword) return;
+    if (!this.walletKeysLocked && (!this.isEnabled || !this.hasPassword)) return;
 
     this.isLocked = true;
     this.enteredPin = "";
@@ -326,6 +339,18 @@ class LockscreenController {
     console.log("[Lockscreen] Dashboard locked");
   }
 
+  /**
+   * Lock with the master password prompt while wallet keys are locked
+   */
+  _lockForWalletUnlock() {
+    // The master password is always text, regardless of the lockscreen PIN type
+    this.passwordType = "text";
+    this.pendingWalletPassword = null;
+    this._setSubtitle("Enter master password to unlock wallets");
+    this._setPasswordPlaceholder("Master password");
+    this.lock();
+  }
+
   /**
    * Unlock the dashboard
    */
@@ -361,6 +386,11 @@ class LockscreenController {
     if (this.isVerifying) return;
     if (!password) return;
 
+    if (this.walletKeysLocked) {
+      await this._unlockWallets(password);
+      return;
+    }
+
     this.isVerifying = true;
     this._hideError();
     this._setLoadingState(true);
@@ -392,6 +422,76 @@ class LockscreenController {
     }
   }
 
+  /**
+   * Unlock wallet keys with the master password (and 2FA code if enabled)
+   *
+   * The same input is reused for the 2FA code: the first submit holds the
+   * master password, the second one the code.
+   */
+  async _unlockWallets(input) {
+    const password = this.pendingWalletPassword || input;
+    const totpCode = this.pendingWalletPassword ? input.trim() : null;
+
+    this.isVerifying = true;
+    this._hideError();
+    this._setLoadingState(true);
+
+    try {
+      const response = await fetch("/api/lockscreen/unlock-wallets", {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({ password, totp_code: totpCode }),
+      });
+
+      const data = await response.json();
+
+      if (response.ok && data.unlocked) {
+        this.walletKeysLocked = false;
+        this.pendingWalletPassword = null;
+        this._setSubtitle("Wallets unlocked - starting services...");
+        // Services start in the background; reload once they are up
+        setTimeout(() => window.location.reload(), 1500);
+      } else if (response.ok && data.requires_totp) {
+        this.pendingWalletPassword = password;
+        this._resetInputs();
+        this._setSubtitle("Enter your 2FA code");
+        this._setPasswordPlaceholder("6-digit code");
+        this.passwordInput?.focus();
+      } else {
+        const message = data.error?.message || data.message || "Incorrect master password";
+        this._showError(message);
+        this._showInputError();
+        this._resetInputs();
+        // A wrong code keeps the password; a wrong password starts over
+        if (data.error?.code !== "INVALID_TOTP") {
+          this._lockForWalletUnlock();
+        }
+      }
+    } catch (error) {
+      console.error("[Lockscreen] Wallet unlock failed:", error);
+      this._showError("Wallet unlock failed");
+      this._showInputError();
+      this._resetInputs();
+    } finally {
+      this.isVerifying = false;
+      this._setLoadingState(false);
+    }
+  }
+
+  /**
+   * Set the subtitle under the brand name
+   */
+  _setSubtitle(text) {
+    if (this.subtitleEl) this.subtitleEl.textContent = text;
+  }
+
+  /**
+   * Set the text password placeholder
+   */
+  _setPasswordPlaceholder(text) {
+    if (this.passwordInput) this.passwordInput.placeholder = text;
+  }
+
   /**
    * Set loading state on submit button
    */
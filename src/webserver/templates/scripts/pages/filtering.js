@@ -50,6 +50,7 @@ const FILTER_TABS = [
   { id: "dexscreener", label: '<i class="icon-trending-up"></i> DexScreener' },
   { id: "geckoterminal", label: '<i class="icon-trending-up"></i> GeckoTerminal' },
   { id: "rugcheck", label: '<i class="icon-shield"></i> RugCheck' },
+  { id: "onchain", label: '<i class="icon-shield-check"></i> On-Chain' },
 ];
 
 const TABBAR_STATE_KEY = "filtering.tab";
@@ -762,6 +763,135 @@ const CONFIG_CATEGORIES = {
       },
     ],
   },
+  "On-Chain - Source": {
+    source: "onchain",
+    fields: [
+      {
+        key: "rugcheck_fallback",
+        label: "Rugcheck Fallback",
+        type: "boolean",
+        hint: "Accept tokens without Rugcheck data when on-chain analysis is available",
+        impact: "high",
+      },
+    ],
+  },
+  "On-Chain - Authorities": {
+    source: "onchain",
+    enableKey: "authority_checks_enabled",
+    fields: [
+      {
+        key: "allow_mint_authority",
+        label: "Allow Mint Authority",
+        type: "boolean",
+        hint: "Allow tokens with mint authority (false = reject if present)",
+        impact: "high",
+      },
+      {
+        key: "allow_freeze_authority",
+        label: "Allow Freeze Authority",
+        type: "boolean",
+        hint: "Allow tokens with freeze authority (false = reject if present)",
+        impact: "high",
+      },
+    ],
+  },
+  "On-Chain - Token-2022 Extensions": {
+    source: "onchain",
+    enableKey: "token2022_checks_enabled",
+    fields: [
+      {
+        key: "block_transfer_hook",
+        label: "Block Transfer Hook",
+        type: "boolean",
+        hint: "Reject tokens whose transfers call an external program",
+        impact: "high",
+      },
+      {
+        key: "block_permanent_delegate",
+        label: "Block Permanent Delegate",
+        type: "boolean",
+        hint: "Reject tokens where a delegate can move or burn any holder's balance",
+        impact: "critical",
+      },
+      {
+        key: "block_non_transferable",
+        label: "Block Non-Transferable",
+        type: "boolean",
+        hint: "Reject soulbound tokens that cannot be sold",
+        impact: "critical",
+      },
+      {
+        key: "block_default_frozen",
+        label: "Block Default Frozen",
+        type: "boolean",
+        hint: "Reject tokens whose new accounts start frozen",
+        impact: "high",
+      },
+      {
+        key: "max_transfer_fee_pct",
+        label: "Max Transfer Fee %",
+        type: "number",
+        unit: "%",
+        min: 0,
+        max: 100,
+        step: 0.5,
+        hint: "Maximum acceptable transfer fee (current or scheduled)",
+        impact: "critical",
+      },
+    ],
+  },
+  "On-Chain - LP Burn/Lock": {
+    source: "onchain",
+    enableKey: "lp_checks_enabled",
+    fields: [
+      {
+        key: "min_lp_secured_pct",
+        label: "Min LP Burned/Locked",
+        type: "number",
+        unit: "%",
+        min: 0,
+        max: 100,
+        step: 5,
+        hint: "Minimum share of the canonical pool's LP that is burned or locked",
+        impact: "high",
+      },
+      {
+        key: "require_lp_data",
+        label: "Require LP Data",
+        type: "boolean",
+        hint: "Reject when LP status is unknown (CLMM/DLMM pools have no LP token)",
+        impact: "medium",
+      },
+    ],
+  },
+  "On-Chain - Holder Distribution": {
+    source: "onchain",
+    enableKey: "holder_checks_enabled",
+    fields: [
+      {
+        key: "max_top_holder_pct",
+        label: "Max Top Holder %",
+        type: "number",
+        unit: "%",
+        min: 0,
+        max: 100,
+        step: 1,
+        hint: "Pool vaults and other program accounts are excluded",
+        impact: "critical",
+      },
+      {
+        key: "max_top_10_holders_pct",
+        label: "Max Top 10 Holders %",
+        type: "number",
+        unit: "%",
+        min: 0,
+        max: 100,
+        step: 1,
+        hint: "Combined share held by the 10 largest wallets",
+        impact: "high",
+      },
+    ],
+  },
 };
 
 // ============================================================================
@@ -1623,6 +1753,7 @@ function renderSourceToggle(source) {
     dexscreener: "DexScreener Enabled",
     geckoterminal: "GeckoTerminal Enabled",
     rugcheck: "RugCheck Enabled",
+    onchain: "On-Chain Enabled",
   };
   const sourceLabel = sourceLabelMap[source] || "Source Enabled";
   const toggleId = `source-toggle-${source}`;
@@ -1645,7 +1776,7 @@ function renderSourceToggle(source) {
 
 function renderSearchBar() {
   // Only show search bar on settings tabs (not status/analytics/explorer)
-  const isSettingsTab = ["meta", "dexscreener", "geckoterminal", "rugcheck"].includes(
+  const isSettingsTab = ["meta", "dexscreener", "geckoterminal", "rugcheck", "onchain"].includes(
     state.activeTab
   );
   if (!isSettingsTab) {
@@ -1655,7 +1786,8 @@ function renderSearchBar() {
   const showSourceToggle =
     state.activeTab === "dexscreener" ||
     state.activeTab === "geckoterminal" ||
-    state.activeTab === "rugcheck";
+    state.activeTab === "rugcheck" ||
+    state.activeTab === "onchain";
   const sourceToggle = showSourceToggle ? renderSourceToggle(state.activeTab) : "";
 
   return `
@@ -99,6 +99,9 @@ function createLifecycle() {
 
     // Update token statistics
     updateTokenStats(data.tokens);
+
+    // Update priority fee statistics
+    updatePriorityFeeStats(data.priority_fees);
   }
 
   // Update trading statistics
@@ -359,6 +362,33 @@ function createLifecycle() {
     if (ohlcvEl) animateValue(ohlcvEl, tokens.with_ohlcv);
   }
 
+  // Update priority fee statistics
+  function updatePriorityFeeStats(fees) {
+    if (!fees) return;
+
+    const p50El = document.getElementById("feesNetworkP50");
+    const p90El = document.getElementById("feesNetworkP90");
+    const avgFeeEl = document.getElementById("feesAvgFee");
+    const landedEl = document.getElementById("feesLandedRate");
+    const landingEl = document.getElementById("feesAvgLanding");
+    const swapsEl = document.getElementById("feesSwaps");
+
+    const microLamports = (value) =>
+      value === null || value === undefined ? "—" : `${Utils.formatNumber(value, 0)} µL/CU`;
+
+    if (p50El) p50El.textContent = fees.enabled ? microLamports(fees.network_p50) : "Off";
+    if (p90El) p90El.textContent = fees.enabled ? microLamports(fees.network_p90) : "Off";
+    if (avgFeeEl)
+      avgFeeEl.textContent = `${Utils.formatNumber(fees.avg_fee_lamports / 1e9, 6)} SOL`;
+    if (landedEl)
+      landedEl.textContent =
+        fees.swaps_recorded > 0 ? `${Utils.formatNumber(fees.landed_rate, 1)}%` : "—";
+    if (landingEl)
+      landingEl.textContent =
+        fees.avg_landing_ms > 0 ? `${Utils.formatNumber(fees.avg_landing_ms / 1000, 2)}s` : "—";
+    if (swapsEl) animateValue(swapsEl, fees.swaps_recorded);
+  }
+
   // Animate number value changes
   function animateValue(element, targetValue) {
     if (!element) return;
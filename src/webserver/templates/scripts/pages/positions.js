@@ -7,12 +7,32 @@ import { DataTable } from "../ui/data_table.js";
 import { TabBar, TabBarManager } from "../ui/tab_bar.js";
 import { TradeActionDialog } from "../ui/trade_action_dialog.js";
 import { PositionDetailsDialog } from "../ui/position_details_dialog.js";
+import { InputDialog } from "../ui/input_dialog.js";
 
 const SUB_TABS = [
   { id: "open", label: '<i class="icon-trending-up"></i> Open' },
   { id: "closed", label: '<i class="icon-trending-down"></i> Closed' },
+  { id: "orders", label: '<i class="icon-list"></i> Orders' },
 ];
 
+const VIEW_LABELS = { open: "Open", closed: "Closed", orders: "Orders" };
+
+const ORDER_TYPE_LABELS = {
+  buy_below: "Buy Below",
+  sell_above: "Sell Above",
+  stop_sell: "Stop",
+  oco: "TP / Stop (OCO)",
+};
+
+const ORDER_STATUS_CLASSES = {
+  pending: "",
+  triggered: "warning",
+  filled: "success",
+  failed: "danger",
+  cancelled: "",
+  expired: "",
+};
+
 const getPositionsTableStateKey = (view) => `positions-table.${view}`;
 const normalizeSortDirection = (direction) => (direction === "desc" ? "desc" : "asc");
 
@@ -29,7 +49,8 @@ const loadPersistedSort = (stateKey) => {
 };
 
 const getInitialSortForView = (view) => {
-  const fallbackColumn = view === "closed" ? "exit_time" : "entry_time";
+  const fallbackColumn =
+    view === "closed" ? "exit_time" : view === "orders" ? "created_at" : "entry_time";
   const persisted = loadPersistedSort(getPositionsTableStateKey(view));
   if (persisted?.column) {
     return { column: persisted.column, direction: persisted.direction || "desc" };
@@ -90,8 +111,29 @@ function createLifecycle() {
     return `<span class="chip ${cls}">${pct}%</span>`;
   };
 
+  const orderPriceCell = (_v, r) => {
+    if (r.order_type === "oco") {
+      return `TP ${priceCell(r.trigger_price_sol)}<br>SL ${priceCell(r.stop_price_sol)}`;
+    }
+    return priceCell(r.trigger_price_sol);
+  };
+
+  const orderSizeCell = (_v, r) => {
+    if (r.size_sol != null) return solCell(r.size_sol);
+    if (r.sell_percentage != null) return `${Utils.formatNumber(r.sell_percentage, 0)}%`;
+    return "—";
+  };
+
+  const orderStatusCell = (v, r) => {
+    const cls = ORDER_STATUS_CLASSES[v] ?? "";
+    const title = r.error ? ` title="${Utils.escapeHtml(r.error)}"` : "";
+    return `<span class="chip ${cls}"${title}>${Utils.escapeHtml(v || "?")}</span>`;
+  };
+
+  const isoTimeCell = (v) => (v ? timeCell(Math.floor(new Date(v).getTime() / 1000)) : "—");
+
   /**
-   * Build columns array based on current view (open/closed)
+   * Build columns array based on current view (open/closed/orders)
    * Different views show different columns (open has unrealized PnL, closed has exit data)
    */
   const buildColumns = () => {
@@ -125,6 +167,9 @@ function createLifecycle() {
                 <button class="btn row-action" data-action="sell" data-mint="${Utils.escapeHtml(
                   mint
                 )}" title="Sell (full or % partial)"><i class="icon-trending-down"></i> Sell</button>
+                <button class="btn row-action" data-action="order" data-mint="${Utils.escapeHtml(
+                  mint
+                )}" title="Place take-profit / stop order"><i class="icon-target"></i> TP/SL</button>
               </div>
             `;
           },
@@ -193,6 +238,75 @@ function createLifecycle() {
           render: (v) => percentCell(v),
         },
       ];
+    } else if (state.view === "orders") {
+      return [
+        {
+          id: "token",
+          label: "Token",
+          sortable: true,
+          minWidth: 140,
+          wrap: false,
+          render: (_v, r) =>
+            `<div class="token-symbol">${Utils.escapeHtml(r.symbol || "?")}</div>
+             <div class="token-name">${Utils.escapeHtml(Utils.formatAddressCompact(r.mint))}</div>`,
+        },
+        {
+          id: "actions",
+          label: "Actions",
+          sortable: false,
+          minWidth: 100,
+          wrap: false,
+          render: (_v, r) => {
+            if (r.status !== "pending") return "—";
+            return `<button class="btn row-action" data-action="cancel-order" data-mint="${Utils.escapeHtml(
+              r.mint
+            )}" data-order-id="${r.id}" title="Cancel order"><i class="icon-x"></i> Cancel</button>`;
+          },
+        },
+        {
+          id: "order_type",
+          label: "Type",
+          sortable: true,
+          minWidth: 120,
+          render: (v) => Utils.escapeHtml(ORDER_TYPE_LABELS[v] || v),
+        },
+        {
+          id: "status",
+          label: "Status",
+          sortable: true,
+          minWidth: 100,
+          render: (v, r) => orderStatusCell(v, r),
+        },
+        {
+          id: "trigger_price_sol",
+          label: "Trigger (SOL)",
+          sortable: true,
+          minWidth: 160,
+          render: orderPriceCell,
+        },
+        { id: "size", label: "Size", sortable: false, minWidth: 90, render: orderSizeCell },
+        {
+          id: "created_at",
+          label: "Created",
+          sortable: true,
+          minWidth: 140,
+          render: (v) => isoTimeCell(v),
+        },
+        {
+          id: "expires_at",
+          label: "Expires",
+          sortable: true,
+          minWidth: 140,
+          render: (v) => isoTimeCell(v),
+        },
+        {
+          id: "triggered_price_sol",
+          label: "Filled At (SOL)",
+          sortable: true,
+          minWidth: 140,
+          render: (v) => (v == null ? "—" : priceCell(v)),
+        },
+      ];
     } else {
       // closed view
       return [
@@ -271,7 +385,7 @@ function createLifecycle() {
     table.updateToolbarSummary([
       {
         id: "positions-total",
-        label: state.view === "open" ? "Open" : "Closed",
+        label: VIEW_LABELS[state.view],
         value: Utils.formatNumber(state.total, 0),
       },
     ]);
@@ -286,21 +400,33 @@ function createLifecycle() {
     ]);
   };
 
+  const loadOrdersRows = async () => {
+    const data = await requestManager.fetch("/api/trader/orders?limit=500", {
+      priority: "normal",
+    });
+    const orders = Array.isArray(data?.orders) ? data.orders : [];
+    return orders.map((order) => ({
+      ...order,
+      row_key: `order-${order.id}`,
+      token: `${order.symbol || "?"} (${order.mint.slice(0, 4)}…${order.mint.slice(-4)})`,
+    }));
+  };
+
   const loadPositionsPage = async ({ reason, signal }) => {
     const status = state.view;
     const url = `/api/positions?status=${encodeURIComponent(status)}&limit=500`;
     try {
-      const rows = await requestManager.fetch(url, {
-        priority: "normal",
-      });
-      state.total = Array.isArray(rows) ? rows.length : 0;
+      const mapped =
+        status === "orders"
+          ? await loadOrdersRows()
+          : (await requestManager.fetch(url, { priority: "normal" })).map((row) => ({
+              ...row,
+              row_key: row.mint,
+              token: `${row.symbol} (${row.mint.slice(0, 4)}…${row.mint.slice(-4)})`,
+            }));
+      state.total = mapped.length;
       state.lastUpdate = Date.now();
 
-      const mapped = rows.map((row) => ({
-        ...row,
-        token: `${row.symbol} (${row.mint.slice(0, 4)}…${row.mint.slice(-4)})`,
-      }));
-
       return {
         rows: mapped,
         cursorNext: null,
@@ -321,8 +447,120 @@ function createLifecycle() {
     }
   };
 
+  const validatePrice = (optional) => (value) => {
+    if (optional && value.trim() === "") return null;
+    const num = parseFloat(value);
+    if (!Number.isFinite(num) || num <= 0) return "Enter a positive SOL price";
+    return null;
+  };
+
+  const submitOrder = async (body) => {
+    try {
+      const order = await requestManager.fetch("/api/trader/orders", {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify(body),
+        priority: "high",
+      });
+      Utils.showToast(`Order #${order?.id ?? ""} placed`, "success");
+      table?.refresh({ reason: "manual", preserveScroll: true });
+    } catch (err) {
+      Utils.showToast(err?.message || "Failed to place order", "error");
+    }
+  };
+
+  /**
+   * Take-profit / stop for an open position. Both prices make an OCO order.
+   */
+  const createExitOrder = async (row) => {
+    const current = row.current_price || row.average_entry_price || 0;
+    const takeProfit = await InputDialog.show({
+      title: `Take Profit for ${row.symbol || "?"}`,
+      message: "Sell when the price rises to (SOL). Leave empty for a stop-only order.",
+      defaultValue: current ? String(current * 1.5) : "",
+      confirmLabel: "Next",
+      validate: validatePrice(true),
+    });
+    if (!takeProfit) return;
+
+    const stop = await InputDialog.show({
+      title: `Stop for ${row.symbol || "?"}`,
+      message: "Sell when the price drops to (SOL). Leave empty for a take-profit-only order.",
+      defaultValue: current ? String(current * 0.7) : "",
+      confirmLabel: "Next",
+      validate: validatePrice(true),
+    });
+    if (!stop) return;
+
+    const tp = parseFloat(takeProfit.value);
+    const sl = parseFloat(stop.value);
+    if (!Number.isFinite(tp) && !Number.isFinite(sl)) {
+      Utils.showToast("Enter a take-profit or stop price", "warning");
+      return;
+    }
+
+    const pct = await InputDialog.show({
+      title: "Sell Percentage",
+      message: "Percentage of the position to sell when the order triggers",
+      defaultValue: "100",
+      confirmLabel: "Place Order",
+      type: "number",
+      validate: (value) => {
+        const num = parseFloat(value);
+        if (!Number.isFinite(num) || num <= 0 || num > 100) return "Enter a value in (0, 100]";
+        return null;
+      },
+    });
+    if (!pct) return;
+
+    const base = { mint: row.mint, sell_percentage: parseFloat(pct.value) };
+    if (Number.isFinite(tp) && Number.isFinite(sl)) {
+      await submitOrder({ ...base, order_type: "oco", trigger_price_sol: tp, stop_price_sol: sl });
+    } else if (Number.isFinite(tp)) {
+      await submitOrder({ ...base, order_type: "sell_above", trigger_price_sol: tp });
+    } else {
+      await submitOrder({ ...base, order_type: "stop_sell", trigger_price_sol: sl });
+    }
+  };
+
+  const createLimitBuyOrder = async () => {
+    const mint = await InputDialog.show({
+      title: "Limit Buy",
+      message: "Token mint to buy",
+      placeholder: "Mint address...",
+      confirmLabel: "Next",
+      validate: (value) => (value.trim().length >= 32 ? null : "Enter a valid mint address"),
+    });
+    if (!mint) return;
+
+    const price = await InputDialog.show({
+      title: "Limit Buy",
+      message: "Buy when the price drops to (SOL)",
+      confirmLabel: "Next",
+      validate: validatePrice(false),
+    });
+    if (!price) return;
+
+    const size = await InputDialog.show({
+      title: "Limit Buy",
+      message: "Amount to spend (SOL)",
+      defaultValue: "0.01",
+      confirmLabel: "Place Order",
+      type: "number",
+      validate: validatePrice(false),
+    });
+    if (!size) return;
+
+    await submitOrder({
+      mint: mint.value.trim(),
+      order_type: "buy_below",
+      trigger_price_sol: parseFloat(price.value),
+      size_sol: parseFloat(size.value),
+    });
+  };
+
   const switchView = (view) => {
-    if (view !== "open" && view !== "closed") return;
+    if (!VIEW_LABELS[view]) return;
     state.view = view;
     state.sort = getInitialSortForView(view);
     if (table) {
@@ -338,7 +576,7 @@ function createLifecycle() {
       });
 
       // Update toolbar title
-      const viewLabel = view === "open" ? "Open" : "Closed";
+      const viewLabel = VIEW_LABELS[view];
       if (table.toolbarView) {
         const titleConfig = table.options.toolbar?.title;
         if (titleConfig) {
@@ -377,12 +615,12 @@ function createLifecycle() {
 
       // Build columns based on current view
       const columns = buildColumns();
-      const viewLabel = state.view === "open" ? "Open" : "Closed";
+      const viewLabel = VIEW_LABELS[state.view];
 
       table = new DataTable({
         container: "#positions-root",
         columns,
-        rowIdField: "mint",
+        rowIdField: "row_key",
         stateKey: getPositionsTableStateKey(state.view),
         enableLogging: false,
         sorting: {
@@ -399,8 +637,8 @@ function createLifecycle() {
           threshold: 160,
           maxRows: 5000,
           loadPage: loadPositionsPage,
-          dedupeKey: (row) => row?.mint ?? null,
-          rowIdField: "mint",
+          dedupeKey: (row) => row?.row_key ?? null,
+          rowIdField: "row_key",
           onPageLoaded: () => updateToolbar(),
         },
         toolbar: {
@@ -415,6 +653,22 @@ function createLifecycle() {
             mode: "client",
             placeholder: "Search by symbol or mint...",
           },
+          buttons: [
+            {
+              id: "limit-buy",
+              label: "Limit Buy",
+              icon: "icon-circle-plus",
+              onClick: () => createLimitBuyOrder(),
+            },
+            {
+              id: "export-trades",
+              label: "Export Trades",
+              icon: "icon-download",
+              onClick: () => {
+                window.location.href = "/api/positions/export?format=csv";
+              },
+            },
+          ],
         },
       });
 
@@ -429,6 +683,23 @@ function createLifecycle() {
         const mint = btn.getAttribute("data-mint");
         if (!action || !mint) return;
 
+        if (action === "cancel-order") {
+          const orderId = btn.getAttribute("data-order-id");
+          try {
+            btn.disabled = true;
+            await requestManager.fetch(`/api/trader/orders/${orderId}/cancel`, {
+              method: "POST",
+              priority: "high",
+            });
+            Utils.showToast(`Order #${orderId} cancelled`, "success");
+            table.refresh({ reason: "manual", preserveScroll: true });
+          } catch (err) {
+            btn.disabled = false;
+            Utils.showToast(err?.message || "Failed to cancel order", "error");
+          }
+          return;
+        }
+
         // Find row data
         const row = table.getData().find((r) => r.mint === mint);
         if (!row) {
@@ -511,6 +782,8 @@ function createLifecycle() {
             btn.disabled = false;
             Utils.showToast("Sell placed", "success");
             table.refresh({ reason: "manual", preserveScroll: true });
+          } else if (action === "order") {
+            await createExitOrder(row);
           }
         } catch (err) {
           btn.disabled = false;
@@ -535,8 +808,10 @@ function createLifecycle() {
           const row = e.target.closest("tr[data-row-id]");
           if (!row) return;
 
-          const mint = row.dataset.rowId;
-          const position = table?.getData()?.find((p) => p.mint === mint);
+          if (state.view === "orders") return;
+
+          const rowKey = row.dataset.rowId;
+          const position = table?.getData()?.find((p) => p.row_key === rowKey);
           if (position && positionDetailsDialog) {
             positionDetailsDialog.show(position);
           }
@@ -23,6 +23,23 @@ function getHealthBadge(health) {
   );
 }
 
+function getRestartsCell(restarts) {
+  const count = restarts?.restart_count || 0;
+  if (restarts?.gave_up) {
+    return '<span class="badge error"><i class="icon-x"></i> Gave up</span>';
+  }
+  if (restarts?.next_restart_in_secs != null) {
+    return `<span class="badge warning">Retry in ${restarts.next_restart_in_secs}s</span>`;
+  }
+  if (count === 0) {
+    return '<span style="color: var(--text-secondary);">0</span>';
+  }
+  const reason = restarts.last_failure_reason
+    ? `<div style="font-size: 11px; color: var(--text-secondary);">${Utils.escapeHtml(restarts.last_failure_reason)}</div>`
+    : "";
+  return `<strong>${count}</strong>${reason}`;
+}
+
 function getActivityBar(metrics) {
   const total = (metrics.total_poll_duration_ns || 0) + (metrics.total_idle_duration_ns || 0);
   const activity = total > 0 ? ((metrics.total_poll_duration_ns || 0) / total) * 100 : 0;
@@ -200,6 +217,14 @@ function createLifecycle() {
           render: (v, row) => Utils.formatUptime(row.uptime_seconds, { style: "compact" }),
           sortFn: (a, b) => (a.uptime_seconds || 0) - (b.uptime_seconds || 0),
         },
+        {
+          id: "restarts",
+          label: "Restarts",
+          sortable: true,
+          minWidth: 120,
+          render: (v, row) => getRestartsCell(row.restarts),
+          sortFn: (a, b) => (a.restarts?.restart_count || 0) - (b.restarts?.restart_count || 0),
+        },
         {
           id: "activity",
           label: "Activity",
@@ -868,6 +868,14 @@ export function createLifecycle() {
       CandleSize: "icon-expand",
       ConsecutiveCandles: "icon-chart-candlestick",
       VolumeSpike: "icon-chart-bar",
+      RsiThreshold: "icon-gauge",
+      RsiDivergence: "icon-git-compare",
+      MacdCross: "icon-activity",
+      BollingerBands: "icon-columns-2",
+      EmaCross: "icon-git-merge",
+      AtrExpansion: "icon-maximize",
+      PriceToVwap: "icon-scale",
+      Formula: "icon-sigma",
     };
     return icons[type] || "icon-puzzle";
   }
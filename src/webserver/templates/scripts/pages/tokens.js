@@ -268,6 +268,18 @@ const REJECTION_LABELS = {
   rug_lp_providers_missing: "LP providers missing",
   rug_lp_lock_low: "LP lock too low",
   rug_lp_lock_missing: "LP lock missing",
+  chain_data_missing: "On-chain data missing",
+  chain_mint_authority: "Mint authority present",
+  chain_freeze_authority: "Freeze authority present",
+  chain_transfer_fee_high: "Transfer fee too high",
+  chain_transfer_hook: "Transfer hook present",
+  chain_permanent_delegate: "Permanent delegate present",
+  chain_non_transferable: "Non-transferable token",
+  chain_default_frozen: "Accounts frozen by default",
+  chain_lp_secured_low: "LP burned/locked too low",
+  chain_lp_missing: "LP status unknown",
+  chain_top_holder: "Top holder % too high",
+  chain_top10_holders: "Top 10 holders % too high",
 };
 
 function getRejectionDisplayLabel(reasonCode) {
@@ -37,6 +37,7 @@ const FEATURE_STATUS = {
 const TOOL_TO_FEATURE_MAP = {
   "wallet-cleanup": "wallet_cleanup",
   "burn-tokens": "burn_tokens",
+  "copy-trader": "copy_trader",
   "token-analyzer": "token_analyzer",
   "create-token": "create_token",
   "trade-watcher": "trade_watcher",
@@ -93,6 +94,14 @@ const TOOL_DEFINITIONS = {
     category: "wallet",
     render: renderBurnTokensTool,
   },
+  "copy-trader": {
+    id: "copy-trader",
+    title: "Copy Trader",
+    description: "Mirror swaps of followed wallets into your own positions",
+    icon: "icon-users",
+    category: "wallet",
+    render: renderCopyTraderTool,
+  },
   "token-analyzer": {
     id: "token-analyzer",
     title: "Token Analyzer",
@@ -1114,6 +1123,7 @@ async function loadHolderWatchConfig() {
         notify_holder_drop: true,
         min_holder_change: 5,
         holder_drop_percent: 10.0,
+        whale_accumulation_percent: 1.0,
         max_watched_tokens: 20,
       }
     );
@@ -1126,6 +1136,7 @@ async function loadHolderWatchConfig() {
       notify_holder_drop: true,
       min_holder_change: 5,
       holder_drop_percent: 10.0,
+      whale_accumulation_percent: 1.0,
       max_watched_tokens: 20,
     };
   }
@@ -1142,6 +1153,7 @@ async function saveHolderWatchConfig() {
     notify_holder_drop: $("#hw-notify-drop")?.checked ?? true,
     min_holder_change: parseInt($("#hw-min-change")?.value, 10) || 5,
     holder_drop_percent: parseFloat($("#hw-drop-percent")?.value) || 10.0,
+    whale_accumulation_percent: parseFloat($("#hw-whale-percent")?.value) || 1.0,
     max_watched_tokens: parseInt($("#hw-max-tokens")?.value, 10) || 20,
   };
 
@@ -1232,6 +1244,15 @@ function renderHolderWatchContent(container, actionsContainer, config) {
             </div>
           </div>
 
+          <div class="hw-form-row hw-two-cols">
+            <div class="hw-form-group">
+              <label for="hw-whale-percent">Whale Accumulation Threshold (%)</label>
+              <input type="number" id="hw-whale-percent" class="form-input" 
+                value="${config.whale_accumulation_percent || 1.0}" min="0.1" max="50" step="0.1">
+              <span class="hint">Supply share a top holder must gain between checks</span>
+            </div>
+          </div>
+
           <div class="hw-form-actions">
             <button class="btn primary" id="hw-save-config">
               <i class="icon-save"></i> Save Settings
@@ -1253,10 +1274,22 @@ function renderHolderWatchContent(container, actionsContainer, config) {
             </button>
           </div>
           <div id="hw-token-list" class="hw-token-list">
-            <div class="empty-state">
-              <i class="icon-eye-off"></i>
-              <p>No tokens being watched</p>
-              <small>Add a token mint address above to start watching</small>
+            <div class="hw-loading">
+              <i class="icon-loader spin"></i>
+            </div>
+          </div>
+        </div>
+      </div>
+
+      <div class="tool-section">
+        <div class="section-header">
+          <h3><i class="icon-bell"></i> Recent Holder Alerts</h3>
+          <span class="hw-status" id="hw-status"></span>
+        </div>
+        <div class="section-content">
+          <div id="hw-event-list" class="hw-event-list">
+            <div class="hw-loading">
+              <i class="icon-loader spin"></i>
             </div>
           </div>
         </div>
@@ -1270,17 +1303,18 @@ function renderHolderWatchContent(container, actionsContainer, config) {
     saveBtn.addEventListener("click", saveHolderWatchConfig);
   }
 
-  // Wire up add token button (placeholder - database integration needed)
+  // Wire up add token button
   const addBtn = $("#hw-add-token");
   const tokenInput = $("#hw-token-input");
   if (addBtn && tokenInput) {
-    addBtn.addEventListener("click", () => {
+    addBtn.addEventListener("click", async () => {
       const mint = tokenInput.value.trim();
-      if (mint && mint.length >= 32) {
-        Utils.showToast("Token watching feature coming soon", "info");
-        tokenInput.value = "";
-      } else {
+      if (!mint || mint.length < 32) {
         Utils.showToast("Please enter a valid mint address", "error");
+        return;
+      }
+      if (await addHolderWatchToken(mint)) {
+        tokenInput.value = "";
       }
     });
 
@@ -1291,6 +1325,8 @@ function renderHolderWatchContent(container, actionsContainer, config) {
     });
   }
 
+  loadHolderWatchData();
+
   // Render action bar
   actionsContainer.innerHTML = `
     <button class="btn" id="hw-refresh-action">
@@ -1300,12 +1336,819 @@ function renderHolderWatchContent(container, actionsContainer, config) {
 
   const refreshBtn = $("#hw-refresh-action");
   if (refreshBtn) {
-    refreshBtn.addEventListener("click", () => {
+    refreshBtn.addEventListener("click", async () => {
+      await fetch("/api/tools/holder-watch/check", { method: "POST" }).catch(() => {});
       renderTokenWatchTool(container, actionsContainer);
     });
   }
 }
 
+/**
+ * Load watched tokens, recent alerts and monitor status
+ */
+async function loadHolderWatchData() {
+  await Promise.all([loadHolderWatchTokens(), loadHolderWatchEvents(), loadHolderWatchStatus()]);
+}
+
+/**
+ * Add a token to Holder Watch
+ */
+async function addHolderWatchToken(mint) {
+  try {
+    const res = await fetch("/api/tools/holder-watch/tokens", {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ mint }),
+    });
+    const data = await res.json().catch(() => ({}));
+
+    if (!res.ok) {
+      Utils.showToast(data.error?.message || data.error || "Failed to add token", "error");
+      return false;
+    }
+
+    Utils.showToast("Token added to Holder Watch", "success");
+    loadHolderWatchTokens();
+    return true;
+  } catch (e) {
+    console.error("[HolderWatch] Add token error:", e);
+    Utils.showToast("Error adding token", "error");
+    return false;
+  }
+}
+
+/**
+ * Remove a token from Holder Watch
+ */
+async function removeHolderWatchToken(id) {
+  try {
+    const res = await fetch(`/api/tools/holder-watch/tokens/${id}`, { method: "DELETE" });
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    Utils.showToast("Token removed from Holder Watch", "success");
+    loadHolderWatchData();
+  } catch (e) {
+    console.error("[HolderWatch] Remove token error:", e);
+    Utils.showToast("Error removing token", "error");
+  }
+}
+
+/**
+ * Render the watched token list
+ */
+async function loadHolderWatchTokens() {
+  const listEl = $("#hw-token-list");
+  if (!listEl) return;
+
+  try {
+    const res = await fetch("/api/tools/holder-watch/tokens");
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    const data = await res.json();
+    const tokens = data.tokens || [];
+
+    if (tokens.length === 0) {
+      listEl.innerHTML = `
+        <div class="empty-state">
+          <i class="icon-eye-off"></i>
+          <p>No tokens being watched</p>
+          <small>Add a token mint address above to start watching</small>
+        </div>
+      `;
+      return;
+    }
+
+    listEl.innerHTML = `
+      <table class="hw-table">
+        <thead>
+          <tr>
+            <th>Token</th>
+            <th>Holders</th>
+            <th>Top 10</th>
+            <th>Alerts</th>
+            <th>Last Check</th>
+            <th></th>
+          </tr>
+        </thead>
+        <tbody>
+          ${tokens
+            .map(
+              (token) => `
+            <tr data-id="${token.id}">
+              <td>
+                <div class="hw-token-cell">
+                  <span class="hw-symbol">${Utils.escapeHtml(token.symbol || "Unknown")}</span>
+                  <span class="hw-mint">${Utils.escapeHtml(token.mint.slice(0, 8))}...</span>
+                </div>
+              </td>
+              <td class="mono">${token.holder_count != null ? Utils.formatCompactNumber(token.holder_count) : "—"}</td>
+              <td class="mono">${token.top10_pct != null ? `${token.top10_pct.toFixed(1)}%` : "—"}</td>
+              <td class="mono">${token.alerts_raised || 0}</td>
+              <td>${Utils.formatTimeAgo(token.last_checked_at, { fallback: "Pending" })}</td>
+              <td>
+                <button class="btn btn-sm btn-icon danger hw-remove-btn" title="Stop watching">
+                  <i class="icon-x"></i>
+                </button>
+              </td>
+            </tr>
+          `
+            )
+            .join("")}
+        </tbody>
+      </table>
+    `;
+
+    listEl.querySelectorAll(".hw-remove-btn").forEach((btn) => {
+      on(btn, "click", (e) => {
+        const id = e.target.closest("tr")?.dataset.id;
+        if (id) {
+          removeHolderWatchToken(id);
+        }
+      });
+    });
+  } catch (e) {
+    console.error("[HolderWatch] Failed to load tokens:", e);
+    listEl.innerHTML = `
+      <div class="error-state">
+        <i class="icon-circle-alert"></i>
+        <p>Failed to load watched tokens</p>
+      </div>
+    `;
+  }
+}
+
+/**
+ * Render recent holder alerts
+ */
+async function loadHolderWatchEvents() {
+  const listEl = $("#hw-event-list");
+  if (!listEl) return;
+
+  try {
+    const res = await fetch("/api/tools/holder-watch/events?limit=50");
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    const data = await res.json();
+    const events = data.events || [];
+
+    if (events.length === 0) {
+      listEl.innerHTML = `
+        <div class="empty-state">
+          <i class="icon-bell-off"></i>
+          <p>No holder alerts yet</p>
+          <small>Alerts appear when holder counts or top holders change past your thresholds</small>
+        </div>
+      `;
+      return;
+    }
+
+    listEl.innerHTML = events
+      .map(
+        (event) => `
+        <div class="hw-event ${Utils.escapeHtml(event.event_type)}">
+          <span class="hw-event-type">${formatHolderEventType(event.event_type)}</span>
+          <span class="hw-symbol">${Utils.escapeHtml(event.symbol || event.mint.slice(0, 8))}</span>
+          <span class="hw-event-message">${Utils.escapeHtml(event.message)}</span>
+          <span class="hw-event-time">${Utils.formatTimeAgo(event.created_at)}</span>
+        </div>
+      `
+      )
+      .join("");
+  } catch (e) {
+    console.error("[HolderWatch] Failed to load events:", e);
+    listEl.innerHTML = `
+      <div class="error-state">
+        <i class="icon-circle-alert"></i>
+        <p>Failed to load holder alerts</p>
+      </div>
+    `;
+  }
+}
+
+/**
+ * Show monitor status next to the alerts header
+ */
+async function loadHolderWatchStatus() {
+  const statusEl = $("#hw-status");
+  if (!statusEl) return;
+
+  try {
+    const res = await fetch("/api/tools/holder-watch/status");
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    const status = await res.json();
+
+    if (!status.enabled) {
+      statusEl.textContent = "Disabled";
+    } else if (status.last_error) {
+      statusEl.textContent = "Last check failed";
+      statusEl.title = status.last_error;
+    } else {
+      statusEl.textContent = `${status.active_count}/${status.max_watched_tokens} tokens · checked ${Utils.formatTimeAgo(status.last_check_at, { fallback: "never" })}`;
+    }
+  } catch (e) {
+    console.error("[HolderWatch] Failed to load status:", e);
+  }
+}
+
+/**
+ * Display label for holder event types
+ */
+function formatHolderEventType(type) {
+  switch (type) {
+    case "holder_drop":
+      return "Holder Drop";
+    case "new_holders":
+      return "New Holders";
+    case "whale_accumulation":
+      return "Whale";
+    default:
+      return type;
+  }
+}
+
+// =============================================================================
+// Copy Trader Tool
+// =============================================================================
+
+const COPY_TRADE_DEFAULTS = {
+  enabled: false,
+  sizing_mode: "fixed",
+  fixed_size_sol: 0.01,
+  proportional_percent: 10.0,
+  min_size_sol: 0.005,
+  max_size_sol: 0.1,
+  min_leader_trade_sol: 0.05,
+  max_slippage_pct: 10.0,
+  delay_secs: 0,
+  mirror_sells: true,
+  require_filter_pass: true,
+  max_leaders: 10,
+  notify: true,
+};
+
+function renderCopyTraderTool(container, actionsContainer) {
+  container.innerHTML = `
+    <div class="tool-panel copy-trader-tool">
+      <div class="ct-loading">
+        <i class="icon-loader spin"></i>
+        <p>Loading settings...</p>
+      </div>
+    </div>
+  `;
+
+  loadCopyTradeConfig().then((config) => {
+    renderCopyTraderContent(container, actionsContainer, config);
+  });
+}
+
+/**
+ * Load copy trade configuration from the server
+ */
+async function loadCopyTradeConfig() {
+  try {
+    const res = await fetch("/api/config/copy_trade");
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    const data = await res.json();
+    return { ...COPY_TRADE_DEFAULTS, ...(data.data || {}) };
+  } catch (e) {
+    console.error("[CopyTrader] Failed to load config:", e);
+    return { ...COPY_TRADE_DEFAULTS };
+  }
+}
+
+/**
+ * Save copy trade configuration to the server
+ */
+async function saveCopyTradeConfig() {
+  const config = {
+    enabled: $("#ct-enabled")?.checked ?? false,
+    sizing_mode: $("#ct-sizing-mode")?.value || "fixed",
+    fixed_size_sol: parseFloat($("#ct-fixed-size")?.value) || COPY_TRADE_DEFAULTS.fixed_size_sol,
+    proportional_percent:
+      parseFloat($("#ct-proportional")?.value) || COPY_TRADE_DEFAULTS.proportional_percent,
+    min_size_sol: parseFloat($("#ct-min-size")?.value) || COPY_TRADE_DEFAULTS.min_size_sol,
+    max_size_sol: parseFloat($("#ct-max-size")?.value) || COPY_TRADE_DEFAULTS.max_size_sol,
+    min_leader_trade_sol: parseFloat($("#ct-min-leader")?.value) || 0,
+    max_slippage_pct:
+      parseFloat($("#ct-slippage")?.value) || COPY_TRADE_DEFAULTS.max_slippage_pct,
+    delay_secs: parseInt($("#ct-delay")?.value, 10) || 0,
+    mirror_sells: $("#ct-mirror-sells")?.checked ?? true,
+    require_filter_pass: $("#ct-filter-pass")?.checked ?? true,
+    max_leaders: parseInt($("#ct-max-leaders")?.value, 10) || COPY_TRADE_DEFAULTS.max_leaders,
+    notify: $("#ct-notify")?.checked ?? true,
+  };
+
+  try {
+    const res = await fetch("/api/config/copy_trade", {
+      method: "PATCH",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify(config),
+    });
+
+    if (res.ok) {
+      Utils.showToast("Copy Trader settings saved", "success");
+      loadCopyTraderStatus();
+    } else {
+      const errData = await res.json().catch(() => ({}));
+      Utils.showToast(errData.error?.message || errData.error || "Failed to save settings", "error");
+    }
+  } catch (e) {
+    console.error("[CopyTrader] Save error:", e);
+    Utils.showToast("Error saving settings", "error");
+  }
+}
+
+/**
+ * Render the copy trader content after config is loaded
+ */
+function renderCopyTraderContent(container, actionsContainer, config) {
+  container.innerHTML = `
+    <div class="tool-panel copy-trader-tool">
+      <div class="tool-section">
+        <div class="section-header">
+          <h3><i class="icon-settings"></i> Copy Trader Settings</h3>
+        </div>
+        <div class="section-content">
+          <div class="ct-form-row">
+            <div class="ct-form-group ct-toggle-group">
+              <label for="ct-enabled">Enable Copy Trading</label>
+              <label class="toggle">
+                <input type="checkbox" id="ct-enabled" ${config.enabled ? "checked" : ""}>
+                <span class="toggle-track"></span>
+              </label>
+            </div>
+          </div>
+
+          <div class="ct-form-row ct-two-cols">
+            <div class="ct-form-group">
+              <label for="ct-sizing-mode">Sizing Mode</label>
+              <select id="ct-sizing-mode" class="form-input">
+                <option value="fixed" ${config.sizing_mode === "fixed" ? "selected" : ""}>Fixed SOL</option>
+                <option value="proportional" ${config.sizing_mode === "proportional" ? "selected" : ""}>Proportional to leader</option>
+              </select>
+              <span class="hint">Default sizing; leaders can override it</span>
+            </div>
+            <div class="ct-form-group">
+              <label for="ct-max-leaders">Max Leaders</label>
+              <input type="number" id="ct-max-leaders" class="form-input"
+                value="${config.max_leaders}" min="1" max="100">
+              <span class="hint">Maximum wallets followed at once</span>
+            </div>
+          </div>
+
+          <div class="ct-form-row ct-two-cols">
+            <div class="ct-form-group">
+              <label for="ct-fixed-size">Fixed Size (SOL)</label>
+              <input type="number" id="ct-fixed-size" class="form-input"
+                value="${config.fixed_size_sol}" min="0.001" step="0.001">
+              <span class="hint">SOL spent per copied buy in fixed mode</span>
+            </div>
+            <div class="ct-form-group">
+              <label for="ct-proportional">Proportional Size (%)</label>
+              <input type="number" id="ct-proportional" class="form-input"
+                value="${config.proportional_percent}" min="0.1" max="1000" step="0.1">
+              <span class="hint">Percent of the leader's SOL amount</span>
+            </div>
+          </div>
+
+          <div class="ct-form-row ct-two-cols">
+            <div class="ct-form-group">
+              <label for="ct-min-size">Min Size (SOL)</label>
+              <input type="number" id="ct-min-size" class="form-input"
+                value="${config.min_size_sol}" min="0.001" step="0.001">
+              <span class="hint">Floor for proportional sizes</span>
+            </div>
+            <div class="ct-form-group">
+              <label for="ct-max-size">Max Size (SOL)</label>
+              <input type="number" id="ct-max-size" class="form-input"
+                value="${config.max_size_sol}" min="0.001" step="0.001">
+              <span class="hint">Cap for any copied buy</span>
+            </div>
+          </div>
+
+          <div class="ct-form-row ct-two-cols">
+            <div class="ct-form-group">
+              <label for="ct-min-leader">Min Leader Trade (SOL)</label>
+              <input type="number" id="ct-min-leader" class="form-input"
+                value="${config.min_leader_trade_sol}" min="0" step="0.01">
+              <span class="hint">Ignore leader buys smaller than this</span>
+            </div>
+            <div class="ct-form-group">
+              <label for="ct-slippage">Max Slippage vs Leader (%)</label>
+              <input type="number" id="ct-slippage" class="form-input"
+                value="${config.max_slippage_pct}" min="0.1" max="100" step="0.5">
+              <span class="hint">Skip buys when price moved further above the leader's fill</span>
+            </div>
+          </div>
+
+          <div class="ct-form-row ct-two-cols">
+            <div class="ct-form-group">
+              <label for="ct-delay">Copy Delay (seconds)</label>
+              <input type="number" id="ct-delay" class="form-input"
+                value="${config.delay_secs}" min="0" max="300">
+              <span class="hint">Wait before mirroring a detected swap</span>
+            </div>
+          </div>
+
+          <div class="ct-form-row ct-three-cols">
+            <div class="ct-form-group ct-toggle-group">
+              <label for="ct-mirror-sells">Mirror Sells</label>
+              <label class="toggle">
+                <input type="checkbox" id="ct-mirror-sells" ${config.mirror_sells ? "checked" : ""}>
+                <span class="toggle-track"></span>
+              </label>
+            </div>
+            <div class="ct-form-group ct-toggle-group">
+              <label for="ct-filter-pass">Require Filter Pass</label>
+              <label class="toggle">
+                <input type="checkbox" id="ct-filter-pass" ${config.require_filter_pass ? "checked" : ""}>
+                <span class="toggle-track"></span>
+              </label>
+            </div>
+            <div class="ct-form-group ct-toggle-group">
+              <label for="ct-notify">Notify</label>
+              <label class="toggle">
+                <input type="checkbox" id="ct-notify" ${config.notify ? "checked" : ""}>
+                <span class="toggle-track"></span>
+              </label>
+            </div>
+          </div>
+
+          <div class="ct-form-actions">
+            <button class="btn primary" id="ct-save-config">
+              <i class="icon-save"></i> Save Settings
+            </button>
+          </div>
+        </div>
+      </div>
+
+      <div class="tool-section">
+        <div class="section-header">
+          <h3><i class="icon-users"></i> Followed Wallets</h3>
+          <span class="ct-status" id="ct-status"></span>
+        </div>
+        <div class="section-content">
+          <div class="ct-add-leader-group">
+            <input type="text" id="ct-leader-address" class="form-input"
+              placeholder="Wallet address to follow...">
+            <input type="text" id="ct-leader-label" class="form-input ct-label-input"
+              placeholder="Label (optional)">
+            <button class="btn primary" id="ct-add-leader">
+              <i class="icon-plus"></i> Follow
+            </button>
+          </div>
+          <div id="ct-leader-list" class="ct-list">
+            <div class="ct-loading">
+              <i class="icon-loader spin"></i>
+            </div>
+          </div>
+        </div>
+      </div>
+
+      <div class="tool-section">
+        <div class="section-header">
+          <h3><i class="icon-activity"></i> Recent Copy Trades</h3>
+        </div>
+        <div class="section-content">
+          <div id="ct-trade-list" class="ct-list">
+            <div class="ct-loading">
+              <i class="icon-loader spin"></i>
+            </div>
+          </div>
+        </div>
+      </div>
+    </div>
+  `;
+
+  const saveBtn = $("#ct-save-config");
+  if (saveBtn) {
+    saveBtn.addEventListener("click", saveCopyTradeConfig);
+  }
+
+  const addBtn = $("#ct-add-leader");
+  const addressInput = $("#ct-leader-address");
+  const labelInput = $("#ct-leader-label");
+  if (addBtn && addressInput) {
+    addBtn.addEventListener("click", async () => {
+      const address = addressInput.value.trim();
+      if (!address || address.length < 32) {
+        Utils.showToast("Please enter a valid wallet address", "error");
+        return;
+      }
+      if (await addCopyLeader(address, labelInput?.value.trim() || null)) {
+        addressInput.value = "";
+        if (labelInput) labelInput.value = "";
+      }
+    });
+
+    addressInput.addEventListener("keypress", (e) => {
+      if (e.key === "Enter") {
+        addBtn.click();
+      }
+    });
+  }
+
+  loadCopyTraderData();
+
+  actionsContainer.innerHTML = `
+    <button class="btn" id="ct-refresh-action">
+      <i class="icon-refresh-cw"></i> Refresh
+    </button>
+  `;
+
+  const refreshBtn = $("#ct-refresh-action");
+  if (refreshBtn) {
+    refreshBtn.addEventListener("click", () => loadCopyTraderData());
+  }
+}
+
+/**
+ * Load followed wallets (with attributed PnL), recent trades and status
+ */
+async function loadCopyTraderData() {
+  await Promise.all([loadCopyLeaders(), loadCopyTrades(), loadCopyTraderStatus()]);
+}
+
+/**
+ * Follow a wallet
+ */
+async function addCopyLeader(address, label) {
+  try {
+    const res = await fetch("/api/tools/copy-trader/leaders", {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ address, label }),
+    });
+    const data = await res.json().catch(() => ({}));
+
+    if (!res.ok) {
+      Utils.showToast(data.error?.message || data.error || "Failed to follow wallet", "error");
+      return false;
+    }
+
+    Utils.showToast("Wallet added to Copy Trader", "success");
+    loadCopyTraderData();
+    return true;
+  } catch (e) {
+    console.error("[CopyTrader] Add leader error:", e);
+    Utils.showToast("Error following wallet", "error");
+    return false;
+  }
+}
+
+/**
+ * Pause or resume a followed wallet
+ */
+async function setCopyLeaderActive(id, isActive) {
+  try {
+    const res = await fetch(`/api/tools/copy-trader/leaders/${id}/status`, {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ is_active: isActive }),
+    });
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    loadCopyTraderData();
+  } catch (e) {
+    console.error("[CopyTrader] Update leader error:", e);
+    Utils.showToast("Error updating wallet", "error");
+  }
+}
+
+/**
+ * Stop following a wallet
+ */
+async function removeCopyLeader(id) {
+  try {
+    const res = await fetch(`/api/tools/copy-trader/leaders/${id}`, { method: "DELETE" });
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    Utils.showToast("Wallet removed from Copy Trader", "success");
+    loadCopyTraderData();
+  } catch (e) {
+    console.error("[CopyTrader] Remove leader error:", e);
+    Utils.showToast("Error removing wallet", "error");
+  }
+}
+
+/**
+ * Format a signed SOL amount with a colour class
+ */
+function formatCopyPnl(value) {
+  if (value == null || value === 0) {
+    return `<span class="ct-pnl">0 SOL</span>`;
+  }
+  const cls = value > 0 ? "positive" : "negative";
+  const sign = value > 0 ? "+" : "";
+  return `<span class="ct-pnl ${cls}">${sign}${value.toFixed(4)} SOL</span>`;
+}
+
+/**
+ * Render the followed wallet list with attributed PnL
+ */
+async function loadCopyLeaders() {
+  const listEl = $("#ct-leader-list");
+  if (!listEl) return;
+
+  try {
+    const [leadersRes, pnlRes] = await Promise.all([
+      fetch("/api/tools/copy-trader/leaders"),
+      fetch("/api/tools/copy-trader/pnl"),
+    ]);
+    if (!leadersRes.ok) {
+      throw new Error(`HTTP ${leadersRes.status}`);
+    }
+    const leaders = (await leadersRes.json()).leaders || [];
+    const pnl = pnlRes.ok ? (await pnlRes.json()).leaders || [] : [];
+    const pnlByLeader = Object.fromEntries(pnl.map((p) => [p.leader_address, p]));
+
+    if (leaders.length === 0) {
+      listEl.innerHTML = `
+        <div class="empty-state">
+          <i class="icon-users"></i>
+          <p>No wallets followed</p>
+          <small>Add a wallet address above to start mirroring its swaps</small>
+        </div>
+      `;
+      return;
+    }
+
+    listEl.innerHTML = `
+      <table class="hw-table">
+        <thead>
+          <tr>
+            <th>Wallet</th>
+            <th>Sizing</th>
+            <th>Copied</th>
+            <th>Skipped</th>
+            <th>Positions</th>
+            <th>PnL</th>
+            <th>Last Trade</th>
+            <th></th>
+          </tr>
+        </thead>
+        <tbody>
+          ${leaders
+            .map((leader) => {
+              const stats = pnlByLeader[leader.address];
+              const sizing = leader.sizing_mode
+                ? `${Utils.escapeHtml(leader.sizing_mode)} ${leader.size_value ?? ""}`
+                : "Default";
+              return `
+            <tr data-id="${leader.id}" class="${leader.is_active ? "" : "ct-paused"}">
+              <td>
+                <div class="hw-token-cell">
+                  <span class="hw-symbol">${Utils.escapeHtml(leader.label || "Unnamed")}</span>
+                  <span class="hw-mint">${Utils.escapeHtml(leader.address.slice(0, 8))}...</span>
+                </div>
+              </td>
+              <td>${sizing}</td>
+              <td class="mono">${leader.trades_copied || 0}</td>
+              <td class="mono">${leader.trades_skipped || 0}</td>
+              <td class="mono">${stats ? `${stats.open_positions}/${stats.positions}` : "—"}</td>
+              <td class="mono">${stats ? formatCopyPnl(stats.total_pnl_sol) : "—"}</td>
+              <td>${Utils.formatTimeAgo(leader.last_trade_at, { fallback: "Never" })}</td>
+              <td class="ct-row-actions">
+                <button class="btn btn-sm btn-icon ct-toggle-btn"
+                  title="${leader.is_active ? "Pause" : "Resume"}"
+                  data-active="${leader.is_active ? "1" : "0"}">
+                  <i class="${leader.is_active ? "icon-pause" : "icon-play"}"></i>
+                </button>
+                <button class="btn btn-sm btn-icon danger ct-remove-btn" title="Stop following">
+                  <i class="icon-x"></i>
+                </button>
+              </td>
+            </tr>
+          `;
+            })
+            .join("")}
+        </tbody>
+      </table>
+    `;
+
+    listEl.querySelectorAll(".ct-toggle-btn").forEach((btn) => {
+      on(btn, "click", (e) => {
+        const button = e.target.closest("button");
+        const id = button?.closest("tr")?.dataset.id;
+        if (id) {
+          setCopyLeaderActive(id, button.dataset.active !== "1");
+        }
+      });
+    });
+
+    listEl.querySelectorAll(".ct-remove-btn").forEach((btn) => {
+      on(btn, "click", (e) => {
+        const id = e.target.closest("tr")?.dataset.id;
+        if (id) {
+          removeCopyLeader(id);
+        }
+      });
+    });
+  } catch (e) {
+    console.error("[CopyTrader] Failed to load leaders:", e);
+    listEl.innerHTML = `
+      <div class="error-state">
+        <i class="icon-circle-alert"></i>
+        <p>Failed to load followed wallets</p>
+      </div>
+    `;
+  }
+}
+
+/**
+ * Render recent copy trades
+ */
+async function loadCopyTrades() {
+  const listEl = $("#ct-trade-list");
+  if (!listEl) return;
+
+  try {
+    const res = await fetch("/api/tools/copy-trader/trades?limit=50");
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    const trades = (await res.json()).trades || [];
+
+    if (trades.length === 0) {
+      listEl.innerHTML = `
+        <div class="empty-state">
+          <i class="icon-activity"></i>
+          <p>No copy trades yet</p>
+          <small>Swaps by followed wallets appear here as they are mirrored or skipped</small>
+        </div>
+      `;
+      return;
+    }
+
+    listEl.innerHTML = trades
+      .map((trade) => {
+        const amount =
+          trade.side === "buy"
+            ? trade.our_size_sol != null
+              ? `${trade.our_size_sol.toFixed(4)} SOL`
+              : "—"
+            : trade.our_sell_pct != null
+              ? `${trade.our_sell_pct.toFixed(0)}%`
+              : "—";
+        return `
+        <div class="ct-trade ${Utils.escapeHtml(trade.status)}">
+          <span class="ct-trade-side ${Utils.escapeHtml(trade.side)}">${Utils.escapeHtml(trade.side)}</span>
+          <span class="hw-symbol">${Utils.escapeHtml(trade.symbol || trade.mint.slice(0, 8))}</span>
+          <span class="ct-trade-amount mono">${amount}</span>
+          <span class="ct-trade-status" title="${Utils.escapeHtml(trade.reason || "")}">${Utils.escapeHtml(trade.reason || trade.status)}</span>
+          <span class="ct-trade-time">${Utils.formatTimeAgo(trade.created_at)}</span>
+        </div>
+      `;
+      })
+      .join("");
+  } catch (e) {
+    console.error("[CopyTrader] Failed to load trades:", e);
+    listEl.innerHTML = `
+      <div class="error-state">
+        <i class="icon-circle-alert"></i>
+        <p>Failed to load copy trades</p>
+      </div>
+    `;
+  }
+}
+
+/**
+ * Show monitor status next to the followed wallets header
+ */
+async function loadCopyTraderStatus() {
+  const statusEl = $("#ct-status");
+  if (!statusEl) return;
+
+  try {
+    const res = await fetch("/api/tools/copy-trader/status");
+    if (!res.ok) {
+      throw new Error(`HTTP ${res.status}`);
+    }
+    const status = await res.json();
+
+    statusEl.title = "";
+    if (!status.enabled) {
+      statusEl.textContent = "Disabled";
+    } else if (status.last_error) {
+      statusEl.textContent = "Last copy failed";
+      statusEl.title = status.last_error;
+    } else {
+      statusEl.textContent = `${status.subscribed_leaders}/${status.max_leaders} subscribed · ${status.trades_copied} copied · ${status.trades_skipped} skipped`;
+    }
+  } catch (e) {
+    console.error("[CopyTrader] Failed to load status:", e);
+  }
+}
+
 // =============================================================================
 // Token Analyzer Tool
 // =============================================================================
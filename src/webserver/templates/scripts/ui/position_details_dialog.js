@@ -756,6 +756,16 @@ export class PositionDetailsDialog {
               ${dcaCount > 0 ? '<span class="pdd-badge pdd-badge-info">DCA</span>' : ""}
             </span>
           </div>
+          ${
+            pos.sizing_mode
+              ? `
+          <div class="pdd-stat-row" title="${Utils.escapeHtml(pos.sizing_reason || "")}">
+            <span class="pdd-stat-label">Sizing</span>
+            <span class="pdd-stat-value">${Utils.escapeHtml(pos.sizing_mode.replace(/_/g, " "))}</span>
+          </div>
+          `
+              : ""
+          }
         </div>
       </div>
     `;
@@ -1638,6 +1648,7 @@ export class PositionDetailsDialog {
   _renderHistoryTab(content) {
     const entries = this.fullDetails?.entries || [];
     const exits = this.fullDetails?.exits || [];
+    const ladder = this.fullDetails?.take_profit_ladder || [];
 
     // Combine and sort by timestamp (newest first)
     const timeline = [
@@ -1664,6 +1675,12 @@ export class PositionDetailsDialog {
         if (!isEntry && item.is_partial) {
           badges += `<span class="pdd-badge pdd-badge-warning">${item.percentage}%</span>`;
         }
+        const ladderTier = !isEntry
+          ? ladder.find((t) => t.transaction_signature === item.transaction_signature)
+          : null;
+        if (ladderTier) {
+          badges += `<span class="pdd-badge pdd-badge-success">TP ${ladderTier.tier}</span>`;
+        }
 
         const signature = item.transaction_signature;
         const shortSig = signature ? `${signature.slice(0, 8)}...${signature.slice(-8)}` : "—";
@@ -1998,6 +2015,7 @@ export class PositionDetailsDialog {
         </div>
         ${this._buildDcaSummary(pos, entries)}
         ${this._buildExitSummary(pos, exits, solPriceUsd)}
+        ${this._buildTakeProfitLadder(this.fullDetails?.take_profit_ladder || [])}
       </div>
     `;
   }
@@ -2256,6 +2274,45 @@ export class PositionDetailsDialog {
     `;
   }
 
+  /**
+   * Build take-profit ladder card for analytics tab (only if the ladder is configured)
+   */
+  _buildTakeProfitLadder(tiers) {
+    if (tiers.length === 0) {
+      return "";
+    }
+
+    const statusLabels = {
+      filled: '<span class="pdd-badge pdd-badge-success">Filled</span>',
+      in_flight: '<span class="pdd-badge pdd-badge-warning">Selling</span>',
+      pending: '<span class="pdd-badge pdd-badge-info">Pending</span>',
+    };
+
+    const rowsHtml = tiers
+      .map((tier) => {
+        const triggered =
+          tier.triggered_at !== null && tier.triggered_at !== undefined
+            ? `<span class="pdd-row-pct" title="${Utils.formatTimestamp(tier.triggered_at)}">${Utils.formatTimeAgo(tier.triggered_at)}</span>`
+            : "";
+        return `
+          <div class="pdd-analysis-row">
+            <span class="pdd-row-label">Tier ${tier.tier} · +${Utils.formatNumber(tier.trigger_pct, 0)}%</span>
+            <span class="pdd-row-value">Sell ${Utils.formatNumber(tier.sell_pct, 0)}% ${statusLabels[tier.status] || ""} ${triggered}</span>
+          </div>
+        `;
+      })
+      .join("");
+
+    return `
+      <div class="pdd-analytics-card pdd-exit-summary">
+        <h4><i class="icon-target"></i> Take-Profit Ladder</h4>
+        <div class="pdd-analysis-rows">
+          ${rowsHtml}
+        </div>
+      </div>
+    `;
+  }
+
   /**
    * Build fee analysis card for analytics tab
    */
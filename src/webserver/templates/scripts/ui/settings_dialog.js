@@ -3738,11 +3738,13 @@ export class SettingsDialog {
           position_closed: true,
           partial_exit: true,
           dca_executed: true,
+          order_updates: true,
           errors: true,
           startup_shutdown: true,
           filtering_alerts: true,
           trade_alerts: true,
           daily_summary: false,
+          weekly_summary: false,
         },
         commands_enabled: true,
         inline_actions: true,
@@ -4033,6 +4035,19 @@ export class SettingsDialog {
             </div>
           </div>
 
+          <div class="settings-field">
+            <div class="settings-field-info">
+              <label>Order Updates</label>
+              <span class="settings-field-hint">Notify when limit, stop or OCO orders fill, fail or expire</span>
+            </div>
+            <div class="settings-field-control">
+              <label class="toggle">
+                <input type="checkbox" id="tgNotifyOrders" ${settings.notifications?.order_updates !== false ? "checked" : ""}>
+                <span class="toggle-track"></span>
+              </label>
+            </div>
+          </div>
+
           <div class="settings-field">
             <div class="settings-field-info">
               <label>Errors</label>
@@ -4097,6 +4112,18 @@ export class SettingsDialog {
               </label>
             </div>
           </div>
+          <div class="settings-field">
+            <div class="settings-field-info">
+              <label>Weekly Summary</label>
+              <span class="settings-field-hint">Receive a weekly P&L summary with best and worst trades</span>
+            </div>
+            <div class="settings-field-control">
+              <label class="toggle">
+                <input type="checkbox" id="tgNotifyWeeklySummary" ${settings.notifications?.weekly_summary === true ? "checked" : ""}>
+                <span class="toggle-track"></span>
+              </label>
+            </div>
+          </div>
         </div>
       </div>
 
@@ -4383,11 +4410,13 @@ export class SettingsDialog {
       { id: "#tgNotifyClosed", key: "position_closed" },
       { id: "#tgNotifyPartial", key: "partial_exit" },
       { id: "#tgNotifyDca", key: "dca_executed" },
+      { id: "#tgNotifyOrders", key: "order_updates" },
       { id: "#tgNotifyError", key: "errors" },
       { id: "#tgNotifyStartup", key: "startup_shutdown" },
       { id: "#tgNotifyFiltering", key: "filtering_alerts" },
       { id: "#tgNotifyTradeAlerts", key: "trade_alerts" },
       { id: "#tgNotifyDailySummary", key: "daily_summary" },
+      { id: "#tgNotifyWeeklySummary", key: "weekly_summary" },
     ];
 
     notificationHandlers.forEach(({ id, key }) => {
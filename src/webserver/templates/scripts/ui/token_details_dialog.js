@@ -2793,6 +2793,24 @@ export class TokenDetailsDialog {
             .join("")
         : '<span class="pool-no-data">No reserve accounts</span>';
 
+    // Multi-pool pricing breakdown (only present when enabled)
+    const priceRowsHtml =
+      pool.price_sol !== null && pool.price_sol !== undefined
+        ? `
+            <div class="pool-detail-row">
+              <span class="pool-detail-label">Pool Price</span>
+              <span class="pool-detail-value">${Utils.formatPriceSol(pool.price_sol)}</span>
+            </div>
+
+            <div class="pool-detail-row">
+              <span class="pool-detail-label">Weight / Deviation</span>
+              <span class="pool-detail-value ${pool.price_outlier ? "negative" : "muted"}">${
+                pool.price_outlier ? "Outlier" : `${(pool.price_weight_pct || 0).toFixed(1)}%`
+              } / ${(pool.price_deviation_pct || 0).toFixed(2)}%</span>
+            </div>
+          `
+        : "";
+
     return `
       <div class="pool-detail-card ${pool.is_canonical ? "canonical" : ""}">
         <div class="pool-detail-header">
@@ -2863,6 +2881,7 @@ export class TokenDetailsDialog {
               <span class="pool-detail-label">Last Updated</span>
               <span class="pool-detail-value muted">${lastUpdated}</span>
             </div>
+            ${priceRowsHtml}
           </div>
 
           <div class="pool-detail-divider"></div>
@@ -3782,6 +3801,18 @@ export class TokenDetailsDialog {
       rug_lp_providers_missing: "LP providers missing",
       rug_lp_lock_low: "LP lock too low",
       rug_lp_lock_missing: "LP lock missing",
+      chain_data_missing: "On-chain data missing",
+      chain_mint_authority: "Mint authority present",
+      chain_freeze_authority: "Freeze authority present",
+      chain_transfer_fee_high: "Transfer fee too high",
+      chain_transfer_hook: "Transfer hook present",
+      chain_permanent_delegate: "Permanent delegate present",
+      chain_non_transferable: "Non-transferable token",
+      chain_default_frozen: "Accounts frozen by default",
+      chain_lp_secured_low: "LP burned/locked too low",
+      chain_lp_missing: "LP status unknown",
+      chain_top_holder: "Top holder % too high",
+      chain_top10_holders: "Top 10 holders % too high",
     };
     return labels[reasonCode] || reasonCode;
   }